@@ -59,7 +59,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         entry.0.push((x.0.clone(), &x.2));
     }
 
-    let values: Vec<f32> = dataset
+    let values: Vec<f64> = dataset
         .iter()
         .map(|x| x.2.values().to_vec())
         .flatten()
@@ -138,7 +138,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .x_label_area_size(40)
         .y_label_area_size(40)
         .caption("Horizontal Boxplot", ("sans-serif", 20))
-        .build_cartesian_2d(-30f32..90f32, 0..3)?;
+        .build_cartesian_2d(-30f64..90f64, 0..3)?;
 
     chart.configure_mesh().light_line_style(&WHITE).draw()?;
     chart.draw_series(vec![
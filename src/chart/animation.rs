@@ -0,0 +1,251 @@
+use std::ops::Range;
+
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+
+use plotters_backend::DrawingBackend;
+
+/// The easing function of a transition, which maps the linear progress of the animation to the
+/// progress of the values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Easing {
+    /// The values change at a constant speed
+    Linear,
+    /// The values start slowly and speed up
+    EaseIn,
+    /// The values start quickly and slow down
+    EaseOut,
+    /// The values start and end slowly
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map the linear progress in range `0.0..=1.0` with the cubic easing function
+    pub fn apply(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        if t >= 1.0 {
+            return 1.0;
+        }
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::EaseInOut => 1.0 - (2.0 - 2.0 * t).powi(3) / 2.0,
+        }
+    }
+}
+
+/// The values that can be interpolated between two states of a chart, such as the ranges of
+/// the axes and the data of the series
+pub trait Interpolate {
+    /// Get the value between this one and the other one
+    /// - `to`: The value at the end of the transition
+    /// - `t`: The progress, 0 gives this value and 1 gives the other one
+    fn interpolate(&self, to: &Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        f64::from(*self).interpolate(&f64::from(*to), t) as f32
+    }
+}
+
+impl<A: Interpolate, B: Interpolate> Interpolate for (A, B) {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        (self.0.interpolate(&to.0, t), self.1.interpolate(&to.1, t))
+    }
+}
+
+impl<T: Interpolate> Interpolate for Range<T> {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self.start.interpolate(&to.start, t)..self.end.interpolate(&to.end, t)
+    }
+}
+
+/// The items are interpolated pairwise. When the lengths differ, the items only in the end
+/// state are taken as they are, and the ones only in the start state are dropped.
+impl<T: Interpolate + Clone> Interpolate for Vec<T> {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        to.iter()
+            .enumerate()
+            .map(|(idx, to)| match self.get(idx) {
+                Some(from) => from.interpolate(to, t),
+                None => to.clone(),
+            })
+            .collect()
+    }
+}
+
+/// The iterator over the frames of a transition, see [animate](fn.animate.html)
+pub struct Transition<T> {
+    from: T,
+    to: T,
+    frames: usize,
+    next: usize,
+    easing: Easing,
+}
+
+impl<T: Interpolate> Iterator for Transition<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.next >= self.frames {
+            return None;
+        }
+        let last = self.frames.saturating_sub(1).max(1) as f64;
+        let t = self.easing.apply(self.next as f64 / last);
+        self.next += 1;
+        Some(self.from.interpolate(&self.to, t))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.frames - self.next;
+        (left, Some(left))
+    }
+}
+
+/// Interpolate two states of a chart with the same structure, such as the ranges and the data
+/// of the series before and after an update, and get the state of each frame of the transition.
+/// The first frame is the start state and the last frame is the end state.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let from = (0.0..10.0, vec![(0.0, 1.0), (5.0, 3.0), (10.0, 2.0)]);
+/// let to = (0.0..20.0, vec![(0.0, 4.0), (10.0, 1.0), (20.0, 5.0)]);
+///
+/// let root = BitMapBackend::gif("plotters-doc-data/transition.gif", (320, 240), 50)
+///     .unwrap()
+///     .into_drawing_area();
+/// for (x_range, data) in animate(&from, &to, 20, Easing::EaseInOut) {
+///     root.fill(&WHITE).unwrap();
+///     let mut chart = ChartBuilder::on(&root)
+///         .set_all_label_area_size(30)
+///         .build_cartesian_2d(x_range, 0.0..6.0)
+///         .unwrap();
+///     chart.configure_mesh().draw().unwrap();
+///     chart.draw_series(LineSeries::new(data, &RED)).unwrap();
+///     root.present().unwrap();
+/// }
+/// ```
+///
+/// - `from`: The state at the start of the transition
+/// - `to`: The state at the end of the transition
+/// - `frames`: The number of the frames
+/// - `easing`: The easing function of the transition
+/// - **returns**: The iterator over the states of the frames
+pub fn animate<T: Interpolate + Clone>(
+    from: &T,
+    to: &T,
+    frames: usize,
+    easing: Easing,
+) -> Transition<T> {
+    Transition {
+        from: from.clone(),
+        to: to.clone(),
+        frames,
+        next: 0,
+        easing,
+    }
+}
+
+/// Render the frames of an animation, each frame is drawn on its own drawing area and presented
+/// once it's done. The frames of a GIF are drawn on the same root area repeatedly.
+///
+/// ```rust,no_run
+/// use plotters::coord::ranged3d::ProjectionMatrixBuilder;
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::gif("plotters-doc-data/rotate.gif", (320, 240), 50)
+///     .unwrap()
+///     .into_drawing_area();
+/// let mut from = ProjectionMatrixBuilder::new();
+/// from.yaw = 0.0;
+/// let mut to = from;
+/// to.yaw = std::f64::consts::PI * 2.0;
+/// render_frames(std::iter::repeat(root).take(12), |area, t| {
+///     area.fill(&WHITE)?;
+///     let mut chart = ChartBuilder::on(area).build_cartesian_3d(-1.0..1.0, -1.0..1.0, -1.0..1.0)?;
+///     chart.interpolate_projection(&from, &to, t);
+///     chart.configure_axes().draw()?;
+///     Ok(())
+/// })
+/// .unwrap();
+/// ```
+///
+/// - `frames`: The drawing areas of the frames
+/// - `render`: The function that draws a frame, which takes the drawing area and the progress
+///   of the animation, from 0 at the first frame to 1 at the last frame
+pub fn render_frames<DB, I, F>(
+    frames: I,
+    mut render: F,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+where
+    DB: DrawingBackend,
+    I: IntoIterator<Item = DrawingArea<DB, Shift>>,
+    F: FnMut(&DrawingArea<DB, Shift>, f64) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+{
+    let frames: Vec<_> = frames.into_iter().collect();
+    let last = frames.len().saturating_sub(1).max(1) as f64;
+    for (idx, area) in frames.iter().enumerate() {
+        render(area, idx as f64 / last)?;
+        area.present()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::coord::ranged3d::ProjectionMatrixBuilder;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_frames() {
+        let root = create_mocked_drawing_area(200, 200, |_| {});
+        let mut from = ProjectionMatrixBuilder::new();
+        from.yaw = 0.0;
+        let mut to = from;
+        to.yaw = 1.0;
+        to.scale = 0.5;
+        let view = ProjectionMatrixBuilder::new().interpolate(&from, &to, 0.5);
+        assert_eq!((view.yaw, view.pitch, view.scale), (0.5, from.pitch, 0.75));
+
+        let mut progress = vec![];
+        let mut points = vec![];
+        render_frames(std::iter::repeat(root).take(3), |area, t| {
+            progress.push(t);
+            let mut chart =
+                ChartBuilder::on(area).build_cartesian_3d(-1.0..1.0, -1.0..1.0, -1.0..1.0)?;
+            chart.interpolate_projection(&from, &to, t);
+            points.push(chart.as_coord_spec().translate(&(1.0, 0.0, 0.0)));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(progress, vec![0.0, 0.5, 1.0]);
+        // The chart rotates and shrinks
+        assert!(points[0] != points[1] && points[1] != points[2]);
+    }
+
+    #[test]
+    fn test_animate() {
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5 && Easing::EaseOut.apply(0.5) > 0.5);
+        assert_eq!(Easing::EaseOut.apply(2.0), 1.0);
+
+        let from = (0.0..10.0, vec![(0.0, 0.0)]);
+        let to = (10.0..30.0, vec![(2.0, 4.0), (5.0, 5.0)]);
+        let frames: Vec<_> = animate(&from, &to, 3, Easing::Linear).collect();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].0, 0.0..10.0);
+        assert_eq!(frames[1], (5.0..20.0, vec![(1.0, 2.0), (5.0, 5.0)]));
+        assert_eq!(frames[2], to);
+    }
+}
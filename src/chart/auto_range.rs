@@ -0,0 +1,225 @@
+use std::ops::Range;
+
+use super::{ChartBuilder, ChartContext, SeriesAnno};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::types::RangedCoordf64;
+use crate::drawing::{ClipBackend, DrawingAreaErrorKind};
+use crate::element::{Drawable, PointCollection};
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+type AutoRangeChartContext<'a, DB> =
+    ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
+type DrawResult<DB, T = ()> = Result<T, DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>;
+
+type DeferredSeries<'a, DB> = Box<
+    dyn FnOnce(&mut AutoRangeChartContext<'a, DB>) -> DrawResult<DB, Option<BackendCoord>> + 'a,
+>;
+
+/// Expand the range to the multiples of a round step, which is 1, 2 or 5 times a power of 10,
+/// with no more than 10 steps in the range
+fn nice_range(range: Range<f64>) -> Range<f64> {
+    let span = range.end - range.start;
+    if span.is_nan() || span <= 0.0 || span.is_infinite() {
+        return range;
+    }
+    let magnitude = 10f64.powf(span.log10().floor());
+    let step = [0.1, 0.2, 0.5, 1.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|step| span / step <= 10.0)
+        .unwrap_or(magnitude);
+    (range.start / step).floor() * step..(range.end / step).ceil() * step
+}
+
+/// Add the padding on both sides of the range, or make an empty range around its value
+fn pad_range(range: Range<f64>, padding: f64) -> Range<f64> {
+    let span = range.end - range.start;
+    if span > 0.0 {
+        let pad = span * padding;
+        return range.start - pad..range.end + pad;
+    }
+    let half = if range.start == 0.0 {
+        0.5
+    } else {
+        range.start.abs() * 0.1
+    };
+    range.start - half..range.end + half
+}
+
+/// The chart whose ranges are inferred from the data, which is created by
+/// [ChartBuilder::build_cartesian_2d_auto](struct.ChartBuilder.html#method.build_cartesian_2d_auto).
+///
+/// The series are kept until the ranges are finalized, then the chart is built with the bounds
+/// of all the series and the points from [AutoRangeChart::fit_to](#method.fit_to), expanded by
+/// the padding and rounded to the nice numbers.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut builder = ChartBuilder::on(&root);
+/// builder.x_label_area_size(30).y_label_area_size(40);
+/// let mut auto = builder.build_cartesian_2d_auto();
+/// auto.draw_series(LineSeries::new(
+///     (0..100).map(|x| (x as f64 / 10.0, (x as f64 / 10.0).sin() * 3.0)),
+///     &RED,
+/// ))
+/// .label("sin");
+/// auto.fit_to(vec![(0.0, 0.0)]);
+/// let mut chart = auto
+///     .finalize_with(|chart| chart.configure_mesh().draw())
+///     .unwrap();
+/// chart.configure_series_labels().draw().unwrap();
+/// ```
+pub struct AutoRangeChart<'a, 'b, 'c, DB: DrawingBackend> {
+    builder: &'c mut ChartBuilder<'a, 'b, DB>,
+    bounds: Option<(Range<f64>, Range<f64>)>,
+    series: Vec<(DeferredSeries<'a, DB>, SeriesAnno<'a, DB>)>,
+    padding: f64,
+    nice: bool,
+}
+
+impl<'a, 'b, 'c, DB: DrawingBackend> AutoRangeChart<'a, 'b, 'c, DB> {
+    pub(super) fn new(builder: &'c mut ChartBuilder<'a, 'b, DB>) -> Self {
+        Self {
+            builder,
+            bounds: None,
+            series: vec![],
+            padding: 0.05,
+            nice: true,
+        }
+    }
+
+    fn fit_point(&mut self, (x, y): (f64, f64)) {
+        if !x.is_finite() || !y.is_finite() {
+            return;
+        }
+        self.bounds = Some(match self.bounds.take() {
+            None => (x..x, y..y),
+            Some((bx, by)) => (
+                bx.start.min(x)..bx.end.max(x),
+                by.start.min(y)..by.end.max(y),
+            ),
+        });
+    }
+
+    /// Make sure the given points are in the ranges, for example, to include the origin
+    pub fn fit_to<I: IntoIterator<Item = (f64, f64)>>(&mut self, points: I) -> &mut Self {
+        for point in points {
+            self.fit_point(point);
+        }
+        self
+    }
+
+    /// Set the padding on both sides of the ranges, as a fraction of the span, 0.05 by default
+    pub fn padding(&mut self, padding: f64) -> &mut Self {
+        self.padding = if padding > 0.0 { padding } else { 0.0 };
+        self
+    }
+
+    /// Set if the ranges are expanded to the nice numbers, which is on by default
+    pub fn nice(&mut self, nice: bool) -> &mut Self {
+        self.nice = nice;
+        self
+    }
+
+    /// Keep a data series, which is drawn when the chart is finalized, and fit the ranges to it
+    /// - `series`: The series, see [ChartContext::draw_series](struct.ChartContext.html#method.draw_series)
+    /// - **returns** The annotation of the series
+    pub fn draw_series<E, S>(&mut self, series: S) -> &mut SeriesAnno<'a, DB>
+    where
+        E: Drawable<ClipBackend<DB>> + 'a,
+        for<'e> &'e E: PointCollection<'e, (f64, f64)>,
+        S: IntoIterator<Item = E>,
+    {
+        let mut elements = vec![];
+        for element in series {
+            for point in element.point_iter() {
+                self.fit_point(*std::borrow::Borrow::borrow(&point));
+            }
+            elements.push(element);
+        }
+        let draw: DeferredSeries<'a, DB> = Box::new(move |chart| chart.draw_series_impl(elements));
+        self.series.push((draw, SeriesAnno::new()));
+        let idx = self.series.len() - 1;
+        &mut self.series[idx].1
+    }
+
+    /// Get the ranges that the chart will be built with
+    pub fn ranges(&self) -> (Range<f64>, Range<f64>) {
+        let (x, y) = self.bounds.clone().unwrap_or((0.0..1.0, 0.0..1.0));
+        let (x, y) = (pad_range(x, self.padding), pad_range(y, self.padding));
+        if self.nice {
+            (nice_range(x), nice_range(y))
+        } else {
+            (x, y)
+        }
+    }
+
+    /// Build the chart with the inferred ranges and draw the series
+    pub fn finalize(
+        self,
+    ) -> Result<AutoRangeChartContext<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.finalize_with(|_| Ok(()))
+    }
+
+    /// Build the chart with the inferred ranges, then call the function before drawing the
+    /// series, which is where the mesh is drawn to keep it below the data
+    pub fn finalize_with<F>(
+        self,
+        before_series: F,
+    ) -> Result<AutoRangeChartContext<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        F: FnOnce(&mut AutoRangeChartContext<'a, DB>) -> DrawResult<DB>,
+    {
+        let (x, y) = self.ranges();
+        let mut chart = self.builder.build_cartesian_2d(x, y)?;
+        before_series(&mut chart)?;
+        for (draw, mut anno) in self.series {
+            anno.set_end_point(draw(&mut chart)?);
+            chart.series_anno.push(anno);
+        }
+        Ok(chart)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_nice_range() {
+        assert_eq!(nice_range(0.3..9.2), 0.0..10.0);
+        assert_eq!(nice_range(-0.13..0.42), -0.2..0.5);
+        assert_eq!(nice_range(120.0..180.0), 120.0..180.0);
+        assert_eq!(pad_range(0.0..10.0, 0.1), -1.0..11.0);
+        assert_eq!(pad_range(5.0..5.0, 0.1), 4.5..5.5);
+    }
+
+    #[test]
+    fn test_auto_range() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 3));
+        });
+        let mut builder = ChartBuilder::on(&drawing_area);
+        let mut auto = builder.build_cartesian_2d_auto();
+        auto.draw_series(
+            vec![(1.0, 2.0), (3.0, 8.0)]
+                .into_iter()
+                .map(|p| Circle::new(p, 3, &RED)),
+        )
+        .label("circles");
+        auto.draw_series(std::iter::once(Circle::new((7.0, 5.0), 3, &BLUE)));
+        assert_eq!(auto.ranges(), (0.0..8.0, 1.0..9.0));
+        auto.fit_to(vec![(0.0, 0.0)]).nice(false).padding(0.0);
+        assert_eq!(auto.ranges(), (0.0..7.0, 0.0..8.0));
+
+        let chart = auto.finalize().unwrap();
+        assert_eq!((chart.x_range(), chart.y_range()), (0.0..7.0, 0.0..8.0));
+        assert_eq!(chart.series_anno.len(), 2);
+    }
+}
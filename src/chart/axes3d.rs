@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use super::ChartContext;
 use crate::coord::cartesian::Cartesian3d;
 use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
-use crate::style::colors::{BLACK, TRANSPARENT};
+use crate::style::colors::TRANSPARENT;
 use crate::style::Color;
 use crate::style::{AsRelative, ShapeStyle, SizeDesc, TextStyle};
 
@@ -101,15 +101,17 @@ where
         let parent_size = chart.drawing_area.dim_in_pixel();
         let base_tick_size = (5u32).percent().max(5).in_pixels(chart.plotting_area());
         let tick_size = base_tick_size;
+        let theme = chart.theme;
+        let label_size = (12).percent().max(12).in_pixels(&parent_size);
         Self {
             parent_size,
             tick_size,
             n_labels: [10, 10, 10],
-            bold_line_style: Into::<ShapeStyle>::into(&BLACK.mix(0.2)),
+            bold_line_style: Into::<ShapeStyle>::into(&theme.bold_grid),
             light_line_style: Into::<ShapeStyle>::into(&TRANSPARENT),
-            axis_panel_style: Into::<ShapeStyle>::into(&BLACK.mix(0.1)),
-            axis_style: Into::<ShapeStyle>::into(&BLACK.mix(0.8)),
-            label_style: ("sans-serf", (12).percent().max(12).in_pixels(&parent_size)).into(),
+            axis_panel_style: Into::<ShapeStyle>::into(&theme.light_grid),
+            axis_style: Into::<ShapeStyle>::into(&theme.foreground.mix(0.8)),
+            label_style: theme.text_style(f64::from(label_size)),
             format_x: &X::format,
             format_y: &Y::format,
             format_z: &Z::format,
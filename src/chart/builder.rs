@@ -1,13 +1,23 @@
+use super::auto_range::AutoRangeChart;
 use super::context::ChartContext;
+use super::series::SeriesLabelPosition;
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
-use crate::coord::ranged1d::AsRangedCoord;
+use crate::coord::geo::{GeoCoord, Projection};
+use crate::coord::polar::Polar;
+use crate::coord::ranged1d::{AsRangedCoord, BoldPoints, Ranged, ValueFormatter};
+use crate::coord::ternary::TernaryCoord;
 use crate::coord::Shift;
 
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
+use crate::element::Text;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{AsRelative, IntoTextStyle, Palette, RichText, SizeDesc, TextStyle, Theme};
 
-use plotters_backend::DrawingBackend;
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+use num_traits::{NumCast, ToPrimitive};
+use std::ops::Range;
 
 /// The enum used to specify the position of label area.
 /// This is used when we configure the label area size with the API
@@ -20,15 +30,64 @@ pub enum LabelAreaPosition {
     Right = 3,
 }
 
+/// The aspect ratio constraint used by
+/// [ChartBuilder::build_cartesian_2d_with_aspect](struct.ChartBuilder.html#method.build_cartesian_2d_with_aspect)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Aspect {
+    /// One data unit maps to the same number of pixels on both axes
+    Equal,
+    /// One data unit on the Y axis maps to the given times of the pixels of one data unit on the
+    /// X axis
+    Ratio(f64),
+}
+
+impl Aspect {
+    fn ratio(self) -> f64 {
+        match self {
+            Aspect::Equal => 1.0,
+            Aspect::Ratio(r) => r,
+        }
+    }
+}
+
+/// How the aspect ratio constraint is satisfied, see
+/// [ChartBuilder::aspect_fit](struct.ChartBuilder.html#method.aspect_fit)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AspectFit {
+    /// Expand one of the axis ranges around its center, the plotting area is unchanged
+    ExpandRange,
+    /// Shrink the plotting area along one of the axes, the axis ranges are unchanged
+    ShrinkArea,
+}
+
+/// A single line of the chart caption, which is made of one or more styled text spans
+struct CaptionLine<'b> {
+    spans: Vec<(String, TextStyle<'b>)>,
+    // `None` follows the alignment of the caption
+    align: Option<HPos>,
+}
+
 /// The helper object to create a chart context, which is used for the high-level figure drawing.
 /// With the help of this object, we can convert a basic drawing area into a chart context, which
 /// allows the high-level charting API being used on the drawing area.
 pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     label_area_size: [u32; 4], // [upper, lower, left, right]
     overlap_plotting_area: [bool; 4],
+    mirror_label_area: [bool; 2], // [top, right]
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
+    rich_title: Option<RichText>,
+    title_align: HPos,
+    title_pos: VPos,
+    title_padding: Option<u32>,
+    subtitles: Vec<CaptionLine<'b>>,
     margin: [u32; 4],
+    aspect_fit: AspectFit,
+    colorbar_area_size: u32,
+    legend_area_size: [u32; 2], // [right, below]
+    auto_label_style: Option<TextStyle<'b>>,
+    auto_axis_desc: [bool; 2], // [x, y]
+    theme: Theme,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -40,11 +99,41 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             label_area_size: [0; 4],
             root_area: root,
             title: None,
+            rich_title: None,
+            title_align: HPos::Center,
+            title_pos: VPos::Top,
+            title_padding: None,
+            subtitles: vec![],
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            mirror_label_area: [false; 2],
+            aspect_fit: AspectFit::ExpandRange,
+            colorbar_area_size: 0,
+            legend_area_size: [0; 2],
+            auto_label_style: None,
+            auto_axis_desc: [false; 2],
+            theme: Theme::LIGHT,
         }
     }
 
+    /// Set the theme of the chart, which provides the default styles of the mesh, the axes and
+    /// the series labels. The background isn't filled by the chart, use
+    /// [DrawingArea::fill_theme](../drawing/struct.DrawingArea.html#method.fill_theme) with the
+    /// same theme.
+    /// - `theme`: The theme, see [Theme](../style/struct.Theme.html)
+    pub fn theme(&mut self, theme: &Theme) -> &mut Self {
+        self.theme = *theme;
+        self
+    }
+
+    /// Set the palette that the series colors are picked from by
+    /// [ChartContext::draw_series_with_palette](struct.ChartContext.html#method.draw_series_with_palette),
+    /// which replaces the palette of the theme
+    pub fn palette<P: Palette>(&mut self, palette: P) -> &mut Self {
+        self.theme = self.theme.palette(palette);
+        self
+    }
+
     /// Set the margin size of the chart (applied for top, bottom, left and right at the same time)
     /// - `size`: The size of the chart margin.
     pub fn margin<S: SizeDesc>(&mut self, size: S) -> &mut Self {
@@ -125,6 +214,209 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self.set_label_area_size(LabelAreaPosition::Right, size)
     }
 
+    /// Set the label style that [ChartBuilder::auto_label_areas](#method.auto_label_areas)
+    /// measures the labels with, which should be the one of the mesh. The rotation of the font
+    /// is taken into account. By default, the default label style of the mesh is used.
+    /// - `style`: The style of the labels
+    pub fn auto_label_style<S: IntoTextStyle<'b>>(&mut self, style: S) -> &mut Self {
+        self.auto_label_style = Some(style.into_text_style(self.root_area));
+        self
+    }
+
+    /// Make [ChartBuilder::auto_label_areas](#method.auto_label_areas) leave the room for the
+    /// axis descriptions, which are set by [MeshStyle::x_desc](struct.MeshStyle.html#method.x_desc)
+    /// and [MeshStyle::y_desc](struct.MeshStyle.html#method.y_desc)
+    /// - `x`: If the X axis has a description
+    /// - `y`: If the Y axis has a description
+    pub fn auto_axis_desc(&mut self, x: bool, y: bool) -> &mut Self {
+        self.auto_axis_desc = [x, y];
+        self
+    }
+
+    /// Set the sizes of the bottom and the left label areas from the measured extents of the
+    /// tick labels that the mesh draws for the given axis specifications, so that the labels
+    /// are neither clipped nor far from the axes. The mirrored label areas follow them. Call
+    /// [ChartBuilder::auto_label_style](#method.auto_label_style) and
+    /// [ChartBuilder::auto_axis_desc](#method.auto_axis_desc) first when they are used.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let (x, y) = (0.0..10.0, 0.0..1_000_000.0);
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .auto_axis_desc(true, true)
+    ///     .auto_label_areas(&x, &y)
+    ///     .build_cartesian_2d(x, y)
+    ///     .unwrap();
+    /// chart.configure_mesh().x_desc("Time").y_desc("Count").draw().unwrap();
+    /// ```
+    /// - `x_spec`: The specification of the X axis
+    /// - `y_spec`: The specification of the Y axis
+    pub fn auto_label_areas<X, Y>(&mut self, x_spec: &X, y_spec: &Y) -> &mut Self
+    where
+        X: AsRangedCoord + Clone,
+        Y: AsRangedCoord + Clone,
+        X::CoordDescType: ValueFormatter<X::Value>,
+        Y::CoordDescType: ValueFormatter<Y::Value>,
+    {
+        let x: X::CoordDescType = x_spec.clone().into();
+        let y: Y::CoordDescType = y_spec.clone().into();
+        self.auto_label_areas_with(x_spec, y_spec, |v| x.format_ext(v), |v| y.format_ext(v))
+    }
+
+    /// Set the sizes of the bottom and the left label areas from the measured extents of the
+    /// tick labels, which are formatted with the same formatters as the mesh, see
+    /// [ChartBuilder::auto_label_areas](#method.auto_label_areas)
+    /// - `x_spec`: The specification of the X axis
+    /// - `y_spec`: The specification of the Y axis
+    /// - `x_fmt`: The formatter of the X labels
+    /// - `y_fmt`: The formatter of the Y labels
+    pub fn auto_label_areas_with<X, Y, FX, FY>(
+        &mut self,
+        x_spec: &X,
+        y_spec: &Y,
+        x_fmt: FX,
+        y_fmt: FY,
+    ) -> &mut Self
+    where
+        X: AsRangedCoord + Clone,
+        Y: AsRangedCoord + Clone,
+        FX: Fn(&X::Value) -> String,
+        FY: Fn(&Y::Value) -> String,
+    {
+        let x: X::CoordDescType = x_spec.clone().into();
+        let y: Y::CoordDescType = y_spec.clone().into();
+        let x_labels: Vec<_> = x.key_points(BoldPoints(10)).iter().map(x_fmt).collect();
+        let y_labels: Vec<_> = y.key_points(BoldPoints(10)).iter().map(y_fmt).collect();
+        self.label_area_size[1] = self.measure_label_area(&x_labels, false, self.auto_axis_desc[0]);
+        self.label_area_size[2] = self.measure_label_area(&y_labels, true, self.auto_axis_desc[1]);
+        self
+    }
+
+    /// Get the label area size that fits the labels, the tick marks and the axis description
+    fn measure_label_area(&self, labels: &[String], vertical_axis: bool, desc: bool) -> u32 {
+        // These are the defaults of the mesh, which are measured against the root area since the
+        // plotting area isn't known yet
+        let style = self.auto_label_style.clone().unwrap_or_else(|| {
+            self.theme
+                .text_style((12i32).percent().max(12).in_pixels(self.root_area) as f64)
+        });
+        let tick_size = (5u32).percent().max(5).in_pixels(self.root_area);
+
+        let (sin, cos) = style.font.get_rotation().to_radians().sin_cos();
+        let extent = labels
+            .iter()
+            .filter_map(|text| self.root_area.estimate_text_size(text, &style).ok())
+            .map(|(w, h)| {
+                let (w, h) = (w as f64, h as f64);
+                if vertical_axis {
+                    (w * cos).abs() + (h * sin).abs()
+                } else {
+                    (w * sin).abs() + (h * cos).abs()
+                }
+            })
+            .fold(0.0, f64::max);
+
+        let mut size = tick_size * 2 + extent.ceil() as i32;
+        if desc {
+            size += tick_size + style.font.get_size().ceil() as i32;
+        }
+        size.max(0) as u32
+    }
+
+    /// Mirror the X axis on the top of the chart, so that the ticks and labels are drawn on both
+    /// the top and the bottom sides. Unless the top label area size is set explicitly, the top label
+    /// area is reserved with the same size as the bottom one. The labels on the top axis can be
+    /// customized with [MeshStyle::top_label_formatter](struct.MeshStyle.html#method.top_label_formatter).
+    /// - `mirror`: If the X axis should be mirrored
+    pub fn x_labels_on_top(&mut self, mirror: bool) -> &mut Self {
+        self.mirror_label_area[0] = mirror;
+        self
+    }
+
+    /// Mirror the Y axis on the right side of the chart, so that the ticks and labels are drawn on
+    /// both the left and the right sides. Unless the right label area size is set explicitly, the
+    /// right label area is reserved with the same size as the left one. The labels on the right axis
+    /// can be customized with [MeshStyle::right_label_formatter](struct.MeshStyle.html#method.right_label_formatter).
+    /// Note: When a secondary coordinate is attached to the chart, the right axis belongs to the
+    /// secondary coordinate.
+    /// - `mirror`: If the Y axis should be mirrored
+    pub fn y_labels_on_right(&mut self, mirror: bool) -> &mut Self {
+        self.mirror_label_area[1] = mirror;
+        self
+    }
+
+    /// Get the actual size of the label areas, with the mirrored label areas resolved
+    fn actual_label_area_size(&self) -> ([u32; 4], [bool; 4]) {
+        let mut size = self.label_area_size;
+        let mut overlap = self.overlap_plotting_area;
+        // The top label area mirrors the bottom one, and the right mirrors the left one
+        for (&mirror, &(dst, src)) in self.mirror_label_area.iter().zip([(0, 1), (3, 2)].iter()) {
+            if mirror && size[dst] == 0 {
+                size[dst] = size[src];
+                overlap[dst] = overlap[src];
+            }
+        }
+        (size, overlap)
+    }
+
+    /// Reserve an area on the right side of the chart for a
+    /// [Colorbar](../element/struct.Colorbar.html), which is drawn with
+    /// [ChartContext::draw_colorbar](struct.ChartContext.html#method.draw_colorbar)
+    /// - `size`: The width of the colorbar area
+    pub fn colorbar_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.colorbar_area_size = size.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Reserve an area outside of the plotting area for the series labels, which are drawn there
+    /// by [ChartContext::configure_series_labels](struct.ChartContext.html#method.configure_series_labels)
+    /// by default, thus the legend never covers the data. The area is only reserved for the 2D
+    /// cartesian charts.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .series_label_area_size(SeriesLabelPosition::RightOfPlot, 100)
+    ///     .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(LineSeries::new(vec![(0.0, 0.0), (1.0, 1.0)], &RED))
+    ///     .unwrap()
+    ///     .label("y = x");
+    /// chart.configure_series_labels().draw().unwrap();
+    /// ```
+    /// - `pos`: Either [SeriesLabelPosition::RightOfPlot](enum.SeriesLabelPosition.html#variant.RightOfPlot)
+    ///   or [SeriesLabelPosition::BelowPlot](enum.SeriesLabelPosition.html#variant.BelowPlot),
+    ///   the other positions are ignored
+    /// - `size`: The width or the height of the area
+    pub fn series_label_area_size<S: SizeDesc>(
+        &mut self,
+        pos: SeriesLabelPosition,
+        size: S,
+    ) -> &mut Self {
+        let size = size.in_pixels(self.root_area).max(0) as u32;
+        match pos {
+            SeriesLabelPosition::RightOfPlot => self.legend_area_size[0] = size,
+            SeriesLabelPosition::BelowPlot => self.legend_area_size[1] = size,
+            _ => {}
+        }
+        self
+    }
+
+    /// Set how [ChartBuilder::build_cartesian_2d_with_aspect](#method.build_cartesian_2d_with_aspect)
+    /// satisfies the aspect ratio, by default the axis ranges are expanded
+    /// - `fit`: The fitting mode
+    pub fn aspect_fit(&mut self, fit: AspectFit) -> &mut Self {
+        self.aspect_fit = fit;
+        self
+    }
+
     /// Set a label area size
     /// - `pos`: THe position where the label area located
     /// - `size`: The size of the label area size
@@ -152,9 +444,260 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             caption.as_ref().to_string(),
             style.into_text_style(self.root_area),
         ));
+        self.rich_title = None;
+        self
+    }
+
+    /// Set the caption of the chart to a rich text, such as a formula with superscripts
+    /// - `caption`: The caption of the chart, see [RichText](../style/struct.RichText.html)
+    /// - `style`: The text style that the spans of the caption are relative to
+    pub fn caption_rich<T: Into<RichText>, Style: IntoTextStyle<'b>>(
+        &mut self,
+        caption: T,
+        style: Style,
+    ) -> &mut Self {
+        let caption = caption.into();
+        self.title = Some((caption.plain_text(), style.into_text_style(self.root_area)));
+        self.rich_title = Some(caption);
+        self
+    }
+
+    /// Set the caption of the chart with a subtitle line below it. The subtitle is drawn with
+    /// the font of the caption at 70% of its size and follows the alignment of the caption.
+    /// - `title`: The caption of the chart
+    /// - `subtitle`: The subtitle of the chart
+    /// - `style`: The text style of the caption
+    pub fn caption_with_subtitle<S: AsRef<str>, T: AsRef<str>, Style: IntoTextStyle<'b>>(
+        &mut self,
+        title: S,
+        subtitle: T,
+        style: Style,
+    ) -> &mut Self {
+        let style = style.into_text_style(self.root_area);
+        let subtitle_style = TextStyle {
+            font: style.font.resize(style.font.get_size() * 0.7),
+            ..style.clone()
+        };
+        self.caption(title, style);
+        self.subtitles.push(CaptionLine {
+            spans: vec![(subtitle.as_ref().to_string(), subtitle_style)],
+            align: None,
+        });
+        self
+    }
+
+    /// Set the horizontal alignment of the caption
+    /// - `align`: The alignment of the caption line, by default the caption is centered
+    pub fn caption_align(&mut self, align: HPos) -> &mut Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Set where the caption and the subtitles are placed
+    /// - `pos`: `VPos::Bottom` places them below the chart, otherwise they are above it, which
+    ///   is the default
+    pub fn caption_position(&mut self, pos: VPos) -> &mut Self {
+        self.title_pos = pos;
+        self
+    }
+
+    /// Set the space above and below each caption line
+    /// - `padding`: The padding in pixels, by default it's half of the line height up to 5 pixels
+    pub fn caption_padding<S: SizeDesc>(&mut self, padding: S) -> &mut Self {
+        self.title_padding = Some(padding.in_pixels(self.root_area).max(0) as u32);
+        self
+    }
+
+    /// Add a subtitle line below the caption of the chart. Subtitles are stacked in the order
+    /// they are added and each of them reserves its own vertical space.
+    /// - `text`: The text of the subtitle, an empty subtitle doesn't reserve any space
+    /// - `style`: The text style
+    pub fn subtitle<S: AsRef<str>, Style: IntoTextStyle<'b>>(
+        &mut self,
+        text: S,
+        style: Style,
+    ) -> &mut Self {
+        self.caption_line(std::iter::once((text, style)), HPos::Center)
+    }
+
+    /// Add a caption line made of multiple styled spans, for example a bold metric name followed
+    /// by a regular description. The spans are drawn one after another on the same line.
+    /// - `spans`: The text and style of each span
+    /// - `align`: The horizontal alignment of the line
+    pub fn caption_line<
+        S: AsRef<str>,
+        Style: IntoTextStyle<'b>,
+        I: IntoIterator<Item = (S, Style)>,
+    >(
+        &mut self,
+        spans: I,
+        align: HPos,
+    ) -> &mut Self {
+        let root_area = self.root_area;
+        let spans = spans
+            .into_iter()
+            .map(|(text, style)| (text.as_ref().to_string(), style.into_text_style(root_area)))
+            .collect();
+        self.subtitles.push(CaptionLine {
+            spans,
+            align: Some(align),
+        });
+        self
+    }
+
+    /// Add multiple centered subtitle lines at once, each of them has its own text style
+    /// - `lines`: The text and style of each line
+    pub fn caption_multiline<
+        S: AsRef<str>,
+        Style: IntoTextStyle<'b>,
+        I: IntoIterator<Item = (S, Style)>,
+    >(
+        &mut self,
+        lines: I,
+    ) -> &mut Self {
+        for line in lines {
+            self.caption_line(std::iter::once(line), HPos::Center);
+        }
         self
     }
 
+    /// Draw the caption lines on the drawing area and returns the remaining area
+    fn draw_caption(
+        &self,
+        mut drawing_area: DrawingArea<DB, Shift>,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        // The lines at the bottom are taken from the bottom up, so that the order is kept
+        if let VPos::Bottom = self.title_pos {
+            for line in self.subtitles.iter().rev() {
+                drawing_area = self.draw_caption_line(drawing_area, line)?;
+            }
+            return self.draw_title(drawing_area);
+        }
+
+        drawing_area = self.draw_title(drawing_area)?;
+        for line in self.subtitles.iter() {
+            drawing_area = self.draw_caption_line(drawing_area, line)?;
+        }
+
+        Ok(drawing_area)
+    }
+
+    fn draw_title(
+        &self,
+        drawing_area: DrawingArea<DB, Shift>,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let (title, style) = match self.title {
+            Some((ref title, ref style)) => (title, style),
+            None => return Ok(drawing_area),
+        };
+        let rich_title = self.rich_title.as_ref().filter(|t| t.as_plain().is_none());
+        if let Some(rich_title) = rich_title {
+            return self.draw_rich_caption(drawing_area, rich_title, style);
+        }
+        match (self.title_align, self.title_pos, self.title_padding) {
+            (HPos::Center, VPos::Top, None) | (HPos::Center, VPos::Center, None) => {
+                drawing_area.titled(title, style.clone())
+            }
+            _ => {
+                let line = CaptionLine {
+                    spans: vec![(title.clone(), style.clone())],
+                    align: None,
+                };
+                self.draw_caption_line(drawing_area, &line)
+            }
+        }
+    }
+
+    /// Reserve the space of a caption line and returns the position of the line along with the
+    /// remaining area
+    fn place_caption_line(
+        &self,
+        drawing_area: DrawingArea<DB, Shift>,
+        (line_w, line_h): (i32, i32),
+        align: HPos,
+    ) -> (BackendCoord, DrawingArea<DB, Shift>) {
+        let (area_w, area_h) = drawing_area.dim_in_pixel();
+        let y_padding = match self.title_padding {
+            Some(padding) => padding as i32,
+            None => (line_h / 2).min(5),
+        };
+        let size = y_padding * 2 + line_h;
+
+        let x = match align {
+            HPos::Left => 0,
+            HPos::Center => (area_w as i32 - line_w) / 2,
+            HPos::Right => area_w as i32 - line_w,
+        };
+
+        match self.title_pos {
+            VPos::Bottom => (
+                (x, area_h as i32 - y_padding - line_h),
+                drawing_area.margin(0, size, 0, 0),
+            ),
+            _ => ((x, y_padding), drawing_area.margin(size, 0, 0, 0)),
+        }
+    }
+
+    fn draw_caption_line(
+        &self,
+        drawing_area: DrawingArea<DB, Shift>,
+        line: &CaptionLine,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut sizes = vec![];
+        for (text, style) in line.spans.iter() {
+            if text.is_empty() {
+                sizes.push((0, 0));
+            } else {
+                sizes.push(drawing_area.estimate_text_size(text, style)?);
+            }
+        }
+
+        let line_w = sizes.iter().map(|(w, _)| *w as i32).sum::<i32>();
+        let line_h = sizes.iter().map(|(_, h)| *h as i32).max().unwrap_or(0);
+
+        if line_h == 0 {
+            return Ok(drawing_area);
+        }
+
+        let align = line.align.unwrap_or(self.title_align);
+        let ((mut x, y), remaining) =
+            self.place_caption_line(drawing_area.clone(), (line_w, line_h), align);
+
+        for ((text, style), (w, _)) in line.spans.iter().zip(sizes) {
+            if !text.is_empty() {
+                let style = style.pos(Pos::new(HPos::Left, VPos::Top));
+                drawing_area.draw_text(text, &style, (x, y))?;
+            }
+            x += w as i32;
+        }
+
+        Ok(remaining)
+    }
+
+    fn draw_rich_caption(
+        &self,
+        drawing_area: DrawingArea<DB, Shift>,
+        text: &RichText,
+        style: &TextStyle,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let ((line_w, line_h), _) = text.layout(style, |text, style| {
+            drawing_area.estimate_text_size(text, style)
+        })?;
+        let (line_w, line_h) = (line_w as i32, line_h as i32);
+
+        if line_h == 0 {
+            return Ok(drawing_area);
+        }
+
+        let (pos, remaining) =
+            self.place_caption_line(drawing_area.clone(), (line_w, line_h), self.title_align);
+
+        let style = style.pos(Pos::new(HPos::Left, VPos::Top));
+        drawing_area.draw(&Text::rich(text.clone(), pos, style))?;
+
+        Ok(remaining)
+    }
+
     #[allow(clippy::type_complexity)]
     #[deprecated(
         note = "`build_ranged` has been renamed to `build_cartesian_2d` and is to be removed in the future."
@@ -184,7 +727,135 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         ChartContext<'a, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
         DrawingAreaErrorKind<DB::ErrorType>,
     > {
+        self.build_cartesian_2d_impl(|_| ((0, 0), x_spec, y_spec))
+    }
+
+    /// Build the chart with a 2D Cartesian coordinate system whose ranges are inferred from the
+    /// series, see [AutoRangeChart](struct.AutoRangeChart.html) for the details
+    /// - Returns: The chart whose ranges are not finalized yet
+    pub fn build_cartesian_2d_auto(&mut self) -> AutoRangeChart<'a, 'b, '_, DB> {
+        AutoRangeChart::new(self)
+    }
+
+    /// Build the chart with a 2D Cartesian coordinate system, where one data unit on both axes
+    /// maps to the pixels with the given aspect ratio. This is useful for geometric data, for
+    /// example, a circle in data coordinates is drawn as a circle with `Aspect::Equal`.
+    ///
+    /// Depending on [ChartBuilder::aspect_fit](#method.aspect_fit), either one of the ranges is
+    /// expanded around its center or the plotting area is shrunk. Since the constraint is
+    /// computed from the actual size of the plotting area, building the chart again after the
+    /// drawing area is resized keeps the aspect ratio.
+    /// - `x_range`: The range of X axis
+    /// - `y_range`: The range of Y axis
+    /// - `aspect`: The aspect ratio constraint
+    /// - Returns: A chart context
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d_with_aspect(-1.0..1.0, -1.0..1.0, Aspect::Equal)
+    ///     .unwrap();
+    /// // The X range is expanded so that the unit circle is round
+    /// assert_eq!(chart.x_range(), -(4.0 / 3.0)..(4.0 / 3.0));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn build_cartesian_2d_with_aspect<X, Y>(
+        &mut self,
+        x_range: Range<X>,
+        y_range: Range<Y>,
+        aspect: Aspect,
+    ) -> Result<
+        ChartContext<
+            'a,
+            DB,
+            Cartesian2d<
+                <Range<X> as AsRangedCoord>::CoordDescType,
+                <Range<Y> as AsRangedCoord>::CoordDescType,
+            >,
+        >,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        X: ToPrimitive + NumCast + Copy,
+        Y: ToPrimitive + NumCast + Copy,
+        Range<X>: AsRangedCoord,
+        Range<Y>: AsRangedCoord,
+    {
+        let fit = self.aspect_fit;
+        self.build_cartesian_2d_impl(move |(w, h)| {
+            let to_f64 = |a: Option<f64>, b: Option<f64>| (a.unwrap_or(0.0), b.unwrap_or(0.0));
+            let x = to_f64(x_range.start.to_f64(), x_range.end.to_f64());
+            let y = to_f64(y_range.start.to_f64(), y_range.end.to_f64());
+            let (x_span, y_span) = ((x.1 - x.0).abs(), (y.1 - y.0).abs());
+            let (w, h) = (w.max(1) as f64, h.max(1) as f64);
+            if x_span == 0.0 || y_span == 0.0 || !aspect.ratio().is_finite() {
+                return ((0, 0), x_range, y_range);
+            }
+
+            // The pixels per data unit of each axis
+            let px = w / x_span;
+            let py = h / y_span;
+            let target = aspect.ratio() * px;
+
+            // Expand the range around its center by the given factor
+            fn expand<T: NumCast + ToPrimitive + Copy>(
+                range: Range<T>,
+                (a, b): (f64, f64),
+                k: f64,
+            ) -> Range<T> {
+                let (mid, half) = ((a + b) / 2.0, (b - a) / 2.0 * k);
+                let (mut start, mut end) = (mid - half, mid + half);
+                // The cast truncates, thus the integer ranges are rounded outward instead
+                let integer = NumCast::from(0.5).and_then(|v: T| v.to_f64()) != Some(0.5);
+                if integer && start <= end {
+                    start = start.floor();
+                    end = end.ceil();
+                } else if integer {
+                    start = start.ceil();
+                    end = end.floor();
+                }
+                match (NumCast::from(start), NumCast::from(end)) {
+                    (Some(start), Some(end)) => start..end,
+                    _ => range,
+                }
+            }
+
+            match fit {
+                AspectFit::ExpandRange if py > target => {
+                    ((0, 0), x_range, expand(y_range, y, py / target))
+                }
+                AspectFit::ExpandRange => ((0, 0), expand(x_range, x, target / py), y_range),
+                AspectFit::ShrinkArea if py > target => {
+                    let dh = h - target * y_span;
+                    ((0, dh.max(0.0) as u32), x_range, y_range)
+                }
+                AspectFit::ShrinkArea => {
+                    let dw = w - py / aspect.ratio() * x_span;
+                    ((dw.max(0.0) as u32, 0), x_range, y_range)
+                }
+            }
+        })
+    }
+
+    /// Lay out the 2D Cartesian chart. The `make_spec` function is called with the size of the
+    /// plotting area, and returns the number of pixels the plotting area should be shrunk by,
+    /// together with the specification of the axes.
+    #[allow(clippy::type_complexity)]
+    fn build_cartesian_2d_impl<X: AsRangedCoord, Y: AsRangedCoord, F>(
+        &mut self,
+        make_spec: F,
+    ) -> Result<
+        ChartContext<'a, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        F: FnOnce((u32, u32)) -> ((u32, u32), X, Y),
+    {
         let mut label_areas = [None, None, None, None];
+        let (label_area_size, overlap_plotting_area) = self.actual_label_area_size();
 
         let mut drawing_area = DrawingArea::clone(self.root_area);
 
@@ -197,15 +868,68 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
+        let (title_dx, title_dy) = {
             let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
+            drawing_area = self.draw_caption(drawing_area)?;
             let (current_dx, current_dy) = drawing_area.get_base_pixel();
             (current_dx - origin_dx, current_dy - origin_dy)
+        };
+
+        // The area below the chart spans the full width, and the one on the right is beside it
+        let mut legend_area = [None, None];
+        if self.legend_area_size[1] > 0 {
+            let (_, h) = drawing_area.dim_in_pixel();
+            let split_point = h.saturating_sub(self.legend_area_size[1]) as i32;
+            let (chart_area, area) = drawing_area.split_vertically(split_point);
+            drawing_area = chart_area;
+            legend_area[1] = Some(area);
+        }
+        if self.legend_area_size[0] > 0 {
+            let (w, _) = drawing_area.dim_in_pixel();
+            let split_point = w.saturating_sub(self.legend_area_size[0]) as i32;
+            let (chart_area, area) = drawing_area.split_horizontally(split_point);
+            drawing_area = chart_area;
+            legend_area[0] = Some(area);
+        }
+
+        let colorbar_area = if self.colorbar_area_size > 0 {
+            let (w, _) = drawing_area.dim_in_pixel();
+            let split_point = w.saturating_sub(self.colorbar_area_size) as i32;
+            let (chart_area, colorbar_area) = drawing_area.split_horizontally(split_point);
+            drawing_area = chart_area;
+            Some(colorbar_area)
         } else {
-            (0, 0)
+            None
+        };
+
+        let plotting_area_size = {
+            let (w, h) = drawing_area.dim_in_pixel();
+            let non_overlapping = |idx: usize| {
+                if overlap_plotting_area[idx] {
+                    0
+                } else {
+                    label_area_size[idx]
+                }
+            };
+            (
+                w.saturating_sub(non_overlapping(2) + non_overlapping(3)),
+                h.saturating_sub(non_overlapping(0) + non_overlapping(1)),
+            )
         };
 
+        let ((shrink_w, shrink_h), x_spec, y_spec) = make_spec(plotting_area_size);
+
+        // Shrink the chart evenly on both sides, so that the plotting area stays centered
+        let (extra_dx, extra_dy) = ((shrink_w / 2) as i32, (shrink_h / 2) as i32);
+        if shrink_w > 0 || shrink_h > 0 {
+            drawing_area = drawing_area.margin(
+                extra_dy,
+                (shrink_h - shrink_h / 2) as i32,
+                extra_dx,
+                (shrink_w - shrink_w / 2) as i32,
+            );
+        }
+
         let (w, h) = drawing_area.dim_in_pixel();
 
         let mut actual_drawing_area_pos = [0, h as i32, 0, w as i32];
@@ -213,11 +937,11 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         const DIR: [(i16, i16); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
 
         for (idx, (dx, dy)) in (0..4).map(|idx| (idx, DIR[idx])) {
-            if self.overlap_plotting_area[idx] {
+            if overlap_plotting_area[idx] {
                 continue;
             }
 
-            let size = self.label_area_size[idx] as i32;
+            let size = label_area_size[idx] as i32;
 
             let split_point = if dx + dy < 0 { size } else { -size };
 
@@ -251,13 +975,13 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         // Initialize the label areas - since the label area might be overlapping
         // with the plotting area, in this case, we need handle them differently
         for (src_idx, dst_idx) in [1, 7, 3, 5].iter().zip(0..4) {
-            if !self.overlap_plotting_area[dst_idx] {
+            if !overlap_plotting_area[dst_idx] {
                 let (h, w) = split[*src_idx].as_ref().unwrap().dim_in_pixel();
                 if h > 0 && w > 0 {
                     std::mem::swap(&mut label_areas[dst_idx], &mut split[*src_idx]);
                 }
-            } else if self.label_area_size[dst_idx] != 0 {
-                let size = self.label_area_size[dst_idx] as i32;
+            } else if label_area_size[dst_idx] != 0 {
+                let size = label_area_size[dst_idx] as i32;
                 let (dw, dh) = drawing_area.dim_in_pixel();
                 let x0 = if DIR[dst_idx].0 > 0 {
                     dw as i32 - size
@@ -300,9 +1024,301 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            root_area_size: self.root_area.dim_in_pixel(),
+            colorbar_area,
+            legend_area,
+            theme: self.theme,
+            palette_idx: 0,
+            hit_regions: vec![],
+            clip: true,
+            clip_region: None,
+            zoomed: false,
+            drawing_area_pos: (
+                actual_drawing_area_pos[2] + title_dx + extra_dx + self.margin[2] as i32,
+                actual_drawing_area_pos[0] + title_dy + extra_dy + self.margin[0] as i32,
+            ),
+        })
+    }
+
+    /// Build the chart with a polar coordinate system. The function will returns a chart
+    /// context, where data series can be rendered on, with the coordinates in (θ, r). The
+    /// circle is centered in the plotting area, and the largest label area size is reserved
+    /// around it for the angular labels.
+    /// - `theta_spec`: The specification of the angle, which covers the full circle clockwise
+    ///   from 12 o'clock. For degrees, `(0.0..360.0).step(30.0)` places the radial lines at the
+    ///   multiples of 30 degrees
+    /// - `r_spec`: The specification of the radius, starting from the center
+    /// - Returns: A chart context
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (480, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .build_polar((0.0..360.0).step(30.0), 0.0..1.0)
+    ///     .unwrap();
+    /// chart.configure_polar_mesh().draw().unwrap();
+    /// chart
+    ///     .draw_series(LineSeries::new(
+    ///         (0..=360).map(|a| (f64::from(a), (f64::from(a) * 3.0).to_radians().cos().abs())),
+    ///         &RED,
+    ///     ))
+    ///     .unwrap();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn build_polar<T: AsRangedCoord, R: AsRangedCoord>(
+        &mut self,
+        theta_spec: T,
+        r_spec: R,
+    ) -> Result<
+        ChartContext<'a, DB, Polar<T::CoordDescType, R::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let mut drawing_area = DrawingArea::clone(self.root_area);
+
+        if *self.margin.iter().max().unwrap_or(&0) > 0 {
+            drawing_area = drawing_area.margin(
+                self.margin[0] as i32,
+                self.margin[1] as i32,
+                self.margin[2] as i32,
+                self.margin[3] as i32,
+            );
+        }
+
+        let (title_dx, title_dy) = {
+            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+            drawing_area = self.draw_caption(drawing_area)?;
+            let (current_dx, current_dy) = drawing_area.get_base_pixel();
+            (current_dx - origin_dx, current_dy - origin_dy)
+        };
+
+        let (x0, y0) = drawing_area.get_base_pixel();
+        let (w, h) = drawing_area.dim_in_pixel();
+        let label_space = self
+            .actual_label_area_size()
+            .0
+            .iter()
+            .cloned()
+            .max()
+            .unwrap_or(0);
+        let radius = (w.min(h) / 2).saturating_sub(label_space);
+        let center = (x0 + w as i32 / 2, y0 + h as i32 / 2);
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area
+                .apply_coord_spec(Polar::new(theta_spec, r_spec, center, radius)),
+            series_anno: vec![],
+            root_area_size: self.root_area.dim_in_pixel(),
+            colorbar_area: None,
+            legend_area: [None, None],
+            theme: self.theme,
+            palette_idx: 0,
+            hit_regions: vec![],
+            clip: true,
+            clip_region: None,
+            zoomed: false,
+            drawing_area_pos: (
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
+            ),
+        })
+    }
+
+    /// Build the chart with a geographic coordinate system. The function will returns a chart
+    /// context, where data series can be rendered on, with the coordinates in (longitude,
+    /// latitude) degrees. The map is centered in the plotting area and keeps the aspect ratio of
+    /// the projection, and the largest label area size is reserved around it.
+    /// - `projection`: The map projection
+    /// - `lon` and `lat`: The range of the longitudes and the latitudes in degrees
+    /// - Returns: A chart context
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_geo(Projection::Mercator, -10.0..30.0, 35.0..60.0)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(GeoSeries::new(
+    ///         vec![Geometry::Polygon(vec![vec![
+    ///             (2.5, 51.5), (6.0, 51.8), (6.2, 49.5), (2.5, 49.5), (2.5, 51.5),
+    ///         ]])],
+    ///         &BLACK,
+    ///     ).fill(&GREEN.mix(0.3)))
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(std::iter::once(Circle::new((4.9, 52.4), 3, RED.filled())))
+    ///     .unwrap();
+    /// ```
+    pub fn build_geo(
+        &mut self,
+        projection: Projection,
+        lon: Range<f64>,
+        lat: Range<f64>,
+    ) -> Result<ChartContext<'a, DB, GeoCoord>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut drawing_area = DrawingArea::clone(self.root_area);
+
+        if *self.margin.iter().max().unwrap_or(&0) > 0 {
+            drawing_area = drawing_area.margin(
+                self.margin[0] as i32,
+                self.margin[1] as i32,
+                self.margin[2] as i32,
+                self.margin[3] as i32,
+            );
+        }
+
+        let (title_dx, title_dy) = {
+            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+            drawing_area = self.draw_caption(drawing_area)?;
+            let (current_dx, current_dy) = drawing_area.get_base_pixel();
+            (current_dx - origin_dx, current_dy - origin_dy)
+        };
+
+        let (x0, y0) = drawing_area.get_base_pixel();
+        let (w, h) = drawing_area.dim_in_pixel();
+        let label_space = self
+            .actual_label_area_size()
+            .0
+            .iter()
+            .cloned()
+            .max()
+            .unwrap_or(0);
+        let width = w.saturating_sub(2 * label_space) as f64;
+        let height = h.saturating_sub(2 * label_space) as f64;
+
+        // The same scale is used for both directions, thus the map isn't distorted further
+        let low = projection.project((lon.start, lat.start));
+        let high = projection.project((lon.end, lat.end));
+        let (dx, dy) = ((high.0 - low.0).abs(), (high.1 - low.1).abs());
+        let scale = match (dx > 0.0, dy > 0.0) {
+            (true, true) => (width / dx).min(height / dy),
+            (true, false) => width / dx,
+            (false, true) => height / dy,
+            (false, false) => 0.0,
+        };
+        let (half_w, half_h) = (dx * scale / 2.0, dy * scale / 2.0);
+        let (cx, cy) = (x0 as f64 + w as f64 / 2.0, y0 as f64 + h as f64 / 2.0);
+        let pixel = |x: f64, y: f64| (x.round() as i32, y.round() as i32);
+        let pixels = (
+            pixel(cx - half_w, cy - half_h),
+            pixel(cx + half_w, cy + half_h),
+        );
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area
+                .apply_coord_spec(GeoCoord::new(projection, lon, lat, pixels)),
+            series_anno: vec![],
+            root_area_size: self.root_area.dim_in_pixel(),
+            colorbar_area: None,
+            legend_area: [None, None],
+            theme: self.theme,
+            palette_idx: 0,
+            hit_regions: vec![],
+            clip: true,
+            clip_region: None,
+            zoomed: false,
+            drawing_area_pos: (
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
+            ),
+        })
+    }
+
+    /// Build the chart with a ternary coordinate system. The function will returns a chart
+    /// context, where data series can be rendered on, with the coordinates in (a, b, c). The
+    /// triangle is equilateral and centered in the plotting area, and the largest label area
+    /// size is reserved around it for the labels.
+    /// - Returns: A chart context
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (480, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(40)
+    ///     .build_ternary()
+    ///     .unwrap();
+    /// chart
+    ///     .configure_ternary_mesh()
+    ///     .axis_names("SiO2", "CaO", "Al2O3")
+    ///     .draw()
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(
+    ///         vec![(0.6, 0.3, 0.1), (0.2, 0.5, 0.3), (0.1, 0.1, 0.8)]
+    ///             .into_iter()
+    ///             .map(|p| Circle::new(p, 4, BLUE.filled())),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn build_ternary(
+        &mut self,
+    ) -> Result<ChartContext<'a, DB, TernaryCoord>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut drawing_area = DrawingArea::clone(self.root_area);
+
+        if *self.margin.iter().max().unwrap_or(&0) > 0 {
+            drawing_area = drawing_area.margin(
+                self.margin[0] as i32,
+                self.margin[1] as i32,
+                self.margin[2] as i32,
+                self.margin[3] as i32,
+            );
+        }
+
+        let (title_dx, title_dy) = {
+            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+            drawing_area = self.draw_caption(drawing_area)?;
+            let (current_dx, current_dy) = drawing_area.get_base_pixel();
+            (current_dx - origin_dx, current_dy - origin_dy)
+        };
+
+        let (x0, y0) = drawing_area.get_base_pixel();
+        let (w, h) = drawing_area.dim_in_pixel();
+        let label_space = self
+            .actual_label_area_size()
+            .0
+            .iter()
+            .cloned()
+            .max()
+            .unwrap_or(0);
+        let width = w.saturating_sub(2 * label_space) as f64;
+        let height = h.saturating_sub(2 * label_space) as f64;
+        let side = width.min(height * 2.0 / 3f64.sqrt());
+        let (half_side, half_height) = (side / 2.0, side * 3f64.sqrt() / 4.0);
+        let (cx, cy) = (x0 as f64 + w as f64 / 2.0, y0 as f64 + h as f64 / 2.0);
+        let pixel = |x: f64, y: f64| (x.round() as i32, y.round() as i32);
+        let corners = [
+            pixel(cx, cy - half_height),
+            pixel(cx - half_side, cy + half_height),
+            pixel(cx + half_side, cy + half_height),
+        ];
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area.apply_coord_spec(TernaryCoord::new(corners)),
+            series_anno: vec![],
+            root_area_size: self.root_area.dim_in_pixel(),
+            colorbar_area: None,
+            legend_area: [None, None],
+            theme: self.theme,
+            palette_idx: 0,
+            hit_regions: vec![],
+            clip: true,
+            clip_region: None,
+            zoomed: false,
             drawing_area_pos: (
-                actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
-                actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
             ),
         })
     }
@@ -333,13 +1349,11 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
+        let (title_dx, title_dy) = {
             let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
+            drawing_area = self.draw_caption(drawing_area)?;
             let (current_dx, current_dy) = drawing_area.get_base_pixel();
             (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
         };
 
         let pixel_range = drawing_area.get_pixel_range();
@@ -354,6 +1368,15 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            root_area_size: self.root_area.dim_in_pixel(),
+            colorbar_area: None,
+            legend_area: [None, None],
+            theme: self.theme,
+            palette_idx: 0,
+            hit_regions: vec![],
+            clip: true,
+            clip_region: None,
+            zoomed: false,
             drawing_area_pos: (
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
@@ -392,6 +1415,34 @@ mod test {
         assert_eq!(chart.label_area_size[3], 200);
     }
 
+    #[test]
+    fn test_auto_label_areas() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area);
+        let (x, y) = (0.0..1.0, 0.0..1.0);
+
+        chart.auto_label_areas(&x, &y);
+        let (x_size, y_size) = (chart.label_area_size[1], chart.label_area_size[2]);
+        assert!(x_size > 10 && y_size > 10);
+
+        // The longer labels need a wider area
+        chart.auto_label_areas_with(&x, &y, |v| format!("{}", v), |v| format!("{:.6} units", v));
+        assert_eq!(chart.label_area_size[1], x_size);
+        assert!(chart.label_area_size[2] > y_size);
+
+        // The rotated X labels and the axis descriptions need a taller area
+        chart
+            .auto_label_style(
+                ("sans-serif", 12)
+                    .into_font()
+                    .transform(FontTransform::Rotate90),
+            )
+            .auto_axis_desc(true, false)
+            .auto_label_areas(&x, &y);
+        assert!(chart.label_area_size[1] > x_size);
+        assert!(chart.build_cartesian_2d(x, y).is_ok());
+    }
+
     #[test]
     fn test_margin_configure() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -428,4 +1479,124 @@ mod test {
         chart.caption("This is a test case", ("serif", 10));
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
+
+    #[test]
+    fn test_subtitle() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+
+        let plain = ChartBuilder::on(&drawing_area)
+            .caption("Title", ("serif", 10))
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+
+        let empty_subtitle = ChartBuilder::on(&drawing_area)
+            .caption("Title", ("serif", 10))
+            .subtitle("", ("serif", 8))
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+
+        let with_subtitle = ChartBuilder::on(&drawing_area)
+            .caption("Title", ("serif", 10))
+            .subtitle("Subtitle", ("serif", 8))
+            .caption_line(
+                vec![("Metric: ", ("serif", 8)), ("description", ("serif", 6))],
+                HPos::Left,
+            )
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+
+        let plain_h = plain.plotting_area().dim_in_pixel().1;
+        assert_eq!(plain_h, empty_subtitle.plotting_area().dim_in_pixel().1);
+        assert!(with_subtitle.plotting_area().dim_in_pixel().1 < plain_h);
+    }
+
+    #[test]
+    fn test_caption_placement() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let positions = Rc::new(RefCell::new(vec![]));
+        let collected = positions.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                collected.borrow_mut().push((text.to_string(), pos.1));
+            });
+        });
+        let title = TextStyle::from(("serif", 10).into_font());
+        let subtitle = TextStyle::from(("serif", 7).into_font());
+        let title_h = drawing_area.estimate_text_size("Title", &title).unwrap().1 as i32;
+        let subtitle_h = drawing_area
+            .estimate_text_size("Subtitle", &subtitle)
+            .unwrap()
+            .1 as i32;
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .caption_with_subtitle("Title", "Subtitle", ("serif", 10))
+            .caption_position(VPos::Bottom)
+            .caption_padding(3)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+
+        // The caption is below the chart with the subtitle under it
+        let (x, y) = chart.plotting_area().get_pixel_range();
+        assert_eq!((x.start, y.start), (0, 0));
+        assert_eq!(y.end, 200 - (title_h + 6) - (subtitle_h + 6));
+        assert_eq!(
+            *positions.borrow(),
+            vec![
+                ("Subtitle".to_string(), 200 - 3 - subtitle_h),
+                ("Title".to_string(), y.end + 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        use crate::coord::types::RangedCoordf64;
+
+        let pixels_per_unit =
+            |chart: &ChartContext<_, Cartesian2d<RangedCoordf64, RangedCoordf64>>| {
+                let (x0, y0) = chart.backend_coord(&(0.0, 0.0));
+                let (x1, y1) = chart.backend_coord(&(1.0, 1.0));
+                ((x1 - x0) as f64, (y0 - y1) as f64)
+            };
+
+        for &(w, h) in [(400, 300), (300, 500)].iter() {
+            let drawing_area = create_mocked_drawing_area(w, h, |_| {});
+
+            let chart = ChartBuilder::on(&drawing_area)
+                .margin(10)
+                .x_label_area_size(20)
+                .y_label_area_size(30)
+                .build_cartesian_2d_with_aspect(0.0..10.0, 0.0..10.0, Aspect::Equal)
+                .unwrap();
+            let (px, py) = pixels_per_unit(&chart);
+            assert!((px - py).abs() <= 1.0);
+            let (x, y) = (chart.x_range(), chart.y_range());
+            assert!((x.start + x.end - 10.0).abs() < 1e-9);
+            assert!((y.start + y.end - 10.0).abs() < 1e-9);
+            assert!(x.end - x.start >= 10.0 && y.end - y.start >= 10.0);
+
+            let chart = ChartBuilder::on(&drawing_area)
+                .aspect_fit(AspectFit::ShrinkArea)
+                .build_cartesian_2d_with_aspect(0.0..10.0, 0.0..5.0, Aspect::Ratio(2.0))
+                .unwrap();
+            let (px, py) = pixels_per_unit(&chart);
+            assert!((2.0 * px - py).abs() <= 2.0);
+            assert_eq!(chart.x_range(), 0.0..10.0);
+            assert_eq!(chart.y_range(), 0.0..5.0);
+        }
+
+        // The integer range is widened to -0.25..7.25, which is rounded outward
+        let drawing_area = create_mocked_drawing_area(640, 480, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d_with_aspect(0..10, 0..7, Aspect::Equal)
+            .unwrap();
+        assert_eq!(chart.x_range(), 0..10);
+        assert_eq!(chart.y_range(), -1..8);
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d_with_aspect(0..10, 7..0, Aspect::Equal)
+            .unwrap();
+        assert_eq!(chart.y_range(), 8..-1);
+    }
 }
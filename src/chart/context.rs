@@ -2,19 +2,36 @@ use std::borrow::Borrow;
 use std::ops::Range;
 
 use super::axes3d::Axes3dStyle;
-use super::{DualCoordChartContext, MeshStyle, SeriesAnno, SeriesLabelStyle};
+use super::hit_test::HitRegion;
+use super::overflow::{self, Overflow};
+use super::polar_mesh::PolarMeshStyle;
+use super::ternary_mesh::TernaryMeshStyle;
+use super::{DualCoordChartContext, MeshStyle, SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d, MeshLine};
+use crate::coord::polar::Polar;
 use crate::coord::ranged1d::{AsRangedCoord, KeyPointHint, Ranged, ValueFormatter};
 use crate::coord::ranged3d::{ProjectionMatrix, ProjectionMatrixBuilder};
+use crate::coord::ternary::TernaryCoord;
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::drawing::clip::ClipRect;
+use crate::drawing::{
+    BackendFeatures, BlendMode, ClipBackend, CompositeBackend, DrawingArea, DrawingAreaErrorKind,
+    Layer, LayerRecorder,
+};
 use crate::element::{
-    CoordMapper, Drawable, EmptyElement, PathElement, PointCollection, Polygon, Text,
+    Colorbar, CoordMapper, Drawable, EmptyElement, PathElement, PointCollection, Polygon, Text,
 };
 use crate::style::text_anchor::{HPos, Pos, VPos};
-use crate::style::{ShapeStyle, TextStyle};
+use crate::style::{
+    Color, ColorCycle, ColorMap, Palette, RGBColor, RichText, ShapeStyle, TextStyle, Theme,
+};
+#[cfg(feature = "area_series")]
+use crate::{
+    element::Rectangle,
+    series::{StackedAreaSeries, StackedLayer, StreamGraphSeries},
+};
 
 use plotters_backend::{BackendCoord, DrawingBackend, FontTransform};
 
@@ -31,6 +48,55 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(super) drawing_area: DrawingArea<DB, CT>,
     pub(super) series_anno: Vec<SeriesAnno<'a, DB>>,
     pub(super) drawing_area_pos: (i32, i32),
+    pub(super) root_area_size: (u32, u32),
+    pub(super) colorbar_area: Option<DrawingArea<DB, Shift>>,
+    pub(super) legend_area: [Option<DrawingArea<DB, Shift>>; 2], // [right, below]
+    pub(super) theme: Theme,
+    pub(super) palette_idx: usize,
+    pub(super) hit_regions: Vec<HitRegion>,
+    pub(super) clip: bool,
+    pub(super) clip_region: Option<ClipRect>,
+    // Whether the ranges were replaced to zoom or pan, then an axis that is narrower than one
+    // tick step is labeled at its ends
+    pub(super) zoomed: bool,
+}
+
+/// Draw the elements of the series onto the plotting area, which clips them to the rectangle,
+/// and return the pixel of the rightmost point of the last element
+fn draw_elements<DB, CT, B, E, R, S>(
+    area: &DrawingArea<DB, CT>,
+    clip: Option<ClipRect>,
+    series: S,
+) -> Result<Option<BackendCoord>, DrawingAreaErrorKind<DB::ErrorType>>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+    B: CoordMapper,
+    for<'b> &'b E: PointCollection<'b, CT::From, B>,
+    E: Drawable<ClipBackend<DB>, B>,
+    R: Borrow<E>,
+    S: IntoIterator<Item = R>,
+{
+    let mut series = series.into_iter().peekable();
+    let blend_mode = series.peek().map_or(BlendMode::Normal, |element| {
+        let element: &E = element.borrow();
+        element.blend_mode()
+    });
+    let area = area.clipped(clip, blend_mode);
+    let mut last = None;
+    for element in series {
+        area.draw(element.borrow())?;
+        last = Some(element);
+    }
+    area.end_blend()?;
+    Ok(last.and_then(|element| {
+        element
+            .borrow()
+            .point_iter()
+            .into_iter()
+            .map(|p| area.map_coordinate(p.borrow()))
+            .max_by_key(|p| p.0)
+    }))
 }
 
 impl<'a, DB, XT, YT, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
@@ -99,21 +165,35 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     //       of points reference with the same lifetime.
     //       However, this doesn't work if the coordinate doesn't live longer than the backend,
     //       this is unnecessarily strict
+    /// Draw the elements of the series, and return the pixel of the rightmost point of the last
+    /// element, which is where the inline series label goes
     pub(super) fn draw_series_impl<B, E, R, S>(
-        &mut self,
+        &self,
         series: S,
-    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    ) -> Result<Option<BackendCoord>, DrawingAreaErrorKind<DB::ErrorType>>
     where
         B: CoordMapper,
         for<'b> &'b E: PointCollection<'b, CT::From, B>,
-        E: Drawable<DB, B>,
+        E: Drawable<ClipBackend<DB>, B>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        for element in series {
-            self.drawing_area.draw(element.borrow())?;
+        draw_elements(&self.drawing_area, self.clip_rect(), series)
+    }
+
+    /// The rectangle that the elements of the series are clipped to
+    fn clip_rect(&self) -> Option<ClipRect> {
+        // The elements are clipped to the same bounds that the points used to be truncated to
+        let area = if self.clip {
+            let (x, y) = self.drawing_area.get_pixel_range();
+            Some(ClipRect::new((x.start, y.start), (x.end, y.end)))
+        } else {
+            None
+        };
+        match (area, self.clip_region) {
+            (Some(area), Some(region)) => Some(area.intersect(&region)),
+            (area, region) => area.or(region),
         }
-        Ok(())
     }
 
     pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
@@ -130,12 +210,384 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     where
         B: CoordMapper,
         for<'b> &'b E: PointCollection<'b, CT::From, B>,
-        E: Drawable<DB, B>,
+        E: Drawable<ClipBackend<DB>, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let end_point = self.draw_series_impl(series)?;
+        Ok(self.alloc_series_anno().set_end_point(end_point))
+    }
+
+    /// Get the chart context that draws onto a layer of the chart, which shares the areas and
+    /// the coordinate of the chart
+    pub(crate) fn on_layer(&self, layer: Layer) -> ChartContext<'a, LayerRecorder<DB>, CT>
+    where
+        CT: Clone,
+    {
+        let on_layer =
+            |area: &Option<DrawingArea<DB, Shift>>| area.as_ref().map(|area| area.on_layer(layer));
+        ChartContext {
+            x_label_area: [
+                on_layer(&self.x_label_area[0]),
+                on_layer(&self.x_label_area[1]),
+            ],
+            y_label_area: [
+                on_layer(&self.y_label_area[0]),
+                on_layer(&self.y_label_area[1]),
+            ],
+            drawing_area: self.drawing_area.on_layer(layer),
+            series_anno: vec![],
+            drawing_area_pos: self.drawing_area_pos,
+            root_area_size: self.root_area_size,
+            colorbar_area: on_layer(&self.colorbar_area),
+            legend_area: [
+                on_layer(&self.legend_area[0]),
+                on_layer(&self.legend_area[1]),
+            ],
+            theme: self.theme,
+            palette_idx: self.palette_idx,
+            hit_regions: vec![],
+            clip: self.clip,
+            clip_region: self.clip_region,
+            zoomed: self.zoomed,
+        }
+    }
+
+    /// Draw a data series onto a layer, so that it's drawn on the top of the layers below
+    /// regardless of the order of the drawing calls, see [Layer](../drawing/enum.Layer.html)
+    /// for details. The series is labelled in the legend in the same way as
+    /// [draw_series](struct.ChartContext.html#method.draw_series).
+    /// - `layer`: The layer to draw on
+    /// - `series`: The data series to draw
+    /// - **returns**: The series annotation
+    pub fn draw_series_on_layer<B, E, R, S>(
+        &mut self,
+        layer: Layer,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        CT: Clone,
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<ClipBackend<LayerRecorder<DB>>, B>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        self.draw_series_impl(series)?;
-        Ok(self.alloc_series_anno())
+        let end_point = self.on_layer(layer).draw_series_impl(series)?;
+        Ok(self.alloc_series_anno().set_end_point(end_point))
+    }
+
+    /// Draw a data series as a group, which is composited as a whole with the opacity and the
+    /// blend mode, see [DrawingArea::draw_composited](../drawing/struct.DrawingArea.html#method.draw_composited).
+    /// With the additive blend mode, the overlapping faint points of a dense scatter plot
+    /// accumulate the density.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 320 * 240 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (320, 240)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+    ///     .unwrap();
+    /// let points = (0..1000).map(|i| ((i as f64 * 0.618).fract(), (i as f64 * 0.382).fract()));
+    /// chart
+    ///     .draw_series_composited(
+    ///         1.0,
+    ///         BlendMode::Additive,
+    ///         points.map(|p| Circle::new(p, 3, BLUE.mix(0.2).filled())),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `opacity`: The opacity of the series
+    /// - `blend_mode`: The way the elements of the series are composited with each other
+    /// - `series`: The data series to draw
+    /// - **returns**: The series annotation
+    pub fn draw_series_composited<B, E, R, S>(
+        &mut self,
+        opacity: f64,
+        blend_mode: BlendMode,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: BackendFeatures,
+        CT: Clone,
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<ClipBackend<CompositeBackend<DB>>, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let clip = self.clip_rect();
+        let mut end_point = None;
+        self.drawing_area
+            .draw_composited(opacity, blend_mode, |area| {
+                end_point = draw_elements(area, clip, series)?;
+                Ok(())
+            })?;
+        Ok(self.alloc_series_anno().set_end_point(end_point))
+    }
+
+    /// Set whether the elements of the series drawn afterwards are clipped to the plotting area,
+    /// which is the default. The series are drawn onto a
+    /// [ClipBackend](../drawing/struct.ClipBackend.html), which cuts everything the elements
+    /// draw at the border of the plotting area, thus the data out of the range doesn't spill
+    /// over the axes and the neighboring charts. Only the circle markers of the points at the
+    /// edge of the range are drawn whole. Turn it off to draw the annotations outside of the
+    /// plotting area.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+    ///     .unwrap();
+    /// // The line is cut where it leaves the chart
+    /// chart
+    ///     .draw_series(LineSeries::new(vec![(0.0, 0.0), (2.0, 2.0)], &RED))
+    ///     .unwrap();
+    /// // The marker across the top of the chart is only drawn in full when the clipping is off
+    /// chart
+    ///     .clip(false)
+    ///     .draw_series(std::iter::once(Circle::new((0.5, 1.05), 5, BLUE.filled())))
+    ///     .unwrap();
+    /// ```
+    pub fn clip(&mut self, clip: bool) -> &mut Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Stop clipping the series drawn afterwards to the region set with
+    /// [clip_region](struct.ChartContext.html#method.clip_region)
+    pub fn clear_clip_region(&mut self) -> &mut Self {
+        self.clip_region = None;
+        self
+    }
+
+    /// Get the theme of the chart, see [ChartBuilder::theme](struct.ChartBuilder.html#method.theme)
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Create a color cycle that hands out the colors of the palette in order, see
+    /// [ChartContext::draw_series_auto](struct.ChartContext.html#method.draw_series_auto)
+    /// - `palette`: The palette to pick colors from
+    pub fn color_cycle<P: Palette>(&self, palette: P) -> ColorCycle<P> {
+        ColorCycle::new(palette)
+    }
+
+    /// Draw a data series with the next color of the color cycle, and label the series with a
+    /// legend in the same color.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0..10, 0..30)
+    ///     .unwrap();
+    /// let mut colors = chart.color_cycle(PaletteCategory10);
+    /// for k in 1..4 {
+    ///     chart
+    ///         .draw_series_auto(
+    ///             |style| LineSeries::new((0..10).map(|x| (x, k * x)), style),
+    ///             &mut colors,
+    ///             format!("y = {}x", k),
+    ///         )
+    ///         .unwrap();
+    /// }
+    /// ```
+    ///
+    /// - `series`: The function that creates the series with the given style
+    /// - `colors`: The color cycle that provides the style
+    /// - `label`: The label of the series
+    /// - **returns**: The series annotation, which can be used to override the legend
+    pub fn draw_series_auto<B, E, R, S, F, P, L>(
+        &mut self,
+        series: F,
+        colors: &mut ColorCycle<P>,
+        label: L,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<ClipBackend<DB>, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        F: FnOnce(ShapeStyle) -> S,
+        P: Palette,
+        L: Into<String>,
+    {
+        let style = colors.next_style();
+        Ok(self.draw_series_with_style(series, style)?.label(label))
+    }
+
+    /// Get the next color of the palette of the chart theme, the colors wrap around after the
+    /// last color of the palette
+    pub fn next_palette_color(&mut self) -> RGBColor {
+        let color = self.theme.palette_color(self.palette_idx);
+        self.palette_idx += 1;
+        color
+    }
+
+    /// Draw a data series with the next color of the palette of the chart, which is set by
+    /// [ChartBuilder::palette](struct.ChartBuilder.html#method.palette) or the chart theme. The
+    /// series gets a legend in the same color, and the color can be queried from the returned
+    /// series annotation.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .palette(PaletteColorblind)
+    ///     .build_cartesian_2d(0..10, 0..30)
+    ///     .unwrap();
+    /// for k in 1..4 {
+    ///     let anno = chart
+    ///         .draw_series_with_palette(|style| LineSeries::new((0..10).map(|x| (x, k * x)), style))
+    ///         .unwrap()
+    ///         .label(format!("y = {}x", k));
+    ///     assert_eq!(anno.color(), Some(PaletteColorblind::pick(k as usize - 1).to_rgba()));
+    /// }
+    /// ```
+    ///
+    /// - `series`: The function that creates the series with the given style
+    /// - **returns**: The series annotation, which can be used to label the series or to
+    ///   override the legend
+    pub fn draw_series_with_palette<B, E, R, S, F>(
+        &mut self,
+        series: F,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<ClipBackend<DB>, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        F: FnOnce(ShapeStyle) -> S,
+    {
+        let style = self.next_palette_color().into();
+        self.draw_series_with_style(series, style)
+    }
+
+    /// Draw the series with the style, and record the color of the style for the legend
+    fn draw_series_with_style<B, E, R, S, F>(
+        &mut self,
+        series: F,
+        style: ShapeStyle,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<ClipBackend<DB>, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        F: FnOnce(ShapeStyle) -> S,
+    {
+        let legend_style = style.clone();
+        Ok(self
+            .draw_series(series(style))?
+            .set_color(legend_style.color)
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], legend_style.clone())
+            }))
+    }
+
+    /// Draw the layers of a stacked area series, and label each layer with a legend in the color
+    /// of the layer
+    /// - `series`: The stacked area series
+    #[cfg(feature = "area_series")]
+    pub fn draw_stacked_areas<P: Palette>(
+        &mut self,
+        mut series: StackedAreaSeries<P>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        CT: CoordTranslate<From = (f64, f64)>,
+    {
+        self.draw_layers(series.build_layers())
+    }
+
+    /// Draw the layers of a stream graph, and label each layer with a legend in the color of the
+    /// layer
+    /// - `series`: The stream graph
+    #[cfg(feature = "area_series")]
+    pub fn draw_stream_graph<P: Palette>(
+        &mut self,
+        mut series: StreamGraphSeries<P>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        CT: CoordTranslate<From = (f64, f64)>,
+    {
+        self.draw_layers(series.build_layers())
+    }
+
+    #[cfg(feature = "area_series")]
+    fn draw_layers(
+        &mut self,
+        layers: Vec<StackedLayer>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        CT: CoordTranslate<From = (f64, f64)>,
+    {
+        for (label, style, outline) in layers {
+            let legend_style = style.clone();
+            self.draw_series(std::iter::once(Polygon::new(outline, style)))?
+                .label(label)
+                .legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 20, y + 5)], legend_style.clone())
+                });
+        }
+        Ok(())
+    }
+
+    /// Draw a colorbar in the area reserved by
+    /// [ChartBuilder::colorbar_area_size](struct.ChartBuilder.html#method.colorbar_area_size).
+    /// The colorbar is drawn vertically and spans the full height of the plotting area.
+    /// - `colorbar`: The colorbar to draw
+    /// - **returns** The result, which is a layout error if no colorbar area has been reserved
+    pub fn draw_colorbar<M: ColorMap>(
+        &self,
+        colorbar: Colorbar<M>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = match self.colorbar_area {
+            Some(ref area) => area,
+            None => return Err(DrawingAreaErrorKind::LayoutError),
+        };
+        let (_, plot_y) = self.drawing_area.get_base_pixel();
+        let (_, area_y) = area.get_base_pixel();
+        let (_, plot_h) = self.drawing_area.dim_in_pixel();
+
+        let mut colorbar = colorbar.with_length(plot_h);
+        colorbar.move_to((10, plot_y - area_y));
+        area.draw(&colorbar)
+    }
+
+    /// Draw a colorbar inside the plotting area
+    /// - `colorbar`: The colorbar to draw
+    /// - `pos`: Where the colorbar is placed in the plotting area
+    /// - **returns** The result of drawing
+    pub fn draw_colorbar_inside<M: ColorMap>(
+        &self,
+        mut colorbar: Colorbar<M>,
+        pos: SeriesLabelPosition,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (w, h) = colorbar.estimate_dimension();
+        let (x, y) = pos.layout_label_area((w as i32, h as i32), self.drawing_area.dim_in_pixel());
+        // The labels at both ends stick out of the strip by half of their size
+        let (label_w, label_h) = colorbar.label_extent();
+        let (x, y) = match pos {
+            SeriesLabelPosition::Coordinate(..) => (x, y),
+            _ if colorbar.is_horizontal() => (x + label_w as i32 / 2, y),
+            _ => (x, y + label_h as i32 / 2),
+        };
+        colorbar.move_to((x, y));
+        self.drawing_area.strip_coord_spec().draw(&colorbar)
     }
 }
 
@@ -150,6 +602,104 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         self.drawing_area.get_y_range()
     }
 
+    /// Clip the elements of the series drawn afterwards to a region of the data, such as the
+    /// part of the chart after today for a forecast line that shares the chart with the
+    /// history. The region is a rectangle given by the ranges of the axes, it's combined with
+    /// the clipping to the plotting area, see [clip](#method.clip), and it's kept until
+    /// [clear_clip_region](#method.clear_clip_region) or until the range of an axis is replaced.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    ///     .unwrap();
+    /// let data: Vec<_> = (0..=10).map(|x| (x as f64, x as f64 * 0.8)).collect();
+    /// chart
+    ///     .draw_series(LineSeries::new(data.clone(), &BLACK))
+    ///     .unwrap();
+    /// // Only the part after 6.0 is drawn as the forecast
+    /// let y_range = chart.y_range();
+    /// chart
+    ///     .clip_region(6.0..10.0, y_range)
+    ///     .draw_series(LineSeries::new(data, ShapeStyle::from(&RED).stroke_width(3)))
+    ///     .unwrap();
+    /// chart.clear_clip_region();
+    /// ```
+    ///
+    /// - `x`: The range of the X axis that the elements are clipped to
+    /// - `y`: The range of the Y axis that the elements are clipped to
+    pub fn clip_region(&mut self, x: Range<X::ValueType>, y: Range<Y::ValueType>) -> &mut Self {
+        let a = self.drawing_area.map_coordinate(&(x.start, y.start));
+        let b = self.drawing_area.map_coordinate(&(x.end, y.end));
+        self.clip_region = Some(ClipRect::new(
+            (a.0.min(b.0), a.1.min(b.1)),
+            (a.0.max(b.0), a.1.max(b.1)),
+        ));
+        self
+    }
+
+    /// Replace the range of the X axis, while the pixel layout of the chart is kept unchanged.
+    /// This is typically used to zoom or pan a chart restored from a
+    /// [ChartState](struct.ChartState.html).
+    /// - `x_spec`: The new specification of X axis
+    /// - **returns**: The chart context with the new X axis
+    pub fn set_x_range<SX: AsRangedCoord>(
+        self,
+        x_spec: SX,
+    ) -> ChartContext<'a, DB, Cartesian2d<SX::CoordDescType, Y>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (_, y_spec, pixel_range) = self.drawing_area.into_coord_spec().into_parts();
+        ChartContext {
+            x_label_area: self.x_label_area,
+            y_label_area: self.y_label_area,
+            drawing_area: area.apply_coord_spec(Cartesian2d::new(x_spec, y_spec, pixel_range)),
+            series_anno: self.series_anno,
+            drawing_area_pos: self.drawing_area_pos,
+            root_area_size: self.root_area_size,
+            colorbar_area: self.colorbar_area,
+            legend_area: self.legend_area,
+            theme: self.theme,
+            palette_idx: self.palette_idx,
+            hit_regions: self.hit_regions,
+            clip: self.clip,
+            // The region is in the pixels of the previous ranges
+            clip_region: None,
+            zoomed: true,
+        }
+    }
+
+    /// Replace the range of the Y axis, while the pixel layout of the chart is kept unchanged.
+    /// See [ChartContext::set_x_range](struct.ChartContext.html#method.set_x_range) for details.
+    /// - `y_spec`: The new specification of Y axis
+    /// - **returns**: The chart context with the new Y axis
+    pub fn set_y_range<SY: AsRangedCoord>(
+        self,
+        y_spec: SY,
+    ) -> ChartContext<'a, DB, Cartesian2d<X, SY::CoordDescType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x_spec, _, pixel_range) = self.drawing_area.into_coord_spec().into_parts();
+        ChartContext {
+            x_label_area: self.x_label_area,
+            y_label_area: self.y_label_area,
+            drawing_area: area.apply_coord_spec(Cartesian2d::new(x_spec, y_spec, pixel_range)),
+            series_anno: self.series_anno,
+            drawing_area_pos: self.drawing_area_pos,
+            root_area_size: self.root_area_size,
+            colorbar_area: self.colorbar_area,
+            legend_area: self.legend_area,
+            theme: self.theme,
+            palette_idx: self.palette_idx,
+            hit_regions: self.hit_regions,
+            clip: self.clip,
+            // The region is in the pixels of the previous ranges
+            clip_region: None,
+            zoomed: true,
+        }
+    }
+
     /// Maps the coordinate to the backend coordinate. This is typically used
     /// with an interactive chart.
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
@@ -157,32 +707,52 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
     }
 
     /// The actual function that draws the mesh lines.
-    /// It also returns the label that suppose to be there.
+    /// It also returns the labels that suppose to be there, the last two lists are the labels
+    /// for the mirrored axes on the top and the right side.
     #[allow(clippy::type_complexity)]
-    fn draw_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+    fn draw_mesh_lines<FmtLabel, FmtMirrorLabel>(
         &mut self,
-        (r, c): (YH, XH),
+        (r, c): (Vec<Y::ValueType>, Vec<X::ValueType>),
         (x_mesh, y_mesh): (bool, bool),
         mesh_line_style: &ShapeStyle,
         mut fmt_label: FmtLabel,
-    ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
+        mut fmt_mirror_label: FmtMirrorLabel,
+    ) -> Result<
+        (
+            Vec<(i32, String)>,
+            Vec<(i32, String)>,
+            Vec<(i32, String)>,
+            Vec<(i32, String)>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
+        FmtMirrorLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
     {
+        let coord = self.drawing_area.as_coord_spec();
+        let (x_spec, y_spec) = (coord.x_spec(), coord.y_spec());
         let mut x_labels = vec![];
         let mut y_labels = vec![];
-        self.drawing_area.draw_mesh(
+        let mut top_labels = vec![];
+        let mut right_labels = vec![];
+        self.drawing_area.draw_mesh_at(
             |b, l| {
                 let draw;
                 match l {
                     MeshLine::XMesh((x, _), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        if let Some(label_text) = fmt_label(x_spec, y_spec, &l) {
+                            let mirror_text = fmt_mirror_label(&l);
+                            top_labels.push((x, mirror_text.unwrap_or_else(|| label_text.clone())));
                             x_labels.push((x, label_text));
                         }
                         draw = x_mesh;
                     }
                     MeshLine::YMesh((_, y), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        if let Some(label_text) = fmt_label(x_spec, y_spec, &l) {
+                            let mirror_text = fmt_mirror_label(&l);
+                            right_labels
+                                .push((y, mirror_text.unwrap_or_else(|| label_text.clone())));
                             y_labels.push((y, label_text));
                         }
                         draw = y_mesh;
@@ -197,7 +767,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             r,
             c,
         )?;
-        Ok((x_labels, y_labels))
+        Ok((x_labels, y_labels, top_labels, right_labels))
     }
 
     fn draw_axis(
@@ -219,12 +789,19 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         /* At this point, the coordinate system tells us the pixel range
          * after the translation.
          * However, we need to use the logic coordinate system for drawing. */
+        let mut breaks = if orientation.0 == 0 {
+            self.drawing_area.get_x_axis_breaks()
+        } else {
+            self.drawing_area.get_y_axis_breaks()
+        };
         if orientation.0 == 0 {
             axis_range.start -= x0;
             axis_range.end -= x0;
+            breaks.iter_mut().for_each(|p| *p -= x0);
         } else {
             axis_range.start -= y0;
             axis_range.end -= y0;
+            breaks.iter_mut().for_each(|p| *p -= y0);
         }
 
         if let Some(axis_style) = axis_style {
@@ -263,11 +840,262 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 vec![(x0, y0), (x1, y1)],
                 axis_style.clone(),
             ))?;
+
+            /* The break marker is a pair of slashes across the axis line, and the line is
+             * erased between them */
+            let (gap, half) = (3, 5);
+            for p in breaks {
+                let (along, across) = if orientation.0 == 0 {
+                    ((1, 0), (0, 1))
+                } else {
+                    ((0, 1), (1, 0))
+                };
+                let (cx, cy) = if orientation.0 == 0 { (p, y0) } else { (x0, p) };
+                let at = |a: i32, b: i32| {
+                    (
+                        cx + along.0 * a + across.0 * b,
+                        cy + along.1 * a + across.1 * b,
+                    )
+                };
+                area.draw(&Polygon::new(
+                    vec![
+                        at(-gap, -half),
+                        at(gap, -half),
+                        at(gap, half),
+                        at(-gap, half),
+                    ],
+                    self.theme.background.filled(),
+                ))?;
+                for side in [-gap, gap].iter() {
+                    area.draw(&PathElement::new(
+                        vec![at(side - 2, half), at(side + 2, -half)],
+                        axis_style.clone(),
+                    ))?;
+                }
+            }
         }
 
         Ok(axis_range)
     }
 
+    /// Draw a tick mark on the axis of the label area
+    /// - `pos`: The position of the tick mark along the axis, relative to the label area
+    fn draw_tick_mark(
+        area: &DrawingArea<DB, Shift>,
+        style: &ShapeStyle,
+        orientation: (i16, i16),
+        pos: i32,
+        tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if tick_size == 0 {
+            return Ok(());
+        }
+        let (tw, th) = area.dim_in_pixel();
+        let xmax = tw as i32 - 1;
+        let ymax = th as i32 - 1;
+        let (kx0, ky0, kx1, ky1) = if tick_size > 0 {
+            match orientation {
+                (dx, dy) if dx > 0 && dy == 0 => (0, pos, tick_size, pos),
+                (dx, dy) if dx < 0 && dy == 0 => (xmax - tick_size, pos, xmax, pos),
+                (dx, dy) if dx == 0 && dy > 0 => (pos, 0, pos, tick_size),
+                (dx, dy) if dx == 0 && dy < 0 => (pos, ymax - tick_size, pos, ymax),
+                _ => panic!("Bug: Invalid orientation specification"),
+            }
+        } else {
+            match orientation {
+                (dx, dy) if dx > 0 && dy == 0 => (xmax, pos, xmax + tick_size, pos),
+                (dx, dy) if dx < 0 && dy == 0 => (0, pos, -tick_size, pos),
+                (dx, dy) if dx == 0 && dy > 0 => (pos, ymax, pos, ymax + tick_size),
+                (dx, dy) if dx == 0 && dy < 0 => (pos, 0, pos, -tick_size),
+                _ => panic!("Bug: Invalid orientation specification"),
+            }
+        };
+        let line = PathElement::new(vec![(kx0, ky0), (kx1, ky1)], style.clone());
+        area.draw(&line)
+    }
+
+    /// Draw the tick marks without labels at the key points of the hints, which are the minor
+    /// ticks between the labeled ones
+    pub(super) fn draw_minor_ticks(
+        &self,
+        (r, c): (Vec<Y::ValueType>, Vec<X::ValueType>),
+        axis_style: &ShapeStyle,
+        x_tick_size: [i32; 2],
+        y_tick_size: [i32; 2],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut x_ticks = vec![];
+        let mut y_ticks = vec![];
+        self.drawing_area.draw_mesh_at(
+            |_, l| {
+                match l {
+                    MeshLine::XMesh((x, _), _, _) => x_ticks.push(x),
+                    MeshLine::YMesh((_, y), _, _) => y_ticks.push(y),
+                }
+                Ok(())
+            },
+            r,
+            c,
+        )?;
+
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let x_range = self.drawing_area.get_x_axis_pixel_range();
+        let y_range = self.drawing_area.get_y_axis_pixel_range();
+        let visible = |p: i32, range: &Range<i32>| {
+            range.start.min(range.end) <= p && p <= range.start.max(range.end)
+        };
+        for idx in 0..2 {
+            if let Some(area) = self.x_label_area[idx].as_ref() {
+                for &x in x_ticks.iter().filter(|x| visible(**x, &x_range)) {
+                    let orientation = (0, -1 + idx as i16 * 2);
+                    Self::draw_tick_mark(area, axis_style, orientation, x - x0, x_tick_size[idx])?;
+                }
+            }
+            if let Some(area) = self.y_label_area[idx].as_ref() {
+                for &y in y_ticks.iter().filter(|y| visible(**y, &y_range)) {
+                    let orientation = (-1 + idx as i16 * 2, 0);
+                    Self::draw_tick_mark(area, axis_style, orientation, y - y0, y_tick_size[idx])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the period labels of the X key points, which are only kept where the period changes
+    /// - `points`: The key points of the X axis, in the ascending order
+    /// - `fmt`: The formatter of the period of a key point
+    /// - **returns**: The pixel positions and the texts of the period labels
+    pub(super) fn period_labels(
+        &self,
+        points: &[X::ValueType],
+        fmt: &dyn Fn(&X::ValueType) -> String,
+    ) -> Vec<(i32, String)> {
+        let x_range = self.drawing_area.get_x_axis_pixel_range();
+        let x_spec = self.as_coord_spec().x_spec();
+        let (low, high) = (
+            x_range.start.min(x_range.end),
+            x_range.start.max(x_range.end),
+        );
+        let mut labels: Vec<(i32, String)> = vec![];
+        for point in points {
+            let x = x_spec.map(point, (x_range.start, x_range.end));
+            if x < low || high < x {
+                continue;
+            }
+            let text = fmt(point);
+            if labels.last().map(|(_, last)| *last != text).unwrap_or(true) {
+                labels.push((x, text));
+            }
+        }
+        labels
+    }
+
+    /// Draw the period labels on the second row of the X labels at the bottom, where each label
+    /// starts at the key point that the period changes
+    pub(super) fn draw_period_labels(
+        &self,
+        labels: &[(i32, String)],
+        label_style: &TextStyle,
+        label_offset: i32,
+        tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = match self.x_label_area[1].as_ref() {
+            Some(area) => area,
+            None => return Ok(()),
+        };
+        let (x0, _) = self.drawing_area.get_base_pixel();
+        let (_, th) = area.dim_in_pixel();
+        let label_dist = tick_size.abs() * 2;
+        let mut row_height = 0;
+        for (_, text) in labels {
+            let (_, h) = area.estimate_text_size(text, label_style)?;
+            row_height = row_height.max(h as i32);
+        }
+        /* The second row is a quarter of the row height away from the first row */
+        let row_dist = label_dist + row_height + row_height / 4;
+        let (y, v_pos) = if tick_size >= 0 {
+            (row_dist, VPos::Top)
+        } else {
+            (th as i32 - row_dist, VPos::Bottom)
+        };
+        let style = label_style.pos(Pos::new(HPos::Left, v_pos));
+        for (x, text) in labels {
+            area.draw_text(text, &style, (*x - x0 + label_offset, y))?;
+        }
+        Ok(())
+    }
+
+    /// Apply the overflow policy to the labels of an axis, which returns whether each label is
+    /// drawn, the lines of the labels and the style to draw them with
+    fn fit_labels<'s>(
+        &self,
+        labels: &[(i32, String)],
+        style: &TextStyle<'s>,
+        policy: Overflow,
+        horizontal: bool,
+    ) -> (Vec<bool>, Vec<Vec<String>>, TextStyle<'s>) {
+        let size = |text: &str| {
+            self.drawing_area
+                .estimate_text_size(text, style)
+                .map(|(w, h)| (w as i32, h as i32))
+                .unwrap_or((0, 0))
+        };
+        let positions: Vec<_> = labels.iter().map(|(p, _)| *p).collect();
+        let mut lines: Vec<_> = labels.iter().map(|(_, t)| vec![t.clone()]).collect();
+        let sizes: Vec<_> = labels.iter().map(|(_, t)| size(t)).collect();
+        let mut extents: Vec<_> = sizes
+            .iter()
+            .map(|&(w, h)| if horizontal { w } else { h })
+            .collect();
+        if !overflow::is_overflowing(&positions, &extents) {
+            return (vec![true; labels.len()], lines, style.clone());
+        }
+
+        let mut style = style.clone();
+        if horizontal {
+            match policy {
+                Overflow::Thin => {}
+                Overflow::Rotate(degrees) => {
+                    style = style.rotate(degrees);
+                    let (sin, cos) = degrees.to_radians().sin_cos();
+                    let (sin, cos) = (sin.abs(), cos.abs());
+                    /* The rotated labels are parallel, so they are apart as long as the space
+                     * between them across the text is more than the text height */
+                    extents = sizes
+                        .iter()
+                        .map(|&(w, h)| {
+                            let (w, h) = (f64::from(w), f64::from(h));
+                            let span = w * cos + h * sin;
+                            if sin > 1e-6 {
+                                span.min(h / sin).ceil() as i32
+                            } else {
+                                span.ceil() as i32
+                            }
+                        })
+                        .collect();
+                }
+                Overflow::Wrap => {
+                    let space = positions
+                        .windows(2)
+                        .map(|pair| (pair[1] - pair[0]).abs())
+                        .min()
+                        .unwrap_or(0);
+                    for (idx, (_, text)) in labels.iter().enumerate() {
+                        lines[idx] =
+                            overflow::wrap_label(text, space - overflow::LABEL_GAP, |line| {
+                                size(line).0
+                            });
+                        extents[idx] = lines[idx]
+                            .iter()
+                            .map(|line| size(line).0)
+                            .max()
+                            .unwrap_or(0);
+                    }
+                }
+            }
+        }
+        (overflow::thin_labels(&positions, &extents), lines, style)
+    }
+
     // TODO: consider make this function less complicated
     #[allow(clippy::too_many_arguments)]
     #[allow(clippy::cognitive_complexity)]
@@ -279,8 +1107,9 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         label_style: &TextStyle,
         label_offset: i32,
         orientation: (i16, i16),
-        axis_desc: Option<(&str, &TextStyle)>,
+        axis_desc: Option<(&RichText, &TextStyle)>,
         tick_size: i32,
+        overflow: Option<Overflow>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
@@ -288,6 +1117,16 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             return Ok(());
         };
 
+        let (shown, label_lines, fitted_style) = match overflow {
+            Some(overflow) => self.fit_labels(labels, label_style, overflow, orientation.0 == 0),
+            None => (
+                vec![true; labels.len()],
+                labels.iter().map(|(_, t)| vec![t.clone()]).collect(),
+                label_style.clone(),
+            ),
+        };
+        let label_style = &fitted_style;
+
         let (x0, y0) = self.drawing_area.get_base_pixel();
         let (tw, th) = area.dim_in_pixel();
 
@@ -327,7 +1166,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let right_align_width = (min_width * 2).min(max_width);
 
         /* Then we need to draw the tick mark and the label */
-        for ((p, t), w) in labels.iter().zip(label_width.into_iter()) {
+        for (idx, ((p, _), w)) in labels.iter().zip(label_width).enumerate() {
             /* Make sure we are actually in the visible range */
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
 
@@ -384,6 +1223,21 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 }
             };
 
+            /* The rotated labels of the X axis are anchored by the end that is closest to the
+             * axis, so that they extend away from the axis */
+            let sin = label_style.font.get_rotation().to_radians().sin();
+            let (h_pos, v_pos) = match (orientation.0, v_pos) {
+                (0, VPos::Top) if sin.abs() > 1e-6 => (
+                    if sin > 0.0 { HPos::Left } else { HPos::Right },
+                    VPos::Center,
+                ),
+                (0, VPos::Bottom) if sin.abs() > 1e-6 => (
+                    if sin > 0.0 { HPos::Right } else { HPos::Left },
+                    VPos::Center,
+                ),
+                _ => (h_pos, v_pos),
+            };
+
             let (text_x, text_y) = if orientation.0 == 0 {
                 (cx + label_offset, cy)
             } else {
@@ -391,40 +1245,28 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             };
 
             let label_style = &label_style.pos(Pos::new(h_pos, v_pos));
-            area.draw_text(&t, label_style, (text_x, text_y))?;
-
-            if tick_size != 0 {
-                if let Some(style) = axis_style {
-                    let xmax = tw as i32 - 1;
-                    let ymax = th as i32 - 1;
-                    let (kx0, ky0, kx1, ky1) = if tick_size > 0 {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => (0, *p - y0, tick_size, *p - y0),
-                            (dx, dy) if dx < 0 && dy == 0 => {
-                                (xmax - tick_size, *p - y0, xmax, *p - y0)
-                            }
-                            (dx, dy) if dx == 0 && dy > 0 => (*p - x0, 0, *p - x0, tick_size),
-                            (dx, dy) if dx == 0 && dy < 0 => {
-                                (*p - x0, ymax - tick_size, *p - x0, ymax)
-                            }
-                            _ => panic!("Bug: Invalid orientation specification"),
-                        }
-                    } else {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => {
-                                (xmax, *p - y0, xmax + tick_size, *p - y0)
-                            }
-                            (dx, dy) if dx < 0 && dy == 0 => (0, *p - y0, -tick_size, *p - y0),
-                            (dx, dy) if dx == 0 && dy > 0 => {
-                                (*p - x0, ymax, *p - x0, ymax + tick_size)
-                            }
-                            (dx, dy) if dx == 0 && dy < 0 => (*p - x0, 0, *p - x0, -tick_size),
-                            _ => panic!("Bug: Invalid orientation specification"),
-                        }
-                    };
-                    let line = PathElement::new(vec![(kx0, ky0), (kx1, ky1)], style.clone());
-                    area.draw(&line)?;
+            if shown[idx] {
+                let lines = &label_lines[idx];
+                let mut line_height = 0;
+                if lines.len() > 1 {
+                    for line in lines {
+                        let (_, h) = area.estimate_text_size(line, label_style)?;
+                        line_height = line_height.max(h as i32);
+                    }
                 }
+                /* The wrapped lines grow away from the axis */
+                let first = match v_pos {
+                    VPos::Bottom => text_y - line_height * (lines.len() as i32 - 1),
+                    _ => text_y,
+                };
+                for (row, line) in lines.iter().enumerate() {
+                    let y = first + line_height * row as i32;
+                    area.draw_text(line, label_style, (text_x, y))?;
+                }
+            }
+
+            if let Some(style) = axis_style {
+                Self::draw_tick_mark(area, style, orientation, rp, tick_size)?;
             }
         }
 
@@ -449,21 +1291,26 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 _ => panic!("Bug: Invalid orientation specification"),
             };
 
-            let actual_style = &actual_style.pos(Pos::new(h_pos, v_pos));
-            area.draw_text(&text, &actual_style, (x0 as i32, y0 as i32))?;
+            let actual_style = actual_style.pos(Pos::new(h_pos, v_pos));
+            area.draw(&Text::rich(
+                text.clone(),
+                (x0 as i32, y0 as i32),
+                actual_style,
+            ))?;
         }
 
         Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub(super) fn draw_mesh<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+    pub(super) fn draw_mesh<FmtLabel, FmtMirrorLabel>(
         &mut self,
-        (r, c): (YH, XH),
+        (r, c): (Vec<Y::ValueType>, Vec<X::ValueType>),
         mesh_line_style: &ShapeStyle,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
         fmt_label: FmtLabel,
+        fmt_mirror_label: FmtMirrorLabel,
         x_mesh: bool,
         y_mesh: bool,
         x_label_offset: i32,
@@ -472,38 +1319,47 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         y_axis: bool,
         axis_style: &ShapeStyle,
         axis_desc_style: &TextStyle,
-        x_desc: Option<String>,
-        y_desc: Option<String>,
+        x_desc: Option<RichText>,
+        y_desc: Option<RichText>,
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
+        label_overflow: [Option<Overflow>; 2],
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
+        FmtMirrorLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        let (x_labels, y_labels, top_labels, right_labels) = self.draw_mesh_lines(
+            (r, c),
+            (x_mesh, y_mesh),
+            mesh_line_style,
+            fmt_label,
+            fmt_mirror_label,
+        )?;
 
         for idx in 0..2 {
             self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
                 if x_axis { Some(axis_style) } else { None },
-                &x_labels[..],
+                if idx == 0 { &top_labels } else { &x_labels },
                 x_label_style,
                 x_label_offset,
                 (0, -1 + idx as i16 * 2),
-                x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                x_desc.as_ref().map(|desc| (desc, axis_desc_style)),
                 x_tick_size[idx],
+                label_overflow[0],
             )?;
 
             self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
                 if y_axis { Some(axis_style) } else { None },
-                &y_labels[..],
+                if idx == 0 { &y_labels } else { &right_labels },
                 y_label_style,
                 y_label_offset,
                 (-1 + idx as i16 * 2, 0),
-                y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                y_desc.as_ref().map(|desc| (desc, axis_desc_style)),
                 y_tick_size[idx],
+                label_overflow[1],
             )?;
         }
 
@@ -534,6 +1390,54 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
     }
 }
 
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged + Clone> ChartContext<'a, DB, Cartesian2d<X, Y>> {
+    /// Convert this chart context into a dual axis chart context with a second X axis, which
+    /// shares the Y axis with the primary coordinate spec. The secondary X axis is drawn in the
+    /// top label area by [DualCoordChartContext::configure_secondary_axes](struct.DualCoordChartContext.html#method.configure_secondary_axes),
+    /// and the series in its coordinate are drawn with
+    /// [DualCoordChartContext::draw_secondary_series](struct.DualCoordChartContext.html#method.draw_secondary_series).
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .top_x_label_area_size(30)
+    ///     .y_label_area_size(40)
+    ///     .build_cartesian_2d(400.0..700.0, 0.0..1.0)
+    ///     .unwrap()
+    ///     .set_secondary_x_coord(1.77..3.1);
+    ///
+    /// chart.configure_mesh().x_desc("Wavelength (nm)").draw().unwrap();
+    /// chart
+    ///     .configure_secondary_axes()
+    ///     .x_desc("Energy (eV)")
+    ///     .x_label_formatter(&|e| format!("{:.2}", e))
+    ///     .draw()
+    ///     .unwrap();
+    /// chart
+    ///     .draw_secondary_series(LineSeries::new(vec![(2.0, 0.2), (2.5, 0.8)], &RED))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `x_coord`: The coordinate spec for the secondary X axis
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn set_secondary_x_coord<SX: AsRangedCoord>(
+        self,
+        x_coord: SX,
+    ) -> DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<SX::CoordDescType, Y>> {
+        // Map the Y axis to the same pixels as the primary one, since the axis is shared
+        let mut pixel_range = self.drawing_area.get_pixel_range();
+        pixel_range.1 = (pixel_range.1.end - 1)..(pixel_range.1.start - 1);
+        let y_coord = self.drawing_area.as_coord_spec().y_spec().clone();
+
+        DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range))
+    }
+}
+
 pub(super) struct KeyPoints3d<X: Ranged, Y: Ranged, Z: Ranged> {
     pub(super) x_points: Vec<X::ValueType>,
     pub(super) y_points: Vec<Y::ValueType>,
@@ -547,6 +1451,27 @@ pub(super) enum Coord3D<X, Y, Z> {
     Z(Z),
 }
 
+impl<'a, DB, T, R, TT, RT> ChartContext<'a, DB, Polar<T, R>>
+where
+    DB: DrawingBackend,
+    T: Ranged<ValueType = TT> + ValueFormatter<TT>,
+    R: Ranged<ValueType = RT> + ValueFormatter<RT>,
+{
+    /// Initialize the configuration of the polar mesh, which is drawn by calling the function
+    /// `PolarMeshStyle::draw`.
+    pub fn configure_polar_mesh(&mut self) -> PolarMeshStyle<'a, '_, T, R, DB> {
+        PolarMeshStyle::new(self)
+    }
+}
+
+impl<'a, DB: DrawingBackend> ChartContext<'a, DB, TernaryCoord> {
+    /// Initialize the configuration of the ternary mesh, which is drawn by calling the function
+    /// `TernaryMeshStyle::draw`.
+    pub fn configure_ternary_mesh(&mut self) -> TernaryMeshStyle<'a, '_, DB> {
+        TernaryMeshStyle::new(self)
+    }
+}
+
 impl<X, Y, Z> Coord3D<X, Y, Z> {
     fn get_x(&self) -> &X {
         match self {
@@ -608,6 +1533,22 @@ where
         self
     }
 
+    /// Set the projection to the point `t` of the way from one configuration to another, see
+    /// [ProjectionMatrixBuilder::interpolate](../coord/ranged3d/struct.ProjectionMatrixBuilder.html#method.interpolate).
+    /// This is used with [render_frames](fn.render_frames.html) to rotate the chart in an
+    /// animation.
+    /// - `from`: The projection at `t = 0`, only the yaw, the pitch and the scale are used
+    /// - `to`: The projection at `t = 1`
+    /// - `t`: The progress of the animation
+    pub fn interpolate_projection(
+        &mut self,
+        from: &ProjectionMatrixBuilder,
+        to: &ProjectionMatrixBuilder,
+        t: f64,
+    ) -> &mut Self {
+        self.with_projection(|pb| pb.interpolate(from, to, t).into_matrix())
+    }
+
     pub fn set_3d_pixel_range(&mut self, size: (i32, i32, i32)) -> &mut Self {
         let (actual_x, actual_y) = self.drawing_area.get_pixel_range();
         self.drawing_area
@@ -891,7 +1832,10 @@ where
 
 #[cfg(test)]
 mod test {
+    use super::Overflow;
     use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
     fn test_chart_context() {
@@ -938,6 +1882,482 @@ mod test {
             .expect("Drawing error");
     }
 
+    #[test]
+    fn test_exterior_series_labels() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "Label");
+                assert!(pos.0 > 300);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .series_label_area_size(SeriesLabelPosition::RightOfPlot, 100)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (300, 300));
+
+        chart
+            .draw_series(std::iter::once(Circle::new((0.5, 0.5), 5, &RED)))
+            .unwrap()
+            .label("Label");
+        chart.configure_series_labels().draw().unwrap();
+        // Nothing has been reserved below the chart
+        assert!(chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::BelowPlot)
+            .draw()
+            .is_err());
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_inline_series_labels() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drawn = Rc::new(RefCell::new(vec![]));
+        let collected = drawn.clone();
+        let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_text(move |color, _, _, pos, text| {
+                collected.borrow_mut().push((text.to_string(), color, pos))
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .series_label_area_size(SeriesLabelPosition::RightOfPlot, 100)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        let mut colors = vec![];
+        for (idx, end) in [0.5, 0.51, 0.9].iter().enumerate() {
+            let anno = chart
+                .draw_series_with_palette(|style| {
+                    LineSeries::new(vec![(0.0, 0.0), (1.0, *end)], style)
+                })
+                .unwrap();
+            if idx != 2 {
+                anno.label(format!("Series {}", idx));
+            }
+            colors.push(anno.color().unwrap());
+        }
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::Inline)
+            .draw()
+            .unwrap();
+
+        let drawn = drawn.borrow();
+        // The series without a label is skipped
+        assert_eq!(drawn.len(), 2);
+        let (upper, lower) = (&drawn[0], &drawn[1]);
+        assert_eq!(upper.0, "Series 1");
+        assert_eq!(upper.1, colors[1]);
+        assert_eq!(lower.1, colors[0]);
+        // The labels are right of the ends, moved apart from each other
+        assert!(upper.2 .0 > 300 && lower.2 .0 == upper.2 .0);
+        assert!(lower.2 .1 - upper.2 .1 >= 12);
+    }
+
+    #[test]
+    fn test_series_label_columns() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let check = |columns: usize, max_height: Option<i32>, expected: (usize, usize)| {
+            let positions = Rc::new(RefCell::new(vec![]));
+            let collected = positions.clone();
+            let drawing_area = create_mocked_drawing_area(400, 400, move |m| {
+                m.check_draw_text(move |_, _, _, pos, _| collected.borrow_mut().push(pos));
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+                .unwrap();
+            for idx in 0..6 {
+                chart
+                    .draw_series(std::iter::once(Circle::new((0.5, 0.5), 5, &RED)))
+                    .unwrap()
+                    .label(format!("Series {}", idx));
+            }
+            let mut style = chart.configure_series_labels();
+            style.columns(columns);
+            if let Some(height) = max_height {
+                style.max_height(height);
+            }
+            style.draw().unwrap();
+
+            let positions = positions.borrow();
+            let mut xs: Vec<_> = positions.iter().map(|p| p.0).collect();
+            let mut ys: Vec<_> = positions.iter().map(|p| p.1).collect();
+            xs.sort();
+            xs.dedup();
+            ys.sort();
+            ys.dedup();
+            assert_eq!((xs.len(), ys.len()), expected);
+        };
+
+        check(1, None, (1, 6));
+        check(2, None, (2, 3));
+        // Two rows fit in the height, thus the labels are wrapped into three columns
+        check(1, Some(60), (3, 2));
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_series_label_glyphs() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_rect(|_, _, filled, ul, br| {
+                // The swatch fills the glyph box left of the label
+                if filled && ul != (0, 0) && br.0 - ul.0 == 40 {
+                    assert_eq!(br.1 - ul.1, 16);
+                }
+            });
+            m.check_draw_circle(|_, _, filled, _, r| {
+                assert!(filled);
+                assert_eq!(r, 3);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(Rectangle::new(
+                [(0.2, 0.2), (0.4, 0.4)],
+                BLUE.filled(),
+            )))
+            .unwrap()
+            .label("Swatch")
+            .legend_swatch(&BLUE);
+        chart
+            .draw_series(LineSeries::new(vec![(0.0, 0.0), (1.0, 1.0)], &RED))
+            .unwrap()
+            .label("Line")
+            .legend_line_marker(&RED, 3);
+        chart
+            .draw_series(std::iter::empty::<Circle<(f64, f64), u32>>())
+            .unwrap()
+            .label("Gradient")
+            .legend_gradient(Gradient::horizontal().stop(0.0, &BLUE).stop(1.0, &RED));
+        chart
+            .configure_series_labels()
+            .glyph_size(40, 16)
+            .draw()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_mirrored_axes() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            for _ in 0..100 {
+                let labels = labels.clone();
+                m.check_draw_text(move |_, _, _, _, text| {
+                    labels.borrow_mut().push(text.to_string())
+                });
+            }
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .x_labels_on_top(true)
+            .y_labels_on_right(true)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (160, 160));
+
+        chart
+            .configure_mesh()
+            .x_labels(3)
+            .y_labels(3)
+            .top_label_formatter(&|x| format!("top-{}", x))
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        assert!(labels.iter().any(|l| l == "top-5"));
+        assert!(labels.iter().any(|l| l == "5"));
+        let top_count = labels.iter().filter(|l| l.starts_with("top-")).count();
+        // The bottom, left and right axes use the default labels
+        assert!(top_count > 0 && labels.len() - top_count >= top_count * 2);
+    }
+
+    #[test]
+    fn test_theme() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let colors = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (lines, paths, labels) = (colors.clone(), colors.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_rect(|c, _, filled, _, _| {
+                assert_eq!(c, Theme::DARK.background_color());
+                assert!(filled);
+            });
+            m.check_draw_line(move |c, _, _, _| lines.borrow_mut().push(c));
+            m.check_draw_path(move |c, _, _| paths.borrow_mut().push(c));
+            m.check_draw_text(move |c, _, _, _, _| labels.borrow_mut().push(c));
+        });
+
+        drawing_area.fill_theme(&Theme::DARK).expect("Fill");
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .theme(&Theme::DARK)
+            .x_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart.configure_mesh().draw().expect("Draw mesh");
+
+        let colors = colors.borrow();
+        assert!(colors.contains(&Theme::DARK.light_grid));
+        assert!(colors.contains(&Theme::DARK.bold_grid));
+        assert!(colors.contains(&Theme::DARK.foreground));
+        assert!(!colors.contains(&BLACK.to_rgba()));
+        assert!(texts.borrow().iter().all(|c| *c == Theme::DARK.foreground));
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_draw_series_auto() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, PaletteColorblind::pick(0).to_rgba());
+            });
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, PaletteColorblind::pick(1).to_rgba());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let mut colors = chart.color_cycle(PaletteColorblind);
+        for k in 0..2 {
+            chart
+                .draw_series_auto(
+                    |style| LineSeries::new((0..10).map(move |x| (x, x / (k + 1))), style),
+                    &mut colors,
+                    format!("series {}", k),
+                )
+                .expect("Drawing error");
+        }
+
+        assert_eq!(chart.series_anno.len(), 2);
+        assert_eq!(chart.series_anno[1].get_label(), "series 1");
+        assert!(chart.series_anno[1].get_draw_func().is_some());
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_draw_series_with_palette() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, Palette100::pick(0).to_rgba());
+            });
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, Palette100::pick(1).to_rgba());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .palette(Palette100)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        for k in 0..2 {
+            let anno = chart
+                .draw_series_with_palette(|style| {
+                    LineSeries::new((0..10).map(move |x| (x, x / (k + 1))), style)
+                })
+                .expect("Drawing error");
+            assert_eq!(anno.color(), Some(Palette100::pick(k as usize).to_rgba()));
+        }
+        assert!(chart.series_anno[0].get_draw_func().is_some());
+        // The palette wraps around after its four colors
+        assert_eq!(
+            chart.next_palette_color().to_rgba(),
+            Palette100::pick(2).to_rgba()
+        );
+        chart.next_palette_color();
+        assert_eq!(
+            chart.next_palette_color().to_rgba(),
+            Palette100::pick(0).to_rgba()
+        );
+    }
+
+    #[test]
+    fn test_minor_ticks() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let ticks = Rc::new(RefCell::new(vec![]));
+        let collected = ticks.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(move |_, _, path| {
+                // The tick marks of the left axis are the short horizontal lines
+                if path.len() == 2 && path[0].1 == path[1].1 && path[0].0 < 30 {
+                    collected.borrow_mut().push((path[1].0 - path[0].0).abs());
+                }
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0..10, (1.0..1000.0).log_scale())
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .minor_ticks(9)
+            .minor_grid_style(&RED)
+            .draw()
+            .expect("Draw mesh");
+
+        let ticks = ticks.borrow();
+        let major = *ticks.iter().max().unwrap();
+        let minor = ticks.iter().filter(|len| **len == major / 2).count();
+        // There are 8 minor ticks in each of the 3 decades, besides the major ticks
+        assert!(minor >= 24, "{} minor ticks", minor);
+    }
+
+    #[test]
+    fn test_tick_locator() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0..10, 0..100)
+            .expect("Create chart");
+        let powers_of_two = |range: &std::ops::Range<i32>, _: usize| {
+            (0..10)
+                .map(|p| 1 << p)
+                .filter(|v| range.contains(v))
+                .collect::<Vec<_>>()
+        };
+        chart
+            .configure_mesh()
+            .y_tick_locator(&powers_of_two)
+            .draw()
+            .expect("Draw mesh");
+
+        assert_eq!(
+            *labels.borrow(),
+            ["1", "2", "4", "8", "16", "32", "64"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_offset_notation() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0..10, 0.0..5e6)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .y_desc("Count")
+            .y_offset_notation()
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        assert!(labels.iter().any(|s| s == "2.5"));
+        assert!(labels.iter().all(|s| !s.contains("000")));
+        // The offset is appended to the description once
+        assert_eq!(labels.iter().filter(|s| s.contains("×10")).count(), 1);
+        assert!(labels.iter().any(|s| s == "6"));
+    }
+
+    #[test]
+    fn test_axis_unit() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0..10, 0.0..4.0 * 1024.0 * 1024.0)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .y_labels(5)
+            .y_desc("Size")
+            .y_unit("bytes")
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        // The ticks are at the round values in MiB
+        assert!(labels.iter().any(|s| s == "2"));
+        assert!(labels.iter().any(|s| s == "3"));
+        // The prefixed unit is shown once in the description
+        assert_eq!(labels.iter().filter(|s| s.contains("MiB")).count(), 1);
+    }
+
+    #[test]
+    fn test_draw_colorbar() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, filled, (x0, y0), _| {
+                assert!(filled);
+                assert_eq!((x0, y0), (170, 0));
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .colorbar_area_size(40)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_colorbar(Colorbar::new(ViridisRGB, 0.0..1.0))
+            .expect("Drawing error");
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        assert!(chart
+            .draw_colorbar(Colorbar::new(ViridisRGB, 0.0..1.0))
+            .is_err());
+        chart
+            .draw_colorbar_inside(
+                Colorbar::new(ViridisRGB, 0.0..1.0),
+                SeriesLabelPosition::UpperLeft,
+            )
+            .expect("Drawing error");
+    }
+
     #[test]
     fn test_chart_context_3d() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -966,4 +2386,269 @@ mod test {
             .draw_series(std::iter::once(Circle::new((5, 5, 5), 5, &RED)))
             .expect("Drawing error");
     }
+
+    #[test]
+    fn test_clip_series() {
+        // The plotting area covers the whole drawing area, and the line leaves it at the corner
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(0, 99), (99, 0)]);
+            });
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!([upper_left, bottom_right], [(50, 0), (100, 49)]);
+            });
+            m.check_fill_polygon(|_, path| {
+                assert!(path.iter().all(|&(x, _)| x <= 100));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_rect_call, 1);
+                // The markers at the edge of the range are drawn whole
+                assert_eq!(b.num_draw_circle_call, 4);
+                // The marker just out of the range is cut at the border
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                vec![(0.0, 0.0), (2.0, 2.0)],
+                &RED,
+            )))
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(Rectangle::new(
+                [(0.5, 0.5), (3.0, 3.0)],
+                RED.filled(),
+            )))
+            .unwrap();
+        chart
+            .draw_series(vec![
+                Circle::new((0.5, 0.5), 3, &RED),
+                Circle::new((0.5, 1.5), 3, &RED),
+            ])
+            .unwrap();
+        chart
+            .draw_series(vec![
+                Circle::new((0.0, 0.0), 3, RED.filled()),
+                Circle::new((1.0, 0.5), 3, RED.filled()),
+                Circle::new((1.02, 0.5), 3, RED.filled()),
+            ])
+            .unwrap();
+        chart
+            .clip(false)
+            .draw_series(std::iter::once(Circle::new((0.5, 1.5), 3, &RED)))
+            .unwrap();
+    }
+
+    #[cfg(feature = "bitmap_backend")]
+    #[test]
+    fn test_blend_series() {
+        let mut buffer = vec![0; 100 * 100 * 3];
+        let (overlap, single) = {
+            let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0..100, 0..100)
+                .unwrap();
+            let style = RED.mix(0.5).filled().blend_mode(BlendMode::Additive);
+            chart
+                .draw_series(vec![
+                    Circle::new((40, 50), 10, style.clone()),
+                    Circle::new((55, 50), 10, style),
+                ])
+                .unwrap();
+            (
+                chart.backend_coord(&(47, 50)),
+                chart.backend_coord(&(33, 50)),
+            )
+        };
+        let pixel = |(x, y): (i32, i32)| {
+            let idx = (y as usize * 100 + x as usize) * 3;
+            (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+        };
+        // The halves of the red add up to the full red where the circles overlap, rather than
+        // the three quarters of the normal blending
+        assert_eq!(pixel(overlap), (255, 0, 0));
+        assert_eq!(pixel(single), (127, 0, 0));
+    }
+
+    #[cfg(feature = "svg_backend")]
+    #[test]
+    fn test_blend_series_on_svg() {
+        let mut content = String::new();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100))
+                .into_drawing_area()
+                .with_fallbacks();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0..100, 0..100)
+                .unwrap();
+            let style = RED.mix(0.5).filled().blend_mode(BlendMode::Additive);
+            chart
+                .draw_series(vec![
+                    Circle::new((40, 50), 10, style.clone()),
+                    Circle::new((55, 50), 10, style),
+                ])
+                .unwrap();
+        }
+        // The area knows the backend isn't a raster one, thus the blend mode is ignored rather
+        // than the series being rasterized into the pixels of the document
+        assert_eq!(content.matches("<circle").count(), 2);
+        assert!(!content.contains("<rect"));
+    }
+
+    #[test]
+    fn test_clip_region() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                // Only the part of the line right of the middle is drawn
+                assert_eq!(path.len(), 2);
+                assert_eq!((path[0].0, path[1]), (50, (100, 0)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        let y_range = chart.y_range();
+        chart
+            .clip_region(0.5..2.0, y_range)
+            .draw_series(std::iter::once(PathElement::new(
+                vec![(0.0, 0.0), (1.0, 1.0)],
+                &RED,
+            )))
+            .unwrap();
+        // The region is combined with the plotting area, and the marker outside is skipped
+        chart
+            .draw_series(vec![
+                Circle::new((0.25, 0.5), 3, &RED),
+                Circle::new((0.75, 0.5), 3, &RED),
+            ])
+            .unwrap();
+        chart
+            .clear_clip_region()
+            .draw_series(std::iter::once(PathElement::new(
+                vec![(0.5, 0.5), (1.0, 1.0)],
+                &RED,
+            )))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_draw_on_layers() {
+        let order = Rc::new(RefCell::new(vec![]));
+        let (rects, paths, lines) = (order.clone(), order.clone(), order.clone());
+        let drawing_area = create_mocked_drawing_area(100, 100, move |m| {
+            m.check_draw_rect(move |_, _, _, _, _| rects.borrow_mut().push("rect"));
+            m.check_draw_path(move |_, _, _| paths.borrow_mut().push("path"));
+            m.check_draw_line(move |_, _, _, _| lines.borrow_mut().push("line"));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .configure_mesh()
+            .layer(Layer::Annotation)
+            .x_labels(2)
+            .y_labels(2)
+            .draw()
+            .unwrap();
+        chart
+            .draw_series_on_layer(
+                Layer::Series,
+                std::iter::once(Rectangle::new([(0.0, 0.0), (0.5, 0.5)], RED.filled())),
+            )
+            .unwrap()
+            .label("area");
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                vec![(0.0, 0.0), (1.0, 1.0)],
+                &BLUE,
+            )))
+            .unwrap();
+        assert_eq!(*order.borrow(), vec!["path"]);
+        assert_eq!(chart.series_anno.len(), 2);
+
+        // The grid is drawn above the series, which is drawn above the direct drawing
+        drawing_area.present().unwrap();
+        let order = order.borrow();
+        assert!(order.len() > 2);
+        assert_eq!(&order[..2], &["path", "rect"]);
+        assert!(order[2..].iter().all(|&op| op != "rect"));
+    }
+
+    #[test]
+    fn test_label_overflow() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let count_labels = |overflow: Option<Overflow>| {
+            let count = Rc::new(Cell::new(0));
+            let collected = count.clone();
+            {
+                let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+                    m.drop_check(move |b| collected.set(b.num_draw_text_call));
+                });
+                let mut chart = ChartBuilder::on(&drawing_area)
+                    .x_label_area_size(40)
+                    .build_cartesian_2d(0..1_000_000, 0.0..1.0)
+                    .unwrap();
+                let mut mesh = chart.configure_mesh();
+                mesh.x_labels(50).disable_y_axis();
+                if let Some(overflow) = overflow {
+                    mesh.x_label_overflow(overflow);
+                }
+                mesh.draw().unwrap();
+            }
+            count.get()
+        };
+
+        let all = count_labels(None);
+        let thinned = count_labels(Some(Overflow::Thin));
+        assert!(thinned > 0 && thinned < all, "{} of {}", thinned, all);
+        // The labels rotated by 90 degrees only take the height of the text
+        let rotated = count_labels(Some(Overflow::Rotate(90.0)));
+        assert!(rotated > thinned, "{} of {}", rotated, all);
+    }
+
+    #[test]
+    fn test_period_labels() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(400, 200, |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                collected.borrow_mut().push((text.to_string(), pos));
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(50)
+            .build_cartesian_2d(0..40, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_labels(20)
+            .x_period_formatter(&|x| format!("P{}", x / 10))
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        let periods: Vec<_> = labels.iter().filter(|(t, _)| t.starts_with('P')).collect();
+        // The period is only labelled where it changes
+        let texts: Vec<_> = periods.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(texts, vec!["P0", "P1", "P2", "P3", "P4"]);
+        let (_, label_pos) = labels.iter().find(|(t, _)| t == "10").expect("Label");
+        let (_, period_pos) = periods[1];
+        // The period starts at its first label, on the row below the label
+        assert_eq!(period_pos.0, label_pos.0);
+        assert!(period_pos.1 > label_pos.1);
+    }
 }
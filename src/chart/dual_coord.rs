@@ -7,11 +7,10 @@ use super::mesh::SecondaryMeshStyle;
 use super::{ChartContext, ChartState, SeriesAnno};
 
 use crate::coord::cartesian::Cartesian2d;
-use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::coord::ranged1d::{AsRangedCoord, Ranged, ValueFormatter};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 
-use crate::drawing::DrawingArea;
-use crate::drawing::DrawingAreaErrorKind;
+use crate::drawing::{ClipBackend, DrawingArea, DrawingAreaErrorKind};
 use crate::element::{Drawable, PointCollection};
 
 use plotters_backend::{BackendCoord, DrawingBackend};
@@ -76,9 +75,7 @@ impl<CT1: CoordTranslate, CT2: CoordTranslate> DualCoordChartState<CT1, CT2> {
         area: &DrawingArea<DB, Shift>,
     ) -> DualCoordChartContext<'_, DB, CT1, CT2> {
         let primary = self.primary.restore(area);
-        let secondary = self
-            .secondary
-            .restore(&primary.plotting_area().strip_coord_spec());
+        let secondary = self.secondary.restore(area);
         DualCoordChartContext { primary, secondary }
     }
 }
@@ -113,6 +110,10 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
         std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
         std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
 
+        let drawing_area_pos = primary.drawing_area_pos;
+        let root_area_size = primary.root_area_size;
+        let theme = primary.theme;
+
         Self {
             primary,
             secondary: ChartContext {
@@ -120,7 +121,16 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 y_label_area: secondary_y_label_area,
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
-                drawing_area_pos: (0, 0),
+                drawing_area_pos,
+                root_area_size,
+                colorbar_area: None,
+                legend_area: [None, None],
+                theme,
+                palette_idx: 0,
+                hit_regions: vec![],
+                clip: true,
+                clip_region: None,
+                zoomed: false,
             },
         }
     }
@@ -198,12 +208,136 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged, SX: Ranged, SY: Ranged>
     ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
     where
         for<'b> &'b E: PointCollection<'b, (SX::ValueType, SY::ValueType)>,
-        E: Drawable<DB>,
+        E: Drawable<ClipBackend<DB>>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let end_point = self.secondary.draw_series_impl(series)?;
+        Ok(self.primary.alloc_series_anno().set_end_point(end_point))
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged + Clone, Y: Ranged, CT2: CoordTranslate>
+    DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, CT2>
+{
+    /// Attach another y axis to the right side of the chart, which shares the X axis with the
+    /// primary coordinate system. The axis is drawn in the right label area, moved further to
+    /// the right than the secondary axis, thus the right label area should be wide enough for
+    /// all the axes.
+    /// - `y_coord`: The coordinate spec for the Y axis
+    /// - `offset`: The distance between the plotting area and the axis in pixels
+    /// - **returns**: The axis, which is configured with
+    ///   [ExtraYAxis::configure_axis](struct.ExtraYAxis.html#method.configure_axis) and used by
+    ///   [DualCoordChartContext::draw_extra_series](struct.DualCoordChartContext.html#method.draw_extra_series)
+    pub fn add_secondary_y_axis<SY: AsRangedCoord>(
+        &self,
+        y_coord: SY,
+        offset: u32,
+    ) -> ExtraYAxis<'a, DB, X, SY::CoordDescType> {
+        let mut pixel_range = self.primary.drawing_area.get_pixel_range();
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+        let x_coord = self.primary.drawing_area.as_coord_spec().x_spec().clone();
+        let drawing_area = self
+            .primary
+            .drawing_area
+            .strip_coord_spec()
+            .apply_coord_spec(Cartesian2d::new(x_coord, y_coord, pixel_range));
+        let label_area = self.secondary.y_label_area[1]
+            .as_ref()
+            .map(|area| area.margin(0, 0, offset, 0));
+
+        ExtraYAxis {
+            chart: ChartContext {
+                x_label_area: [None, None],
+                y_label_area: [None, label_area],
+                drawing_area,
+                series_anno: vec![],
+                drawing_area_pos: self.primary.drawing_area_pos,
+                root_area_size: self.primary.root_area_size,
+                colorbar_area: None,
+                legend_area: [None, None],
+                theme: self.primary.theme,
+                palette_idx: 0,
+                hit_regions: vec![],
+                clip: true,
+                clip_region: None,
+                zoomed: false,
+            },
+        }
+    }
+
+    /// Draw a series use the coordinate system of an extra y axis, the series is labeled in the
+    /// same legend as the other series.
+    /// - `axis`: The axis that is created by
+    ///   [DualCoordChartContext::add_secondary_y_axis](struct.DualCoordChartContext.html#method.add_secondary_y_axis)
+    /// - `series`: The series to draw
+    /// - `Returns` the series annotation object or error code
+    pub fn draw_extra_series<SY: Ranged, E, R, S>(
+        &mut self,
+        axis: &ExtraYAxis<'a, DB, X, SY>,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, SY::ValueType)>,
+        E: Drawable<ClipBackend<DB>>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        self.secondary.draw_series_impl(series)?;
-        Ok(self.primary.alloc_series_anno())
+        let end_point = axis.chart.draw_series_impl(series)?;
+        Ok(self.primary.alloc_series_anno().set_end_point(end_point))
+    }
+}
+
+/// An additional y axis of a dual coordinate chart, which is created by
+/// [DualCoordChartContext::add_secondary_y_axis](struct.DualCoordChartContext.html#method.add_secondary_y_axis).
+/// Each extra axis has its own range and style, and the series are drawn on it with
+/// [DualCoordChartContext::draw_extra_series](struct.DualCoordChartContext.html#method.draw_extra_series).
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(40)
+///     .right_y_label_area_size(100)
+///     .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+///     .unwrap()
+///     .set_secondary_coord(0.0..10.0, 0.0..1.0);
+/// let mut pressure = chart.add_secondary_y_axis(900.0..1100.0, 50);
+///
+/// chart.configure_mesh().draw().unwrap();
+/// chart.configure_secondary_axes().draw().unwrap();
+/// pressure.configure_axis().y_desc("hPa").draw().unwrap();
+/// chart
+///     .draw_extra_series(
+///         &pressure,
+///         LineSeries::new((0..10).map(|x| (x as f64, 1000.0 + x as f64)), &BLUE),
+///     )
+///     .unwrap()
+///     .label("Pressure");
+/// ```
+pub struct ExtraYAxis<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> {
+    chart: ChartContext<'a, DB, Cartesian2d<X, Y>>,
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ExtraYAxis<'a, DB, X, Y> {
+    /// Get a reference to the drawing area that uses the coordinate system of the axis
+    pub fn plotting_area(&self) -> &DrawingArea<DB, Cartesian2d<X, Y>> {
+        &self.chart.drawing_area
+    }
+}
+
+impl<'a, DB: DrawingBackend, XT, YT, X: Ranged<ValueType = XT>, Y: Ranged<ValueType = YT>>
+    ExtraYAxis<'a, DB, X, Y>
+where
+    X: ValueFormatter<XT>,
+    Y: ValueFormatter<YT>,
+{
+    /// Start configure the style for the axis
+    pub fn configure_axis<'b>(&'b mut self) -> SecondaryMeshStyle<'a, 'b, X, Y, DB> {
+        SecondaryMeshStyle::new(&mut self.chart)
     }
 }
 
@@ -239,3 +373,76 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate> DerefMut
         self.borrow_mut()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_extra_y_axis() {
+        let labels = Rc::new(RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                collected.borrow_mut().push((pos.0, text.to_string()));
+            });
+            m.drop_check(|b| {
+                assert!(b.num_draw_path_call > 0);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .right_y_label_area_size(100)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap()
+            .set_secondary_coord(0..10, 0..10);
+        let mut extra = chart.add_secondary_y_axis(500..600, 50);
+        extra.configure_axis().draw().unwrap();
+        chart
+            .draw_extra_series(&extra, std::iter::once(Circle::new((5, 550), 3, &RED)))
+            .unwrap()
+            .label("Extra");
+
+        // The labels of the extra axis are on the right of the plotting area and the offset
+        let labels = labels.borrow();
+        assert!(!labels.is_empty());
+        assert!(labels.iter().all(|(x, _)| *x >= 250));
+        assert!(labels.iter().any(|(_, text)| text == "550"));
+        assert_eq!(
+            extra.plotting_area().map_coordinate(&(0, 500)),
+            chart.borrow_secondary().backend_coord(&(0, 0))
+        );
+    }
+
+    #[test]
+    fn test_secondary_x_coord() {
+        let labels = Rc::new(RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                collected.borrow_mut().push((pos.1, text.to_string()));
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .top_x_label_area_size(30)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap()
+            .set_secondary_x_coord(100..200);
+        chart
+            .configure_secondary_axes()
+            .x_label_formatter(&|x| format!("<{}>", x))
+            .draw()
+            .unwrap();
+
+        // The secondary X labels are in the top label area and the Y axis is shared
+        let labels = labels.borrow();
+        assert!(labels.iter().any(|(_, text)| text == "<150>"));
+        assert!(labels.iter().all(|(y, _)| *y < 30));
+        assert_eq!(
+            chart.borrow_secondary().backend_coord(&(150, 3)),
+            chart.backend_coord(&(5, 3))
+        );
+        assert_eq!(chart.borrow_secondary().y_range(), 0..10);
+    }
+}
@@ -0,0 +1,309 @@
+use std::ops::Range;
+
+use super::{ChartBuilder, ChartContext};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::AsRangedCoord;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use plotters_backend::DrawingBackend;
+
+type FacetChart<'c, DB, X, Y> = ChartContext<
+    'c,
+    DB,
+    Cartesian2d<
+        <Range<X> as AsRangedCoord>::CoordDescType,
+        <Range<Y> as AsRangedCoord>::CoordDescType,
+    >,
+>;
+
+/// The smallest range that covers all the ranges
+fn union<T: PartialOrd, I: Iterator<Item = Range<T>>>(ranges: I) -> Option<Range<T>> {
+    ranges.fold(None, |acc, r| {
+        Some(match acc {
+            None => r,
+            Some(acc) => {
+                let start = if r.start < acc.start {
+                    r.start
+                } else {
+                    acc.start
+                };
+                let end = if r.end > acc.end { r.end } else { acc.end };
+                start..end
+            }
+        })
+    })
+}
+
+/// The position of a cell in a [FacetGrid](struct.FacetGrid.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FacetCell {
+    /// The row of the cell, counted from the top
+    pub row: usize,
+    /// The column of the cell, counted from the left
+    pub col: usize,
+    /// The index of the cell in the row-major order
+    pub index: usize,
+    /// If the cell has the X label area, which is on the bottom row when the X axis is shared or
+    /// only the outer cells are labelled
+    pub x_labels: bool,
+    /// If the cell has the Y label area, which is on the left column when the Y axis is shared or
+    /// only the outer cells are labelled
+    pub y_labels: bool,
+}
+
+/// The layout of a grid of the subplots, where a cartesian chart is built in each cell.
+///
+/// When the X axis is shared, all the charts are built with the union of the X ranges of the
+/// cells and only the bottom row has the X labels, the same goes for the Y axis and the left
+/// column. The plotting areas of the cells keep the same size either way, since the label areas
+/// are taken from the outer cells only.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// FacetGrid::new(&root, (2, 3))
+///     .share_x(true)
+///     .share_y(true)
+///     .draw(
+///         |cell| (0.0..10.0, 0.0..(cell.index + 1) as f64),
+///         |cell, chart| {
+///             chart.configure_mesh().draw()?;
+///             chart.draw_series(LineSeries::new(
+///                 (0..10).map(|x| (x as f64, (x * cell.index) as f64 / 10.0)),
+///                 &Palette99::pick(cell.index),
+///             ))?;
+///             Ok(())
+///         },
+///     )
+///     .unwrap();
+/// ```
+pub struct FacetGrid<DB: DrawingBackend> {
+    area: DrawingArea<DB, Shift>,
+    shape: (usize, usize),
+    share_x: bool,
+    share_y: bool,
+    outer_labels: bool,
+    x_label_area_size: u32,
+    y_label_area_size: u32,
+    margin: u32,
+}
+
+impl<DB: DrawingBackend> FacetGrid<DB> {
+    /// Create a grid of the subplots on the drawing area
+    /// - `area`: The drawing area to split
+    /// - `shape`: The number of the rows and the columns
+    /// - **returns** The newly created layout
+    pub fn new(area: &DrawingArea<DB, Shift>, (rows, cols): (usize, usize)) -> Self {
+        Self {
+            area: area.clone(),
+            shape: (rows.max(1), cols.max(1)),
+            share_x: false,
+            share_y: false,
+            outer_labels: false,
+            x_label_area_size: 30,
+            y_label_area_size: 40,
+            margin: 5,
+        }
+    }
+
+    /// Share the X range of all the cells and only label the X axis on the bottom row
+    pub fn share_x(mut self, share: bool) -> Self {
+        self.share_x = share;
+        self
+    }
+
+    /// Share the Y range of all the cells and only label the Y axis on the left column
+    pub fn share_y(mut self, share: bool) -> Self {
+        self.share_y = share;
+        self
+    }
+
+    /// Only label the X axis on the bottom row and the Y axis on the left column even if the
+    /// axes aren't shared, which suits the grids whose rows and columns have the same ranges
+    pub fn outer_labels(mut self, outer: bool) -> Self {
+        self.outer_labels = outer;
+        self
+    }
+
+    /// If the X labels are only on the bottom row
+    fn outer_x(&self) -> bool {
+        self.share_x || self.outer_labels
+    }
+
+    /// If the Y labels are only on the left column
+    fn outer_y(&self) -> bool {
+        self.share_y || self.outer_labels
+    }
+
+    /// Set the size of the X label areas
+    pub fn x_label_area_size(mut self, size: u32) -> Self {
+        self.x_label_area_size = size;
+        self
+    }
+
+    /// Set the size of the Y label areas
+    pub fn y_label_area_size(mut self, size: u32) -> Self {
+        self.y_label_area_size = size;
+        self
+    }
+
+    /// Set the margin of the chart in each cell
+    pub fn margin(mut self, size: u32) -> Self {
+        self.margin = size;
+        self
+    }
+
+    /// Split the drawing area into the cells, the outer cells are larger by the size of the
+    /// label areas when the axes are shared
+    fn split(&self) -> Vec<DrawingArea<DB, Shift>> {
+        let (rows, cols) = self.shape;
+        let (w, h) = self.area.dim_in_pixel();
+        let breakpoints = |n: usize, total: u32, label: u32, first: bool| {
+            let label = label.min(total) as i32;
+            let size = (total as i32 - label) as f64 / n as f64;
+            let start = if first { label } else { 0 };
+            (1..n)
+                .map(|k| start + (size * k as f64).round() as i32)
+                .collect::<Vec<_>>()
+        };
+        let xs = if self.outer_y() {
+            breakpoints(cols, w, self.y_label_area_size, true)
+        } else {
+            breakpoints(cols, w, 0, true)
+        };
+        let ys = if self.outer_x() {
+            breakpoints(rows, h, self.x_label_area_size, false)
+        } else {
+            breakpoints(rows, h, 0, false)
+        };
+        self.area.split_by_breakpoints(xs, ys)
+    }
+
+    /// Build the chart in each cell and draw it
+    /// - `ranges`: The function that returns the X and the Y ranges of a cell, the shared
+    ///   ranges are the union of the ones of all the cells
+    /// - `draw`: The function that draws a cell on its chart
+    pub fn draw<X, Y, R, F>(
+        &self,
+        mut ranges: R,
+        mut draw: F,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: PartialOrd + Clone,
+        Y: PartialOrd + Clone,
+        Range<X>: AsRangedCoord<Value = X>,
+        Range<Y>: AsRangedCoord<Value = Y>,
+        R: FnMut(FacetCell) -> (Range<X>, Range<Y>),
+        F: FnMut(
+            FacetCell,
+            &mut FacetChart<'_, DB, X, Y>,
+        ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        let (rows, cols) = self.shape;
+        let cells: Vec<_> = (0..rows * cols)
+            .map(|index| FacetCell {
+                row: index / cols,
+                col: index % cols,
+                index,
+                x_labels: !self.outer_x() || index / cols == rows - 1,
+                y_labels: !self.outer_y() || index % cols == 0,
+            })
+            .collect();
+        let mut specs: Vec<_> = cells.iter().map(|cell| ranges(*cell)).collect();
+
+        if self.share_x {
+            if let Some(x) = union(specs.iter().map(|s| s.0.clone())) {
+                specs.iter_mut().for_each(|s| s.0 = x.clone());
+            }
+        }
+        if self.share_y {
+            if let Some(y) = union(specs.iter().map(|s| s.1.clone())) {
+                specs.iter_mut().for_each(|s| s.1 = y.clone());
+            }
+        }
+
+        for ((area, cell), (x, y)) in self.split().iter().zip(cells).zip(specs) {
+            let mut builder = ChartBuilder::on(area);
+            builder.margin(self.margin);
+            if cell.x_labels {
+                builder.x_label_area_size(self.x_label_area_size);
+            }
+            if cell.y_labels {
+                builder.y_label_area_size(self.y_label_area_size);
+            }
+            let mut chart = builder.build_cartesian_2d(x, y)?;
+            draw(cell, &mut chart)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_facet_grid() {
+        let drawing_area = create_mocked_drawing_area(440, 330, |_| {});
+        let mut charts = vec![];
+        FacetGrid::new(&drawing_area, (2, 2))
+            .share_x(true)
+            .share_y(true)
+            .margin(0)
+            .draw(
+                |cell| (cell.col as f64..10.0, 0.0..(cell.index + 1) as f64),
+                |cell, chart| {
+                    let (x, y) = chart.plotting_area().get_pixel_range();
+                    charts.push((
+                        cell,
+                        x.end - x.start,
+                        y.end - y.start,
+                        chart.x_range(),
+                        chart.y_range(),
+                    ));
+                    Ok(())
+                },
+            )
+            .unwrap();
+
+        assert_eq!(charts.len(), 4);
+        for (cell, w, h, x, y) in charts {
+            assert_eq!((w, h), (200, 150));
+            assert_eq!((x, y), (0.0..10.0, 0.0..4.0));
+            assert_eq!(
+                (cell.x_labels, cell.y_labels),
+                (cell.row == 1, cell.col == 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_outer_labels() {
+        let drawing_area = create_mocked_drawing_area(440, 330, |_| {});
+        let mut charts = vec![];
+        FacetGrid::new(&drawing_area, (2, 2))
+            .outer_labels(true)
+            .margin(0)
+            .draw(
+                |cell| (0.0..(cell.col + 1) as f64, 0.0..(cell.row + 1) as f64),
+                |cell, chart| {
+                    let (x, y) = chart.plotting_area().get_pixel_range();
+                    charts.push((cell, x.end - x.start, y.end - y.start, chart.x_range()));
+                    Ok(())
+                },
+            )
+            .unwrap();
+        // The ranges are kept for each cell, while the label areas are on the outer cells only
+        for (cell, w, h, x) in charts {
+            assert_eq!((w, h), (200, 150));
+            assert_eq!(x, 0.0..(cell.col + 1) as f64);
+            assert_eq!(
+                (cell.x_labels, cell.y_labels),
+                (cell.row == 1, cell.col == 0)
+            );
+        }
+    }
+}
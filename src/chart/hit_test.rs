@@ -0,0 +1,268 @@
+use std::borrow::Borrow;
+
+use super::{ChartContext, SeriesAnno};
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+use crate::drawing::{ClipBackend, DrawingAreaErrorKind};
+use crate::element::{Drawable, PointCollection};
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The shape that an element covers for the hit-testing, which is given for each element drawn
+/// by [ChartContext::draw_series_with_id](struct.ChartContext.html#method.draw_series_with_id)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HitShape {
+    /// The bounding box of the points, such as a bar or a rectangle
+    Bounds,
+    /// The path through the points, such as a line, which is hit near any of its segments
+    Path,
+    /// The circles of the given radius in pixels around the points, such as the markers
+    Points(u32),
+}
+
+/// The pixels of an element that is drawn with an id, which is used for the hit-testing
+pub(crate) struct HitRegion {
+    id: usize,
+    shape: HitShape,
+    points: Vec<BackendCoord>,
+}
+
+/// The squared distance between the pixel and the segment
+fn segment_distance_sq(
+    (x, y): BackendCoord,
+    (x0, y0): BackendCoord,
+    (x1, y1): BackendCoord,
+) -> f64 {
+    let (px, py) = (f64::from(x - x0), f64::from(y - y0));
+    let (dx, dy) = (f64::from(x1 - x0), f64::from(y1 - y0));
+    let (dot, len_sq) = (px * dx + py * dy, dx * dx + dy * dy);
+    // The nearest point of the segment is an end point unless the pixel projects onto it
+    let t = if dot <= 0.0 {
+        0.0
+    } else if dot >= len_sq {
+        1.0
+    } else {
+        dot / len_sq
+    };
+    let (ex, ey) = (px - t * dx, py - t * dy);
+    ex * ex + ey * ey
+}
+
+impl HitRegion {
+    fn contains(&self, (x, y): BackendCoord, tolerance: i32) -> bool {
+        let mut points = self.points.iter();
+        let first = match points.next() {
+            Some(point) => *point,
+            None => return false,
+        };
+        match self.shape {
+            HitShape::Bounds => {
+                let (x0, y0, x1, y1) = points.fold((first.0, first.1, first.0, first.1), |b, p| {
+                    (b.0.min(p.0), b.1.min(p.1), b.2.max(p.0), b.3.max(p.1))
+                });
+                x0 - tolerance <= x
+                    && x <= x1 + tolerance
+                    && y0 - tolerance <= y
+                    && y <= y1 + tolerance
+            }
+            HitShape::Path => {
+                let limit = f64::from(tolerance) * f64::from(tolerance);
+                // A path of a single point is hit near the point
+                segment_distance_sq((x, y), first, first) <= limit
+                    || self
+                        .points
+                        .windows(2)
+                        .any(|w| segment_distance_sq((x, y), w[0], w[1]) <= limit)
+            }
+            HitShape::Points(radius) => {
+                let limit = f64::from(radius as i32 + tolerance);
+                self.points
+                    .iter()
+                    .any(|p| segment_distance_sq((x, y), *p, *p) <= limit * limit)
+            }
+        }
+    }
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Draw a data series and remember the pixels of its elements under the id, so that
+    /// [ChartContext::hit_test](#method.hit_test) finds the series at a pixel, for example, to
+    /// highlight the series under the mouse cursor. The area that each element covers is given
+    /// by the `shape` function, see [HitShape](enum.HitShape.html).
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    ///     .unwrap();
+    /// for (id, x) in [2.0, 5.0, 8.0].iter().enumerate() {
+    ///     let bar = Rectangle::new([(*x - 0.5, 0.0), (*x + 0.5, *x)], BLUE.filled());
+    ///     chart
+    ///         .draw_series_with_id(id, |_| HitShape::Bounds, std::iter::once(bar))
+    ///         .unwrap();
+    /// }
+    ///
+    /// let cursor = chart.backend_coord(&(5.2, 3.0));
+    /// assert_eq!(chart.hit_test(cursor, 0), Some(1));
+    /// ```
+    /// - `id`: The id of the series, which is chosen by the caller
+    /// - `shape`: The function that gives the shape that an element covers
+    /// - `series`: The series to draw
+    /// - **returns** The series annotation object or error code
+    pub fn draw_series_with_id<E, R, S, F>(
+        &mut self,
+        id: usize,
+        shape: F,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, CT::From>,
+        E: Drawable<ClipBackend<DB>>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        F: Fn(&E) -> HitShape,
+    {
+        let mut regions = vec![];
+        let area = &self.drawing_area;
+        let end_point = self.draw_series_impl(series.into_iter().inspect(|element| {
+            let element = element.borrow();
+            let points = element
+                .point_iter()
+                .into_iter()
+                .map(|p| area.map_coordinate(p.borrow()))
+                .collect();
+            regions.push(HitRegion {
+                id,
+                shape: shape(element),
+                points,
+            });
+        }))?;
+        self.hit_regions.extend(regions);
+        Ok(self.alloc_series_anno().set_end_point(end_point))
+    }
+
+    /// Find the series that is drawn at the pixel by
+    /// [ChartContext::draw_series_with_id](#method.draw_series_with_id). The series drawn later
+    /// is on the top, thus it's found first.
+    /// - `pixel`: The backend coordinate, for example, the position of the mouse cursor
+    /// - `tolerance`: How far the pixel can be from an element in pixels
+    /// - **returns** The id of the series, or `None` if there's no element at the pixel
+    pub fn hit_test(&self, pixel: BackendCoord, tolerance: u32) -> Option<usize> {
+        let tolerance = tolerance as i32;
+        self.hit_regions
+            .iter()
+            .rev()
+            .find(|region| region.contains(pixel, tolerance))
+            .map(|region| region.id)
+    }
+}
+
+impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
+    /// Convert the backend coordinate to the data coordinate of the chart, without consuming
+    /// the chart like [ChartContext::into_coord_trans](#method.into_coord_trans) does
+    /// - `pixel`: The backend coordinate
+    /// - **returns** The data coordinate, or `None` if the pixel is out of the plotting area
+    pub fn data_coord(&self, (x, y): BackendCoord) -> Option<CT::From> {
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+        if !x_range.contains(&x) || !y_range.contains(&y) {
+            return None;
+        }
+        self.drawing_area.reverse_map_coordinate((x, y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_hit_test() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+
+        chart
+            .draw_series_with_id(
+                7,
+                |_| HitShape::Path,
+                std::iter::once(PathElement::new(
+                    vec![(0.0, 0.0), (5.0, 5.0), (10.0, 0.0)],
+                    &RED,
+                )),
+            )
+            .unwrap();
+        chart
+            .draw_series_with_id(
+                3,
+                |_| HitShape::Points(3),
+                std::iter::once(Circle::new((5.0, 5.0), 3, &BLUE)),
+            )
+            .unwrap();
+
+        let on_line = chart.backend_coord(&(2.5, 2.5));
+        assert_eq!(chart.hit_test(on_line, 1), Some(7));
+        assert_eq!(chart.hit_test((on_line.0, on_line.1 - 5), 1), None);
+        assert_eq!(chart.hit_test((on_line.0, on_line.1 - 5), 5), Some(7));
+        // The circle is drawn later, thus it's on the top of the line
+        assert_eq!(chart.hit_test(chart.backend_coord(&(5.0, 5.0)), 3), Some(3));
+        assert_eq!(chart.hit_test(chart.backend_coord(&(5.0, 9.0)), 3), None);
+        // The radius of the marker is covered without any tolerance
+        let (x, y) = chart.backend_coord(&(5.0, 5.0));
+        assert_eq!(chart.hit_test((x + 2, y - 2), 0), Some(3));
+        assert_eq!(chart.hit_test((x + 5, y - 1), 0), None);
+
+        let (x, y): (f64, f64) = chart.data_coord(chart.backend_coord(&(2.0, 8.0))).unwrap();
+        assert!((x - 2.0).abs() < 0.1 && (y - 8.0).abs() < 0.1);
+        assert_eq!(chart.data_coord((150, 50)), None);
+        assert_eq!(
+            drawing_area.reverse_map_coordinate((150, 50)),
+            Some((150, 50))
+        );
+    }
+
+    #[test]
+    fn test_hit_shapes() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        let line = || std::iter::once(PathElement::new(vec![(0.0, 0.0), (8.0, 8.0)], &RED));
+        let corner = chart.backend_coord(&(8.0, 0.0));
+
+        // A line of two points is hit near the segment, not in its bounding box
+        chart
+            .draw_series_with_id(0, |_| HitShape::Path, line())
+            .unwrap();
+        assert_eq!(chart.hit_test(chart.backend_coord(&(4.0, 4.0)), 1), Some(0));
+        assert_eq!(chart.hit_test(corner, 1), None);
+
+        chart
+            .draw_series_with_id(1, |_| HitShape::Bounds, line())
+            .unwrap();
+        assert_eq!(chart.hit_test(corner, 1), Some(1));
+    }
+
+    #[test]
+    fn test_draw_series_with_id_clipped() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        // The marker out of the plotting area is skipped like the other series
+        chart
+            .draw_series_with_id(
+                0,
+                |_| HitShape::Points(3),
+                vec![
+                    Circle::new((5.0, 5.0), 3, &BLUE),
+                    Circle::new((5.0, 15.0), 3, &BLUE),
+                ],
+            )
+            .unwrap();
+    }
+}
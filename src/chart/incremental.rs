@@ -0,0 +1,192 @@
+use std::ops::Range;
+
+use super::ChartContext;
+use crate::coord::CoordTranslate;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+use plotters_backend::DrawingBackend;
+
+/// A region of the pixels, the range of the columns and the range of the rows
+pub type PixelRegion = (Range<i32>, Range<i32>);
+
+/// The cached pixels of an RGB bitmap, such as the background and the mesh of a chart, which are
+/// restored rather than drawn again for each frame of a realtime chart.
+///
+/// ```rust
+/// use plotters::chart::{CachedLayer, IncrementalLine};
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0u8; 640 * 480 * 3];
+/// let state = {
+///     let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+///     root.fill(&WHITE).unwrap();
+///     let mut chart = ChartBuilder::on(&root)
+///         .margin(10)
+///         .build_cartesian_2d(0.0..100.0, -1.0..1.0)
+///         .unwrap();
+///     chart.configure_mesh().disable_mesh().x_labels(0).y_labels(0).draw().unwrap();
+///     chart.into_chart_state()
+/// };
+/// let layer = CachedLayer::capture(&buffer, (640, 480));
+///
+/// let mut data = vec![];
+/// let mut line = IncrementalLine::new(&RED);
+/// for frame in 0..100 {
+///     data.push((frame as f64, (frame as f64 / 10.0).sin()));
+///     let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+///     let chart = state.clone().restore(&root);
+///     // Only the new segment is drawn, and only its region needs to be shown again
+///     let _dirty = line.draw_appended(&chart, &data).unwrap();
+/// }
+///
+/// // Start over from the background, for example, when the data is scrolled
+/// layer.restore(&mut buffer);
+/// line.reset();
+/// ```
+pub struct CachedLayer {
+    size: (u32, u32),
+    pixels: Vec<u8>,
+}
+
+impl CachedLayer {
+    /// Keep a copy of the RGB bitmap
+    /// - `buffer`: The RGB pixels of the bitmap
+    /// - `size`: The size of the bitmap
+    /// - **returns** The cached layer
+    pub fn capture(buffer: &[u8], size: (u32, u32)) -> Self {
+        Self {
+            size,
+            pixels: buffer[..(size.0 * size.1 * 3) as usize].to_vec(),
+        }
+    }
+
+    /// Restore the whole bitmap from the cache
+    pub fn restore(&self, buffer: &mut [u8]) {
+        buffer[..self.pixels.len()].copy_from_slice(&self.pixels);
+    }
+
+    /// Restore a region of the bitmap from the cache, for example, the plotting area that is
+    /// drawn again, the part of the region out of the bitmap is ignored
+    pub fn restore_region(&self, buffer: &mut [u8], (xs, ys): PixelRegion) {
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        let (x0, x1) = (xs.start.max(0), xs.end.min(w));
+        if x0 >= x1 {
+            return;
+        }
+        for y in ys.start.max(0)..ys.end.min(h) {
+            let start = ((y * w + x0) * 3) as usize;
+            let end = ((y * w + x1) * 3) as usize;
+            buffer[start..end].copy_from_slice(&self.pixels[start..end]);
+        }
+    }
+}
+
+/// A line series of growing data, which draws only the points appended since the last frame.
+///
+/// Since the line drawn for the previous frames is still on the bitmap, the cost of a frame
+/// depends on the new data rather than on all of it. See
+/// [CachedLayer](struct.CachedLayer.html) for an example.
+pub struct IncrementalLine {
+    style: ShapeStyle,
+    drawn: usize,
+}
+
+impl IncrementalLine {
+    /// Create a new line that hasn't drawn any point
+    pub fn new<S: Into<ShapeStyle>>(style: S) -> Self {
+        Self {
+            style: style.into(),
+            drawn: 0,
+        }
+    }
+
+    /// Forget the points that are drawn, thus the next frame draws the whole line, for example,
+    /// after the background is restored
+    pub fn reset(&mut self) {
+        self.drawn = 0;
+    }
+
+    /// Draw the points of the data that are appended since the last call, connected to the last
+    /// point that is drawn. If the data is shorter than before, the whole line is drawn again.
+    /// - `chart`: The chart of the frame
+    /// - `data`: All the data of the line, including what's drawn before
+    /// - **returns** The region of the pixels that is drawn, or `None` if nothing is drawn
+    pub fn draw_appended<DB: DrawingBackend, CT: CoordTranslate>(
+        &mut self,
+        chart: &ChartContext<DB, CT>,
+        data: &[CT::From],
+    ) -> Result<Option<PixelRegion>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        CT::From: Clone,
+    {
+        if data.len() < self.drawn {
+            self.drawn = 0;
+        }
+        if data.len() == self.drawn {
+            return Ok(None);
+        }
+        let points = &data[self.drawn.saturating_sub(1)..];
+        self.drawn = data.len();
+
+        let area = chart.plotting_area();
+        area.draw(&PathElement::new(points.to_vec(), self.style.clone()))?;
+
+        let pixels: Vec<_> = points.iter().map(|p| area.map_coordinate(p)).collect();
+        let reach = self.style.stroke_width as i32;
+        let (x0, y0, x1, y1) = pixels.iter().skip(1).fold(
+            (pixels[0].0, pixels[0].1, pixels[0].0, pixels[0].1),
+            |b, p| (b.0.min(p.0), b.1.min(p.1), b.2.max(p.0), b.3.max(p.1)),
+        );
+        Ok(Some((
+            x0 - reach..x1 + reach + 1,
+            y0 - reach..y1 + reach + 1,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_incremental_line() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path, vec![(0, 99), (50, 49)]));
+            m.check_draw_path(|_, _, path| assert_eq!(path, vec![(50, 49), (100, 0)]));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+
+        let mut line = IncrementalLine::new(&RED);
+        let mut data = vec![(0.0, 0.0), (0.5, 0.5)];
+        let dirty = line.draw_appended(&chart, &data).unwrap();
+        assert_eq!(dirty, Some((-1..52, 48..101)));
+        assert_eq!(line.draw_appended(&chart, &data).unwrap(), None);
+        data.push((1.0, 1.0));
+        assert_eq!(
+            line.draw_appended(&chart, &data).unwrap(),
+            Some((49..102, -2..51))
+        );
+    }
+
+    #[test]
+    fn test_cached_layer() {
+        let mut buffer: Vec<u8> = (0..4 * 3 * 3).map(|v| v as u8).collect();
+        let layer = CachedLayer::capture(&buffer, (4, 3));
+        for v in buffer.iter_mut() {
+            *v = 255;
+        }
+        layer.restore_region(&mut buffer, (1..3, 1..5));
+        assert_eq!(&buffer[..12], &[255; 12][..]);
+        assert_eq!(&buffer[12..15], &[255; 3][..]);
+        assert_eq!(&buffer[15..21], &[15, 16, 17, 18, 19, 20][..]);
+        assert_eq!(&buffer[33..36], &[255; 3][..]);
+        layer.restore(&mut buffer);
+        assert_eq!(buffer, (0..36).map(|v| v as u8).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,150 @@
+use super::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{PathElement, Rectangle};
+use crate::style::ShapeStyle;
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The corners of a pixel rectangle in the order of upper left, upper right, lower left and
+/// lower right
+fn corners((x0, y0): BackendCoord, (x1, y1): BackendCoord) -> [BackendCoord; 4] {
+    let (left, right) = (x0.min(x1), x0.max(x1));
+    let (top, bottom) = (y0.min(y1), y0.max(y1));
+    [(left, top), (right, top), (left, bottom), (right, bottom)]
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
+    /// Get the drawing area of an inset chart that covers the given rectangle of the data
+    /// coordinate, which is used with [ChartBuilder::on](struct.ChartBuilder.html#method.on) to
+    /// build a chart within the chart. The area is clipped to the plotting area.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..10.0, -1.0..1.0)
+    ///     .unwrap();
+    /// let data: Vec<_> = (0..1000).map(|x| x as f64 / 100.0).map(|x| (x, x.sin())).collect();
+    /// chart.draw_series(LineSeries::new(data.clone(), &BLUE)).unwrap();
+    ///
+    /// let inset = chart.inset([(6.0, 0.9), (9.5, 0.1)]);
+    /// let mut detail = ChartBuilder::on(&inset)
+    ///     .build_cartesian_2d(1.0..2.0, 0.8..1.0)
+    ///     .unwrap();
+    /// detail.plotting_area().fill(&WHITE).unwrap();
+    /// detail.draw_series(LineSeries::new(data, &BLUE)).unwrap();
+    /// chart
+    ///     .draw_inset_connector([(1.0, 1.0), (2.0, 0.8)], &inset, &BLACK)
+    ///     .unwrap();
+    /// ```
+    /// - `rect`: The two opposite corners of the inset in the data coordinate
+    /// - **returns** The drawing area of the inset
+    pub fn inset(&self, rect: [(X::ValueType, Y::ValueType); 2]) -> DrawingArea<DB, Shift> {
+        let [p, q] = rect;
+        let [upper_left, _, _, lower_right] = corners(
+            self.drawing_area.map_coordinate(&p),
+            self.drawing_area.map_coordinate(&q),
+        );
+        self.inset_pixels(upper_left, lower_right)
+    }
+
+    /// Get the drawing area of an inset chart in the fractions of the plotting area, see
+    /// [ChartContext::inset](#method.inset)
+    /// - `pos`: The upper left corner of the inset, `(0.0, 0.0)` is the upper left corner of the
+    ///   plotting area
+    /// - `size`: The width and the height of the inset
+    /// - **returns** The drawing area of the inset
+    pub fn inset_relative(&self, pos: (f64, f64), size: (f64, f64)) -> DrawingArea<DB, Shift> {
+        let (x, y) = self.drawing_area.get_pixel_range();
+        let (w, h) = (f64::from(x.end - x.start), f64::from(y.end - y.start));
+        let pixel = |(fx, fy): (f64, f64)| {
+            (
+                x.start + (fx * w).round() as i32,
+                y.start + (fy * h).round() as i32,
+            )
+        };
+        self.inset_pixels(pixel(pos), pixel((pos.0 + size.0, pos.1 + size.1)))
+    }
+
+    fn inset_pixels(
+        &self,
+        (x0, y0): BackendCoord,
+        (x1, y1): BackendCoord,
+    ) -> DrawingArea<DB, Shift> {
+        let (bx, by) = self.drawing_area.get_base_pixel();
+        self.drawing_area
+            .strip_coord_spec()
+            .shrink((x0 - bx, y0 - by), (x1 - x0, y1 - y0))
+    }
+
+    /// Outline the source region of an inset and link it to the inset with the lines between
+    /// the two nearest pairs of the corresponding corners
+    /// - `source`: The two opposite corners of the zoomed region in the data coordinate
+    /// - `inset`: The drawing area of the inset
+    /// - `style`: The style of the outline and the lines
+    pub fn draw_inset_connector<S: Into<ShapeStyle>>(
+        &self,
+        source: [(X::ValueType, Y::ValueType); 2],
+        inset: &DrawingArea<DB, Shift>,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let [p, q] = source;
+        let from = corners(
+            self.drawing_area.map_coordinate(&p),
+            self.drawing_area.map_coordinate(&q),
+        );
+        let (x, y) = inset.get_pixel_range();
+        let to = corners((x.start, y.start), (x.end - 1, y.end - 1));
+
+        let area = self.drawing_area.use_screen_coord();
+        area.draw(&Rectangle::new([from[0], from[3]], style.clone()))?;
+
+        let mut links: Vec<_> = from.iter().zip(to.iter()).collect();
+        links.sort_by_key(|(a, b)| {
+            let (dx, dy) = (i64::from(a.0 - b.0), i64::from(a.1 - b.1));
+            dx * dx + dy * dy
+        });
+        for (a, b) in links.into_iter().take(2) {
+            area.draw(&PathElement::new(vec![*a, *b], style.clone()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_inset() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_rect(|_, _, _, upper_left, lower_right| {
+                assert_eq!((upper_left, lower_right), ((40, 279), (120, 359)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+
+        let inset = chart.inset([(9.0, 6.0), (6.0, 9.0)]);
+        assert_eq!(inset.get_base_pixel(), chart.backend_coord(&(6.0, 9.0)));
+        assert_eq!(inset.dim_in_pixel(), (120, 120));
+        let relative = chart.inset_relative((0.5, 0.25), (0.25, 0.5));
+        assert_eq!(relative.get_base_pixel(), (200, 100));
+        assert_eq!(relative.dim_in_pixel(), (100, 200));
+
+        chart
+            .draw_inset_connector([(1.0, 1.0), (3.0, 3.0)], &inset, &BLACK)
+            .unwrap();
+    }
+}
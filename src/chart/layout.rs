@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+use super::state::LabelAreaLayout;
+use super::{ChartContext, LabelAreaPosition};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The number of the labels of the mesh by default
+const DEFAULT_LABELS: usize = 10;
+
+/// The geometry of a chart, computed by the layout without drawing the chart, see
+/// [ChartContext::layout](struct.ChartContext.html#method.layout).
+///
+/// All the positions are in pixels, relative to the upper-left corner of the area the chart is
+/// built on, thus they are the same on any backend of the same size, including the
+/// [NullBackend](../drawing/struct.NullBackend.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartLayout {
+    /// The size of the area the chart is built on
+    pub root_size: (u32, u32),
+    /// The position and the size of the plotting area
+    pub plotting_area: (BackendCoord, (u32, u32)),
+    /// The position and the size of the label areas, in the order of top, bottom, left and
+    /// right, `None` for the label area that the chart doesn't have
+    pub label_areas: [LabelAreaLayout; 4],
+    /// The X pixels of the ticks of the X axis, with the text of their labels
+    pub x_ticks: Vec<(i32, String)>,
+    /// The Y pixels of the ticks of the Y axis, with the text of their labels
+    pub y_ticks: Vec<(i32, String)>,
+}
+
+impl ChartLayout {
+    /// Get the position and the size of a label area
+    pub fn label_area(&self, pos: LabelAreaPosition) -> LabelAreaLayout {
+        self.label_areas[pos as usize]
+    }
+
+    /// Check if the pixel is inside the plotting area, which is used for hit-testing the pointer
+    /// events of a GUI host
+    pub fn in_plotting_area(&self, (x, y): BackendCoord) -> bool {
+        let ((x0, y0), (w, h)) = self.plotting_area;
+        x >= x0 && y >= y0 && x < x0 + w as i32 && y < y0 + h as i32
+    }
+}
+
+impl<'a, DB, XT, YT, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    DB: DrawingBackend,
+    X: Ranged<ValueType = XT> + ValueFormatter<XT>,
+    Y: Ranged<ValueType = YT> + ValueFormatter<YT>,
+{
+    /// Get the geometry of the chart, with the ticks of a mesh that has the default number of
+    /// the labels. Nothing is drawn, thus the chart can be built on a
+    /// [NullBackend](../drawing/struct.NullBackend.html) to size the widgets, place the
+    /// overlays and hit-test the pointer before the first real draw. The chart state, see
+    /// [into_chart_state](#method.into_chart_state), restores the same layout on the real
+    /// backend.
+    ///
+    /// ```rust
+    /// use plotters::drawing::NullBackend;
+    /// use plotters::prelude::*;
+    ///
+    /// let root = NullBackend::new((640, 480)).into_drawing_area();
+    /// let chart = ChartBuilder::on(&root)
+    ///     .caption("Latency", ("sans-serif", 20))
+    ///     .set_all_label_area_size(40)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+    ///     .unwrap();
+    /// let layout = chart.layout();
+    /// let ((x, y), (w, h)) = layout.plotting_area;
+    /// assert_eq!((x, w), (40, 560));
+    /// assert!(y > 0 && y + h as i32 == 440);
+    /// for (pixel, _label) in &layout.x_ticks {
+    ///     assert!(x <= *pixel && *pixel <= x + w as i32);
+    /// }
+    /// let state = chart.into_chart_state();
+    /// ```
+    pub fn layout(&self) -> ChartLayout {
+        self.layout_with_labels(DEFAULT_LABELS, DEFAULT_LABELS)
+    }
+
+    /// Get the geometry of the chart, with the ticks of a mesh that has the given numbers of the
+    /// labels, see [MeshStyle::x_labels](struct.MeshStyle.html#method.x_labels)
+    /// - `x_labels`: The max number of the labels of the X axis
+    /// - `y_labels`: The max number of the labels of the Y axis
+    pub fn layout_with_labels(&self, x_labels: usize, y_labels: usize) -> ChartLayout {
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let origin = (x0 - self.drawing_area_pos.0, y0 - self.drawing_area_pos.1);
+        let spec = self.as_coord_spec();
+        let (x_limits, y_limits) = spec.pixel_limits();
+        // The ticks out of the axis are dropped, like the labels of the mesh
+        let visible = |p: i32, range: Range<i32>| {
+            range.start.min(range.end) <= p && p <= range.start.max(range.end)
+        };
+        let x_ticks = spec
+            .x_spec()
+            .key_points(x_labels)
+            .into_iter()
+            .map(|x| (spec.x_spec().map(&x, x_limits), x))
+            .filter(|(pixel, _)| visible(*pixel, self.drawing_area.get_x_axis_pixel_range()))
+            .map(|(pixel, x)| (pixel - origin.0, spec.x_spec().format_ext(&x)))
+            .collect();
+        let y_ticks = spec
+            .y_spec()
+            .key_points(y_labels)
+            .into_iter()
+            .map(|y| (spec.y_spec().map(&y, y_limits), y))
+            .filter(|(pixel, _)| visible(*pixel, self.drawing_area.get_y_axis_pixel_range()))
+            .map(|(pixel, y)| (pixel - origin.1, spec.y_spec().format_ext(&y)))
+            .collect();
+        ChartLayout {
+            root_size: self.root_area_size,
+            plotting_area: (self.drawing_area_pos, self.drawing_area.dim_in_pixel()),
+            label_areas: self.label_area_layout(),
+            x_ticks,
+            y_ticks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::drawing::NullBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_layout() {
+        let root = NullBackend::new((400, 300)).into_drawing_area();
+        let (_, right) = root.split_horizontally(100);
+        let chart = ChartBuilder::on(&right)
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0..10, 0.0..1.0)
+            .unwrap();
+        let layout = chart.layout_with_labels(3, 2);
+        // The positions are relative to the area that the chart is built on
+        assert_eq!(layout.root_size, (300, 300));
+        assert_eq!(layout.plotting_area, ((50, 10), (240, 250)));
+        assert_eq!(
+            layout.label_area(LabelAreaPosition::Bottom),
+            Some(((50, 260), (240, 30)))
+        );
+        assert_eq!(layout.label_area(LabelAreaPosition::Top), None);
+        let x_ticks: Vec<_> = layout
+            .x_ticks
+            .iter()
+            .map(|(x, s)| (*x, s.as_str()))
+            .collect();
+        assert_eq!(x_ticks, vec![(50, "0"), (170, "5"), (290, "10")]);
+        // The tick of 1.0 is just above the axis, thus the mesh doesn't label it
+        assert_eq!(layout.y_ticks, vec![(134, "0.5".to_string())]);
+        assert!(layout.in_plotting_area((50, 10)));
+        assert!(!layout.in_plotting_area((290, 100)));
+
+        // The same chart on a real backend has the same layout
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        let (_, right) = drawing_area.split_horizontally(100);
+        let restored = chart.into_chart_state().restore(&right);
+        assert_eq!(restored.layout_with_labels(3, 2), layout);
+    }
+}
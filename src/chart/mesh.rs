@@ -1,17 +1,135 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use super::builder::LabelAreaPosition;
 use super::context::ChartContext;
+use super::overflow::Overflow;
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
-use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
-use crate::drawing::DrawingAreaErrorKind;
-use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, TickLocator, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+use crate::drawing::{DrawingAreaErrorKind, Layer, LayerRecorder};
+use crate::style::formatters::{
+    format_scaled, offset_exponent, tick_step, AxisUnit, OffsetFormatter, TickFormatter,
 };
+use crate::style::{AsRelative, IntoTextStyle, RichText, ShapeStyle, SizeDesc, TextStyle};
 
+use num_traits::ToPrimitive;
 use plotters_backend::DrawingBackend;
 
+/// A label formatter function
+type LabelFormatter<'b, T> = &'b dyn Fn(&T) -> String;
+
+/// The chart context that draws onto a layer of the chart
+type OnLayer<'a, DB, X, Y> = fn(
+    &ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    Layer,
+) -> ChartContext<'a, LayerRecorder<DB>, Cartesian2d<X, Y>>;
+
+/// The conversion from the axis value to a number
+type ToNumber<T> = fn(&T) -> Option<f64>;
+
+/// A tick formatter along with the conversion from the axis value to a number
+type TickFormatterRef<'b, T> = (&'b dyn TickFormatter, ToNumber<T>);
+
+/// A unit along with the conversions between the axis value and a number
+type UnitRef<T> = (AxisUnit, ToNumber<T>, fn(f64) -> Option<T>);
+
+fn from_number<T: num_traits::NumCast>(value: f64) -> Option<T> {
+    <T as num_traits::NumCast>::from(value)
+}
+
+/// The tick locator of an axis with a unit, which places the ticks at the round values in the
+/// prefixed unit, e.g. every 0.5MiB instead of every 500000 bytes
+struct UnitLocator<T> {
+    scale: f64,
+    to_f64: ToNumber<T>,
+    from_f64: fn(f64) -> Option<T>,
+}
+
+/// Choose the prefix of the unit by the range of the axis
+/// - **returns**: The prefixed unit and the tick locator in the prefixed unit
+fn unit_locator<T>(unit: &UnitRef<T>, range: Range<T>) -> (String, UnitLocator<T>) {
+    let bounds = [(unit.1)(&range.start), (unit.1)(&range.end)];
+    let (scale, prefixed) = unit.0.prefix_for(bounds.iter().filter_map(|v| *v));
+    let locator = UnitLocator {
+        scale,
+        to_f64: unit.1,
+        from_f64: unit.2,
+    };
+    (prefixed, locator)
+}
+
+impl<T> TickLocator<T> for UnitLocator<T> {
+    fn locate(&self, range: &Range<T>, max_points: usize) -> Vec<T> {
+        let (start, end) = match ((self.to_f64)(&range.start), (self.to_f64)(&range.end)) {
+            (Some(start), Some(end)) => (start / self.scale, end / self.scale),
+            _ => return vec![],
+        };
+        let coord: RangedCoordf64 = (start..end).into();
+        coord
+            .key_points(max_points)
+            .into_iter()
+            .filter_map(|v| (self.from_f64)(v * self.scale))
+            .collect()
+    }
+
+    fn locate_light(&self, range: &Range<T>, max_points: usize) -> Vec<T> {
+        self.locate(range, max_points)
+    }
+}
+
+/// Append the offset of the offset notation to the axis description, e.g. `Count (×10⁶)`
+fn offset_desc(desc: Option<RichText>, exponent: Option<i32>) -> Option<RichText> {
+    match (desc, exponent) {
+        (Some(desc), Some(exponent)) => Some(
+            desc.text(" (×10")
+                .superscript(exponent.to_string())
+                .text(")"),
+        ),
+        (None, Some(exponent)) => Some(
+            RichText::new()
+                .text("×10")
+                .superscript(exponent.to_string()),
+        ),
+        (desc, None) => desc,
+    }
+}
+
+/// Append the prefixed unit to the axis description, e.g. `Size (MiB)`
+fn unit_desc(desc: Option<RichText>, unit: &str) -> RichText {
+    match desc {
+        Some(desc) => desc.text(format!(" ({})", unit)),
+        None => RichText::new().text(unit),
+    }
+}
+
+/// Get the key points of the axis from the tick locator if there's one, otherwise from the
+/// coordinate. The key points are the labeled ones unless the number of the minor ticks per label
+/// is given. The labeled key points of a zoomed axis that is narrower than one tick step are the
+/// ends of the axis, thus the axis still has two labels.
+fn axis_key_points<R: Ranged>(
+    spec: &R,
+    locator: Option<&dyn TickLocator<R::ValueType>>,
+    labels: usize,
+    minor: Option<usize>,
+    zoomed: bool,
+) -> Vec<R::ValueType> {
+    let points = match (locator, minor) {
+        (Some(locator), None) => locator.locate(&spec.range(), labels),
+        (Some(locator), Some(minor)) => locator.locate_light(&spec.range(), labels * minor),
+        (None, None) => spec.key_points(BoldPoints(labels)),
+        (None, Some(minor)) => spec.key_points(LightPoints::new(labels, labels * minor)),
+    };
+    if zoomed && minor.is_none() && labels >= 2 && points.len() < 2 {
+        let range = spec.range();
+        // The ends of an empty range are mapped to the same pixel
+        if spec.map(&range.start, (0, 1 << 20)) != spec.map(&range.end, (0, 1 << 20)) {
+            return vec![range.start, range.end];
+        }
+    }
+    points
+}
+
 /// The style used to describe the mesh and axis for a secondary coordinate system.
 pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     style: MeshStyle<'a, 'b, X, Y, DB>,
@@ -83,6 +201,84 @@ where
         self
     }
 
+    /// Set the formatter function for the labels on the top X axis, see
+    /// [MeshStyle::top_label_formatter](struct.MeshStyle.html#method.top_label_formatter)
+    /// - `fmt`: The formatter function
+    pub fn top_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
+        self.style.top_label_formatter(fmt);
+        self
+    }
+
+    /// Set the formatter function for the labels on the right Y axis, see
+    /// [MeshStyle::right_label_formatter](struct.MeshStyle.html#method.right_label_formatter)
+    /// - `fmt`: The formatter function
+    pub fn right_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
+        self.style.right_label_formatter(fmt);
+        self
+    }
+
+    /// Set the tick formatter for the X label text, see
+    /// [MeshStyle::x_tick_formatter](struct.MeshStyle.html#method.x_tick_formatter)
+    /// - `fmt`: The tick formatter
+    pub fn x_tick_formatter(&mut self, fmt: &'b dyn TickFormatter) -> &mut Self
+    where
+        XT: ToPrimitive,
+    {
+        self.style.x_tick_formatter(fmt);
+        self
+    }
+
+    /// Set the tick formatter for the Y label text, see
+    /// [MeshStyle::y_tick_formatter](struct.MeshStyle.html#method.y_tick_formatter)
+    /// - `fmt`: The tick formatter
+    pub fn y_tick_formatter(&mut self, fmt: &'b dyn TickFormatter) -> &mut Self
+    where
+        YT: ToPrimitive,
+    {
+        self.style.y_tick_formatter(fmt);
+        self
+    }
+
+    /// Use the offset notation for the secondary X axis, see
+    /// [MeshStyle::x_offset_notation](struct.MeshStyle.html#method.x_offset_notation)
+    pub fn x_offset_notation(&mut self) -> &mut Self
+    where
+        XT: ToPrimitive,
+    {
+        self.style.x_offset_notation();
+        self
+    }
+
+    /// Use the offset notation for the secondary Y axis, see
+    /// [MeshStyle::x_offset_notation](struct.MeshStyle.html#method.x_offset_notation)
+    pub fn y_offset_notation(&mut self) -> &mut Self
+    where
+        YT: ToPrimitive,
+    {
+        self.style.y_offset_notation();
+        self
+    }
+
+    /// Set the unit of the secondary X axis, see
+    /// [MeshStyle::x_unit](struct.MeshStyle.html#method.x_unit)
+    pub fn x_unit<U: Into<AxisUnit>>(&mut self, unit: U) -> &mut Self
+    where
+        XT: ToPrimitive + num_traits::NumCast,
+    {
+        self.style.x_unit(unit);
+        self
+    }
+
+    /// Set the unit of the secondary Y axis, see
+    /// [MeshStyle::x_unit](struct.MeshStyle.html#method.x_unit)
+    pub fn y_unit<U: Into<AxisUnit>>(&mut self, unit: U) -> &mut Self
+    where
+        YT: ToPrimitive + num_traits::NumCast,
+    {
+        self.style.y_unit(unit);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -93,18 +289,29 @@ where
 
     /// Set the X axis's description
     /// - `desc`: The description of the X axis
-    pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+    pub fn x_desc<T: Into<RichText>>(&mut self, desc: T) -> &mut Self {
         self.style.x_desc(desc);
         self
     }
 
     /// Set the Y axis's description
     /// - `desc`: The description of the Y axis
-    pub fn y_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+    pub fn y_desc<T: Into<RichText>>(&mut self, desc: T) -> &mut Self {
         self.style.y_desc(desc);
         self
     }
 
+    /// Draw the secondary axes onto a layer, see
+    /// [MeshStyle::layer](struct.MeshStyle.html#method.layer)
+    pub fn layer(&mut self, layer: Layer) -> &mut Self
+    where
+        X: Clone,
+        Y: Clone,
+    {
+        self.style.layer(layer);
+        self
+    }
+
     /// Draw the axes for the secondary coordinate system
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         self.style.draw()
@@ -116,6 +323,44 @@ where
         self
     }
 
+    /// Set the tick locator for the secondary X axis, see
+    /// [MeshStyle::x_tick_locator](struct.MeshStyle.html#method.x_tick_locator)
+    /// - `locator`: The tick locator
+    pub fn x_tick_locator(&mut self, locator: &'b dyn TickLocator<XT>) -> &mut Self {
+        self.style.x_tick_locator(locator);
+        self
+    }
+
+    /// Set the tick locator for the secondary Y axis, see
+    /// [MeshStyle::x_tick_locator](struct.MeshStyle.html#method.x_tick_locator)
+    /// - `locator`: The tick locator
+    pub fn y_tick_locator(&mut self, locator: &'b dyn TickLocator<YT>) -> &mut Self {
+        self.style.y_tick_locator(locator);
+        self
+    }
+
+    /// Draw the minor ticks between the labels, see
+    /// [MeshStyle::minor_ticks](struct.MeshStyle.html#method.minor_ticks)
+    /// - `n`: The max number of the minor ticks per label
+    pub fn minor_ticks(&mut self, n: usize) -> &mut Self {
+        self.style.minor_ticks(n);
+        self
+    }
+
+    /// Set what to do with the X labels that would overlap each other, see
+    /// [MeshStyle::x_label_overflow](struct.MeshStyle.html#method.x_label_overflow)
+    pub fn x_label_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.style.x_label_overflow(overflow);
+        self
+    }
+
+    /// Set what to do with the Y labels that would overlap each other, see
+    /// [MeshStyle::y_label_overflow](struct.MeshStyle.html#method.y_label_overflow)
+    pub fn y_label_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.style.y_label_overflow(overflow);
+        self
+    }
+
     /// Set all the tick mark to the same size
     /// `value`: The new size
     pub fn set_all_tick_mark_size<S: SizeDesc>(&mut self, value: S) -> &mut Self {
@@ -152,19 +397,34 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) n_x_labels: usize,
     pub(super) n_y_labels: usize,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
-    pub(super) x_desc: Option<String>,
-    pub(super) y_desc: Option<String>,
+    pub(super) x_desc: Option<RichText>,
+    pub(super) y_desc: Option<RichText>,
     pub(super) bold_line_style: Option<ShapeStyle>,
     pub(super) light_line_style: Option<ShapeStyle>,
+    pub(super) minor_grid_style: Option<ShapeStyle>,
+    pub(super) minor_ticks: Option<usize>,
+    pub(super) x_tick_locator: Option<&'b dyn TickLocator<X::ValueType>>,
+    pub(super) y_tick_locator: Option<&'b dyn TickLocator<Y::ValueType>>,
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) x_label_style: Option<TextStyle<'b>>,
     pub(super) y_label_style: Option<TextStyle<'b>>,
-    pub(super) format_x: &'b dyn Fn(&X::ValueType) -> String,
-    pub(super) format_y: &'b dyn Fn(&Y::ValueType) -> String,
+    pub(super) format_x: Option<LabelFormatter<'b, X::ValueType>>,
+    pub(super) format_y: Option<LabelFormatter<'b, Y::ValueType>>,
+    pub(super) tick_format_x: Option<TickFormatterRef<'b, X::ValueType>>,
+    pub(super) tick_format_y: Option<TickFormatterRef<'b, Y::ValueType>>,
+    pub(super) x_offset_notation: Option<ToNumber<X::ValueType>>,
+    pub(super) y_offset_notation: Option<ToNumber<Y::ValueType>>,
+    pub(super) x_unit: Option<UnitRef<X::ValueType>>,
+    pub(super) y_unit: Option<UnitRef<Y::ValueType>>,
+    pub(super) format_top_x: Option<LabelFormatter<'b, X::ValueType>>,
+    pub(super) format_right_y: Option<LabelFormatter<'b, Y::ValueType>>,
+    pub(super) format_x_period: Option<LabelFormatter<'b, X::ValueType>>,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
     pub(super) y_tick_size: [i32; 2],
+    pub(super) label_overflow: [Option<Overflow>; 2],
+    pub(super) layer: Option<(Layer, OnLayer<'a, DB, X, Y>)>,
 }
 
 impl<'a, 'b, X, Y, XT, YT, DB> MeshStyle<'a, 'b, X, Y, DB>
@@ -201,10 +461,23 @@ where
             n_y_labels: 10,
             bold_line_style: None,
             light_line_style: None,
+            minor_grid_style: None,
+            minor_ticks: None,
+            x_tick_locator: None,
+            y_tick_locator: None,
             x_label_style: None,
             y_label_style: None,
-            format_x: &X::format,
-            format_y: &Y::format,
+            format_x: None,
+            format_y: None,
+            tick_format_x: None,
+            tick_format_y: None,
+            x_offset_notation: None,
+            y_offset_notation: None,
+            x_unit: None,
+            y_unit: None,
+            format_top_x: None,
+            format_right_y: None,
+            format_x_period: None,
             target: Some(chart),
             _phantom_data: PhantomData,
             x_desc: None,
@@ -212,14 +485,16 @@ where
             axis_desc_style: None,
             x_tick_size,
             y_tick_size,
+            label_overflow: [None, None],
+            layer: None,
         }
     }
 }
 
 impl<'a, 'b, X, Y, DB> MeshStyle<'a, 'b, X, Y, DB>
 where
-    X: Ranged,
-    Y: Ranged,
+    X: Ranged + ValueFormatter<<X as Ranged>::ValueType>,
+    Y: Ranged + ValueFormatter<<Y as Ranged>::ValueType>,
     DB: DrawingBackend,
 {
     /// Set all the tick mark to the same size
@@ -268,6 +543,40 @@ where
         self
     }
 
+    /// Set what to do with the X labels that would overlap each other when they are measured,
+    /// such as the labels of a dense date and time axis. By default, all the labels are drawn.
+    /// The label area isn't resized, thus the rotated or the wrapped labels may need a larger
+    /// [ChartBuilder::x_label_area_size](struct.ChartBuilder.html#method.x_label_area_size).
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (320, 240)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(60)
+    ///     .build_cartesian_2d(0..1_000_000, 0.0..1.0)
+    ///     .unwrap();
+    /// chart
+    ///     .configure_mesh()
+    ///     .x_labels(30)
+    ///     .x_label_overflow(Overflow::Rotate(45.0))
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    pub fn x_label_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.label_overflow[0] = Some(overflow);
+        self
+    }
+
+    /// Set what to do with the Y labels that would overlap each other, which are always thinned
+    /// since they are stacked vertically, see
+    /// [MeshStyle::x_label_overflow](struct.MeshStyle.html#method.x_label_overflow)
+    pub fn y_label_overflow(&mut self, overflow: Overflow) -> &mut Self {
+        self.label_overflow[1] = Some(overflow);
+        self
+    }
+
     /// Disable the mesh for the x axis.
     pub fn disable_x_mesh(&mut self) -> &mut Self {
         self.draw_x_mesh = false;
@@ -336,6 +645,40 @@ where
         self
     }
 
+    /// Draw the minor ticks between the labels, which are shorter tick marks at the fine grid
+    /// lines. The fine grid lines are the points that the coordinate suggests between the
+    /// labels, such as the multiples 2 to 9 of each power on a log axis.
+    /// - `n`: The max number of the minor ticks per label
+    pub fn minor_ticks(&mut self, n: usize) -> &mut Self {
+        self.minor_ticks = Some(n.max(1));
+        self
+    }
+
+    /// Set the tick locator for the X axis, which decides where the labeled ticks and the fine
+    /// grid lines are instead of the coordinate. The labels are still formatted by the label
+    /// formatter.
+    /// - `locator`: The tick locator, see [TickLocator](../coord/ranged1d/trait.TickLocator.html)
+    pub fn x_tick_locator(&mut self, locator: &'b dyn TickLocator<X::ValueType>) -> &mut Self {
+        self.x_tick_locator = Some(locator);
+        self
+    }
+
+    /// Set the tick locator for the Y axis, see
+    /// [MeshStyle::x_tick_locator](#method.x_tick_locator)
+    /// - `locator`: The tick locator
+    pub fn y_tick_locator(&mut self, locator: &'b dyn TickLocator<Y::ValueType>) -> &mut Self {
+        self.y_tick_locator = Some(locator);
+        self
+    }
+
+    /// Set the style for the minor grid lines, which overrides the fine grid style when the
+    /// minor ticks are enabled with [minor_ticks](#method.minor_ticks)
+    /// - `style`: The minor grid line style
+    pub fn minor_grid_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.minor_grid_style = Some(style.into());
+        self
+    }
+
     /// Set the style of the label text
     /// - `style`: The text style that would be applied to the labels
     pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -345,7 +688,9 @@ where
         self
     }
 
-    /// Set the style of the label X axis text
+    /// Set the style of the label X axis text. The labels that are rotated, for example with
+    /// `TextStyle::rotate(-45.0)` for the long category names, are anchored by the end that is
+    /// closest to the axis.
     /// - `style`: The text style that would be applied to the labels
     pub fn x_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
         self.x_label_style = Some(style.into_text_style(&self.parent_size));
@@ -362,14 +707,141 @@ where
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
-        self.format_x = fmt;
+        self.format_x = Some(fmt);
         self
     }
 
     /// Set the formatter function for the Y label text
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
-        self.format_y = fmt;
+        self.format_y = Some(fmt);
+        self
+    }
+
+    /// Set the formatter function for the labels on the top X axis. This is useful when the
+    /// mirrored axis shows a transformed scale of the same tick positions, e.g. the energy for
+    /// the wavelength on the bottom axis. If not set, the top axis uses the same labels as the
+    /// bottom axis. The top labels are drawn only if the chart has a top label area, see
+    /// [ChartBuilder::x_labels_on_top](struct.ChartBuilder.html#method.x_labels_on_top)
+    /// - `fmt`: The formatter function
+    pub fn top_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
+        self.format_top_x = Some(fmt);
+        self
+    }
+
+    /// Set the formatter function for the labels on the right Y axis. If not set, the right axis
+    /// uses the same labels as the left axis. The right labels are drawn only if the chart has a
+    /// right label area, see
+    /// [ChartBuilder::y_labels_on_right](struct.ChartBuilder.html#method.y_labels_on_right)
+    /// - `fmt`: The formatter function
+    pub fn right_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
+        self.format_right_y = Some(fmt);
+        self
+    }
+
+    /// Set the formatter of the coarser period of the X labels, which makes the labels
+    /// hierarchical: the period is drawn on a second row below the bottom X labels, only at the
+    /// first label and where the period changes, thus the labels of a date axis show the hours or
+    /// the days in the first row and the months or the years in the second row. The X label area
+    /// should be tall enough for both rows.
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, TimeZone, Utc};
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let start = Utc.with_ymd_and_hms(2020, 1, 20, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2020, 2, 10, 0, 0, 0).unwrap();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(50)
+    ///     .y_label_area_size(30)
+    ///     .build_cartesian_2d(start..end, 0..10)
+    ///     .unwrap();
+    /// chart
+    ///     .configure_mesh()
+    ///     .x_label_formatter(&|t: &DateTime<Utc>| t.format("%d").to_string())
+    ///     .x_period_formatter(&|t: &DateTime<Utc>| t.format("%b %Y").to_string())
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `fmt`: The formatter of the period of a label
+    pub fn x_period_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
+        self.format_x_period = Some(fmt);
+        self
+    }
+
+    /// Set the tick formatter for the X label text. Unlike the label formatter, the tick
+    /// formatter is aware of the distance between adjacent ticks, thus it's able to pick a
+    /// precision that keeps the labels distinct. This overrides the label formatter.
+    /// See [formatters](../style/formatters/index.html) for the available formatters.
+    /// - `fmt`: The tick formatter
+    pub fn x_tick_formatter(&mut self, fmt: &'b dyn TickFormatter) -> &mut Self
+    where
+        X::ValueType: ToPrimitive,
+    {
+        self.tick_format_x = Some((fmt, ToPrimitive::to_f64));
+        self
+    }
+
+    /// Set the tick formatter for the Y label text. This overrides the label formatter.
+    /// See [MeshStyle::x_tick_formatter](#method.x_tick_formatter) for details.
+    /// - `fmt`: The tick formatter
+    pub fn y_tick_formatter(&mut self, fmt: &'b dyn TickFormatter) -> &mut Self
+    where
+        Y::ValueType: ToPrimitive,
+    {
+        self.tick_format_y = Some((fmt, ToPrimitive::to_f64));
+        self
+    }
+
+    /// Use the offset notation for the X labels: when the labels are large or tiny, they are
+    /// divided by a common power of 10, which is shown once in the axis description, e.g. the
+    /// labels `1`, `2` and `3` with `×10⁶`. This overrides the label formatter and the tick
+    /// formatter.
+    pub fn x_offset_notation(&mut self) -> &mut Self
+    where
+        X::ValueType: ToPrimitive,
+    {
+        self.x_offset_notation = Some(ToPrimitive::to_f64);
+        self
+    }
+
+    /// Use the offset notation for the Y labels.
+    /// See [MeshStyle::x_offset_notation](#method.x_offset_notation) for details.
+    pub fn y_offset_notation(&mut self) -> &mut Self
+    where
+        Y::ValueType: ToPrimitive,
+    {
+        self.y_offset_notation = Some(ToPrimitive::to_f64);
+        self
+    }
+
+    /// Set the unit of the X axis: the labels are rescaled with the prefix of the unit that
+    /// suits the range of the axis, and the prefixed unit is shown once in the axis description,
+    /// e.g. the labels `0.5` and `1` with `Size (MiB)`. Unless there's a tick locator, the ticks
+    /// are placed at the round values in the prefixed unit. A string is the unit of the same symbol
+    /// with the SI prefixes, except `"bytes"`, which are the bytes with the binary prefixes, see
+    /// [AxisUnit](../style/formatters/struct.AxisUnit.html). This overrides the label formatter,
+    /// the tick formatter and the offset notation.
+    /// - `unit`: The unit of the axis
+    pub fn x_unit<U: Into<AxisUnit>>(&mut self, unit: U) -> &mut Self
+    where
+        X::ValueType: ToPrimitive + num_traits::NumCast,
+    {
+        self.x_unit = Some((unit.into(), ToPrimitive::to_f64, from_number));
+        self
+    }
+
+    /// Set the unit of the Y axis.
+    /// See [MeshStyle::x_unit](#method.x_unit) for details.
+    /// - `unit`: The unit of the axis
+    pub fn y_unit<U: Into<AxisUnit>>(&mut self, unit: U) -> &mut Self
+    where
+        Y::ValueType: ToPrimitive + num_traits::NumCast,
+    {
+        self.y_unit = Some((unit.into(), ToPrimitive::to_f64, from_number));
         self
     }
 
@@ -382,41 +854,111 @@ where
 
     /// Set the X axis's description
     /// - `desc`: The description of the X axis
-    pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+    pub fn x_desc<T: Into<RichText>>(&mut self, desc: T) -> &mut Self {
         self.x_desc = Some(desc.into());
         self
     }
 
     /// Set the Y axis's description
     /// - `desc`: The description of the Y axis
-    pub fn y_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+    pub fn y_desc<T: Into<RichText>>(&mut self, desc: T) -> &mut Self {
         self.y_desc = Some(desc.into());
         self
     }
 
+    /// Draw the mesh onto a layer, so that the grid lines and the labels are drawn on the top of
+    /// the layers below regardless of the order of the drawing calls, e.g. above the filled
+    /// areas on the background layer, see [Layer](../drawing/enum.Layer.html) for details
+    /// - `layer`: The layer to draw on
+    pub fn layer(&mut self, layer: Layer) -> &mut Self
+    where
+        X: Clone,
+        Y: Clone,
+    {
+        self.layer = Some((layer, ChartContext::on_layer));
+        self
+    }
+
     /// Draw the configured mesh on the target plot
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let mut target = None;
         std::mem::swap(&mut target, &mut self.target);
         let target = target.unwrap();
 
-        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
-        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
-        let default_axis_color = RGBColor(0, 0, 0);
-        let default_label_font = FontDesc::new(
-            FontFamily::SansSerif,
-            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
-            FontStyle::Normal,
-        );
+        match self.layer {
+            Some((layer, on_layer)) => self.draw_on(&mut on_layer(target, layer)),
+            None => self.draw_on(target),
+        }
+    }
+
+    fn draw_on<DB2: DrawingBackend<ErrorType = DB::ErrorType>>(
+        &self,
+        target: &mut ChartContext<'a, DB2, Cartesian2d<X, Y>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let theme = *target.theme();
+        let default_mesh_color_1 = theme.bold_grid;
+        let default_mesh_color_2 = theme.light_grid;
+        let default_axis_color = theme.foreground;
+        let default_label_style = theme.text_style(f64::from(
+            (12i32).percent().max(12).in_pixels(&self.parent_size),
+        ));
 
         let bold_style = self
             .bold_line_style
             .clone()
             .unwrap_or_else(|| (&default_mesh_color_1).into());
         let light_style = self
-            .light_line_style
-            .clone()
+            .minor_ticks
+            .and(self.minor_grid_style.clone())
+            .or_else(|| self.light_line_style.clone())
             .unwrap_or_else(|| (&default_mesh_color_2).into());
+        let minor_per_label = Some(self.minor_ticks.unwrap_or(10));
+        let (n_x_labels, n_y_labels) = (self.n_x_labels, self.n_y_labels);
+        // The prefix of a unit is chosen by the range of the axis, and the ticks are placed at the
+        // round values in the prefixed unit unless there's a tick locator
+        let spec = target.as_coord_spec();
+        let x_unit = self
+            .x_unit
+            .as_ref()
+            .map(|unit| unit_locator(unit, spec.x_spec().range()));
+        let y_unit = self
+            .y_unit
+            .as_ref()
+            .map(|unit| unit_locator(unit, spec.y_spec().range()));
+        let x_locator: Option<&dyn TickLocator<X::ValueType>> = match (self.x_tick_locator, &x_unit)
+        {
+            (Some(locator), _) => Some(locator),
+            (None, Some((_, locator))) => Some(locator),
+            (None, None) => None,
+        };
+        let y_locator: Option<&dyn TickLocator<Y::ValueType>> = match (self.y_tick_locator, &y_unit)
+        {
+            (Some(locator), _) => Some(locator),
+            (None, Some((_, locator))) => Some(locator),
+            (None, None) => None,
+        };
+        let key_points = |target: &ChartContext<'a, DB2, Cartesian2d<X, Y>>, minor| {
+            let (spec, zoomed) = (target.as_coord_spec(), target.zoomed);
+            (
+                axis_key_points(spec.y_spec(), y_locator, n_y_labels, minor, zoomed),
+                axis_key_points(spec.x_spec(), x_locator, n_x_labels, minor, zoomed),
+            )
+        };
+        let (y_points, x_points) = key_points(target, None);
+        let x_offset = self.x_offset_notation.and_then(|to_f64| {
+            offset_exponent(x_points.iter().filter_map(to_f64)).map(|exp| (exp, to_f64))
+        });
+        let y_offset = self.y_offset_notation.and_then(|to_f64| {
+            offset_exponent(y_points.iter().filter_map(to_f64)).map(|exp| (exp, to_f64))
+        });
+        let x_desc = match x_unit {
+            Some((ref prefixed, _)) => Some(unit_desc(self.x_desc.clone(), prefixed)),
+            None => offset_desc(self.x_desc.clone(), x_offset.map(|(exp, _)| exp)),
+        };
+        let y_desc = match y_unit {
+            Some((ref prefixed, _)) => Some(unit_desc(self.y_desc.clone(), prefixed)),
+            None => offset_desc(self.y_desc.clone(), y_offset.map(|(exp, _)| exp)),
+        };
         let axis_style = self
             .axis_style
             .clone()
@@ -425,12 +967,9 @@ where
         let x_label_style = self
             .x_label_style
             .clone()
-            .unwrap_or_else(|| default_label_font.clone().into());
+            .unwrap_or_else(|| default_label_style.clone());
 
-        let y_label_style = self
-            .y_label_style
-            .clone()
-            .unwrap_or_else(|| default_label_font.into());
+        let y_label_style = self.y_label_style.clone().unwrap_or(default_label_style);
 
         let axis_desc_style = self
             .axis_desc_style
@@ -438,13 +977,11 @@ where
             .unwrap_or_else(|| x_label_style.clone());
 
         target.draw_mesh(
-            (
-                LightPoints::new(self.n_y_labels, self.n_y_labels * 10),
-                LightPoints::new(self.n_x_labels, self.n_x_labels * 10),
-            ),
+            key_points(target, minor_per_label),
             &light_style,
             &x_label_style,
             &y_label_style,
+            |_, _, _| None,
             |_| None,
             self.draw_x_mesh,
             self.draw_y_mesh,
@@ -454,20 +991,67 @@ where
             false,
             &axis_style,
             &axis_desc_style,
-            self.x_desc.clone(),
-            self.y_desc.clone(),
+            x_desc,
+            y_desc,
             self.x_tick_size,
             self.y_tick_size,
+            self.label_overflow,
         )?;
 
+        let x_step = x_unit
+            .as_ref()
+            .map(|(_, locator)| locator.to_f64)
+            .or_else(|| x_offset.map(|(_, to_f64)| to_f64))
+            .or_else(|| self.tick_format_x.map(|(_, to_f64)| to_f64))
+            .and_then(|to_f64| tick_step(x_points.iter().filter_map(to_f64)));
+        let y_step = y_unit
+            .as_ref()
+            .map(|(_, locator)| locator.to_f64)
+            .or_else(|| y_offset.map(|(_, to_f64)| to_f64))
+            .or_else(|| self.tick_format_y.map(|(_, to_f64)| to_f64))
+            .and_then(|to_f64| tick_step(y_points.iter().filter_map(to_f64)));
+        let x_periods = self
+            .format_x_period
+            .map(|fmt| target.period_labels(&x_points, fmt));
+
         target.draw_mesh(
-            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+            (y_points, x_points),
             &bold_style,
             &x_label_style,
             &y_label_style,
+            |x_spec, y_spec, m| match m {
+                MeshLine::XMesh(_, _, v) => x_unit
+                    .as_ref()
+                    .and_then(|(_, locator)| {
+                        (locator.to_f64)(v).map(|v| format_scaled(v, x_step, locator.scale))
+                    })
+                    .or_else(|| {
+                        let (exp, to_f64) = x_offset?;
+                        to_f64(v).map(|v| OffsetFormatter(exp).format_tick(v, x_step))
+                    })
+                    .or_else(|| {
+                        let (fmt, to_f64) = self.tick_format_x?;
+                        to_f64(v).map(|v| fmt.format_tick(v, x_step))
+                    })
+                    .or_else(|| Some(self.format_x.map_or_else(|| x_spec.format_ext(v), |f| f(v)))),
+                MeshLine::YMesh(_, _, v) => y_unit
+                    .as_ref()
+                    .and_then(|(_, locator)| {
+                        (locator.to_f64)(v).map(|v| format_scaled(v, y_step, locator.scale))
+                    })
+                    .or_else(|| {
+                        let (exp, to_f64) = y_offset?;
+                        to_f64(v).map(|v| OffsetFormatter(exp).format_tick(v, y_step))
+                    })
+                    .or_else(|| {
+                        let (fmt, to_f64) = self.tick_format_y?;
+                        to_f64(v).map(|v| fmt.format_tick(v, y_step))
+                    })
+                    .or_else(|| Some(self.format_y.map_or_else(|| y_spec.format_ext(v), |f| f(v)))),
+            },
             |m| match m {
-                MeshLine::XMesh(_, _, v) => Some((self.format_x)(v)),
-                MeshLine::YMesh(_, _, v) => Some((self.format_y)(v)),
+                MeshLine::XMesh(_, _, v) => self.format_top_x.map(|fmt| fmt(v)),
+                MeshLine::YMesh(_, _, v) => self.format_right_y.map(|fmt| fmt(v)),
             },
             self.draw_x_mesh,
             self.draw_y_mesh,
@@ -481,6 +1065,34 @@ where
             None,
             self.x_tick_size,
             self.y_tick_size,
-        )
+            self.label_overflow,
+        )?;
+
+        if let Some(periods) = x_periods {
+            target.draw_period_labels(
+                &periods,
+                &x_label_style,
+                self.x_label_offset,
+                self.x_tick_size[1],
+            )?;
+        }
+
+        if self.minor_ticks.is_some() {
+            let half = |size: [i32; 2], draw: bool| {
+                if draw {
+                    [size[0] / 2, size[1] / 2]
+                } else {
+                    [0, 0]
+                }
+            };
+            target.draw_minor_ticks(
+                key_points(target, minor_per_label),
+                &axis_style,
+                half(self.x_tick_size, self.draw_x_axis),
+                half(self.y_tick_size, self.draw_y_axis),
+            )?;
+        }
+
+        Ok(())
     }
 }
@@ -12,19 +12,47 @@ In Plotters, a series is abstracted as an iterator of elements.
 detailed description for each struct.
 */
 
+mod animation;
+mod auto_range;
 mod axes3d;
 mod builder;
 mod context;
 mod dual_coord;
+mod facet;
+mod hit_test;
+mod incremental;
+mod inset;
+mod layout;
 mod mesh;
+mod overflow;
+mod pair;
+mod parallel;
+mod polar_mesh;
+#[cfg(feature = "candlestick")]
+mod price_volume;
 mod series;
 mod state;
+mod ternary_mesh;
+mod zoom;
 
-pub use builder::{ChartBuilder, LabelAreaPosition};
+pub use animation::{animate, render_frames, Easing, Interpolate, Transition};
+pub use auto_range::AutoRangeChart;
+pub use builder::{Aspect, AspectFit, ChartBuilder, LabelAreaPosition};
 pub use context::ChartContext;
-pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
+pub use dual_coord::{DualCoordChartContext, DualCoordChartState, ExtraYAxis};
+pub use facet::{FacetCell, FacetGrid};
+pub use hit_test::HitShape;
+pub use incremental::{CachedLayer, IncrementalLine, PixelRegion};
+pub use layout::ChartLayout;
 pub use mesh::{MeshStyle, SecondaryMeshStyle};
+pub use overflow::Overflow;
+pub use pair::{PairDiagonal, PairPlot};
+pub use parallel::ParallelCoordinates;
+pub use polar_mesh::PolarMeshStyle;
+#[cfg(feature = "candlestick")]
+pub use price_volume::PriceVolumePanels;
 pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
 pub use state::ChartState;
+pub use ternary_mesh::TernaryMeshStyle;
 
 use context::Coord3D;
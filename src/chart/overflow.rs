@@ -0,0 +1,141 @@
+/// What to do with the tick labels of an axis that would overlap each other, see
+/// [MeshStyle::x_label_overflow](struct.MeshStyle.html#method.x_label_overflow).
+///
+/// The labels of the Y axis are stacked vertically, so they are always thinned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Overflow {
+    /// Only draw every k-th label, with the smallest k that makes the labels fit
+    Thin,
+    /// Rotate the labels by the angle in degrees, and thin them if they still overlap
+    Rotate(f64),
+    /// Break the labels into lines between the words, and thin them if they still overlap
+    Wrap,
+}
+
+/// The minimal space between two labels in pixels
+pub(super) const LABEL_GAP: i32 = 4;
+
+/// Whether the consecutive labels of the extents along the axis overlap, only the labels with
+/// the indices of the step are taken into account
+fn overlaps(positions: &[i32], extents: &[i32], step: usize) -> bool {
+    let shown: Vec<_> = (0..positions.len()).step_by(step).collect();
+    shown.windows(2).any(|pair| {
+        let (a, b) = (pair[0], pair[1]);
+        let space = (positions[b] - positions[a]).abs();
+        space < (extents[a] + extents[b] + 1) / 2 + LABEL_GAP
+    })
+}
+
+/// Pick the labels to draw, which are every k-th one, with the smallest k that makes the labels
+/// fit. The positions are sorted along the axis, and the extents are the sizes of the labels
+/// along the axis.
+pub(super) fn thin_labels(positions: &[i32], extents: &[i32]) -> Vec<bool> {
+    let step = (1..positions.len().max(1))
+        .find(|&step| !overlaps(positions, extents, step))
+        .unwrap_or_else(|| positions.len().max(1));
+    (0..positions.len()).map(|idx| idx % step == 0).collect()
+}
+
+/// Whether any of the labels overlap, which is the case that the overflow policy applies
+pub(super) fn is_overflowing(positions: &[i32], extents: &[i32]) -> bool {
+    overlaps(positions, extents, 1)
+}
+
+/// Move the labels apart along the axis until they don't overlap, keeping them as close to their
+/// positions as possible and within the bounds. The positions are the sorted centers of the
+/// labels, and the labels that don't fit in the bounds are stacked from the start.
+pub(super) fn spread_labels(positions: &[i32], extents: &[i32], bounds: (i32, i32)) -> Vec<i32> {
+    let space = |a: usize, b: usize| (extents[a] + extents[b] + 1) / 2 + LABEL_GAP;
+    let mut result = positions.to_vec();
+    for idx in 1..result.len() {
+        result[idx] = result[idx].max(result[idx - 1] + space(idx - 1, idx));
+    }
+    // Pull the labels back from the end, then push them from the start, which wins if they don't
+    // fit in the bounds
+    for idx in (0..result.len()).rev() {
+        let limit = if idx + 1 == result.len() {
+            bounds.1 - extents[idx] / 2
+        } else {
+            result[idx + 1] - space(idx, idx + 1)
+        };
+        result[idx] = result[idx].min(limit);
+    }
+    for idx in 0..result.len() {
+        let limit = if idx == 0 {
+            bounds.0 + (extents[idx] + 1) / 2
+        } else {
+            result[idx - 1] + space(idx - 1, idx)
+        };
+        result[idx] = result[idx].max(limit);
+    }
+    result
+}
+
+/// Break the text into the lines that are at most the width, between the words. A word that is
+/// wider than the width is kept in a line on its own.
+pub(super) fn wrap_label<F: Fn(&str) -> i32>(text: &str, width: i32, measure: F) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for word in text.split_whitespace() {
+        if let Some(line) = lines.last_mut() {
+            let joined = format!("{} {}", line, word);
+            if measure(&joined) <= width {
+                *line = joined;
+                continue;
+            }
+        }
+        lines.push(word.to_string());
+    }
+    if lines.is_empty() {
+        lines.push(text.to_string());
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_thin_labels() {
+        let positions = [0, 10, 20, 30, 40];
+        assert_eq!(thin_labels(&positions, &[4; 5]), vec![true; 5]);
+        assert!(!is_overflowing(&positions, &[4; 5]));
+        assert!(is_overflowing(&positions, &[12; 5]));
+        assert_eq!(
+            thin_labels(&positions, &[12; 5]),
+            vec![true, false, true, false, true]
+        );
+        assert_eq!(
+            thin_labels(&positions, &[100; 5]),
+            vec![true, false, false, false, false]
+        );
+        assert!(thin_labels(&[], &[]).is_empty());
+
+        let measure = |text: &str| text.len() as i32;
+        assert_eq!(
+            wrap_label("Jan 2020 week 1", 8, measure),
+            vec!["Jan 2020", "week 1"]
+        );
+        assert_eq!(wrap_label("September", 4, measure), vec!["September"]);
+        assert_eq!(wrap_label("", 4, measure), vec![""]);
+    }
+
+    #[test]
+    fn test_spread_labels() {
+        let bounds = (0, 100);
+        assert_eq!(
+            spread_labels(&[10, 50, 90], &[10; 3], bounds),
+            vec![10, 50, 90]
+        );
+        assert_eq!(
+            spread_labels(&[50, 52, 54], &[10; 3], bounds),
+            vec![50, 64, 78]
+        );
+        // The labels at the end are pulled back into the bounds
+        assert_eq!(spread_labels(&[90, 92], &[10; 2], bounds), vec![81, 95]);
+        assert_eq!(spread_labels(&[-20], &[10], bounds), vec![5]);
+        // Too many labels are stacked from the start
+        assert_eq!(spread_labels(&[50; 8], &[10; 8], bounds)[7], 5 + 7 * 14);
+        assert!(spread_labels(&[], &[], bounds).is_empty());
+    }
+}
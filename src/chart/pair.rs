@@ -0,0 +1,290 @@
+use std::ops::Range;
+
+use super::parallel::value_range;
+use super::FacetGrid;
+use crate::coord::Shift;
+use crate::data::kde::{gaussian_density, silverman_bandwidth};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Circle, PathElement, Rectangle};
+use crate::style::{Color, ShapeStyle, BLUE};
+use plotters_backend::DrawingBackend;
+
+/// The number of the samples of a density curve on the diagonal
+const KDE_SAMPLES: usize = 100;
+
+/// What the diagonal cells of a [PairPlot](struct.PairPlot.html) show
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PairDiagonal {
+    /// The histogram of the column with the given number of bins
+    Histogram(usize),
+    /// The gaussian kernel density estimate of the column, whose bandwidth is estimated with the
+    /// Silverman's rule of thumb
+    Kde,
+}
+
+/// Count the finite values in each of the bins, which split the range evenly
+fn histogram(values: &[f64], range: &Range<f64>, bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    let width = (range.end - range.start) / bins as f64;
+    for &v in values.iter().filter(|v| v.is_finite()) {
+        if v >= range.start && v <= range.end {
+            let idx = ((v - range.start) / width) as usize;
+            counts[idx.min(bins - 1)] += 1;
+        }
+    }
+    counts
+}
+
+/// The pair plot, or the scatter matrix, which draws every pair of the columns of a table in a
+/// grid of the subplots to explore their relations at once.
+///
+/// The cell on the row `i` and the column `j` is the scatter plot of the column `j` against the
+/// column `i`, and the diagonal cells show the distribution of each column. All the cells of a
+/// column share the X range of the column, and all the cells of a row share the Y range of the
+/// column of the row, so only the outer cells are labelled, with the column names. The
+/// distributions on the diagonal are scaled to the height of their cells.
+///
+/// The records that have a NaN in either column of a pair are left out of its scatter plot.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 640)).into_drawing_area();
+/// let x: Vec<f64> = (0..50).map(|i| i as f64 / 5.0).collect();
+/// PairPlot::new()
+///     .column("x", x.clone())
+///     .column("sin", x.iter().map(|x| x.sin()))
+///     .column("square", x.iter().map(|x| x * x))
+///     .diagonal(PairDiagonal::Kde)
+///     .draw(&root)
+///     .unwrap();
+/// ```
+pub struct PairPlot {
+    columns: Vec<(String, Vec<f64>)>,
+    diagonal: PairDiagonal,
+    point_size: u32,
+    point_style: ShapeStyle,
+    diagonal_style: ShapeStyle,
+}
+
+impl PairPlot {
+    /// Create a new pair plot without columns
+    pub fn new() -> Self {
+        Self {
+            columns: vec![],
+            diagonal: PairDiagonal::Histogram(10),
+            point_size: 2,
+            point_style: BLUE.mix(0.6).filled(),
+            diagonal_style: BLUE.mix(0.6).filled(),
+        }
+    }
+
+    /// Add a column, which is the row and the column of the grid after the previous ones
+    /// - `name`: The name of the column
+    /// - `values`: The values of the column, the `k`-th values of all the columns are a record
+    pub fn column<S: Into<String>, I: IntoIterator<Item = f64>>(
+        mut self,
+        name: S,
+        values: I,
+    ) -> Self {
+        self.columns
+            .push((name.into(), values.into_iter().collect()));
+        self
+    }
+
+    /// Set what the diagonal cells show, which is a histogram of 10 bins by default
+    pub fn diagonal(mut self, diagonal: PairDiagonal) -> Self {
+        self.diagonal = diagonal;
+        self
+    }
+
+    /// Set the size of the points of the scatter plots
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = size;
+        self
+    }
+
+    /// Set the style of the points of the scatter plots
+    pub fn point_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.point_style = style.into();
+        self
+    }
+
+    /// Set the style of the histogram bars or the density curves on the diagonal
+    pub fn diagonal_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.diagonal_style = style.into();
+        self
+    }
+
+    /// Get the range of each column, which is the range of its finite values
+    pub fn ranges(&self) -> Vec<Range<f64>> {
+        self.columns
+            .iter()
+            .map(|(_, values)| value_range(values.iter().cloned()))
+            .collect()
+    }
+
+    /// The outline of the distribution of a column, with the heights in the `0..1` range
+    fn distribution(&self, idx: usize, range: &Range<f64>) -> Vec<(f64, f64)> {
+        let values = &self.columns[idx].1;
+        match self.diagonal {
+            PairDiagonal::Histogram(bins) => {
+                let counts = histogram(values, range, bins.max(1));
+                let max = counts.iter().cloned().max().unwrap_or(0).max(1) as f64;
+                let width = (range.end - range.start) / counts.len() as f64;
+                counts
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &c)| (range.start + width * k as f64, c as f64 / max))
+                    .collect()
+            }
+            PairDiagonal::Kde => {
+                let samples: Vec<f64> = values.iter().cloned().filter(|v| v.is_finite()).collect();
+                if samples.is_empty() {
+                    return vec![];
+                }
+                let bandwidth = silverman_bandwidth(&samples);
+                let bandwidth = if bandwidth > 0.0 {
+                    bandwidth
+                } else {
+                    (range.end - range.start) / 20.0
+                };
+                let curve: Vec<(f64, f64)> = (0..=KDE_SAMPLES)
+                    .map(|k| {
+                        let x =
+                            range.start + (range.end - range.start) * k as f64 / KDE_SAMPLES as f64;
+                        (x, gaussian_density(&samples, bandwidth, x))
+                    })
+                    .collect();
+                let max = curve.iter().map(|p| p.1).fold(0.0, f64::max);
+                curve
+                    .into_iter()
+                    .map(|(x, y)| (x, if max > 0.0 { y / max } else { 0.0 }))
+                    .collect()
+            }
+        }
+    }
+
+    /// Draw the grid onto the drawing area
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let n = self.columns.len();
+        if n == 0 {
+            return Ok(());
+        }
+        let ranges = self.ranges();
+        FacetGrid::new(area, (n, n)).outer_labels(true).draw(
+            |cell| (ranges[cell.col].clone(), ranges[cell.row].clone()),
+            |cell, chart| {
+                let (x_name, y_name) = (&self.columns[cell.col].0, &self.columns[cell.row].0);
+                chart
+                    .configure_mesh()
+                    .disable_mesh()
+                    .x_labels(3)
+                    .y_labels(3)
+                    .x_desc(x_name.as_str())
+                    .y_desc(y_name.as_str())
+                    .draw()?;
+
+                let y_range = &ranges[cell.row];
+                if cell.row == cell.col {
+                    let range = &ranges[cell.col];
+                    let height = |h: f64| y_range.start + (y_range.end - y_range.start) * h;
+                    let outline = self.distribution(cell.col, range);
+                    match self.diagonal {
+                        PairDiagonal::Histogram(_) => {
+                            let width = (range.end - range.start) / outline.len() as f64;
+                            chart.draw_series(outline.iter().map(|&(x, h)| {
+                                Rectangle::new(
+                                    [(x, y_range.start), (x + width, height(h))],
+                                    self.diagonal_style.clone(),
+                                )
+                            }))?;
+                        }
+                        PairDiagonal::Kde => {
+                            let style = ShapeStyle {
+                                filled: false,
+                                ..self.diagonal_style.clone()
+                            };
+                            chart.draw_series(std::iter::once(PathElement::new(
+                                outline
+                                    .iter()
+                                    .map(|&(x, h)| (x, height(h)))
+                                    .collect::<Vec<_>>(),
+                                style,
+                            )))?;
+                        }
+                    }
+                } else {
+                    let xs = &self.columns[cell.col].1;
+                    let ys = &self.columns[cell.row].1;
+                    // The ranges are the extents of the values, thus the markers are drawn in
+                    // full rather than cut at the border
+                    chart.clip(false).draw_series(
+                        xs.iter()
+                            .zip(ys)
+                            .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+                            .map(|(&x, &y)| {
+                                Circle::new((x, y), self.point_size, self.point_style.clone())
+                            }),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+impl Default for PairPlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pair_plot_diagonal() {
+        assert_eq!(
+            histogram(&[0.0, 0.5, 1.0, 4.0, std::f64::NAN], &(0.0..4.0), 4),
+            vec![2, 1, 0, 1]
+        );
+
+        let plot = PairPlot::new()
+            .column("a", vec![0.0, 0.5, 1.0, 4.0])
+            .column("b", vec![1.0, std::f64::NAN, 1.0, 1.0]);
+        assert_eq!(plot.ranges(), vec![0.0..4.0, 0.5..1.5]);
+        let plot = plot.diagonal(PairDiagonal::Histogram(4));
+        assert_eq!(
+            plot.distribution(0, &(0.0..4.0)),
+            vec![(0.0, 1.0), (1.0, 0.5), (2.0, 0.0), (3.0, 0.5)]
+        );
+
+        let plot = plot.diagonal(PairDiagonal::Kde);
+        let curve = plot.distribution(1, &(0.5..1.5));
+        assert_eq!(curve.len(), KDE_SAMPLES + 1);
+        assert_eq!(curve[KDE_SAMPLES / 2], (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_draw_pair_plot() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                // The 3 records are drawn in both of the scatter plots, minus the one with a NaN
+                assert_eq!(b.num_draw_circle_call, 4);
+            });
+        });
+        PairPlot::new()
+            .column("a", vec![0.0, 1.0, 2.0])
+            .column("b", vec![2.0, std::f64::NAN, 0.0])
+            .draw(&drawing_area)
+            .unwrap();
+    }
+}
@@ -0,0 +1,300 @@
+use std::ops::Range;
+
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{PathElement, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ColorMap, ShapeStyle, TextStyle, BLACK, BLUE};
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The space around the axes in pixels, which fits the axis names and the tick labels
+const MARGIN: (i32, i32, i32, i32) = (30, 10, 50, 20);
+const TICK_SIZE: i32 = 4;
+
+/// The smallest range that covers the finite values, which is widened if all the values are the
+/// same
+pub(super) fn value_range<I: Iterator<Item = f64>>(values: I) -> Range<f64> {
+    let mut finite = values.filter(|v| v.is_finite());
+    let range = match finite.next() {
+        Some(first) => finite.fold(first..first, |r, v| r.start.min(v)..r.end.max(v)),
+        None => return 0.0..1.0,
+    };
+    if range.end > range.start {
+        range
+    } else {
+        range.start - 0.5..range.end + 0.5
+    }
+}
+
+/// The parallel coordinates plot, which draws the records of a table as the polylines across a
+/// row of vertical axes, one axis for each column with its own range.
+///
+/// The axes are spread evenly over the drawing area, with the names on the top and the tick
+/// labels on the left of each axis. The range of an axis is the range of its values unless it's
+/// given, and the missing or NaN values break the lines. The lines are colored by the values of
+/// one of the columns with a color map, or all drawn with the same style.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let cars = vec![
+///     vec![21.0, 6.0, 160.0, 110.0],
+///     vec![22.8, 4.0, 108.0, 93.0],
+///     vec![18.7, 8.0, 360.0, 175.0],
+///     vec![14.3, 8.0, 360.0, 245.0],
+/// ];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// ParallelCoordinates::new()
+///     .axis("mpg")
+///     .axis_with_range("cylinders", 0.0..10.0)
+///     .axis("displacement")
+///     .axis("horsepower")
+///     .records(cars)
+///     .color_by(0, ViridisRGB)
+///     .draw(&root)
+///     .unwrap();
+/// ```
+pub struct ParallelCoordinates<'a> {
+    axes: Vec<(String, Option<Range<f64>>)>,
+    records: Vec<Vec<f64>>,
+    color_by: Option<(usize, Box<dyn ColorMap + 'a>)>,
+    line_style: ShapeStyle,
+    axis_style: ShapeStyle,
+    name_style: TextStyle<'a>,
+    tick_style: TextStyle<'a>,
+    ticks: usize,
+}
+
+impl<'a> ParallelCoordinates<'a> {
+    /// Create a new parallel coordinates plot without axes
+    pub fn new() -> Self {
+        Self {
+            axes: vec![],
+            records: vec![],
+            color_by: None,
+            line_style: BLUE.mix(0.5).into(),
+            axis_style: BLACK.into(),
+            name_style: ("sans-serif", 14).into(),
+            tick_style: ("sans-serif", 11).into(),
+            ticks: 5,
+        }
+    }
+
+    /// Add an axis to the right of the previous axes, whose range is the range of its values
+    /// - `name`: The name of the axis
+    pub fn axis<S: Into<String>>(mut self, name: S) -> Self {
+        self.axes.push((name.into(), None));
+        self
+    }
+
+    /// Add an axis to the right of the previous axes with the given range
+    /// - `name`: The name of the axis
+    /// - `range`: The range of the axis, from the bottom to the top
+    pub fn axis_with_range<S: Into<String>>(mut self, name: S, range: Range<f64>) -> Self {
+        self.axes.push((name.into(), Some(range)));
+        self
+    }
+
+    /// Add the records, whose values are in the order of the axes
+    pub fn records<I: IntoIterator<Item = R>, R: IntoIterator<Item = f64>>(
+        mut self,
+        records: I,
+    ) -> Self {
+        self.records
+            .extend(records.into_iter().map(|r| r.into_iter().collect()));
+        self
+    }
+
+    /// Color the lines by the values of an axis with the color map, which maps the range of the
+    /// axis from the bottom to the top
+    /// - `axis`: The index of the axis
+    /// - `map`: The color map
+    pub fn color_by<M: ColorMap + 'a>(mut self, axis: usize, map: M) -> Self {
+        self.color_by = Some((axis, Box::new(map)));
+        self
+    }
+
+    /// Set the style of the lines, the color is overridden by
+    /// [color_by](#method.color_by)
+    pub fn line_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.line_style = style.into();
+        self
+    }
+
+    /// Set the style of the axis lines and the ticks
+    pub fn axis_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.axis_style = style.into();
+        self
+    }
+
+    /// Set the style of the axis names
+    pub fn name_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.name_style = style.into();
+        self
+    }
+
+    /// Set the style of the tick labels
+    pub fn tick_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.tick_style = style.into();
+        self
+    }
+
+    /// Set the maximum number of the tick labels on each axis, which is 5 by default
+    pub fn ticks(mut self, count: usize) -> Self {
+        self.ticks = count;
+        self
+    }
+
+    /// Get the range of each axis, which is either given or the range of its values
+    pub fn axis_ranges(&self) -> Vec<Range<f64>> {
+        self.axes
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, range))| match range {
+                Some(range) => range.clone(),
+                None => value_range(self.records.iter().filter_map(|r| r.get(idx).cloned())),
+            })
+            .collect()
+    }
+
+    /// Draw the plot onto the drawing area
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if self.axes.is_empty() {
+            return Ok(());
+        }
+        let (w, h) = area.dim_in_pixel();
+        let (top, bottom) = (MARGIN.0, h as i32 - MARGIN.1);
+        let (left, right) = (MARGIN.2, w as i32 - MARGIN.3);
+        let axis_x = |idx: usize| {
+            if self.axes.len() == 1 {
+                (left + right) / 2
+            } else {
+                left + (right - left) * idx as i32 / (self.axes.len() as i32 - 1)
+            }
+        };
+        let ranges = self.axis_ranges();
+        let axis_y = |idx: usize, value: f64| {
+            let range = &ranges[idx];
+            let t = (value - range.start) / (range.end - range.start);
+            bottom - ((bottom - top) as f64 * t).round() as i32
+        };
+
+        for record in &self.records {
+            let style = match self.color_by {
+                Some((axis, ref map)) => match record.get(axis) {
+                    Some(value) if !value.is_nan() && axis < ranges.len() => {
+                        let range = &ranges[axis];
+                        let t = (value - range.start) / (range.end - range.start);
+                        let t = if t <= 0.0 { 0.0 } else { t.min(1.0) };
+                        ShapeStyle {
+                            color: map.get_color(t).to_rgba(),
+                            ..self.line_style.clone()
+                        }
+                    }
+                    _ => self.line_style.clone(),
+                },
+                None => self.line_style.clone(),
+            };
+            let mut segment: Vec<BackendCoord> = vec![];
+            for idx in 0..self.axes.len() {
+                match record.get(idx) {
+                    Some(value) if value.is_finite() => {
+                        segment.push((axis_x(idx), axis_y(idx, *value)))
+                    }
+                    _ => {
+                        if segment.len() > 1 {
+                            area.draw(&PathElement::new(segment.clone(), style.clone()))?;
+                        }
+                        segment.clear();
+                    }
+                }
+            }
+            if segment.len() > 1 {
+                area.draw(&PathElement::new(segment, style))?;
+            }
+        }
+
+        let name_style = self.name_style.pos(Pos::new(HPos::Center, VPos::Bottom));
+        let tick_style = self.tick_style.pos(Pos::new(HPos::Right, VPos::Center));
+        for (idx, (name, _)) in self.axes.iter().enumerate() {
+            let x = axis_x(idx);
+            area.draw(&PathElement::new(
+                vec![(x, top), (x, bottom)],
+                self.axis_style.clone(),
+            ))?;
+            area.draw(&Text::new(name.as_str(), (x, top - 8), name_style.clone()))?;
+
+            let range = &ranges[idx];
+            let coord: RangedCoordf64 = (range.start..range.end).into();
+            for value in coord.key_points(self.ticks) {
+                let y = axis_y(idx, value);
+                area.draw(&PathElement::new(
+                    vec![(x - TICK_SIZE, y), (x, y)],
+                    self.axis_style.clone(),
+                ))?;
+                area.draw(&Text::new(
+                    RangedCoordf64::format(&value),
+                    (x - TICK_SIZE - 2, y),
+                    tick_style.clone(),
+                ))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Default for ParallelCoordinates<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_axis_ranges() {
+        let plot = ParallelCoordinates::new()
+            .axis("a")
+            .axis_with_range("b", 0.0..10.0)
+            .axis("c")
+            .records(vec![vec![1.0, 2.0, 5.0], vec![3.0, std::f64::NAN, 5.0]]);
+        assert_eq!(plot.axis_ranges(), vec![1.0..3.0, 0.0..10.0, 4.5..5.5]);
+    }
+
+    #[test]
+    fn test_draw_parallel_coordinates() {
+        let drawing_area = create_mocked_drawing_area(200, 130, |m| {
+            // The first record is broken by the missing value, the second one is a single line
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BlackWhite.get_color(0.0).to_rgba());
+                assert_eq!(path, vec![(50, 120), (115, 75)]);
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BlackWhite.get_color(1.0).to_rgba());
+                assert_eq!(path.len(), 3);
+            });
+            m.check_draw_path(|c, _, _| assert_eq!(c, BLACK.to_rgba()));
+            m.drop_check(|b| {
+                assert!(b.num_draw_text_call >= 3);
+            });
+        });
+        ParallelCoordinates::new()
+            .axis("a")
+            .axis_with_range("b", 0.0..2.0)
+            .axis("c")
+            .records(vec![vec![0.0, 1.0, std::f64::NAN], vec![1.0, 2.0, 3.0]])
+            .color_by(0, BlackWhite)
+            .draw(&drawing_area)
+            .unwrap();
+    }
+}
@@ -0,0 +1,231 @@
+use std::marker::PhantomData;
+
+use super::ChartContext;
+use crate::coord::polar::Polar;
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, PathElement};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::Color;
+use crate::style::{AsRelative, ShapeStyle, SizeDesc, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+/// The configurations about the mesh of a polar chart, which is made of the circles of the
+/// radius values and the radial lines of the angle values
+pub struct PolarMeshStyle<'a, 'b, T: Ranged, R: Ranged, DB: DrawingBackend> {
+    target: Option<&'b mut ChartContext<'a, DB, Polar<T, R>>>,
+    n_labels: [usize; 2],
+    label_offset: i32,
+    line_style: ShapeStyle,
+    axis_style: ShapeStyle,
+    label_style: TextStyle<'b>,
+    format_theta: &'b dyn Fn(&T::ValueType) -> String,
+    format_r: &'b dyn Fn(&R::ValueType) -> String,
+    _phantom: PhantomData<&'a (T, R)>,
+}
+
+impl<'a, 'b, T, R, TT, RT, DB> PolarMeshStyle<'a, 'b, T, R, DB>
+where
+    T: Ranged<ValueType = TT> + ValueFormatter<TT>,
+    R: Ranged<ValueType = RT> + ValueFormatter<RT>,
+    DB: DrawingBackend,
+{
+    /// Set the maximum number of the radial lines and the angular labels
+    pub fn theta_labels(&mut self, n: usize) -> &mut Self {
+        self.n_labels[0] = n;
+        self
+    }
+
+    /// Set the maximum number of the circles and the radius labels
+    pub fn r_labels(&mut self, n: usize) -> &mut Self {
+        self.n_labels[1] = n;
+        self
+    }
+
+    /// Set the distance between the outer circle and the angular labels
+    pub fn label_offset<S: SizeDesc>(&mut self, offset: S) -> &mut Self {
+        self.label_offset = offset.in_pixels(&self.parent_size());
+        self
+    }
+
+    /// Set the style of the circles and the radial lines
+    pub fn line_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.line_style = style.into();
+        self
+    }
+
+    /// Set the style of the outer circle
+    pub fn axis_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.axis_style = style.into();
+        self
+    }
+
+    pub fn label_style<S: Into<TextStyle<'b>>>(&mut self, style: S) -> &mut Self {
+        self.label_style = style.into();
+        self
+    }
+
+    pub fn theta_formatter<F: Fn(&T::ValueType) -> String>(&mut self, f: &'b F) -> &mut Self {
+        self.format_theta = f;
+        self
+    }
+
+    pub fn r_formatter<F: Fn(&R::ValueType) -> String>(&mut self, f: &'b F) -> &mut Self {
+        self.format_r = f;
+        self
+    }
+
+    fn parent_size(&self) -> (u32, u32) {
+        self.target
+            .as_ref()
+            .map_or((0, 0), |chart| chart.drawing_area.dim_in_pixel())
+    }
+
+    pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, Polar<T, R>>) -> Self {
+        let parent_size = chart.drawing_area.dim_in_pixel();
+        let theme = chart.theme;
+        let label_size = (12).percent().max(12).in_pixels(&parent_size);
+        Self {
+            n_labels: [12, 5],
+            label_offset: 5,
+            line_style: Into::<ShapeStyle>::into(&theme.bold_grid),
+            axis_style: Into::<ShapeStyle>::into(&theme.foreground.mix(0.8)),
+            label_style: theme.text_style(f64::from(label_size)),
+            format_theta: &T::format,
+            format_r: &R::format,
+            _phantom: PhantomData,
+            target: Some(chart),
+        }
+    }
+
+    /// Draw the configured mesh on the chart
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let chart = self.target.take().unwrap();
+        let coord = chart.drawing_area.as_coord_spec();
+        let area = chart.drawing_area.strip_coord_spec();
+        let (bx, by) = area.get_base_pixel();
+        let (cx, cy) = coord.center();
+        let center = (cx - bx, cy - by);
+        let radius = coord.radius() as i32;
+        let relative = |(x, y): (i32, i32)| (x - bx, y - by);
+
+        for r in coord.r_spec().key_points(self.n_labels[1]) {
+            let distance = coord.radius_of(&r);
+            if distance <= 0 || distance >= radius {
+                continue;
+            }
+            area.draw(&Circle::new(
+                center,
+                distance as u32,
+                self.line_style.clone(),
+            ))?;
+            let pos = (center.0 + 3, center.1 - distance - 2);
+            let style = self.label_style.pos(Pos::new(HPos::Left, VPos::Bottom));
+            area.draw_text(&(self.format_r)(&r), &style, pos)?;
+        }
+
+        // The angles are deduplicated, since the start and the end of the range are at the same
+        // position of the circle
+        let mut drawn: Vec<i32> = vec![];
+        for theta in coord.theta_spec().key_points(self.n_labels[0]) {
+            let angle = coord.angle_of(&theta);
+            let key = (angle.to_degrees() * 100.0).round() as i32 % 36000;
+            if drawn.contains(&key) {
+                continue;
+            }
+            drawn.push(key);
+
+            let end = relative(coord.point_at(angle, f64::from(radius)));
+            area.draw(&PathElement::new(
+                vec![center, end],
+                self.line_style.clone(),
+            ))?;
+
+            let (sin, cos) = (angle.sin(), angle.cos());
+            let hpos = if sin > 0.3 {
+                HPos::Left
+            } else if sin < -0.3 {
+                HPos::Right
+            } else {
+                HPos::Center
+            };
+            let vpos = if cos > 0.3 {
+                VPos::Bottom
+            } else if cos < -0.3 {
+                VPos::Top
+            } else {
+                VPos::Center
+            };
+            let pos = relative(coord.point_at(angle, f64::from(radius + self.label_offset)));
+            let style = self.label_style.pos(Pos::new(hpos, vpos));
+            area.draw_text(&(self.format_theta)(&theta), &style, pos)?;
+        }
+
+        area.draw(&Circle::new(center, radius as u32, self.axis_style.clone()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_polar_mesh() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|_, _, filled, center, _| {
+                assert!(!filled);
+                assert_eq!(center, (100, 100));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "0.5");
+                assert_eq!(pos, (103, 73));
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                // The circle of 0.5 and the outer circle
+                assert_eq!(b.num_draw_circle_call, 2);
+                // 0, 90, 180 and 270 degrees, where 360 degrees is the same as 0 degrees
+                assert_eq!(b.num_draw_path_call, 4);
+                assert_eq!(b.num_draw_text_call, 5);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(50)
+            .build_polar((0.0..360.0).step(90.0), 0.0..1.0)
+            .unwrap();
+        assert_eq!(chart.as_coord_spec().radius(), 50);
+        chart.configure_polar_mesh().r_labels(2).draw().unwrap();
+    }
+
+    #[cfg(all(feature = "line_series", feature = "area_series"))]
+    #[test]
+    fn test_polar_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(100, 0), (200, 100), (100, 150)]);
+            });
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path[0], (100, 0));
+                assert_eq!(path[path.len() - 1], (100, 100));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_polar(0.0..360.0, 0.0..1.0)
+            .unwrap();
+        let data = vec![(0.0, 1.0), (90.0, 1.0), (180.0, 0.5)];
+        chart
+            .draw_series(LineSeries::new(data.clone(), &RED))
+            .unwrap();
+        chart
+            .draw_series(AreaSeries::new(data, 0.0, &RED.mix(0.2)).border_style(&RED))
+            .unwrap();
+    }
+}
@@ -0,0 +1,142 @@
+use super::{ChartBuilder, ChartContext};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::AsRangedCoord;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use plotters_backend::DrawingBackend;
+
+/// The layout of a financial chart: a price panel on the top and a volume panel below it.
+///
+/// The two panels share the X axis: both charts are built with the same X range and the same
+/// Y label area size, so a data point is at the same horizontal position on both panels. The X
+/// labels only belong to the volume panel.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let panels = PriceVolumePanels::new(&root, 0.25);
+/// let (mut price, mut volume) = panels.build(0.0..10.0, 100.0..120.0, 0.0..1000.0).unwrap();
+/// price.configure_mesh().draw().unwrap();
+/// price
+///     .draw_series((0..10).map(|x| {
+///         let x = x as f64 + 0.5;
+///         OHLCBar::new(x, 105.0, 112.0, 101.0, 109.0, &GREEN, &RED, 10)
+///     }))
+///     .unwrap();
+/// volume.configure_mesh().draw().unwrap();
+/// volume
+///     .draw_series((0..10).map(|x| {
+///         let x = x as f64;
+///         Rectangle::new([(x + 0.1, 0.0), (x + 0.9, 500.0)], BLUE.filled())
+///     }))
+///     .unwrap();
+/// ```
+pub struct PriceVolumePanels<DB: DrawingBackend> {
+    price: DrawingArea<DB, Shift>,
+    volume: DrawingArea<DB, Shift>,
+    x_label_area_size: u32,
+    y_label_area_size: u32,
+}
+
+impl<DB: DrawingBackend> PriceVolumePanels<DB> {
+    /// Split the drawing area into the price panel and the volume panel
+    /// - `area`: The drawing area to split
+    /// - `volume_ratio`: The fraction of the height that the volume panel takes
+    /// - **returns** The newly created layout
+    pub fn new(area: &DrawingArea<DB, Shift>, volume_ratio: f64) -> Self {
+        let (_, h) = area.dim_in_pixel();
+        let split = if volume_ratio.is_nan() || volume_ratio <= 0.0 {
+            h as i32
+        } else if volume_ratio >= 1.0 {
+            0
+        } else {
+            (h as f64 * (1.0 - volume_ratio)).round() as i32
+        };
+        let (price, volume) = area.split_vertically(split);
+        Self {
+            price,
+            volume,
+            x_label_area_size: 30,
+            y_label_area_size: 40,
+        }
+    }
+
+    /// Set the size of the X label area below the volume panel
+    pub fn x_label_area_size(mut self, size: u32) -> Self {
+        self.x_label_area_size = size;
+        self
+    }
+
+    /// Set the size of the Y label area on the left of both panels
+    pub fn y_label_area_size(mut self, size: u32) -> Self {
+        self.y_label_area_size = size;
+        self
+    }
+
+    /// Get the drawing area of the price panel
+    pub fn price_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.price
+    }
+
+    /// Get the drawing area of the volume panel
+    pub fn volume_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.volume
+    }
+
+    /// Build the charts of both panels
+    /// - `x_spec`: The specification of the shared X axis
+    /// - `price_spec`: The specification of the Y axis of the price panel
+    /// - `volume_spec`: The specification of the Y axis of the volume panel
+    /// - **returns** The chart contexts of the price panel and the volume panel
+    #[allow(clippy::type_complexity)]
+    pub fn build<X: AsRangedCoord + Clone, P: AsRangedCoord, V: AsRangedCoord>(
+        &self,
+        x_spec: X,
+        price_spec: P,
+        volume_spec: V,
+    ) -> Result<
+        (
+            ChartContext<'_, DB, Cartesian2d<X::CoordDescType, P::CoordDescType>>,
+            ChartContext<'_, DB, Cartesian2d<X::CoordDescType, V::CoordDescType>>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let price = ChartBuilder::on(&self.price)
+            .y_label_area_size(self.y_label_area_size)
+            .build_cartesian_2d(x_spec.clone(), price_spec)?;
+        let volume = ChartBuilder::on(&self.volume)
+            .x_label_area_size(self.x_label_area_size)
+            .y_label_area_size(self.y_label_area_size)
+            .build_cartesian_2d(x_spec, volume_spec)?;
+        Ok((price, volume))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_shared_x_axis() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |_| {});
+        let panels = PriceVolumePanels::new(&drawing_area, 0.25);
+        assert_eq!(panels.price_area().dim_in_pixel(), (400, 300));
+        assert_eq!(panels.volume_area().dim_in_pixel(), (400, 100));
+        assert_eq!(panels.volume_area().get_base_pixel(), (0, 300));
+
+        let (price, volume) = panels.build(0..10, 0.0..1.0, 0..100).unwrap();
+        assert_eq!(
+            price.as_coord_spec().get_x_axis_pixel_range(),
+            volume.as_coord_spec().get_x_axis_pixel_range()
+        );
+        for x in 0..10 {
+            assert_eq!(
+                price.backend_coord(&(x, 0.0)).0,
+                volume.backend_coord(&(x, 0)).0
+            );
+        }
+    }
+}
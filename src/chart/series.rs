@@ -1,19 +1,30 @@
+use super::overflow::spread_labels;
 use super::ChartContext;
-use crate::coord::CoordTranslate;
-use crate::drawing::DrawingAreaErrorKind;
-use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
-use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{
+    Circle, DynElement, EmptyElement, IntoDynElement, MultiLineText, PathElement, Rectangle,
+};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{
+    Gradient, IntoTextStyle, RGBAColor, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT,
+};
 
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord> + 'a;
 
+type SeriesAnnoGlyphFn<'a, DB> = dyn Fn((u32, u32)) -> DynElement<'a, DB, BackendCoord> + 'a;
+
 /// The annotations (such as the label of the series, the legend element, etc)
 /// When a series is drawn onto a drawing area, an series annotation object
 /// is created and a mutable reference is returned.
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
     draw_func: Option<Box<SeriesAnnoDrawFn<'a, DB>>>,
+    glyph_func: Option<Box<SeriesAnnoGlyphFn<'a, DB>>>,
+    color: Option<RGBAColor>,
+    end_point: Option<BackendCoord>,
 }
 
 impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
@@ -27,13 +38,37 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.draw_func.as_ref().map(|x| x.as_ref())
     }
 
+    pub(crate) fn get_glyph_func(&self) -> Option<&SeriesAnnoGlyphFn<'a, DB>> {
+        self.glyph_func.as_ref().map(|x| x.as_ref())
+    }
+
     pub(crate) fn new() -> Self {
         Self {
             label: None,
             draw_func: None,
+            glyph_func: None,
+            color: None,
+            end_point: None,
         }
     }
 
+    pub(crate) fn set_color(&mut self, color: RGBAColor) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub(crate) fn set_end_point(&mut self, point: Option<BackendCoord>) -> &mut Self {
+        self.end_point = point;
+        self
+    }
+
+    /// Get the color that the chart assigned to the series, which is `None` unless the series
+    /// is drawn with [ChartContext::draw_series_with_palette](struct.ChartContext.html#method.draw_series_with_palette)
+    /// or [ChartContext::draw_series_auto](struct.ChartContext.html#method.draw_series_auto)
+    pub fn color(&self) -> Option<RGBAColor> {
+        self.color
+    }
+
     /// Set the series label
     /// - `label`: The string would be use as label for current series
     pub fn label<L: Into<String>>(&mut self, label: L) -> &mut Self {
@@ -50,8 +85,91 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         func: T,
     ) -> &mut Self {
         self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
+        self.glyph_func = None;
+        self
+    }
+
+    /// Set the legend glyph creator function, which makes any element the glyph of the legend,
+    /// such as a filled swatch for an area or a box series. Unlike the
+    /// [legend](#method.legend) element, the glyph is drawn in a box of the glyph size, see
+    /// [SeriesLabelStyle::glyph_size](struct.SeriesLabelStyle.html#method.glyph_size).
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0..10, 0..10)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(AreaSeries::new((0..10).map(|x| (x, x)), 0, BLUE.mix(0.3)))
+    ///     .unwrap()
+    ///     .label("area")
+    ///     .legend_glyph(|(w, h)| {
+    ///         let (w, h) = (w as i32, h as i32);
+    ///         EmptyElement::at((0, 0))
+    ///             + Rectangle::new([(0, h / 2), (w, h)], BLUE.mix(0.3).filled())
+    ///             + PathElement::new(vec![(0, h / 2), (w, h / 2)], &BLUE)
+    ///     });
+    /// chart
+    ///     .configure_series_labels()
+    ///     .glyph_size(30, 12)
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `func`: The function that creates the glyph of the given size, in the pixels of the
+    ///   glyph box, whose upper left corner is the point (0,0)
+    pub fn legend_glyph<E: IntoDynElement<'a, DB, BackendCoord>, T: Fn((u32, u32)) -> E + 'a>(
+        &mut self,
+        func: T,
+    ) -> &mut Self {
+        self.glyph_func = Some(Box::new(move |size| func(size).into_dyn()));
+        self.draw_func = None;
         self
     }
+
+    /// Use a swatch that fills the glyph box as the legend glyph, which suits the area, the bar
+    /// and the box series
+    /// - `style`: The style of the swatch, which is always filled with the color of the style
+    pub fn legend_swatch<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        let style = style.into().filled();
+        self.legend_glyph(move |(w, h)| {
+            Rectangle::new([(0, 0), (w as i32, h as i32)], style.clone())
+        })
+    }
+
+    /// Use a line across the glyph box with a marker in the middle as the legend glyph, which
+    /// suits the line series with the point markers
+    /// - `style`: The style of the line, the marker is filled with the color of the style
+    /// - `marker_size`: The radius of the marker in pixels
+    pub fn legend_line_marker<S: Into<ShapeStyle>>(
+        &mut self,
+        style: S,
+        marker_size: u32,
+    ) -> &mut Self
+    where
+        DB: 'a,
+    {
+        let style = style.into();
+        self.legend_glyph(move |(w, h)| {
+            let (w, y) = (w as i32, h as i32 / 2);
+            EmptyElement::at((0, 0))
+                + PathElement::new(vec![(0, y), (w, y)], style.clone())
+                + Circle::new((w / 2, y), marker_size, style.filled())
+        })
+    }
+
+    /// Use a strip filled with the gradient as the legend glyph, which suits the series colored
+    /// by the values
+    /// - `gradient`: The gradient, which is laid across the glyph box
+    pub fn legend_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.legend_glyph(move |(w, h)| {
+            Rectangle::new([(0, 0), (w as i32, h as i32)], TRANSPARENT)
+                .fill_gradient(gradient.clone())
+        })
+    }
 }
 
 /// Describes where we want to put the series label
@@ -67,26 +185,69 @@ pub enum SeriesLabelPosition {
     LowerRight,
     /// Force the series label drawn at the specific location
     Coordinate(i32, i32),
+    /// Draw the series label on the right of the plotting area, in the area reserved by
+    /// [ChartBuilder::series_label_area_size](struct.ChartBuilder.html#method.series_label_area_size)
+    RightOfPlot,
+    /// Draw the series label below the plotting area, in the area reserved by
+    /// [ChartBuilder::series_label_area_size](struct.ChartBuilder.html#method.series_label_area_size)
+    BelowPlot,
+    /// Draw the label of each series next to the right end of it, in the color of the series,
+    /// instead of a legend. The labels that would overlap are moved apart vertically. The color
+    /// is the one that the chart assigned to the series, or the color of the label font
+    /// otherwise, and the labels go past the plotting area if the series ends at the right of
+    /// it, thus leave a margin or reserve the area with
+    /// [ChartBuilder::series_label_area_size](struct.ChartBuilder.html#method.series_label_area_size).
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .margin_right(60)
+    ///     .build_cartesian_2d(0..10, 0..30)
+    ///     .unwrap();
+    /// for k in 1..4 {
+    ///     chart
+    ///         .draw_series_with_palette(|style| LineSeries::new((0..10).map(|x| (x, k * x)), style))
+    ///         .unwrap()
+    ///         .label(format!("y = {}x", k));
+    /// }
+    /// chart
+    ///     .configure_series_labels()
+    ///     .position(SeriesLabelPosition::Inline)
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    Inline,
 }
 
 impl SeriesLabelPosition {
-    fn layout_label_area(&self, label_dim: (i32, i32), area_dim: (u32, u32)) -> (i32, i32) {
+    pub(super) fn layout_label_area(
+        &self,
+        label_dim: (i32, i32),
+        area_dim: (u32, u32),
+    ) -> (i32, i32) {
         use SeriesLabelPosition::*;
         (
             match self {
                 UpperLeft | MiddleLeft | LowerLeft => 5,
-                UpperMiddle | MiddleMiddle | LowerMiddle => {
+                UpperMiddle | MiddleMiddle | LowerMiddle | BelowPlot => {
                     (area_dim.0 as i32 - label_dim.0 as i32) / 2
                 }
-                UpperRight | MiddleRight | LowerRight => area_dim.0 as i32 - label_dim.0 as i32 - 5,
+                UpperRight | MiddleRight | LowerRight | RightOfPlot | Inline => {
+                    area_dim.0 as i32 - label_dim.0 - 5
+                }
                 Coordinate(x, _) => *x,
             },
             match self {
-                UpperLeft | UpperMiddle | UpperRight => 5,
-                MiddleLeft | MiddleMiddle | MiddleRight => {
-                    (area_dim.1 as i32 - label_dim.1 as i32) / 2
+                UpperLeft | UpperMiddle | UpperRight | RightOfPlot => 5,
+                MiddleLeft | MiddleMiddle | MiddleRight | Inline => {
+                    (area_dim.1 as i32 - label_dim.1) / 2
+                }
+                LowerLeft | LowerMiddle | LowerRight | BelowPlot => {
+                    area_dim.1 as i32 - label_dim.1 - 5
                 }
-                LowerLeft | LowerMiddle | LowerRight => area_dim.1 as i32 - label_dim.1 as i32 - 5,
                 Coordinate(_, y) => *y,
             },
         )
@@ -102,18 +263,30 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    columns: usize,
+    max_height: Option<u32>,
+    glyph_size: Option<(u32, u32)>,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
     pub(super) fn new(target: &'b mut ChartContext<'a, DB, CT>) -> Self {
+        // The series label goes to the reserved area by default, so that it never covers the data
+        let position = match target.legend_area {
+            [Some(_), _] => SeriesLabelPosition::RightOfPlot,
+            [None, Some(_)] => SeriesLabelPosition::BelowPlot,
+            _ => SeriesLabelPosition::MiddleRight,
+        };
         Self {
             target,
-            position: SeriesLabelPosition::MiddleRight,
+            position,
             legend_area_size: 30,
             border_style: (&TRANSPARENT).into(),
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            columns: 1,
+            max_height: None,
+            glyph_size: None,
         }
     }
 
@@ -134,6 +307,41 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Set the number of the columns of the series labels, which are filled in column by column
+    ///
+    /// - `n`: The number of the columns, 1 by default
+    pub fn columns(&mut self, n: usize) -> &mut Self {
+        self.columns = n.max(1);
+        self
+    }
+
+    /// Set the maximum height of the series label area, the labels are wrapped into more
+    /// columns when a column gets taller than it
+    ///
+    /// - `height`: The size specification
+    pub fn max_height<S: SizeDesc>(&mut self, height: S) -> &mut Self {
+        let height = height
+            .in_pixels(&self.target.plotting_area().dim_in_pixel())
+            .max(0) as u32;
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Set the size of the box that the legend glyphs set with
+    /// [SeriesAnno::legend_glyph](struct.SeriesAnno.html#method.legend_glyph) are drawn in. The
+    /// box is 10 pixels narrower than the legend area and as tall as a label by default, and the
+    /// legend area grows when the glyph is wider than it.
+    ///
+    /// - `width`: The width of the glyph
+    /// - `height`: The height of the glyph
+    pub fn glyph_size<W: SizeDesc, H: SizeDesc>(&mut self, width: W, height: H) -> &mut Self {
+        let dim = self.target.plotting_area().dim_in_pixel();
+        let width = width.in_pixels(&dim).max(0) as u32;
+        let height = height.in_pixels(&dim).max(0) as u32;
+        self.glyph_size = Some((width, height));
+        self
+    }
+
     /// Set the size of legend area
     /// `size` - The size of legend area in pixel
     pub fn legend_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
@@ -165,14 +373,18 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
-    /// Draw the series label area
+    /// Draw the series label area. The exterior positions are a layout error unless the area
+    /// has been reserved when the chart is built.
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
-        let drawing_area = self.target.plotting_area().strip_coord_spec();
+        let plotting_area = self.target.plotting_area().strip_coord_spec();
+        let drawing_area = match self.position {
+            SeriesLabelPosition::RightOfPlot => self.target.legend_area[0].clone(),
+            SeriesLabelPosition::BelowPlot => self.target.legend_area[1].clone(),
+            _ => Some(plotting_area.clone()),
+        }
+        .ok_or(DrawingAreaErrorKind::LayoutError)?;
 
-        // TODO: Issue #68 Currently generic font family doesn't load on OSX, change this after the issue
-        // resolved
-        let default_font = ("sans-serif", 12).into_font();
-        let default_style: TextStyle = default_font.into();
+        let default_style = self.target.theme.text_style(12.0);
 
         let font = {
             let mut temp = None;
@@ -180,40 +392,77 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+        if let SeriesLabelPosition::Inline = self.position {
+            return self.draw_inline(&plotting_area, &font);
+        }
+
+        let mut labels = vec![];
         let mut funcs = vec![];
 
         for anno in self.target.series_anno.iter() {
             let label_text = anno.get_label();
             let draw_func = anno.get_draw_func();
+            let glyph_func = anno.get_glyph_func();
 
-            if label_text == "" && draw_func.is_none() {
+            if label_text.is_empty() && draw_func.is_none() && glyph_func.is_none() {
                 continue;
             }
 
-            funcs.push(
+            funcs.push((
                 draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()),
-            );
-            label_element.push_line(label_text);
+                glyph_func,
+            ));
+            labels.push(label_text);
         }
-
-        let (mut w, mut h) = label_element.estimate_dimension().map_err(|e| {
-            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-        })?;
+        let legend_area_size = match self.glyph_size {
+            Some((w, _)) => self.legend_area_size.max(w + 10),
+            None => self.legend_area_size,
+        };
 
         let margin = self.margin as i32;
 
-        w += self.legend_area_size as i32 + margin * 2;
+        // The labels are filled in column by column, a column is wrapped when it gets taller
+        // than the maximum height
+        let mut rows = labels.len() / self.columns + usize::from(labels.len() % self.columns != 0);
+        if let Some(max_height) = self.max_height {
+            let font_height = font.font.get_size();
+            let available = f64::from(max_height as i32 - margin * 2) - font_height;
+            let fit = (available / (font_height * 1.25)).floor().max(0.0) as usize + 1;
+            rows = rows.min(fit);
+        }
+        let rows = rows.max(1);
+
+        let mut columns = vec![];
+        let (mut w, mut h) = (margin, 0);
+        for chunk in labels.chunks(rows) {
+            let mut column = MultiLineText::<_, &str>::new((0, 0), &font);
+            for label in chunk {
+                column.push_line(*label);
+            }
+            let (cw, ch) = column.estimate_dimension().map_err(|e| {
+                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
+            })?;
+            columns.push((w, column));
+            w += legend_area_size as i32 + cw + margin;
+            h = h.max(ch);
+        }
+
+        w += margin;
         h += margin * 2;
 
         let (area_w, area_h) = drawing_area.dim_in_pixel();
 
-        let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
-
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
+        let (label_x, label_y) = {
+            // The exterior labels are aligned with the plotting area
+            let (px, py) = plotting_area.get_base_pixel();
+            let (ax, ay) = drawing_area.get_base_pixel();
+            let (plot_w, _) = plotting_area.dim_in_pixel();
+            match self.position {
+                SeriesLabelPosition::RightOfPlot => (5, py - ay + 5),
+                SeriesLabelPosition::BelowPlot => (px - ax + (plot_w as i32 - w) / 2, 5),
+                _ => self.position.layout_label_area((w, h), (area_w, area_h)),
+            }
+        };
 
         drawing_area.draw(&Rectangle::new(
             [(label_x, label_y), (label_x + w, label_y + h)],
@@ -223,20 +472,73 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             [(label_x, label_y), (label_x + w, label_y + h)],
             self.border_style.clone(),
         ))?;
-        drawing_area.draw(&label_element)?;
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
-            .compute_line_layout()
-            .map_err(|e| {
-                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-            })?
-            .into_iter()
-            .zip(funcs.into_iter())
-        {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
-            drawing_area.draw(&legend_element)?;
+        let mut funcs = funcs.into_iter();
+        for (x, mut column) in columns {
+            column.relocate((label_x + x + legend_area_size as i32, label_y + margin));
+            drawing_area.draw(&column)?;
+
+            for (((_, y0), (_, y1)), make_elem) in column
+                .compute_line_layout()
+                .map_err(|e| {
+                    DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
+                })?
+                .into_iter()
+                .zip(funcs.by_ref())
+            {
+                let (left, center) = (label_x + x, (y0 + y1) / 2);
+                match make_elem {
+                    (_, Some(make_glyph)) => {
+                        let (w, h) = self
+                            .glyph_size
+                            .unwrap_or((legend_area_size - 10, (y1 - y0).max(0) as u32));
+                        let top = center - h as i32 / 2;
+                        let glyph_area = drawing_area.clone().shrink((left, top), (w, h));
+                        glyph_area.draw(&make_glyph((w, h)))?;
+                    }
+                    (make_elem, None) => drawing_area.draw(&make_elem((left, center)))?,
+                }
+            }
         }
 
         Ok(())
     }
+
+    /// Draw the labels next to the right ends of the series, the labels are vertically centered
+    /// at the ends unless they're moved apart
+    fn draw_inline(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        font: &TextStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (base_x, base_y) = area.get_base_pixel();
+        let (w, h) = area.dim_in_pixel();
+
+        let mut labels = vec![];
+        for anno in self.target.series_anno.iter() {
+            let text = anno.get_label();
+            if let (false, Some((x, y))) = (text.is_empty(), anno.end_point) {
+                let (_, text_h) = area.estimate_text_size(text, font)?;
+                let x = (x - base_x).min(w as i32) + 5;
+                let y = (y - base_y).max(0).min(h as i32);
+                labels.push((y, text_h as i32, x, text, anno.color));
+            }
+        }
+        labels.sort_by_key(|label| label.0);
+
+        let positions: Vec<_> = labels.iter().map(|label| label.0).collect();
+        let extents: Vec<_> = labels.iter().map(|label| label.1).collect();
+        let font = font.pos(Pos::new(HPos::Left, VPos::Center));
+        for ((_, _, x, text, color), y) in
+            labels
+                .into_iter()
+                .zip(spread_labels(&positions, &extents, (0, h as i32)))
+        {
+            match color {
+                Some(color) => area.draw_text(text, &font.color(&color), (x, y))?,
+                None => area.draw_text(text, &font, (x, y))?,
+            }
+        }
+        Ok(())
+    }
 }
@@ -2,8 +2,12 @@ use std::sync::Arc;
 
 use super::ChartContext;
 use crate::coord::{CoordTranslate, Shift};
-use crate::drawing::DrawingArea;
-use plotters_backend::DrawingBackend;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::style::Theme;
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The position and the size of a label area, relative to the area the chart is built on
+pub(super) type LabelAreaLayout = Option<(BackendCoord, (u32, u32))>;
 
 /// A chart context state - This is the data that is needed to reconstruct the chart context
 /// without actually drawing the chart. This is useful when we want to do realtime rendering and
@@ -31,11 +35,61 @@ use plotters_backend::DrawingBackend;
 ///    chart.plotting_area().fill(&WHITE).unwrap(); // Clear the previously drawn graph
 ///    // At this point, you are able to draw next frame
 ///```
+///
+/// The label areas are saved with the state as well, thus a restored chart keeps exactly the same
+/// pixel layout. This makes it possible to zoom or pan a chart by replacing its coordinate ranges
+/// with [ChartContext::set_x_range](struct.ChartContext.html#method.set_x_range) without
+/// estimating the label area sizes again:
+/// ```rust
+///   use plotters::prelude::*;
+///    let mut buffer = vec![0u8;640*480*3];
+///    let root = BitMapBackend::with_buffer(&mut buffer[..], (640, 480)).into_drawing_area();
+///    let state = ChartBuilder::on(&root)
+///        .set_all_label_area_size(40)
+///        .build_cartesian_2d(0.0..100.0, 0.0..1.0)
+///        .expect("Unable to build ChartContext")
+///        .into_chart_state();
+///    // Zoom into the range 20..30 of the X axis
+///    root.fill(&WHITE).unwrap();
+///    let mut chart = state
+///        .try_restore(&root)
+///        .expect("The layout doesn't fit the drawing area")
+///        .set_x_range(20.0..30.0);
+///    chart.configure_mesh().draw().unwrap();
+///```
 #[derive(Clone)]
 pub struct ChartState<CT: CoordTranslate> {
     drawing_area_pos: (i32, i32),
     drawing_area_size: (u32, u32),
+    root_area_size: (u32, u32),
+    label_areas: [LabelAreaLayout; 4],
+    colorbar_area: LabelAreaLayout,
+    legend_area: [LabelAreaLayout; 2],
     coord: CT,
+    theme: Theme,
+    zoomed: bool,
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Get the layout of the area relative to the area the chart is built on
+    pub(super) fn area_layout(&self, area: &Option<DrawingArea<DB, Shift>>) -> LabelAreaLayout {
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let root = (x0 - self.drawing_area_pos.0, y0 - self.drawing_area_pos.1);
+        area.as_ref().map(|area| {
+            let (x, y) = area.get_base_pixel();
+            ((x - root.0, y - root.1), area.dim_in_pixel())
+        })
+    }
+
+    /// Get the layout of the label areas, in the order of top, bottom, left and right
+    pub(super) fn label_area_layout(&self) -> [LabelAreaLayout; 4] {
+        [
+            self.area_layout(&self.x_label_area[0]),
+            self.area_layout(&self.x_label_area[1]),
+            self.area_layout(&self.y_label_area[0]),
+            self.area_layout(&self.y_label_area[1]),
+        ]
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>> for ChartState<CT> {
@@ -43,6 +97,15 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>>
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            root_area_size: chart.root_area_size,
+            label_areas: chart.label_area_layout(),
+            colorbar_area: chart.area_layout(&chart.colorbar_area),
+            legend_area: [
+                chart.area_layout(&chart.legend_area[0]),
+                chart.area_layout(&chart.legend_area[1]),
+            ],
+            theme: chart.theme,
+            zoomed: chart.zoomed,
             coord: chart.drawing_area.into_coord_spec(),
         }
     }
@@ -63,6 +126,15 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         ChartState {
             drawing_area_pos: self.drawing_area_pos,
             drawing_area_size: self.drawing_area.dim_in_pixel(),
+            root_area_size: self.root_area_size,
+            label_areas: self.label_area_layout(),
+            colorbar_area: self.area_layout(&self.colorbar_area),
+            legend_area: [
+                self.area_layout(&self.legend_area[0]),
+                self.area_layout(&self.legend_area[1]),
+            ],
+            theme: self.theme,
+            zoomed: self.zoomed,
             coord: Arc::new(self.drawing_area.into_coord_spec()),
         }
     }
@@ -77,7 +149,16 @@ where
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            root_area_size: chart.root_area_size,
+            label_areas: chart.label_area_layout(),
+            colorbar_area: chart.area_layout(&chart.colorbar_area),
+            legend_area: [
+                chart.area_layout(&chart.legend_area[0]),
+                chart.area_layout(&chart.legend_area[1]),
+            ],
             coord: chart.drawing_area.as_coord_spec().clone(),
+            theme: chart.theme,
+            zoomed: chart.zoomed,
         }
     }
 }
@@ -98,15 +179,108 @@ impl<CT: CoordTranslate> ChartState<CT> {
         self,
         area: &DrawingArea<DB, Shift>,
     ) -> ChartContext<'a, DB, CT> {
-        let area = area
+        let restore_area =
+            |layout: LabelAreaLayout| layout.map(|(pos, size)| area.clone().shrink(pos, size));
+        let x_label_area = [
+            restore_area(self.label_areas[0]),
+            restore_area(self.label_areas[1]),
+        ];
+        let y_label_area = [
+            restore_area(self.label_areas[2]),
+            restore_area(self.label_areas[3]),
+        ];
+        let colorbar_area = restore_area(self.colorbar_area);
+        let legend_area = [
+            restore_area(self.legend_area[0]),
+            restore_area(self.legend_area[1]),
+        ];
+        let drawing_area = area
             .clone()
             .shrink(self.drawing_area_pos, self.drawing_area_size);
         ChartContext {
-            x_label_area: [None, None],
-            y_label_area: [None, None],
-            drawing_area: area.apply_coord_spec(self.coord),
+            x_label_area,
+            y_label_area,
+            drawing_area: drawing_area.apply_coord_spec(self.coord),
             series_anno: vec![],
             drawing_area_pos: self.drawing_area_pos,
+            root_area_size: self.root_area_size,
+            colorbar_area,
+            legend_area,
+            theme: self.theme,
+            palette_idx: 0,
+            hit_regions: vec![],
+            clip: true,
+            clip_region: None,
+            zoomed: self.zoomed,
         }
     }
+
+    /// Restore the chart context on the given drawing area, and make sure the saved layout still
+    /// fits the drawing area.
+    ///
+    /// - `area`: The given drawing area where we want to restore the chart context, which should
+    ///   have the same size as the area the chart was built on
+    /// - **returns** The newly created chart context, or a layout error if the size of the drawing
+    ///   area has changed
+    pub fn try_restore<'a, DB: DrawingBackend>(
+        self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<ChartContext<'a, DB, CT>, DrawingAreaErrorKind<DB::ErrorType>> {
+        if area.dim_in_pixel() != self.root_area_size {
+            return Err(DrawingAreaErrorKind::LayoutError);
+        }
+        Ok(self.restore(area))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_restore_with_new_range() {
+        let state = {
+            let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+            ChartBuilder::on(&drawing_area)
+                .x_label_area_size(30)
+                .y_label_area_size(40)
+                .build_cartesian_2d(0.0..100.0, 0..10)
+                .unwrap()
+                .into_chart_state()
+        };
+
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            // The zoomed X axis is narrower than one tick step, thus it's labeled at its ends
+            // after the single label of the Y axis
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "0"));
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "42.0"));
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "42.001"));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+        let mut chart = state
+            .clone()
+            .try_restore(&drawing_area)
+            .unwrap()
+            .set_x_range(42f64..42.001);
+
+        assert_eq!(chart.plotting_area().get_base_pixel(), (40, 0));
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (360, 270));
+        assert_eq!(chart.x_range(), 42.0..42.001);
+        assert_eq!(chart.backend_coord(&(42.0, 0)), (40, 269));
+
+        // The coordinate itself doesn't have a key point at the ends
+        assert!(chart.as_coord_spec().x_spec().key_points(2).len() < 2);
+
+        chart
+            .configure_mesh()
+            .x_labels(2)
+            .y_labels(1)
+            .draw()
+            .unwrap();
+
+        let resized = create_mocked_drawing_area(500, 300, |_| {});
+        assert!(state.try_restore(&resized).is_err());
+    }
 }
@@ -0,0 +1,262 @@
+use super::ChartContext;
+use crate::coord::ternary::TernaryCoord;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::PathElement;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::Color;
+use crate::style::{AsRelative, ShapeStyle, SizeDesc, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+/// Format the share of a component, e.g. `0.3`
+fn default_format(value: &f64) -> String {
+    format!("{}", (value * 1e6).round() / 1e6)
+}
+
+/// Pick the anchor of a label that is placed in the given direction from its point
+fn anchor_towards((dx, dy): (f64, f64)) -> Pos {
+    let hpos = if dx > 0.3 {
+        HPos::Left
+    } else if dx < -0.3 {
+        HPos::Right
+    } else {
+        HPos::Center
+    };
+    let vpos = if dy > 0.3 {
+        VPos::Top
+    } else if dy < -0.3 {
+        VPos::Bottom
+    } else {
+        VPos::Center
+    };
+    Pos::new(hpos, vpos)
+}
+
+/// The configurations about the mesh of a ternary chart, which is made of the lines of the
+/// constant shares of each component, the labels of the shares along the edges of the triangle
+/// and the names of the components at the corners
+pub struct TernaryMeshStyle<'a, 'b, DB: DrawingBackend> {
+    target: Option<&'b mut ChartContext<'a, DB, TernaryCoord>>,
+    n_labels: usize,
+    label_offset: i32,
+    line_style: ShapeStyle,
+    axis_style: ShapeStyle,
+    label_style: TextStyle<'b>,
+    format: &'b dyn Fn(&f64) -> String,
+    names: [String; 3],
+}
+
+impl<'a, 'b, DB: DrawingBackend> TernaryMeshStyle<'a, 'b, DB> {
+    /// Set the number of the parts that each edge is divided into by the lines, which is 10 by
+    /// default
+    pub fn labels(&mut self, n: usize) -> &mut Self {
+        self.n_labels = n.max(1);
+        self
+    }
+
+    /// Set the distance between the edges and the labels
+    pub fn label_offset<S: SizeDesc>(&mut self, offset: S) -> &mut Self {
+        self.label_offset = offset.in_pixels(&self.parent_size());
+        self
+    }
+
+    /// Set the style of the lines of the constant shares
+    pub fn line_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.line_style = style.into();
+        self
+    }
+
+    /// Set the style of the edges of the triangle
+    pub fn axis_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.axis_style = style.into();
+        self
+    }
+
+    /// Set the style of the labels and the names of the components
+    pub fn label_style<S: Into<TextStyle<'b>>>(&mut self, style: S) -> &mut Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the formatter function of the shares, which are from 0 to 1
+    pub fn label_formatter<F: Fn(&f64) -> String>(&mut self, f: &'b F) -> &mut Self {
+        self.format = f;
+        self
+    }
+
+    /// Set the names of the components that are drawn at the corners of the triangle
+    pub fn axis_names<A: Into<String>, B: Into<String>, C: Into<String>>(
+        &mut self,
+        a: A,
+        b: B,
+        c: C,
+    ) -> &mut Self {
+        self.names = [a.into(), b.into(), c.into()];
+        self
+    }
+
+    fn parent_size(&self) -> (u32, u32) {
+        self.target
+            .as_ref()
+            .map_or((0, 0), |chart| chart.drawing_area.dim_in_pixel())
+    }
+
+    pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, TernaryCoord>) -> Self {
+        let parent_size = chart.drawing_area.dim_in_pixel();
+        let theme = chart.theme;
+        let label_size = (12).percent().max(12).in_pixels(&parent_size);
+        Self {
+            n_labels: 10,
+            label_offset: 5,
+            line_style: Into::<ShapeStyle>::into(&theme.bold_grid),
+            axis_style: Into::<ShapeStyle>::into(&theme.foreground.mix(0.8)),
+            label_style: theme.text_style(f64::from(label_size)),
+            format: &default_format,
+            names: [String::new(), String::new(), String::new()],
+            target: Some(chart),
+        }
+    }
+
+    /// Draw the configured mesh on the chart
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let chart = self.target.take().unwrap();
+        let coord = chart.drawing_area.as_coord_spec();
+        let area = chart.drawing_area.strip_coord_spec();
+        let (bx, by) = area.get_base_pixel();
+        let relative = |(x, y): (f64, f64)| {
+            (
+                (x - f64::from(bx)).round() as i32,
+                (y - f64::from(by)).round() as i32,
+            )
+        };
+        let offset = f64::from(self.label_offset);
+        let corners: Vec<_> = (0..3)
+            .map(|k| {
+                let mut share = [0.0; 3];
+                share[k] = 1.0;
+                coord.point_at((share[0], share[1], share[2]))
+            })
+            .collect();
+        let centroid = coord.point_at((1.0, 1.0, 1.0));
+
+        let n = self.n_labels;
+        for k in 0..3 {
+            // The share of the component k is labeled on the edge where the next component is 0,
+            // which is the edge from the corner of k to the corner of the component after next
+            let (from, to) = (corners[k], corners[(k + 2) % 3]);
+            let mut normal = (to.1 - from.1, from.0 - to.0);
+            let opposite = corners[(k + 1) % 3];
+            if normal.0 * (from.0 - opposite.0) + normal.1 * (from.1 - opposite.1) < 0.0 {
+                normal = (-normal.0, -normal.1);
+            }
+            let length = (normal.0 * normal.0 + normal.1 * normal.1).sqrt();
+            if length == 0.0 {
+                continue;
+            }
+            let normal = (normal.0 / length, normal.1 / length);
+            let style = self.label_style.pos(anchor_towards(normal));
+
+            for idx in 1..n {
+                let value = idx as f64 / n as f64;
+                let point = |other: f64| {
+                    let mut share = [0.0; 3];
+                    share[k] = value;
+                    share[(k + 1) % 3] = other;
+                    share[(k + 2) % 3] = 1.0 - value - other;
+                    coord.point_at((share[0], share[1], share[2]))
+                };
+                let (start, end) = (point(1.0 - value), point(0.0));
+                area.draw(&PathElement::new(
+                    vec![relative(start), relative(end)],
+                    self.line_style.clone(),
+                ))?;
+                let pos = relative((end.0 + normal.0 * offset, end.1 + normal.1 * offset));
+                area.draw_text(&(self.format)(&value), &style, pos)?;
+            }
+        }
+
+        for (corner, name) in corners.iter().zip(self.names.iter()) {
+            let direction = (corner.0 - centroid.0, corner.1 - centroid.1);
+            let length = (direction.0 * direction.0 + direction.1 * direction.1).sqrt();
+            if name.is_empty() || length == 0.0 {
+                continue;
+            }
+            let direction = (direction.0 / length, direction.1 / length);
+            let pos = relative((
+                corner.0 + direction.0 * offset,
+                corner.1 + direction.1 * offset,
+            ));
+            let style = self.label_style.pos(anchor_towards(direction));
+            area.draw_text(name, &style, pos)?;
+        }
+
+        let mut outline: Vec<_> = corners.iter().cloned().map(relative).collect();
+        outline.push(outline[0]);
+        area.draw(&PathElement::new(outline, self.axis_style.clone()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ternary_mesh() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                assert!(["0.5", "A", "B", "C"].contains(&text));
+            });
+            m.drop_check(|b| {
+                // A line of 0.5 for each component and the outline
+                assert_eq!(b.num_draw_path_call, 4);
+                assert_eq!(b.num_draw_text_call, 6);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area).build_ternary().unwrap();
+        assert_eq!(
+            chart.as_coord_spec().corners(),
+            [(100, 13), (0, 187), (200, 187)]
+        );
+        chart
+            .configure_ternary_mesh()
+            .labels(2)
+            .axis_names("A", "B", "C")
+            .draw()
+            .unwrap();
+    }
+
+    #[cfg(all(feature = "line_series", feature = "point_series"))]
+    #[test]
+    fn test_ternary_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(100, 13), (100, 187)]);
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (50, 100));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area).build_ternary().unwrap();
+        chart
+            .draw_series(LineSeries::new(
+                vec![(1.0, 0.0, 0.0), (0.0, 0.5, 0.5)],
+                &RED,
+            ))
+            .unwrap();
+        chart
+            .draw_series(PointSeries::of_element(
+                vec![(0.5, 0.5, 0.0)],
+                3,
+                &BLUE,
+                &|c, s, st| Circle::new(c, s, st),
+            ))
+            .unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+use std::ops::Range;
+
+use super::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{ReversibleRanged, ZoomableRanged};
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// Get the range of an axis between two pixels, which are clamped to the pixel range of the
+/// axis. The range keeps the direction of the axis, thus the start of the range is the pixel
+/// that is closer to the start of the pixel range.
+fn zoom_axis<R: ReversibleRanged>(
+    spec: &R,
+    (a, b): (i32, i32),
+    limit: (i32, i32),
+) -> Range<R::ValueType> {
+    let range = spec.range();
+    let (low, high) = (limit.0.min(limit.1), limit.0.max(limit.1));
+    let inside = |p: i32| {
+        if p < low {
+            low
+        } else if high < p {
+            high
+        } else {
+            p
+        }
+    };
+    let (a, b) = (inside(a), inside(b));
+    if a == b {
+        return range;
+    }
+    let (first, second) = if (a - limit.0).abs() <= (b - limit.0).abs() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let start = spec.unmap(first, limit).unwrap_or(range.start);
+    let end = spec.unmap(second, limit).unwrap_or(range.end);
+    start..end
+}
+
+impl<'a, DB, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    DB: DrawingBackend,
+    X: ReversibleRanged,
+    Y: ReversibleRanged,
+{
+    /// Get the ranges of the axes selected by a rectangle in the pixels of the backend, which
+    /// is typically the rubber band dragged by the mouse in an interactive chart. The rectangle
+    /// is clamped to the plotting area, and the values are mapped back with the coordinates, so
+    /// that a log scale axis zooms in the log scale, and a date axis zooms by the time. If the
+    /// rectangle has no width or no height, the range of that axis is kept, thus a horizontal
+    /// drag only zooms the X axis.
+    /// - `pixel_rect`: The two opposite corners of the rectangle, in any order
+    /// - **returns**: The ranges of the X axis and the Y axis, in the directions of the axes
+    pub fn zoom_to(
+        &self,
+        pixel_rect: [BackendCoord; 2],
+    ) -> (Range<X::ValueType>, Range<Y::ValueType>) {
+        let spec = self.as_coord_spec();
+        let (x_limit, y_limit) = spec.pixel_limits();
+        let (from, to) = (pixel_rect[0], pixel_rect[1]);
+        (
+            zoom_axis(spec.x_spec(), (from.0, to.0), x_limit),
+            zoom_axis(spec.y_spec(), (from.1, to.1), y_limit),
+        )
+    }
+
+    /// Zoom the chart into a rectangle in the pixels of the backend, while the pixel layout of
+    /// the chart is kept unchanged. The ranges are selected with
+    /// [zoom_to](#method.zoom_to), and the coordinates are rebuilt with the ranges, thus a
+    /// log scale axis stays in the log scale.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0u8; 640 * 480 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer[..], (640, 480)).into_drawing_area();
+    /// let state = ChartBuilder::on(&root)
+    ///     .set_all_label_area_size(40)
+    ///     .build_cartesian_2d(0.0..100.0, (1.0..1000.0).log_scale())
+    ///     .unwrap()
+    ///     .into_chart_state();
+    /// // The rubber band is dragged from (200, 100) to (400, 300) in the pixels of the backend
+    /// root.fill(&WHITE).unwrap();
+    /// let mut chart = state.restore(&root).zoom([(200, 100), (400, 300)]);
+    /// let (x_range, y_range) = (chart.x_range(), chart.y_range());
+    /// assert!(20.0 < x_range.start && x_range.end < 70.0);
+    /// assert!(1.0 < y_range.start && y_range.end < 1000.0);
+    /// chart.configure_mesh().draw().unwrap();
+    /// ```
+    ///
+    /// - `pixel_rect`: The two opposite corners of the rectangle, in any order
+    /// - **returns**: The chart context with the zoomed coordinates
+    pub fn zoom(self, pixel_rect: [BackendCoord; 2]) -> Self
+    where
+        X: ZoomableRanged,
+        Y: ZoomableRanged,
+    {
+        let (x_range, y_range) = self.zoom_to(pixel_rect);
+        let x_spec = self.as_coord_spec().x_spec().zoomed(x_range);
+        let y_spec = self.as_coord_spec().y_spec().zoomed(y_range);
+        self.set_x_range(x_spec).set_y_range(y_spec)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_zoom_to() {
+        let drawing_area = create_mocked_drawing_area(110, 110, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0f64..110.0, 0.0f64..110.0)
+            .unwrap();
+        let (x, y) = chart.zoom_to([(60, 10), (20, 50)]);
+        assert!((x.start - 20.0).abs() < 1.5 && (x.end - 60.0).abs() < 1.5);
+        // The bottom of the rectangle is the start of the Y range
+        assert!((y.start - 60.0).abs() < 1.5 && (y.end - 100.0).abs() < 1.5);
+
+        // The rectangle is clamped to the plotting area
+        let (x, _) = chart.zoom_to([(-50, 10), (20, 50)]);
+        assert_eq!(x.start, 0.0);
+        // A horizontal drag doesn't zoom the Y axis
+        let (_, y) = chart.zoom_to([(10, 30), (20, 30)]);
+        assert_eq!(y, 0.0..110.0);
+    }
+
+    #[test]
+    fn test_zoom() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, (1.0f64..10000.0).log_scale())
+            .unwrap();
+        let chart = chart.zoom([(10, 25), (50, 75)]);
+        let x = chart.x_range();
+        assert!((9..=11).contains(&x.start) && (49..=51).contains(&x.end));
+        // The log scale axis is zoomed in the log scale
+        let y = chart.y_range();
+        assert!((y.start - 10.0).abs() < 1.0 && (y.end - 1000.0).abs() < 100.0);
+        assert!(chart.backend_coord(&(x.start, y.end)).1.abs() <= 1);
+    }
+}
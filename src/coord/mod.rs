@@ -21,6 +21,9 @@ See documentation for module [combinators](combinators/index.html)  for details.
 Currently we support the following 2D coordinate system:
 
 - 2-dimensional Cartesian Coordinate: This is done by the combinator [Cartesian2d](cartesian/struct.Cartesian2d.html).
+- 2-dimensional Polar Coordinate: This is done by the combinator [Polar](polar/struct.Polar.html).
+- Ternary Coordinate of the three-component compositions: This is done by [TernaryCoord](ternary/struct.TernaryCoord.html).
+- Geographic Coordinate of the longitudes and the latitudes: This is done by [GeoCoord](geo/struct.GeoCoord.html).
 
 */
 
@@ -50,6 +53,18 @@ pub mod cartesian {
     pub use super::ranged3d::Cartesian3d;
 }
 
+pub mod polar {
+    pub use super::ranged2d::polar::Polar;
+}
+
+pub mod geo {
+    pub use super::ranged2d::geo::{GeoCoord, Projection};
+}
+
+pub mod ternary {
+    pub use super::ranged2d::ternary::TernaryCoord;
+}
+
 mod translate;
 pub use translate::{CoordTranslate, ReverseCoordTranslate};
 
@@ -0,0 +1,273 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use num_traits::{NumCast, ToPrimitive};
+use std::ops::Range;
+
+/// The trait for the ranges that can be converted into a broken axis
+pub trait IntoBrokenAxis {
+    /// The type of the value
+    type ValueType: Clone + ToPrimitive + NumCast;
+
+    /// Make the broken axis that skips the given part of the range
+    ///
+    /// - `gap`: The part of the range that is cut out of the axis
+    /// - **returns**: The broken axis specification, more parts can be cut out with
+    ///   [BrokenAxisRange::break_at](struct.BrokenAxisRange.html#method.break_at)
+    fn break_at(self, gap: Range<Self::ValueType>) -> BrokenAxisRange<Self::ValueType>;
+}
+
+impl<T: Clone + ToPrimitive + NumCast> IntoBrokenAxis for Range<T> {
+    type ValueType = T;
+    fn break_at(self, gap: Range<T>) -> BrokenAxisRange<T> {
+        BrokenAxisRange {
+            range: self,
+            breaks: vec![],
+            gap_size: 0.03,
+        }
+        .break_at(gap)
+    }
+}
+
+/// The axis decorator that cuts one or more parts out of the axis, thus a few huge values don't
+/// flatten the rest of the data. The remaining segments are scaled linearly and share the axis
+/// in proportion to their lengths, and the axis line is drawn with a break marker at each gap.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(0..5, (0.0..12_000.0).break_at(100.0..10_000.0))
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(
+///         [20.0, 45.0, 11_000.0, 60.0, 80.0]
+///             .iter()
+///             .enumerate()
+///             .map(|(x, y)| Circle::new((x as i32, *y), 3, BLUE.filled())),
+///     )
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct BrokenAxisRange<V> {
+    range: Range<V>,
+    breaks: Vec<(f64, f64)>,
+    gap_size: f64,
+}
+
+impl<V: Clone + ToPrimitive + NumCast> BrokenAxisRange<V> {
+    /// Cut another part out of the axis, the overlapping parts are merged
+    pub fn break_at(mut self, gap: Range<V>) -> Self {
+        let (start, end) = match (gap.start.to_f64(), gap.end.to_f64()) {
+            (Some(a), Some(b)) if a < b => (a, b),
+            (Some(a), Some(b)) if b < a => (b, a),
+            _ => return self,
+        };
+        self.breaks.push((start, end));
+        self.breaks.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let mut merged: Vec<(f64, f64)> = vec![];
+        for (start, end) in self.breaks.iter().cloned() {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        self.breaks = merged;
+        self
+    }
+
+    /// Set the size of each gap relative to the total size of the remaining segments, which is
+    /// 0.03 by default
+    pub fn gap_size(mut self, size: f64) -> Self {
+        if size >= 0.0 {
+            self.gap_size = size;
+        }
+        self
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> From<BrokenAxisRange<V>> for BrokenAxis<V> {
+    fn from(spec: BrokenAxisRange<V>) -> BrokenAxis<V> {
+        let start = spec.range.start.to_f64().unwrap_or(0.0);
+        let end = spec.range.end.to_f64().unwrap_or(0.0);
+        let (low, high) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        // Only the parts of the breaks that are inside of the range are cut out
+        let breaks: Vec<_> = spec
+            .breaks
+            .iter()
+            .map(|&(a, b)| (a.max(low), b.min(high)))
+            .filter(|(a, b)| a < b)
+            .collect();
+        let kept = (high - low) - breaks.iter().map(|(a, b)| b - a).sum::<f64>();
+        let mut axis = BrokenAxis {
+            linear: (0.0..1.0).into(),
+            logic: spec.range,
+            breaks,
+            gap: kept * spec.gap_size,
+        };
+        axis.linear = (axis.compress(start)..axis.compress(end)).into();
+        axis
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> AsRangedCoord for BrokenAxisRange<V> {
+    type CoordDescType = BrokenAxis<V>;
+    type Value = V;
+}
+
+/// The coordinate of a broken axis, see [BrokenAxisRange](struct.BrokenAxisRange.html)
+pub struct BrokenAxis<V> {
+    linear: RangedCoordf64,
+    logic: Range<V>,
+    breaks: Vec<(f64, f64)>,
+    gap: f64,
+}
+
+impl<V> BrokenAxis<V> {
+    /// Map the value to the axis without the breaks, where each break takes the size of a gap
+    fn compress(&self, value: f64) -> f64 {
+        let mut offset = 0.0;
+        for &(start, end) in self.breaks.iter() {
+            if value <= start {
+                break;
+            }
+            if value < end {
+                return start - offset + (value - start) / (end - start) * self.gap;
+            }
+            offset += end - start - self.gap;
+        }
+        value - offset
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> BrokenAxis<V> {
+    /// The segments of the range that are shown on the axis, from the lowest to the highest
+    fn segments(&self) -> Vec<(f64, f64)> {
+        let start = self.logic.start.to_f64().unwrap_or(0.0);
+        let end = self.logic.end.to_f64().unwrap_or(0.0);
+        let (mut low, high) = (start.min(end), start.max(end));
+        let mut segments = vec![];
+        for &(start, end) in self.breaks.iter() {
+            segments.push((low, start));
+            low = end;
+        }
+        segments.push((low, high));
+        segments
+    }
+
+    fn to_value(value: f64) -> Option<V> {
+        let converted: V = NumCast::from(value)?;
+        let error = converted.to_f64()? - value;
+        if error.abs() <= 1e-9 * value.abs().max(1.0) {
+            Some(converted)
+        } else {
+            None
+        }
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> Ranged for BrokenAxis<V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        let value = self.compress(value.to_f64().unwrap_or(0.0));
+        self.linear.map(&value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        let segments = self.segments();
+        let total: f64 = segments.iter().map(|(a, b)| b - a).sum();
+        if max_points == 0 || total <= 0.0 {
+            return vec![];
+        }
+        // Each segment has its own key points, the number of them is proportional to its length
+        let mut points = vec![];
+        for (start, end) in segments {
+            let share = ((end - start) / total * max_points as f64).floor() as usize;
+            let segment: RangedCoordf64 = (start..end).into();
+            points.extend(segment.key_points(share.max(1)));
+        }
+        points.into_iter().filter_map(Self::to_value).collect()
+    }
+
+    fn range(&self) -> Range<V> {
+        self.logic.clone()
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.breaks
+            .iter()
+            .map(|&(start, _)| {
+                let center = self.compress(start) + self.gap / 2.0;
+                self.linear.map(&center, limit)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_broken_axis_map() {
+        let axis: BrokenAxis<f64> = (0.0..300.0).break_at(100.0..200.0).gap_size(0.0).into();
+        assert_eq!(axis.map(&0.0, (0, 200)), 0);
+        assert_eq!(axis.map(&100.0, (0, 200)), 100);
+        assert_eq!(axis.map(&150.0, (0, 200)), 100);
+        assert_eq!(axis.map(&250.0, (0, 200)), 150);
+        assert_eq!(axis.axis_breaks((0, 200)), vec![100]);
+
+        // Each gap takes a tenth of the remaining segments
+        let axis: BrokenAxis<i32> = (0..400)
+            .break_at(300..350)
+            .break_at(100..200)
+            .gap_size(0.1)
+            .into();
+        assert_eq!(axis.breaks, vec![(100.0, 200.0), (300.0, 350.0)]);
+        assert_eq!(axis.map(&100, (0, 300)), 100);
+        assert_eq!(axis.map(&200, (0, 300)), 125);
+        assert_eq!(axis.map(&400, (0, 300)), 300);
+        assert_eq!(axis.axis_breaks((0, 300)), vec![112, 237]);
+    }
+
+    #[test]
+    fn test_draw_break_marker() {
+        use crate::prelude::*;
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, points| {
+                assert_eq!(c, WHITE.to_rgba());
+                // The marker is on the left axis line at the middle of the gap
+                assert!(points.iter().all(|p| p.0 >= 24 && (p.1 - 99).abs() <= 3));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0..10, (0..300).break_at(100..200))
+            .unwrap();
+        chart.configure_mesh().disable_mesh().draw().unwrap();
+    }
+
+    #[test]
+    fn test_broken_axis_key_points() {
+        let axis: BrokenAxis<i32> = (0..12000).break_at(100..10000).into();
+        let points = axis.key_points(10);
+        assert!(!points.is_empty() && points.len() <= 10);
+        assert!(points.iter().all(|p| *p <= 100 || *p >= 10000));
+        assert!(points.iter().any(|p| *p >= 10000));
+        assert!(points.windows(2).all(|w| w[0] < w[1]));
+    }
+}
@@ -74,6 +74,10 @@ where
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_breaks(limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPoints<R>
@@ -197,6 +201,10 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_breaks(limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPointMethod<R> {
@@ -59,6 +59,10 @@ impl<T, R: DiscreteRanged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T>
     fn format(value: &T) -> String {
         R::format(value)
     }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.0.format_ext(value)
+    }
 }
 
 impl<T: DiscreteRanged> Ranged for GroupBy<T> {
@@ -270,6 +270,10 @@ where
     fn format(value: &T) -> String {
         R::format(value)
     }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.inner.format_ext(value)
+    }
 }
 
 impl<T: Ranged, S: Clone, R: LinspaceRoundingMethod<T::ValueType>> Ranged for Linspace<T, S, R>
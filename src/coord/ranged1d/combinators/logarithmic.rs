@@ -1,5 +1,7 @@
 use crate::coord::ranged1d::types::RangedCoordf64;
-use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use crate::coord::ranged1d::{
+    AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged, ReversibleRanged, ZoomableRanged,
+};
 use std::marker::PhantomData;
 use std::ops::Range;
 
@@ -254,6 +256,24 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
     }
 }
 
+impl<V: LogScalable> ReversibleRanged for LogCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        let value_ln = self.linear.unmap(input, limit)?;
+        Some(self.f64_to_value(value_ln.exp()))
+    }
+}
+
+impl<V: LogScalable> ZoomableRanged for LogCoord<V> {
+    fn zoomed(&self, range: Range<V>) -> Self {
+        LogRangeExt {
+            range,
+            zero: self.zero_point,
+            base: self.base,
+        }
+        .into()
+    }
+}
+
 /// The logarithmic coodinate decorator.
 /// This decorator is used to make the axis rendered as logarithmically.
 #[deprecated(note = "LogRange is deprecated, use IntoLogRange trait method instead")]
@@ -282,4 +302,17 @@ mod test {
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_log_unmap() {
+        let coord: LogCoord<f64> = (1.0..1000.0).log_scale().base(2.0).into();
+        let value = coord.unmap(50, (0, 150)).unwrap();
+        assert!((value - 10.0).abs() < 1e-6);
+        assert_eq!(coord.unmap(151, (0, 150)), None);
+
+        let zoomed = coord.zoomed(10.0..100.0);
+        assert_eq!(zoomed.range(), 10.0..100.0);
+        assert_eq!(zoomed.base, coord.base);
+        assert!((zoomed.map(&31.622_776_601_683_793, (0, 100)) - 50).abs() <= 1);
+    }
 }
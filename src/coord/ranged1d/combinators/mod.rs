@@ -1,3 +1,6 @@
+mod broken;
+pub use broken::{BrokenAxis, BrokenAxisRange, IntoBrokenAxis};
+
 mod ckps;
 pub use ckps::{BindKeyPointMethod, BindKeyPoints, WithKeyPointMethod, WithKeyPoints};
 
@@ -16,5 +19,14 @@ pub use logarithmic::LogRange;
 mod nested;
 pub use nested::{BuildNestedCoord, NestedRange, NestedValue};
 
+mod nested_segmented;
+pub use nested_segmented::{IntoNestedSegmented, NestedSegmentValue, NestedSegmentedCoord};
+
+mod nice;
+pub use nice::RangeExt;
+
 mod partial_axis;
 pub use partial_axis::{make_partial_axis, IntoPartialAxis};
+
+mod symlog;
+pub use symlog::{IntoSymLogRange, SymLogCoord, SymLogRangeExt};
@@ -0,0 +1,240 @@
+use crate::coord::ranged1d::{
+    AsRangedCoord, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use std::ops::Range;
+
+/// The value used by the [NestedSegmentedCoord](struct.NestedSegmentedCoord.html)
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum NestedSegmentValue<C, G> {
+    /// The center of a category, which is where the category label is placed
+    Category(C),
+    /// The left end of the slot of a group in a category
+    Exact(C, G),
+    /// The center of the slot of a group in a category
+    CenterOf(C, G),
+    /// The right end of the last slot of the last category
+    Last,
+}
+
+impl<C, G> From<(C, G)> for NestedSegmentValue<C, G> {
+    fn from((cat, group): (C, G)) -> Self {
+        NestedSegmentValue::Exact(cat, group)
+    }
+}
+
+/// A two-level discrete coordinate, which is typically used for grouped bar charts.
+///
+/// The outer level is a discrete coordinate of categories, and each category is divided into the
+/// same set of group slots. A value `(category, group)` refers to the left end of the group slot,
+/// thus a bar that fills exactly one slot can be described with data coordinates only, or with
+/// the [Histogram](../../series/struct.Histogram.html) series.
+///
+/// By default, only the categories are labeled, use
+/// [NestedSegmentedCoord::label_groups](#method.label_groups) to label the groups as well.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let groups = ["2019", "2020"];
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .build_cartesian_2d((0..3).nested(&groups[..]), 0..10)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .data(vec![((0, &"2019"), 3), ((0, &"2020"), 5), ((1, &"2019"), 7)]),
+///     )
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct NestedSegmentedCoord<P: DiscreteRanged, G: DiscreteRanged> {
+    primary: P,
+    groups: G,
+    label_groups: bool,
+}
+
+impl<P: DiscreteRanged, G: DiscreteRanged> NestedSegmentedCoord<P, G> {
+    /// Set if the groups should be labeled as well as the categories
+    /// - `value`: If the groups are labeled
+    pub fn label_groups(mut self, value: bool) -> Self {
+        self.label_groups = value;
+        self
+    }
+
+    fn slot_count(&self) -> usize {
+        self.primary.size() * self.groups.size()
+    }
+
+    fn map_slot(&self, slot: f64, limit: (i32, i32)) -> i32 {
+        let slot_size = f64::from(limit.1 - limit.0) / self.slot_count() as f64;
+        limit.0 + (slot * slot_size).round() as i32
+    }
+}
+
+impl<CT, GT, P, G> ValueFormatter<NestedSegmentValue<CT, GT>> for NestedSegmentedCoord<P, G>
+where
+    P: Ranged<ValueType = CT> + DiscreteRanged,
+    G: Ranged<ValueType = GT> + DiscreteRanged,
+    P: ValueFormatter<CT>,
+    G: ValueFormatter<GT>,
+{
+    fn format(value: &NestedSegmentValue<CT, GT>) -> String {
+        match value {
+            NestedSegmentValue::Category(cat) => P::format(cat),
+            NestedSegmentValue::Exact(_, group) => G::format(group),
+            NestedSegmentValue::CenterOf(_, group) => G::format(group),
+            NestedSegmentValue::Last => "".to_string(),
+        }
+    }
+}
+
+impl<P: DiscreteRanged, G: DiscreteRanged> Ranged for NestedSegmentedCoord<P, G> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = NestedSegmentValue<P::ValueType, G::ValueType>;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let group_count = self.groups.size();
+        let slot = match value {
+            NestedSegmentValue::Category(cat) => {
+                let idx = self.primary.index_of(cat).unwrap_or(0);
+                (idx * group_count) as f64 + group_count as f64 / 2.0
+            }
+            NestedSegmentValue::Exact(..) => self.index_of(value).unwrap_or(0) as f64,
+            NestedSegmentValue::CenterOf(..) => self.index_of(value).unwrap_or(0) as f64 + 0.5,
+            NestedSegmentValue::Last => self.slot_count() as f64,
+        };
+        self.map_slot(slot, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let categories = self.primary.key_points(hint.max_num_points());
+        if !self.label_groups || hint.max_num_points() < self.slot_count() + self.primary.size() {
+            return categories
+                .into_iter()
+                .map(NestedSegmentValue::Category)
+                .collect();
+        }
+
+        let mut points = vec![];
+        for c_idx in 0..self.primary.size() {
+            for group in self.groups.values() {
+                if let Some(cat) = self.primary.from_index(c_idx) {
+                    points.push(NestedSegmentValue::CenterOf(cat, group));
+                }
+            }
+            if let Some(cat) = self.primary.from_index(c_idx) {
+                points.push(NestedSegmentValue::Category(cat));
+            }
+        }
+        points
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.from_index(0).unwrap_or(NestedSegmentValue::Last)..NestedSegmentValue::Last
+    }
+}
+
+impl<P: DiscreteRanged, G: DiscreteRanged> DiscreteRanged for NestedSegmentedCoord<P, G> {
+    fn size(&self) -> usize {
+        self.slot_count() + 1
+    }
+
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        match value {
+            NestedSegmentValue::Exact(cat, group) | NestedSegmentValue::CenterOf(cat, group) => {
+                let c_idx = self.primary.index_of(cat)?;
+                let g_idx = self.groups.index_of(group)?;
+                Some(c_idx * self.groups.size() + g_idx)
+            }
+            NestedSegmentValue::Last => Some(self.slot_count()),
+            NestedSegmentValue::Category(_) => None,
+        }
+    }
+
+    fn from_index(&self, index: usize) -> Option<Self::ValueType> {
+        if index == self.slot_count() {
+            return Some(NestedSegmentValue::Last);
+        }
+        if index > self.slot_count() {
+            return None;
+        }
+        let group_count = self.groups.size();
+        Some(NestedSegmentValue::Exact(
+            self.primary.from_index(index / group_count)?,
+            self.groups.from_index(index % group_count)?,
+        ))
+    }
+}
+
+/// The trait for the discrete coordinates that can be divided into group slots, see
+/// [NestedSegmentedCoord](struct.NestedSegmentedCoord.html) for details.
+pub trait IntoNestedSegmented: AsRangedCoord
+where
+    Self::CoordDescType: DiscreteRanged,
+{
+    /// Divide each value of the coordinate into a slot for each group
+    /// - `groups`: The discrete coordinate of the groups
+    /// - **returns**: The nested segmented coordinate
+    fn nested<G: AsRangedCoord>(
+        self,
+        groups: G,
+    ) -> NestedSegmentedCoord<Self::CoordDescType, G::CoordDescType>
+    where
+        G::CoordDescType: DiscreteRanged,
+    {
+        let primary: Self::CoordDescType = self.into();
+        let groups: G::CoordDescType = groups.into();
+        assert!(primary.size() > 0 && groups.size() > 0);
+        NestedSegmentedCoord {
+            primary,
+            groups,
+            label_groups: false,
+        }
+    }
+}
+
+impl<T: AsRangedCoord> IntoNestedSegmented for T where T::CoordDescType: DiscreteRanged {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nested_segmented_coord() {
+        let coord = (0..2).nested(0..2);
+
+        assert_eq!(coord.size(), 3 * 3 + 1);
+        assert_eq!(coord.map(&(0, 0).into(), (0, 900)), 0);
+        assert_eq!(coord.map(&(1, 2).into(), (0, 900)), 500);
+        assert_eq!(
+            coord.map(&NestedSegmentValue::CenterOf(1, 2), (0, 900)),
+            550
+        );
+        assert_eq!(coord.map(&NestedSegmentValue::Category(1), (0, 900)), 450);
+        assert_eq!(coord.map(&NestedSegmentValue::Last, (0, 900)), 900);
+
+        assert_eq!(coord.index_of(&(2, 1).into()), Some(7));
+        assert_eq!(coord.from_index(8), Some(NestedSegmentValue::Exact(2, 2)));
+        assert_eq!(coord.from_index(9), Some(NestedSegmentValue::Last));
+        assert_eq!(coord.from_index(10), None);
+
+        assert_eq!(
+            coord.key_points(100),
+            vec![
+                NestedSegmentValue::Category(0),
+                NestedSegmentValue::Category(1),
+                NestedSegmentValue::Category(2)
+            ]
+        );
+
+        let coord = coord.label_groups(true);
+        let key_points = coord.key_points(100);
+        assert_eq!(key_points.len(), 12);
+        assert_eq!(key_points[1], NestedSegmentValue::CenterOf(0, 1));
+        assert_eq!(key_points[3], NestedSegmentValue::Category(0));
+    }
+}
@@ -0,0 +1,158 @@
+use num_traits::{NumCast, ToPrimitive};
+use std::ops::Range;
+
+/// The number of the ticks that a nice range is rounded for by default, which is the default
+/// number of the labels of the mesh
+const DEFAULT_TICKS: usize = 10;
+
+/// Check if the value type is an integer type, which can't hold the half
+fn is_integral<T: NumCast + ToPrimitive>() -> bool {
+    <T as NumCast>::from(0.5).and_then(|v: T| v.to_f64()) != Some(0.5)
+}
+
+/// The step of `1`, `2` or `5` times a power of 10 that divides the span into at most `count`
+/// intervals
+fn nice_step(span: f64, count: usize) -> f64 {
+    let raw = span / count.max(1) as f64;
+    let scale = 10f64.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * scale)
+        .find(|step| *step >= raw)
+        .unwrap_or(10.0 * scale)
+}
+
+/// Round the value to a multiple of the step, the step below 1 is applied with its inverse, so
+/// that the bounds such as `0.3` are exact
+fn round_to_step(value: f64, step: f64, round: fn(f64) -> f64) -> f64 {
+    if step < 1.0 {
+        let inverse = (1.0 / step).round();
+        round(value * inverse) / inverse
+    } else {
+        round(value / step) * step
+    }
+}
+
+/// Make the range from the bounds in ascending order, in the direction of the original range.
+/// The bound that doesn't fit the value type, such as a negative unsigned integer, is kept.
+fn rebuild<T: NumCast>(range: Range<T>, low: f64, high: f64) -> Range<T> {
+    let cast = |value: f64, original: T| NumCast::from(value).unwrap_or(original);
+    let reversed = range.start.to_f64() > range.end.to_f64();
+    if reversed {
+        cast(high, range.start)..cast(low, range.end)
+    } else {
+        cast(low, range.start)..cast(high, range.end)
+    }
+}
+
+/// The adapters of the numeric ranges, which are applied before the range is turned into a
+/// coordinate, thus they work with any other combinator, e.g. `(0.31..9.7).nice().step(1.0)`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// // The series doesn't touch the border and the axis ends at the round numbers
+/// assert_eq!((0.31..9.7).nice(), 0.0..10.0);
+/// assert_eq!((0.0..100.0).padded(0.05), -5.0..105.0);
+/// assert_eq!((3..97).nice(), 0..100);
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .set_all_label_area_size(40)
+///     .build_cartesian_2d((0.31..9.7).nice(), (-1.0..1.0).padded(0.1))
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+pub trait RangeExt: Sized {
+    /// Expand the range to the multiples of the nice step, which is `1`, `2` or `5` times a
+    /// power of 10, so that the ticks of the mesh land on the round numbers at both ends. The
+    /// step divides the range into about 10 intervals, which is the default number of the
+    /// labels of the mesh.
+    fn nice(self) -> Self {
+        self.nice_ticks(DEFAULT_TICKS)
+    }
+
+    /// Expand the range to the multiples of the nice step, which divides the range into at most
+    /// the given number of the intervals
+    /// - `count`: The number of the intervals, which is typically the number of the labels
+    /// - **returns**: The expanded range, in the same direction as this range
+    fn nice_ticks(self, count: usize) -> Self;
+
+    /// Add the same margin to both ends of the range, so that the series doesn't touch the
+    /// border of the plotting area. The margins of an integer range are rounded outwards.
+    /// - `fraction`: The margin of each end as the fraction of the length of the range
+    /// - **returns**: The padded range, in the same direction as this range
+    fn padded(self, fraction: f64) -> Self;
+}
+
+impl<T: NumCast + ToPrimitive> RangeExt for Range<T> {
+    fn nice_ticks(self, count: usize) -> Self {
+        let (start, end) = match (self.start.to_f64(), self.end.to_f64()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return self,
+        };
+        let (mut low, mut high) = (start.min(end), start.max(end));
+        if !(high - low).is_finite() || high == low {
+            return self;
+        }
+        // The step is computed again for the expanded range, as the expansion may need a
+        // larger step, until the range is stable
+        for _ in 0..3 {
+            let mut step = nice_step(high - low, count);
+            if is_integral::<T>() {
+                step = step.max(1.0);
+            }
+            let nice_low = round_to_step(low, step, f64::floor);
+            let nice_high = round_to_step(high, step, f64::ceil);
+            if nice_low == low && nice_high == high {
+                break;
+            }
+            low = nice_low;
+            high = nice_high;
+        }
+        rebuild(self, low, high)
+    }
+
+    fn padded(self, fraction: f64) -> Self {
+        let (start, end) = match (self.start.to_f64(), self.end.to_f64()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return self,
+        };
+        let (low, high) = (start.min(end), start.max(end));
+        let margin = (high - low) * fraction;
+        let (mut low, mut high) = (low - margin, high + margin);
+        if is_integral::<T>() {
+            low = low.floor();
+            high = high.ceil();
+        }
+        rebuild(self, low, high)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nice() {
+        assert_eq!((0.31..9.7).nice(), 0.0..10.0);
+        assert_eq!((-0.3..0.72).nice(), -0.4..0.8);
+        assert_eq!((12.0..1234.0).nice_ticks(5), 0.0..1500.0);
+        // The direction of the range is kept
+        assert_eq!((9.7..0.31).nice(), 10.0..0.0);
+        assert_eq!((3..97).nice(), 0..100);
+        assert_eq!((1..4).nice(), 1..4);
+        // The empty ranges are kept as they are
+        assert_eq!((5.0..5.0).nice(), 5.0..5.0);
+    }
+
+    #[test]
+    fn test_padded() {
+        assert_eq!((0.0..100.0).padded(0.05), -5.0..105.0);
+        assert_eq!((100.0..0.0).padded(0.05), 105.0..-5.0);
+        assert_eq!((0..10).padded(0.05), -1..11);
+        assert_eq!((0u32..10).padded(0.05), 0..11);
+        assert_eq!((0.0f32..1.0).padded(0.5), -0.5..1.5);
+    }
+}
@@ -50,6 +50,10 @@ where
 
         left.min(right)..left.max(right)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for PartialAxis<R>
@@ -0,0 +1,250 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use num_traits::{NumCast, ToPrimitive};
+use std::ops::Range;
+
+/// Convert a range to a symmetric log scale coordinate spec
+pub trait IntoSymLogRange {
+    /// The type of the value
+    type ValueType: Clone + ToPrimitive + NumCast;
+
+    /// Make the symmetric log scale coordinate, which is linear between -1 and 1 by default
+    fn symlog_scale(self) -> SymLogRangeExt<Self::ValueType>;
+}
+
+impl<T: Clone + ToPrimitive + NumCast> IntoSymLogRange for Range<T> {
+    type ValueType = T;
+    fn symlog_scale(self) -> SymLogRangeExt<T> {
+        SymLogRangeExt {
+            range: self,
+            threshold: 1.0,
+            base: 10.0,
+        }
+    }
+}
+
+/// The symmetric logarithmic coordinate decorator.
+/// This decorator makes the axis linear within the threshold around zero and logarithmic
+/// outside of it, in both directions, thus the data can span the positive and the negative
+/// values over many magnitudes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(-10.0..10.0, (-1e4..1e4).symlog_scale().threshold(10.0))
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(LineSeries::new(
+///         (-100..=100).map(|x| x as f64 / 10.0).map(|x| (x, x.powi(4) * x.signum())),
+///         &RED,
+///     ))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct SymLogRangeExt<V> {
+    range: Range<V>,
+    threshold: f64,
+    base: f64,
+}
+
+impl<V> SymLogRangeExt<V> {
+    /// Set the threshold of the linear region, the axis is linear from `-threshold` to
+    /// `threshold`
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        if threshold > 0.0 {
+            self.threshold = threshold;
+        }
+        self
+    }
+
+    /// Set the base multipler of the logarithmic regions
+    pub fn base(mut self, base: f64) -> Self {
+        if base > 1.0 {
+            self.base = base;
+        }
+        self
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> From<SymLogRangeExt<V>> for SymLogCoord<V> {
+    fn from(spec: SymLogRangeExt<V>) -> SymLogCoord<V> {
+        let mut coord = SymLogCoord {
+            linear: (0.0..1.0).into(),
+            logic: spec.range,
+            threshold: spec.threshold,
+            base: spec.base,
+        };
+        let start = coord.transform(coord.logic.start.to_f64().unwrap_or(0.0));
+        let end = coord.transform(coord.logic.end.to_f64().unwrap_or(0.0));
+        coord.linear = (start..end).into();
+        coord
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> AsRangedCoord for SymLogRangeExt<V> {
+    type CoordDescType = SymLogCoord<V>;
+    type Value = V;
+}
+
+/// A symmetric log scaled coordinate axis
+pub struct SymLogCoord<V> {
+    linear: RangedCoordf64,
+    logic: Range<V>,
+    threshold: f64,
+    base: f64,
+}
+
+impl<V> SymLogCoord<V> {
+    /// Map the value to the scale where the threshold is 1 and each power of the base above the
+    /// threshold adds 1
+    fn transform(&self, value: f64) -> f64 {
+        let scaled = value / self.threshold;
+        if scaled.abs() <= 1.0 {
+            scaled
+        } else {
+            scaled.signum() * (1.0 + scaled.abs().ln() / self.base.ln())
+        }
+    }
+
+    /// The inverse of `transform`
+    fn inverse(&self, value: f64) -> f64 {
+        if value.abs() <= 1.0 {
+            value * self.threshold
+        } else {
+            value.signum() * self.threshold * self.base.powf(value.abs() - 1.0)
+        }
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> SymLogCoord<V> {
+    /// Convert the key point to the value type, the key points that the value type can't
+    /// represent, such as the fractions for the integers, are dropped
+    fn to_value(value: f64) -> Option<V> {
+        let converted: V = NumCast::from(value)?;
+        let error = converted.to_f64()? - value;
+        if error.abs() <= 1e-9 * value.abs().max(1.0) {
+            Some(converted)
+        } else {
+            None
+        }
+    }
+}
+
+impl<V: Clone + ToPrimitive + NumCast> Ranged for SymLogCoord<V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        let value = self.transform(value.to_f64().unwrap_or(0.0));
+        self.linear.map(&value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let Range { mut start, mut end } = self.linear.range();
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+
+        // The bold points are the whole numbers on the transformed scale: zero, the thresholds
+        // and the powers of the base above the thresholds
+        let (first, last) = (start.ceil() as i64, end.floor() as i64);
+        if last - first < 1 {
+            // The range doesn't cross two bold points, thus it's labeled as a linear range
+            let linear: RangedCoordf64 = (self.inverse(start)..self.inverse(end)).into();
+            return linear
+                .key_points(hint)
+                .into_iter()
+                .filter_map(Self::to_value)
+                .collect();
+        }
+
+        let bold_count = (last - first + 1) as usize;
+        let mut step = 1;
+        while bold_count > max_points * step as usize {
+            step += 1;
+        }
+        let step = step as i64;
+
+        // The light points divide the linear region evenly and are the multiples of the powers
+        // of the base in the logarithmic regions, when their number fits
+        let base = self.base.round() as i64;
+        let with_light = hint.weight().allow_light_points()
+            && step == 1
+            && self.base.fract() == 0.0
+            && bold_count * base as usize <= max_points;
+
+        let mut points = vec![];
+        for idx in first..=last {
+            if idx % step == 0 {
+                points.push(self.inverse(idx as f64));
+            }
+            if !with_light || idx == last {
+                continue;
+            }
+            let (low, high) = (self.inverse(idx as f64), self.inverse(idx as f64 + 1.0));
+            if idx == 0 || idx == -1 {
+                let unit = (high - low) / self.base;
+                points.extend((1..base).map(|k| low + unit * k as f64));
+            } else {
+                // The bold point that is closer to zero
+                let near = if idx > 0 { low } else { high };
+                let mut multiples: Vec<_> = (2..base).map(|m| near * m as f64).collect();
+                if idx < 0 {
+                    multiples.reverse();
+                }
+                points.extend(multiples);
+            }
+        }
+        points.into_iter().filter_map(Self::to_value).collect()
+    }
+
+    fn range(&self) -> Range<V> {
+        self.logic.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::{BoldPoints, LightPoints};
+
+    #[test]
+    fn test_symlog_map() {
+        let coord: SymLogCoord<f64> = (-1000.0..1000.0).symlog_scale().into();
+        // The transformed range is -4..4, each decade takes an eighth of the axis
+        assert_eq!(coord.map(&0.0, (0, 800)), 400);
+        assert_eq!(coord.map(&1.0, (0, 800)), 500);
+        assert_eq!(coord.map(&-0.5, (0, 800)), 350);
+        assert_eq!(coord.map(&100.0, (0, 800)), 700);
+        assert_eq!(coord.map(&-1000.0, (0, 800)), 0);
+        assert!((coord.inverse(coord.transform(-42.0)) + 42.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_symlog_key_points() {
+        let coord: SymLogCoord<i32> = (-1000..1000).symlog_scale().threshold(10.0).into();
+        assert_eq!(
+            coord.key_points(BoldPoints(10)),
+            vec![-1000, -100, -10, 0, 10, 100, 1000]
+        );
+        assert_eq!(coord.key_points(BoldPoints(4)), vec![-100, 0, 100]);
+
+        let light = coord.key_points(LightPoints::new(10, 100));
+        assert!(light.contains(&-20) && light.contains(&5) && light.contains(&900));
+        assert!(light.windows(2).all(|w| w[0] < w[1]));
+
+        let coord: SymLogCoord<f64> = (0.1..0.5).symlog_scale().into();
+        let points = coord.key_points(BoldPoints(5));
+        assert!(points.len() >= 2 && points.iter().all(|p| *p >= 0.1 && *p <= 0.5));
+    }
+}
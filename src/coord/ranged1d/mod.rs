@@ -76,6 +76,13 @@ impl DefaultValueFormatOption for NoDefaultFormatting {}
 pub trait ValueFormatter<V> {
     /// Format the value
     fn format(value: &V) -> String;
+
+    /// Format the value with the coordinate, which is used by the mesh to draw the labels.
+    /// The coordinates that need their own setup to format a value, such as the time zone of a
+    /// datetime axis, override this method.
+    fn format_ext(&self, value: &V) -> String {
+        Self::format(value)
+    }
 }
 
 // By default the value is formatted by the debug trait
@@ -88,6 +95,48 @@ where
     }
 }
 
+/// The trait that decides where the ticks of an axis are. A tick locator replaces the key points
+/// of the coordinate when the mesh is drawn, see
+/// [MeshStyle::x_tick_locator](../../chart/struct.MeshStyle.html#method.x_tick_locator), thus
+/// the ticks can be placed at the data points, at the month starts or at the powers of 2 without
+/// changing the coordinate. The closures that take the range and the max number of ticks are
+/// tick locators as well.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use std::ops::Range;
+///
+/// // The ticks at the powers of 2
+/// let powers_of_two = |range: &Range<i32>, _max_points: usize| {
+///     (0..31)
+///         .map(|p| 1 << p)
+///         .filter(|v| range.start <= *v && *v <= range.end)
+///         .collect::<Vec<i32>>()
+/// };
+/// assert_eq!(powers_of_two.locate(&(3..20), 10), vec![4, 8, 16]);
+/// ```
+pub trait TickLocator<V> {
+    /// Find the positions of the labeled ticks
+    /// - `range`: The range of the axis
+    /// - `max_points`: The max number of ticks that fits the axis, which is only a hint
+    /// - **returns**: The values where the ticks are
+    fn locate(&self, range: &Range<V>, max_points: usize) -> Vec<V>;
+
+    /// Find the positions of the fine grid lines and the minor ticks, which is none by default
+    /// - `range`: The range of the axis
+    /// - `max_points`: The max number of the fine grid lines
+    /// - **returns**: The values where the fine grid lines are
+    fn locate_light(&self, _range: &Range<V>, _max_points: usize) -> Vec<V> {
+        vec![]
+    }
+}
+
+impl<V, F: Fn(&Range<V>, usize) -> Vec<V>> TickLocator<V> for F {
+    fn locate(&self, range: &Range<V>, max_points: usize) -> Vec<V> {
+        self(range, max_points)
+    }
+}
+
 /// Specify the weight of key points.
 pub enum KeyPointWeight {
     // Allows only bold key points
@@ -210,6 +259,12 @@ pub trait Ranged {
             (limit.1 + 1)..(limit.0 + 1)
         }
     }
+
+    /// This function gives the pixels where the axis is broken, which are drawn with a break
+    /// marker, see [IntoBrokenAxis](../combinators/trait.IntoBrokenAxis.html)
+    fn axis_breaks(&self, _limit: (i32, i32)) -> Vec<i32> {
+        vec![]
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
@@ -219,6 +274,16 @@ pub trait ReversibleRanged: Ranged {
     fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType>;
 }
 
+/// The trait for the coordinate that can be rebuilt with a different range, while the other
+/// settings of the coordinate, such as the base of a log scale, are kept. This is used to zoom
+/// a chart, see [ChartContext::zoom](../../chart/struct.ChartContext.html#method.zoom).
+pub trait ZoomableRanged: Ranged {
+    /// Make the same kind of coordinate with the range
+    /// - `range`: The new range of the coordinate
+    /// - **returns**: The newly created coordinate
+    fn zoomed(&self, range: Range<Self::ValueType>) -> Self;
+}
+
 /// The trait for the type that can be converted into a ranged coordinate axis
 pub trait AsRangedCoord: Sized {
     type CoordDescType: Ranged<ValueType = Self::Value> + From<Self>;
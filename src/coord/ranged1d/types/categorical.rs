@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::Range;
+
+use crate::coord::ranged1d::{
+    DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+
+/// The coordinate of the categories identified by arbitrary keys, such as the strings.
+///
+/// The categories are in the order that their keys first appear, and the repeated keys are
+/// ignored, thus the index of a key never changes. The categories are evenly spread over the
+/// axis, and the labels are skipped evenly when there are more categories than the labels fit.
+/// The labels are formatted with `Display`, so a string key is labelled as it is.
+///
+/// The coordinate is discrete, thus
+/// [into_segmented](../ranged1d/trait.IntoSegmentedCoord.html) turns it into the segmented
+/// coordinate, where each category is a segment that a bar or a boxplot is centered in with
+/// [SegmentValue::CenterOf](../ranged1d/enum.SegmentValue.html).
+///
+/// Please note: the range of an empty coordinate panics, like the
+/// [RangedSlice](struct.RangedSlice.html)
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let sales = [("apple", 30), ("pear", 12), ("plum", 21), ("apple", 5)];
+/// let fruits: Categorical<&str> = sales.iter().map(|(fruit, _)| *fruit).collect();
+/// assert_eq!(fruits.index_of(&"plum"), Some(2));
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(30)
+///     .build_cartesian_2d(fruits.into_segmented(), 0..40)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(sales.iter().map(|(fruit, count)| {
+///         let fruit = SegmentValue::CenterOf(*fruit);
+///         Rectangle::new([(fruit.clone(), 0), (fruit, *count)], BLUE.mix(0.3).filled())
+///     }))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Categorical<T: Eq + Hash> {
+    keys: Vec<T>,
+    index: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash + Clone> Categorical<T> {
+    /// Create the coordinate of the categories in the order their keys first appear
+    /// - `keys`: The keys of the categories
+    /// - **returns** The newly created coordinate
+    pub fn new<I: IntoIterator<Item = T>>(keys: I) -> Self {
+        let mut categorical = Self {
+            keys: vec![],
+            index: HashMap::new(),
+        };
+        for key in keys {
+            categorical.push(key);
+        }
+        categorical
+    }
+
+    /// Add a category after the existing ones, unless the key is already there
+    /// - `key`: The key of the category
+    /// - **returns** The index of the category
+    pub fn push(&mut self, key: T) -> usize {
+        let next = self.keys.len();
+        let keys = &mut self.keys;
+        *self.index.entry(key.clone()).or_insert_with(|| {
+            keys.push(key);
+            next
+        })
+    }
+
+    /// Get the keys of the categories in order
+    pub fn keys(&self) -> &[T] {
+        &self.keys
+    }
+}
+
+impl<T: Eq + Hash + Clone> FromIterator<T> for Categorical<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<T: Eq + Hash + Clone> Ranged for Categorical<T> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        // If there's no category, we should always panic
+        self.keys[0].clone()..self.keys[self.keys.len() - 1].clone()
+    }
+
+    fn map(&self, value: &T, limit: (i32, i32)) -> i32 {
+        match self.index.get(value) {
+            Some(&pos) if self.keys.len() > 1 => {
+                let pixel_span = f64::from(limit.1 - limit.0);
+                let value_span = (self.keys.len() - 1) as f64;
+                (f64::from(limit.0) + pixel_span * pos as f64 / value_span).round() as i32
+            }
+            _ => limit.0,
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<T> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 || self.keys.is_empty() {
+            return vec![];
+        }
+        let step = (self.keys.len() - 1) / max_points + 1;
+        self.keys.iter().step_by(step).cloned().collect()
+    }
+}
+
+impl<T: Eq + Hash + Clone> DiscreteRanged for Categorical<T> {
+    fn size(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn index_of(&self, value: &T) -> Option<usize> {
+        self.index.get(value).cloned()
+    }
+
+    fn from_index(&self, index: usize) -> Option<T> {
+        self.keys.get(index).cloned()
+    }
+}
+
+impl<T: Eq + Hash + Clone + Display> ValueFormatter<T> for Categorical<T> {
+    fn format(value: &T) -> String {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_categorical() {
+        let mut coord: Categorical<String> = vec!["b", "a", "b", "c"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(coord.keys(), &["b", "a", "c"]);
+        assert_eq!(coord.push("a".to_string()), 1);
+        assert_eq!(coord.push("d".to_string()), 3);
+
+        assert_eq!(coord.size(), 4);
+        assert_eq!(coord.index_of(&"c".to_string()), Some(2));
+        assert_eq!(coord.from_index(3), Some("d".to_string()));
+        assert_eq!(coord.from_index(4), None);
+        assert_eq!(coord.range(), "b".to_string().."d".to_string());
+        assert_eq!(coord.map(&"a".to_string(), (0, 30)), 10);
+        assert_eq!(coord.map(&"x".to_string(), (0, 30)), 0);
+        assert_eq!(Categorical::<String>::format(&"a".to_string()), "a");
+
+        // The labels are skipped evenly when they don't fit
+        assert_eq!(coord.key_points(4).len(), 4);
+        assert_eq!(coord.key_points(2), vec!["b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn test_segmented_categorical() {
+        let drawing_area = create_mocked_drawing_area(120, 100, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                assert!(["x", "y", "z"].contains(&text) || text.parse::<i32>().is_ok());
+            });
+        });
+        let coord = Categorical::new(vec!["x", "y", "z"]).into_segmented();
+        assert_eq!(coord.map(&SegmentValue::CenterOf("y"), (0, 120)), 60);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d(coord, 0..10)
+            .unwrap();
+        chart.configure_mesh().draw().unwrap();
+    }
+}
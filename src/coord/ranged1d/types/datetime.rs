@@ -1,10 +1,13 @@
 /// The datetime coordinates
 use chrono::{Date, DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use std::fmt::Display;
 use std::ops::{Add, Range, Sub};
+use std::time::Duration as StdDuration;
 
+use super::period::{compute_period_per_point, format_duration};
 use crate::coord::ranged1d::{
     AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
-    ValueFormatter,
+    ReversibleRanged, ValueFormatter, ZoomableRanged,
 };
 
 /// The trait that describe some time value. This is the uniformed abstraction that works
@@ -231,6 +234,15 @@ where
     }
 }
 
+impl<D> ZoomableRanged for RangedDate<D>
+where
+    D: Datelike + TimeValue + Sub<D, Output = Duration> + Add<Duration, Output = D> + Clone,
+{
+    fn zoomed(&self, range: Range<D>) -> Self {
+        range.into()
+    }
+}
+
 impl<Z: TimeZone> AsRangedCoord for Range<Date<Z>> {
     type CoordDescType = RangedDate<Date<Z>>;
     type Value = Date<Z>;
@@ -655,9 +667,498 @@ where
     }
 }
 
-/// The coordinate that for duration of time
+impl<DT> ReversibleRanged for RangedDateTime<DT>
+where
+    DT: Datelike + Timelike + TimeValue + Clone + PartialOrd,
+    DT: Add<Duration, Output = DT>,
+    DT: Sub<DT, Output = Duration>,
+    RangedDate<DT::DateType>: Ranged<ValueType = DT::DateType>,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<DT> {
+        let span = unmap_span(self.1.clone() - self.0.clone(), input, limit)?;
+        Some(self.0.clone() + span)
+    }
+}
+
+impl<DT> ZoomableRanged for RangedDateTime<DT>
+where
+    DT: Datelike + Timelike + TimeValue + Clone + PartialOrd,
+    DT: Add<Duration, Output = DT>,
+    DT: Sub<DT, Output = Duration>,
+    RangedDate<DT::DateType>: Ranged<ValueType = DT::DateType>,
+{
+    fn zoomed(&self, range: Range<DT>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+/// Get the time span from the start of the range to the pixel, within the pixel range
+/// - `total_span`: The time span of the whole range
+/// - `input`: The pixel
+/// - `limit`: The pixel range
+/// - **returns**: The span, or `None` if the pixel is outside of the pixel range
+fn unmap_span(total_span: Duration, input: i32, limit: (i32, i32)) -> Option<Duration> {
+    if input < limit.0.min(limit.1) || limit.0.max(limit.1) < input || limit.0 == limit.1 {
+        return None;
+    }
+    let offset = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+    Some(match total_span.num_nanoseconds() {
+        Some(total_ns) => Duration::nanoseconds((total_ns as f64 * offset).round() as i64),
+        // The span is centuries long, thus the precision of a second is enough
+        None => Duration::seconds((total_span.num_seconds() as f64 * offset).round() as i64),
+    })
+}
+
+/// The level of detail of a datetime label, which is the largest unit of time that the value
+/// is aligned to in the local time. For example, the local midnight of the first day of a month
+/// is at the `Month` level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeLevel {
+    /// The value has a fraction of a second, which is formatted as `%H:%M:%S%.3f` by default
+    SubSecond,
+    /// The value is a whole second, which is formatted as `%H:%M:%S` by default
+    Second,
+    /// The value is a whole minute, which is formatted as `%H:%M` by default
+    Minute,
+    /// The value is a whole hour, which is formatted as `%H:%M` by default
+    Hour,
+    /// The value is a midnight, which is formatted as `%b %d` by default
+    Day,
+    /// The value is the first day of a month, which is formatted as `%b %Y` by default
+    Month,
+    /// The value is the first day of a year, which is formatted as `%Y` by default
+    Year,
+}
+
+impl TimeLevel {
+    fn of<T: Datelike + Timelike>(value: &T) -> Self {
+        if value.nanosecond() != 0 {
+            TimeLevel::SubSecond
+        } else if value.second() != 0 {
+            TimeLevel::Second
+        } else if value.minute() != 0 {
+            TimeLevel::Minute
+        } else if value.hour() != 0 {
+            TimeLevel::Hour
+        } else if value.day() != 1 {
+            TimeLevel::Day
+        } else if value.month() != 1 {
+            TimeLevel::Month
+        } else {
+            TimeLevel::Year
+        }
+    }
+
+    fn default_format(self) -> &'static str {
+        match self {
+            TimeLevel::SubSecond => "%H:%M:%S%.3f",
+            TimeLevel::Second => "%H:%M:%S",
+            TimeLevel::Minute | TimeLevel::Hour => "%H:%M",
+            TimeLevel::Day => "%b %d",
+            TimeLevel::Month => "%b %Y",
+            TimeLevel::Year => "%Y",
+        }
+    }
+}
+
+/// The trait that converts a datetime range to a coordinate in the given time zone
+pub trait IntoZonedDateTime<Z: TimeZone> {
+    /// Make the coordinate that aligns the key points and formats the labels in the local time
+    /// of the time zone
+    /// - `tz`: The time zone of the labels, for example `FixedOffset` or `Local`
+    /// - **returns**: The coordinate, the values are still the datetimes of the range
+    fn with_timezone<Tz: TimeZone>(self, tz: Tz) -> ZonedDateTime<Z, Tz>;
+}
+
+impl<Z: TimeZone> IntoZonedDateTime<Z> for Range<DateTime<Z>> {
+    fn with_timezone<Tz: TimeZone>(self, tz: Tz) -> ZonedDateTime<Z, Tz> {
+        ZonedDateTime {
+            range: self,
+            tz,
+            formats: vec![],
+        }
+    }
+}
+
+/// The datetime coordinate that shows the local time of a time zone.
+///
+/// The key points are aligned in the local wall-clock time, thus the days start at the local
+/// midnights, and the local times that are skipped at a daylight saving time change have no key
+/// point. Each label is formatted by the [TimeLevel](enum.TimeLevel.html) of its value, so a
+/// midnight shows the date and the other hours show the time, and the `strftime` style format of
+/// each level can be overridden with [label_format](#method.label_format).
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2020, 1, 3, 0, 0, 0).unwrap();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(30)
+///     .build_cartesian_2d(
+///         (start..end)
+///             .with_timezone(FixedOffset::east_opt(-5 * 3600).unwrap())
+///             .label_format(TimeLevel::Day, "%a %d"),
+///         0..10,
+///     )
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ZonedDateTime<Z: TimeZone, Tz: TimeZone> {
+    range: Range<DateTime<Z>>,
+    tz: Tz,
+    formats: Vec<(TimeLevel, String)>,
+}
+
+impl<Z: TimeZone, Tz: TimeZone> ZonedDateTime<Z, Tz> {
+    /// Set the `strftime` style format of the labels at the level
+    /// - `level`: The level of the labels that use the format
+    /// - `fmt`: The format, see `chrono::format::strftime` for the syntax
+    pub fn label_format(mut self, level: TimeLevel, fmt: &str) -> Self {
+        self.formats.retain(|(l, _)| *l != level);
+        self.formats.push((level, fmt.to_string()));
+        self
+    }
+
+    /// Split the range into the buckets of a calendar unit in the local time, so that the
+    /// [Histogram](../../series/struct.Histogram.html) series counts the values of each bucket
+    /// - `bucket`: The calendar unit of the buckets
+    /// - **returns**: The discrete coordinate of the buckets
+    pub fn buckets(self, bucket: TimeBucket) -> TimeBuckets<Z, Tz> {
+        TimeBuckets::new(self, bucket)
+    }
+
+    /// Get the datetimes that have the local time, there are two of them if the local time
+    /// repeats when the daylight saving time ends, and none if the local time is skipped
+    fn local_to_datetimes(&self, local: &NaiveDateTime) -> Vec<DateTime<Z>> {
+        let zone = self.range.start.timezone();
+        match self.tz.from_local_datetime(local) {
+            chrono::LocalResult::Single(t) => vec![t.with_timezone(&zone)],
+            chrono::LocalResult::Ambiguous(a, b) => {
+                vec![a.with_timezone(&zone), b.with_timezone(&zone)]
+            }
+            chrono::LocalResult::None => vec![],
+        }
+    }
+
+    /// Get the beginning of the local date, which isn't the midnight when the daylight saving
+    /// time starts at the midnight
+    fn start_of_day(&self, date: NaiveDate) -> Option<DateTime<Z>> {
+        (0..24)
+            .filter_map(|hour| date.and_hms_opt(hour, 0, 0))
+            .filter_map(|local| self.local_to_datetimes(&local).into_iter().next())
+            .next()
+    }
+}
+
+impl<Z: TimeZone, Tz: TimeZone> Ranged for ZonedDateTime<Z, Tz> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = DateTime<Z>;
+
+    fn range(&self) -> Range<DateTime<Z>> {
+        self.range.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        TimeValue::map_coord(value, &self.range.start, &self.range.end, limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        let start = self.range.start.with_timezone(&self.tz).naive_local();
+        let end = self.range.end.with_timezone(&self.tz).naive_local();
+        let total_span = self
+            .range
+            .end
+            .clone()
+            .signed_duration_since(self.range.start.clone());
+
+        let period = total_span
+            .num_nanoseconds()
+            .and_then(|total_ns| compute_period_per_point(total_ns as u64, max_points, true));
+
+        let mut ret = vec![];
+        if let Some(ns_per_point) = period {
+            // Walk through the local time from the local midnight, thus the key points are
+            // aligned to the local hours and days
+            let start_ns = u64::from(start.num_seconds_from_midnight()) * 1_000_000_000
+                + u64::from(start.nanosecond());
+            let aligned_ns = if start_ns % ns_per_point > 0 {
+                start_ns + (ns_per_point - start_ns % ns_per_point)
+            } else {
+                start_ns
+            };
+            let mut local = NaiveDateTime::from_date(start.date_floor())
+                + Duration::nanoseconds(aligned_ns as i64);
+            while local <= end {
+                ret.extend(self.local_to_datetimes(&local));
+                local += Duration::nanoseconds(ns_per_point as i64);
+            }
+        } else {
+            let date_range = RangedDate(start.date_ceil(), end.date_floor());
+            ret = date_range
+                .key_points(max_points)
+                .into_iter()
+                .filter_map(|date| self.start_of_day(date))
+                .collect();
+        }
+
+        ret.retain(|t| *t >= self.range.start && *t < self.range.end);
+        ret
+    }
+}
+
+impl<Z: TimeZone, Tz: TimeZone> ReversibleRanged for ZonedDateTime<Z, Tz> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<DateTime<Z>> {
+        let total_span = self
+            .range
+            .end
+            .clone()
+            .signed_duration_since(self.range.start.clone());
+        let span = unmap_span(total_span, input, limit)?;
+        Some(self.range.start.clone() + span)
+    }
+}
+
+impl<Z: TimeZone, Tz: TimeZone> ZoomableRanged for ZonedDateTime<Z, Tz> {
+    fn zoomed(&self, range: Range<DateTime<Z>>) -> Self {
+        Self {
+            range,
+            tz: self.tz.clone(),
+            formats: self.formats.clone(),
+        }
+    }
+}
+
+impl<Z: TimeZone, Tz: TimeZone> ValueFormatter<DateTime<Z>> for ZonedDateTime<Z, Tz>
+where
+    Z::Offset: Display,
+    Tz::Offset: Display,
+{
+    fn format(value: &DateTime<Z>) -> String {
+        value
+            .format(TimeLevel::of(value).default_format())
+            .to_string()
+    }
+
+    fn format_ext(&self, value: &DateTime<Z>) -> String {
+        let local = value.with_timezone(&self.tz);
+        let level = TimeLevel::of(&local);
+        let fmt = self
+            .formats
+            .iter()
+            .find(|(l, _)| *l == level)
+            .map_or(level.default_format(), |(_, fmt)| fmt.as_str());
+        local.format(fmt).to_string()
+    }
+}
+
+/// The calendar unit of the buckets of a [TimeBuckets](struct.TimeBuckets.html) coordinate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeBucket {
+    /// The buckets of a minute
+    Minute,
+    /// The buckets of an hour
+    Hour,
+    /// The days, which start at the local midnights
+    Day,
+    /// The weeks, which start at the local midnights of the Mondays
+    Week,
+    /// The months, which start at the local midnights of their first days
+    Month,
+}
+
+impl TimeBucket {
+    /// The local start of the bucket that contains the local time
+    fn floor(self, local: &NaiveDateTime) -> NaiveDateTime {
+        let date = local.date();
+        match self {
+            TimeBucket::Minute => date.and_hms_opt(local.hour(), local.minute(), 0),
+            TimeBucket::Hour => date.and_hms_opt(local.hour(), 0, 0),
+            TimeBucket::Day => date.and_hms_opt(0, 0, 0),
+            TimeBucket::Week => (date
+                - Duration::days(i64::from(date.weekday().num_days_from_monday())))
+            .and_hms_opt(0, 0, 0),
+            TimeBucket::Month => date.with_day(1).and_then(|d| d.and_hms_opt(0, 0, 0)),
+        }
+        .unwrap()
+    }
+
+    /// The local start of the bucket after the bucket that starts at the local time
+    fn next(self, local: &NaiveDateTime) -> NaiveDateTime {
+        match self {
+            TimeBucket::Minute => *local + Duration::minutes(1),
+            TimeBucket::Hour => *local + Duration::hours(1),
+            TimeBucket::Day => *local + Duration::days(1),
+            TimeBucket::Week => *local + Duration::weeks(1),
+            TimeBucket::Month => {
+                let (year, month) = match local.month() {
+                    12 => (local.year() + 1, 1),
+                    month => (local.year(), month + 1),
+                };
+                NaiveDate::from_ymd_opt(year, month, 1)
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+                    .unwrap()
+            }
+        }
+    }
+}
+
+/// The discrete datetime coordinate that splits the time into the buckets of a calendar unit in
+/// the local time of a time zone, which is made by [ZonedDateTime::buckets](struct.ZonedDateTime.html#method.buckets).
+///
+/// Each value is in the bucket that starts at or before it, thus the
+/// [Histogram](../../series/struct.Histogram.html) series counts the events of each minute,
+/// hour, day, week or month on this coordinate. The boundaries follow the daylight saving time
+/// changes of the time zone: the day that the clocks move forward is one hour shorter, the hour
+/// that is repeated when the clocks move back makes two buckets, and the hour that is skipped
+/// makes none. The range is extended to the whole buckets, and the labels are the starts of the
+/// buckets, which are formatted in the same way as [ZonedDateTime](struct.ZonedDateTime.html).
+///
+/// ```rust
+/// use chrono::{Duration, TimeZone, Utc};
+/// use plotters::prelude::*;
+///
+/// let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// let events: Vec<_> = (0..200).map(|i| start + Duration::minutes(i * i)).collect();
+/// let end = events[events.len() - 1];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(30)
+///     .build_cartesian_2d((start..end).with_timezone(Utc).buckets(TimeBucket::Day), 0..100)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .style(BLUE.filled())
+///             .data(events.iter().map(|t| (*t, 1))),
+///     )
+///     .unwrap();
+/// ```
 #[derive(Clone)]
-pub struct RangedDuration(Duration, Duration);
+pub struct TimeBuckets<Z: TimeZone, Tz: TimeZone> {
+    zoned: ZonedDateTime<Z, Tz>,
+    bucket: TimeBucket,
+    edges: Vec<DateTime<Z>>,
+}
+
+impl<Z: TimeZone, Tz: TimeZone> TimeBuckets<Z, Tz> {
+    fn new(zoned: ZonedDateTime<Z, Tz>, bucket: TimeBucket) -> Self {
+        let end = zoned.range.end.clone();
+        let mut local = bucket.floor(&zoned.range.start.with_timezone(&zoned.tz).naive_local());
+        let mut edges = vec![];
+        loop {
+            let mut starts = zoned.local_to_datetimes(&local);
+            if bucket >= TimeBucket::Day {
+                // The day starts at the earliest local time of the date, even though the
+                // midnight is repeated or skipped
+                starts.truncate(1);
+                if starts.is_empty() {
+                    starts.extend(zoned.start_of_day(local.date()));
+                }
+            }
+            let done = match starts.first() {
+                Some(first) => *first >= end,
+                None => false,
+            };
+            edges.extend(starts);
+            if done {
+                break;
+            }
+            local = bucket.next(&local);
+        }
+        // The two passes of a repeated hour are interleaved
+        edges.sort();
+        edges.dedup();
+        Self {
+            zoned,
+            bucket,
+            edges,
+        }
+    }
+
+    /// Get the calendar unit of the buckets
+    pub fn bucket(&self) -> TimeBucket {
+        self.bucket
+    }
+
+    /// Get the starts of the buckets, followed by the end of the last bucket
+    pub fn edges(&self) -> &[DateTime<Z>] {
+        &self.edges
+    }
+}
+
+impl<Z: TimeZone, Tz: TimeZone> Ranged for TimeBuckets<Z, Tz> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = DateTime<Z>;
+
+    fn range(&self) -> Range<DateTime<Z>> {
+        self.edges[0].clone()..self.edges[self.edges.len() - 1].clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let (first, last) = (&self.edges[0], &self.edges[self.edges.len() - 1]);
+        TimeValue::map_coord(value, first, last, limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = (self.edges.len() - 1) / max_points + 1;
+        self.edges.iter().step_by(step).cloned().collect()
+    }
+}
+
+impl<Z: TimeZone, Tz: TimeZone> DiscreteRanged for TimeBuckets<Z, Tz> {
+    fn size(&self) -> usize {
+        self.edges.len() - 1
+    }
+
+    fn index_of(&self, value: &DateTime<Z>) -> Option<usize> {
+        if *value < self.edges[0] || *value >= self.edges[self.edges.len() - 1] {
+            return None;
+        }
+        match self.edges.binary_search(value) {
+            Ok(idx) => Some(idx),
+            Err(idx) => Some(idx - 1),
+        }
+    }
+
+    fn from_index(&self, index: usize) -> Option<DateTime<Z>> {
+        self.edges.get(index).cloned()
+    }
+}
+
+impl<Z: TimeZone, Tz: TimeZone> ValueFormatter<DateTime<Z>> for TimeBuckets<Z, Tz>
+where
+    Z::Offset: Display,
+    Tz::Offset: Display,
+{
+    fn format(value: &DateTime<Z>) -> String {
+        ZonedDateTime::<Z, Tz>::format(value)
+    }
+
+    fn format_ext(&self, value: &DateTime<Z>) -> String {
+        self.zoned.format_ext(value)
+    }
+}
+
+/// The coordinate that for duration of time, which works for both `chrono::Duration` and
+/// `std::time::Duration`. The labels are formatted in the unit that fits the value, from
+/// nanoseconds to hours, such as `250ms` or `1.5s`.
+#[derive(Clone)]
+pub struct RangedDuration<D = Duration>(D, D);
 
 impl AsRangedCoord for Range<Duration> {
     type CoordDescType = RangedDuration;
@@ -670,8 +1171,28 @@ impl From<Range<Duration>> for RangedDuration {
     }
 }
 
+impl ValueFormatter<Duration> for RangedDuration {
+    fn format(value: &Duration) -> String {
+        let (negative, value) = if *value < Duration::zero() {
+            (true, -*value)
+        } else {
+            (false, *value)
+        };
+        // The durations that don't fit in a std duration are longer than the age of the universe
+        let value = value
+            .to_std()
+            .unwrap_or_else(|_| StdDuration::new(u64::MAX, 999_999_999));
+        let text = format_duration(&value);
+        if negative {
+            format!("-{}", text)
+        } else {
+            text
+        }
+    }
+}
+
 impl Ranged for RangedDuration {
-    type FormatOption = DefaultFormatting;
+    type FormatOption = NoDefaultFormatting;
     type ValueType = Duration;
 
     fn range(&self) -> Range<Duration> {
@@ -765,80 +1286,61 @@ impl Ranged for RangedDuration {
     }
 }
 
-#[allow(clippy::inconsistent_digit_grouping)]
-fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -> Option<u64> {
-    let min_ns_per_point = total_ns as f64 / max_points as f64;
-    let actual_ns_per_point: u64 = (10u64).pow((min_ns_per_point as f64).log10().floor() as u32);
-
-    fn determine_actual_ns_per_point(
-        total_ns: u64,
-        mut actual_ns_per_point: u64,
-        units: &[u64],
-        base: u64,
-        max_points: usize,
-    ) -> u64 {
-        let mut unit_per_point_idx = 0;
-        while total_ns / actual_ns_per_point > max_points as u64 * units[unit_per_point_idx] {
-            unit_per_point_idx += 1;
-            if unit_per_point_idx == units.len() {
-                unit_per_point_idx = 0;
-                actual_ns_per_point *= base;
-            }
-        }
-        units[unit_per_point_idx] * actual_ns_per_point
+impl AsRangedCoord for Range<StdDuration> {
+    type CoordDescType = RangedDuration<StdDuration>;
+    type Value = StdDuration;
+}
+
+impl From<Range<StdDuration>> for RangedDuration<StdDuration> {
+    fn from(range: Range<StdDuration>) -> Self {
+        Self(range.start, range.end)
     }
+}
 
-    if actual_ns_per_point < 1_000_000_000 {
-        Some(determine_actual_ns_per_point(
-            total_ns as u64,
-            actual_ns_per_point,
-            &[1, 2, 5],
-            10,
-            max_points,
-        ))
-    } else if actual_ns_per_point < 3600_000_000_000 {
-        Some(determine_actual_ns_per_point(
-            total_ns as u64,
-            1_000_000_000,
-            &[1, 2, 5, 10, 15, 20, 30],
-            60,
-            max_points,
-        ))
-    } else if actual_ns_per_point < 3600_000_000_000 * 24 {
-        Some(determine_actual_ns_per_point(
-            total_ns as u64,
-            3600_000_000_000,
-            &[1, 2, 4, 8, 12],
-            24,
-            max_points,
-        ))
-    } else if !sub_daily {
-        if actual_ns_per_point < 3600_000_000_000 * 24 * 10 {
-            Some(determine_actual_ns_per_point(
-                total_ns as u64,
-                3600_000_000_000 * 24,
-                &[1, 2, 5, 7],
-                10,
-                max_points,
-            ))
-        } else {
-            Some(determine_actual_ns_per_point(
-                total_ns as u64,
-                3600_000_000_000 * 24 * 10,
-                &[1, 2, 5],
-                10,
-                max_points,
-            ))
-        }
-    } else {
-        None
+impl RangedDuration<StdDuration> {
+    /// The same coordinate for `chrono::Duration`, which computes the mapping and key points
+    fn as_chrono(&self) -> RangedDuration {
+        RangedDuration(to_chrono(&self.0), to_chrono(&self.1))
+    }
+}
+
+impl ValueFormatter<StdDuration> for RangedDuration<StdDuration> {
+    fn format(value: &StdDuration) -> String {
+        format_duration(value)
     }
 }
 
+impl Ranged for RangedDuration<StdDuration> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = StdDuration;
+
+    fn range(&self) -> Range<StdDuration> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.as_chrono().map(&to_chrono(value), limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
+        self.as_chrono()
+            .key_points(hint)
+            .into_iter()
+            .filter_map(|d| d.to_std().ok())
+            .collect()
+    }
+}
+
+/// Convert the std duration, the ones that are too long for chrono are cut to about 270 million
+/// years
+fn to_chrono(value: &StdDuration) -> Duration {
+    Duration::from_std(*value).unwrap_or_else(|_| Duration::days(100_000_000_000))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use chrono::{TimeZone, Utc};
+    use chrono::{LocalResult, TimeZone, Utc};
 
     #[test]
     fn test_date_range_long() {
@@ -1126,6 +1628,34 @@ mod test {
         assert_eq!(max, 3600 * 2);
     }
 
+    #[test]
+    fn test_duration_format() {
+        let format = |d| <RangedDuration as ValueFormatter<Duration>>::format(&d);
+        assert_eq!(format(Duration::nanoseconds(250)), "250ns");
+        assert_eq!(format(Duration::microseconds(12)), "12µs");
+        assert_eq!(format(Duration::milliseconds(250)), "250ms");
+        assert_eq!(format(Duration::milliseconds(1500)), "1.5s");
+        assert_eq!(format(Duration::seconds(-90)), "-1.5min");
+        assert_eq!(format(Duration::hours(30)), "30h");
+        assert_eq!(format(Duration::zero()), "0ns");
+    }
+
+    #[test]
+    fn test_std_duration_range() {
+        use std::time::Duration;
+        let coord: RangedDuration<Duration> =
+            (Duration::from_millis(0)..Duration::from_millis(100)).into();
+        assert_eq!(coord.map(&Duration::from_millis(25), (0, 100)), 25);
+
+        let kps = coord.key_points(10);
+        assert!(kps.len() <= 10);
+        assert_eq!(kps[1] - kps[0], Duration::from_millis(10));
+        assert_eq!(
+            RangedDuration::<Duration>::format(&Duration::from_micros(1500)),
+            "1.5ms"
+        );
+    }
+
     #[test]
     fn test_date_discrete() {
         let coord: RangedDate<Date<_>> = (Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 12, 31)).into();
@@ -1160,4 +1690,216 @@ mod test {
             assert_eq!(coord1.index_of(&coord1.from_index(i).unwrap()).unwrap(), i);
         }
     }
+
+    #[test]
+    fn test_zoned_datetime_key_points() {
+        use chrono::FixedOffset;
+        let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 1, 3, 0, 0, 0).unwrap();
+        let coord = (start..end).with_timezone(tz);
+
+        // The hours are aligned in the local time rather than in UTC
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+        for kp in kps.iter() {
+            let local = kp.with_timezone(&tz);
+            assert_eq!((local.hour() % 8, local.minute()), (0, 0));
+        }
+
+        let end = Utc.with_ymd_and_hms(2020, 1, 11, 0, 0, 0).unwrap();
+        let coord = (start..end).with_timezone(tz);
+        let kps = coord.key_points(10);
+        assert!(kps.len() >= 9 && kps.len() <= 10);
+        assert!(kps
+            .iter()
+            .all(|kp| kp.with_timezone(&tz).num_seconds_from_midnight() == 0));
+        assert_eq!(kps[0], Utc.with_ymd_and_hms(2020, 1, 1, 18, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn test_datetime_unmap() {
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 1, 11, 0, 0, 0).unwrap();
+        let coord: RangedDateTime<_> = (start..end).into();
+        let middle = Utc.with_ymd_and_hms(2020, 1, 6, 0, 0, 0).unwrap();
+        assert_eq!(coord.unmap(50, (0, 100)), Some(middle));
+        assert_eq!(coord.unmap(101, (0, 100)), None);
+        assert_eq!(coord.zoomed(middle..end).range(), middle..end);
+
+        let coord = (start..end).with_timezone(chrono::FixedOffset::east_opt(3600).unwrap());
+        assert_eq!(coord.unmap(50, (100, 0)), Some(middle));
+        let zoomed = coord.zoomed(middle..end);
+        assert_eq!(zoomed.range(), middle..end);
+        assert_eq!(zoomed.format_ext(&middle), coord.format_ext(&middle));
+    }
+
+    #[test]
+    fn test_zoned_datetime_format() {
+        use chrono::FixedOffset;
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 3, 1, 0, 0, 0).unwrap();
+        let coord = (start.clone()..end).with_timezone(tz);
+
+        let at = |d, h, m| Utc.with_ymd_and_hms(2020, 1, d, h, m, 0).unwrap();
+        assert_eq!(coord.format_ext(&at(2, 5, 0)), "Jan 02");
+        assert_eq!(coord.format_ext(&at(2, 6, 30)), "01:30");
+        assert_eq!(coord.format_ext(&start), "19:00");
+        assert_eq!(ZonedDateTime::<Utc, FixedOffset>::format(&start), "2020");
+
+        let coord = coord.label_format(TimeLevel::Day, "%m/%d");
+        assert_eq!(coord.format_ext(&at(2, 5, 0)), "01/02");
+        let feb = Utc.with_ymd_and_hms(2020, 2, 1, 5, 0, 0).unwrap();
+        assert_eq!(coord.format_ext(&feb), "Feb 2020");
+    }
+
+    #[test]
+    fn test_zoned_datetime_mesh_labels() {
+        use crate::prelude::*;
+        use chrono::FixedOffset;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let collected = labels.clone();
+        let drawing_area = create_mocked_drawing_area(600, 200, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                collected.borrow_mut().push(text.to_string());
+            });
+        });
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 1, 5, 0, 0, 0).unwrap();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d((start..end).with_timezone(tz), 0..10)
+            .unwrap();
+        chart.configure_mesh().x_labels(5).draw().unwrap();
+
+        // The local midnights are 15:00 in UTC and shown as the dates
+        let labels = labels.borrow();
+        assert!(labels.contains(&"Jan 02".to_string()));
+        assert!(!labels.iter().any(|l| l.contains("UTC")));
+    }
+
+    /// The time zone that is one hour ahead of UTC between 2020-03-29 01:00 UTC and
+    /// 2020-10-25 01:00 UTC, like London in 2020
+    #[derive(Clone, Copy, Debug)]
+    struct Summer;
+
+    impl Summer {
+        fn offset_at(utc: &NaiveDateTime) -> chrono::FixedOffset {
+            let begin = NaiveDate::from_ymd_opt(2020, 3, 29)
+                .unwrap()
+                .and_hms_opt(1, 0, 0);
+            let end = NaiveDate::from_ymd_opt(2020, 10, 25)
+                .unwrap()
+                .and_hms_opt(1, 0, 0);
+            let summer = *utc >= begin.unwrap() && *utc < end.unwrap();
+            chrono::FixedOffset::east_opt(if summer { 3600 } else { 0 }).unwrap()
+        }
+    }
+
+    impl TimeZone for Summer {
+        type Offset = chrono::FixedOffset;
+
+        fn from_offset(_: &chrono::FixedOffset) -> Self {
+            Summer
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<chrono::FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_datetime(
+            &self,
+            local: &NaiveDateTime,
+        ) -> LocalResult<chrono::FixedOffset> {
+            let offsets: Vec<_> = [3600, 0]
+                .iter()
+                .map(|&secs| chrono::FixedOffset::east_opt(secs).unwrap())
+                .filter(|offset| Self::offset_at(&(*local - *offset)) == *offset)
+                .collect();
+            match offsets[..] {
+                [a, b] => LocalResult::Ambiguous(a, b),
+                [a] => LocalResult::Single(a),
+                _ => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> chrono::FixedOffset {
+            Self::offset_at(&utc.and_hms_opt(0, 0, 0).unwrap())
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> chrono::FixedOffset {
+            Self::offset_at(utc)
+        }
+    }
+
+    #[test]
+    fn test_time_buckets() {
+        let at = |m, d, h, min| Utc.with_ymd_and_hms(2020, m, d, h, min, 0).unwrap();
+        let coord = (at(1, 15, 10, 30)..at(3, 2, 0, 0))
+            .with_timezone(Utc)
+            .buckets(TimeBucket::Month);
+        assert_eq!(
+            coord.edges(),
+            &[
+                at(1, 1, 0, 0),
+                at(2, 1, 0, 0),
+                at(3, 1, 0, 0),
+                at(4, 1, 0, 0)
+            ]
+        );
+        assert_eq!(coord.size(), 3);
+        assert_eq!(coord.index_of(&at(2, 29, 23, 59)), Some(1));
+        assert_eq!(coord.index_of(&at(4, 1, 0, 0)), None);
+        assert_eq!(coord.from_index(3), Some(at(4, 1, 0, 0)));
+        assert_eq!(coord.range(), at(1, 1, 0, 0)..at(4, 1, 0, 0));
+
+        // 2020-01-15 is a Wednesday
+        let coord = (at(1, 15, 10, 30)..at(1, 16, 0, 0))
+            .with_timezone(Utc)
+            .buckets(TimeBucket::Week);
+        assert_eq!(coord.edges(), &[at(1, 13, 0, 0), at(1, 20, 0, 0)]);
+        let coord = (at(1, 15, 10, 30)..at(1, 15, 10, 32))
+            .with_timezone(Utc)
+            .buckets(TimeBucket::Minute);
+        assert_eq!(coord.size(), 2);
+        assert_eq!(coord.key_points(1), vec![at(1, 15, 10, 30)]);
+    }
+
+    #[test]
+    fn test_time_buckets_dst() {
+        let at = |m, d, h| Utc.with_ymd_and_hms(2020, m, d, h, 0, 0).unwrap();
+
+        // The clocks move forward at 01:00 UTC, thus the day is 23 hours long
+        let coord = (at(3, 28, 12)..at(3, 30, 12))
+            .with_timezone(Summer)
+            .buckets(TimeBucket::Day);
+        assert_eq!(
+            coord.edges(),
+            &[at(3, 28, 0), at(3, 29, 0), at(3, 29, 23), at(3, 30, 23)]
+        );
+        // The clocks move back at 01:00 UTC, thus the day is 25 hours long and its local
+        // 01:00 hour is repeated
+        let coord = (at(10, 25, 0)..at(10, 25, 2))
+            .with_timezone(Summer)
+            .buckets(TimeBucket::Day);
+        assert_eq!(coord.edges(), &[at(10, 24, 23), at(10, 26, 0)]);
+        let coord = (at(10, 24, 23)..at(10, 25, 2))
+            .with_timezone(Summer)
+            .buckets(TimeBucket::Hour);
+        assert_eq!(
+            coord.edges(),
+            &[at(10, 24, 23), at(10, 25, 0), at(10, 25, 1), at(10, 25, 2)]
+        );
+        // The local 01:00 hour is skipped, so is its bucket
+        let coord = (at(3, 29, 0)..at(3, 29, 2))
+            .with_timezone(Summer)
+            .buckets(TimeBucket::Hour);
+        assert_eq!(coord.edges(), &[at(3, 29, 0), at(3, 29, 1), at(3, 29, 2)]);
+        assert_eq!(coord.format_ext(&at(3, 29, 1)), "02:00");
+    }
 }
@@ -2,14 +2,26 @@
 mod datetime;
 #[cfg(feature = "chrono")]
 pub use datetime::{
-    IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime, RangedDuration, Yearly,
+    IntoMonthly, IntoYearly, IntoZonedDateTime, Monthly, RangedDate, RangedDateTime,
+    RangedDuration, TimeBucket, TimeBuckets, TimeLevel, Yearly, ZonedDateTime,
 };
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod period;
+
+#[cfg(feature = "time")]
+mod time_coord;
+#[cfg(feature = "time")]
+pub use time_coord::{RangedOffsetDateTime, RangedTimeDate, RangedTimeDuration};
+
 mod numeric;
 pub use numeric::{
     RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
     RangedCoordu128, RangedCoordu32, RangedCoordu64, RangedCoordusize,
 };
 
+mod categorical;
+pub use categorical::Categorical;
+
 mod slice;
 pub use slice::RangedSlice;
@@ -3,7 +3,7 @@ use std::ops::Range;
 
 use crate::coord::ranged1d::{
     AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
-    ReversibleRanged, ValueFormatter,
+    ReversibleRanged, ValueFormatter, ZoomableRanged,
 };
 
 macro_rules! impl_discrete_trait {
@@ -97,6 +97,11 @@ macro_rules! make_numeric_coord {
                 return self.0..self.1;
             }
         }
+        impl ZoomableRanged for $name {
+            fn zoomed(&self, range: Range<$type>) -> Self {
+                range.into()
+            }
+        }
     };
     ($type:ty, $name:ident, $key_points:ident, $doc: expr) => {
         make_numeric_coord!($type, $name, $key_points, $doc, DefaultFormatting);
@@ -0,0 +1,97 @@
+use std::time::Duration as StdDuration;
+
+/// Format the duration in the largest unit that is at most the duration, with up to 3 decimals
+pub(super) fn format_duration(value: &StdDuration) -> String {
+    const UNITS: &[(&str, f64)] = &[
+        ("h", 3600.0),
+        ("min", 60.0),
+        ("s", 1.0),
+        ("ms", 1e-3),
+        ("µs", 1e-6),
+    ];
+    if value.as_secs() == 0 && value.subsec_nanos() < 1000 {
+        return format!("{}ns", value.subsec_nanos());
+    }
+    let secs = value.as_secs() as f64 + f64::from(value.subsec_nanos()) / 1e9;
+    let (unit, size) = UNITS
+        .iter()
+        .find(|(_, size)| secs >= *size)
+        .unwrap_or(&UNITS[UNITS.len() - 1]);
+    let text = format!("{:.3}", secs / size);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", text, unit)
+}
+
+#[allow(clippy::inconsistent_digit_grouping)]
+pub(super) fn compute_period_per_point(
+    total_ns: u64,
+    max_points: usize,
+    sub_daily: bool,
+) -> Option<u64> {
+    let min_ns_per_point = total_ns as f64 / max_points as f64;
+    let actual_ns_per_point: u64 = (10u64).pow(min_ns_per_point.log10().floor() as u32);
+
+    fn determine_actual_ns_per_point(
+        total_ns: u64,
+        mut actual_ns_per_point: u64,
+        units: &[u64],
+        base: u64,
+        max_points: usize,
+    ) -> u64 {
+        let mut unit_per_point_idx = 0;
+        while total_ns / actual_ns_per_point > max_points as u64 * units[unit_per_point_idx] {
+            unit_per_point_idx += 1;
+            if unit_per_point_idx == units.len() {
+                unit_per_point_idx = 0;
+                actual_ns_per_point *= base;
+            }
+        }
+        units[unit_per_point_idx] * actual_ns_per_point
+    }
+
+    if actual_ns_per_point < 1_000_000_000 {
+        Some(determine_actual_ns_per_point(
+            total_ns,
+            actual_ns_per_point,
+            &[1, 2, 5],
+            10,
+            max_points,
+        ))
+    } else if actual_ns_per_point < 3600_000_000_000 {
+        Some(determine_actual_ns_per_point(
+            total_ns,
+            1_000_000_000,
+            &[1, 2, 5, 10, 15, 20, 30],
+            60,
+            max_points,
+        ))
+    } else if actual_ns_per_point < 3600_000_000_000 * 24 {
+        Some(determine_actual_ns_per_point(
+            total_ns,
+            3600_000_000_000,
+            &[1, 2, 4, 8, 12],
+            24,
+            max_points,
+        ))
+    } else if !sub_daily {
+        if actual_ns_per_point < 3600_000_000_000 * 24 * 10 {
+            Some(determine_actual_ns_per_point(
+                total_ns,
+                3600_000_000_000 * 24,
+                &[1, 2, 5, 7],
+                10,
+                max_points,
+            ))
+        } else {
+            Some(determine_actual_ns_per_point(
+                total_ns,
+                3600_000_000_000 * 24 * 10,
+                &[1, 2, 5],
+                10,
+                max_points,
+            ))
+        }
+    } else {
+        None
+    }
+}
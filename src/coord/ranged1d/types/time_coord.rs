@@ -0,0 +1,308 @@
+/// The date and time coordinates for the `time` crate
+use std::ops::Range;
+
+use time::{Date, Duration, OffsetDateTime, Time};
+
+use super::period::{compute_period_per_point, format_duration};
+use crate::coord::ranged1d::{
+    AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
+    ValueFormatter,
+};
+
+/// Map the span from the beginning to the value, by the total span of the coordinate
+fn map_span(value: Duration, total: Duration, limit: (i32, i32)) -> i32 {
+    let total = total.whole_nanoseconds() as f64;
+    if total == 0.0 {
+        return limit.0;
+    }
+    limit.0 + (f64::from(limit.1 - limit.0) * value.whole_nanoseconds() as f64 / total) as i32
+}
+
+/// The length of the span in nanoseconds, the spans that are too long are cut to the maximum
+fn span_ns(span: Duration) -> u64 {
+    let ns = span.whole_nanoseconds();
+    if ns < 0 {
+        0
+    } else if ns > i128::from(u64::MAX) {
+        u64::MAX
+    } else {
+        ns as u64
+    }
+}
+
+/// The ranged coordinate for `time::Date`, which has the same key points as the
+/// [RangedDate](struct.RangedDate.html) of chrono
+#[derive(Clone)]
+pub struct RangedTimeDate(Date, Date);
+
+impl From<Range<Date>> for RangedTimeDate {
+    fn from(range: Range<Date>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for Range<Date> {
+    type CoordDescType = RangedTimeDate;
+    type Value = Date;
+}
+
+impl Ranged for RangedTimeDate {
+    type FormatOption = DefaultFormatting;
+    type ValueType = Date;
+
+    fn range(&self) -> Range<Date> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Date, limit: (i32, i32)) -> i32 {
+        map_span(*value - self.0, self.1 - self.0, limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Date> {
+        let max_points = hint.max_num_points();
+        let total_days = (self.1 - self.0).whole_days();
+        let total_weeks = (self.1 - self.0).whole_weeks();
+
+        let days_per_point = if total_days > 0 && total_days as usize <= max_points {
+            1
+        } else if total_weeks > 0 && total_weeks as usize <= max_points {
+            7
+        } else {
+            7 * ((total_weeks as f64) / (max_points as f64)).ceil().max(1.0) as i64
+        };
+
+        (0..=total_days / days_per_point)
+            .map(|idx| self.0 + Duration::days(idx * days_per_point))
+            .collect()
+    }
+}
+
+impl DiscreteRanged for RangedTimeDate {
+    fn size(&self) -> usize {
+        ((self.1 - self.0).whole_days().max(-1) + 1) as usize
+    }
+
+    fn index_of(&self, value: &Date) -> Option<usize> {
+        let ret = (*value - self.0).whole_days();
+        if ret < 0 {
+            return None;
+        }
+        Some(ret as usize)
+    }
+
+    fn from_index(&self, index: usize) -> Option<Date> {
+        self.0.checked_add(Duration::days(index as i64))
+    }
+}
+
+/// The ranged coordinate for `time::OffsetDateTime`, which has the same key points as the
+/// [RangedDateTime](struct.RangedDateTime.html) of chrono. The key points are aligned in the
+/// offset of the beginning of the range.
+#[derive(Clone)]
+pub struct RangedOffsetDateTime(OffsetDateTime, OffsetDateTime);
+
+impl From<Range<OffsetDateTime>> for RangedOffsetDateTime {
+    fn from(range: Range<OffsetDateTime>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for Range<OffsetDateTime> {
+    type CoordDescType = RangedOffsetDateTime;
+    type Value = OffsetDateTime;
+}
+
+impl Ranged for RangedOffsetDateTime {
+    type FormatOption = DefaultFormatting;
+    type ValueType = OffsetDateTime;
+
+    fn range(&self) -> Range<OffsetDateTime> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &OffsetDateTime, limit: (i32, i32)) -> i32 {
+        map_span(*value - self.0, self.1 - self.0, limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<OffsetDateTime> {
+        let max_points = hint.max_num_points();
+        let total_ns = span_ns(self.1 - self.0);
+
+        if let Some(period) = compute_period_per_point(total_ns, max_points, true) {
+            let midnight = self.0.replace_time(Time::MIDNIGHT);
+            let since_midnight = span_ns(self.0 - midnight);
+            let rem = since_midnight % period;
+            let first = if rem > 0 {
+                since_midnight + (period - rem)
+            } else {
+                since_midnight
+            };
+
+            let mut current = midnight + Duration::nanoseconds(first as i64);
+            let mut ret = vec![];
+            while current < self.1 {
+                ret.push(current);
+                current += Duration::nanoseconds(period as i64);
+            }
+            return ret;
+        }
+
+        // Otherwise, it actually behaves like a date
+        let offset = self.0.offset();
+        let first = if self.0.time() == Time::MIDNIGHT {
+            Some(self.0.date())
+        } else {
+            self.0.date().next_day()
+        };
+        let last = self.1.to_offset(offset).date();
+        match first {
+            Some(first) if first <= last => RangedTimeDate(first, last)
+                .key_points(max_points)
+                .into_iter()
+                .map(|date| date.midnight().assume_offset(offset))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+/// The ranged coordinate for `time::Duration`, which has the same key points and labels as the
+/// [RangedDuration](struct.RangedDuration.html) of chrono
+#[derive(Clone)]
+pub struct RangedTimeDuration(Duration, Duration);
+
+impl From<Range<Duration>> for RangedTimeDuration {
+    fn from(range: Range<Duration>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for Range<Duration> {
+    type CoordDescType = RangedTimeDuration;
+    type Value = Duration;
+}
+
+impl ValueFormatter<Duration> for RangedTimeDuration {
+    fn format(value: &Duration) -> String {
+        let text = format_duration(&value.unsigned_abs());
+        if value.is_negative() {
+            format!("-{}", text)
+        } else {
+            text
+        }
+    }
+}
+
+impl Ranged for RangedTimeDuration {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Duration;
+
+    fn range(&self) -> Range<Duration> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Duration, limit: (i32, i32)) -> i32 {
+        map_span(*value - self.0, self.1 - self.0, limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Duration> {
+        let total_ns = span_ns(self.1 - self.0);
+        let period = match compute_period_per_point(total_ns, hint.max_num_points(), false) {
+            Some(period) => i128::from(period),
+            None => return vec![],
+        };
+
+        let start = self.0.whole_nanoseconds();
+        let first = start + (period - start.rem_euclid(period)) % period;
+        let mut current = Duration::nanoseconds_i128(first);
+        let mut ret = vec![];
+        while current < self.1 {
+            ret.push(current);
+            current += Duration::nanoseconds_i128(period);
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use time::{Month, UtcOffset};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_time_date() {
+        let coord: RangedTimeDate =
+            (date(2020, Month::January, 1)..date(2020, Month::January, 8)).into();
+        let points = coord.key_points(10);
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[1], date(2020, Month::January, 2));
+        assert_eq!(coord.map(&date(2020, Month::January, 4), (0, 700)), 300);
+        assert_eq!(coord.size(), 8);
+        assert_eq!(coord.index_of(&date(2020, Month::January, 3)), Some(2));
+
+        let coord: RangedTimeDate =
+            (date(2020, Month::January, 1)..date(2021, Month::January, 1)).into();
+        let points = coord.key_points(10);
+        assert!(points.len() <= 10);
+        assert!(points
+            .windows(2)
+            .all(|w| (w[1] - w[0]).whole_days() % 7 == 0));
+    }
+
+    #[test]
+    fn test_offset_date_time() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let start = date(2020, Month::March, 1)
+            .with_hms(10, 17, 0)
+            .unwrap()
+            .assume_offset(offset);
+        let coord: RangedOffsetDateTime = (start..start + Duration::hours(6)).into();
+        let points = coord.key_points(7);
+        assert_eq!(points.len(), 6);
+        // The key points are aligned to the hours in the offset of the range
+        assert_eq!(points[0].hour(), 11);
+        assert_eq!(points[0].minute(), 0);
+        assert_eq!(points[0].offset(), offset);
+
+        let coord: RangedOffsetDateTime = (start..start + Duration::days(60)).into();
+        let points = coord.key_points(10);
+        assert!(!points.is_empty() && points.len() <= 10);
+        assert!(points.iter().all(|p| p.time() == Time::MIDNIGHT));
+    }
+
+    #[test]
+    fn test_time_duration() {
+        let coord: RangedTimeDuration = (Duration::milliseconds(-250)..Duration::seconds(1)).into();
+        let points = coord.key_points(6);
+        assert_eq!(points[0], Duration::milliseconds(-200));
+        assert!(points
+            .windows(2)
+            .all(|w| w[1] - w[0] == Duration::milliseconds(200)));
+        assert_eq!(
+            RangedTimeDuration::format(&Duration::milliseconds(-250)),
+            "-250ms"
+        );
+        assert_eq!(RangedTimeDuration::format(&Duration::seconds(90)), "1.5min");
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(date(2020, Month::May, 1)..date(2020, Month::May, 30), 0..10)
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                vec![
+                    (date(2020, Month::May, 1), 0),
+                    (date(2020, Month::May, 20), 9),
+                ],
+                RED,
+            )))
+            .unwrap();
+    }
+}
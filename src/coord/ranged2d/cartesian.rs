@@ -51,13 +51,22 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         &self,
         h_limit: YH,
         v_limit: XH,
-        mut draw_mesh: DrawMesh,
+        draw_mesh: DrawMesh,
     ) -> Result<(), E> {
-        let (xkp, ykp) = (
+        self.draw_mesh_at(
             self.logic_x.key_points(v_limit),
             self.logic_y.key_points(h_limit),
-        );
+            draw_mesh,
+        )
+    }
 
+    /// Draw the mesh lines at the given key points rather than the key points of the coordinates
+    pub(crate) fn draw_mesh_at<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
+        &self,
+        xkp: Vec<X::ValueType>,
+        ykp: Vec<Y::ValueType>,
+        mut draw_mesh: DrawMesh,
+    ) -> Result<(), E> {
         for logic_x in xkp {
             let x = self.logic_x.map(&logic_x, self.back_x);
             draw_mesh(MeshLine::XMesh(
@@ -99,6 +108,30 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
 
+    /// Get the horizental backend coordinates where the X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<i32> {
+        self.logic_x.axis_breaks(self.back_x)
+    }
+
+    /// Get the vertical backend coordinates where the Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<i32> {
+        self.logic_y.axis_breaks(self.back_y)
+    }
+
+    /// Get the pixels that the start and the end of the X axis and the Y axis are mapped to
+    pub(crate) fn pixel_limits(&self) -> ((i32, i32), (i32, i32)) {
+        (self.back_x, self.back_y)
+    }
+
+    /// Split the coordinate system into the 1D coordinate specs and the pixel ranges
+    pub(crate) fn into_parts(self) -> (X, Y, (Range<i32>, Range<i32>)) {
+        (
+            self.logic_x,
+            self.logic_y,
+            (self.back_x.0..self.back_x.1, self.back_y.0..self.back_y.1),
+        )
+    }
+
     /// Get the 1D coordinate spec for X axis
     pub fn x_spec(&self) -> &X {
         &self.logic_x
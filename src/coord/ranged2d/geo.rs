@@ -0,0 +1,183 @@
+/*!
+ The geographic coordinate system.
+
+ This module provides the coordinate system of the longitudes and the latitudes in degrees, which
+ are projected to the plane with one of the map projections in [Projection](enum.Projection.html).
+
+ This types of coordinate system is used by the chart constructed with [ChartBuilder::build_geo](../../chart/ChartBuilder.html#method.build_geo).
+*/
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+use plotters_backend::BackendCoord;
+
+/// The latitude limit of the Mercator projection, which makes the projected map a square
+const MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// The projection of the longitudes and the latitudes to the plane
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Projection {
+    /// The longitudes and the latitudes are mapped linearly with the same scale, which is also
+    /// known as the plate carrée
+    Equirectangular,
+    /// The conformal projection that keeps the angles and stretches the areas towards the poles,
+    /// the latitudes are clamped to ±85.05°
+    Mercator,
+}
+
+impl Projection {
+    /// Project the longitude and the latitude in degrees to the plane, where the unit is the
+    /// radius of the earth
+    pub fn project(self, (lon, lat): (f64, f64)) -> (f64, f64) {
+        let x = lon.to_radians();
+        match self {
+            Projection::Equirectangular => (x, lat.to_radians()),
+            Projection::Mercator => {
+                let lat = if lat.abs() > MERCATOR_MAX_LATITUDE {
+                    MERCATOR_MAX_LATITUDE * lat.signum()
+                } else {
+                    lat
+                };
+                let lat = lat.to_radians();
+                (x, (PI / 4.0 + lat / 2.0).tan().ln())
+            }
+        }
+    }
+
+    /// The inverse of [Projection::project](#method.project)
+    pub fn unproject(self, (x, y): (f64, f64)) -> (f64, f64) {
+        let lat = match self {
+            Projection::Equirectangular => y,
+            Projection::Mercator => 2.0 * y.exp().atan() - PI / 2.0,
+        };
+        (x.to_degrees(), lat.to_degrees())
+    }
+}
+
+/// The geographic coordinate system, which maps the points in `(longitude, latitude)` degrees
+/// to the screen with a map projection. The north is at the top.
+#[derive(Clone)]
+pub struct GeoCoord {
+    projection: Projection,
+    lon: Range<f64>,
+    lat: Range<f64>,
+    min: (f64, f64),
+    scale: (f64, f64),
+    pixels: (BackendCoord, BackendCoord),
+}
+
+impl GeoCoord {
+    /// Create a new geographic coordinate system
+    /// - `projection`: The map projection
+    /// - `lon` and `lat`: The range of the longitudes and the latitudes in degrees
+    /// - `pixels`: The upper left and the lower right corners of the map on the screen, the
+    ///   projected map is stretched to fill them
+    pub fn new(
+        projection: Projection,
+        lon: Range<f64>,
+        lat: Range<f64>,
+        pixels: (BackendCoord, BackendCoord),
+    ) -> Self {
+        let low = projection.project((lon.start, lat.start));
+        let high = projection.project((lon.end, lat.end));
+        let span = |a: f64, b: f64, pixels: i32| {
+            if a == b {
+                0.0
+            } else {
+                f64::from(pixels) / (b - a)
+            }
+        };
+        Self {
+            projection,
+            min: low,
+            scale: (
+                span(low.0, high.0, (pixels.1).0 - (pixels.0).0),
+                span(low.1, high.1, (pixels.1).1 - (pixels.0).1),
+            ),
+            lon,
+            lat,
+            pixels,
+        }
+    }
+
+    /// Get the map projection
+    pub fn projection(&self) -> Projection {
+        self.projection
+    }
+
+    /// Get the range of the longitudes in degrees
+    pub fn lon_range(&self) -> Range<f64> {
+        self.lon.clone()
+    }
+
+    /// Get the range of the latitudes in degrees
+    pub fn lat_range(&self) -> Range<f64> {
+        self.lat.clone()
+    }
+
+    /// Get the upper left and the lower right corners of the map on the screen
+    pub fn pixel_range(&self) -> (BackendCoord, BackendCoord) {
+        self.pixels
+    }
+}
+
+impl CoordTranslate for GeoCoord {
+    type From = (f64, f64);
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        let (x, y) = self.projection.project(*from);
+        let ((x0, _), (_, y1)) = self.pixels;
+        (
+            x0 + ((x - self.min.0) * self.scale.0).round() as i32,
+            y1 - ((y - self.min.1) * self.scale.1).round() as i32,
+        )
+    }
+}
+
+impl ReverseCoordTranslate for GeoCoord {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        if self.scale.0 == 0.0 || self.scale.1 == 0.0 {
+            return None;
+        }
+        let ((x0, _), (_, y1)) = self.pixels;
+        let x = self.min.0 + f64::from(input.0 - x0) / self.scale.0;
+        let y = self.min.1 + f64::from(y1 - input.1) / self.scale.1;
+        Some(self.projection.unproject((x, y)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_geo_translate() {
+        let coord = GeoCoord::new(
+            Projection::Equirectangular,
+            -180.0..180.0,
+            -90.0..90.0,
+            ((0, 0), (360, 180)),
+        );
+        assert_eq!(coord.translate(&(-180.0, 90.0)), (0, 0));
+        assert_eq!(coord.translate(&(0.0, 0.0)), (180, 90));
+        assert_eq!(coord.translate(&(90.0, -45.0)), (270, 135));
+
+        let coord = GeoCoord::new(
+            Projection::Mercator,
+            -180.0..180.0,
+            -MERCATOR_MAX_LATITUDE..MERCATOR_MAX_LATITUDE,
+            ((0, 0), (400, 400)),
+        );
+        assert_eq!(coord.translate(&(0.0, 0.0)), (200, 200));
+        // The latitudes are stretched towards the poles and clamped at the limit
+        assert_eq!(coord.translate(&(0.0, 60.0)), (200, 116));
+        assert_eq!(coord.translate(&(0.0, 90.0)), (200, 0));
+
+        let (lon, lat) = coord.reverse_translate((200, 116)).unwrap();
+        assert!(lon.abs() < 1e-9);
+        assert!((lat - 60.0).abs() < 0.5);
+    }
+}
@@ -1 +1,4 @@
 pub mod cartesian;
+pub mod geo;
+pub mod polar;
+pub mod ternary;
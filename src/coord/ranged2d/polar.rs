@@ -0,0 +1,136 @@
+/*!
+ The 2-dimensional polar coordinate system.
+
+ This module provides the polar coordinate system, which is composed by the 1D ranged coordinate
+ specification of the angle and the one of the radius.
+
+ This types of coordinate system is used by the chart constructed with [ChartBuilder::build_polar](../../chart/ChartBuilder.html#method.build_polar).
+*/
+
+use std::f64::consts::PI;
+
+use crate::coord::ranged1d::{Ranged, ReversibleRanged};
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+use plotters_backend::BackendCoord;
+
+/// The resolution that the angle values are mapped with
+const ANGLE_RESOLUTION: i32 = 1 << 20;
+
+/// A polar coordinate system described by the 1D ranged coordinate specs of the angle and the
+/// radius. The angle range covers the full circle, starting from 12 o'clock and going clockwise.
+/// The start of the radius range is at the center.
+#[derive(Clone)]
+pub struct Polar<T: Ranged, R: Ranged> {
+    logic_theta: T,
+    logic_r: R,
+    center: BackendCoord,
+    radius: i32,
+}
+
+impl<T: Ranged, R: Ranged> Polar<T, R> {
+    /// Create a new polar coordinate system
+    /// - `logic_theta` and `logic_r`: The description for the angle and the radius
+    /// - `center`: The center of the circle on the screen
+    /// - `radius`: The radius of the circle on the screen
+    pub fn new<IntoT: Into<T>, IntoR: Into<R>>(
+        logic_theta: IntoT,
+        logic_r: IntoR,
+        center: BackendCoord,
+        radius: u32,
+    ) -> Self {
+        Self {
+            logic_theta: logic_theta.into(),
+            logic_r: logic_r.into(),
+            center,
+            radius: radius as i32,
+        }
+    }
+
+    /// Get the center of the circle on the screen
+    pub fn center(&self) -> BackendCoord {
+        self.center
+    }
+
+    /// Get the radius of the circle on the screen
+    pub fn radius(&self) -> u32 {
+        self.radius as u32
+    }
+
+    /// Get the 1D coordinate spec of the angle
+    pub fn theta_spec(&self) -> &T {
+        &self.logic_theta
+    }
+
+    /// Get the 1D coordinate spec of the radius
+    pub fn r_spec(&self) -> &R {
+        &self.logic_r
+    }
+
+    /// Get the clockwise angle from 12 o'clock in radians of the given value
+    pub fn angle_of(&self, theta: &T::ValueType) -> f64 {
+        let mapped = self.logic_theta.map(theta, (0, ANGLE_RESOLUTION));
+        f64::from(mapped) / f64::from(ANGLE_RESOLUTION) * 2.0 * PI
+    }
+
+    /// Get the distance from the center in pixels of the given value
+    pub fn radius_of(&self, r: &R::ValueType) -> i32 {
+        self.logic_r.map(r, (0, self.radius))
+    }
+
+    /// Get the point on the screen with the given angle in radians and distance in pixels
+    pub(crate) fn point_at(&self, angle: f64, distance: f64) -> BackendCoord {
+        (
+            self.center.0 + (distance * angle.sin()).round() as i32,
+            self.center.1 - (distance * angle.cos()).round() as i32,
+        )
+    }
+}
+
+impl<T: Ranged, R: Ranged> CoordTranslate for Polar<T, R> {
+    type From = (T::ValueType, R::ValueType);
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        self.point_at(self.angle_of(&from.0), f64::from(self.radius_of(&from.1)))
+    }
+}
+
+impl<T: ReversibleRanged, R: ReversibleRanged> ReverseCoordTranslate for Polar<T, R> {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let (dx, dy) = (
+            f64::from(input.0 - self.center.0),
+            f64::from(self.center.1 - input.1),
+        );
+        let mut angle = dx.atan2(dy);
+        if angle < 0.0 {
+            angle += 2.0 * PI;
+        }
+        let mapped = (angle / (2.0 * PI) * f64::from(ANGLE_RESOLUTION)).round() as i32;
+        let distance = (dx * dx + dy * dy).sqrt().round() as i32;
+        Some((
+            self.logic_theta.unmap(mapped, (0, ANGLE_RESOLUTION))?,
+            self.logic_r.unmap(distance, (0, self.radius))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::types::RangedCoordf64;
+
+    #[test]
+    fn test_polar_translate() {
+        let coord: Polar<RangedCoordf64, RangedCoordf64> =
+            Polar::new(0.0..360.0, 0.0..10.0, (100, 100), 50);
+        assert_eq!(coord.translate(&(0.0, 0.0)), (100, 100));
+        assert_eq!(coord.translate(&(0.0, 10.0)), (100, 50));
+        assert_eq!(coord.translate(&(90.0, 10.0)), (150, 100));
+        assert_eq!(coord.translate(&(180.0, 5.0)), (100, 125));
+        assert_eq!(coord.translate(&(270.0, 10.0)), (50, 100));
+
+        let (theta, r) = coord.reverse_translate((150, 100)).unwrap();
+        assert!((theta - 90.0).abs() < 1e-3);
+        assert!((r - 10.0).abs() < 1e-3);
+    }
+}
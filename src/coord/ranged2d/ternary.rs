@@ -0,0 +1,108 @@
+/*!
+ The ternary coordinate system.
+
+ This module provides the coordinate system of the three-component compositional data, such as
+ the phase diagrams in chemistry and the soil textures, where the three components of each point
+ add up to 1.
+
+ This types of coordinate system is used by the chart constructed with [ChartBuilder::build_ternary](../../chart/ChartBuilder.html#method.build_ternary).
+*/
+
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+use plotters_backend::BackendCoord;
+
+/// The ternary coordinate system, where a point `(a, b, c)` is drawn inside a triangle with the
+/// distance to each corner given by its share of the total. The corner of `a` is at the top, the
+/// corner of `b` at the bottom left and the corner of `c` at the bottom right.
+///
+/// The components don't have to add up to 1 exactly, since they are divided by their sum, thus
+/// the raw amounts, such as the masses of the components, can be used directly.
+#[derive(Clone)]
+pub struct TernaryCoord {
+    corners: [(f64, f64); 3],
+}
+
+impl TernaryCoord {
+    /// Create a new ternary coordinate system
+    /// - `corners`: The corners of the triangle on the screen for the components `a`, `b` and `c`
+    pub fn new(corners: [BackendCoord; 3]) -> Self {
+        let point = |(x, y): BackendCoord| (f64::from(x), f64::from(y));
+        Self {
+            corners: [point(corners[0]), point(corners[1]), point(corners[2])],
+        }
+    }
+
+    /// Get the corners of the triangle on the screen for the components `a`, `b` and `c`
+    pub fn corners(&self) -> [BackendCoord; 3] {
+        let pixel = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        [
+            pixel(self.corners[0]),
+            pixel(self.corners[1]),
+            pixel(self.corners[2]),
+        ]
+    }
+
+    /// Get the point on the screen of the given composition, without rounding it to the pixels
+    pub(crate) fn point_at(&self, (a, b, c): (f64, f64, f64)) -> (f64, f64) {
+        let total = a + b + c;
+        let (a, b, c) = if total == 0.0 {
+            (1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0)
+        } else {
+            (a / total, b / total, c / total)
+        };
+        let [p, q, r] = self.corners;
+        (a * p.0 + b * q.0 + c * r.0, a * p.1 + b * q.1 + c * r.1)
+    }
+}
+
+impl CoordTranslate for TernaryCoord {
+    type From = (f64, f64, f64);
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        let (x, y) = self.point_at(*from);
+        (x.round() as i32, y.round() as i32)
+    }
+}
+
+impl ReverseCoordTranslate for TernaryCoord {
+    /// Get the composition of the point on the screen, which is `None` outside of the triangle
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let [p, q, r] = self.corners;
+        let (x, y) = (f64::from(input.0), f64::from(input.1));
+        let det = (q.1 - r.1) * (p.0 - r.0) + (r.0 - q.0) * (p.1 - r.1);
+        if det == 0.0 {
+            return None;
+        }
+        let a = ((q.1 - r.1) * (x - r.0) + (r.0 - q.0) * (y - r.1)) / det;
+        let b = ((r.1 - p.1) * (x - r.0) + (p.0 - r.0) * (y - r.1)) / det;
+        let c = 1.0 - a - b;
+        // Allow the rounding error of the pixels on the edges
+        let tolerance = 1.0 / det.abs().sqrt();
+        if a < -tolerance || b < -tolerance || c < -tolerance {
+            return None;
+        }
+        Some((a, b, c))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ternary_translate() {
+        let coord = TernaryCoord::new([(100, 0), (0, 200), (200, 200)]);
+        assert_eq!(coord.translate(&(1.0, 0.0, 0.0)), (100, 0));
+        assert_eq!(coord.translate(&(0.0, 1.0, 0.0)), (0, 200));
+        assert_eq!(coord.translate(&(0.0, 0.0, 2.0)), (200, 200));
+        assert_eq!(coord.translate(&(0.0, 0.5, 0.5)), (100, 200));
+        assert_eq!(coord.translate(&(1.0, 1.0, 2.0)), (125, 150));
+
+        let (a, b, c) = coord.reverse_translate((125, 150)).unwrap();
+        assert!((a - 0.25).abs() < 1e-9);
+        assert!((b - 0.25).abs() < 1e-9);
+        assert!((c - 0.5).abs() < 1e-9);
+        assert!(coord.reverse_translate((0, 0)).is_none());
+    }
+}
@@ -167,6 +167,19 @@ impl ProjectionMatrixBuilder {
         self
     }
 
+    /// Set the yaw, the pitch and the scale to the point `t` of the way from one configuration
+    /// to another, which is used for the frames of an animation. The pivot point is kept.
+    /// - `from`: The configuration at `t = 0`
+    /// - `to`: The configuration at `t = 1`
+    /// - `t`: The progress of the interpolation
+    pub fn interpolate(mut self, from: &Self, to: &Self, t: f64) -> Self {
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        self.yaw = lerp(from.yaw, to.yaw);
+        self.pitch = lerp(from.pitch, to.pitch);
+        self.scale = lerp(from.scale, to.scale);
+        self
+    }
+
     /// Build the matrix based on the configuration
     pub fn into_matrix(self) -> ProjectionMatrix {
         let mut ret = if self.pivot_before == (0, 0, 0) {
@@ -23,6 +23,10 @@ where
     fn translate(&self, from: &Self::From) -> BackendCoord {
         self.deref().translate(from)
     }
+
+    fn depth(&self, from: &Self::From) -> i32 {
+        self.deref().depth(from)
+    }
 }
 
 /// The trait indicates that the coordinate system supports reverse transform
@@ -0,0 +1,75 @@
+use ndarray::ArrayView1;
+
+/// The iterator over the points of 1D arrays, which reads the arrays in place rather than
+/// copying them into a vector, thus it can be used directly as the data of a series.
+///
+/// A single array is converted to the points of the index and the value, and a pair of arrays is
+/// converted to the points of the X and Y values, which stop at the end of the shorter array.
+///
+/// ```rust
+/// use plotters::data::ArrayPoints;
+/// use plotters::prelude::*;
+///
+/// let x = ndarray::Array1::linspace(0.0, 6.0, 100);
+/// let y = x.mapv(f64::sin);
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..6.0, -1.0..1.0)
+///     .unwrap();
+/// chart
+///     .draw_series(LineSeries::new(ArrayPoints::from((x.view(), y.view())), &RED))
+///     .unwrap();
+/// ```
+pub struct ArrayPoints<'a> {
+    x: Option<ArrayView1<'a, f64>>,
+    y: ArrayView1<'a, f64>,
+    idx: usize,
+}
+
+impl<'a> From<ArrayView1<'a, f64>> for ArrayPoints<'a> {
+    fn from(y: ArrayView1<'a, f64>) -> Self {
+        Self { x: None, y, idx: 0 }
+    }
+}
+
+impl<'a> From<(ArrayView1<'a, f64>, ArrayView1<'a, f64>)> for ArrayPoints<'a> {
+    fn from((x, y): (ArrayView1<'a, f64>, ArrayView1<'a, f64>)) -> Self {
+        Self {
+            x: Some(x),
+            y,
+            idx: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for ArrayPoints<'a> {
+    type Item = (f64, f64);
+    fn next(&mut self) -> Option<(f64, f64)> {
+        let idx = self.idx;
+        let y = *self.y.get(idx)?;
+        let x = match self.x {
+            Some(ref x) => *x.get(idx)?,
+            None => idx as f64,
+        };
+        self.idx += 1;
+        Some((x, y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_array_points() {
+        let y = ndarray::arr1(&[1.0, 4.0, 9.0]);
+        let points: Vec<_> = ArrayPoints::from(y.view()).collect();
+        assert_eq!(points, vec![(0.0, 1.0), (1.0, 4.0), (2.0, 9.0)]);
+
+        let x = ndarray::arr1(&[0.5, 1.5]);
+        let points: Vec<_> = ArrayPoints::from((x.view(), y.view())).collect();
+        assert_eq!(points, vec![(0.5, 1.0), (1.5, 4.0)]);
+    }
+}
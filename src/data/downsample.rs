@@ -0,0 +1,250 @@
+use num_traits::ToPrimitive;
+#[cfg(feature = "line_series")]
+use plotters_backend::BackendCoord;
+
+fn to_f64<T: ToPrimitive>(value: &T) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+/// The twice of the area of the triangle of the three points
+fn triangle_area((ax, ay): (f64, f64), (bx, by): (f64, f64), (cx, cy): (f64, f64)) -> f64 {
+    ((ax - cx) * (by - ay) - (ax - bx) * (cy - ay)).abs()
+}
+
+/// The range of the indices of the bucket, when the points are split into the buckets
+fn bucket(len: usize, buckets: usize, idx: usize) -> std::ops::Range<usize> {
+    idx * len / buckets..(idx + 1) * len / buckets
+}
+
+/// Pick the points with the Largest-Triangle-Three-Buckets algorithm
+fn lttb<X: ToPrimitive, Y: ToPrimitive>(data: &[(X, Y)], threshold: usize) -> Vec<usize> {
+    let len = data.len();
+    if threshold >= len || threshold < 3 {
+        return (0..len).collect();
+    }
+    let point = |idx: usize| (to_f64(&data[idx].0), to_f64(&data[idx].1));
+    // The first and the last points are always kept, the others are split into the buckets
+    let buckets = threshold - 2;
+    let mut picked = Vec::with_capacity(threshold);
+    picked.push(0);
+    for idx in 0..buckets {
+        let current = bucket(len - 2, buckets, idx);
+        let next = if idx + 1 < buckets {
+            bucket(len - 2, buckets, idx + 1)
+        } else {
+            len - 2..len - 1
+        };
+        let count = (next.end - next.start) as f64;
+        let (sx, sy) = next.fold((0.0, 0.0), |(sx, sy), i| {
+            let (x, y) = point(i + 1);
+            (sx + x, sy + y)
+        });
+        let average = (sx / count, sy / count);
+        let previous = point(picked[picked.len() - 1]);
+        let mut best = (current.start + 1, -1.0);
+        for i in current {
+            let area = triangle_area(previous, point(i + 1), average);
+            if area > best.1 {
+                best = (i + 1, area);
+            }
+        }
+        picked.push(best.0);
+    }
+    picked.push(len - 1);
+    picked
+}
+
+/// Pick the points with the lowest and the highest values of each bucket, in the original order
+fn min_max<X, Y: ToPrimitive>(data: &[(X, Y)], buckets: usize) -> Vec<usize> {
+    let len = data.len();
+    if buckets == 0 || buckets * 2 >= len {
+        return (0..len).collect();
+    }
+    let mut picked = Vec::with_capacity(buckets * 2);
+    for idx in 0..buckets {
+        let range = bucket(len, buckets, idx);
+        if range.start == range.end {
+            continue;
+        }
+        let (mut low, mut high) = (range.start, range.start);
+        for i in range {
+            let value = to_f64(&data[i].1);
+            if value < to_f64(&data[low].1) {
+                low = i;
+            }
+            if value > to_f64(&data[high].1) {
+                high = i;
+            }
+        }
+        picked.push(low.min(high));
+        if low != high {
+            picked.push(low.max(high));
+        }
+    }
+    picked
+}
+
+/// Collapse each run of the consecutive points in the same pixel column to the first, the lowest,
+/// the highest and the last of them, which draws the same pixels as the whole run
+#[cfg(feature = "line_series")]
+pub(crate) fn decimate_columns<I: IntoIterator<Item = BackendCoord>>(
+    points: I,
+) -> Vec<BackendCoord> {
+    let mut result = vec![];
+    let mut run: Vec<BackendCoord> = vec![];
+    fn flush(run: &mut Vec<BackendCoord>, result: &mut Vec<BackendCoord>) {
+        if run.len() <= 4 {
+            result.append(run);
+            return;
+        }
+        let (mut low, mut high) = (0, 0);
+        for (i, point) in run.iter().enumerate() {
+            if point.1 < run[low].1 {
+                low = i;
+            }
+            if point.1 > run[high].1 {
+                high = i;
+            }
+        }
+        let mut picked = vec![0, low.min(high), low.max(high), run.len() - 1];
+        picked.dedup();
+        result.extend(picked.into_iter().map(|i| run[i]));
+        run.clear();
+    }
+    for point in points {
+        if run.last().map(|last| last.0 != point.0) == Some(true) {
+            flush(&mut run, &mut result);
+        }
+        run.push(point);
+    }
+    flush(&mut run, &mut result);
+    result
+}
+
+/// The iterator adapter that reduces a long data series to fewer points that look the same
+/// when plotted, which can be used directly as the data of a
+/// [LineSeries](../series/struct.LineSeries.html).
+///
+/// The data is expected to be sorted by X. The Largest-Triangle-Three-Buckets algorithm keeps
+/// the shape of the line with the given number of points, and the min-max decimation keeps the
+/// extremes of each bucket, which is what a dense signal looks like.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data: Vec<_> = (0..100_000).map(|x| (x, ((x as f64) / 1000.0).sin())).collect();
+/// let reduced: Vec<_> = Downsample::lttb(data.iter().cloned(), 640).collect();
+/// assert_eq!(reduced.len(), 640);
+/// assert_eq!(reduced[0], data[0]);
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..100_000, -1.0..1.0)
+///     .unwrap();
+/// chart
+///     .draw_series(LineSeries::new(Downsample::min_max(data, 640), &RED))
+///     .unwrap();
+/// ```
+pub struct Downsample<X, Y> {
+    points: std::vec::IntoIter<(X, Y)>,
+}
+
+impl<X: ToPrimitive, Y: ToPrimitive> Downsample<X, Y> {
+    fn pick<I: IntoIterator<Item = (X, Y)>, F: FnOnce(&[(X, Y)]) -> Vec<usize>>(
+        iter: I,
+        pick: F,
+    ) -> Self {
+        let data: Vec<_> = iter.into_iter().collect();
+        let picked = pick(&data);
+        let mut picked = picked.into_iter().peekable();
+        let points: Vec<_> = data
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| {
+                if picked.peek() == Some(idx) {
+                    picked.next();
+                    return true;
+                }
+                false
+            })
+            .map(|(_, point)| point)
+            .collect();
+        Self {
+            points: points.into_iter(),
+        }
+    }
+
+    /// Reduce the data with the Largest-Triangle-Three-Buckets algorithm
+    ///
+    /// - `iter`: The data points, sorted by X
+    /// - `threshold`: The number of the points to keep, the data is kept as it is if it has
+    ///   fewer points or the threshold is less than 3
+    /// - **returns** The iterator over the points that are kept
+    pub fn lttb<I: IntoIterator<Item = (X, Y)>>(iter: I, threshold: usize) -> Self {
+        Self::pick(iter, |data| lttb(data, threshold))
+    }
+
+    /// Reduce the data to the lowest and the highest points of each bucket
+    ///
+    /// - `iter`: The data points, sorted by X
+    /// - `buckets`: The number of the buckets, usually the width of the plot in pixels, thus up
+    ///   to twice as many points are kept
+    /// - **returns** The iterator over the points that are kept
+    pub fn min_max<I: IntoIterator<Item = (X, Y)>>(iter: I, buckets: usize) -> Self {
+        Self::pick(iter, |data| min_max(data, buckets))
+    }
+}
+
+impl<X, Y> Iterator for Downsample<X, Y> {
+    type Item = (X, Y);
+    fn next(&mut self) -> Option<(X, Y)> {
+        self.points.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lttb() {
+        let data = vec![(0, 0), (1, 3), (2, 0), (3, 0), (4, 5), (5, 0), (6, 0)];
+        // The peaks are the points that make the largest triangles
+        assert_eq!(lttb(&data, 4), vec![0, 1, 4, 6]);
+        assert_eq!(lttb(&data, 2), (0..7).collect::<Vec<_>>());
+        assert_eq!(lttb(&data, 10), (0..7).collect::<Vec<_>>());
+
+        let reduced: Vec<_> = Downsample::lttb(data, 4).collect();
+        assert_eq!(reduced, vec![(0, 0), (1, 3), (4, 5), (6, 0)]);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let data = vec![
+            (0, 3),
+            (1, 1),
+            (2, 7),
+            (3, 2),
+            (4, 2),
+            (5, 9),
+            (6, -1),
+            (7, 4),
+        ];
+        assert_eq!(min_max(&data, 2), vec![1, 2, 5, 6]);
+        let reduced: Vec<_> = Downsample::min_max(data.clone(), 2).collect();
+        assert_eq!(reduced, vec![(1, 1), (2, 7), (5, 9), (6, -1)]);
+        assert_eq!(Downsample::min_max(data, 4).count(), 8);
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_decimate_columns() {
+        let points = vec![(0, 5), (1, 3), (1, 9), (1, 0), (1, 4), (1, 6), (2, 2)];
+        assert_eq!(
+            decimate_columns(points),
+            vec![(0, 5), (1, 3), (1, 9), (1, 0), (1, 6), (2, 2)]
+        );
+        assert_eq!(decimate_columns(vec![(0, 0), (0, 1)]), vec![(0, 0), (0, 1)]);
+    }
+}
@@ -0,0 +1,61 @@
+use super::{weighted_percentile, Quartiles};
+
+/// Estimate the bandwidth of the gaussian kernel with the Silverman's rule of thumb
+pub(crate) fn silverman_bandwidth(samples: &[f64]) -> f64 {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let sd = (samples.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / n).sqrt();
+    let quartiles = Quartiles::new(samples).values();
+    let iqr = (quartiles[3] - quartiles[1]) / 1.34;
+    let spread = if iqr > 0.0 { sd.min(iqr) } else { sd };
+    0.9 * spread * n.powf(-0.2)
+}
+
+/// Evaluate the unscaled gaussian kernel density of the samples at the given value
+pub(crate) fn gaussian_density(samples: &[f64], bandwidth: f64, v: f64) -> f64 {
+    samples
+        .iter()
+        .map(|s| {
+            let u = (v - s) / bandwidth;
+            (-0.5 * u * u).exp()
+        })
+        .sum()
+}
+
+/// Estimate the bandwidth of the gaussian kernel for the weighted samples with the Silverman's
+/// rule of thumb, the number of the samples is the effective sample size of the weights
+#[cfg_attr(
+    not(any(feature = "boxplot", feature = "ridgeline_series")),
+    allow(dead_code)
+)]
+pub(crate) fn weighted_silverman_bandwidth(samples: &[(f64, f64)]) -> f64 {
+    let total = samples.iter().map(|(_, w)| w).sum::<f64>();
+    let n = total * total / samples.iter().map(|(_, w)| w * w).sum::<f64>();
+    let mean = samples.iter().map(|(v, w)| v * w).sum::<f64>() / total;
+    let variance = samples
+        .iter()
+        .map(|(v, w)| (v - mean) * (v - mean) * w)
+        .sum::<f64>()
+        / total;
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let iqr = (weighted_percentile(&sorted, 0.75) - weighted_percentile(&sorted, 0.25)) / 1.34;
+    let sd = variance.sqrt();
+    let spread = if iqr > 0.0 { sd.min(iqr) } else { sd };
+    0.9 * spread * n.powf(-0.2)
+}
+
+/// Evaluate the unscaled gaussian kernel density of the weighted samples at the given value
+#[cfg_attr(
+    not(any(feature = "boxplot", feature = "ridgeline_series")),
+    allow(dead_code)
+)]
+pub(crate) fn weighted_gaussian_density(samples: &[(f64, f64)], bandwidth: f64, v: f64) -> f64 {
+    samples
+        .iter()
+        .map(|(s, w)| {
+            let u = (v - s) / bandwidth;
+            w * (-0.5 * u * u).exp()
+        })
+        .sum()
+}
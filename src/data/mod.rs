@@ -1,12 +1,34 @@
 /*!
 The data processing module, which implements algorithms related to visualization of data.
-Such as, down-sampling, etc.
+Such as, down-sampling, regression, smoothing, etc.
 */
 
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "ndarray")]
+pub use array::ArrayPoints;
+
 mod data_range;
 pub use data_range::fitting_range;
 
+pub(crate) mod kde;
+
+mod downsample;
+#[cfg(feature = "line_series")]
+pub(crate) use downsample::decimate_columns;
+pub use downsample::Downsample;
+
+mod moving_average;
+pub use moving_average::MovingAverage;
+
 mod quartiles;
-pub use quartiles::Quartiles;
+pub(crate) use quartiles::weighted_percentile;
+pub use quartiles::{Quartiles, QuartilesAccumulator};
+
+mod regression;
+pub use regression::{linear_regression, RegressionError, TrendFit, TrendKind, TrendLine};
+
+mod savitzky_golay;
+pub use savitzky_golay::SavitzkyGolay;
 
 pub mod float;
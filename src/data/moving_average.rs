@@ -0,0 +1,212 @@
+use num_traits::{NumCast, ToPrimitive};
+use std::collections::VecDeque;
+
+enum AverageKind {
+    Simple {
+        window: VecDeque<f64>,
+        sum: f64,
+    },
+    Exponential {
+        alpha: f64,
+        seed: Vec<f64>,
+        last: Option<f64>,
+    },
+}
+
+/// The iterator adapter that smooths a data series with a moving average, which can be used
+/// directly as the data of a [LineSeries](../series/struct.LineSeries.html).
+///
+/// Each output point uses the X value of the last data point in the window, thus the first
+/// `window - 1` data points don't produce output, and a window larger than the data yields an
+/// empty series. The data points with a non-finite value, such as NaN, are skipped and don't
+/// contribute to the average.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data = vec![(0, 1.0), (1, 3.0), (2, 2.0), (3, 4.0)];
+/// let smoothed: Vec<_> = MovingAverage::new(data.iter().cloned(), 2).collect();
+/// assert_eq!(smoothed, vec![(1, 2.0), (2, 2.5), (3, 3.0)]);
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..3, 0.0..5.0)
+///     .unwrap();
+/// chart
+///     .draw_series(LineSeries::new(MovingAverage::exponential(data, 2), &RED))
+///     .unwrap();
+/// ```
+pub struct MovingAverage<I, X, Y>
+where
+    I: Iterator<Item = (X, Y)>,
+{
+    iter: I,
+    size: usize,
+    kind: AverageKind,
+}
+
+impl<I, X, Y> MovingAverage<I, X, Y>
+where
+    I: Iterator<Item = (X, Y)>,
+    Y: ToPrimitive + NumCast,
+{
+    /// Create a simple moving average, which is the mean of the last `window` values
+    ///
+    /// - `iter`: The data points
+    /// - `window`: The number of data points in the window
+    /// - **returns** The iterator over the smoothed data points
+    pub fn new<T: IntoIterator<Item = (X, Y), IntoIter = I>>(iter: T, window: usize) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            size: window,
+            kind: AverageKind::Simple {
+                window: VecDeque::with_capacity(window),
+                sum: 0.0,
+            },
+        }
+    }
+
+    /// Create an exponential moving average with the smoothing factor `2 / (window + 1)`. The
+    /// average is seeded with the simple average of the first `window` values.
+    ///
+    /// - `iter`: The data points
+    /// - `window`: The span of the average
+    /// - **returns** The iterator over the smoothed data points
+    pub fn exponential<T: IntoIterator<Item = (X, Y), IntoIter = I>>(
+        iter: T,
+        window: usize,
+    ) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            size: window,
+            kind: AverageKind::Exponential {
+                alpha: 2.0 / (window as f64 + 1.0),
+                seed: Vec::with_capacity(window),
+                last: None,
+            },
+        }
+    }
+
+    /// Create an exponential moving average with the given smoothing factor, which is seeded
+    /// with the first value, thus every data point produces an output point.
+    ///
+    /// - `iter`: The data points
+    /// - `alpha`: The smoothing factor in `(0, 1]`, a larger factor follows the data more closely
+    /// - **returns** The iterator over the smoothed data points
+    pub fn exponential_with_alpha<T: IntoIterator<Item = (X, Y), IntoIter = I>>(
+        iter: T,
+        alpha: f64,
+    ) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            size: 1,
+            kind: AverageKind::Exponential {
+                alpha,
+                seed: Vec::with_capacity(1),
+                last: None,
+            },
+        }
+    }
+}
+
+impl<I, X, Y> Iterator for MovingAverage<I, X, Y>
+where
+    I: Iterator<Item = (X, Y)>,
+    Y: ToPrimitive + NumCast,
+{
+    type Item = (X, Y);
+    fn next(&mut self) -> Option<(X, Y)> {
+        if self.size == 0 {
+            return None;
+        }
+        loop {
+            let (x, y) = self.iter.next()?;
+            let y = match y.to_f64() {
+                Some(y) if y.is_finite() => y,
+                _ => continue,
+            };
+            let average = match self.kind {
+                AverageKind::Simple {
+                    ref mut window,
+                    ref mut sum,
+                } => {
+                    window.push_back(y);
+                    *sum += y;
+                    if window.len() > self.size {
+                        *sum -= window.pop_front().unwrap_or(0.0);
+                    }
+                    if window.len() < self.size {
+                        None
+                    } else {
+                        Some(*sum / self.size as f64)
+                    }
+                }
+                AverageKind::Exponential {
+                    alpha,
+                    ref mut seed,
+                    ref mut last,
+                } => {
+                    *last = match *last {
+                        Some(last) => Some(alpha * y + (1.0 - alpha) * last),
+                        None => {
+                            seed.push(y);
+                            if seed.len() < self.size {
+                                None
+                            } else {
+                                Some(seed.iter().sum::<f64>() / self.size as f64)
+                            }
+                        }
+                    };
+                    *last
+                }
+            };
+            if let Some(average) = average {
+                return Some((x, NumCast::from(average)?));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_simple_moving_average() {
+        let data: Vec<_> = (0..6).map(|x| (x, x as f64)).collect();
+        let result: Vec<_> = MovingAverage::new(data.clone(), 3).collect();
+        assert_eq!(result, vec![(2, 1.0), (3, 2.0), (4, 3.0), (5, 4.0)]);
+
+        assert_eq!(MovingAverage::new(data.clone(), 7).count(), 0);
+        assert_eq!(MovingAverage::new(data, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_exponential_moving_average() {
+        let data = vec![(0, 2.0), (1, 4.0), (2, 6.0), (3, 6.0)];
+        let result: Vec<_> = MovingAverage::exponential(data.clone(), 3).collect();
+        assert_eq!(result, vec![(2, 4.0), (3, 5.0)]);
+
+        assert_eq!(MovingAverage::exponential(data.clone(), 5).count(), 0);
+
+        let result: Vec<_> = MovingAverage::exponential_with_alpha(data, 0.5).collect();
+        assert_eq!(result, vec![(0, 2.0), (1, 3.0), (2, 4.5), (3, 5.25)]);
+    }
+
+    #[test]
+    fn test_moving_average_skips_non_finite() {
+        let data = vec![
+            (0, 1.0),
+            (1, std::f64::NAN),
+            (2, 3.0),
+            (3, std::f64::INFINITY),
+            (4, 5.0),
+        ];
+        let result: Vec<_> = MovingAverage::new(data.clone(), 2).collect();
+        assert_eq!(result, vec![(2, 2.0), (4, 4.0)]);
+
+        let result: Vec<_> = MovingAverage::exponential_with_alpha(data, 0.5).collect();
+        assert_eq!(result, vec![(0, 1.0), (2, 2.0), (4, 3.5)]);
+    }
+}
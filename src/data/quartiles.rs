@@ -1,3 +1,30 @@
+/// The value at the fraction of the weighted samples, which are sorted by the value and have
+/// positive weights. Each sample is placed at the middle of its weight, and the value is
+/// interpolated between the samples, thus the samples of the same weight give the same
+/// percentiles as the unweighted samples.
+pub(crate) fn weighted_percentile(sorted: &[(f64, f64)], fraction: f64) -> f64 {
+    assert!(!sorted.is_empty());
+    let mut total = 0.0;
+    let centers: Vec<f64> = sorted
+        .iter()
+        .map(|(_, w)| {
+            total += w;
+            total - w / 2.0
+        })
+        .collect();
+    let (first, last) = (centers[0], centers[centers.len() - 1]);
+    let pos = first + (last - first) * fraction;
+    if pos <= first {
+        return sorted[0].0;
+    }
+    let idx = match centers.iter().position(|c| *c >= pos) {
+        Some(idx) => idx,
+        None => return sorted[sorted.len() - 1].0,
+    };
+    let (lo, hi) = (sorted[idx - 1].0, sorted[idx].0);
+    lo + (hi - lo) * (pos - centers[idx - 1]) / (centers[idx] - centers[idx - 1])
+}
+
 /// The quartiles
 #[derive(Clone, Debug)]
 pub struct Quartiles {
@@ -6,6 +33,8 @@ pub struct Quartiles {
     median: f64,
     upper: f64,
     upper_fence: f64,
+    count: usize,
+    outliers: Vec<f64>,
 }
 
 impl Quartiles {
@@ -44,24 +73,130 @@ impl Quartiles {
     /// assert_eq!(quartiles.median(), 37.5);
     /// ```
     pub fn new<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Self {
-        let mut s = s.to_owned();
-        s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        Self::with_fence(s, 1.5)
+    }
+
+    /// Create a new quartiles struct, whose fences are `k` times the interquartile range away
+    /// from the lower and upper quartiles. The `new` method uses the Tukey fence with `k = 1.5`.
+    ///
+    /// - `s`: The array of the original values
+    /// - `k`: The multiplier of the interquartile range
+    /// - **returns** The newly created quartiles
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::with_fence(&[7, 15, 36, 39, 40, 41], 3.0);
+    /// assert_eq!(quartiles.values(), [-38.25, 20.25, 37.5, 39.75, 98.25]);
+    /// ```
+    pub fn with_fence<T: Into<f64> + Copy + PartialOrd>(s: &[T], k: f64) -> Self {
+        let s = Quartiles::sorted(s);
 
         let lower = Quartiles::percentile_of_sorted(&s, 25_f64);
         let median = Quartiles::percentile_of_sorted(&s, 50_f64);
         let upper = Quartiles::percentile_of_sorted(&s, 75_f64);
         let iqr = upper - lower;
+        let lower_fence = lower - k * iqr;
+        let upper_fence = upper + k * iqr;
+        Self {
+            lower_fence,
+            lower,
+            median,
+            upper,
+            upper_fence,
+            count: s.len(),
+            outliers: Quartiles::outliers_of(&s, lower_fence, upper_fence),
+        }
+    }
+
+    /// Create a new quartiles struct, whose fences are the given percentiles of the values,
+    /// which is more robust than the interquartile range based fences for heavy-tailed data.
+    ///
+    /// - `s`: The array of the original values
+    /// - `lower_pct`: The percentile of the lower fence, in range `0.0..=100.0`
+    /// - `upper_pct`: The percentile of the upper fence, in range `0.0..=100.0`
+    /// - **returns** The newly created quartiles
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let values: Vec<_> = (0..=100).collect();
+    /// let quartiles = Quartiles::with_percentile_fence(&values, 5.0, 95.0);
+    /// assert_eq!(quartiles.values(), [5.0, 25.0, 50.0, 75.0, 95.0]);
+    /// ```
+    pub fn with_percentile_fence<T: Into<f64> + Copy + PartialOrd>(
+        s: &[T],
+        lower_pct: f64,
+        upper_pct: f64,
+    ) -> Self {
+        let s = Quartiles::sorted(s);
+        let lower_fence = Quartiles::percentile_of_sorted(&s, lower_pct);
+        let upper_fence = Quartiles::percentile_of_sorted(&s, upper_pct);
+        Self {
+            lower_fence,
+            lower: Quartiles::percentile_of_sorted(&s, 25_f64),
+            median: Quartiles::percentile_of_sorted(&s, 50_f64),
+            upper: Quartiles::percentile_of_sorted(&s, 75_f64),
+            upper_fence,
+            count: s.len(),
+            outliers: Quartiles::outliers_of(&s, lower_fence, upper_fence),
+        }
+    }
+
+    /// Create a new quartiles struct with the Tukey fences from the weighted values, such as
+    /// the survey weights or the counts of the pre-aggregated values. Only the relative weights
+    /// matter, and the values with the same weight give the same quartiles as
+    /// [new](#method.new). The values that are NaN or infinite and the values that have no
+    /// positive weight are ignored, and the count is the number of the weighted values.
+    ///
+    /// - `s`: The array of the values and their weights
+    /// - **returns** The newly created quartiles
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::weighted(&[(10, 1.0), (20, 100.0), (30, 1.0)]);
+    /// assert_eq!(quartiles.median(), 20.0);
+    /// ```
+    pub fn weighted<T: Into<f64> + Copy + PartialOrd>(s: &[(T, f64)]) -> Self {
+        let mut s: Vec<(f64, f64)> = s
+            .iter()
+            .map(|&(v, w)| (v.into(), w))
+            .filter(|(v, w)| v.is_finite() && *w > 0.0 && w.is_finite())
+            .collect();
+        s.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let lower = weighted_percentile(&s, 0.25);
+        let median = weighted_percentile(&s, 0.5);
+        let upper = weighted_percentile(&s, 0.75);
+        let iqr = upper - lower;
         let lower_fence = lower - 1.5 * iqr;
         let upper_fence = upper + 1.5 * iqr;
+        let values: Vec<f64> = s.iter().map(|(v, _)| *v).collect();
         Self {
             lower_fence,
             lower,
             median,
             upper,
             upper_fence,
+            count: s.len(),
+            outliers: Quartiles::outliers_of(&values, lower_fence, upper_fence),
         }
     }
 
+    fn outliers_of<T: Into<f64> + Copy>(s: &[T], lower_fence: f64, upper_fence: f64) -> Vec<f64> {
+        s.iter()
+            .map(|&v| v.into())
+            .filter(|&v| v < lower_fence || v > upper_fence)
+            .collect()
+    }
+
+    fn sorted<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Vec<T> {
+        let mut s = s.to_owned();
+        s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        s
+    }
+
     /// Get the quartiles values.
     ///
     /// - **returns** The array [lower fence, lower quartile, median, upper quartile, upper fence]
@@ -73,13 +208,13 @@ impl Quartiles {
     /// let values = quartiles.values();
     /// assert_eq!(values, [-9.0, 20.25, 37.5, 39.75, 69.0]);
     /// ```
-    pub fn values(&self) -> [f32; 5] {
+    pub fn values(&self) -> [f64; 5] {
         [
-            self.lower_fence as f32,
-            self.lower as f32,
-            self.median as f32,
-            self.upper as f32,
-            self.upper_fence as f32,
+            self.lower_fence,
+            self.lower,
+            self.median,
+            self.upper,
+            self.upper_fence,
         ]
     }
 
@@ -96,6 +231,185 @@ impl Quartiles {
     pub fn median(&self) -> f64 {
         self.median
     }
+
+    /// Get the number of the original values.
+    ///
+    /// - **returns** The number of values
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.count(), 6);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the original values that are outside of the fences.
+    ///
+    /// - **returns** The outliers in ascending order
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41, 120]);
+    /// assert_eq!(quartiles.outliers(), &[120.0]);
+    /// ```
+    pub fn outliers(&self) -> &[f64] {
+        &self.outliers
+    }
+}
+
+/// The P² estimator of a single quantile, which keeps five markers instead of the values
+#[derive(Clone, Debug)]
+struct P2Quantile {
+    heights: [f64; 5],
+    positions: [f64; 5],
+    desired: [f64; 5],
+    increments: [f64; 5],
+}
+
+impl P2Quantile {
+    fn new(p: f64, initial: &[f64; 5]) -> Self {
+        Self {
+            heights: *initial,
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    fn push(&mut self, value: f64) {
+        let (q, n) = (&mut self.heights, &mut self.positions);
+        let cell = if value < q[0] {
+            q[0] = value;
+            0
+        } else if value >= q[4] {
+            q[4] = value;
+            3
+        } else {
+            (0..4).find(|&i| value < q[i + 1]).unwrap_or(3)
+        };
+        for position in n.iter_mut().skip(cell + 1) {
+            *position += 1.0;
+        }
+        for (desired, inc) in self.desired.iter_mut().zip(self.increments.iter()) {
+            *desired += inc;
+        }
+
+        for i in 1..4 {
+            let d = self.desired[i] - n[i];
+            if (d >= 1.0 && n[i + 1] - n[i] > 1.0) || (d <= -1.0 && n[i - 1] - n[i] < -1.0) {
+                let d = d.signum();
+                // The piecewise-parabolic prediction of the marker height
+                let parabolic = q[i]
+                    + d / (n[i + 1] - n[i - 1])
+                        * ((n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                            + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]));
+                q[i] = if q[i - 1] < parabolic && parabolic < q[i + 1] {
+                    parabolic
+                } else {
+                    let j = if d > 0.0 { i + 1 } else { i - 1 };
+                    q[i] + d * (q[j] - q[i]) / (n[j] - n[i])
+                };
+                n[i] += d;
+            }
+        }
+    }
+
+    fn estimate(&self) -> f64 {
+        self.heights[2]
+    }
+}
+
+/// The incremental builder of the [Quartiles](struct.Quartiles.html), which estimates the
+/// quartiles of a data stream with the P² algorithm in constant memory, rather than keeping and
+/// sorting all the values.
+///
+/// The estimated quartiles are exact for the first five values and approximated afterwards. As
+/// the values are not kept, the quartiles from the accumulator have no outliers.
+///
+/// ```rust
+/// use plotters::data::QuartilesAccumulator;
+///
+/// let mut acc = QuartilesAccumulator::new();
+/// for value in 0..=1000 {
+///     acc.push(value);
+/// }
+/// let quartiles = acc.finish().unwrap();
+/// assert!((quartiles.median() - 500.0).abs() < 1.0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct QuartilesAccumulator {
+    initial: Vec<f64>,
+    estimators: Option<[P2Quantile; 3]>,
+    count: usize,
+}
+
+impl QuartilesAccumulator {
+    /// Create a new accumulator without values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value to the accumulator, the NaN values are ignored
+    /// - `value`: The value to add
+    pub fn push<T: Into<f64>>(&mut self, value: T) {
+        let value = value.into();
+        if value.is_nan() {
+            return;
+        }
+        self.count += 1;
+        if let Some(ref mut estimators) = self.estimators {
+            for estimator in estimators.iter_mut() {
+                estimator.push(value);
+            }
+            return;
+        }
+        self.initial.push(value);
+        if self.initial.len() == 5 {
+            let mut initial = [0.0; 5];
+            initial.copy_from_slice(&self.initial);
+            initial.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            self.estimators = Some([
+                P2Quantile::new(0.25, &initial),
+                P2Quantile::new(0.5, &initial),
+                P2Quantile::new(0.75, &initial),
+            ]);
+        }
+    }
+
+    /// Get the quartiles of the values that have been added so far, with the Tukey fences
+    /// - **returns** The quartiles, or `None` if no value has been added
+    pub fn finish(&self) -> Option<Quartiles> {
+        let estimators = match self.estimators {
+            Some(ref estimators) => estimators,
+            None if self.initial.is_empty() => return None,
+            None => return Some(Quartiles::new(&self.initial)),
+        };
+        let lower = estimators[0].estimate();
+        let median = estimators[1].estimate();
+        let upper = estimators[2].estimate();
+        let iqr = upper - lower;
+        Some(Quartiles {
+            lower_fence: lower - 1.5 * iqr,
+            lower,
+            median,
+            upper,
+            upper_fence: upper + 1.5 * iqr,
+            count: self.count,
+            outliers: vec![],
+        })
+    }
+}
+
+impl<T: Into<f64>> Extend<T> for QuartilesAccumulator {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -124,4 +438,92 @@ mod test {
             [0.0, 15.0, 20.0, 25.0, 40.0]
         );
     }
+
+    #[test]
+    fn test_precision() {
+        // The nanosecond timestamps differ below the precision of f32
+        let base = 1_600_000_000_000_000_000.0;
+        let quartiles = Quartiles::new(&[base, base + 1024.0, base + 2048.0]);
+        assert_eq!(quartiles.values()[2] - base, 1024.0);
+        let quartiles = Quartiles::new(&[1e-50, 2e-50, 3e-50]);
+        assert_eq!(quartiles.median(), 2e-50);
+        assert_eq!(quartiles.values()[2], 2e-50);
+    }
+
+    #[test]
+    fn test_fences() {
+        let values = [10, 20, 30];
+        assert_eq!(
+            Quartiles::with_fence(&values, 1.5).values(),
+            Quartiles::new(&values).values()
+        );
+        assert_eq!(
+            Quartiles::with_fence(&values, 0.0).values(),
+            [15.0, 15.0, 20.0, 25.0, 25.0]
+        );
+        assert_eq!(
+            Quartiles::with_percentile_fence(&values, 0.0, 100.0).values(),
+            [10.0, 15.0, 20.0, 25.0, 30.0]
+        );
+        assert!(Quartiles::new(&values).outliers().is_empty());
+        assert_eq!(
+            Quartiles::with_fence(&values, 0.0).outliers(),
+            &[10.0, 30.0]
+        );
+    }
+
+    #[test]
+    fn test_weighted() {
+        let values = [7, 15, 36, 39, 40, 41];
+        let weighted: Vec<_> = values.iter().map(|&v| (v, 3.0)).collect();
+        let expected = Quartiles::new(&values).values();
+        for (a, b) in Quartiles::weighted(&weighted)
+            .values()
+            .iter()
+            .zip(&expected)
+        {
+            assert!((a - b).abs() < 1e-9);
+        }
+
+        // The heavy value pulls the quartiles, and the unweighted values are ignored
+        let quartiles = Quartiles::weighted(&[(0.0, 1.0), (10.0, 8.0), (20.0, 1.0), (99.0, 0.0)]);
+        assert_eq!(quartiles.count(), 3);
+        assert_eq!(quartiles.values(), [-10.0, 5.0, 10.0, 15.0, 30.0]);
+        assert!(quartiles.outliers().is_empty());
+
+        // The NaN values and weights are ignored rather than panicking in the sort
+        let quartiles = Quartiles::weighted(&[
+            (0.0, 1.0),
+            (f64::NAN, 1.0),
+            (10.0, 8.0),
+            (20.0, 1.0),
+            (30.0, f64::NAN),
+            (f64::INFINITY, 1.0),
+        ]);
+        assert_eq!(quartiles.count(), 3);
+        assert_eq!(quartiles.values(), [-10.0, 5.0, 10.0, 15.0, 30.0]);
+    }
+
+    #[test]
+    fn test_accumulator() {
+        let mut acc = QuartilesAccumulator::new();
+        assert!(acc.finish().is_none());
+
+        acc.extend(vec![10, 20, 30]);
+        assert_eq!(
+            acc.finish().unwrap().values(),
+            Quartiles::new(&[10, 20, 30]).values()
+        );
+
+        // A deterministic shuffle of 0..10000
+        let mut acc = QuartilesAccumulator::new();
+        acc.extend((0..10000u32).map(|i| f64::from(i * 7919 % 10000)));
+        acc.push(std::f64::NAN);
+        let quartiles = acc.finish().unwrap();
+        let values = quartiles.values();
+        assert_eq!(quartiles.count(), 10000);
+        assert!((values[1] - 2500.0).abs() < 50.0);
+        assert!((values[2] - 5000.0).abs() < 50.0);
+        assert!((values[3] - 7500.0).abs() < 50.0);
+    }
 }
@@ -0,0 +1,513 @@
+use crate::element::{Drawable, PathElement, PointCollection};
+use crate::style::ShapeStyle;
+use num_traits::{NumCast, ToPrimitive};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error of the regression algorithms
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegressionError {
+    /// There are fewer than two finite data points
+    NotEnoughPoints,
+    /// All the finite data points have the same X value, thus the slope is undefined
+    VerticalLine,
+    /// The data points don't determine the curve, such as there are fewer distinct X values
+    /// than the coefficients of a polynomial
+    DegenerateData,
+}
+
+impl Display for RegressionError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RegressionError::NotEnoughPoints => {
+                write!(fmt, "At least two finite points are required")
+            }
+            RegressionError::VerticalLine => write!(fmt, "All the points have the same X value"),
+            RegressionError::DegenerateData => {
+                write!(
+                    fmt,
+                    "The points don't determine the coefficients of the curve"
+                )
+            }
+        }
+    }
+}
+
+impl Error for RegressionError {}
+
+fn finite_points<X: ToPrimitive, Y: ToPrimitive, I: IntoIterator<Item = (X, Y)>>(
+    iter: I,
+) -> Vec<(f64, f64)> {
+    iter.into_iter()
+        .filter_map(|(x, y)| Some((x.to_f64()?, y.to_f64()?)))
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect()
+}
+
+fn fit(points: &[(f64, f64)]) -> Result<(f64, f64, f64), RegressionError> {
+    if points.len() < 2 {
+        return Err(RegressionError::NotEnoughPoints);
+    }
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    for (x, y) in points {
+        sxx += (x - mean_x) * (x - mean_x);
+        sxy += (x - mean_x) * (y - mean_y);
+        syy += (y - mean_y) * (y - mean_y);
+    }
+
+    if sxx == 0.0 {
+        return Err(RegressionError::VerticalLine);
+    }
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    // A horizontal line is a perfect fit for the data without variance
+    let r2 = if syy == 0.0 {
+        1.0
+    } else {
+        sxy * sxy / (sxx * syy)
+    };
+
+    Ok((slope, intercept, r2))
+}
+
+/// Fit a line to the data points with the least squares method. The points with a NaN or
+/// infinite coordinate are ignored.
+///
+/// - `iter`: The iterator over the data points
+/// - **returns** The tuple of slope, intercept and the coefficient of determination (R²), or an
+///   error if there are fewer than two finite points
+///
+/// ```rust
+/// use plotters::data::linear_regression;
+///
+/// let (slope, intercept, r2) = linear_regression(vec![(0, 1), (1, 3), (2, 5)]).unwrap();
+/// assert_eq!((slope, intercept, r2), (2.0, 1.0, 1.0));
+/// ```
+pub fn linear_regression<X: ToPrimitive, Y: ToPrimitive, I: IntoIterator<Item = (X, Y)>>(
+    iter: I,
+) -> Result<(f64, f64, f64), RegressionError> {
+    fit(&finite_points(iter))
+}
+
+/// The kind of the curve that is fitted to the data points by [TrendFit](struct.TrendFit.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrendKind {
+    /// The line `y = a + b·x`
+    Linear,
+    /// The polynomial `y = c0 + c1·x + ... + cn·x^n` of the given degree
+    Polynomial(usize),
+    /// The exponential curve `y = a·e^(b·x)`, which is fitted to the points with a positive Y
+    Exponential,
+    /// The logarithmic curve `y = a + b·ln(x)`, which is fitted to the points with a positive X
+    Logarithmic,
+}
+
+/// Solve the linear equations with the Gaussian elimination, or `None` if they are singular
+pub(crate) fn solve(mut matrix: Vec<Vec<f64>>, mut values: Vec<f64>) -> Option<Vec<f64>> {
+    let n = values.len();
+    let norm = matrix
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(0.0, |m: f64, v| m.max(v.abs()));
+    for col in 0..n {
+        let mut pivot = col;
+        for row in col + 1..n {
+            if matrix[row][col].abs() > matrix[pivot][col].abs() {
+                pivot = row;
+            }
+        }
+        if matrix[pivot][col].abs() <= norm * 1e-12 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        values.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        for row in col + 1..n {
+            let factor = matrix[row][col] / pivot_row[col];
+            for (cell, pivot) in matrix[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *cell -= factor * pivot;
+            }
+            values[row] -= factor * values[col];
+        }
+    }
+    let mut result = vec![0.0; n];
+    for row in (0..n).rev() {
+        let rest: f64 = (row + 1..n).map(|k| matrix[row][k] * result[k]).sum();
+        result[row] = (values[row] - rest) / matrix[row][row];
+    }
+    Some(result)
+}
+
+/// Fit the polynomial of the degree with the normal equations of the least squares method
+fn fit_polynomial(points: &[(f64, f64)], degree: usize) -> Result<Vec<f64>, RegressionError> {
+    if points.len() < degree + 1 {
+        return Err(RegressionError::NotEnoughPoints);
+    }
+    let n = degree + 1;
+    let mut matrix = vec![vec![0.0; n]; n];
+    let mut values = vec![0.0; n];
+    for (x, y) in points {
+        let powers: Vec<_> = (0..2 * n - 1).map(|k| x.powi(k as i32)).collect();
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell += powers[i + j];
+            }
+            values[i] += powers[i] * y;
+        }
+    }
+    solve(matrix, values).ok_or(RegressionError::DegenerateData)
+}
+
+/// The curve fitted to the data points with the least squares method, which can be evaluated
+/// and printed as an equation, for example, for the label of a legend.
+///
+/// The exponential curve is fitted as a line to the logarithm of Y, and the logarithmic curve
+/// is fitted as a line over the logarithm of X. The coefficient of determination (R²) is always
+/// computed for the original Y values.
+///
+/// ```rust
+/// use plotters::data::{TrendFit, TrendKind};
+///
+/// let fit = TrendFit::new(vec![(0.0, 1.0), (1.0, 2.0), (2.0, 5.0), (3.0, 10.0)], TrendKind::Polynomial(2))
+///     .unwrap();
+/// assert_eq!(fit.equation(), "y = 1.00x^2 + 0.00x + 1.00");
+/// assert!((fit.eval(4.0) - 17.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendFit {
+    kind: TrendKind,
+    coefficients: Vec<f64>,
+    r2: f64,
+    x_range: (f64, f64),
+}
+
+impl TrendFit {
+    /// Fit the curve to the data points, the points with a NaN or infinite coordinate, and the
+    /// points out of the domain of an exponential or logarithmic curve are ignored.
+    ///
+    /// - `iter`: The iterator over the data points
+    /// - `kind`: The kind of the curve
+    /// - **returns** The fitted curve, or an error if the curve can't be fitted
+    pub fn new<X: ToPrimitive, Y: ToPrimitive, I: IntoIterator<Item = (X, Y)>>(
+        iter: I,
+        kind: TrendKind,
+    ) -> Result<Self, RegressionError> {
+        let points: Vec<_> = finite_points(iter)
+            .into_iter()
+            .filter(|(x, y)| match kind {
+                TrendKind::Exponential => *y > 0.0,
+                TrendKind::Logarithmic => *x > 0.0,
+                _ => true,
+            })
+            .collect();
+        let coefficients = match kind {
+            TrendKind::Linear => {
+                let (slope, intercept, _) = fit(&points)?;
+                vec![intercept, slope]
+            }
+            TrendKind::Polynomial(degree) => fit_polynomial(&points, degree)?,
+            TrendKind::Exponential => {
+                let logs: Vec<_> = points.iter().map(|(x, y)| (*x, y.ln())).collect();
+                let (slope, intercept, _) = fit(&logs)?;
+                vec![intercept.exp(), slope]
+            }
+            TrendKind::Logarithmic => {
+                let logs: Vec<_> = points.iter().map(|(x, y)| (x.ln(), *y)).collect();
+                let (slope, intercept, _) = fit(&logs)?;
+                vec![intercept, slope]
+            }
+        };
+
+        let x0 = points.iter().map(|p| p.0).fold(points[0].0, f64::min);
+        let x1 = points.iter().map(|p| p.0).fold(points[0].0, f64::max);
+        let mut fit = Self {
+            kind,
+            coefficients,
+            r2: 1.0,
+            x_range: (x0, x1),
+        };
+
+        let mean = points.iter().map(|p| p.1).sum::<f64>() / points.len() as f64;
+        let total: f64 = points.iter().map(|(_, y)| (y - mean) * (y - mean)).sum();
+        let residual: f64 = points
+            .iter()
+            .map(|(x, y)| (y - fit.eval(*x)) * (y - fit.eval(*x)))
+            .sum();
+        // A constant curve is a perfect fit for the data without variance
+        if total > 0.0 {
+            fit.r2 = 1.0 - residual / total;
+        }
+        Ok(fit)
+    }
+
+    /// Get the kind of the curve
+    pub fn kind(&self) -> TrendKind {
+        self.kind
+    }
+
+    /// Get the coefficients of the curve: `[a, b]` for the linear, exponential and logarithmic
+    /// curves, and `[c0, c1, ..., cn]` for a polynomial
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Get the coefficient of determination (R²) of the fit
+    pub fn r2(&self) -> f64 {
+        self.r2
+    }
+
+    /// Get the range of X of the points that are fitted
+    pub fn x_range(&self) -> (f64, f64) {
+        self.x_range
+    }
+
+    /// Evaluate the curve at the X value
+    pub fn eval(&self, x: f64) -> f64 {
+        let c = &self.coefficients;
+        match self.kind {
+            TrendKind::Linear => c[0] + c[1] * x,
+            TrendKind::Polynomial(_) => c.iter().rev().fold(0.0, |sum, c| sum * x + c),
+            TrendKind::Exponential => c[0] * (c[1] * x).exp(),
+            TrendKind::Logarithmic => c[0] + c[1] * x.ln(),
+        }
+    }
+
+    /// Format the equation of the curve, with 2 decimal places
+    pub fn equation(&self) -> String {
+        let c = &self.coefficients;
+        let term = |value: f64, first: bool| {
+            // Round first, thus a tiny negative value isn't printed as "- 0.00", and adding zero
+            // turns the negative zero into zero
+            let value = (value * 100.0).round() / 100.0 + 0.0;
+            if first {
+                format!("{:.2}", value)
+            } else if value < 0.0 {
+                format!(" - {:.2}", -value)
+            } else {
+                format!(" + {:.2}", value)
+            }
+        };
+        match self.kind {
+            TrendKind::Linear => format!("y = {}x{}", term(c[1], true), term(c[0], false)),
+            TrendKind::Polynomial(degree) => {
+                let mut equation = String::from("y = ");
+                for power in (0..=degree).rev() {
+                    equation.push_str(&term(c[power], power == degree));
+                    match power {
+                        0 => {}
+                        1 => equation.push('x'),
+                        _ => equation.push_str(&format!("x^{}", power)),
+                    }
+                }
+                equation
+            }
+            TrendKind::Exponential => format!("y = {}e^({}x)", term(c[0], true), term(c[1], true)),
+            TrendKind::Logarithmic => format!("y = {}{}ln(x)", term(c[0], true), term(c[1], false)),
+        }
+    }
+}
+
+/// The element that draws the least squares line of the data points, which is clipped to the X
+/// range of the data.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data = vec![(1.0, 2.1), (2.0, 3.9), (3.0, 6.2), (4.0, 7.8)];
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..5.0, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(data.iter().map(|p| Circle::new(*p, 3, &BLUE)))
+///     .unwrap();
+/// let trend = TrendLine::from_points(data.iter().cloned(), &RED).unwrap();
+/// assert!(trend.r2() > 0.99);
+/// chart.draw_series(std::iter::once(trend)).unwrap();
+/// ```
+pub struct TrendLine<X, Y> {
+    path: PathElement<(X, Y)>,
+    slope: f64,
+    intercept: f64,
+    r2: f64,
+}
+
+impl<X: NumCast, Y: NumCast> TrendLine<X, Y> {
+    /// Create a new trend line from the data points, the points with a NaN or infinite coordinate
+    /// are ignored.
+    ///
+    /// - `iter`: The iterator over the data points
+    /// - `style`: The style of the line
+    /// - **returns** The trend line element, or an error if the line can't be fitted
+    pub fn from_points<I, S>(iter: I, style: S) -> Result<Self, RegressionError>
+    where
+        I: IntoIterator<Item = (X, Y)>,
+        X: ToPrimitive,
+        Y: ToPrimitive,
+        S: Into<ShapeStyle>,
+    {
+        let points = finite_points(iter);
+        let (slope, intercept, r2) = fit(&points)?;
+
+        let x0 = points.iter().map(|p| p.0).fold(points[0].0, f64::min);
+        let x1 = points.iter().map(|p| p.0).fold(points[0].0, f64::max);
+        let path = [x0, x1]
+            .iter()
+            .filter_map(|&x| Some((NumCast::from(x)?, NumCast::from(slope * x + intercept)?)))
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            path: PathElement::new(path, style),
+            slope,
+            intercept,
+            r2,
+        })
+    }
+
+    /// Get the slope of the fitted line
+    pub fn slope(&self) -> f64 {
+        self.slope
+    }
+
+    /// Get the intercept of the fitted line
+    pub fn intercept(&self) -> f64 {
+        self.intercept
+    }
+
+    /// Get the coefficient of determination (R²) of the fit
+    pub fn r2(&self) -> f64 {
+        self.r2
+    }
+}
+
+impl<'a, X, Y> PointCollection<'a, (X, Y)> for &'a TrendLine<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        self.path.point_iter()
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for TrendLine<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.path.draw(points, backend, parent_dim)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_linear_regression() {
+        let (slope, intercept, r2) = linear_regression(vec![
+            (0.0, 1.0),
+            (1.0, std::f64::NAN),
+            (2.0, 5.0),
+            (3.0, 7.0),
+        ])
+        .unwrap();
+        assert!((slope - 2.0).abs() < 1e-9);
+        assert!((intercept - 1.0).abs() < 1e-9);
+        assert!((r2 - 1.0).abs() < 1e-9);
+
+        let (_, _, r2) = linear_regression(vec![(0, 0), (1, 2), (2, 1), (3, 3)]).unwrap();
+        assert!(r2 > 0.0 && r2 < 1.0);
+
+        assert_eq!(
+            linear_regression(vec![(0.0, 1.0), (std::f64::NAN, 2.0)]),
+            Err(RegressionError::NotEnoughPoints)
+        );
+        assert_eq!(
+            linear_regression(vec![(1, 1), (1, 2)]),
+            Err(RegressionError::VerticalLine)
+        );
+    }
+
+    #[test]
+    fn test_trend_fit() {
+        let fit = TrendFit::new(vec![(0, 1), (1, 3), (2, 5)], TrendKind::Linear).unwrap();
+        assert_eq!(fit.coefficients(), &[1.0, 2.0][..]);
+        assert_eq!(fit.equation(), "y = 2.00x + 1.00");
+        assert_eq!(fit.r2(), 1.0);
+
+        let data: Vec<_> = (0..5)
+            .map(|x| (x as f64, 2.0 * x as f64 * x as f64 - 3.0))
+            .collect();
+        let fit = TrendFit::new(data.clone(), TrendKind::Polynomial(2)).unwrap();
+        for (expected, actual) in [-3.0, 0.0, 2.0].iter().zip(fit.coefficients()) {
+            assert!((expected - actual).abs() < 1e-9);
+        }
+        assert!((fit.r2() - 1.0).abs() < 1e-9);
+        assert_eq!(fit.equation(), "y = 2.00x^2 + 0.00x - 3.00");
+        assert_eq!(
+            TrendFit::new(
+                vec![(1.0, 1.0), (1.0, 2.0), (1.0, 3.0)],
+                TrendKind::Polynomial(2)
+            ),
+            Err(RegressionError::DegenerateData)
+        );
+        assert_eq!(
+            TrendFit::new(vec![(1.0, 1.0), (2.0, 2.0)], TrendKind::Polynomial(2)),
+            Err(RegressionError::NotEnoughPoints)
+        );
+
+        // The point with a negative Y is out of the domain of the exponential curve
+        let data = vec![
+            (0.0, 2.0),
+            (1.0, 2.0 * 1f64.exp()),
+            (2.0, -1.0),
+            (3.0, 2.0 * 3f64.exp()),
+        ];
+        let fit = TrendFit::new(data, TrendKind::Exponential).unwrap();
+        assert!((fit.eval(2.0) - 2.0 * 2f64.exp()).abs() < 1e-9);
+        assert_eq!(fit.equation(), "y = 2.00e^(1.00x)");
+
+        let data: Vec<_> = (1..5)
+            .map(|x| (x as f64, 1.0 + 3.0 * (x as f64).ln()))
+            .collect();
+        let fit = TrendFit::new(data, TrendKind::Logarithmic).unwrap();
+        assert!((fit.coefficients()[1] - 3.0).abs() < 1e-9);
+        assert_eq!(fit.equation(), "y = 1.00 + 3.00ln(x)");
+        assert_eq!(fit.x_range(), (1.0, 4.0));
+    }
+
+    #[test]
+    fn test_trend_line() {
+        let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 2);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+
+        let trend = TrendLine::from_points(vec![(2.0, 3.0), (4.0, 5.0), (6.0, 7.0)], &RED).unwrap();
+        assert_eq!(trend.slope(), 1.0);
+        assert_eq!(trend.intercept(), 1.0);
+        let points: Vec<_> = (&trend).point_iter().to_vec();
+        assert_eq!(points, vec![(2.0, 3.0), (6.0, 7.0)]);
+
+        chart.draw_series(std::iter::once(trend)).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use super::regression::solve;
+use num_traits::{NumCast, ToPrimitive};
+use std::collections::VecDeque;
+
+/// The weights of the window that evaluate the least squares polynomial at the center point
+fn coefficients(half: usize, order: usize) -> Option<Vec<f64>> {
+    let offsets: Vec<_> = (0..=2 * half).map(|i| i as f64 - half as f64).collect();
+    let n = order + 1;
+    let mut matrix = vec![vec![0.0; n]; n];
+    for z in offsets.iter() {
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell += z.powi((i + j) as i32);
+            }
+        }
+    }
+    let mut center = vec![0.0; n];
+    center[0] = 1.0;
+    let polynomial = solve(matrix, center)?;
+    Some(
+        offsets
+            .iter()
+            .map(|z| polynomial.iter().rev().fold(0.0, |sum, c| sum * z + c))
+            .collect(),
+    )
+}
+
+/// The iterator adapter that smooths a data series with the Savitzky–Golay filter, which fits a
+/// polynomial to the window around each point, thus the peaks are kept better than a moving
+/// average does. It can be used directly as the data of a
+/// [LineSeries](../series/struct.LineSeries.html).
+///
+/// Each output point uses the X value of the center of the window, thus the first and the last
+/// `window / 2` data points don't produce output. The window should be odd and larger than the
+/// order of the polynomial, otherwise the series is empty.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data: Vec<_> = (0..100)
+///     .map(|x| (x, (x as f64 / 10.0).sin() + if x % 2 == 0 { 0.1 } else { -0.1 }))
+///     .collect();
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..100, -1.5..1.5)
+///     .unwrap();
+/// chart
+///     .draw_series(LineSeries::new(SavitzkyGolay::new(data, 7, 2), &RED))
+///     .unwrap();
+/// ```
+pub struct SavitzkyGolay<I, X, Y>
+where
+    I: Iterator<Item = (X, Y)>,
+{
+    iter: I,
+    weights: Vec<f64>,
+    values: VecDeque<f64>,
+    centers: VecDeque<X>,
+    skipped: usize,
+}
+
+impl<I, X, Y> SavitzkyGolay<I, X, Y>
+where
+    I: Iterator<Item = (X, Y)>,
+    Y: ToPrimitive + NumCast,
+{
+    /// Create a Savitzky–Golay filter
+    ///
+    /// - `iter`: The data points, evenly spaced in X
+    /// - `window`: The number of data points in the window, which should be odd
+    /// - `order`: The order of the polynomial fitted to the window
+    /// - **returns** The iterator over the smoothed data points
+    pub fn new<T: IntoIterator<Item = (X, Y), IntoIter = I>>(
+        iter: T,
+        window: usize,
+        order: usize,
+    ) -> Self {
+        let weights = if window % 2 == 1 && window > order {
+            coefficients(window / 2, order).unwrap_or_default()
+        } else {
+            vec![]
+        };
+        Self {
+            iter: iter.into_iter(),
+            values: VecDeque::with_capacity(weights.len() + 1),
+            centers: VecDeque::new(),
+            skipped: 0,
+            weights,
+        }
+    }
+}
+
+impl<I, X, Y> Iterator for SavitzkyGolay<I, X, Y>
+where
+    I: Iterator<Item = (X, Y)>,
+    Y: ToPrimitive + NumCast,
+{
+    type Item = (X, Y);
+    fn next(&mut self) -> Option<(X, Y)> {
+        if self.weights.is_empty() {
+            return None;
+        }
+        loop {
+            let (x, y) = self.iter.next()?;
+            // The X values before the first center never produce output
+            if self.skipped < self.weights.len() / 2 {
+                self.skipped += 1;
+            } else {
+                self.centers.push_back(x);
+            }
+            self.values.push_back(y.to_f64()?);
+            if self.values.len() > self.weights.len() {
+                self.values.pop_front();
+            }
+            if self.values.len() == self.weights.len() {
+                let value: f64 = self
+                    .values
+                    .iter()
+                    .zip(self.weights.iter())
+                    .map(|(y, w)| y * w)
+                    .sum();
+                return Some((self.centers.pop_front()?, NumCast::from(value)?));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_savitzky_golay() {
+        let weights = coefficients(2, 2).unwrap();
+        for (expected, actual) in [-3.0, 12.0, 17.0, 12.0, -3.0].iter().zip(weights) {
+            assert!((expected / 35.0 - actual).abs() < 1e-9);
+        }
+
+        // A quadratic is kept as it is by a quadratic filter
+        let data: Vec<_> = (0..8).map(|x| (x, (x * x) as f64)).collect();
+        let result: Vec<_> = SavitzkyGolay::new(data.clone(), 5, 2).collect();
+        assert_eq!(result.len(), 4);
+        for (x, y) in result {
+            assert!((2..6).contains(&x));
+            assert!((y - (x * x) as f64).abs() < 1e-9);
+        }
+
+        assert_eq!(SavitzkyGolay::new(data.clone(), 4, 2).count(), 0);
+        assert_eq!(SavitzkyGolay::new(data.clone(), 3, 3).count(), 0);
+        assert_eq!(SavitzkyGolay::new(data, 9, 2).count(), 0);
+    }
+}
@@ -1,12 +1,19 @@
+use super::backend_impl::{AntiAliasedBackend, DrawingWarning, ResilientBackend};
+use super::capabilities::{BackendCapabilities, BackendFeatures, FallbackBackend};
+use super::clip::{ClipBackend, ClipRect};
+use super::composite::{BlendMode, CompositeBackend};
+use super::layer::{Layer, LayerRecorder, LayerStack};
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
 use crate::coord::ranged1d::{KeyPointHint, Ranged};
-use crate::coord::{CoordTranslate, Shift};
+use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use crate::element::ImageElement;
 use crate::element::{CoordMapper, Drawable, PointCollection};
 use crate::style::text_anchor::{HPos, Pos, VPos};
-use crate::style::{Color, SizeDesc, TextStyle};
+use crate::style::{Color, SizeDesc, TextStyle, Theme};
 
 /// The abstraction of a drawing area
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
 
 use std::borrow::Borrow;
 use std::cell::RefCell;
@@ -15,6 +22,9 @@ use std::iter::{once, repeat};
 use std::ops::Range;
 use std::rc::Rc;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use image::{DynamicImage, GenericImageView};
+
 /// The representation of the rectangle in backend canvas
 #[derive(Clone, Debug)]
 pub struct Rect {
@@ -22,6 +32,9 @@ pub struct Rect {
     y0: i32,
     x1: i32,
     y1: i32,
+    // Whether the backend clips the drawing, then the points out of the rectangle aren't
+    // squashed onto its border
+    clipped: bool,
 }
 
 impl Rect {
@@ -39,7 +52,13 @@ impl Rect {
             .map(move |&p| {
                 let x1 = if vertical { full_x } else { p };
                 let y1 = if vertical { p } else { full_y };
-                let ret = Rect { x0, y0, x1, y1 };
+                let ret = Rect {
+                    x0,
+                    y0,
+                    x1,
+                    y1,
+                    clipped: false,
+                };
 
                 if vertical {
                     y0 = y1
@@ -65,6 +84,7 @@ impl Rect {
                 y1: compute_evenly_split(self.y0, self.y1, row, ri + 1),
                 x0: compute_evenly_split(self.x0, self.x1, col, ci),
                 x1: compute_evenly_split(self.x0, self.x1, col, ci + 1),
+                clipped: false,
             })
     }
 
@@ -95,10 +115,13 @@ impl Rect {
         ysegs
             .into_iter()
             .map(move |(y0, y1)| {
-                xsegs
-                    .clone()
-                    .into_iter()
-                    .map(move |(x0, x1)| Self { x0, y0, x1, y1 })
+                xsegs.clone().into_iter().map(move |(x0, x1)| Self {
+                    x0,
+                    y0,
+                    x1,
+                    y1,
+                    clipped: false,
+                })
             })
             .flatten()
     }
@@ -107,6 +130,29 @@ impl Rect {
     pub fn truncate(&self, p: (i32, i32)) -> (i32, i32) {
         (p.0.min(self.x1).max(self.x0), p.1.min(self.y1).max(self.y0))
     }
+
+    /// Whether the drawing in the rectangle is clipped to it by the backend
+    pub(crate) fn is_clipped(&self) -> bool {
+        self.clipped
+    }
+}
+
+/// How an image is fitted to a drawing area, see
+/// [DrawingArea::fill_with_image](struct.DrawingArea.html#method.fill_with_image)
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFill {
+    /// Scale the image to the size of the area, which doesn't keep the aspect ratio
+    Stretch,
+    /// Scale the image to the largest size that fits in the area and center it, keeping the
+    /// aspect ratio
+    Fit,
+    /// Scale the image to the smallest size that covers the area and crop it at the center,
+    /// keeping the aspect ratio
+    Cover,
+    /// Repeat the image at its own size from the upper left corner, the copies at the right and
+    /// the bottom are cropped
+    Tile,
 }
 
 /// The abstraction of a drawing area. Plotters uses drawing area as the fundamental abstraction for the
@@ -116,6 +162,8 @@ impl Rect {
 ///     3. Element based drawing - drawing area provides the environment the element can be drawn onto it.
 pub struct DrawingArea<DB: DrawingBackend, CT: CoordTranslate> {
     backend: Rc<RefCell<DB>>,
+    layers: Rc<LayerStack<DB>>,
+    capabilities: BackendCapabilities,
     rect: Rect,
     coord: CT,
 }
@@ -124,6 +172,8 @@ impl<DB: DrawingBackend, CT: CoordTranslate + Clone> Clone for DrawingArea<DB, C
     fn clone(&self) -> Self {
         Self {
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             rect: self.rect.clone(),
             coord: self.coord.clone(),
         }
@@ -201,6 +251,22 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>
         })
     }
 
+    /// Draw the mesh lines at the given key points rather than the key points of the coordinates
+    pub(crate) fn draw_mesh_at<DrawFunc>(
+        &self,
+        mut draw_func: DrawFunc,
+        y_points: Vec<Y::ValueType>,
+        x_points: Vec<X::ValueType>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        self.backend_ops(move |b| {
+            self.coord
+                .draw_mesh_at(x_points, y_points, |line| draw_func(b, line))
+        })
+    }
+
     /// Get the range of X of the guest coordinate for current drawing area
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.coord.get_x_range()
@@ -218,6 +284,14 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>
     pub fn get_y_axis_pixel_range(&self) -> Range<i32> {
         self.coord.get_y_axis_pixel_range()
     }
+
+    pub fn get_x_axis_breaks(&self) -> Vec<i32> {
+        self.coord.get_x_axis_breaks()
+    }
+
+    pub fn get_y_axis_breaks(&self) -> Vec<i32> {
+        self.coord.get_y_axis_breaks()
+    }
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
@@ -231,6 +305,8 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             coord: Shift((self.rect.x0, self.rect.y0)),
         }
     }
@@ -239,10 +315,19 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             coord: Shift((0, 0)),
         }
     }
 
+    /// Get the drawing features that the elements are drawn with, which are the capabilities of
+    /// the backend for the areas returned by [with_fallbacks](#method.with_fallbacks), and all
+    /// the features otherwise
+    pub fn capabilities(&self) -> BackendCapabilities {
+        self.capabilities
+    }
+
     /// Get the area dimension in pixel
     pub fn dim_in_pixel(&self) -> (u32, u32) {
         (
@@ -292,6 +377,154 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         })
     }
 
+    /// Fill the entire drawing area with the background color of the theme
+    pub fn fill_theme(&self, theme: &Theme) -> Result<(), DrawingAreaError<DB>> {
+        self.fill(&theme.background_color())
+    }
+
+    /// Fill the entire drawing area with an image, such as a texture or a picture behind the
+    /// plot
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    ///
+    /// let paper = image::open("paper.png").unwrap();
+    /// let root = BitMapBackend::new("out.png", (640, 480)).into_drawing_area();
+    /// root.fill_with_image(&paper, ImageFill::Tile).unwrap();
+    /// ```
+    ///
+    /// - `image`: The image to fill with
+    /// - `mode`: How the image is fitted to the area
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn fill_with_image(
+        &self,
+        image: &DynamicImage,
+        mode: ImageFill,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        let (w, h) = self.dim_in_pixel();
+        let (iw, ih) = image.dimensions();
+        if w == 0 || h == 0 || iw == 0 || ih == 0 {
+            return Ok(());
+        }
+        let (fw, fh) = (f64::from(w), f64::from(h));
+        let (fiw, fih) = (f64::from(iw), f64::from(ih));
+        let scaled = |v: f64| (v.round() as u32).max(1);
+
+        // The parts of the image to draw, as the crop of the image, the position and the size
+        let mut parts = vec![];
+        match mode {
+            ImageFill::Stretch => parts.push(((0, 0, iw, ih), (0, 0), (w, h))),
+            ImageFill::Fit => {
+                let scale = (fw / fiw).min(fh / fih);
+                let (dw, dh) = (scaled(fiw * scale).min(w), scaled(fih * scale).min(h));
+                let pos = ((w - dw) as i32 / 2, (h - dh) as i32 / 2);
+                parts.push(((0, 0, iw, ih), pos, (dw, dh)));
+            }
+            ImageFill::Cover => {
+                let scale = (fw / fiw).max(fh / fih);
+                let (cw, ch) = (scaled(fw / scale).min(iw), scaled(fh / scale).min(ih));
+                parts.push((((iw - cw) / 2, (ih - ch) / 2, cw, ch), (0, 0), (w, h)));
+            }
+            ImageFill::Tile => {
+                for y in (0..h).step_by(ih as usize) {
+                    for x in (0..w).step_by(iw as usize) {
+                        let (cw, ch) = ((w - x).min(iw), (h - y).min(ih));
+                        parts.push(((0, 0, cw, ch), (x as i32, y as i32), (cw, ch)));
+                    }
+                }
+            }
+        }
+
+        // The uncropped image is decoded only once, which is shared by the whole tiles
+        let whole = ImageElement::new((), image);
+        for ((x, y, cw, ch), (px, py), (dw, dh)) in parts {
+            let cropped;
+            let element = if (x, y, cw, ch) == (0, 0, iw, ih) {
+                &whole
+            } else {
+                cropped = ImageElement::new((), &image.crop_imm(x, y, cw, ch));
+                &cropped
+            };
+            let resized;
+            let element = if element.size() == (dw, dh) {
+                element
+            } else {
+                resized = element.copy_to(()).resize(dw, dh);
+                &resized
+            };
+            let pos = (self.rect.x0 + px, self.rect.y0 + py);
+            self.backend_ops(|b| element.draw(once(pos), b, (w, h)))?;
+        }
+        Ok(())
+    }
+
+    /// Tile a text over the entire drawing area, such as a "DRAFT" stamp or the branding behind
+    /// the plot. The copies of the text are evenly spaced, and only the copies that fit in the
+    /// area are drawn, but a text larger than the area is still drawn once at the center.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// root.fill(&WHITE).unwrap();
+    /// root.watermark("DRAFT", ("sans-serif", 40).into_font().color(&RED), -30.0, 0.2)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `text`: The text of the watermark
+    /// - `style`: The style of the text
+    /// - `angle`: The clockwise angle that the text is rotated by, in degrees
+    /// - `opacity`: The opacity of the text from 0 to 1, which is multiplied with the alpha of
+    ///   the text color
+    pub fn watermark<'b, S: Into<TextStyle<'b>>>(
+        &self,
+        text: &str,
+        style: S,
+        angle: f64,
+        opacity: f64,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        let style = style.into();
+        let opacity = if opacity.is_nan() || opacity <= 0.0 {
+            return Ok(());
+        } else {
+            opacity.min(1.0)
+        };
+        let (text_w, text_h) = self.estimate_text_size(text, &style)?;
+        let (text_w, text_h) = (f64::from(text_w), f64::from(text_h));
+
+        // The copies are apart by twice the text height around the bounding box of the rotated
+        // text
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let (sin, cos) = (sin.abs(), cos.abs());
+        let tile = (text_w * cos + text_h * sin, text_w * sin + text_h * cos);
+        let gap = text_h * 2.0;
+        let centers = |size: u32, tile: f64| {
+            let (size, step) = (f64::from(size), tile + gap);
+            let count = ((size - tile) / step).floor().max(0.0) as usize + 1;
+            let start = (size - step * (count - 1) as f64) / 2.0;
+            (0..count).map(move |idx| (start + step * idx as f64).round() as i32)
+        };
+
+        let style = TextStyle {
+            color: BackendColor {
+                alpha: style.color.alpha * opacity,
+                rgb: style.color.rgb,
+            },
+            ..style
+        }
+        .rotate(angle)
+        .pos(Pos::new(HPos::Center, VPos::Center));
+        let (w, h) = self.dim_in_pixel();
+        for y in centers(h, tile.1) {
+            for x in centers(w, tile.0) {
+                let pos = (self.rect.x0 + x, self.rect.y0 + y);
+                self.backend_ops(|b| style.draw_text(b, text, pos))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Draw a single pixel
     pub fn draw_pixel<ColorType: Color>(
         &self,
@@ -302,9 +535,164 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.draw_pixel(pos, color.to_backend_color()))
     }
 
-    /// Present all the pending changes to the backend
+    /// Present all the pending changes to the backend, the drawing on the layers is drawn
+    /// before the backend presents it
     pub fn present(&self) -> Result<(), DrawingAreaError<DB>> {
-        self.backend_ops(|b| b.present())
+        self.backend_ops(|b| {
+            self.layers.replay(b)?;
+            b.present()
+        })
+    }
+
+    /// Get the drawing area that records the drawing onto a layer, which is drawn on the top of
+    /// the drawing directly onto the drawing area in the order of the layers, see
+    /// [Layer](enum.Layer.html) for details
+    /// - `layer`: The layer to draw on
+    /// - **returns** The drawing area on the layer, which has the same size and coordinate
+    pub fn on_layer(&self, layer: Layer) -> DrawingArea<LayerRecorder<DB>, CT>
+    where
+        CT: Clone,
+    {
+        let recorder = Rc::new(RefCell::new(LayerRecorder::new(self.layers.clone(), layer)));
+        DrawingArea {
+            layers: Rc::new(LayerStack::new(recorder.clone())),
+            capabilities: self.capabilities,
+            backend: recorder,
+            rect: self.rect.clone(),
+            coord: self.coord.clone(),
+        }
+    }
+
+    /// Get the drawing area whose drawing is clipped to the rectangle, which is where the series
+    /// of a chart are drawn, see [ClipBackend](struct.ClipBackend.html)
+    /// - `clip`: The rectangle to clip to, or `None` to draw without clipping
+    /// - `blend_mode`: The blend mode of the series, which is blended as a group until
+    ///   [end_blend](#method.end_blend) is called
+    /// - **returns** The clipped drawing area, which has the same size and coordinate
+    pub(crate) fn clipped(
+        &self,
+        clip: Option<ClipRect>,
+        blend_mode: BlendMode,
+    ) -> DrawingArea<ClipBackend<DB>, &CT> {
+        let mut backend = ClipBackend::new(self.backend.clone(), clip, self.capabilities);
+        backend.begin_blend(blend_mode);
+        let backend = Rc::new(RefCell::new(backend));
+        DrawingArea {
+            layers: Rc::new(LayerStack::new(backend.clone())),
+            capabilities: self.capabilities,
+            backend,
+            rect: Rect {
+                clipped: clip.is_some(),
+                ..self.rect.clone()
+            },
+            coord: &self.coord,
+        }
+    }
+
+    /// Get the drawing area that draws with anti-aliasing, see
+    /// [AntiAliasedBackend](struct.AntiAliasedBackend.html). Only the drawing on the returned
+    /// area and the areas derived from it, such as the split areas and the chart areas, is
+    /// anti-aliased, the other areas of the backend draw as they are.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 640 * 240 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (640, 240)).into_drawing_area();
+    /// root.fill(&WHITE).unwrap();
+    /// let (left, right) = root.split_horizontally(320);
+    /// // Only the circle on the right is anti-aliased
+    /// let right = right.with_anti_aliasing();
+    /// left.draw(&Circle::new((160, 120), 100, BLUE.filled()))
+    ///     .unwrap();
+    /// right
+    ///     .draw(&Circle::new((160, 120), 100, BLUE.filled()))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - **returns** The drawing area, which has the same size and coordinate
+    pub fn with_anti_aliasing(self) -> DrawingArea<AntiAliasedBackend<ClipBackend<DB>>, CT> {
+        let shared = ClipBackend::new(self.backend.clone(), None, self.capabilities);
+        let backend = Rc::new(RefCell::new(AntiAliasedBackend::new(shared)));
+        DrawingArea {
+            layers: Rc::new(LayerStack::new(backend.clone())),
+            capabilities: self.capabilities,
+            backend,
+            rect: self.rect,
+            coord: self.coord,
+        }
+    }
+
+    /// Draw a group of drawing that is composited as a whole: the group is rasterized into its
+    /// own pixels, where its drawing is composited with the blend mode, and then the pixels are
+    /// drawn over the drawing area with the opacity. Thus the overlapping parts of the opaque
+    /// elements of a group with the opacity don't double-darken, and the faint elements drawn
+    /// with the additive blend mode accumulate the density. The drawing outside of the drawing
+    /// area is dropped.
+    ///
+    /// Only the backends that have the `raster` capability, such as the `BitMapBackend`, are
+    /// drawn on this way, see [BackendCapabilities](struct.BackendCapabilities.html). On the
+    /// vector backends, such as the `SVGBackend`, the group is drawn directly with the alpha
+    /// channel of its colors scaled by the opacity, and the blend mode is ignored, since each
+    /// pixel of the group would be a separate shape of the document.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 320 * 240 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (320, 240)).into_drawing_area();
+    /// root.fill(&WHITE).unwrap();
+    /// // The overlapping circles are as translucent as a single one
+    /// root.draw_composited(0.5, BlendMode::Normal, |group| {
+    ///     group.draw(&Circle::new((120, 120), 60, BLUE.filled()))?;
+    ///     group.draw(&Circle::new((200, 120), 60, BLUE.filled()))
+    /// })
+    /// .unwrap();
+    /// ```
+    ///
+    /// - `opacity`: The opacity of the group
+    /// - `blend_mode`: The way the drawing in the group is composited with each other
+    /// - `draw`: The function that draws the group onto the given drawing area
+    pub fn draw_composited<F>(
+        &self,
+        opacity: f64,
+        blend_mode: BlendMode,
+        draw: F,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        DB: BackendFeatures,
+        CT: Clone,
+        F: FnOnce(&DrawingArea<CompositeBackend<DB>, CT>) -> Result<(), DrawingAreaError<DB>>,
+    {
+        let (size, raster) = {
+            let backend = RefCell::borrow(self.backend.borrow());
+            (backend.get_size(), backend.capabilities().raster)
+        };
+        let (group, capabilities) = if raster {
+            let group =
+                CompositeBackend::new(size, self.get_base_pixel(), self.dim_in_pixel(), blend_mode);
+            // The group composites the colors on its own
+            (group, BackendCapabilities::all())
+        } else {
+            let (x, y) = self.get_pixel_range();
+            let clip = ClipRect::new((x.start, y.start), (x.end - 1, y.end - 1));
+            let backend = ClipBackend::new(self.backend.clone(), Some(clip), self.capabilities);
+            let group = CompositeBackend::direct(size, backend, blend_mode, opacity);
+            (group, self.capabilities)
+        };
+        let group = Rc::new(RefCell::new(group));
+        let area = DrawingArea {
+            layers: Rc::new(LayerStack::new(group.clone())),
+            capabilities,
+            backend: group.clone(),
+            rect: self.rect.clone(),
+            coord: self.coord.clone(),
+        };
+        draw(&area)?;
+        // The layers of the group are drawn into the group when its drawing area is dropped
+        drop(area);
+        let group = RefCell::borrow(group.borrow());
+        self.backend_ops(|b| group.composite(b, opacity))
     }
 
     /// Draw an high-level element
@@ -318,7 +706,14 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
             let b = p.borrow();
             B::map(&self.coord, b, &self.rect)
         });
-        self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
+        self.backend_ops(move |b| {
+            element.draw_with_capabilities(
+                backend_coords,
+                b,
+                self.dim_in_pixel(),
+                &self.capabilities,
+            )
+        })
     }
 
     /// Map coordinate to the backend coordinate
@@ -343,6 +738,118 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     }
 }
 
+/// The backend that can describe the drawing and the roles of its elements for the assistive
+/// technologies, such as the [AccessibleSVGBackend](struct.AccessibleSVGBackend.html).
+///
+/// The methods do nothing by default, thus the backends that can't describe the drawing, such
+/// as the `SVGBackend` and the `BitMapBackend`, implement the trait with the defaults and
+/// ignore the description and the roles.
+pub trait AccessibleBackend: DrawingBackend {
+    /// Set the title and the description of the whole drawing, an empty string leaves it out
+    fn set_description(&mut self, _title: &str, _desc: &str) {}
+    /// Start a group of the elements that has the role and the label
+    fn begin_group(&mut self, _role: &str, _label: &str) {}
+    /// End the last group that is started
+    fn end_group(&mut self) {}
+}
+
+// The plain SVG output has no place for the description and the roles, use the
+// AccessibleSVGBackend to write them
+#[cfg(feature = "svg_backend")]
+impl<'a> AccessibleBackend for plotters_svg::SVGBackend<'a> {}
+
+#[cfg(feature = "bitmap_backend")]
+impl<'a, P: plotters_bitmap::bitmap_pixel::PixelFormat> AccessibleBackend
+    for plotters_bitmap::BitMapBackend<'a, P>
+{
+}
+
+impl<DB: AccessibleBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
+    /// Set the title and the description of the drawing, which are read by the screen readers
+    /// - `title`: The short title, such as the caption of the chart
+    /// - `desc`: The longer description, such as the summary of the data
+    pub fn set_description(&self, title: &str, desc: &str) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| {
+            b.set_description(title, desc);
+            Ok(())
+        })
+    }
+
+    /// Draw the elements with a role annotation, the elements that are drawn by the closure are
+    /// grouped with the ARIA role and label, for example, `("listitem", "Sales: 30")` for a bar
+    /// - `role`: The ARIA role of the group
+    /// - `label`: The label that is read by the screen readers
+    /// - `draw`: The closure that draws the elements
+    pub fn draw_with_role<R, F: FnOnce() -> Result<R, DrawingAreaError<DB>>>(
+        &self,
+        role: &str,
+        label: &str,
+        draw: F,
+    ) -> Result<R, DrawingAreaError<DB>> {
+        self.backend_ops(|b| {
+            b.begin_group(role, label);
+            Ok(())
+        })?;
+        let ret = draw();
+        self.backend_ops(|b| {
+            b.end_group();
+            Ok(())
+        })?;
+        ret
+    }
+}
+
+impl<DB: BackendFeatures, CT: CoordTranslate> DrawingArea<DB, CT> {
+    /// Get the drawing area that degrades the drawing features the backend lacks, which the
+    /// backend describes with [BackendFeatures](trait.BackendFeatures.html), see
+    /// [BackendCapabilities](struct.BackendCapabilities.html). The colors and the paths are
+    /// degraded by the [FallbackBackend](struct.FallbackBackend.html) of the area, and the
+    /// gradient fills by the elements, which are given the capabilities of the area. The areas
+    /// derived from it, such as the split areas and the chart areas, keep the capabilities.
+    /// - **returns** The drawing area, which has the same size and coordinate
+    pub fn with_fallbacks(self) -> DrawingArea<FallbackBackend<DB>, CT> {
+        let capabilities = RefCell::borrow(self.backend.borrow()).capabilities();
+        let backend = Rc::new(RefCell::new(FallbackBackend::new(
+            self.backend.clone(),
+            capabilities,
+        )));
+        DrawingArea {
+            layers: Rc::new(LayerStack::new(backend.clone())),
+            capabilities,
+            backend,
+            rect: self.rect,
+            coord: self.coord,
+        }
+    }
+}
+
+impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<ClipBackend<DB>, CT> {
+    /// End the blend group of the series drawn on the clipped area, see
+    /// [ClipBackend](struct.ClipBackend.html)
+    pub(crate) fn end_blend(&self) -> Result<(), DrawingAreaError<ClipBackend<DB>>> {
+        self.backend_ops(|b| b.end_blend())
+    }
+}
+
+impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<ResilientBackend<DB>, CT> {
+    /// Get the warnings that the backend collected so far instead of failing, see
+    /// [ResilientBackend](struct.ResilientBackend.html)
+    pub fn warnings(&self) -> Vec<DrawingWarning> {
+        RefCell::borrow(self.backend.borrow()).warnings()
+    }
+}
+
+impl<DB: DrawingBackend, CT: ReverseCoordTranslate> DrawingArea<DB, CT> {
+    /// Map the backend coordinate back to the guest coordinate, which is the reverse of
+    /// [DrawingArea::map_coordinate](#method.map_coordinate). The pixel isn't required to be
+    /// in the drawing area.
+    /// - `pixel`: The backend coordinate
+    /// - **returns** The guest coordinate, or `None` if the coordinate can't be reversed
+    pub fn reverse_map_coordinate(&self, pixel: BackendCoord) -> Option<CT::From> {
+        self.coord.reverse_translate(pixel)
+    }
+}
+
 impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
     fn with_rc_cell(backend: Rc<RefCell<DB>>) -> Self {
         let (x1, y1) = RefCell::borrow(backend.borrow()).get_size();
@@ -352,7 +859,10 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y0: 0,
                 x1: x1 as i32,
                 y1: y1 as i32,
+                clipped: false,
             },
+            layers: Rc::new(LayerStack::new(backend.clone())),
+            capabilities: BackendCapabilities::all(),
             backend,
             coord: Shift((0, 0)),
         }
@@ -382,6 +892,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             coord: coord_spec,
         }
     }
@@ -404,8 +916,11 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y0: self.rect.y0 + top,
                 x1: self.rect.x1 - right,
                 y1: self.rect.y1 - bottom,
+                clipped: false,
             },
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             coord: Shift((self.rect.x0 + left, self.rect.y0 + top)),
         }
     }
@@ -417,6 +932,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         let mut ret = self.rect.split(split_point.iter(), true).map(|rect| Self {
             rect: rect.clone(),
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             coord: Shift((rect.x0, rect.y0)),
         });
 
@@ -430,6 +947,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         let mut ret = self.rect.split(split_point.iter(), false).map(|rect| Self {
             rect: rect.clone(),
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             coord: Shift((rect.x0, rect.y0)),
         });
 
@@ -443,6 +962,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .map(|rect| Self {
                 rect: rect.clone(),
                 backend: self.backend.clone(),
+                layers: self.layers.clone(),
+                capabilities: self.capabilities,
                 coord: Shift((rect.x0, rect.y0)),
             })
             .collect()
@@ -467,6 +988,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .map(|rect| Self {
                 rect: rect.clone(),
                 backend: self.backend.clone(),
+                layers: self.layers.clone(),
+                capabilities: self.capabilities,
                 coord: Shift((rect.x0, rect.y0)),
             })
             .collect()
@@ -501,8 +1024,11 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y0: self.rect.y0 + y_padding * 2 + text_h as i32,
                 x1: self.rect.x1,
                 y1: self.rect.y1,
+                clipped: false,
             },
             backend: self.backend.clone(),
+            layers: self.layers.clone(),
+            capabilities: self.capabilities,
             coord: Shift((self.rect.x0, self.rect.y0 + y_padding * 2 + text_h as i32)),
         })
     }
@@ -514,7 +1040,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingAreaError<DB>> {
-        self.backend_ops(|b| b.draw_text(text, style, (pos.0 + self.rect.x0, pos.1 + self.rect.y0)))
+        self.backend_ops(|b| style.draw_text(b, text, (pos.0 + self.rect.x0, pos.1 + self.rect.y0)))
     }
 }
 
@@ -554,6 +1080,118 @@ mod drawing_area_tests {
         drawing_area.fill(&WHITE).expect("Drawing Failure");
     }
 
+    #[test]
+    fn test_draw_composited() {
+        let drawing_area = create_mocked_drawing_area(10, 10, |m| {
+            m.check_draw_pixel(|c, _| assert_eq!(c, BLUE.mix(0.5)));
+            m.drop_check(|b| {
+                // The overlapping pixels of the opaque rectangles are drawn once
+                assert_eq!(b.num_draw_pixel_call, 36 + 36 - 9);
+            });
+        });
+        drawing_area
+            .draw_composited(0.5, BlendMode::Normal, |group| {
+                group.draw(&Rectangle::new([(0, 0), (5, 5)], BLUE.filled()))?;
+                group.draw(&Rectangle::new([(3, 3), (8, 8)], BLUE.filled()))
+            })
+            .unwrap();
+
+        // The faint points add up
+        let drawing_area = create_mocked_drawing_area(10, 10, |m| {
+            m.check_draw_pixel(|c, pos| {
+                assert_eq!(pos, (2, 2));
+                assert_eq!(c, RED.mix(0.75));
+            });
+        });
+        drawing_area
+            .draw_composited(1.0, BlendMode::Additive, |group| {
+                for _ in 0..3 {
+                    group.draw_pixel((2, 2), &RED.mix(0.25))?;
+                }
+                Ok(())
+            })
+            .unwrap();
+
+        // The group is drawn directly onto a vector backend, with the faded colors
+        let drawing_area = create_mocked_drawing_area(10, 10, |m| {
+            m.set_capabilities(crate::drawing::BackendCapabilities::all().raster(false));
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, BLUE.mix(0.5).to_rgba());
+                assert_eq!((ul, br), ((0, 0), (5, 5)));
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, BLUE.mix(0.5).to_rgba());
+                assert_eq!((ul, br), ((3, 3), (8, 8)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_pixel_call, 0);
+            });
+        });
+        drawing_area
+            .draw_composited(0.5, BlendMode::Multiply, |group| {
+                group.draw(&Rectangle::new([(0, 0), (5, 5)], BLUE.filled()))?;
+                group.draw(&Rectangle::new([(3, 3), (8, 8)], BLUE.filled()))
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_anti_aliasing_area() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_pixel(|c, _| assert!(c.3 > 0.0 && c.3 <= 1.0));
+            m.check_draw_path(|_, _, path| assert_eq!(path, vec![(0, 0), (30, 10)]));
+            m.drop_check(|b| {
+                // Only the line on the anti-aliased area is drawn as the blended pixels
+                assert_eq!(b.num_draw_path_call, 1);
+                assert!(b.num_draw_pixel_call > 31 && b.num_draw_pixel_call <= 31 * 2);
+            });
+        });
+        let line = PathElement::new(vec![(0, 0), (30, 10)], &BLACK);
+        drawing_area.draw(&line).unwrap();
+        drawing_area.with_anti_aliasing().draw(&line).unwrap();
+    }
+
+    #[test]
+    fn test_layers() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, _, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.check_draw_rect(|c, _, _, _, _| assert_eq!(c, GREEN.to_rgba()));
+            m.check_draw_rect(|c, _, _, _, _| assert_eq!(c, RED.to_rgba()));
+            m.check_draw_text(|c, font, size, pos, text| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(font, "serif");
+                assert_eq!(size, 20.0);
+                assert_eq!(pos, (10, 10));
+                assert_eq!(text, "top");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+
+        // The layers are drawn above the direct drawing from the bottom layer
+        drawing_area
+            .on_layer(Layer::Overlay)
+            .draw_text(
+                "top",
+                &("serif", 20).into_text_style(&drawing_area),
+                (10, 10),
+            )
+            .unwrap();
+        drawing_area.on_layer(Layer::Annotation).fill(&RED).unwrap();
+        drawing_area.fill(&BLUE).unwrap();
+        drawing_area
+            .on_layer(Layer::Background)
+            .fill(&GREEN)
+            .unwrap();
+        drawing_area.present().unwrap();
+
+        // The drawing after presenting is drawn when the drawing area is dropped
+        drawing_area.on_layer(Layer::Series).fill(&RED).unwrap();
+    }
+
     #[test]
     fn test_split_evenly() {
         let colors = vec![
@@ -733,6 +1371,59 @@ mod drawing_area_tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_watermark() {
+        let count = |text: &str, size: f64| {
+            let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+            let counted = calls.clone();
+            let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+                m.check_draw_text(move |c, _, _, pos, _| {
+                    assert_eq!(c, RED.mix(0.25));
+                    assert!(pos.0 > 0 && pos.0 < 400 && pos.1 > 0 && pos.1 < 300);
+                    counted.set(counted.get() + 1);
+                });
+            });
+            drawing_area
+                .watermark(
+                    text,
+                    ("sans-serif", size).into_font().color(&RED.mix(0.5)),
+                    0.0,
+                    0.5,
+                )
+                .unwrap();
+            drop(drawing_area);
+            calls.get()
+        };
+        assert!(count("DRAFT", 20.0) > 4);
+        // The text that doesn't fit is drawn once at the center
+        assert_eq!(count("CONFIDENTIAL", 200.0), 1);
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    #[test]
+    fn test_fill_with_image() {
+        let count = |size: (u32, u32), mode: ImageFill| {
+            let image = image::DynamicImage::new_rgb8(size.0, size.1);
+            let drawing_area = create_mocked_drawing_area(5, 3, move |m| {
+                m.check_draw_pixel(move |_, pos| {
+                    assert!(pos.0 >= 0 && pos.0 < 5 && pos.1 >= 0 && pos.1 < 3);
+                    if mode == ImageFill::Fit {
+                        assert!(pos.0 >= 1 && pos.0 < 4);
+                    }
+                });
+                m.drop_check(move |b| match mode {
+                    ImageFill::Fit => assert_eq!(b.num_draw_pixel_call, 9),
+                    _ => assert_eq!(b.num_draw_pixel_call, 15),
+                });
+            });
+            drawing_area.fill_with_image(&image, mode).unwrap();
+        };
+        count((1, 1), ImageFill::Fit);
+        count((1, 1), ImageFill::Stretch);
+        count((2, 2), ImageFill::Tile);
+        count((10, 2), ImageFill::Cover);
+    }
+
     #[test]
     fn test_margin() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -0,0 +1,534 @@
+use std::cell::{Cell, RefCell};
+use std::fs::File;
+use std::io::{BufWriter, Error, Write};
+use std::path::Path;
+
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+use plotters_svg::SVGBackend;
+
+use crate::drawing::composite::BlendMarker;
+use crate::drawing::{AccessibleBackend, BackendCapabilities, BackendFeatures, BlendMode};
+use crate::element::{ElementMetadata, MetadataMarker};
+
+enum Target<'a> {
+    File(&'a Path),
+    Buffer(&'a mut String),
+}
+
+fn escape(value: &str) -> String {
+    let mut ret = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '<' => ret.push_str("&lt;"),
+            '>' => ret.push_str("&gt;"),
+            '&' => ret.push_str("&amp;"),
+            '"' => ret.push_str("&quot;"),
+            '\'' => ret.push_str("&apos;"),
+            other => ret.push(other),
+        }
+    }
+    ret
+}
+
+/// Split the `opacity` attributes of the SVG fragment into the `fill-opacity` of the filled
+/// shapes and the texts and the `stroke-opacity` of the lines and the outlines, thus the alpha
+/// channel of the colors survives the editors that only read the paint opacities
+fn split_opacity(fragment: &str) -> String {
+    let mut ret = String::with_capacity(fragment.len() + 32);
+    for line in fragment.lines() {
+        let filled = line.contains(" fill=\"") && !line.contains(" fill=\"none\"");
+        let attr = if filled {
+            " fill-opacity=\""
+        } else {
+            " stroke-opacity=\""
+        };
+        ret.push_str(&line.replacen(" opacity=\"", attr, 1));
+        ret.push('\n');
+    }
+    ret
+}
+
+/// The SVG backend that makes the charts screen reader friendly. The document has the
+/// `role="img"` and the `<title>` and `<desc>` set with
+/// [DrawingArea::set_description](struct.DrawingArea.html#method.set_description), and the
+/// elements drawn in [DrawingArea::draw_with_role](struct.DrawingArea.html#method.draw_with_role)
+/// are grouped in a `<g>` with the ARIA role and label. The elements that have the
+/// [metadata](../element/trait.ElementExt.html#method.with_metadata) are grouped in a `<g>` with
+/// the id and the class, and the tooltip is its `<title>`, both when they're drawn on the area
+/// and with `ChartContext::draw_series`.
+///
+/// The alpha channel of the colors is written as the `fill-opacity` of the filled shapes and
+/// the texts and as the `stroke-opacity` of the lines and the outlines. The series drawn with a
+/// [blend mode](../style/struct.ShapeStyle.html#method.blend_mode) is grouped in an isolated
+/// `<g>`, whose elements have the `mix-blend-mode` of the blend mode, `plus-lighter` for the
+/// additive blending and `multiply` for the multiplication.
+///
+/// The backend is separate from the `SVGBackend`, since the `SVGBackend` comes from the
+/// `plotters-svg` crate, whose output has no place for the groups, the roles and the
+/// attributes of the elements. The metadata of the elements drawn on a
+/// [layer](enum.Layer.html) is lost, since the layers are drawn later than the elements.
+///
+/// The shapes are drawn by the [SVGBackend](../prelude/struct.SVGBackend.html), thus they look
+/// the same as the plain SVG output.
+///
+/// ```rust
+/// use plotters::drawing::AccessibleSVGBackend;
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// {
+///     let root = AccessibleSVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+///     root.set_description("Temperature", "The temperature of the day, by hour")
+///         .unwrap();
+///     let mut chart = ChartBuilder::on(&root)
+///         .build_cartesian_2d(0..24, 0..30)
+///         .unwrap();
+///     root.draw_with_role("list", "The temperature line", || {
+///         chart
+///             .draw_series(LineSeries::new((0..24).map(|h| (h, 10 + h / 2)), &RED))
+///             .map(|_| ())
+///     })
+///     .unwrap();
+///     root.present().unwrap();
+/// }
+/// assert!(content.contains("<title id=\"plotters-title\">Temperature</title>"));
+/// assert!(content.contains("<g role=\"list\" aria-label=\"The temperature line\">"));
+/// ```
+pub struct AccessibleSVGBackend<'a> {
+    target: Target<'a>,
+    size: (u32, u32),
+    id_prefix: String,
+    title: Option<String>,
+    desc: Option<String>,
+    body: String,
+    open_groups: usize,
+    // The groups of the element metadata, which are started and ended while the backend is
+    // only borrowed, and are moved to the body before the next drawing
+    pending: RefCell<String>,
+    open_elements: Cell<usize>,
+    // The `mix-blend-mode` values of the blend groups, which are written to the stylesheet
+    blend_modes: RefCell<Vec<&'static str>>,
+    saved: bool,
+}
+
+impl<'a> AccessibleSVGBackend<'a> {
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
+        Self {
+            target,
+            size,
+            id_prefix: "plotters".to_string(),
+            title: None,
+            desc: None,
+            body: String::new(),
+            open_groups: 0,
+            pending: RefCell::new(String::new()),
+            open_elements: Cell::new(0),
+            blend_modes: RefCell::new(vec![]),
+            saved: false,
+        }
+    }
+
+    /// Create a new backend that writes the SVG file when it's presented
+    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        Self::with_target(Target::File(path.as_ref()), size)
+    }
+
+    /// Create a new backend that appends the SVG document to the string when it's presented
+    pub fn with_string(buf: &'a mut String, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(buf), size)
+    }
+
+    /// Set the prefix of the IDs of the title and the description, which should be unique when
+    /// multiple charts are in the same HTML page. By default, the prefix is `plotters`.
+    pub fn id_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.id_prefix = prefix.into();
+        self
+    }
+
+    /// Start or end the group of the element metadata, see
+    /// [ElementExt::with_metadata](../element/trait.ElementExt.html#method.with_metadata)
+    fn write_metadata(&self, marker: &str) {
+        let mut pending = self.pending.borrow_mut();
+        match ElementMetadata::from_marker(marker) {
+            Some(metadata) => {
+                pending.push_str("<g");
+                if let Some(ref id) = metadata.id {
+                    pending.push_str(&format!(" id=\"{}\"", escape(id)));
+                }
+                if let Some(ref class) = metadata.class {
+                    pending.push_str(&format!(" class=\"{}\"", escape(class)));
+                }
+                pending.push_str(">\n");
+                if let Some(ref tooltip) = metadata.tooltip {
+                    pending.push_str(&format!("<title>{}</title>\n", escape(tooltip)));
+                }
+                self.open_elements.set(self.open_elements.get() + 1);
+            }
+            None if self.open_elements.get() > 0 => {
+                pending.push_str("</g>\n");
+                self.open_elements.set(self.open_elements.get() - 1);
+            }
+            None => {}
+        }
+    }
+
+    /// Start or end the group of the series that is drawn with a blend mode, see
+    /// [ShapeStyle::blend_mode](../style/struct.ShapeStyle.html#method.blend_mode)
+    /// - **returns** If the group is started
+    fn write_blend(&self, marker: &str) -> bool {
+        let mode = match BlendMarker::from_marker(marker) {
+            Some(BlendMode::Normal) => return false,
+            Some(BlendMode::Additive) => "plus-lighter",
+            Some(BlendMode::Multiply) => "multiply",
+            // The group ends like the group of the element metadata
+            None => {
+                self.write_metadata(marker);
+                return false;
+            }
+        };
+        let mut blend_modes = self.blend_modes.borrow_mut();
+        if !blend_modes.contains(&mode) {
+            blend_modes.push(mode);
+        }
+        self.pending.borrow_mut().push_str(&format!(
+            "<g class=\"plotters-blend-{}\" style=\"isolation: isolate\">\n",
+            mode
+        ));
+        self.open_elements.set(self.open_elements.get() + 1);
+        true
+    }
+
+    /// Move the pending groups of the element metadata to the body
+    fn flush(&mut self) {
+        let pending = std::mem::take(self.pending.get_mut());
+        self.body.push_str(&pending);
+    }
+
+    /// Draw with a temporary SVG backend, and keep the content of the SVG fragment
+    fn forward<F>(&mut self, draw: F) -> Result<(), DrawingErrorKind<Error>>
+    where
+        F: FnOnce(&mut SVGBackend) -> Result<(), DrawingErrorKind<Error>>,
+    {
+        self.flush();
+        let mut fragment = String::new();
+        {
+            let mut backend = SVGBackend::with_string(&mut fragment, self.size);
+            draw(&mut backend)?;
+            backend.present()?;
+        }
+        let start = fragment.find('\n').map(|idx| idx + 1).unwrap_or(0);
+        let end = fragment.rfind("</svg>").unwrap_or(fragment.len());
+        if start < end {
+            self.body.push_str(&split_opacity(&fragment[start..end]));
+        }
+        Ok(())
+    }
+
+    fn document(&self) -> String {
+        let mut doc = format!(
+            "<svg width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" xmlns=\"http://www.w3.org/2000/svg\" role=\"img\"",
+            w = self.size.0,
+            h = self.size.1
+        );
+        let mut labelled_by = vec![];
+        if self.title.is_some() {
+            labelled_by.push(format!("{}-title", self.id_prefix));
+        }
+        if self.desc.is_some() {
+            labelled_by.push(format!("{}-desc", self.id_prefix));
+        }
+        if !labelled_by.is_empty() {
+            doc.push_str(&format!(
+                " aria-labelledby=\"{}\"",
+                escape(&labelled_by.join(" "))
+            ));
+        }
+        doc.push_str(">\n");
+        if let Some(ref title) = self.title {
+            doc.push_str(&format!(
+                "<title id=\"{}-title\">{}</title>\n",
+                escape(&self.id_prefix),
+                escape(title)
+            ));
+        }
+        if let Some(ref desc) = self.desc {
+            doc.push_str(&format!(
+                "<desc id=\"{}-desc\">{}</desc>\n",
+                escape(&self.id_prefix),
+                escape(desc)
+            ));
+        }
+        let blend_modes = self.blend_modes.borrow();
+        if !blend_modes.is_empty() {
+            doc.push_str("<style>\n");
+            for mode in blend_modes.iter() {
+                doc.push_str(&format!(
+                    ".plotters-blend-{m} > * {{ mix-blend-mode: {m}; }}\n",
+                    m = mode
+                ));
+            }
+            doc.push_str("</style>\n");
+        }
+        doc.push_str(&self.body);
+        doc.push_str(&self.pending.borrow());
+        for _ in 0..self.open_groups + self.open_elements.get() {
+            doc.push_str("</g>\n");
+        }
+        doc.push_str("</svg>\n");
+        doc
+    }
+}
+
+impl<'a> AccessibleBackend for AccessibleSVGBackend<'a> {
+    fn set_description(&mut self, title: &str, desc: &str) {
+        self.title = Some(title.to_string()).filter(|s| !s.is_empty());
+        self.desc = Some(desc.to_string()).filter(|s| !s.is_empty());
+    }
+
+    fn begin_group(&mut self, role: &str, label: &str) {
+        self.flush();
+        self.body.push_str(&format!(
+            "<g role=\"{}\" aria-label=\"{}\">\n",
+            escape(role),
+            escape(label)
+        ));
+        self.open_groups += 1;
+    }
+
+    fn end_group(&mut self) {
+        self.flush();
+        if self.open_groups > 0 {
+            self.body.push_str("</g>\n");
+            self.open_groups -= 1;
+        }
+    }
+}
+
+impl<'a> BackendFeatures for AccessibleSVGBackend<'a> {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::all().raster(false)
+    }
+}
+
+impl<'a> DrawingBackend for AccessibleSVGBackend<'a> {
+    type ErrorType = Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if self.saved {
+            return Ok(());
+        }
+        let doc = self.document();
+        match self.target {
+            Target::File(path) => {
+                let file = File::create(path).map_err(DrawingErrorKind::DrawingError)?;
+                BufWriter::new(file)
+                    .write_all(doc.as_bytes())
+                    .map_err(DrawingErrorKind::DrawingError)?;
+            }
+            Target::Buffer(ref mut buf) => buf.push_str(&doc),
+        }
+        self.saved = true;
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.forward(|b| b.draw_pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.forward(|b| b.draw_line(from, to, style))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.forward(|b| b.draw_rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.forward(|b| b.draw_path(path, style))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.forward(|b| b.fill_polygon(path, style))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.forward(|b| b.draw_circle(center, radius, style, fill))
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.forward(|b| b.draw_text(text, style, pos))
+    }
+
+    fn estimate_text_size<S: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &S,
+    ) -> Result<(u32, u32), DrawingErrorKind<Error>> {
+        if MetadataMarker::is_marker(style) {
+            self.write_metadata(text);
+            return Ok((0, 0));
+        }
+        if BlendMarker::is_marker(style) {
+            // The size acknowledges the group, thus the series isn't rasterized
+            return Ok(if self.write_blend(text) {
+                (1, 1)
+            } else {
+                (0, 0)
+            });
+        }
+        let ((x0, y0), (x1, y1)) = style
+            .layout_box(text)
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        Ok(((x1 - x0) as u32, (y1 - y0) as u32))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.forward(|b| b.blit_bitmap(pos, size, src))
+    }
+}
+
+impl Drop for AccessibleSVGBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            // drop should not panic, so we ignore a failed present
+            let _ = self.present();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_accessible_svg() {
+        let mut content = String::new();
+        {
+            let root = AccessibleSVGBackend::with_string(&mut content, (100, 100))
+                .id_prefix("chart-1")
+                .into_drawing_area();
+            root.set_description("Sales & costs", "Two \"bars\"")
+                .unwrap();
+            root.fill(&WHITE).unwrap();
+            root.draw_with_role("listitem", "Sales: 30", || {
+                root.draw(&Rectangle::new([(10, 10), (20, 40)], RED.filled()))
+            })
+            .unwrap();
+            root.draw_with_role("group", "Unclosed", || {
+                root.draw_with_role("listitem", "Costs: 20", || {
+                    root.draw(&Circle::new((50, 50), 5, BLUE.filled()))
+                })
+            })
+            .unwrap();
+            root.present().unwrap();
+        }
+
+        assert!(content.starts_with("<svg"));
+        assert!(content.contains("role=\"img\" aria-labelledby=\"chart-1-title chart-1-desc\">"));
+        assert!(content.contains("<title id=\"chart-1-title\">Sales &amp; costs</title>"));
+        assert!(content.contains("<desc id=\"chart-1-desc\">Two &quot;bars&quot;</desc>"));
+        assert!(content.contains("<g role=\"listitem\" aria-label=\"Sales: 30\">\n<rect"));
+        assert_eq!(
+            content.matches("<g ").count(),
+            content.matches("</g>").count()
+        );
+        assert_eq!(content.matches("<svg").count(), 1);
+        assert_eq!(content.matches("</svg>").count(), 1);
+        assert!(content.contains("<circle"));
+    }
+
+    #[test]
+    fn test_blend_group_and_paint_opacity() {
+        let mut content = String::new();
+        {
+            let root =
+                AccessibleSVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            let style = BLUE.mix(0.5).filled().blend_mode(BlendMode::Additive);
+            chart
+                .draw_series((4..6).map(|x| Circle::new((x, 5), 10, style.clone())))
+                .unwrap();
+            chart
+                .draw_series(std::iter::once(PathElement::new(
+                    vec![(0, 0), (10, 10)],
+                    RED.mix(0.25),
+                )))
+                .unwrap();
+            root.present().unwrap();
+        }
+
+        assert!(
+            content.contains(".plotters-blend-plus-lighter > * { mix-blend-mode: plus-lighter; }")
+        );
+        // Both circles are in the group, which is closed before the line
+        let group = content
+            .find("<g class=\"plotters-blend-plus-lighter\" style=\"isolation: isolate\">")
+            .unwrap();
+        let end = group + content[group..].find("</g>").unwrap();
+        assert_eq!(content[group..end].matches("<circle").count(), 2);
+        assert!(content[end..].contains("<polyline"));
+        assert_eq!(
+            content.matches("<g ").count(),
+            content.matches("</g>").count()
+        );
+        // The shapes are kept rather than rasterized into the pixels
+        assert!(!content.contains("<rect"));
+        assert!(content.contains("fill-opacity=\"0.5\""));
+        assert!(content.contains("stroke-opacity=\"0.25\""));
+        assert!(!content.contains(" opacity="));
+    }
+}
@@ -0,0 +1,390 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+use plotters_bitmap::{BitMapBackend, BitMapBackendError};
+
+use crate::drawing::BackendFeatures;
+
+/// The file format of an [AnimatedBackend](struct.AnimatedBackend.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnimationFormat {
+    /// The animated GIF, whose frames are written as they are presented
+    Gif,
+    /// The animated PNG, whose frames are kept until the backend is finished or dropped, since
+    /// the number of the frames is written before the first frame
+    Apng,
+}
+
+/// The error of an [AnimatedBackend](struct.AnimatedBackend.html)
+#[derive(Debug)]
+pub enum AnimatedBackendError {
+    /// The error of drawing a frame
+    BitMapError(BitMapBackendError),
+    /// The error of writing the file
+    IOError(std::io::Error),
+    /// The error of encoding the GIF
+    GifEncodingError(gif::EncodingError),
+    /// The error of encoding the PNG
+    PngEncodingError(png::EncodingError),
+    /// The size of the frames is too large for the format, the GIF frames are at most 65535
+    /// pixels wide and high
+    InvalidSize((u32, u32)),
+}
+
+impl std::fmt::Display for AnimatedBackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for AnimatedBackendError {}
+
+fn map_error(
+    error: DrawingErrorKind<BitMapBackendError>,
+) -> DrawingErrorKind<AnimatedBackendError> {
+    match error {
+        DrawingErrorKind::DrawingError(e) => {
+            DrawingErrorKind::DrawingError(AnimatedBackendError::BitMapError(e))
+        }
+        DrawingErrorKind::FontError(e) => DrawingErrorKind::FontError(e),
+    }
+}
+
+/// The frame-oriented bitmap backend, which appends a frame to an animated GIF or PNG file each
+/// time the drawing is presented.
+///
+/// The file is completed when the backend is dropped, which ignores the errors. Call
+/// [AnimatedBackend::finish](#method.finish) instead to get them, for example by drawing on the
+/// backend through an `Rc<RefCell<AnimatedBackend>>`:
+///
+/// ```rust,no_run
+/// use plotters::drawing::{AnimatedBackend, AnimationFormat};
+/// use plotters::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let backend = AnimatedBackend::new("blink.gif", (320, 240), AnimationFormat::Gif, 500).unwrap();
+/// let backend = Rc::new(RefCell::new(backend));
+/// {
+///     let root = DrawingArea::from(&backend);
+///     for color in [RED, BLUE].iter() {
+///         root.fill(color).unwrap();
+///         root.present().unwrap();
+///     }
+/// }
+/// let backend = Rc::try_unwrap(backend).ok().unwrap().into_inner();
+/// backend.finish().unwrap();
+/// ```
+///
+/// ```rust,no_run
+/// use plotters::drawing::{AnimatedBackend, AnimationFormat};
+/// use plotters::prelude::*;
+///
+/// let backend =
+///     AnimatedBackend::new("wave.png", (320, 240), AnimationFormat::Apng, 50).unwrap();
+/// let root = backend.into_drawing_area();
+/// for phase in 0..30 {
+///     root.fill(&WHITE).unwrap();
+///     let mut chart = ChartBuilder::on(&root)
+///         .build_cartesian_2d(0.0..6.3, -1.0..1.0)
+///         .unwrap();
+///     chart
+///         .draw_series(LineSeries::new(
+///             (0..63).map(|x| (x as f64 / 10.0, (x as f64 / 10.0 + phase as f64 / 5.0).sin())),
+///             &BLUE,
+///         ))
+///         .unwrap();
+///     root.present().unwrap();
+/// }
+/// ```
+pub struct AnimatedBackend {
+    path: PathBuf,
+    size: (u32, u32),
+    delay: u32,
+    buffer: Vec<u8>,
+    gif: Option<gif::Encoder<BufWriter<File>>>,
+    frames: Vec<Vec<u8>>,
+    // If anything is drawn since the last frame is presented
+    dirty: bool,
+}
+
+impl AnimatedBackend {
+    /// Create a new animated backend
+    /// - `path`: The path of the file
+    /// - `size`: The size of the frames in pixels
+    /// - `format`: The file format
+    /// - `delay`: The delay of each frame in milliseconds, the delay of GIF frames is rounded
+    ///   to 10 milliseconds
+    /// - **returns** The newly created backend, or an error if the format can't encode the
+    ///   frames of the size
+    pub fn new<T: AsRef<Path>>(
+        path: T,
+        (w, h): (u32, u32),
+        format: AnimationFormat,
+        delay: u32,
+    ) -> Result<Self, AnimatedBackendError> {
+        let gif = match format {
+            AnimationFormat::Gif => {
+                if w > u32::from(u16::MAX) || h > u32::from(u16::MAX) {
+                    return Err(AnimatedBackendError::InvalidSize((w, h)));
+                }
+                let file = File::create(path.as_ref()).map_err(AnimatedBackendError::IOError)?;
+                let mut encoder = gif::Encoder::new(BufWriter::new(file), w as u16, h as u16, &[])
+                    .map_err(AnimatedBackendError::GifEncodingError)?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .map_err(AnimatedBackendError::GifEncodingError)?;
+                Some(encoder)
+            }
+            AnimationFormat::Apng => None,
+        };
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            size: (w, h),
+            delay,
+            buffer: vec![0; w as usize * h as usize * 3],
+            gif,
+            frames: vec![],
+            dirty: false,
+        })
+    }
+
+    fn with_bitmap<R, F>(&mut self, draw: F) -> Result<R, DrawingErrorKind<AnimatedBackendError>>
+    where
+        F: FnOnce(&mut BitMapBackend) -> Result<R, DrawingErrorKind<BitMapBackendError>>,
+    {
+        let mut bitmap = BitMapBackend::with_buffer(&mut self.buffer, self.size);
+        draw(&mut bitmap).map_err(map_error)
+    }
+
+    /// Add the current buffer as a frame, which is written right away to a GIF
+    fn add_frame(&mut self) -> Result<(), AnimatedBackendError> {
+        self.dirty = false;
+        if let Some(encoder) = self.gif.as_mut() {
+            let (w, h) = self.size;
+            let mut frame = gif::Frame::from_rgb_speed(w as u16, h as u16, &self.buffer, 10);
+            frame.delay = ((self.delay + 5) / 10).min(u32::from(u16::MAX)) as u16;
+            return encoder
+                .write_frame(&frame)
+                .map_err(AnimatedBackendError::GifEncodingError);
+        }
+        self.frames.push(self.buffer.clone());
+        Ok(())
+    }
+
+    /// Add the pending drawing as the final frame and complete the file, after which the
+    /// backend has nothing left to write
+    fn complete(&mut self) -> Result<(), AnimatedBackendError> {
+        if self.dirty {
+            self.add_frame()?;
+        }
+        if let Some(encoder) = self.gif.take() {
+            return encoder
+                .into_inner()
+                .and_then(|mut writer| writer.flush())
+                .map_err(AnimatedBackendError::IOError);
+        }
+        if !self.frames.is_empty() {
+            self.write_apng()?;
+            self.frames.clear();
+        }
+        Ok(())
+    }
+
+    /// Complete the file like dropping the backend does, but report the errors of writing it.
+    /// The drawing after the last presented frame makes the final frame.
+    /// - **returns** The result of writing the file
+    pub fn finish(mut self) -> Result<(), AnimatedBackendError> {
+        self.complete()
+    }
+
+    fn write_apng(&self) -> Result<(), AnimatedBackendError> {
+        let file = File::create(&self.path).map_err(AnimatedBackendError::IOError)?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.size.0, self.size.1);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .set_animated(self.frames.len() as u32, 0)
+            .map_err(AnimatedBackendError::PngEncodingError)?;
+        encoder
+            .set_frame_delay(self.delay.min(u32::from(u16::MAX)) as u16, 1000)
+            .map_err(AnimatedBackendError::PngEncodingError)?;
+        let mut writer = encoder
+            .write_header()
+            .map_err(AnimatedBackendError::PngEncodingError)?;
+        for frame in self.frames.iter() {
+            writer
+                .write_image_data(frame)
+                .map_err(AnimatedBackendError::PngEncodingError)?;
+        }
+        writer
+            .finish()
+            .map_err(AnimatedBackendError::PngEncodingError)
+    }
+}
+
+impl BackendFeatures for AnimatedBackend {}
+
+impl DrawingBackend for AnimatedBackend {
+    type ErrorType = AnimatedBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.dirty = true;
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.add_frame().map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.draw_pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.draw_line(from, to, style))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.draw_rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.draw_path(path, style))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.draw_circle(center, radius, style, fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.fill_polygon(vert, style))
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.draw_text(text, style, pos))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<AnimatedBackendError>> {
+        self.with_bitmap(|b| b.blit_bitmap(pos, size, src))
+    }
+}
+
+impl Drop for AnimatedBackend {
+    fn drop(&mut self) {
+        // Drop should not panic, so we ignore a failed write, which finish reports
+        let _ = self.complete();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::path::Path;
+
+    fn draw_frames(path: &Path, format: AnimationFormat) -> Vec<u8> {
+        {
+            let backend = AnimatedBackend::new(path, (20, 10), format, 100).unwrap();
+            let root = backend.into_drawing_area();
+            for color in [RED, GREEN, BLUE].iter() {
+                root.fill(color).unwrap();
+                root.present().unwrap();
+            }
+        }
+        let content = std::fs::read(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        content
+    }
+
+    #[test]
+    fn test_animated_backend() {
+        let dir = std::env::temp_dir();
+
+        let gif = draw_frames(
+            &dir.join("plotters-animated-test.gif"),
+            AnimationFormat::Gif,
+        );
+        assert_eq!(&gif[..6], b"GIF89a");
+        // Each frame starts with a graphic control extension
+        assert_eq!(gif.windows(2).filter(|w| w == &[0x21, 0xf9]).count(), 3);
+
+        let apng = draw_frames(
+            &dir.join("plotters-animated-test.png"),
+            AnimationFormat::Apng,
+        );
+        assert_eq!(&apng[1..4], b"PNG");
+        let actl = apng.windows(4).position(|w| w == b"acTL").unwrap();
+        assert_eq!(&apng[actl + 4..actl + 8], &[0, 0, 0, 3]);
+    }
+
+    #[test]
+    fn test_animated_backend_finish() {
+        let path = std::env::temp_dir().join("plotters-animated-finish-test.png");
+        let mut backend =
+            AnimatedBackend::new(&path, (20, 10), AnimationFormat::Apng, 100).unwrap();
+        backend.ensure_prepared().unwrap();
+        backend.present().unwrap();
+        // The drawing after the last frame makes the final frame
+        backend.ensure_prepared().unwrap();
+        backend.finish().unwrap();
+        let apng = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let actl = apng.windows(4).position(|w| w == b"acTL").unwrap();
+        assert_eq!(&apng[actl + 4..actl + 8], &[0, 0, 0, 2]);
+
+        let path = std::env::temp_dir().join("plotters-animated-size-test.gif");
+        match AnimatedBackend::new(&path, (70000, 10), AnimationFormat::Gif, 100) {
+            Err(AnimatedBackendError::InvalidSize(size)) => assert_eq!(size, (70000, 10)),
+            _ => panic!("The GIF frames can't be wider than 65535 pixels"),
+        }
+        assert!(!path.exists());
+    }
+}
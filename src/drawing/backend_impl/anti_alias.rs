@@ -0,0 +1,427 @@
+use crate::drawing::{AccessibleBackend, BackendCapabilities, BackendFeatures};
+use plotters_backend::rasterizer::polygonize;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The number of the scanlines that each row of the pixels of a polygon is sampled with
+const SUB_SCANLINES: usize = 4;
+
+/// The coverage of a pixel from the signed distance between the pixel center and the edge, which
+/// is positive inside the shape
+fn coverage(distance: f64) -> f64 {
+    if distance <= -0.5 {
+        0.0
+    } else if distance >= 0.5 {
+        1.0
+    } else {
+        distance + 0.5
+    }
+}
+
+fn with_coverage(color: BackendColor, coverage: f64) -> BackendColor {
+    BackendColor {
+        alpha: color.alpha * coverage,
+        rgb: color.rgb,
+    }
+}
+
+/// Add the coverage of the span of a scanline to the pixels of a row, the pixel `i` covers the
+/// `[i - 0.5, i + 0.5]` range around its center
+fn add_span(row: &mut [f64], from: f64, to: f64, weight: f64) {
+    let width = row.len() as f64;
+    let (from, to) = ((from + 0.5).max(0.0), (to + 0.5).min(width));
+    if from >= to {
+        return;
+    }
+    let (first, last) = (from.floor() as usize, to.floor() as usize);
+    if first == last {
+        row[first] += (to - from) * weight;
+        return;
+    }
+    row[first] += (first as f64 + 1.0 - from) * weight;
+    for pixel in &mut row[first + 1..last] {
+        *pixel += weight;
+    }
+    if last < row.len() {
+        row[last] += (to - last as f64) * weight;
+    }
+}
+
+/// The backend wrapper that draws the shapes of the wrapped backend with anti-aliasing, so that
+/// a bitmap isn't jagged compared to a vector rendering of the same chart.
+///
+/// The lines of width 1 are drawn with the Xiaolin Wu's algorithm. The edges of the circles, the
+/// filled polygons and the wider lines and paths, which are drawn as polygons, are blended by
+/// the coverage of the pixels. All of them are drawn in terms of the blended pixels of the
+/// wrapped backend. The rectangles, the axis aligned lines, the text and the bitmaps are drawn
+/// by the wrapped backend as they are, since their edges are on the pixel grid.
+///
+/// The backend can wrap the root backend, then everything is drawn with anti-aliasing, or a
+/// single drawing area can be drawn with anti-aliasing with
+/// [DrawingArea::with_anti_aliasing](struct.DrawingArea.html#method.with_anti_aliasing), while
+/// the other areas of the backend are drawn as they are.
+///
+/// ```rust
+/// use plotters::drawing::AntiAliasedBackend;
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 320 * 240 * 3];
+/// let backend = AntiAliasedBackend::new(BitMapBackend::with_buffer(&mut buffer, (320, 240)));
+/// let root = backend.into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// root.draw(&PathElement::new(vec![(10, 10), (300, 100)], &BLACK))
+///     .unwrap();
+/// root.draw(&Circle::new((160, 160), 50, BLUE.filled()))
+///     .unwrap();
+/// ```
+pub struct AntiAliasedBackend<DB: DrawingBackend> {
+    inner: DB,
+}
+
+impl<DB: DrawingBackend> AntiAliasedBackend<DB> {
+    /// Wrap the backend to draw with anti-aliasing
+    pub fn new(inner: DB) -> Self {
+        Self { inner }
+    }
+
+    /// Get the wrapped backend back
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    fn blend_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+        coverage: f64,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if coverage <= 0.0 {
+            return Ok(());
+        }
+        self.inner.draw_pixel(point, with_coverage(color, coverage))
+    }
+
+    /// Draw a line of width 1 with the Xiaolin Wu's algorithm, each step along the major axis
+    /// splits the color between the two pixels that the line passes between
+    fn draw_wu_line(
+        &mut self,
+        (x0, y0): BackendCoord,
+        (x1, y1): BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let steep = (y1 - y0).abs() > (x1 - x0).abs();
+        let (mut a0, mut b0, mut a1, mut b1) = if steep {
+            (y0, x0, y1, x1)
+        } else {
+            (x0, y0, x1, y1)
+        };
+        if a0 > a1 {
+            std::mem::swap(&mut a0, &mut a1);
+            std::mem::swap(&mut b0, &mut b1);
+        }
+        let gradient = if a1 == a0 {
+            0.0
+        } else {
+            f64::from(b1 - b0) / f64::from(a1 - a0)
+        };
+        for a in a0..=a1 {
+            let b = f64::from(b0) + gradient * f64::from(a - a0);
+            let base = b.floor();
+            let fraction = b - base;
+            let base = base as i32;
+            for (offset, coverage) in [(0, 1.0 - fraction), (1, fraction)].iter() {
+                let point = if steep {
+                    (base + offset, a)
+                } else {
+                    (a, base + offset)
+                };
+                self.blend_pixel(point, color, *coverage)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill a polygon with the even-odd rule, each row of the pixels is sampled with a few
+    /// scanlines, and each pixel is covered by the exact length of the spans over it
+    fn fill_covered_polygon(
+        &mut self,
+        vert: &[BackendCoord],
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if vert.len() < 3 || color.alpha == 0.0 {
+            return Ok(());
+        }
+        let min_x = vert.iter().map(|p| p.0).min().unwrap();
+        let max_x = vert.iter().map(|p| p.0).max().unwrap();
+        let min_y = vert.iter().map(|p| p.1).min().unwrap();
+        let max_y = vert.iter().map(|p| p.1).max().unwrap();
+        let edges: Vec<_> = vert.iter().zip(vert.iter().cycle().skip(1)).collect();
+        let weight = 1.0 / SUB_SCANLINES as f64;
+        let mut row = vec![0.0; (max_x - min_x + 1) as usize];
+        let mut crossings = vec![];
+        for y in min_y..=max_y {
+            row.iter_mut().for_each(|pixel| *pixel = 0.0);
+            for sub in 0..SUB_SCANLINES {
+                let sy = f64::from(y) - 0.5 + (sub as f64 + 0.5) * weight;
+                crossings.clear();
+                for (from, to) in edges.iter() {
+                    let (y0, y1) = (f64::from(from.1), f64::from(to.1));
+                    if (y0 <= sy) != (y1 <= sy) {
+                        let t = (sy - y0) / (y1 - y0);
+                        crossings.push(f64::from(from.0) + t * f64::from(to.0 - from.0));
+                    }
+                }
+                crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                for span in crossings.chunks(2) {
+                    if let [from, to] = span {
+                        let offset = f64::from(min_x);
+                        add_span(&mut row, from - offset, to - offset, weight);
+                    }
+                }
+            }
+            for (idx, covered) in row.iter().enumerate() {
+                self.blend_pixel((min_x + idx as i32, y), color, covered.min(1.0))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<DB: BackendFeatures> BackendFeatures for AntiAliasedBackend<DB> {
+    fn capabilities(&self) -> BackendCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn clip_bounds(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.inner.clip_bounds()
+    }
+}
+
+impl<DB: AccessibleBackend> AccessibleBackend for AntiAliasedBackend<DB> {
+    fn set_description(&mut self, title: &str, desc: &str) {
+        self.inner.set_description(title, desc)
+    }
+
+    fn begin_group(&mut self, role: &str, label: &str) {
+        self.inner.begin_group(role, label)
+    }
+
+    fn end_group(&mut self) {
+        self.inner.end_group()
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for AntiAliasedBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = style.color();
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+        // The axis aligned lines are not jagged
+        if from.0 == to.0 || from.1 == to.1 {
+            return self.inner.draw_line(from, to, style);
+        }
+        if style.stroke_width() != 1 {
+            let outline = polygonize(&[from, to], style.stroke_width());
+            return self.fill_covered_polygon(&outline, color);
+        }
+        self.draw_wu_line(from, to, color)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if style.stroke_width() != 1 {
+            let path: Vec<_> = path.into_iter().collect();
+            let outline = polygonize(&path, style.stroke_width());
+            return self.fill_covered_polygon(&outline, style.color());
+        }
+        let mut begin: Option<BackendCoord> = None;
+        for end in path {
+            if let Some(begin) = begin {
+                self.draw_line(begin, end, style)?;
+            }
+            begin = Some(end);
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        (cx, cy): BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = style.color();
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+        let radius = f64::from(radius);
+        let half_width = f64::from(style.stroke_width()) / 2.0;
+        let (outer, inner) = if fill {
+            (radius, None)
+        } else {
+            (radius + half_width, Some(radius - half_width))
+        };
+        let reach = outer.ceil() as i32 + 1;
+        for y in cy - reach..=cy + reach {
+            for x in cx - reach..=cx + reach {
+                let (dx, dy) = (f64::from(x - cx), f64::from(y - cy));
+                let distance = (dx * dx + dy * dy).sqrt();
+                let mut covered = coverage(outer - distance);
+                if let Some(inner) = inner {
+                    covered -= coverage(inner - distance);
+                }
+                self.blend_pixel((x, y), color, covered)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.fill_covered_polygon(&vert, style.color())
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.blit_bitmap(pos, size, src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    fn drawn_pixels<F: FnOnce(&mut AntiAliasedBackend<MockedBackend>)>(
+        draw: F,
+    ) -> Vec<(BackendCoord, f64)> {
+        let pixels = Rc::new(RefCell::new(vec![]));
+        let mut backend = MockedBackend::new(100, 100);
+        let collected = pixels.clone();
+        backend.check_draw_pixel(move |c, pos| collected.borrow_mut().push((pos, c.3)));
+        let mut backend = AntiAliasedBackend::new(backend);
+        draw(&mut backend);
+        let pixels = pixels.borrow().clone();
+        pixels
+    }
+
+    #[test]
+    fn test_anti_aliased_line() {
+        let pixels = drawn_pixels(|b| b.draw_line((0, 0), (10, 3), &BLACK).unwrap());
+        let mut columns: HashMap<i32, f64> = HashMap::new();
+        for ((x, _), alpha) in pixels.iter() {
+            assert!(*alpha > 0.0 && *alpha <= 1.0);
+            *columns.entry(*x).or_insert(0.0) += alpha;
+        }
+        // Each column of the line has a full pixel of color split between two pixels
+        assert_eq!(columns.len(), 11);
+        assert!(columns.values().all(|sum| (sum - 1.0).abs() < 1e-9));
+        assert!(pixels.iter().any(|(_, alpha)| *alpha < 1.0));
+
+        // The axis aligned lines are drawn by the wrapped backend
+        assert!(drawn_pixels(|b| b.draw_line((0, 0), (10, 0), &BLACK).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_anti_aliased_circle() {
+        let pixels = drawn_pixels(|b| b.draw_circle((50, 50), 10, &RED, true).unwrap());
+        let area: f64 = pixels.iter().map(|(_, alpha)| alpha).sum();
+        assert!((area - std::f64::consts::PI * 100.0).abs() < 1.0);
+        assert!(pixels.iter().any(|(_, alpha)| *alpha < 1.0));
+
+        let ring = drawn_pixels(|b| b.draw_circle((50, 50), 10, &RED, false).unwrap());
+        let length: f64 = ring.iter().map(|(_, alpha)| alpha).sum();
+        assert!((length - std::f64::consts::PI * 20.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_anti_aliased_polygon() {
+        let triangle = vec![(10, 10), (50, 10), (10, 30)];
+        let pixels = drawn_pixels(|b| b.fill_polygon(triangle, &BLUE).unwrap());
+        let area: f64 = pixels.iter().map(|(_, alpha)| alpha).sum();
+        assert!((area - 400.0).abs() < 1.0);
+        // The pixels along the slanted edge are partially covered
+        assert!(pixels.iter().any(|(_, alpha)| *alpha > 0.1 && *alpha < 0.9));
+        assert!(pixels.iter().all(|(_, alpha)| *alpha <= 1.0));
+
+        // The wide lines are filled as the polygons
+        let style = Color::stroke_width(&BLACK, 4);
+        let line = drawn_pixels(|b| b.draw_line((10, 10), (70, 50), &style).unwrap());
+        let area: f64 = line.iter().map(|(_, alpha)| alpha).sum();
+        let length = (60.0f64 * 60.0 + 40.0 * 40.0).sqrt();
+        assert!((area - length * 4.0).abs() < length * 0.5);
+        assert!(line.iter().any(|(_, alpha)| *alpha < 1.0));
+    }
+}
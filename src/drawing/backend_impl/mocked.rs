@@ -1,6 +1,6 @@
 use crate::coord::Shift;
 use crate::drawing::area::IntoDrawingArea;
-use crate::drawing::DrawingArea;
+use crate::drawing::{AccessibleBackend, BackendCapabilities, BackendFeatures, DrawingArea};
 use crate::style::RGBAColor;
 use plotters_backend::{
     BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
@@ -34,6 +34,7 @@ pub struct MockedBackend {
     check_draw_circle: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, u32)>>,
     check_draw_text: VecDeque<Box<dyn FnMut(RGBAColor, &str, f64, BackendCoord, &str)>>,
     check_fill_polygon: VecDeque<Box<dyn FnMut(RGBAColor, Vec<BackendCoord>)>>,
+    capabilities: BackendCapabilities,
     drop_check: Option<Box<dyn FnMut(&Self)>>,
 }
 
@@ -73,6 +74,7 @@ impl MockedBackend {
             check_draw_circle: vec![].into(),
             check_draw_text: vec![].into(),
             check_fill_polygon: vec![].into(),
+            capabilities: BackendCapabilities::all(),
             drop_check: None,
         }
     }
@@ -93,12 +95,26 @@ impl MockedBackend {
     def_set_checker_func!(drop_check, &Self);
     def_set_checker_func!(check_fill_polygon, RGBAColor, Vec<BackendCoord>);
 
+    /// Set the drawing features that the backend describes
+    pub fn set_capabilities(&mut self, capabilities: BackendCapabilities) -> &mut Self {
+        self.capabilities = capabilities;
+        self
+    }
+
     fn check_before_draw(&mut self) {
         self.draw_count += 1;
         //assert_eq!(self.init_count, self.draw_count);
     }
 }
 
+impl BackendFeatures for MockedBackend {
+    fn capabilities(&self) -> BackendCapabilities {
+        self.capabilities
+    }
+}
+
+impl AccessibleBackend for MockedBackend {}
+
 #[derive(Debug)]
 pub struct MockedError;
 
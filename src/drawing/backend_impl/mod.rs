@@ -3,6 +3,43 @@ mod mocked;
 #[cfg(test)]
 pub use mocked::{check_color, create_mocked_drawing_area, MockedBackend};
 
+mod anti_alias;
+pub use anti_alias::AntiAliasedBackend;
+
+mod null;
+pub use null::NullBackend;
+
+mod recorded;
+pub use recorded::RecordedDrawing;
+
+mod recording;
+pub use recording::{DrawCall, DrawCallKind, RecordingBackend};
+
+mod resilient;
+pub use resilient::{DrawingWarning, ResilientBackend};
+
+#[cfg(feature = "svg_backend")]
+mod accessible_svg;
+#[cfg(feature = "svg_backend")]
+pub use accessible_svg::AccessibleSVGBackend;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "animated_backend"))]
+mod animated;
+#[cfg(all(not(target_arch = "wasm32"), feature = "tiled_bitmap"))]
+mod tiled;
+#[cfg(feature = "vertex_batch_backend")]
+mod vertex_batch;
+#[cfg(feature = "wgpu_backend")]
+mod wgpu_renderer;
+#[cfg(all(not(target_arch = "wasm32"), feature = "animated_backend"))]
+pub use animated::{AnimatedBackend, AnimatedBackendError, AnimationFormat};
+#[cfg(all(not(target_arch = "wasm32"), feature = "tiled_bitmap"))]
+pub use tiled::{TileBackend, TiledBitMap};
+#[cfg(feature = "vertex_batch_backend")]
+pub use vertex_batch::{Vertex, VertexBatchBackend};
+#[cfg(feature = "wgpu_backend")]
+pub use wgpu_renderer::WgpuRenderer;
+
 /// This is the dummy backend placeholder for the backend that never fails
 #[derive(Debug)]
 pub struct DummyBackendError;
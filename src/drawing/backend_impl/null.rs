@@ -0,0 +1,129 @@
+use crate::drawing::BackendFeatures;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+use super::DummyBackendError;
+
+/// The backend that draws nothing, but measures the text with the fonts like any other backend.
+///
+/// A chart that is built on this backend goes through the same layout as on a real backend: the
+/// label areas are sized, the caption is placed and the plotting area is computed, while none of
+/// the pixels is rasterized. Thus a GUI host can get the geometry of a chart, with
+/// [ChartContext::layout](../chart/struct.ChartContext.html#method.layout), before the first
+/// real draw.
+///
+/// ```rust
+/// use plotters::drawing::NullBackend;
+/// use plotters::prelude::*;
+///
+/// let root = NullBackend::new((640, 480)).into_drawing_area();
+/// let chart = ChartBuilder::on(&root)
+///     .x_label_area_size(40)
+///     .y_label_area_size(40)
+///     .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+///     .unwrap();
+/// assert_eq!(chart.layout().plotting_area, ((40, 0), (600, 440)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NullBackend {
+    size: (u32, u32),
+}
+
+impl NullBackend {
+    /// Create a new backend
+    /// - `size`: The size of the drawing in pixels
+    /// - **returns** The newly created backend
+    pub fn new(size: (u32, u32)) -> Self {
+        Self { size }
+    }
+}
+
+impl BackendFeatures for NullBackend {}
+
+impl DrawingBackend for NullBackend {
+    type ErrorType = DummyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        _: BackendCoord,
+        _: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        _: BackendCoord,
+        _: BackendCoord,
+        _: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        _: BackendCoord,
+        _: BackendCoord,
+        _: &S,
+        _: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        _: I,
+        _: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        _: BackendCoord,
+        _: u32,
+        _: &S,
+        _: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        _: I,
+        _: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        _: &str,
+        _: &S,
+        _: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        _: BackendCoord,
+        _: (u32, u32),
+        _: &[u8],
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+}
@@ -0,0 +1,581 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::coord::Shift;
+use crate::drawing::{BackendCapabilities, BackendFeatures, DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontDesc, FontFamily, FontStyle, FontTransform, RGBAColor, TextStyle};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+use super::DummyBackendError;
+
+/// The style of a recorded shape
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+struct Stroke {
+    color: RGBAColor,
+    width: u32,
+}
+
+impl Stroke {
+    fn new<S: BackendStyle>(style: &S) -> Self {
+        Self {
+            color: to_rgba(style.color()),
+            width: style.stroke_width(),
+        }
+    }
+
+    fn scaled(&self, transform: &Transform) -> Self {
+        Self {
+            color: self.color,
+            width: transform.length(self.width),
+        }
+    }
+}
+
+impl BackendStyle for Stroke {
+    fn color(&self) -> BackendColor {
+        self.color.to_backend_color()
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.width
+    }
+}
+
+/// The style of a recorded text. The font style, the rotation and the anchor are kept as the
+/// plain values, so that the recorded text can be serialized.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+struct Font {
+    family: String,
+    size: f64,
+    style: String,
+    rotation: u32,
+    color: RGBAColor,
+    anchor: (String, String),
+}
+
+impl Font {
+    fn new<S: BackendTextStyle>(style: &S) -> Self {
+        let anchor = style.anchor();
+        let h_pos = match anchor.h_pos {
+            HPos::Left => "left",
+            HPos::Center => "center",
+            HPos::Right => "right",
+        };
+        let v_pos = match anchor.v_pos {
+            VPos::Top => "top",
+            VPos::Center => "center",
+            VPos::Bottom => "bottom",
+        };
+        let rotation = match style.transform() {
+            FontTransform::None => 0,
+            FontTransform::Rotate90 => 90,
+            FontTransform::Rotate180 => 180,
+            FontTransform::Rotate270 => 270,
+        };
+        Self {
+            family: style.family().as_str().to_string(),
+            size: style.size(),
+            style: style.style().as_str().to_string(),
+            rotation,
+            color: to_rgba(style.color()),
+            anchor: (h_pos.to_string(), v_pos.to_string()),
+        }
+    }
+
+    fn text_style(&self, scale: f64) -> TextStyle<'_> {
+        let transform = match self.rotation {
+            90 => FontTransform::Rotate90,
+            180 => FontTransform::Rotate180,
+            270 => FontTransform::Rotate270,
+            _ => FontTransform::None,
+        };
+        let h_pos = match self.anchor.0.as_str() {
+            "center" => HPos::Center,
+            "right" => HPos::Right,
+            _ => HPos::Left,
+        };
+        let v_pos = match self.anchor.1.as_str() {
+            "center" => VPos::Center,
+            "bottom" => VPos::Bottom,
+            _ => VPos::Top,
+        };
+        let font = FontDesc::new(
+            FontFamily::from(self.family.as_str()),
+            self.size * scale,
+            FontStyle::from(self.style.as_str()),
+        )
+        .transform(transform);
+        TextStyle {
+            font,
+            color: self.color.to_backend_color(),
+            pos: Pos::new(h_pos, v_pos),
+        }
+    }
+}
+
+/// A recorded backend command
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+enum Command {
+    Pixel(BackendCoord, RGBAColor),
+    Line(BackendCoord, BackendCoord, Stroke),
+    Rect(BackendCoord, BackendCoord, Stroke, bool),
+    Path(Vec<BackendCoord>, Stroke),
+    Circle(BackendCoord, u32, Stroke, bool),
+    Polygon(Vec<BackendCoord>, Stroke),
+    Text(String, Font, BackendCoord),
+    Bitmap(BackendCoord, (u32, u32), Vec<u8>),
+}
+
+/// The mapping from the pixels of a recorded drawing to the pixels it's replayed onto
+struct Transform {
+    origin: BackendCoord,
+    scale: (f64, f64),
+}
+
+impl Transform {
+    fn new(from: (u32, u32), origin: BackendCoord, to: (u32, u32)) -> Self {
+        let ratio = |to: u32, from: u32| f64::from(to) / f64::from(from.max(1));
+        Self {
+            origin,
+            scale: (ratio(to.0, from.0), ratio(to.1, from.1)),
+        }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.scale == (1.0, 1.0)
+    }
+
+    fn point(&self, (x, y): BackendCoord) -> BackendCoord {
+        (
+            self.origin.0 + (f64::from(x) * self.scale.0).round() as i32,
+            self.origin.1 + (f64::from(y) * self.scale.1).round() as i32,
+        )
+    }
+
+    /// The scale of the sizes, such as the stroke widths and the font sizes, which is the
+    /// smaller one of the two directions, thus nothing outgrows the area it's replayed onto
+    fn size_scale(&self) -> f64 {
+        self.scale.0.min(self.scale.1)
+    }
+
+    fn length(&self, length: u32) -> u32 {
+        if length == 0 {
+            return 0;
+        }
+        ((f64::from(length) * self.size_scale()).round() as u32).max(1)
+    }
+}
+
+fn to_rgba(color: BackendColor) -> RGBAColor {
+    RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha)
+}
+
+/// Resample a RGB bitmap to the given size with the nearest neighbor
+fn resample(src: &[u8], (iw, ih): (u32, u32), (w, h): (u32, u32)) -> Vec<u8> {
+    let mut dst = Vec::with_capacity((w * h * 3) as usize);
+    for y in 0..h {
+        let sy = (u64::from(y) * u64::from(ih) / u64::from(h)) as usize;
+        for x in 0..w {
+            let sx = (u64::from(x) * u64::from(iw) / u64::from(w)) as usize;
+            let offset = (sy * iw as usize + sx) * 3;
+            dst.extend_from_slice(&src[offset..offset + 3]);
+        }
+    }
+    dst
+}
+
+impl Command {
+    fn replay<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        transform: &Transform,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let t = transform;
+        match self {
+            Command::Pixel(pos, color) => {
+                let (x0, y0) = t.point(*pos);
+                let (x1, y1) = t.point((pos.0 + 1, pos.1 + 1));
+                if x1 - x0 <= 1 && y1 - y0 <= 1 {
+                    backend.draw_pixel((x0, y0), color.to_backend_color())
+                } else {
+                    let style = Stroke {
+                        color: *color,
+                        width: 1,
+                    };
+                    backend.draw_rect((x0, y0), (x1 - 1, y1 - 1), &style, true)
+                }
+            }
+            Command::Line(from, to, style) => {
+                backend.draw_line(t.point(*from), t.point(*to), &style.scaled(t))
+            }
+            Command::Rect(upper_left, bottom_right, style, fill) => backend.draw_rect(
+                t.point(*upper_left),
+                t.point(*bottom_right),
+                &style.scaled(t),
+                *fill,
+            ),
+            Command::Path(path, style) => {
+                backend.draw_path(path.iter().map(|p| t.point(*p)), &style.scaled(t))
+            }
+            Command::Circle(center, radius, style, fill) => {
+                backend.draw_circle(t.point(*center), t.length(*radius), &style.scaled(t), *fill)
+            }
+            Command::Polygon(vert, style) => {
+                backend.fill_polygon(vert.iter().map(|p| t.point(*p)), style)
+            }
+            Command::Text(text, font, pos) => {
+                backend.draw_text(text, &font.text_style(t.size_scale()), t.point(*pos))
+            }
+            Command::Bitmap(pos, (iw, ih), src) => {
+                let upper_left = t.point(*pos);
+                if t.is_identity() {
+                    return backend.blit_bitmap(upper_left, (*iw, *ih), src);
+                }
+                let bottom_right = t.point((pos.0 + *iw as i32, pos.1 + *ih as i32));
+                let w = (bottom_right.0 - upper_left.0).max(0) as u32;
+                let h = (bottom_right.1 - upper_left.1).max(0) as u32;
+                if w == 0 || h == 0 {
+                    return Ok(());
+                }
+                backend.blit_bitmap(upper_left, (w, h), &resample(src, (*iw, *ih), (w, h)))
+            }
+        }
+    }
+}
+
+/// A display list of all the backend commands of a drawing, which is replayed onto any other
+/// backend later, at any size. Thus a chart is drawn once, and exported many times, such as
+/// to a PNG, a SVG and a PDF file, without computing the series again.
+///
+/// The drawing is recorded by drawing onto the recorded drawing as a backend, or with
+/// [RecordedDrawing::record](#method.record). The recording is replayed by drawing it onto a
+/// drawing area as an element, where it's scaled to fit the area, or with
+/// [RecordedDrawing::replay](#method.replay). The coordinates are scaled in each direction,
+/// while the stroke widths, the radiuses and the font sizes are scaled by the smaller factor of
+/// the two directions. The text is recorded as the text, thus it's rendered by the font of the
+/// target backend.
+///
+/// With the `serialize` feature, the recorded drawing can be serialized with serde and saved
+/// to render it later.
+///
+/// ```rust
+/// use plotters::drawing::RecordedDrawing;
+/// use plotters::prelude::*;
+///
+/// let drawing = RecordedDrawing::record((640, 480), |root| {
+///     root.fill(&WHITE)?;
+///     let mut chart = ChartBuilder::on(root)
+///         .margin(10)
+///         .build_cartesian_2d(0.0..10.0, 0.0..100.0)?;
+///     chart.configure_mesh().disable_mesh().draw()?;
+///     chart.draw_series(LineSeries::new((0..=10).map(|x| (x as f64, (x * x) as f64)), &RED))?;
+///     Ok(())
+/// })
+/// .unwrap();
+///
+/// // The same drawing is exported twice, in different formats and sizes
+/// let mut svg = String::new();
+/// let root = SVGBackend::with_string(&mut svg, (1280, 960)).into_drawing_area();
+/// root.draw(&drawing).unwrap();
+///
+/// let mut buffer = vec![0; 320 * 240 * 3];
+/// let mut backend = BitMapBackend::with_buffer(&mut buffer, (320, 240));
+/// drawing.replay(&mut backend).unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct RecordedDrawing {
+    size: (u32, u32),
+    commands: Vec<Command>,
+}
+
+/// The upper left corner of a recorded drawing drawn as an element
+const ORIGIN: BackendCoord = (0, 0);
+
+impl RecordedDrawing {
+    /// Create an empty recording which is recorded by drawing onto it as a backend
+    /// - `size`: The size of the drawing in pixels, which is scaled from when it's replayed
+    /// - **returns** The newly created recording
+    pub fn new(size: (u32, u32)) -> Self {
+        Self {
+            size,
+            commands: vec![],
+        }
+    }
+
+    /// Record the drawing onto a drawing area
+    /// - `size`: The size of the drawing area in pixels
+    /// - `draw`: The function that draws onto the drawing area
+    /// - **returns** The recorded drawing, or the error returned by `draw`
+    pub fn record<F>(
+        size: (u32, u32),
+        draw: F,
+    ) -> Result<Self, DrawingAreaErrorKind<DummyBackendError>>
+    where
+        F: FnOnce(
+            &DrawingArea<RecordedDrawing, Shift>,
+        ) -> Result<(), DrawingAreaErrorKind<DummyBackendError>>,
+    {
+        let backend = Rc::new(RefCell::new(Self::new(size)));
+        {
+            let root: DrawingArea<_, _> = (&backend).into();
+            draw(&root)?;
+            root.present()?;
+        }
+        Ok(match Rc::try_unwrap(backend) {
+            Ok(backend) => backend.into_inner(),
+            Err(backend) => backend.borrow().clone(),
+        })
+    }
+
+    /// Get the size of the recorded drawing in pixels
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Get the number of the recorded commands
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Check if nothing is recorded
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Replay the recorded drawing onto the backend, which is scaled to the size of the backend
+    /// - `backend`: The backend to draw onto
+    /// - **returns** The result of the drawing
+    pub fn replay<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let transform = Transform::new(self.size, (0, 0), backend.get_size());
+        backend.ensure_prepared()?;
+        self.replay_with(backend, &transform)
+    }
+
+    fn replay_with<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        transform: &Transform,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        for command in self.commands.iter() {
+            command.replay(backend, transform)?;
+        }
+        Ok(())
+    }
+
+    fn record_command(
+        &mut self,
+        command: Command,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(command);
+        Ok(())
+    }
+}
+
+impl<'a> PointCollection<'a, BackendCoord> for &'a RecordedDrawing {
+    type Point = &'a BackendCoord;
+    type IntoIter = std::iter::Once<&'a BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&ORIGIN)
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for RecordedDrawing {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(origin) = pos.next() {
+            let transform = Transform::new(self.size, origin, parent_dim);
+            self.replay_with(backend, &transform)?;
+        }
+        Ok(())
+    }
+}
+
+impl BackendFeatures for RecordedDrawing {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::all().raster(false)
+    }
+}
+
+impl DrawingBackend for RecordedDrawing {
+    type ErrorType = DummyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        pos: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.record_command(Command::Pixel(pos, to_rgba(color)))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.record_command(Command::Line(from, to, Stroke::new(style)))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        let style = Stroke::new(style);
+        self.record_command(Command::Rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        let path = path.into_iter().collect();
+        self.record_command(Command::Path(path, Stroke::new(style)))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        let style = Stroke::new(style);
+        self.record_command(Command::Circle(center, radius, style, fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        let vert = vert.into_iter().collect();
+        self.record_command(Command::Polygon(vert, Stroke::new(style)))
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.record_command(Command::Text(text.to_string(), Font::new(style), pos))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.record_command(Command::Bitmap(pos, (iw, ih), src.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn draw_sample(
+        root: &DrawingArea<RecordedDrawing, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DummyBackendError>> {
+        root.draw(&Rectangle::new([(10, 10), (49, 29)], RED.filled()))?;
+        root.draw(&PathElement::new(
+            vec![(0, 0), (100, 50)],
+            ShapeStyle::from(&BLUE).stroke_width(2),
+        ))?;
+        root.draw(&Circle::new((50, 25), 5, &GREEN))?;
+        root.draw(&Text::new("label", (20, 40), ("sans-serif", 10)))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let drawing = RecordedDrawing::record((100, 50), draw_sample).unwrap();
+        assert_eq!(drawing.size(), (100, 50));
+        assert_eq!(drawing.len(), 4);
+
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(filled);
+                assert_eq!((ul, br), ((20, 20), (98, 58)));
+            });
+            m.check_draw_path(|c, w, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(w, 4);
+                assert_eq!(path, vec![(0, 0), (200, 100)]);
+            });
+            m.check_draw_text(|_, _, size, pos, text| {
+                assert_eq!(text, "label");
+                assert_eq!(size, 20.0);
+                assert_eq!(pos, (40, 80));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        drawing_area.draw(&drawing).unwrap();
+    }
+
+    #[test]
+    fn test_replay_onto_sub_area() {
+        let drawing = RecordedDrawing::record((100, 50), |root| {
+            root.draw(&Rectangle::new([(0, 0), (50, 25)], RED.filled()))
+        })
+        .unwrap();
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_rect(|_, _, _, ul, br| {
+                assert_eq!((ul, br), ((100, 0), (150, 50)));
+            });
+        });
+        let (_, right) = drawing_area.split_horizontally(100);
+        right.draw(&drawing).unwrap();
+    }
+}
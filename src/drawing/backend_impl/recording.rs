@@ -0,0 +1,426 @@
+use crate::drawing::{BackendCapabilities, BackendFeatures};
+use crate::style::RGBAColor;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+use super::DummyBackendError;
+
+/// The type of the primitive of a [DrawCall](enum.DrawCall.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DrawCallKind {
+    /// A single pixel, which includes the pixels of the blitted bitmaps
+    Pixel,
+    /// A straight line
+    Line,
+    /// A rectangle, either filled or outlined
+    Rect,
+    /// A path of connected lines
+    Path,
+    /// A circle, either filled or outlined
+    Circle,
+    /// A filled polygon
+    Polygon,
+    /// A text
+    Text,
+}
+
+/// A draw call recorded by a [RecordingBackend](struct.RecordingBackend.html), in the pixels of
+/// the backend
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCall {
+    /// A pixel is drawn
+    Pixel {
+        /// The position of the pixel
+        pos: BackendCoord,
+        /// The color of the pixel
+        color: RGBAColor,
+    },
+    /// A line is drawn
+    Line {
+        /// The start point
+        from: BackendCoord,
+        /// The end point
+        to: BackendCoord,
+        /// The color of the line
+        color: RGBAColor,
+        /// The stroke width
+        width: u32,
+    },
+    /// A rectangle is drawn
+    Rect {
+        /// The upper left corner
+        upper_left: BackendCoord,
+        /// The bottom right corner
+        bottom_right: BackendCoord,
+        /// The color of the rectangle
+        color: RGBAColor,
+        /// The stroke width
+        width: u32,
+        /// If the rectangle is filled
+        filled: bool,
+    },
+    /// A path is drawn
+    Path {
+        /// The points of the path
+        points: Vec<BackendCoord>,
+        /// The color of the path
+        color: RGBAColor,
+        /// The stroke width
+        width: u32,
+    },
+    /// A circle is drawn
+    Circle {
+        /// The center of the circle
+        center: BackendCoord,
+        /// The radius in pixels
+        radius: u32,
+        /// The color of the circle
+        color: RGBAColor,
+        /// The stroke width
+        width: u32,
+        /// If the circle is filled
+        filled: bool,
+    },
+    /// A polygon is filled
+    Polygon {
+        /// The vertices of the polygon
+        points: Vec<BackendCoord>,
+        /// The fill color
+        color: RGBAColor,
+    },
+    /// A text is drawn
+    Text {
+        /// The text
+        text: String,
+        /// The position that the text is anchored at
+        pos: BackendCoord,
+        /// The font family
+        family: String,
+        /// The font size
+        size: f64,
+        /// The color of the text
+        color: RGBAColor,
+    },
+}
+
+impl DrawCall {
+    /// Get the type of the primitive
+    pub fn kind(&self) -> DrawCallKind {
+        match self {
+            DrawCall::Pixel { .. } => DrawCallKind::Pixel,
+            DrawCall::Line { .. } => DrawCallKind::Line,
+            DrawCall::Rect { .. } => DrawCallKind::Rect,
+            DrawCall::Path { .. } => DrawCallKind::Path,
+            DrawCall::Circle { .. } => DrawCallKind::Circle,
+            DrawCall::Polygon { .. } => DrawCallKind::Polygon,
+            DrawCall::Text { .. } => DrawCallKind::Text,
+        }
+    }
+
+    /// Get the color of the primitive
+    pub fn color(&self) -> RGBAColor {
+        match self {
+            DrawCall::Pixel { color, .. }
+            | DrawCall::Line { color, .. }
+            | DrawCall::Rect { color, .. }
+            | DrawCall::Path { color, .. }
+            | DrawCall::Circle { color, .. }
+            | DrawCall::Polygon { color, .. }
+            | DrawCall::Text { color, .. } => *color,
+        }
+    }
+
+    /// Get the bounding box of the primitive as the upper left and the bottom right corners.
+    /// The line widths aren't included, and a text is only its anchor point, since its size
+    /// depends on the font.
+    pub fn bounds(&self) -> (BackendCoord, BackendCoord) {
+        let points: Vec<BackendCoord> = match self {
+            DrawCall::Pixel { pos, .. } | DrawCall::Text { pos, .. } => vec![*pos],
+            DrawCall::Line { from, to, .. } => vec![*from, *to],
+            DrawCall::Rect {
+                upper_left,
+                bottom_right,
+                ..
+            } => vec![*upper_left, *bottom_right],
+            DrawCall::Path { points, .. } | DrawCall::Polygon { points, .. } => points.clone(),
+            DrawCall::Circle { center, radius, .. } => {
+                let r = *radius as i32;
+                vec![(center.0 - r, center.1 - r), (center.0 + r, center.1 + r)]
+            }
+        };
+        let first = points.first().cloned().unwrap_or((0, 0));
+        points.iter().fold((first, first), |(ul, br), p| {
+            (
+                (ul.0.min(p.0), ul.1.min(p.1)),
+                (br.0.max(p.0), br.1.max(p.1)),
+            )
+        })
+    }
+}
+
+fn to_rgba(color: BackendColor) -> RGBAColor {
+    RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha)
+}
+
+/// The backend that draws nothing but records a log of the draw calls, so that the code that
+/// draws a chart can be unit tested by querying what was drawn, rather than comparing the
+/// rendered images with golden images.
+///
+/// The log is kept through `present()`, and it's cleared with
+/// [RecordingBackend::clear](#method.clear). The backend is shared with the drawing area to
+/// read the log after drawing. The texts are measured by the font as they would be on any other
+/// backend.
+///
+/// ```rust
+/// use plotters::drawing::{DrawCallKind, DrawingArea, RecordingBackend};
+/// use plotters::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let backend = Rc::new(RefCell::new(RecordingBackend::new((200, 100))));
+/// let root: DrawingArea<_, _> = (&backend).into();
+/// root.draw(&Rectangle::new([(10, 10), (50, 50)], RED.filled()))
+///     .unwrap();
+/// root.draw(&Circle::new((150, 50), 20, &BLUE)).unwrap();
+///
+/// let backend = backend.borrow();
+/// assert_eq!(backend.calls_within(DrawCallKind::Rect, (0, 0), (100, 100)).len(), 1);
+/// assert_eq!(backend.calls_of(DrawCallKind::Circle)[0].color(), BLUE.to_rgba());
+/// ```
+pub struct RecordingBackend {
+    size: (u32, u32),
+    calls: Vec<DrawCall>,
+}
+
+impl RecordingBackend {
+    /// Create a new backend
+    /// - `size`: The size of the drawing in pixels
+    /// - **returns** The newly created backend
+    pub fn new(size: (u32, u32)) -> Self {
+        Self {
+            size,
+            calls: vec![],
+        }
+    }
+
+    /// Get all the recorded draw calls in the order they were made
+    pub fn calls(&self) -> &[DrawCall] {
+        &self.calls
+    }
+
+    /// Get the recorded draw calls of the given type
+    pub fn calls_of(&self, kind: DrawCallKind) -> Vec<&DrawCall> {
+        self.calls.iter().filter(|c| c.kind() == kind).collect()
+    }
+
+    /// Get the recorded draw calls of the given type whose bounds are inside the rectangle
+    /// between `upper_left` and `bottom_right`, both inclusive
+    pub fn calls_within(
+        &self,
+        kind: DrawCallKind,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Vec<&DrawCall> {
+        self.calls
+            .iter()
+            .filter(|c| c.kind() == kind)
+            .filter(|c| {
+                let (ul, br) = c.bounds();
+                ul.0 >= upper_left.0
+                    && ul.1 >= upper_left.1
+                    && br.0 <= bottom_right.0
+                    && br.1 <= bottom_right.1
+            })
+            .collect()
+    }
+
+    /// Get the recorded texts in the order they were drawn
+    pub fn texts(&self) -> Vec<&str> {
+        self.calls
+            .iter()
+            .filter_map(|c| match c {
+                DrawCall::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Clear the log
+    pub fn clear(&mut self) {
+        self.calls.clear();
+    }
+}
+
+impl BackendFeatures for RecordingBackend {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::all().raster(false)
+    }
+}
+
+impl DrawingBackend for RecordingBackend {
+    type ErrorType = DummyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        pos: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.calls.push(DrawCall::Pixel {
+            pos,
+            color: to_rgba(color),
+        });
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.calls.push(DrawCall::Line {
+            from,
+            to,
+            color: to_rgba(style.color()),
+            width: style.stroke_width(),
+        });
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.calls.push(DrawCall::Rect {
+            upper_left,
+            bottom_right,
+            color: to_rgba(style.color()),
+            width: style.stroke_width(),
+            filled: fill,
+        });
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.calls.push(DrawCall::Path {
+            points: path.into_iter().collect(),
+            color: to_rgba(style.color()),
+            width: style.stroke_width(),
+        });
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.calls.push(DrawCall::Circle {
+            center,
+            radius,
+            color: to_rgba(style.color()),
+            width: style.stroke_width(),
+            filled: fill,
+        });
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.calls.push(DrawCall::Polygon {
+            points: vert.into_iter().collect(),
+            color: to_rgba(style.color()),
+        });
+        Ok(())
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.calls.push(DrawCall::Text {
+            text: text.to_string(),
+            pos,
+            family: style.family().as_str().to_string(),
+            size: style.size(),
+            color: to_rgba(style.color()),
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_recording_backend() {
+        let backend = Rc::new(RefCell::new(RecordingBackend::new((100, 100))));
+        let root: DrawingArea<_, _> = (&backend).into();
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        chart
+            .draw_series((1..4).map(|x| Rectangle::new([(x, 0), (x + 1, x)], RED.filled())))
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(Text::new(
+                "peak",
+                (3, 3),
+                ("sans-serif", 10),
+            )))
+            .unwrap();
+
+        let backend = backend.borrow();
+        let rects = backend.calls_of(DrawCallKind::Rect);
+        assert_eq!(rects.len(), 3);
+        assert!(rects.iter().all(|r| r.color() == RED.to_rgba()));
+        // Only the first bar is inside the left fifth of the plot
+        assert_eq!(
+            backend
+                .calls_within(DrawCallKind::Rect, (0, 0), (20, 99))
+                .len(),
+            1
+        );
+        assert_eq!(backend.texts(), vec!["peak"]);
+
+        let circle = DrawCall::Circle {
+            center: (10, 20),
+            radius: 5,
+            color: BLACK.to_rgba(),
+            width: 1,
+            filled: false,
+        };
+        assert_eq!(circle.bounds(), ((5, 15), (15, 25)));
+    }
+}
@@ -0,0 +1,410 @@
+use std::cell::RefCell;
+
+use crate::drawing::{AccessibleBackend, BackendCapabilities, BackendFeatures};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The non-fatal issue that a [ResilientBackend](struct.ResilientBackend.html) runs into, which
+/// is collected instead of aborting the drawing
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawingWarning {
+    /// The text couldn't be drawn with its font, thus it's skipped
+    Text {
+        /// The text that is skipped
+        text: String,
+        /// The description of the font error
+        message: String,
+    },
+    /// The size of the text couldn't be estimated with its font, thus it's estimated from the
+    /// font size and the number of the characters
+    TextSize {
+        /// The text that is estimated
+        text: String,
+        /// The description of the font error
+        message: String,
+    },
+    /// The primitive is entirely outside of the backend, thus it's skipped
+    OutOfBounds {
+        /// The kind of the primitive, such as `"rect"`
+        primitive: &'static str,
+        /// The upper left and the bottom right corners of the bounding box of the primitive
+        bounds: (BackendCoord, BackendCoord),
+    },
+}
+
+impl std::fmt::Display for DrawingWarning {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DrawingWarning::Text { text, message } => {
+                write!(fmt, "Text {:?} is skipped: {}", text, message)
+            }
+            DrawingWarning::TextSize { text, message } => {
+                write!(fmt, "The size of text {:?} is estimated: {}", text, message)
+            }
+            DrawingWarning::OutOfBounds { primitive, bounds } => write!(
+                fmt,
+                "The {} from {:?} to {:?} is out of bounds",
+                primitive, bounds.0, bounds.1
+            ),
+        }
+    }
+}
+
+/// The bounding box of the points
+fn bounds<I: IntoIterator<Item = BackendCoord>>(points: I) -> Option<(BackendCoord, BackendCoord)> {
+    points.into_iter().fold(None, |acc, (x, y)| match acc {
+        None => Some(((x, y), (x, y))),
+        Some(((x0, y0), (x1, y1))) => Some(((x0.min(x), y0.min(y)), (x1.max(x), y1.max(y)))),
+    })
+}
+
+/// The backend wrapper that turns the non-fatal issues of the wrapped backend into the
+/// warnings, so that a batch of charts can be completed and the problems can be logged,
+/// instead of aborting a whole chart because of a single label.
+///
+/// The text that can't be drawn with its font is skipped, the size of the text that can't be
+/// estimated is estimated from the font size, and the primitives that are entirely outside of
+/// the backend are skipped, each with a warning. The other errors of the wrapped backend, such
+/// as failing to write the file, are still returned. The warnings are retrieved from the drawing
+/// area with [DrawingArea::warnings](struct.DrawingArea.html#method.warnings), for example after
+/// the drawing is presented.
+///
+/// ```rust
+/// use plotters::drawing::ResilientBackend;
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = ResilientBackend::new(SVGBackend::with_string(&mut content, (320, 240)))
+///     .into_drawing_area();
+/// root.draw(&Rectangle::new([(400, 10), (500, 20)], RED.filled()))
+///     .unwrap();
+/// root.present().unwrap();
+/// let warnings = root.warnings();
+/// assert_eq!(warnings.len(), 1);
+/// assert_eq!(
+///     warnings[0].to_string(),
+///     "The rect from (320, 10) to (320, 20) is out of bounds"
+/// );
+/// ```
+pub struct ResilientBackend<DB: DrawingBackend> {
+    inner: DB,
+    // The size estimation doesn't borrow the backend mutably, but it warns as well
+    warnings: RefCell<Vec<DrawingWarning>>,
+}
+
+impl<DB: DrawingBackend> ResilientBackend<DB> {
+    /// Wrap the backend to collect the non-fatal issues as the warnings
+    pub fn new(inner: DB) -> Self {
+        Self {
+            inner,
+            warnings: RefCell::new(vec![]),
+        }
+    }
+
+    /// Get the warnings collected so far
+    pub fn warnings(&self) -> Vec<DrawingWarning> {
+        self.warnings.borrow().clone()
+    }
+
+    fn warn(&self, warning: DrawingWarning) {
+        self.warnings.borrow_mut().push(warning);
+    }
+
+    /// Get the wrapped backend back
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    /// Check if the primitive is on the backend, otherwise warn about it
+    /// - `primitive`: The kind of the primitive
+    /// - `bounds`: The bounding box of the primitive
+    /// - `margin`: The pixels that the primitive is drawn beyond its bounding box, such as the
+    ///   width of the line
+    fn in_bounds(
+        &self,
+        primitive: &'static str,
+        bounds: Option<(BackendCoord, BackendCoord)>,
+        margin: i32,
+    ) -> bool {
+        let (w, h) = self.inner.get_size();
+        let (w, h) = (w as i32 + margin, h as i32 + margin);
+        match bounds {
+            Some(((x0, y0), (x1, y1))) if x1 < -margin || y1 < -margin || x0 >= w || y0 >= h => {
+                let bounds = ((x0, y0), (x1, y1));
+                self.warn(DrawingWarning::OutOfBounds { primitive, bounds });
+                false
+            }
+            _ => true,
+        }
+    }
+}
+
+impl<DB: BackendFeatures> BackendFeatures for ResilientBackend<DB> {
+    fn capabilities(&self) -> BackendCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn clip_bounds(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.inner.clip_bounds()
+    }
+}
+
+impl<DB: AccessibleBackend> AccessibleBackend for ResilientBackend<DB> {
+    fn set_description(&mut self, title: &str, desc: &str) {
+        self.inner.set_description(title, desc)
+    }
+
+    fn begin_group(&mut self, role: &str, label: &str) {
+        self.inner.begin_group(role, label)
+    }
+
+    fn end_group(&mut self) {
+        self.inner.end_group()
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for ResilientBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if !self.in_bounds("line", bounds(vec![from, to]), style.stroke_width() as i32) {
+            return Ok(());
+        }
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if !self.in_bounds("rect", bounds(vec![upper_left, bottom_right]), 0) {
+            return Ok(());
+        }
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        if !self.in_bounds(
+            "path",
+            bounds(path.iter().cloned()),
+            style.stroke_width() as i32,
+        ) {
+            return Ok(());
+        }
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let r = radius as i32;
+        let corners = vec![(center.0 - r, center.1 - r), (center.0 + r, center.1 + r)];
+        if !self.in_bounds("circle", bounds(corners), style.stroke_width() as i32) {
+            return Ok(());
+        }
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        if !self.in_bounds("polygon", bounds(vert.iter().cloned()), 0) {
+            return Ok(());
+        }
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self.inner.draw_text(text, style, pos) {
+            Err(DrawingErrorKind::FontError(e)) => {
+                self.warn(DrawingWarning::Text {
+                    text: text.to_string(),
+                    message: e.to_string(),
+                });
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        match self.inner.estimate_text_size(text, style) {
+            Err(DrawingErrorKind::FontError(e)) => {
+                self.warn(DrawingWarning::TextSize {
+                    text: text.to_string(),
+                    message: e.to_string(),
+                });
+                // The average character is a bit wider than half of the font size
+                let size = style.size();
+                let width = size * 0.6 * text.chars().count() as f64;
+                Ok((width.round() as u32, size.round() as u32))
+            }
+            result => result,
+        }
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.blit_bitmap(pos, (iw, ih), src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+    use plotters_backend::FontFamily;
+
+    #[derive(Debug)]
+    struct BrokenFont;
+
+    impl std::fmt::Display for BrokenFont {
+        fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(fmt, "broken font")
+        }
+    }
+
+    impl std::error::Error for BrokenFont {}
+
+    /// The text style whose font can never be loaded
+    struct BrokenStyle;
+
+    impl BackendTextStyle for BrokenStyle {
+        type FontError = BrokenFont;
+
+        fn size(&self) -> f64 {
+            10.0
+        }
+
+        fn family(&self) -> FontFamily<'_> {
+            FontFamily::SansSerif
+        }
+
+        fn layout_box(&self, _: &str) -> Result<((i32, i32), (i32, i32)), BrokenFont> {
+            Err(BrokenFont)
+        }
+
+        fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+            &self,
+            _: &str,
+            _: BackendCoord,
+            _: DrawFunc,
+        ) -> Result<Result<(), E>, BrokenFont> {
+            Err(BrokenFont)
+        }
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let mut inner = MockedBackend::new(100, 100);
+        inner.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert_eq!(b.num_draw_line_call, 1);
+            assert_eq!(b.num_draw_circle_call, 0);
+        });
+        let mut backend = ResilientBackend::new(inner);
+        backend.draw_rect((50, 50), (150, 150), &RED, true).unwrap();
+        backend.draw_rect((100, 0), (150, 50), &RED, true).unwrap();
+        // The thick line is partly on the backend
+        backend
+            .draw_line((-2, 0), (-2, 50), &ShapeStyle::from(&RED).stroke_width(4))
+            .unwrap();
+        backend.draw_circle((-20, 50), 10, &RED, true).unwrap();
+        assert_eq!(
+            backend.warnings(),
+            vec![
+                DrawingWarning::OutOfBounds {
+                    primitive: "rect",
+                    bounds: ((100, 0), (150, 50)),
+                },
+                DrawingWarning::OutOfBounds {
+                    primitive: "circle",
+                    bounds: ((-30, 40), (-10, 60)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_size_warning() {
+        let backend = ResilientBackend::new(MockedBackend::new(100, 100));
+        let size = backend.estimate_text_size("abcde", &BrokenStyle).unwrap();
+        assert_eq!(size, (30, 10));
+        let warnings = backend.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "The size of text \"abcde\" is estimated: broken font"
+        );
+    }
+
+    #[cfg(feature = "bitmap_backend")]
+    #[test]
+    fn test_text_warning() {
+        let mut buffer = vec![0; 30 * 20 * 3];
+        let mut backend = ResilientBackend::new(BitMapBackend::with_buffer(&mut buffer, (30, 20)));
+        backend.draw_text("label", &BrokenStyle, (0, 0)).unwrap();
+        assert_eq!(
+            backend.warnings(),
+            vec![DrawingWarning::Text {
+                text: "label".to_string(),
+                message: "broken font".to_string(),
+            }]
+        );
+    }
+}
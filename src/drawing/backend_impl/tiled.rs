@@ -0,0 +1,300 @@
+use std::error::Error;
+
+use crate::coord::Shift;
+use crate::drawing::{BackendFeatures, DrawingArea, IntoDrawingArea};
+
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+use plotters_bitmap::{BitMapBackend, BitMapBackendError};
+
+/// The backend of a band of a [TiledBitMap](struct.TiledBitMap.html), which has the size of the
+/// whole canvas but only keeps the pixels of the band. The draw calls are translated to the band
+/// and the pixels out of the band are clipped.
+pub struct TileBackend<'a> {
+    inner: BitMapBackend<'a>,
+    size: (u32, u32),
+    top: i32,
+    height: i32,
+}
+
+impl<'a> TileBackend<'a> {
+    fn translate(&self, (x, y): BackendCoord) -> BackendCoord {
+        (x, y - self.top)
+    }
+
+    /// If the rows from `y0` to `y1` in the canvas overlap the band
+    fn overlaps(&self, y0: i32, y1: i32) -> bool {
+        y0.min(y1) < self.top + self.height && y0.max(y1) >= self.top
+    }
+}
+
+impl<'a> BackendFeatures for TileBackend<'a> {}
+
+impl<'a> DrawingBackend for TileBackend<'a> {
+    type ErrorType = BitMapBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if !self.overlaps(point.1, point.1) {
+            return Ok(());
+        }
+        let point = self.translate(point);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let width = style.stroke_width() as i32;
+        if !self.overlaps(from.1.min(to.1) - width, from.1.max(to.1) + width) {
+            return Ok(());
+        }
+        let (from, to) = (self.translate(from), self.translate(to));
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let width = style.stroke_width() as i32;
+        if !self.overlaps(upper_left.1 - width, bottom_right.1 + width) {
+            return Ok(());
+        }
+        let (upper_left, bottom_right) = (self.translate(upper_left), self.translate(bottom_right));
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let top = self.top;
+        self.inner
+            .draw_path(path.into_iter().map(|(x, y)| (x, y - top)), style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let reach = radius as i32 + style.stroke_width() as i32;
+        if !self.overlaps(center.1 - reach, center.1 + reach) {
+            return Ok(());
+        }
+        let center = self.translate(center);
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let top = self.top;
+        self.inner
+            .fill_polygon(vert.into_iter().map(|(x, y)| (x, y - top)), style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let pos = self.translate(pos);
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<BitMapBackendError>> {
+        self.inner.estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if !self.overlaps(pos.1, pos.1 + ih as i32) {
+            return Ok(());
+        }
+        let pos = self.translate(pos);
+        self.inner.blit_bitmap(pos, (iw, ih), src)
+    }
+}
+
+/// The huge bitmap, such as a poster, which is rendered band by band with bounded memory.
+///
+/// Rather than allocating the RGB buffer of the whole canvas, the drawing function is called
+/// once for each band of rows, on a [TileBackend](struct.TileBackend.html) that clips the
+/// drawing to the band. Thus the memory is the width times the band height, and the drawing
+/// function is expected to draw the same thing each time it's called.
+///
+/// ```rust,no_run
+/// use plotters::drawing::TiledBitMap;
+/// use plotters::prelude::*;
+///
+/// TiledBitMap::new((30000, 20000))
+///     .band_height(256)
+///     .render_png("poster.png", |root| {
+///         root.fill(&WHITE)?;
+///         let mut chart = ChartBuilder::on(&root)
+///             .caption("Poster", ("sans-serif", 400))
+///             .build_cartesian_2d(0.0..10.0, -1.0..1.0)?;
+///         chart.draw_series(LineSeries::new(
+///             (0..10000).map(|x| (x as f64 / 1000.0, (x as f64 / 1000.0).sin())),
+///             BLUE.stroke_width(20),
+///         ))?;
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+pub struct TiledBitMap {
+    size: (u32, u32),
+    band_height: u32,
+}
+
+impl TiledBitMap {
+    /// Create a new tiled bitmap
+    /// - `size`: The size of the whole canvas in pixels
+    /// - **returns** The newly created tiled bitmap, with the bands of 256 rows
+    pub fn new(size: (u32, u32)) -> Self {
+        Self {
+            size,
+            band_height: 256,
+        }
+    }
+
+    /// Set the number of the rows in a band, which is at least 1
+    pub fn band_height(mut self, band_height: u32) -> Self {
+        self.band_height = band_height.max(1);
+        self
+    }
+
+    /// Render the canvas band by band
+    /// - `draw`: The drawing function, which is called once for each band
+    /// - `sink`: The function that receives the index of the first row of each band and its RGB
+    ///   pixels, from the top band to the bottom one
+    pub fn render<Draw, Sink>(&self, mut draw: Draw, mut sink: Sink) -> Result<(), Box<dyn Error>>
+    where
+        Draw: FnMut(DrawingArea<TileBackend, Shift>) -> Result<(), Box<dyn Error>>,
+        Sink: FnMut(u32, &[u8]) -> Result<(), Box<dyn Error>>,
+    {
+        let (w, h) = self.size;
+        // The sizes are computed in usize, since a wide canvas with tall bands overflows u32
+        let row_bytes = w as usize * 3;
+        let mut buffer = vec![0; row_bytes * self.band_height.min(h) as usize];
+        let mut top = 0;
+        while top < h {
+            let height = self.band_height.min(h - top);
+            let band = &mut buffer[..row_bytes * height as usize];
+            for byte in band.iter_mut() {
+                *byte = 0;
+            }
+            {
+                let backend = TileBackend {
+                    inner: BitMapBackend::with_buffer(band, (w, height)),
+                    size: self.size,
+                    top: top as i32,
+                    height: height as i32,
+                };
+                draw(backend.into_drawing_area())?;
+            }
+            sink(top, &buffer[..row_bytes * height as usize])?;
+            top += height;
+        }
+        Ok(())
+    }
+
+    /// Render the canvas to a PNG file, which is written band by band as well
+    /// - `path`: The path of the PNG file
+    /// - `draw`: The drawing function, which is called once for each band
+    pub fn render_png<P, Draw>(&self, path: P, draw: Draw) -> Result<(), Box<dyn Error>>
+    where
+        P: AsRef<std::path::Path>,
+        Draw: FnMut(DrawingArea<TileBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    {
+        use std::io::Write;
+
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        let mut encoder = png::Encoder::new(file, self.size.0, self.size.1);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut stream = encoder.write_header()?.into_stream_writer()?;
+        self.render(draw, |_, band| Ok(stream.write_all(band)?))?;
+        stream.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn draw<DB: DrawingBackend>(root: DrawingArea<DB, Shift>) -> Result<(), Box<dyn Error>>
+    where
+        DB::ErrorType: 'static,
+    {
+        root.fill(&WHITE)?;
+        root.draw(&Rectangle::new([(2, 3), (7, 12)], RED.filled()))?;
+        root.draw(&PathElement::new(vec![(0, 15), (19, 15)], BLUE))?;
+        Ok(())
+    }
+
+    #[test]
+    // The closure makes the drawing function general over the lifetime of the band
+    #[allow(clippy::redundant_closure)]
+    fn test_tiled_bitmap() {
+        let mut expected = vec![0; 20 * 20 * 3];
+        draw(BitMapBackend::with_buffer(&mut expected, (20, 20)).into_drawing_area()).unwrap();
+
+        let mut tiled = vec![];
+        let mut tops = vec![];
+        TiledBitMap::new((20, 20))
+            .band_height(6)
+            .render(
+                |root| draw(root),
+                |top, band| {
+                    tops.push(top);
+                    tiled.extend_from_slice(band);
+                    Ok(())
+                },
+            )
+            .unwrap();
+
+        assert_eq!(tops, vec![0, 6, 12, 18]);
+        assert!(tiled == expected);
+    }
+}
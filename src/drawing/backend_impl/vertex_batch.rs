@@ -0,0 +1,308 @@
+use crate::drawing::{BackendCapabilities, BackendFeatures};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+
+use super::DummyBackendError;
+
+/// A vertex of the triangles made by a [VertexBatchBackend](struct.VertexBatchBackend.html).
+/// The layout is fixed, thus a vertex buffer of 24 bytes per vertex is described with the
+/// position as two `f32` at the offset 0 and the color as four `f32` at the offset 8.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vertex {
+    /// The position in the normalized device coordinate, where (-1, -1) is the bottom left
+    /// corner and (1, 1) is the top right corner of the drawing
+    pub position: [f32; 2],
+    /// The RGBA color, with each channel in 0 to 1
+    pub color: [f32; 4],
+}
+
+/// The backend that turns the draw calls into a batch of triangles, which is uploaded to a
+/// vertex buffer and drawn in a single GPU render pass.
+///
+/// The backend itself doesn't depend on any GPU API, it only produces the vertices. With the
+/// `wgpu_backend` feature, the [WgpuRenderer](struct.WgpuRenderer.html) uploads the vertices
+/// and draws them in a wgpu render pass. With the other GPU APIs, creating the buffer, the
+/// pipeline and the render pass is left to the application.
+///
+/// The lines, the rectangles and the circles are tessellated to the triangles directly, thus
+/// drawing millions of points costs a few vertices for each point, rather than rasterizing
+/// them on the CPU. The other shapes fall back to the default implementation in terms of lines.
+/// Each `present()` completes a frame, which is taken by
+/// [VertexBatchBackend::take_frame](#method.take_frame), thus the backend is shared with the
+/// drawing area to read the frames.
+///
+/// ```rust
+/// use plotters::drawing::{DrawingArea, VertexBatchBackend};
+/// use plotters::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let backend = Rc::new(RefCell::new(VertexBatchBackend::new((640, 480))));
+/// let root: DrawingArea<_, _> = (&backend).into();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+///     .unwrap();
+/// chart
+///     .draw_series((0..1000).map(|i| {
+///         let x = i as f64 / 1000.0;
+///         Circle::new((x, x * x), 2, BLUE.filled())
+///     }))
+///     .unwrap();
+/// root.present().unwrap();
+///
+/// let vertices = backend.borrow_mut().take_frame();
+/// assert!(!vertices.is_empty());
+/// ```
+pub struct VertexBatchBackend {
+    size: (u32, u32),
+    pending: Vec<Vertex>,
+    frame: Vec<Vertex>,
+}
+
+impl VertexBatchBackend {
+    /// Create a new backend
+    /// - `size`: The size of the drawing in pixels
+    /// - **returns** The newly created backend
+    pub fn new(size: (u32, u32)) -> Self {
+        Self {
+            size,
+            pending: vec![],
+            frame: vec![],
+        }
+    }
+
+    /// Get the triangles of the last presented frame, three vertices for each triangle
+    pub fn frame(&self) -> &[Vertex] {
+        &self.frame
+    }
+
+    /// Take the triangles of the last presented frame out, leaving the frame empty
+    pub fn take_frame(&mut self) -> Vec<Vertex> {
+        let mut frame = vec![];
+        std::mem::swap(&mut frame, &mut self.frame);
+        frame
+    }
+
+    fn vertex(&self, (x, y): (f64, f64), color: [f32; 4]) -> Vertex {
+        let (w, h) = (f64::from(self.size.0), f64::from(self.size.1));
+        Vertex {
+            position: [(x * 2.0 / w - 1.0) as f32, (1.0 - y * 2.0 / h) as f32],
+            color,
+        }
+    }
+
+    fn push_triangle(&mut self, points: [(f64, f64); 3], color: [f32; 4]) {
+        for point in points.iter() {
+            let vertex = self.vertex(*point, color);
+            self.pending.push(vertex);
+        }
+    }
+
+    fn push_quad(&mut self, points: [(f64, f64); 4], color: [f32; 4]) {
+        self.push_triangle([points[0], points[1], points[2]], color);
+        self.push_triangle([points[0], points[2], points[3]], color);
+    }
+
+    /// The segment is a quad whose sides are half of the width away from the center line
+    fn push_segment(&mut self, from: BackendCoord, to: BackendCoord, width: u32, color: [f32; 4]) {
+        let (x0, y0) = (f64::from(from.0) + 0.5, f64::from(from.1) + 0.5);
+        let (x1, y1) = (f64::from(to.0) + 0.5, f64::from(to.1) + 0.5);
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        let len = (dx * dx + dy * dy).sqrt();
+        let half = f64::from(width.max(1)) / 2.0;
+        let (nx, ny) = if len > 0.0 {
+            (-dy / len * half, dx / len * half)
+        } else {
+            (0.0, half)
+        };
+        // The zero-length segment is still drawn as a dot
+        let (ex, ey) = if len > 0.0 { (0.0, 0.0) } else { (half, 0.0) };
+        self.push_quad(
+            [
+                (x0 + nx - ex, y0 + ny - ey),
+                (x1 + nx + ex, y1 + ny + ey),
+                (x1 - nx + ex, y1 - ny + ey),
+                (x0 - nx - ex, y0 - ny - ey),
+            ],
+            color,
+        );
+    }
+}
+
+fn to_color(color: BackendColor) -> [f32; 4] {
+    let (r, g, b) = color.rgb;
+    [
+        f32::from(r) / 255.0,
+        f32::from(g) / 255.0,
+        f32::from(b) / 255.0,
+        color.alpha as f32,
+    ]
+}
+
+/// The number of the sides of the polygon that approximates a circle of the radius
+fn circle_sides(radius: u32) -> usize {
+    (radius.min(64) as usize * 2).max(8)
+}
+
+impl BackendFeatures for VertexBatchBackend {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::all().raster(false)
+    }
+}
+
+impl DrawingBackend for VertexBatchBackend {
+    type ErrorType = DummyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        // The buffer of the previous frame is reused by the next one
+        std::mem::swap(&mut self.frame, &mut self.pending);
+        self.pending.clear();
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        (x, y): BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        if color.alpha > 0.0 {
+            let (x, y) = (f64::from(x), f64::from(y));
+            self.push_quad(
+                [(x, y), (x + 1.0, y), (x + 1.0, y + 1.0), (x, y + 1.0)],
+                to_color(color),
+            );
+        }
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        if style.color().alpha > 0.0 {
+            self.push_segment(from, to, style.stroke_width(), to_color(style.color()));
+        }
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        (x0, y0): BackendCoord,
+        (x1, y1): BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        if !fill {
+            let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)];
+            for side in corners.windows(2) {
+                self.draw_line(side[0], side[1], style)?;
+            }
+            return Ok(());
+        }
+        let (l, t) = (f64::from(x0.min(x1)), f64::from(y0.min(y1)));
+        let (r, b) = (f64::from(x0.max(x1)) + 1.0, f64::from(y0.max(y1)) + 1.0);
+        self.push_quad([(l, t), (r, t), (r, b), (l, b)], to_color(style.color()));
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        let mut begin: Option<BackendCoord> = None;
+        for end in path {
+            if let Some(begin) = begin {
+                self.draw_line(begin, end, style)?;
+            }
+            begin = Some(end);
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        (x, y): BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let color = to_color(style.color());
+        let (cx, cy) = (f64::from(x) + 0.5, f64::from(y) + 0.5);
+        let sides = circle_sides(radius);
+        let point = |idx: usize, r: f64| {
+            let angle = std::f64::consts::PI * 2.0 * idx as f64 / sides as f64;
+            (cx + r * angle.cos(), cy + r * angle.sin())
+        };
+        let outer = f64::from(radius);
+        let inner = (outer - f64::from(style.stroke_width())).max(0.0);
+        for idx in 0..sides {
+            if fill {
+                self.push_triangle([(cx, cy), point(idx, outer), point(idx + 1, outer)], color);
+            } else {
+                self.push_quad(
+                    [
+                        point(idx, outer),
+                        point(idx + 1, outer),
+                        point(idx + 1, inner),
+                        point(idx, inner),
+                    ],
+                    color,
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_vertex_batch_backend() {
+        let mut backend = VertexBatchBackend::new((100, 50));
+        backend.draw_rect((0, 0), (99, 49), &RED, true).unwrap();
+        backend.draw_line((10, 10), (20, 10), &BLUE).unwrap();
+        backend.draw_circle((50, 25), 10, &GREEN, true).unwrap();
+        backend
+            .draw_path(vec![(0, 0), (5, 5), (10, 0)], &BLACK)
+            .unwrap();
+        assert!(backend.frame().is_empty());
+        backend.present().unwrap();
+
+        let frame = backend.take_frame();
+        assert_eq!(frame.len(), 6 + 6 + circle_sides(10) * 3 + 2 * 6);
+        assert!(backend.frame().is_empty());
+        // The filled rectangle covers the whole drawing
+        assert_eq!(frame[0].position, [-1.0, 1.0]);
+        assert_eq!(frame[2].position, [1.0, -1.0]);
+        assert_eq!(frame[0].color, [1.0, 0.0, 0.0, 1.0]);
+        // The horizontal line is one pixel thick
+        let ys: Vec<_> = frame[6..12].iter().map(|v| v.position[1]).collect();
+        let thickness = ys.iter().cloned().fold(f32::MIN, f32::max)
+            - ys.iter().cloned().fold(f32::MAX, f32::min);
+        assert!((thickness - 2.0 / 50.0).abs() < 1e-6);
+        // The documented layout of the vertex buffer
+        assert_eq!(std::mem::size_of::<Vertex>(), 24);
+    }
+}
@@ -0,0 +1,223 @@
+use std::mem::{size_of, size_of_val};
+
+use super::Vertex;
+
+const SHADER: &str = r#"
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>, @location(1) color: vec4<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(position, 0.0, 1.0);
+    out.color = color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
+"#;
+
+const ATTRIBUTES: [wgpu::VertexAttribute; 2] = [
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x2,
+        offset: 0,
+        shader_location: 0,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x4,
+        offset: 8,
+        shader_location: 1,
+    },
+];
+
+/// The bytes of the vertices in the layout of the vertex buffer
+fn vertex_bytes(vertices: &[Vertex]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(size_of_val(vertices));
+    for vertex in vertices {
+        for value in vertex.position.iter().chain(vertex.color.iter()) {
+            bytes.extend_from_slice(&value.to_ne_bytes());
+        }
+    }
+    bytes
+}
+
+/// The wgpu renderer of the triangles made by a
+/// [VertexBatchBackend](struct.VertexBatchBackend.html), which is enabled by the `wgpu_backend`
+/// feature.
+///
+/// The renderer owns the render pipeline and a vertex buffer, which grows to the largest frame
+/// that has been uploaded. Each frame is uploaded with
+/// [upload](#method.upload) and drawn with a single draw call, either in a render pass of the
+/// application with [draw](#method.draw), or in a render pass of its own with
+/// [render](#method.render). The colors are blended with the alpha blending.
+///
+/// ```rust,no_run
+/// use plotters::drawing::{DrawingArea, VertexBatchBackend, WgpuRenderer};
+/// use plotters::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// # fn frame(device: &wgpu::Device, queue: &wgpu::Queue, view: &wgpu::TextureView) {
+/// let backend = Rc::new(RefCell::new(VertexBatchBackend::new((640, 480))));
+/// let root: DrawingArea<_, _> = (&backend).into();
+/// root.fill(&WHITE).unwrap();
+/// root.draw(&Circle::new((320, 240), 100, BLUE.filled()))
+///     .unwrap();
+/// root.present().unwrap();
+///
+/// let mut renderer = WgpuRenderer::new(device, wgpu::TextureFormat::Bgra8UnormSrgb);
+/// renderer.upload(device, queue, backend.borrow().frame());
+/// renderer.render(device, queue, view, Some(wgpu::Color::WHITE));
+/// # }
+/// ```
+pub struct WgpuRenderer {
+    pipeline: wgpu::RenderPipeline,
+    buffer: Option<wgpu::Buffer>,
+    num_vertices: u32,
+}
+
+impl WgpuRenderer {
+    /// Create the renderer that draws to the textures of the format
+    /// - `device`: The device that the pipeline and the vertex buffer are created on
+    /// - `format`: The format of the textures that the renderer draws to
+    /// - **returns** The newly created renderer
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("plotters vertex batch shader"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("plotters vertex batch pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: size_of::<Vertex>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &ATTRIBUTES,
+                }],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+            cache: None,
+        });
+        Self {
+            pipeline,
+            buffer: None,
+            num_vertices: 0,
+        }
+    }
+
+    /// Upload the triangles of a frame to the vertex buffer, the buffer is only recreated when
+    /// the frame doesn't fit in it
+    /// - `device`: The device of the renderer
+    /// - `queue`: The queue that writes the buffer
+    /// - `vertices`: The triangles of the frame, see
+    ///   [VertexBatchBackend::frame](struct.VertexBatchBackend.html#method.frame)
+    pub fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, vertices: &[Vertex]) {
+        let bytes = vertex_bytes(vertices);
+        let fits = self
+            .buffer
+            .as_ref()
+            .is_some_and(|buffer| buffer.size() >= bytes.len() as u64);
+        if !fits && !bytes.is_empty() {
+            self.buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("plotters vertex batch"),
+                size: bytes.len() as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+        }
+        if let (Some(buffer), false) = (&self.buffer, bytes.is_empty()) {
+            queue.write_buffer(buffer, 0, &bytes);
+        }
+        self.num_vertices = vertices.len() as u32;
+    }
+
+    /// Record the draw call of the uploaded frame in the render pass of the application
+    /// - `pass`: The render pass, whose color attachment has the format of the renderer
+    pub fn draw(&self, pass: &mut wgpu::RenderPass<'_>) {
+        if let (Some(buffer), true) = (&self.buffer, self.num_vertices > 0) {
+            let size = u64::from(self.num_vertices) * size_of::<Vertex>() as u64;
+            pass.set_pipeline(&self.pipeline);
+            pass.set_vertex_buffer(0, buffer.slice(..size));
+            pass.draw(0..self.num_vertices, 0..1);
+        }
+    }
+
+    /// Draw the uploaded frame to the texture in a render pass of its own, and submit it
+    /// - `device`: The device of the renderer
+    /// - `queue`: The queue that the commands are submitted to
+    /// - `view`: The texture to draw to
+    /// - `clear`: The color that the texture is cleared with, or `None` to draw over the content
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view: &wgpu::TextureView,
+        clear: Option<wgpu::Color>,
+    ) {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("plotters vertex batch encoder"),
+        });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("plotters vertex batch pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: clear.map_or(wgpu::LoadOp::Load, wgpu::LoadOp::Clear),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            self.draw(&mut pass);
+        }
+        queue.submit(Some(encoder.finish()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vertex_bytes() {
+        let vertices = [Vertex {
+            position: [-1.0, 0.5],
+            color: [1.0, 0.0, 0.0, 0.25],
+        }];
+        let bytes = vertex_bytes(&vertices);
+        assert_eq!(bytes.len(), size_of::<Vertex>());
+        // The offsets match the attributes of the vertex buffer layout
+        assert_eq!(&bytes[4..8], &0.5f32.to_ne_bytes());
+        assert_eq!(
+            &bytes[ATTRIBUTES[1].offset as usize..12],
+            &1.0f32.to_ne_bytes()
+        );
+        assert_eq!(&bytes[20..24], &0.25f32.to_ne_bytes());
+    }
+}
@@ -0,0 +1,486 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::area::AccessibleBackend;
+use crate::style::{RGBColor, WHITE};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+    FontFamily, FontStyle, FontTransform,
+};
+
+/// The drawing features that a backend supports, which the backend describes with
+/// [BackendFeatures](trait.BackendFeatures.html). The drawing areas returned by
+/// [DrawingArea::with_fallbacks](struct.DrawingArea.html#method.with_fallbacks) degrade the
+/// features that the backend lacks, rather than leaving them to the backend:
+///
+/// - Without the transparency, the semi-transparent colors are blended with the background
+///   into the opaque colors. The fully transparent colors are kept, as the backends skip them.
+/// - Without the gradients, a gradient fill, which is drawn as many thin polygons, is drawn as
+///   a single polygon with the color at the middle of the gradient.
+/// - Without the paths, a path is drawn as the separate lines between its points.
+///
+/// The dash patterns, the joins and the caps of the lines are always drawn by the elements.
+///
+/// The `raster` capability tells the backends that draw the pixels of an image from the vector
+/// backends, which the groups of
+/// [DrawingArea::draw_composited](struct.DrawingArea.html#method.draw_composited) are drawn
+/// directly onto, rather than rasterized into the pixels.
+///
+/// ```rust
+/// use plotters::drawing::{BackendCapabilities, BackendFeatures};
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let backend = SVGBackend::with_string(&mut content, (320, 240));
+/// assert_eq!(backend.capabilities(), BackendCapabilities::all().raster(false));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackendCapabilities {
+    /// If the backend composites the semi-transparent colors
+    pub transparency: bool,
+    /// If the gradient fills are drawn with their color bands
+    pub gradients: bool,
+    /// If the paths are drawn with `DrawingBackend::draw_path`
+    pub paths: bool,
+    /// If the backend draws the pixels of an image, such as a bitmap, rather than the shapes
+    /// of a vector document
+    pub raster: bool,
+    /// The color that the semi-transparent colors are blended with, when the backend has no
+    /// transparency
+    pub background: RGBColor,
+}
+
+impl BackendCapabilities {
+    /// The capabilities of the backends that support all the features, which is the default
+    pub fn all() -> Self {
+        Self {
+            transparency: true,
+            gradients: true,
+            paths: true,
+            raster: true,
+            background: WHITE,
+        }
+    }
+
+    /// The capabilities of the simplest backends, such as the plotters and the terminals, which
+    /// support none of the features
+    pub fn minimal() -> Self {
+        Self {
+            transparency: false,
+            gradients: false,
+            paths: false,
+            raster: false,
+            background: WHITE,
+        }
+    }
+
+    /// Set if the backend draws the pixels of an image
+    pub fn raster(mut self, raster: bool) -> Self {
+        self.raster = raster;
+        self
+    }
+
+    /// Set the color that the semi-transparent colors are blended with
+    pub fn background(mut self, color: RGBColor) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Get the color that the backend draws, which is opaque if the backend has no transparency
+    pub(crate) fn resolve(&self, color: BackendColor) -> BackendColor {
+        if self.transparency || color.alpha <= 0.0 || color.alpha >= 1.0 {
+            return color;
+        }
+        let bg = self.background;
+        let blend = |c: u8, b: u8| {
+            (f64::from(c) * color.alpha + f64::from(b) * (1.0 - color.alpha)).round() as u8
+        };
+        BackendColor {
+            rgb: (
+                blend(color.rgb.0, bg.0),
+                blend(color.rgb.1, bg.1),
+                blend(color.rgb.2, bg.2),
+            ),
+            alpha: 1.0,
+        }
+    }
+}
+
+impl Default for BackendCapabilities {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// The backend that describes the drawing features it supports, see
+/// [BackendCapabilities](struct.BackendCapabilities.html).
+///
+/// The backends of Plotters implement it, and so can the custom backends, the default is a
+/// backend that supports all the features. The backends of the other crates can't implement it,
+/// thus they're drawn as the backends that support all the features.
+///
+/// ```rust
+/// use plotters::drawing::{BackendCapabilities, BackendFeatures};
+/// use plotters::prelude::*;
+/// use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+/// # use plotters::drawing::DummyBackendError;
+///
+/// // A pen plotter, which has a single opaque pen and draws the lines one by one
+/// struct Plotter;
+///
+/// impl DrawingBackend for Plotter {
+///     type ErrorType = DummyBackendError;
+///     fn get_size(&self) -> (u32, u32) {
+///         (100, 100)
+///     }
+///     fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+///         Ok(())
+///     }
+///     fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+///         Ok(())
+///     }
+///     fn draw_pixel(
+///         &mut self,
+///         _: BackendCoord,
+///         _: BackendColor,
+///     ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+///         Ok(())
+///     }
+/// }
+///
+/// impl BackendFeatures for Plotter {
+///     fn capabilities(&self) -> BackendCapabilities {
+///         BackendCapabilities::minimal()
+///     }
+/// }
+///
+/// let root = Plotter.into_drawing_area().with_fallbacks();
+/// // The circle is drawn in the opaque light blue
+/// root.draw(&Circle::new((50, 50), 20, BLUE.mix(0.3).filled()))
+///     .unwrap();
+/// ```
+pub trait BackendFeatures: DrawingBackend {
+    /// Get the drawing features that the backend supports
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::all()
+    }
+
+    /// Get the upper left and the bottom right corners of the rectangle that the backend clips
+    /// the drawing to, such as the plotting area of the series drawn by
+    /// [ChartContext::draw_series](../chart/struct.ChartContext.html#method.draw_series)
+    fn clip_bounds(&self) -> Option<(BackendCoord, BackendCoord)> {
+        None
+    }
+}
+
+/// The style whose color is resolved for the backend
+struct ResolvedStyle {
+    color: BackendColor,
+    stroke_width: u32,
+}
+
+impl ResolvedStyle {
+    fn new<S: BackendStyle>(style: &S, capabilities: &BackendCapabilities) -> Self {
+        Self {
+            color: capabilities.resolve(style.color()),
+            stroke_width: style.stroke_width(),
+        }
+    }
+}
+
+impl BackendStyle for ResolvedStyle {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+}
+
+/// The text style whose colors are resolved for the backend
+struct ResolvedText<'a, S> {
+    style: &'a S,
+    capabilities: BackendCapabilities,
+}
+
+impl<'a, S: BackendTextStyle> BackendTextStyle for ResolvedText<'a, S> {
+    type FontError = S::FontError;
+
+    fn color(&self) -> BackendColor {
+        self.capabilities.resolve(self.style.color())
+    }
+
+    fn size(&self) -> f64 {
+        self.style.size()
+    }
+
+    fn transform(&self) -> FontTransform {
+        self.style.transform()
+    }
+
+    fn style(&self) -> FontStyle {
+        self.style.style()
+    }
+
+    fn anchor(&self) -> plotters_backend::text_anchor::Pos {
+        self.style.anchor()
+    }
+
+    fn family(&self) -> FontFamily<'_> {
+        self.style.family()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box(&self, text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        self.style.layout_box(text)
+    }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        mut draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        let capabilities = self.capabilities;
+        self.style.draw(text, pos, |x, y, color| {
+            draw(x, y, capabilities.resolve(color))
+        })
+    }
+}
+
+/// The backend that degrades the drawing features that another backend lacks, which is the
+/// backend of the drawing areas returned by
+/// [DrawingArea::with_fallbacks](struct.DrawingArea.html#method.with_fallbacks).
+///
+/// The colors of all the primitives are resolved with the capabilities of the backend, and the
+/// paths are drawn as separate lines if the backend has no paths. The gradient fills are
+/// degraded by the elements, which are given the capabilities by the drawing area.
+pub struct FallbackBackend<DB: DrawingBackend> {
+    backend: Rc<RefCell<DB>>,
+    capabilities: BackendCapabilities,
+}
+
+impl<DB: DrawingBackend> FallbackBackend<DB> {
+    pub(crate) fn new(backend: Rc<RefCell<DB>>, capabilities: BackendCapabilities) -> Self {
+        Self {
+            backend,
+            capabilities,
+        }
+    }
+}
+
+impl<DB: DrawingBackend> BackendFeatures for FallbackBackend<DB> {
+    fn capabilities(&self) -> BackendCapabilities {
+        self.capabilities
+    }
+}
+
+impl<DB: AccessibleBackend> AccessibleBackend for FallbackBackend<DB> {
+    fn set_description(&mut self, title: &str, desc: &str) {
+        self.backend.borrow_mut().set_description(title, desc)
+    }
+
+    fn begin_group(&mut self, role: &str, label: &str) {
+        self.backend.borrow_mut().begin_group(role, label)
+    }
+
+    fn end_group(&mut self) {
+        self.backend.borrow_mut().end_group()
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for FallbackBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.backend.borrow().get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.backend.borrow_mut().ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.backend.borrow_mut().present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = self.capabilities.resolve(color);
+        self.backend.borrow_mut().draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = ResolvedStyle::new(style, &self.capabilities);
+        self.backend.borrow_mut().draw_line(from, to, &style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = ResolvedStyle::new(style, &self.capabilities);
+        self.backend
+            .borrow_mut()
+            .draw_rect(upper_left, bottom_right, &style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = ResolvedStyle::new(style, &self.capabilities);
+        let mut backend = self.backend.borrow_mut();
+        if self.capabilities.paths {
+            return backend.draw_path(path, &style);
+        }
+        let path: Vec<_> = path.into_iter().collect();
+        for segment in path.windows(2) {
+            backend.draw_line(segment[0], segment[1], &style)?;
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = ResolvedStyle::new(style, &self.capabilities);
+        self.backend
+            .borrow_mut()
+            .draw_circle(center, radius, &style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = ResolvedStyle::new(style, &self.capabilities);
+        self.backend.borrow_mut().fill_polygon(vert, &style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = ResolvedText {
+            style,
+            capabilities: self.capabilities,
+        };
+        self.backend.borrow_mut().draw_text(text, &style, pos)
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.backend.borrow().estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.backend.borrow_mut().blit_bitmap(pos, size, src)
+    }
+}
+
+#[cfg(feature = "bitmap_backend")]
+impl<'a, P: plotters_bitmap::bitmap_pixel::PixelFormat> BackendFeatures
+    for plotters_bitmap::BitMapBackend<'a, P>
+{
+}
+
+#[cfg(feature = "svg_backend")]
+impl<'a> BackendFeatures for plotters_svg::SVGBackend<'a> {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::all().raster(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_resolve_color() {
+        let half = BackendColor {
+            rgb: (0, 0, 255),
+            alpha: 0.5,
+        };
+        assert_eq!(BackendCapabilities::all().resolve(half).alpha, 0.5);
+        let opaque = BackendCapabilities::minimal().resolve(half);
+        assert_eq!((opaque.rgb, opaque.alpha), ((128, 128, 255), 1.0));
+        let opaque = BackendCapabilities::minimal()
+            .background(BLACK)
+            .resolve(half);
+        assert_eq!(opaque.rgb, (0, 0, 128));
+        let invisible = BackendCapabilities::minimal().resolve(TRANSPARENT.to_backend_color());
+        assert_eq!(invisible.alpha, 0.0);
+    }
+
+    #[test]
+    fn test_fallbacks() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.set_capabilities(BackendCapabilities::minimal());
+            m.check_draw_rect(|c, _, _, _, _| {
+                assert_eq!(c.to_rgba(), RGBColor(255, 128, 128).to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c.to_rgba(), RGBColor(128, 0, 128).to_rgba());
+            });
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c.to_rgba(), RGBColor(128, 128, 128).to_rgba());
+                assert_eq!(to.0 - from.0, 10);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                // The gradient is drawn as a single polygon
+                assert_eq!(b.num_fill_polygon_call, 1);
+                // The path is drawn as separate lines
+                assert_eq!(b.num_draw_path_call, 0);
+                assert_eq!(b.num_draw_line_call, 2);
+            });
+        });
+        assert_eq!(drawing_area.capabilities(), BackendCapabilities::all());
+        let root = drawing_area.with_fallbacks();
+        assert_eq!(root.capabilities(), BackendCapabilities::minimal());
+        // The split areas keep the capabilities of the backend
+        let (left, right) = root.split_horizontally(50);
+        assert_eq!(right.capabilities(), BackendCapabilities::minimal());
+        left.draw(&Rectangle::new([(0, 0), (10, 10)], RED.mix(0.5).filled()))
+            .unwrap();
+        let gradient = Gradient::horizontal().stop(0.0, &RED).stop(1.0, &BLUE);
+        right
+            .draw(&Polygon::new(vec![(0, 0), (40, 0), (40, 40)], &BLACK).fill_gradient(gradient))
+            .unwrap();
+        left.draw(&PathElement::new(
+            vec![(0, 50), (10, 60), (20, 50)],
+            BLACK.mix(0.5),
+        ))
+        .unwrap();
+    }
+}
@@ -0,0 +1,619 @@
+/*!
+  The clipping of the drawing to the plotting area while a series is drawn, see
+  [ChartContext::clip](../chart/struct.ChartContext.html#method.clip).
+
+  The backends have no clipping, so the series is drawn onto a [ClipBackend](struct.ClipBackend.html),
+  which cuts every primitive to the clip rectangle before it goes to the backend.
+*/
+use std::cell::RefCell;
+use std::f64::consts::PI;
+use std::rc::Rc;
+
+use super::area::AccessibleBackend;
+use super::capabilities::{BackendCapabilities, BackendFeatures};
+use super::composite::{BlendMarker, BlendMode, GroupPixels};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The rectangle the drawing is clipped to, both corners are included
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ClipRect {
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+}
+
+impl ClipRect {
+    pub(crate) fn new(upper_left: BackendCoord, bottom_right: BackendCoord) -> Self {
+        Self {
+            upper_left,
+            bottom_right,
+        }
+    }
+
+    /// The upper left and the bottom right corners
+    pub(crate) fn corners(&self) -> (BackendCoord, BackendCoord) {
+        (self.upper_left, self.bottom_right)
+    }
+
+    /// The overlap of the two rectangles, which contains no point if they don't overlap
+    pub(crate) fn intersect(&self, other: &ClipRect) -> ClipRect {
+        ClipRect::new(
+            (
+                self.upper_left.0.max(other.upper_left.0),
+                self.upper_left.1.max(other.upper_left.1),
+            ),
+            (
+                self.bottom_right.0.min(other.bottom_right.0),
+                self.bottom_right.1.min(other.bottom_right.1),
+            ),
+        )
+    }
+
+    pub(crate) fn contains(&self, (x, y): BackendCoord) -> bool {
+        self.upper_left.0 <= x
+            && x <= self.bottom_right.0
+            && self.upper_left.1 <= y
+            && y <= self.bottom_right.1
+    }
+
+    /// Shrink the rectangle to the clip rectangle, or `None` if they don't overlap
+    pub(crate) fn clamp_rect(
+        &self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        let a = (
+            upper_left.0.max(self.upper_left.0),
+            upper_left.1.max(self.upper_left.1),
+        );
+        let b = (
+            bottom_right.0.min(self.bottom_right.0),
+            bottom_right.1.min(self.bottom_right.1),
+        );
+        if a.0 > b.0 || a.1 > b.1 {
+            return None;
+        }
+        Some((a, b))
+    }
+
+    /// Clip the segment with the Liang-Barsky algorithm
+    fn clip_segment(
+        &self,
+        a: BackendCoord,
+        b: BackendCoord,
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        if self.contains(a) && self.contains(b) {
+            return Some((a, b));
+        }
+        let (x0, y0) = (f64::from(a.0), f64::from(a.1));
+        let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let (mut t0, mut t1) = (0.0f64, 1.0f64);
+        let edges = [
+            (-dx, x0 - f64::from(self.upper_left.0)),
+            (dx, f64::from(self.bottom_right.0) - x0),
+            (-dy, y0 - f64::from(self.upper_left.1)),
+            (dy, f64::from(self.bottom_right.1) - y0),
+        ];
+        for &(p, q) in edges.iter() {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+            } else if p < 0.0 {
+                t0 = t0.max(q / p);
+            } else {
+                t1 = t1.min(q / p);
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+        let at = |t: f64| ((x0 + dx * t).round() as i32, (y0 + dy * t).round() as i32);
+        Some((at(t0), at(t1)))
+    }
+
+    /// Cut the path into the runs that are inside of the clip rectangle
+    pub(crate) fn clip_path(&self, path: Vec<BackendCoord>) -> Vec<Vec<BackendCoord>> {
+        if path.iter().all(|p| self.contains(*p)) {
+            return vec![path];
+        }
+        let mut runs = vec![];
+        let mut current: Vec<BackendCoord> = vec![];
+        for segment in path.windows(2) {
+            match self.clip_segment(segment[0], segment[1]) {
+                Some((from, to)) => {
+                    if current.last() != Some(&from) {
+                        if current.len() >= 2 {
+                            runs.push(current);
+                        }
+                        current = vec![from];
+                    }
+                    current.push(to);
+                }
+                None => {
+                    if current.len() >= 2 {
+                        runs.push(current);
+                    }
+                    current = vec![];
+                }
+            }
+        }
+        if current.len() >= 2 {
+            runs.push(current);
+        }
+        runs
+    }
+
+    /// Clip the polygon with the Sutherland-Hodgman algorithm
+    pub(crate) fn clip_polygon(&self, polygon: Vec<BackendCoord>) -> Vec<BackendCoord> {
+        if polygon.iter().all(|p| self.contains(*p)) {
+            return polygon;
+        }
+        let (left, top) = (f64::from(self.upper_left.0), f64::from(self.upper_left.1));
+        let (right, bottom) = (
+            f64::from(self.bottom_right.0),
+            f64::from(self.bottom_right.1),
+        );
+        // Each edge is described by the axis it cuts, the bound and whether the inside is
+        // below the bound
+        let edges = [
+            (0, left, false),
+            (0, right, true),
+            (1, top, false),
+            (1, bottom, true),
+        ];
+
+        let mut points: Vec<(f64, f64)> = polygon
+            .iter()
+            .map(|&(x, y)| (f64::from(x), f64::from(y)))
+            .collect();
+        for &(axis, bound, below) in edges.iter() {
+            let value = |p: &(f64, f64)| if axis == 0 { p.0 } else { p.1 };
+            let inside = |p: &(f64, f64)| (value(p) <= bound) == below || value(p) == bound;
+            let mut output = vec![];
+            for (idx, cur) in points.iter().enumerate() {
+                let prev = &points[(idx + points.len() - 1) % points.len()];
+                if inside(cur) != inside(prev) {
+                    let t = (bound - value(prev)) / (value(cur) - value(prev));
+                    output.push((prev.0 + (cur.0 - prev.0) * t, prev.1 + (cur.1 - prev.1) * t));
+                }
+                if inside(cur) {
+                    output.push(*cur);
+                }
+            }
+            points = output;
+        }
+        points
+            .into_iter()
+            .map(|(x, y)| (x.round() as i32, y.round() as i32))
+            .collect()
+    }
+}
+
+/// The vertices of the circle, there are enough of them that the chords stay within half a
+/// pixel of the arc
+fn circle_points((x, y): BackendCoord, radius: u32) -> Vec<BackendCoord> {
+    let count = 8.max(radius as usize * 2);
+    (0..count)
+        .map(|idx| {
+            let angle = 2.0 * PI * idx as f64 / count as f64;
+            (
+                x + (f64::from(radius) * angle.cos()).round() as i32,
+                y + (f64::from(radius) * angle.sin()).round() as i32,
+            )
+        })
+        .collect()
+}
+
+/// The backend that clips the drawing to a rectangle, which is the backend of the series drawn
+/// by [ChartContext::draw_series](../chart/struct.ChartContext.html#method.draw_series).
+///
+/// Every primitive is cut to the clip rectangle: the lines and the paths are cut at the border,
+/// the filled rectangles and the polygons are shrunk to it, the outlines of the rectangles that
+/// cross it are drawn as the paths that are cut, the bitmaps are cropped and the pixels and the
+/// texts are only drawn when they're anchored inside of it. The circles centered inside are
+/// drawn whole, thus the markers of the points at the edge of the range aren't cut in half, and
+/// the circles centered outside are drawn as the paths and the polygons that are cut.
+/// Without a clip rectangle the drawing goes to the backend unchanged.
+///
+/// The series whose elements have a blend mode, see
+/// [ShapeStyle::blend_mode](../style/struct.ShapeStyle.html#method.blend_mode), is blended as a
+/// group. The backends that blend the groups on their own, such as the
+/// [AccessibleSVGBackend](struct.AccessibleSVGBackend.html), get the series as it is. On the
+/// drawing areas that have the `raster` capability, the series is rasterized into the pixels
+/// of a group, like the ones of
+/// [DrawingArea::draw_composited](struct.DrawingArea.html#method.draw_composited), which are
+/// drawn onto the backend when the series is done. Otherwise the blend mode is ignored.
+pub struct ClipBackend<DB: DrawingBackend> {
+    backend: Rc<RefCell<DB>>,
+    clip: Option<ClipRect>,
+    capabilities: BackendCapabilities,
+    blend: Option<BlendGroup<DB>>,
+}
+
+/// The group of the series that is drawn with a blend mode
+enum BlendGroup<DB: DrawingBackend> {
+    /// The backend blends the group on its own
+    Backend,
+    /// The series is rasterized into the pixels of the group
+    Pixels(GroupPixels<DB::ErrorType>),
+}
+
+/// Draw onto the pixels of the blend group if there's one, otherwise onto the backend
+macro_rules! target {
+    ($self:ident, $backend:ident => $draw:expr) => {
+        match $self.blend {
+            Some(BlendGroup::Pixels(ref mut group)) => {
+                let $backend = group;
+                $draw
+            }
+            _ => {
+                let mut backend = $self.backend.borrow_mut();
+                let $backend = &mut *backend;
+                $draw
+            }
+        }
+    };
+}
+
+impl<DB: DrawingBackend> ClipBackend<DB> {
+    pub(crate) fn new(
+        backend: Rc<RefCell<DB>>,
+        clip: Option<ClipRect>,
+        capabilities: BackendCapabilities,
+    ) -> Self {
+        Self {
+            backend,
+            clip,
+            capabilities,
+            blend: None,
+        }
+    }
+
+    /// Start the group of the series that is drawn with the blend mode, see
+    /// [ClipBackend](struct.ClipBackend.html)
+    pub(crate) fn begin_blend(&mut self, blend_mode: BlendMode) {
+        if blend_mode == BlendMode::Normal || self.blend.is_some() {
+            return;
+        }
+        let backend = self.backend.borrow();
+        if BlendMarker::begin(&*backend, blend_mode) {
+            self.blend = Some(BlendGroup::Backend);
+        } else if self.capabilities.raster {
+            let size = backend.get_size();
+            let (upper_left, dim) = match self.clip {
+                Some(clip) => {
+                    let (a, b) = clip.corners();
+                    let dim = ((b.0 - a.0 + 1).max(0), (b.1 - a.1 + 1).max(0));
+                    (a, (dim.0 as u32, dim.1 as u32))
+                }
+                None => ((0, 0), size),
+            };
+            let group = GroupPixels::new(size, upper_left, dim, blend_mode);
+            self.blend = Some(BlendGroup::Pixels(group));
+        }
+    }
+
+    /// End the group of the series, the pixels of the group are drawn onto the backend
+    pub(crate) fn end_blend(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self.blend.take() {
+            Some(BlendGroup::Backend) => {
+                BlendMarker::end(&*self.backend.borrow());
+                Ok(())
+            }
+            Some(BlendGroup::Pixels(group)) => {
+                group.composite(&mut *self.backend.borrow_mut(), 1.0)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Draw the runs of the path that are inside of the clip rectangle
+    fn draw_runs<S: BackendStyle>(
+        &mut self,
+        path: Vec<BackendCoord>,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let runs = match self.clip {
+            Some(clip) => clip.clip_path(path),
+            None => vec![path],
+        };
+        for run in runs {
+            target!(self, backend => backend.draw_path(run, style))?;
+        }
+        Ok(())
+    }
+
+    /// Fill the part of the polygon that is inside of the clip rectangle
+    fn fill_clipped<S: BackendStyle>(
+        &mut self,
+        polygon: Vec<BackendCoord>,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let polygon = match self.clip {
+            Some(clip) => clip.clip_polygon(polygon),
+            None => polygon,
+        };
+        if polygon.len() < 3 {
+            return Ok(());
+        }
+        target!(self, backend => backend.fill_polygon(polygon, style))
+    }
+}
+
+// The clip rectangle is the only thing the backend adds, the capabilities are the ones of the
+// drawing area that the series is drawn on
+impl<DB: DrawingBackend> BackendFeatures for ClipBackend<DB> {
+    fn capabilities(&self) -> BackendCapabilities {
+        self.capabilities
+    }
+
+    fn clip_bounds(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.clip.map(|clip| clip.corners())
+    }
+}
+
+impl<DB: AccessibleBackend> AccessibleBackend for ClipBackend<DB> {
+    fn set_description(&mut self, title: &str, desc: &str) {
+        self.backend.borrow_mut().set_description(title, desc)
+    }
+
+    fn begin_group(&mut self, role: &str, label: &str) {
+        self.backend.borrow_mut().begin_group(role, label)
+    }
+
+    fn end_group(&mut self) {
+        self.backend.borrow_mut().end_group()
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for ClipBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.backend.borrow().get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.backend.borrow_mut().ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.backend.borrow_mut().present()
+    }
+
+    // `Option::is_some_and` is newer than the minimum supported Rust version
+    #[allow(clippy::unnecessary_map_or)]
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.clip.map_or(false, |clip| !clip.contains(point)) {
+            return Ok(());
+        }
+        target!(self, backend => backend.draw_pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let segment = match self.clip {
+            Some(clip) => clip.clip_segment(from, to),
+            None => Some((from, to)),
+        };
+        match segment {
+            Some((from, to)) => target!(self, backend => backend.draw_line(from, to, style)),
+            None => Ok(()),
+        }
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let clip = match self.clip {
+            Some(clip) if !clip.contains(upper_left) || !clip.contains(bottom_right) => clip,
+            _ => {
+                return target!(self, backend => {
+                    backend.draw_rect(upper_left, bottom_right, style, fill)
+                })
+            }
+        };
+        if !fill {
+            let (a, b) = (upper_left, bottom_right);
+            return self.draw_runs(vec![a, (b.0, a.1), b, (a.0, b.1), a], style);
+        }
+        match clip.clamp_rect(upper_left, bottom_right) {
+            Some((a, b)) => target!(self, backend => backend.draw_rect(a, b, style, true)),
+            None => Ok(()),
+        }
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.clip.is_none() {
+            return target!(self, backend => backend.draw_path(path, style));
+        }
+        self.draw_runs(path.into_iter().collect(), style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let r = radius as i32;
+        let (a, b) = ((center.0 - r, center.1 - r), (center.0 + r, center.1 + r));
+        match self.clip {
+            Some(clip) if clip.clamp_rect(a, b).is_none() => Ok(()),
+            // The markers of the points at the edge of the range are drawn whole, like the texts
+            // anchored inside, only the circles from the outside are cut
+            Some(clip) if !clip.contains(center) => {
+                let mut points = circle_points(center, radius);
+                if fill {
+                    return self.fill_clipped(points, style);
+                }
+                points.push(points[0]);
+                self.draw_runs(points, style)
+            }
+            _ => target!(self, backend => backend.draw_circle(center, radius, style, fill)),
+        }
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.clip.is_none() {
+            return target!(self, backend => backend.fill_polygon(vert, style));
+        }
+        self.fill_clipped(vert.into_iter().collect(), style)
+    }
+
+    #[allow(clippy::unnecessary_map_or)]
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.clip.map_or(false, |clip| !clip.contains(pos)) {
+            return Ok(());
+        }
+        target!(self, backend => backend.draw_text(text, style, pos))
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.backend.borrow().estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (w, h): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let end = (pos.0 + w as i32 - 1, pos.1 + h as i32 - 1);
+        let (a, b) = match self.clip {
+            Some(clip) => match clip.clamp_rect(pos, end) {
+                Some(rect) => rect,
+                None => return Ok(()),
+            },
+            None => (pos, end),
+        };
+        if (a, b) == (pos, end) {
+            return target!(self, backend => backend.blit_bitmap(pos, (w, h), src));
+        }
+        // The rows of the part inside are copied out of the RGB pixels
+        let (dx, dy) = ((a.0 - pos.0) as usize, (a.1 - pos.1) as usize);
+        let (cw, ch) = ((b.0 - a.0 + 1) as usize, (b.1 - a.1 + 1) as usize);
+        let mut cropped = Vec::with_capacity(cw * ch * 3);
+        for row in dy..dy + ch {
+            let start = (row * w as usize + dx) * 3;
+            cropped.extend_from_slice(&src[start..start + cw * 3]);
+        }
+        target!(self, backend => backend.blit_bitmap(a, (cw as u32, ch as u32), &cropped))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_clip_shapes() {
+        let clip = ClipRect::new((0, 0), (10, 10));
+        assert_eq!(
+            clip.clip_path(vec![(2, 2), (5, 5)]),
+            vec![vec![(2, 2), (5, 5)]]
+        );
+        // The path leaves the rectangle and comes back
+        assert_eq!(
+            clip.clip_path(vec![(5, 5), (5, 20), (8, 20), (8, 5), (9, 5)]),
+            vec![vec![(5, 5), (5, 10)], vec![(8, 10), (8, 5), (9, 5)]]
+        );
+        assert!(clip.clip_path(vec![(-5, 20), (20, 20)]).is_empty());
+
+        assert_eq!(clip.clamp_rect((-5, 2), (5, 30)), Some(((0, 2), (5, 10))));
+        assert_eq!(clip.clamp_rect((11, 2), (15, 30)), None);
+
+        assert_eq!(
+            clip.clip_polygon(vec![(5, 5), (20, 5), (20, 8), (5, 8)]),
+            vec![(5, 5), (10, 5), (10, 8), (5, 8)]
+        );
+        assert!(clip
+            .clip_polygon(vec![(20, 20), (30, 20), (30, 30)])
+            .is_empty());
+
+        let overlap = clip.intersect(&ClipRect::new((5, -5), (20, 5)));
+        assert_eq!(overlap, ClipRect::new((5, 0), (10, 5)));
+        let apart = clip.intersect(&ClipRect::new((20, 20), (30, 30)));
+        assert!(!apart.contains((10, 10)) && !apart.contains((20, 20)));
+        assert!(apart.clip_path(vec![(0, 0), (30, 30)]).is_empty());
+    }
+
+    #[test]
+    fn test_clip_backend() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(50, 50), (60, 50)]);
+            });
+            m.check_draw_rect(|_, _, fill, upper_left, bottom_right| {
+                assert!(fill);
+                assert_eq!((upper_left, bottom_right), ((50, 50), (60, 55)));
+            });
+            m.check_fill_polygon(|_, path| {
+                assert!(path.iter().all(|&(x, y)| x >= 50 && y >= 40 && x <= 60));
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(60, 45), (55, 45), (55, 40)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 1);
+                // Only the corner of the outline of the rectangle that is inside is drawn
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_draw_text_call, 1);
+                assert_eq!(b.num_draw_pixel_call, 0);
+            });
+        });
+        let area = drawing_area.clipped(Some(ClipRect::new((50, 40), (60, 60))), BlendMode::Normal);
+        area.draw(&PathElement::new(vec![(40, 50), (80, 50)], &RED))
+            .unwrap();
+        area.draw(&Rectangle::new([(40, 50), (80, 55)], RED.filled()))
+            .unwrap();
+        area.draw(&Rectangle::new([(55, 30), (70, 45)], &RED))
+            .unwrap();
+        // The circles centered inside are drawn as they are, even across the border, the one
+        // centered outside is cut
+        area.draw(&Circle::new((55, 50), 3, &RED)).unwrap();
+        area.draw(&Circle::new((60, 45), 5, &RED)).unwrap();
+        area.draw(&Circle::new((62, 50), 5, RED.filled())).unwrap();
+        area.draw(&Circle::new((90, 90), 5, RED.filled())).unwrap();
+        area.draw(&Pixel::new((0, 0), &RED)).unwrap();
+        area.draw(&Text::new("in", (55, 55), ("sans-serif", 10)))
+            .unwrap();
+        area.draw(&Text::new("out", (5, 5), ("sans-serif", 10)))
+            .unwrap();
+    }
+}
@@ -0,0 +1,628 @@
+use std::error::Error;
+use std::marker::PhantomData;
+
+use super::capabilities::{BackendCapabilities, BackendFeatures};
+use super::clip::ClipBackend;
+
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+    FontFamily, FontStyle, FontTransform,
+};
+
+/// The way a group of drawing is composited, see
+/// [DrawingArea::draw_composited](struct.DrawingArea.html#method.draw_composited) and
+/// [ChartContext::draw_series_composited](../chart/struct.ChartContext.html#method.draw_series_composited),
+/// or the way the elements of a series are composited with each other, see
+/// [ShapeStyle::blend_mode](../style/struct.ShapeStyle.html#method.blend_mode)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
+pub enum BlendMode {
+    /// The drawing is drawn over the existing content, with the alpha channel of its colors
+    Normal,
+    /// The colors are added to the existing content, which makes the overlapping area
+    /// brighter. This is useful for density plots with many faint points
+    Additive,
+    /// The colors are multiplied with the existing content, which makes the overlapping area
+    /// darker, like the overlapping inks
+    Multiply,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for BlendMode {
+    fn default() -> Self {
+        BlendMode::Normal
+    }
+}
+
+/// The font family of the blend markers
+const BLEND_MARKER_FAMILY: &str = "plotters-blend-mode";
+/// The blend marker that ends the group
+const BLEND_END_MARKER: &str = "end";
+
+/// The text style that asks the backend to blend the series drawn with a blend mode on its own,
+/// see [ShapeStyle::blend_mode](../style/struct.ShapeStyle.html#method.blend_mode). Like the
+/// metadata markers, it's passed through `DrawingBackend::estimate_text_size`, which is
+/// forwarded by all the wrapper backends. The backends that blend the groups, such as the
+/// `AccessibleSVGBackend`, acknowledge the marker that starts the group with a non-empty size,
+/// and the other backends only measure the empty layout of the style.
+pub(crate) struct BlendMarker;
+
+impl BlendMarker {
+    /// Check if the text style is a blend marker
+    #[cfg_attr(not(feature = "svg_backend"), allow(dead_code))]
+    pub(crate) fn is_marker<S: BackendTextStyle>(style: &S) -> bool {
+        match style.family() {
+            FontFamily::Name(name) => name == BLEND_MARKER_FAMILY,
+            _ => false,
+        }
+    }
+
+    /// Parse the blend mode from the text of a blend marker
+    /// - **returns** The blend mode of the group that starts, or `None` if the group ends
+    #[cfg_attr(not(feature = "svg_backend"), allow(dead_code))]
+    pub(crate) fn from_marker(text: &str) -> Option<BlendMode> {
+        match text {
+            "additive" => Some(BlendMode::Additive),
+            "multiply" => Some(BlendMode::Multiply),
+            "normal" => Some(BlendMode::Normal),
+            _ => None,
+        }
+    }
+
+    /// Ask the backend to start the group of the blend mode
+    /// - **returns** If the backend blends the group on its own
+    pub(crate) fn begin<DB: DrawingBackend>(backend: &DB, blend_mode: BlendMode) -> bool {
+        let text = match blend_mode {
+            BlendMode::Normal => "normal",
+            BlendMode::Additive => "additive",
+            BlendMode::Multiply => "multiply",
+        };
+        match backend.estimate_text_size(text, &BlendMarker) {
+            Ok((w, h)) => w > 0 || h > 0,
+            Err(_) => false,
+        }
+    }
+
+    /// End the group that the backend has acknowledged
+    pub(crate) fn end<DB: DrawingBackend>(backend: &DB) {
+        let _ = backend.estimate_text_size(BLEND_END_MARKER, &BlendMarker);
+    }
+}
+
+impl BackendTextStyle for BlendMarker {
+    type FontError = std::fmt::Error;
+
+    fn family(&self) -> FontFamily<'_> {
+        FontFamily::Name(BLEND_MARKER_FAMILY)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box(&self, _text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        Ok(((0, 0), (0, 0)))
+    }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        _text: &str,
+        _pos: BackendCoord,
+        _draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        Ok(Ok(()))
+    }
+}
+
+/// Composite a premultiplied source pixel onto a premultiplied destination pixel
+fn blend(mode: BlendMode, src: [f64; 4], dst: [f64; 4]) -> [f64; 4] {
+    let (sa, da) = (src[3], dst[3]);
+    let mut result = [0.0; 4];
+    for idx in 0..3 {
+        let (s, d) = (src[idx], dst[idx]);
+        result[idx] = match mode {
+            BlendMode::Normal => s + d * (1.0 - sa),
+            BlendMode::Additive => (s + d).min(1.0),
+            BlendMode::Multiply => s * (1.0 - da) + d * (1.0 - sa) + s * d,
+        };
+    }
+    result[3] = match mode {
+        BlendMode::Additive => (sa + da).min(1.0),
+        _ => sa + da * (1.0 - sa),
+    };
+    result
+}
+
+/// The style whose colors are faded by the opacity of a group
+struct Faded<'a, S> {
+    style: &'a S,
+    opacity: f64,
+}
+
+impl<'a, S> Faded<'a, S> {
+    fn fade(&self, color: BackendColor) -> BackendColor {
+        BackendColor {
+            alpha: color.alpha * self.opacity,
+            rgb: color.rgb,
+        }
+    }
+}
+
+impl<'a, S: BackendStyle> BackendStyle for Faded<'a, S> {
+    fn color(&self) -> BackendColor {
+        self.fade(self.style.color())
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.style.stroke_width()
+    }
+}
+
+impl<'a, S: BackendTextStyle> BackendTextStyle for Faded<'a, S> {
+    type FontError = S::FontError;
+
+    fn color(&self) -> BackendColor {
+        self.fade(self.style.color())
+    }
+
+    fn size(&self) -> f64 {
+        self.style.size()
+    }
+
+    fn transform(&self) -> FontTransform {
+        self.style.transform()
+    }
+
+    fn style(&self) -> FontStyle {
+        self.style.style()
+    }
+
+    fn anchor(&self) -> plotters_backend::text_anchor::Pos {
+        self.style.anchor()
+    }
+
+    fn family(&self) -> FontFamily<'_> {
+        self.style.family()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box(&self, text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        self.style.layout_box(text)
+    }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        mut draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        self.style
+            .draw(text, pos, |x, y, color| draw(x, y, self.fade(color)))
+    }
+}
+
+/// The pixels of a group, where each pixel drawn is composited with the pixel under it, the
+/// shapes are rasterized into the pixels by the default implementations of the backend
+pub(crate) struct GroupPixels<E> {
+    size: (u32, u32),
+    upper_left: BackendCoord,
+    dim: (u32, u32),
+    blend_mode: BlendMode,
+    // The premultiplied RGBA values of the pixels in the 0..1 range
+    pixels: Vec<[f64; 4]>,
+    _phantom: PhantomData<E>,
+}
+
+impl<E> GroupPixels<E> {
+    /// Create the transparent pixels of the given area of the backend
+    /// - `size`: The size of the backend
+    /// - `upper_left`: The upper left corner of the area, the drawing outside is dropped
+    /// - `dim`: The size of the area
+    /// - `blend_mode`: The way the pixels of the group are composited with each other
+    pub(crate) fn new(
+        size: (u32, u32),
+        upper_left: BackendCoord,
+        dim: (u32, u32),
+        blend_mode: BlendMode,
+    ) -> Self {
+        Self {
+            size,
+            upper_left,
+            dim,
+            blend_mode,
+            pixels: vec![[0.0; 4]; dim.0 as usize * dim.1 as usize],
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Draw the pixels onto the backend
+    /// - `backend`: The backend to draw on
+    /// - `opacity`: The opacity of the group, which scales the alpha channel of all the pixels
+    pub(crate) fn composite<DB: DrawingBackend<ErrorType = E>>(
+        &self,
+        backend: &mut DB,
+        opacity: f64,
+    ) -> Result<(), DrawingErrorKind<E>>
+    where
+        E: Error + Send + Sync,
+    {
+        // The channels are never negative, since they are blended from the colors
+        let to_byte = |v: f64| (v * 255.0).round().min(255.0) as u8;
+        for (idx, pixel) in self.pixels.iter().enumerate() {
+            let alpha = pixel[3];
+            if alpha <= 0.0 {
+                continue;
+            }
+            let x = self.upper_left.0 + (idx % self.dim.0 as usize) as i32;
+            let y = self.upper_left.1 + (idx / self.dim.0 as usize) as i32;
+            let color = BackendColor {
+                alpha: alpha * opacity,
+                rgb: (
+                    to_byte(pixel[0] / alpha),
+                    to_byte(pixel[1] / alpha),
+                    to_byte(pixel[2] / alpha),
+                ),
+            };
+            backend.draw_pixel((x, y), color)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Error + Send + Sync> DrawingBackend for GroupPixels<E> {
+    type ErrorType = E;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<E>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<E>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        (x, y): BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<E>> {
+        let (dx, dy) = (x - self.upper_left.0, y - self.upper_left.1);
+        if dx < 0 || dy < 0 || dx >= self.dim.0 as i32 || dy >= self.dim.1 as i32 {
+            return Ok(());
+        }
+        if color.alpha <= 0.0 {
+            return Ok(());
+        }
+        let alpha = color.alpha.min(1.0);
+        let channel = |v: u8| f64::from(v) / 255.0 * alpha;
+        let src = [
+            channel(color.rgb.0),
+            channel(color.rgb.1),
+            channel(color.rgb.2),
+            alpha,
+        ];
+        let idx = dy as usize * self.dim.0 as usize + dx as usize;
+        self.pixels[idx] = blend(self.blend_mode, src, self.pixels[idx]);
+        Ok(())
+    }
+}
+
+enum Group<DB: DrawingBackend> {
+    /// The group is rasterized into its own pixels
+    Pixels(GroupPixels<DB::ErrorType>),
+    /// The group is drawn directly onto the clipped backend with the faded colors
+    Direct {
+        backend: ClipBackend<DB>,
+        opacity: f64,
+    },
+}
+
+/// The backend of the drawing areas that
+/// [DrawingArea::draw_composited](struct.DrawingArea.html#method.draw_composited) draws on.
+///
+/// On the raster backends, which have the `raster` capability, the group is rasterized into its
+/// own pixels. The pixels start transparent, and each pixel drawn is composited with the pixel
+/// under it with the blend mode of the group. When the group is done, its pixels are drawn onto
+/// the backend with the opacity of the group, thus the overlapping parts of an element don't
+/// double-darken like they do when each part is composited with the backend.
+///
+/// On the vector backends, such as the `SVGBackend`, the pixels would become the separate
+/// elements of the document, thus the group is drawn directly onto the backend instead, with
+/// the alpha channel of its colors scaled by the opacity. The blend mode is ignored, the
+/// overlapping parts are composited with each other as usual, and the bitmaps are drawn as
+/// they are.
+pub struct CompositeBackend<DB: DrawingBackend> {
+    size: (u32, u32),
+    blend_mode: BlendMode,
+    group: Group<DB>,
+}
+
+impl<DB: DrawingBackend> CompositeBackend<DB> {
+    /// Create the group that is rasterized into the pixels of the given area of the backend
+    /// - `size`: The size of the backend
+    /// - `upper_left`: The upper left corner of the area, the drawing outside is dropped
+    /// - `dim`: The size of the area
+    /// - `blend_mode`: The way the pixels of the group are composited with each other
+    pub(crate) fn new(
+        size: (u32, u32),
+        upper_left: BackendCoord,
+        dim: (u32, u32),
+        blend_mode: BlendMode,
+    ) -> Self {
+        Self {
+            size,
+            blend_mode,
+            group: Group::Pixels(GroupPixels::new(size, upper_left, dim, blend_mode)),
+        }
+    }
+
+    /// Create the group that is drawn directly onto a vector backend
+    /// - `size`: The size of the backend
+    /// - `backend`: The backend, which is clipped to the area of the group
+    /// - `blend_mode`: The blend mode of the group, which is ignored
+    /// - `opacity`: The opacity of the group, which scales the alpha channel of the colors
+    pub(crate) fn direct(
+        size: (u32, u32),
+        backend: ClipBackend<DB>,
+        blend_mode: BlendMode,
+        opacity: f64,
+    ) -> Self {
+        Self {
+            size,
+            blend_mode,
+            group: Group::Direct { backend, opacity },
+        }
+    }
+
+    /// Get the blend mode of the group
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    /// Draw the pixels of the group onto the backend, the group that is drawn directly is
+    /// already on the backend
+    /// - `backend`: The backend to draw on
+    /// - `opacity`: The opacity of the group, which scales the alpha channel of all the pixels
+    pub(crate) fn composite(
+        &self,
+        backend: &mut DB,
+        opacity: f64,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &self.group {
+            Group::Pixels(group) => group.composite(backend, opacity),
+            Group::Direct { .. } => Ok(()),
+        }
+    }
+}
+
+impl<DB: DrawingBackend> BackendFeatures for CompositeBackend<DB> {
+    fn capabilities(&self) -> BackendCapabilities {
+        match &self.group {
+            // The group composites the colors on its own
+            Group::Pixels(_) => BackendCapabilities::all(),
+            Group::Direct { backend, .. } => backend.capabilities(),
+        }
+    }
+
+    fn clip_bounds(&self) -> Option<(BackendCoord, BackendCoord)> {
+        match &self.group {
+            Group::Pixels(_) => None,
+            Group::Direct { backend, .. } => backend.clip_bounds(),
+        }
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for CompositeBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.draw_pixel(point, color),
+            Group::Direct { backend, opacity } => backend.draw_pixel(
+                point,
+                BackendColor {
+                    alpha: color.alpha * *opacity,
+                    rgb: color.rgb,
+                },
+            ),
+        }
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.draw_line(from, to, style),
+            Group::Direct { backend, opacity } => backend.draw_line(
+                from,
+                to,
+                &Faded {
+                    style,
+                    opacity: *opacity,
+                },
+            ),
+        }
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.draw_rect(upper_left, bottom_right, style, fill),
+            Group::Direct { backend, opacity } => backend.draw_rect(
+                upper_left,
+                bottom_right,
+                &Faded {
+                    style,
+                    opacity: *opacity,
+                },
+                fill,
+            ),
+        }
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.draw_path(path, style),
+            Group::Direct { backend, opacity } => backend.draw_path(
+                path,
+                &Faded {
+                    style,
+                    opacity: *opacity,
+                },
+            ),
+        }
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.draw_circle(center, radius, style, fill),
+            Group::Direct { backend, opacity } => backend.draw_circle(
+                center,
+                radius,
+                &Faded {
+                    style,
+                    opacity: *opacity,
+                },
+                fill,
+            ),
+        }
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.fill_polygon(vert, style),
+            Group::Direct { backend, opacity } => backend.fill_polygon(
+                vert,
+                &Faded {
+                    style,
+                    opacity: *opacity,
+                },
+            ),
+        }
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.draw_text(text, style, pos),
+            Group::Direct { backend, opacity } => backend.draw_text(
+                text,
+                &Faded {
+                    style,
+                    opacity: *opacity,
+                },
+                pos,
+            ),
+        }
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        match &self.group {
+            Group::Pixels(group) => group.estimate_text_size(text, style),
+            Group::Direct { backend, .. } => backend.estimate_text_size(text, style),
+        }
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match &mut self.group {
+            Group::Pixels(group) => group.blit_bitmap(pos, size, src),
+            Group::Direct { backend, .. } => backend.blit_bitmap(pos, size, src),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_blend() {
+        let red = [0.5, 0.0, 0.0, 0.5];
+        let blue = [0.0, 0.0, 1.0, 1.0];
+        assert_eq!(blend(BlendMode::Normal, red, blue), [0.5, 0.0, 0.5, 1.0]);
+        assert_eq!(blend(BlendMode::Additive, red, red), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(blend(BlendMode::Additive, blue, blue), [0.0, 0.0, 1.0, 1.0]);
+        // The white is neutral to the multiplication, and the black makes everything black
+        let white = [1.0, 1.0, 1.0, 1.0];
+        assert_eq!(blend(BlendMode::Multiply, blue, white), blue);
+        let black = [0.0, 0.0, 0.0, 1.0];
+        assert_eq!(blend(BlendMode::Multiply, black, blue), black);
+        // Nothing is multiplied on a transparent pixel
+        assert_eq!(blend(BlendMode::Multiply, red, [0.0; 4]), red);
+    }
+
+    #[cfg(feature = "svg_backend")]
+    #[test]
+    fn test_composite_on_svg() {
+        use crate::prelude::*;
+
+        let mut content = String::new();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw_composited(0.5, BlendMode::Normal, |group| {
+                group.draw(&Circle::new((40, 50), 20, BLUE.filled()))?;
+                group.draw(&Circle::new((60, 50), 20, BLUE.filled()))
+            })
+            .unwrap();
+        }
+        // The circles are kept as the shapes, rather than rasterized into the pixels
+        assert_eq!(content.matches("<circle").count(), 2);
+        assert!(!content.contains("<rect"));
+        assert!(content.contains("opacity=\"0.5\""));
+    }
+}
@@ -0,0 +1,326 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::capabilities::{BackendCapabilities, BackendFeatures};
+use crate::style::text_anchor::Pos;
+use crate::style::{FontDesc, FontFamily, FontStyle, FontTransform, TextStyle};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The layers that the drawing is sorted by before it goes to the backend.
+///
+/// The drawing on a layer is recorded, and it's drawn on the top of everything drawn directly
+/// onto the drawing area, from the background layer to the overlay layer, when the drawing is
+/// [presented](struct.DrawingArea.html#method.present) or the last drawing area of the backend
+/// is dropped. The drawing on the same layer keeps its order.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// // The annotation is drawn first, but it stays on the top of the series
+/// let label = Text::new("peak", (5.0, 9.0), ("sans-serif", 15));
+/// chart
+///     .draw_series_on_layer(Layer::Annotation, vec![label])
+///     .unwrap();
+/// let data = (0..=10).map(|x| (x as f64, x as f64 % 9.0));
+/// chart
+///     .draw_series_on_layer(Layer::Background, AreaSeries::new(data, 0.0, BLUE.mix(0.3)))
+///     .unwrap();
+/// // The grid lines are drawn above the filled area on the background
+/// chart.configure_mesh().layer(Layer::Grid).draw().unwrap();
+/// root.present().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// The layer at the bottom, such as the shaded regions and the filled areas
+    Background,
+    /// The layer of the grid
+    Grid,
+    /// The layer of the data series
+    Series,
+    /// The layer of the labels and the reference lines
+    Annotation,
+    /// The layer at the top, such as the legend and the tooltips
+    Overlay,
+}
+
+/// The style of a recorded shape
+#[derive(Clone, Copy)]
+struct RecordedStyle {
+    color: BackendColor,
+    stroke_width: u32,
+}
+
+impl RecordedStyle {
+    fn new<S: BackendStyle>(style: &S) -> Self {
+        Self {
+            color: style.color(),
+            stroke_width: style.stroke_width(),
+        }
+    }
+}
+
+impl BackendStyle for RecordedStyle {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+}
+
+/// The style of a recorded text, which is turned back into a text style when it's drawn
+struct RecordedText {
+    family: String,
+    size: f64,
+    style: FontStyle,
+    transform: FontTransform,
+    color: BackendColor,
+    anchor: Pos,
+}
+
+impl RecordedText {
+    fn new<S: BackendTextStyle>(style: &S) -> Self {
+        Self {
+            family: style.family().as_str().to_string(),
+            size: style.size(),
+            style: style.style(),
+            transform: style.transform(),
+            color: style.color(),
+            anchor: style.anchor(),
+        }
+    }
+
+    fn text_style(&self) -> TextStyle<'_> {
+        let font = FontDesc::new(
+            FontFamily::from(self.family.as_str()),
+            self.size,
+            self.style,
+        )
+        .transform(self.transform.clone());
+        TextStyle {
+            font,
+            color: self.color,
+            pos: self.anchor,
+        }
+    }
+}
+
+/// A drawing operation recorded on a layer
+enum LayerOp {
+    Pixel(BackendCoord, BackendColor),
+    Line(BackendCoord, BackendCoord, RecordedStyle),
+    Rect(BackendCoord, BackendCoord, RecordedStyle, bool),
+    Path(Vec<BackendCoord>, RecordedStyle),
+    Circle(BackendCoord, u32, RecordedStyle, bool),
+    Polygon(Vec<BackendCoord>, RecordedStyle),
+    Text(String, RecordedText, BackendCoord),
+    Bitmap(BackendCoord, (u32, u32), Vec<u8>),
+}
+
+impl LayerOp {
+    fn draw<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self {
+            LayerOp::Pixel(point, color) => backend.draw_pixel(*point, *color),
+            LayerOp::Line(from, to, style) => backend.draw_line(*from, *to, style),
+            LayerOp::Rect(upper_left, bottom_right, style, fill) => {
+                backend.draw_rect(*upper_left, *bottom_right, style, *fill)
+            }
+            LayerOp::Path(path, style) => backend.draw_path(path.iter().cloned(), style),
+            LayerOp::Circle(center, radius, style, fill) => {
+                backend.draw_circle(*center, *radius, style, *fill)
+            }
+            LayerOp::Polygon(vert, style) => backend.fill_polygon(vert.iter().cloned(), style),
+            LayerOp::Text(text, style, pos) => backend.draw_text(text, &style.text_style(), *pos),
+            LayerOp::Bitmap(pos, size, src) => backend.blit_bitmap(*pos, *size, src),
+        }
+    }
+}
+
+/// The recorded drawing on the layers of a backend, which is shared by all the drawing areas
+/// of the backend
+pub(crate) struct LayerStack<DB: DrawingBackend> {
+    backend: Rc<RefCell<DB>>,
+    ops: RefCell<Vec<(Layer, LayerOp)>>,
+}
+
+impl<DB: DrawingBackend> LayerStack<DB> {
+    pub(crate) fn new(backend: Rc<RefCell<DB>>) -> Self {
+        Self {
+            backend,
+            ops: RefCell::new(vec![]),
+        }
+    }
+
+    fn push(&self, layer: Layer, op: LayerOp) {
+        self.ops.borrow_mut().push((layer, op));
+    }
+
+    /// Draw the recorded drawing onto the backend in the layer order, and forget it
+    pub(crate) fn replay(&self, backend: &mut DB) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut ops: Vec<_> = self.ops.borrow_mut().drain(..).collect();
+        // The sort is stable, so the drawing on the same layer keeps its order
+        ops.sort_by_key(|(layer, _)| *layer);
+        for (_, op) in ops.iter() {
+            op.draw(backend)?;
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DrawingBackend> Drop for LayerStack<DB> {
+    fn drop(&mut self) {
+        if self.ops.borrow().is_empty() {
+            return;
+        }
+        // There's no way to report the error when the drawing is dropped
+        if let Ok(mut backend) = self.backend.try_borrow_mut() {
+            if backend.ensure_prepared().is_ok() {
+                self.replay(&mut backend).ok();
+            }
+        }
+    }
+}
+
+/// The backend that records the drawing onto a layer of another backend, which is the backend
+/// of the drawing areas returned by
+/// [DrawingArea::on_layer](struct.DrawingArea.html#method.on_layer).
+///
+/// The recorded drawing is drawn onto the backend when the drawing is presented, thus presenting
+/// the recorder itself does nothing.
+pub struct LayerRecorder<DB: DrawingBackend> {
+    layers: Rc<LayerStack<DB>>,
+    layer: Layer,
+}
+
+impl<DB: DrawingBackend> LayerRecorder<DB> {
+    pub(crate) fn new(layers: Rc<LayerStack<DB>>, layer: Layer) -> Self {
+        Self { layers, layer }
+    }
+
+    /// Get the layer that the drawing is recorded on
+    pub fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    fn record(&mut self, op: LayerOp) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.layers.push(self.layer, op);
+        Ok(())
+    }
+}
+
+impl<DB: BackendFeatures> BackendFeatures for LayerRecorder<DB> {
+    fn capabilities(&self) -> BackendCapabilities {
+        self.layers.backend.borrow().capabilities()
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for LayerRecorder<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.layers.backend.borrow().get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.record(LayerOp::Pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.record(LayerOp::Line(from, to, RecordedStyle::new(style)))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = RecordedStyle::new(style);
+        self.record(LayerOp::Rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path = path.into_iter().collect();
+        self.record(LayerOp::Path(path, RecordedStyle::new(style)))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = RecordedStyle::new(style);
+        self.record(LayerOp::Circle(center, radius, style, fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let vert = vert.into_iter().collect();
+        self.record(LayerOp::Polygon(vert, RecordedStyle::new(style)))
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = RecordedText::new(style);
+        self.record(LayerOp::Text(text.to_string(), style, pos))
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.layers.backend.borrow().estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.record(LayerOp::Bitmap(pos, (iw, ih), src.to_vec()))
+    }
+}
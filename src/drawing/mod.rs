@@ -12,7 +12,23 @@ about the [coordinate abstraction](../coord/index.html) and [element system](../
 */
 mod area;
 mod backend_impl;
+pub(crate) mod capabilities;
+pub(crate) mod clip;
+mod composite;
+mod layer;
+mod paged;
+#[cfg(all(not(target_arch = "wasm32"), feature = "parallel"))]
+mod parallel;
 
-pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea, Rect};
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+pub use area::ImageFill;
+pub use area::{AccessibleBackend, DrawingArea, DrawingAreaErrorKind, IntoDrawingArea, Rect};
+pub use capabilities::{BackendCapabilities, BackendFeatures, FallbackBackend};
+pub use clip::ClipBackend;
+pub use composite::{BlendMode, CompositeBackend};
+pub use layer::{Layer, LayerRecorder};
+pub use paged::PagedDrawingArea;
+#[cfg(all(not(target_arch = "wasm32"), feature = "parallel"))]
+pub use parallel::draw_panels_parallel;
 
 pub use backend_impl::*;
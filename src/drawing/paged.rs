@@ -0,0 +1,106 @@
+use super::area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use crate::coord::Shift;
+
+use plotters_backend::DrawingBackend;
+
+/// The sequence of pages, each of which is a drawing area on its own backend, which makes a
+/// document with many charts, such as a report.
+///
+/// The backend of each page is created by the page factory with the index of the page, starting
+/// from 0. Since none of the backends has a notion of pages, a page is usually a file on its own,
+/// for example, a numbered SVG file. When a new page is started, the previous one is presented.
+///
+/// ```rust,no_run
+/// use plotters::drawing::PagedDrawingArea;
+/// use plotters::prelude::*;
+///
+/// let paths: Vec<_> = (1..=3).map(|n| format!("report-{}.svg", n)).collect();
+/// let mut pages = PagedDrawingArea::new(|idx| SVGBackend::new(&paths[idx], (640, 480)));
+/// for n in 1..=3 {
+///     let root = pages.new_page().unwrap();
+///     root.fill(&WHITE).unwrap();
+///     let mut chart = ChartBuilder::on(&root)
+///         .caption(format!("Chart {}", n), ("sans-serif", 30))
+///         .build_cartesian_2d(0..10, 0..10 * n)
+///         .unwrap();
+///     chart.configure_mesh().draw().unwrap();
+/// }
+/// pages.finish().unwrap();
+/// ```
+pub struct PagedDrawingArea<DB: DrawingBackend, F: FnMut(usize) -> DB> {
+    make_page: F,
+    current: Option<DrawingArea<DB, Shift>>,
+    page_count: usize,
+}
+
+impl<DB: DrawingBackend, F: FnMut(usize) -> DB> PagedDrawingArea<DB, F> {
+    /// Create a new page sequence with no pages
+    /// - `make_page`: The factory of the page backends, which takes the index of the page
+    /// - **returns** The newly created page sequence
+    pub fn new(make_page: F) -> Self {
+        Self {
+            make_page,
+            current: None,
+            page_count: 0,
+        }
+    }
+
+    /// Present the current page, if any, and start a new page
+    /// - **returns** The drawing area of the new page
+    pub fn new_page(
+        &mut self,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        if let Some(page) = self.current.take() {
+            page.present()?;
+        }
+        let page = (self.make_page)(self.page_count).into_drawing_area();
+        self.page_count += 1;
+        self.current = Some(page.clone());
+        Ok(page)
+    }
+
+    /// Get the number of the pages that have been started
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// Present the last page and end the sequence
+    pub fn finish(mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        match self.current.take() {
+            Some(page) => page.present(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_paged_drawing_area() {
+        let created = Rc::new(RefCell::new(vec![]));
+        let created_in_factory = created.clone();
+        let mut pages = PagedDrawingArea::new(move |idx| {
+            created_in_factory.borrow_mut().push(idx);
+            let mut backend = MockedBackend::new(100 * (idx as u32 + 1), 100);
+            backend.drop_check(move |b| assert_eq!(b.num_draw_rect_call, idx as u32 + 1));
+            backend
+        });
+
+        for idx in 0..3 {
+            let root = pages.new_page().unwrap();
+            assert_eq!(root.dim_in_pixel(), (100 * (idx + 1), 100));
+            for _ in 0..=idx {
+                root.fill(&WHITE).unwrap();
+            }
+        }
+        assert_eq!(pages.page_count(), 3);
+        pages.finish().unwrap();
+        assert_eq!(*created.borrow(), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,155 @@
+use std::ops::Range;
+
+use rayon::prelude::*;
+
+use super::area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use crate::coord::Shift;
+
+use plotters_bitmap::{BitMapBackend, BitMapBackendError};
+
+type PanelResult = Result<(), DrawingAreaErrorKind<BitMapBackendError>>;
+
+/// Copy the pixels of the rectangle between the frame buffer of the width and the panel buffer
+fn copy_rect(
+    frame_width: usize,
+    (xs, ys): &(Range<i32>, Range<i32>),
+    mut copy: impl FnMut(Range<usize>, Range<usize>),
+) {
+    let row = (xs.end - xs.start) as usize * 3;
+    for (idx, y) in ys.clone().enumerate() {
+        let start = (y as usize * frame_width + xs.start as usize) * 3;
+        copy(start..start + row, idx * row..(idx + 1) * row);
+    }
+}
+
+/// Draw the panels of a bitmap on multiple threads.
+///
+/// The layout function splits the root drawing area into the panels, for example, with
+/// [DrawingArea::split_evenly](struct.DrawingArea.html#method.split_evenly), and it's also where
+/// the background shared by the panels is drawn. Then each panel is drawn by the drawing function
+/// on a thread of its own, with the index of the panel and a bitmap that holds a copy of the
+/// pixels of the panel, and the panels are merged back to the frame buffer when all of them are
+/// done. Thus the drawing of a panel shouldn't go beyond the panel.
+///
+/// ```rust
+/// use plotters::drawing::draw_panels_parallel;
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 800 * 600 * 3];
+/// draw_panels_parallel(
+///     &mut buffer,
+///     (800, 600),
+///     |root| {
+///         root.fill(&WHITE).unwrap();
+///         root.split_evenly((2, 2))
+///     },
+///     |idx, panel| {
+///         let mut chart = ChartBuilder::on(&panel)
+///             .margin(10)
+///             .build_cartesian_2d(0.0..1.0, 0.0..1.0)?;
+///         chart.draw_series(LineSeries::new(
+///             (0..=100).map(|x| (x as f64 / 100.0, (x as f64 / 100.0).powi(idx as i32 + 1))),
+///             &Palette99::pick(idx),
+///         ))?;
+///         Ok(())
+///     },
+/// )
+/// .unwrap();
+/// ```
+/// - `buffer`: The RGB frame buffer
+/// - `size`: The size of the frame in pixels
+/// - `layout`: The function that splits the root drawing area into the panels
+/// - `draw`: The function that draws a panel, which is called on the worker threads
+/// - **returns** The first error of the panels, if any
+pub fn draw_panels_parallel<Layout, Draw>(
+    buffer: &mut [u8],
+    (w, h): (u32, u32),
+    layout: Layout,
+    draw: Draw,
+) -> PanelResult
+where
+    Layout: for<'a, 'b> FnOnce(
+        &'b DrawingArea<BitMapBackend<'a>, Shift>,
+    ) -> Vec<DrawingArea<BitMapBackend<'a>, Shift>>,
+    Draw: Fn(usize, DrawingArea<BitMapBackend, Shift>) -> PanelResult + Sync,
+{
+    let rects: Vec<_> = {
+        let root = BitMapBackend::with_buffer(buffer, (w, h)).into_drawing_area();
+        layout(&root)
+            .iter()
+            .map(DrawingArea::get_pixel_range)
+            .collect()
+    };
+
+    let frame: &[u8] = buffer;
+    let panels = rects
+        .par_iter()
+        .enumerate()
+        .map(|(idx, rect)| {
+            let size = (
+                (rect.0.end - rect.0.start) as u32,
+                (rect.1.end - rect.1.start) as u32,
+            );
+            let mut pixels = vec![0; size.0 as usize * size.1 as usize * 3];
+            copy_rect(w as usize, rect, |src, dst| {
+                pixels[dst].copy_from_slice(&frame[src])
+            });
+            draw(
+                idx,
+                BitMapBackend::with_buffer(&mut pixels, size).into_drawing_area(),
+            )?;
+            Ok(pixels)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (rect, pixels) in rects.iter().zip(panels) {
+        copy_rect(w as usize, rect, |dst, src| {
+            buffer[dst].copy_from_slice(&pixels[src])
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn draw_panel(idx: usize, panel: DrawingArea<BitMapBackend, Shift>) -> PanelResult {
+        let (w, h) = panel.dim_in_pixel();
+        panel.draw(&Rectangle::new(
+            [(1, 1), (w as i32 - 2, h as i32 - 2)],
+            Palette99::pick(idx).filled(),
+        ))?;
+        panel.draw(&PathElement::new(
+            vec![(0, 0), (w as i32 - 1, h as i32 - 1)],
+            BLACK,
+        ))
+    }
+
+    #[test]
+    fn test_draw_panels_parallel() {
+        let mut serial = vec![0; 30 * 20 * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut serial, (30, 20)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            for (idx, panel) in root.split_evenly((2, 3)).into_iter().enumerate() {
+                draw_panel(idx, panel).unwrap();
+            }
+        }
+
+        let mut parallel = vec![0; 30 * 20 * 3];
+        draw_panels_parallel(
+            &mut parallel,
+            (30, 20),
+            |root| {
+                root.fill(&WHITE).unwrap();
+                root.split_evenly((2, 3))
+            },
+            draw_panel,
+        )
+        .unwrap();
+
+        assert!(serial == parallel);
+    }
+}
@@ -0,0 +1,269 @@
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The number of line segments a curved arrow is drawn with
+const CURVE_SEGMENTS: usize = 32;
+/// The gap between the tail of an arrow and its label, in pixels
+const LABEL_GAP: i32 = 3;
+
+/// The shape of the head of an arrow
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrowHead {
+    /// A filled triangle
+    Filled,
+    /// Two lines from the tip
+    Open,
+    /// No head, only the shaft is drawn
+    None,
+}
+
+/// The arrow element, which points from one point to another in the guest coordinate. The
+/// sizes of the heads are in pixels, so the heads look the same on any scale.
+///
+/// The arrow can be curved as a quadratic Bézier curve, and it can carry a label at its tail,
+/// which is useful to annotate a point of a chart.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(vec![
+///         Arrow::new((2.0, 8.0), (5.0, 5.0), &RED).label("this is the anomaly"),
+///         Arrow::curved((8.0, 2.0), (8.0, 5.0), (5.5, 4.5), &BLUE).tail(ArrowHead::Open),
+///     ])
+///     .unwrap();
+/// ```
+pub struct Arrow<'a, Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    head: ArrowHead,
+    tail: ArrowHead,
+    head_size: (u32, u32),
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, Coord> Arrow<'a, Coord> {
+    /// Create a new straight arrow
+    /// - `from`: The tail of the arrow
+    /// - `to`: The point that the arrow points to
+    /// - `style`: The style of the arrow
+    /// - **returns** The newly created arrow, which has a filled head and no tail
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, to: Coord, style: S) -> Self {
+        Self::from_points(vec![from, to], style.into())
+    }
+
+    /// Create a new curved arrow, which is a quadratic Bézier curve
+    /// - `from`: The tail of the arrow
+    /// - `control`: The control point, which the curve bends towards
+    /// - `to`: The point that the arrow points to
+    /// - `style`: The style of the arrow
+    /// - **returns** The newly created arrow, which has a filled head and no tail
+    pub fn curved<S: Into<ShapeStyle>>(from: Coord, control: Coord, to: Coord, style: S) -> Self {
+        Self::from_points(vec![from, control, to], style.into())
+    }
+
+    fn from_points(points: Vec<Coord>, style: ShapeStyle) -> Self {
+        Self {
+            points,
+            style,
+            head: ArrowHead::Filled,
+            tail: ArrowHead::None,
+            head_size: (8, 6),
+            label: None,
+        }
+    }
+
+    /// Set the shape of the head at the end of the arrow
+    pub fn head(mut self, head: ArrowHead) -> Self {
+        self.head = head;
+        self
+    }
+
+    /// Set the shape of the head at the tail of the arrow, which is none by default
+    pub fn tail(mut self, tail: ArrowHead) -> Self {
+        self.tail = tail;
+        self
+    }
+
+    /// Set the size of the heads
+    /// - `length`: The length of a head along the arrow in pixels
+    /// - `width`: The width of a head across the arrow in pixels
+    pub fn head_size(mut self, length: u32, width: u32) -> Self {
+        self.head_size = (length, width);
+        self
+    }
+
+    /// Attach a label to the tail of the arrow, on the side facing away from the arrow
+    pub fn label<L: ToString>(mut self, label: L) -> Self {
+        let style = self
+            .label
+            .take()
+            .map(|l| l.1)
+            .unwrap_or_else(|| ("sans-serif", 12).into());
+        self.label = Some((label.to_string(), style));
+        self
+    }
+
+    /// Set the style of the label
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        let text = self.label.take().map(|l| l.0).unwrap_or_default();
+        self.label = Some((text, style.into()));
+        self
+    }
+
+    /// Draw a head at the tip, which points in the direction from the given point
+    fn draw_head<DB: DrawingBackend>(
+        &self,
+        head: ArrowHead,
+        from: BackendCoord,
+        tip: BackendCoord,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (dx, dy) = ((tip.0 - from.0) as f64, (tip.1 - from.1) as f64);
+        let len = (dx * dx + dy * dy).sqrt();
+        if head == ArrowHead::None || len == 0.0 {
+            return Ok(());
+        }
+
+        let (ux, uy) = (dx / len, dy / len);
+        let head_len = self.head_size.0 as f64;
+        let half_width = self.head_size.1 as f64 / 2.0;
+        let base = (tip.0 as f64 - ux * head_len, tip.1 as f64 - uy * head_len);
+        let side = |sign: f64| {
+            (
+                (base.0 - uy * half_width * sign).round() as i32,
+                (base.1 + ux * half_width * sign).round() as i32,
+            )
+        };
+        let (left, right) = (side(1.0), side(-1.0));
+
+        if head == ArrowHead::Filled {
+            backend.fill_polygon(vec![tip, left, right], &self.style)
+        } else {
+            backend.draw_path(vec![left, tip, right], &self.style)
+        }
+    }
+}
+
+impl<'b, 'a, Coord: 'b> PointCollection<'b, Coord> for &'b Arrow<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Arrow<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let path: Vec<BackendCoord> = match points.len() {
+            2 => points.clone(),
+            3 => {
+                let (p0, p1, p2) = (points[0], points[1], points[2]);
+                (0..=CURVE_SEGMENTS)
+                    .map(|i| {
+                        let t = i as f64 / CURVE_SEGMENTS as f64;
+                        let (a, b, c) = ((1.0 - t) * (1.0 - t), 2.0 * (1.0 - t) * t, t * t);
+                        let x = a * p0.0 as f64 + b * p1.0 as f64 + c * p2.0 as f64;
+                        let y = a * p0.1 as f64 + b * p1.1 as f64 + c * p2.1 as f64;
+                        (x.round() as i32, y.round() as i32)
+                    })
+                    .collect()
+            }
+            _ => return Ok(()),
+        };
+        self.style.stroke_path(backend, path)?;
+
+        // The heads follow the tangents at the ends, which point to the control point of a curve
+        let (tail, tip) = (points[0], points[points.len() - 1]);
+        self.draw_head(self.head, points[points.len() - 2], tip, backend)?;
+        self.draw_head(self.tail, points[1], tail, backend)?;
+
+        if let Some((ref text, ref style)) = self.label {
+            let (dx, dy) = (points[1].0 - tail.0, points[1].1 - tail.1);
+            let (hpos, x) = match dx {
+                dx if dx > dy.abs() / 2 => (HPos::Right, tail.0 - LABEL_GAP),
+                dx if -dx > dy.abs() / 2 => (HPos::Left, tail.0 + LABEL_GAP),
+                _ => (HPos::Center, tail.0),
+            };
+            let (vpos, y) = match dy {
+                dy if dy > dx.abs() / 2 => (VPos::Bottom, tail.1 - LABEL_GAP),
+                dy if -dy > dx.abs() / 2 => (VPos::Top, tail.1 + LABEL_GAP),
+                _ => (VPos::Center, tail.1),
+            };
+            backend.draw_text(text, &style.pos(Pos::new(hpos, vpos)), (x, y))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_draw_arrow() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(10, 50), (60, 50)]);
+            });
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(60, 50), (52, 53), (52, 47)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                // The open tail points backwards
+                assert_eq!(path, vec![(18, 47), (10, 50), (18, 53)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "note");
+                assert_eq!(pos, (7, 50));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        drawing_area
+            .draw(
+                &Arrow::new((10, 50), (60, 50), &RED)
+                    .tail(ArrowHead::Open)
+                    .label("note"),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_draw_curved_arrow() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), CURVE_SEGMENTS + 1);
+                assert_eq!(path[0], (0, 0));
+                assert_eq!(path[CURVE_SEGMENTS / 2], (50, 25));
+                assert_eq!(path[CURVE_SEGMENTS], (100, 0));
+            });
+            m.check_fill_polygon(|_, path| {
+                // The head follows the direction from the control point
+                assert_eq!(path[0], (100, 0));
+                assert!(path[1..].iter().all(|p| p.0 < 100 && p.1 > 0));
+            });
+        });
+        drawing_area
+            .draw(&Arrow::curved((0, 0), (50, 50), (100, 0), &BLUE))
+            .unwrap();
+    }
+}
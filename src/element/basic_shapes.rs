@@ -1,5 +1,7 @@
-use super::{Drawable, PointCollection};
-use crate::style::{Color, ShapeStyle, SizeDesc};
+use super::fill_rule::{trapezoids, FillRule};
+use super::{check_point_count, Drawable, PointCollection};
+use crate::drawing::{BackendCapabilities, BlendMode};
+use crate::style::{Color, Fill, Gradient, Pattern, ShapeStyle, SizeDesc};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// An element of a single pixel
@@ -37,6 +39,10 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Pixel<Coord> {
         }
         Ok(())
     }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
 }
 
 #[cfg(test)]
@@ -95,7 +101,11 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for PathElement<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.draw_path(points, &self.style)
+        self.style.stroke_path(backend, points)
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
     }
 }
 
@@ -121,11 +131,105 @@ fn test_path_element() {
     .expect("Drawing Failure");
 }
 
+/// An element of a multi-segment path, which can be closed and filled, and is drawn with a
+/// single call to the backend for the outline, so that a backend such as SVG emits one path
+/// rather than a line for each segment
+pub struct PolyPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    fill: Option<ShapeStyle>,
+    closed: bool,
+}
+impl<Coord> PolyPath<Coord> {
+    /// Create a new open path with no fill
+    /// - `points`: The iterator of the points
+    /// - `style`: The style of the outline
+    /// - returns the created element
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into(),
+            style: style.into(),
+            fill: None,
+            closed: false,
+        }
+    }
+
+    /// Fill the region enclosed by the path with the color of the style
+    pub fn fill<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.fill = Some(style.into());
+        self
+    }
+
+    /// Set if the outline goes back to the first point at the end
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a PolyPath<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for PolyPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.collect();
+        if let Some(ref fill) = self.fill {
+            backend.fill_polygon(points.iter().cloned(), &fill.color.to_backend_color())?;
+        }
+        if self.closed && points.len() > 2 {
+            points.push(points[0]);
+        }
+        self.style.stroke_path(backend, points)
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_poly_path_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, vec![(10, 10), (100, 10), (100, 100)]);
+        });
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path, vec![(10, 10), (100, 10), (100, 100), (10, 10)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_draw_line_call, 0);
+        });
+    });
+    da.draw(
+        &PolyPath::new(vec![(10, 10), (100, 10), (100, 100)], &BLUE)
+            .fill(&RED)
+            .closed(true),
+    )
+    .expect("Drawing Failure");
+}
+
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
     margin: (u32, u32, u32, u32),
+    fill: Option<Fill>,
 }
 
 impl<Coord> Rectangle<Coord> {
@@ -138,6 +242,7 @@ impl<Coord> Rectangle<Coord> {
             points,
             style: style.into(),
             margin: (0, 0, 0, 0),
+            fill: None,
         }
     }
 
@@ -150,6 +255,20 @@ impl<Coord> Rectangle<Coord> {
         self.margin = (t, b, l, r);
         self
     }
+
+    /// Fill the rectangle with a gradient instead of the color of the style. If the style isn't
+    /// filled, the outline is still drawn on top of the gradient.
+    pub fn fill_gradient(mut self, gradient: Gradient) -> Self {
+        self.fill = Some(Fill::Gradient(gradient));
+        self
+    }
+
+    /// Fill the rectangle with a pattern instead of the color of the style. If the style isn't
+    /// filled, the outline is still drawn on top of the pattern.
+    pub fn fill_pattern(mut self, pattern: Pattern) -> Self {
+        self.fill = Some(Fill::Pattern(pattern));
+        self
+    }
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
@@ -162,10 +281,21 @@ impl<'a, Coord> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
 
 impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
     fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let capabilities = BackendCapabilities::all();
+        self.draw_with_capabilities(points, backend, parent_dim, &capabilities)
+    }
+
+    fn draw_with_capabilities<I: Iterator<Item = BackendCoord>>(
         &self,
         mut points: I,
         backend: &mut DB,
         _: (u32, u32),
+        capabilities: &BackendCapabilities,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         match (points.next(), points.next()) {
             (Some(a), Some(b)) => {
@@ -174,11 +304,28 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
                 b.1 -= self.margin.1 as i32;
                 a.0 += self.margin.2 as i32;
                 b.0 -= self.margin.3 as i32;
+                if let Some(ref fill) = self.fill {
+                    fill.fill_polygon(backend, &[a, (b.0, a.1), b, (a.0, b.1)], capabilities)?;
+                    if self.style.filled {
+                        return Ok(());
+                    }
+                }
+                if !self.style.filled && !self.style.strokes_natively() {
+                    let outline = vec![a, (b.0, a.1), b, (a.0, b.1), a];
+                    return self.style.stroke_path(backend, outline);
+                }
                 backend.draw_rect(a, b, &self.style, self.style.filled)
             }
-            _ => Ok(()),
+            (a, _) => {
+                check_point_count("Rectangle", 2, a.map_or(0, |_| 1));
+                Ok(())
+            }
         }
     }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
 }
 
 #[cfg(test)]
@@ -265,6 +412,10 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Circle<Coord, S
         }
         Ok(())
     }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
 }
 
 #[cfg(test)]
@@ -287,10 +438,14 @@ fn test_circle_element() {
         .expect("Drawing Failure");
 }
 
-/// An element of a filled polygon
+/// An element of a filled polygon, which is either a single ring of points or multiple rings,
+/// such as a shape with holes
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
+    rings: Vec<usize>,
+    fill_rule: FillRule,
     style: ShapeStyle,
+    fill: Option<Fill>,
 }
 impl<Coord> Polygon<Coord> {
     /// Create a new polygon
@@ -298,11 +453,81 @@ impl<Coord> Polygon<Coord> {
     /// - `style`: The shape style
     /// - returns the created element
     pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        let points = points.into();
         Self {
-            points: points.into(),
+            rings: vec![points.len()],
+            points,
+            fill_rule: FillRule::EvenOdd,
+            style: style.into(),
+            fill: None,
+        }
+    }
+
+    /// Create a new polygon of multiple rings, the parts of the plane that are inside are
+    /// decided by the [fill rule](#method.fill_rule), which is even-odd by default. Thus the
+    /// rings inside the outer ring are the holes, such as an annulus or a map polygon with lakes.
+    /// - `rings`: The rings of the points
+    /// - `style`: The shape style
+    /// - returns the created element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let circle = |r: f64| -> Vec<(f64, f64)> {
+    ///     (0..64)
+    ///         .map(|i| i as f64 / 64.0 * std::f64::consts::PI * 2.0)
+    ///         .map(|a| (r * a.cos(), r * a.sin()))
+    ///         .collect()
+    /// };
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (480, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(std::iter::once(Polygon::with_rings(
+    ///         vec![circle(0.9), circle(0.5)],
+    ///         BLUE.filled(),
+    ///     )))
+    ///     .unwrap();
+    /// ```
+    pub fn with_rings<R: IntoIterator<Item = Vec<Coord>>, S: Into<ShapeStyle>>(
+        rings: R,
+        style: S,
+    ) -> Self {
+        let (mut points, mut sizes) = (vec![], vec![]);
+        for ring in rings {
+            sizes.push(ring.len());
+            points.extend(ring);
+        }
+        Self {
+            points,
+            rings: sizes,
+            fill_rule: FillRule::EvenOdd,
             style: style.into(),
+            fill: None,
         }
     }
+
+    /// Set how the rings decide which parts of the plane are filled, which only matters to a
+    /// polygon of multiple rings or a polygon whose edges cross
+    pub fn fill_rule(mut self, rule: FillRule) -> Self {
+        self.fill_rule = rule;
+        self
+    }
+
+    /// Fill the polygon with a gradient instead of the color of the style
+    pub fn fill_gradient(mut self, gradient: Gradient) -> Self {
+        self.fill = Some(Fill::Gradient(gradient));
+        self
+    }
+
+    /// Fill the polygon with a pattern instead of the color of the style
+    pub fn fill_pattern(mut self, pattern: Pattern) -> Self {
+        self.fill = Some(Fill::Pattern(pattern));
+        self
+    }
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Polygon<Coord> {
@@ -315,13 +540,71 @@ impl<'a, Coord> PointCollection<'a, Coord> for &'a Polygon<Coord> {
 
 impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
     fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let capabilities = BackendCapabilities::all();
+        self.draw_with_capabilities(points, backend, parent_dim, &capabilities)
+    }
+
+    fn draw_with_capabilities<I: Iterator<Item = BackendCoord>>(
         &self,
         points: I,
         backend: &mut DB,
         _: (u32, u32),
+        capabilities: &BackendCapabilities,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if self.rings.len() > 1 {
+            return self.draw_rings(points, backend, capabilities);
+        }
+        if let Some(ref fill) = self.fill {
+            return fill.fill_polygon(backend, &points, capabilities);
+        }
         backend.fill_polygon(points, &self.style.color.to_backend_color())
     }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
+}
+
+impl<Coord> Polygon<Coord> {
+    /// Fill the rings as the trapezoids of the area inside them, since the backends only fill
+    /// the simple polygons
+    fn draw_rings<DB: DrawingBackend>(
+        &self,
+        points: Vec<BackendCoord>,
+        backend: &mut DB,
+        capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if points.is_empty() {
+            return Ok(());
+        }
+        let mut bounds = (points[0], points[0]);
+        for &(x, y) in &points {
+            bounds = (
+                ((bounds.0).0.min(x), (bounds.0).1.min(y)),
+                ((bounds.1).0.max(x), (bounds.1).1.max(y)),
+            );
+        }
+        let mut rings = vec![];
+        let mut rest = points.into_iter();
+        for &size in &self.rings {
+            rings.push(rest.by_ref().take(size).collect::<Vec<_>>());
+        }
+
+        let color = self.style.color.to_backend_color();
+        for part in trapezoids(&rings, self.fill_rule) {
+            match self.fill {
+                Some(ref fill) => fill.fill_polygon_in(backend, &part, bounds, capabilities)?,
+                None => backend.fill_polygon(part, &color)?,
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -346,3 +629,98 @@ fn test_polygon_element() {
     da.draw(&Polygon::new(points.clone(), &BLUE))
         .expect("Drawing Failure");
 }
+
+#[cfg(test)]
+#[test]
+fn test_polygon_with_holes() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_fill_polygon(|c, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(p, vec![(10, 10), (90, 10), (90, 30), (10, 30)]);
+        });
+        m.check_fill_polygon(|_, _| {});
+        m.drop_check(|b| {
+            // Above, below, left and right of the hole
+            assert_eq!(b.num_fill_polygon_call, 4);
+        });
+    });
+    let outer = vec![(10, 10), (90, 10), (90, 90), (10, 90)];
+    let hole = vec![(30, 30), (70, 30), (70, 70), (30, 70)];
+    da.draw(&Polygon::with_rings(vec![outer, hole], &BLUE))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_gradient_polygon_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_fill_polygon(|c, p| {
+            // The region before the first stop is a single band at the top
+            assert_eq!(c, RED.to_rgba());
+            assert!(p.iter().all(|p| p.1 <= 10));
+        });
+        m.check_fill_polygon(|c, p| {
+            assert_ne!(c, RED.to_rgba());
+            assert!(p.iter().all(|p| p.1 >= 10));
+        });
+        m.drop_check(|b| {
+            // One band for each of the 80 pixels between the stops
+            assert_eq!(b.num_fill_polygon_call, 1 + 80 + 1);
+        });
+    });
+    let gradient = Gradient::vertical().stop(0.1, &RED).stop(0.9, &BLUE);
+    da.draw(&Polygon::new(vec![(0, 0), (50, 0), (50, 100)], &BLACK).fill_gradient(gradient))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_gradient_rect_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_line(|c, _, from, to| {
+            // The first row is beyond the last stop
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!((from, to), ((10, 10), (29, 10)));
+        });
+        m.check_draw_line(|_, _, _, _| {});
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert!(b.num_draw_line_call > 21);
+        });
+    });
+    let gradient = Gradient::radial((0.5, 0.5), 0.5)
+        .stop(0.0, &RED)
+        .stop(0.5, &BLUE);
+    da.draw(&Rectangle::new([(10, 10), (30, 30)], &BLACK).fill_gradient(gradient))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_pattern_rect_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_fill_polygon(|c, _| {
+            assert_eq!(c, WHITE.to_rgba());
+        });
+        m.check_draw_line(|c, _, from, to| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!((from, to), ((30, 20), (10, 20)));
+        });
+        m.check_draw_line(|_, _, _, _| {});
+        m.drop_check(|b| {
+            // The lines on the top and left edges are outside of the rectangle, and the outline
+            // isn't drawn, because the style is filled
+            assert_eq!(b.num_draw_line_call, 2 + 2);
+            assert_eq!(b.num_draw_rect_call, 0);
+        });
+    });
+    let pattern = Pattern::new(PatternKind::Grid, &RED)
+        .spacing(10)
+        .background(&WHITE);
+    da.draw(&Rectangle::new([(10, 10), (30, 30)], RED.filled()).fill_pattern(pattern))
+        .expect("Drawing Failure");
+}
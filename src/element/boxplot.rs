@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
 
+use crate::coord::ranged1d::SegmentValue;
 use crate::data::Quartiles;
-use crate::element::{Drawable, PointCollection};
+use crate::element::{check_point_count, Drawable, MarkerShape, PointCollection};
 use crate::style::{Color, ShapeStyle, BLACK};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
@@ -47,19 +48,55 @@ impl<K, V> BoxplotOrient<K, V> for BoxplotOrientH<K, V> {
 }
 
 const DEFAULT_WIDTH: u32 = 10;
+const DEFAULT_OUTLIER_SIZE: u32 = 3;
+
+/// Get the two-sided standard normal quantile of the confidence level, with the rational
+/// approximation from Abramowitz and Stegun (26.2.23)
+fn normal_quantile(confidence: f64) -> f64 {
+    let p = (1.0 - confidence) / 2.0;
+    let t = (-2.0 * p.ln()).sqrt();
+    t - (2.515_517 + 0.802_853 * t + 0.010_328 * t * t)
+        / (1.0 + 1.432_788 * t + 0.189_269 * t * t + 0.001_308 * t * t * t)
+}
+
+/// The xorshift generator for the deterministic outlier jitter
+struct JitterRng(u64);
+
+impl JitterRng {
+    fn new(seed: u64) -> Self {
+        // The state of xorshift must not be zero
+        JitterRng(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Get the next number in range `0.0..1.0`
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
 
 /// The boxplot element
-pub struct Boxplot<K, O: BoxplotOrient<K, f32>> {
+pub struct Boxplot<K, O: BoxplotOrient<K, f64>> {
     style: ShapeStyle,
     width: u32,
+    width_ratio: Option<(f64, K)>,
     whisker_width: f64,
     offset: f64,
     key: K,
-    values: [f32; 5],
+    values: [f64; 5],
+    count: usize,
+    notch: Option<f64>,
+    outliers: Vec<f64>,
+    outlier_style: Option<ShapeStyle>,
+    outlier_marker: MarkerShape,
+    outlier_size: u32,
+    jitter: Option<(u64, f64)>,
     _p: PhantomData<O>,
 }
 
-impl<K: Clone> Boxplot<K, BoxplotOrientV<K, f32>> {
+impl<K: Clone> Boxplot<K, BoxplotOrientV<K, f64>> {
     /// Create a new vertical boxplot element.
     ///
     /// - `key`: The key (the X axis value)
@@ -73,19 +110,11 @@ impl<K: Clone> Boxplot<K, BoxplotOrientV<K, f32>> {
     /// let plot = Boxplot::new_vertical("group", &quartiles);
     /// ```
     pub fn new_vertical(key: K, quartiles: &Quartiles) -> Self {
-        Self {
-            style: Into::<ShapeStyle>::into(&BLACK),
-            width: DEFAULT_WIDTH,
-            whisker_width: 1.0,
-            offset: 0.0,
-            key,
-            values: quartiles.values(),
-            _p: PhantomData,
-        }
+        Self::from_quartiles(key, quartiles)
     }
 }
 
-impl<K: Clone> Boxplot<K, BoxplotOrientH<K, f32>> {
+impl<K: Clone> Boxplot<K, BoxplotOrientH<K, f64>> {
     /// Create a new horizontal boxplot element.
     ///
     /// - `key`: The key (the Y axis value)
@@ -99,19 +128,32 @@ impl<K: Clone> Boxplot<K, BoxplotOrientH<K, f32>> {
     /// let plot = Boxplot::new_horizontal("group", &quartiles);
     /// ```
     pub fn new_horizontal(key: K, quartiles: &Quartiles) -> Self {
+        Self::from_quartiles(key, quartiles)
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f64>> Boxplot<K, O> {
+    /// Create a new boxplot element in any orientation
+    pub(crate) fn from_quartiles(key: K, quartiles: &Quartiles) -> Self {
         Self {
             style: Into::<ShapeStyle>::into(&BLACK),
             width: DEFAULT_WIDTH,
+            width_ratio: None,
             whisker_width: 1.0,
             offset: 0.0,
             key,
             values: quartiles.values(),
+            count: quartiles.count(),
+            notch: None,
+            outliers: quartiles.outliers().to_vec(),
+            outlier_style: None,
+            outlier_marker: MarkerShape::Circle,
+            outlier_size: DEFAULT_OUTLIER_SIZE,
+            jitter: None,
             _p: PhantomData,
         }
     }
-}
 
-impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
     /// Set the style of the boxplot.
     ///
     /// - `S`: The required style
@@ -175,31 +217,170 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self.offset = offset.into();
         self
     }
+
+    /// Draw a notch around the median, which spans the confidence interval of the median. If the
+    /// notches of two boxes don't overlap, the medians are significantly different. The notch is
+    /// limited by the box.
+    ///
+    /// - `confidence`: The confidence level in range `0.0..1.0`, 0.95 gives the usual
+    ///   median ± 1.57 × IQR / √n notch
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).notched(0.95);
+    /// ```
+    pub fn notched(mut self, confidence: f64) -> Self {
+        self.notch = if confidence > 0.0 && confidence < 1.0 && self.count > 0 {
+            let iqr = self.values[3] - self.values[1];
+            let scale = 1.57 * normal_quantile(confidence) / normal_quantile(0.95);
+            Some(scale * iqr / (self.count as f64).sqrt())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Set the style of the outlier markers, by default the style of the box is used.
+    ///
+    /// - `style`: The required style
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41, 120]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).outlier_style(RED.filled());
+    /// ```
+    pub fn outlier_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.outlier_style = Some(style.into());
+        self
+    }
+
+    /// Set the shape of the outlier markers, which is a circle by default.
+    ///
+    /// - `marker`: The required marker shape
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41, 120]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).outlier_marker(MarkerShape::Cross);
+    /// ```
+    pub fn outlier_marker(mut self, marker: MarkerShape) -> Self {
+        self.outlier_marker = marker;
+        self
+    }
+
+    /// Set the size of the outlier markers in pixels.
+    ///
+    /// - `size`: The required size
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41, 120]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).outlier_size(2);
+    /// ```
+    pub fn outlier_size(mut self, size: u32) -> Self {
+        self.outlier_size = size;
+        self
+    }
+
+    /// Spread the outlier markers randomly along the key axis, so the outliers with similar
+    /// values don't hide each other. The offsets are pseudo-random, but the same seed always
+    /// gives the same offsets.
+    ///
+    /// - `seed`: The seed of the offsets
+    /// - `amount`: The largest offset in pixels, in both directions
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41, 120, 121, 121]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).jitter(42, 3.0);
+    /// ```
+    pub fn jitter(mut self, seed: u64, amount: f64) -> Self {
+        self.jitter = Some((seed, amount));
+        self
+    }
+}
+
+impl<T: Clone, O: BoxplotOrient<SegmentValue<T>, f64>> Boxplot<SegmentValue<T>, O> {
+    /// Set the bar width as a fraction of the segment of the key on a segmented key axis, thus
+    /// the box scales with the chart. The width set with [width](#method.width) is used when the
+    /// key isn't the center of a segment.
+    ///
+    /// - `ratio`: The fraction of the segment, such as 0.6 for 60 percent of the segment
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical(SegmentValue::CenterOf(1), &quartiles).width_relative(0.6);
+    /// ```
+    pub fn width_relative(mut self, ratio: f64) -> Self {
+        self.width_ratio = match self.key {
+            SegmentValue::CenterOf(ref key) => Some((ratio, SegmentValue::Exact(key.clone()))),
+            _ => None,
+        };
+        self
+    }
 }
 
-impl<'a, K: Clone, O: BoxplotOrient<K, f32>> PointCollection<'a, (O::XType, O::YType)>
+impl<'a, K: Clone, O: BoxplotOrient<K, f64>> PointCollection<'a, (O::XType, O::YType)>
     for &'a Boxplot<K, O>
 {
     type Point = (O::XType, O::YType);
     type IntoIter = Vec<Self::Point>;
     fn point_iter(self) -> Self::IntoIter {
+        let notch = self.notch.iter().flat_map(|notch| {
+            let (lower, median, upper) = (self.values[1], self.values[2], self.values[3]);
+            vec![(median - notch).max(lower), (median + notch).min(upper)]
+        });
         self.values
             .iter()
-            .map(|v| O::make_coord(self.key.clone(), *v))
+            .cloned()
+            .chain(notch)
+            .chain(self.outliers.iter().cloned())
+            .map(|v| O::make_coord(self.key.clone(), v))
+            // The start of the segment, which gives the width relative to the segment
+            .chain(
+                self.width_ratio
+                    .iter()
+                    .map(|(_, start)| O::make_coord(start.clone(), self.values[2])),
+            )
             .collect()
     }
 }
 
-impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K, O> {
+impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f64>> Drawable<DB> for Boxplot<K, O> {
     fn draw<I: Iterator<Item = BackendCoord>>(
         &self,
         points: I,
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        let points: Vec<_> = points.take(5).collect();
-        if points.len() == 5 {
-            let width = f64::from(self.width);
+        let expected = if self.notch.is_some() { 7 } else { 5 };
+        let total = expected + self.outliers.len() + self.width_ratio.iter().count();
+        let mut points: Vec<_> = points.take(total).collect();
+        if check_point_count("Boxplot", expected, points.len()) {
+            let mut width = f64::from(self.width);
+            if let (Some((ratio, _)), true) = (&self.width_ratio, points.len() == total) {
+                let start = points.pop().unwrap();
+                // The start of the segment is at the median value, so it's only apart from
+                // the median along the key axis, by the half of the segment
+                let half = (start.0 - points[2].0).abs() + (start.1 - points[2].1).abs();
+                if half > 0 {
+                    width = f64::from(half) * 2.0 * *ratio;
+                }
+            }
             let moved = |coord| O::with_offset(coord, self.offset);
             let start_bar = |coord| O::with_offset(moved(coord), -width / 2.0);
             let end_bar = |coord| O::with_offset(moved(coord), width / 2.0);
@@ -227,15 +408,36 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
 
             // |---[   |  ]----|
             // ____^______^_____
-            let corner1 = start_bar(points[3]);
-            let corner2 = end_bar(points[1]);
-            let upper_left = (corner1.0.min(corner2.0), corner1.1.min(corner2.1));
-            let bottom_right = (corner1.0.max(corner2.0), corner1.1.max(corner2.1));
-            backend.draw_rect(upper_left, bottom_right, &self.style, false)?;
-
-            // |---[   |  ]----|
-            // ________^________
-            backend.draw_line(start_bar(points[2]), end_bar(points[2]), &self.style)?;
+            if self.notch.is_some() {
+                // The box narrows to the half of its width at the median
+                let start_notch = |coord| O::with_offset(moved(coord), -width / 4.0);
+                let end_notch = |coord| O::with_offset(moved(coord), width / 4.0);
+                let outline = vec![
+                    start_bar(points[1]),
+                    start_bar(points[5]),
+                    start_notch(points[2]),
+                    start_bar(points[6]),
+                    start_bar(points[3]),
+                    end_bar(points[3]),
+                    end_bar(points[6]),
+                    end_notch(points[2]),
+                    end_bar(points[5]),
+                    end_bar(points[1]),
+                    start_bar(points[1]),
+                ];
+                backend.draw_path(outline, &self.style)?;
+                backend.draw_line(start_notch(points[2]), end_notch(points[2]), &self.style)?;
+            } else {
+                let corner1 = start_bar(points[3]);
+                let corner2 = end_bar(points[1]);
+                let upper_left = (corner1.0.min(corner2.0), corner1.1.min(corner2.1));
+                let bottom_right = (corner1.0.max(corner2.0), corner1.1.max(corner2.1));
+                backend.draw_rect(upper_left, bottom_right, &self.style, false)?;
+
+                // |---[   |  ]----|
+                // ________^________
+                backend.draw_line(start_bar(points[2]), end_bar(points[2]), &self.style)?;
+            }
 
             // |---[   |  ]----|
             // ____________^^^^_
@@ -248,6 +450,21 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
                 end_whisker(points[4]),
                 &self.style,
             )?;
+
+            let outlier_style = self.outlier_style.as_ref().unwrap_or(&self.style);
+            let mut jitter = self
+                .jitter
+                .map(|(seed, amount)| (JitterRng::new(seed), amount));
+            for point in &points[expected..] {
+                let point = match jitter {
+                    Some((ref mut rng, amount)) => {
+                        O::with_offset(moved(*point), (rng.next() * 2.0 - 1.0) * amount)
+                    }
+                    None => moved(*point),
+                };
+                self.outlier_marker
+                    .draw(backend, point, self.outlier_size, outlier_style)?;
+            }
         }
         Ok(())
     }
@@ -258,11 +475,23 @@ mod test {
     use super::*;
     use crate::prelude::*;
 
+    #[test]
+    fn test_draw_missing_points() {
+        let mut backend = MockedBackend::new(100, 100);
+        let boxplot = Boxplot::new_vertical(0, &Quartiles::new(&[1, 2, 3]));
+        // The boxplot without the 5 points is skipped rather than drawn partially
+        assert!(boxplot
+            .draw(vec![(0, 0), (0, 1)].into_iter(), &mut backend, (100, 100))
+            .is_ok());
+        assert_eq!(backend.num_draw_line_call, 0);
+        assert_eq!(backend.num_draw_rect_call, 0);
+    }
+
     #[test]
     fn test_draw_v() {
         let root = MockedBackend::new(1024, 768).into_drawing_area();
         let chart = ChartBuilder::on(&root)
-            .build_cartesian_2d(0..2, 0f32..100f32)
+            .build_cartesian_2d(0..2, 0f64..100f64)
             .unwrap();
 
         let values = Quartiles::new(&[6]);
@@ -276,7 +505,7 @@ mod test {
     fn test_draw_h() {
         let root = MockedBackend::new(1024, 768).into_drawing_area();
         let chart = ChartBuilder::on(&root)
-            .build_cartesian_2d(0f32..100f32, 0..2)
+            .build_cartesian_2d(0f64..100f64, 0..2)
             .unwrap();
 
         let values = Quartiles::new(&[6]);
@@ -285,4 +514,127 @@ mod test {
             .draw(&Boxplot::new_horizontal(1, &values))
             .is_ok());
     }
+
+    #[test]
+    fn test_draw_notched() {
+        let root = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 11);
+                assert_eq!(path[0], path[10]);
+                // The notch is indented by the quarter of the width at the median
+                assert_eq!(path[2].0 - path[0].0, 5);
+                assert_eq!(path[7].0 - path[5].0, -5);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_rect_call, 0);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, 0f64..100f64)
+            .unwrap();
+
+        let samples: Vec<_> = (0..100).collect();
+        let values = Quartiles::new(&samples);
+        let boxplot = Boxplot::new_vertical(1, &values).width(20).notched(0.95);
+        let points: Vec<_> = (&boxplot).point_iter();
+        let notch = 1.57 * (values.values()[3] - values.values()[1]) / 10.0;
+        assert!((points[5].1 - (49.5 - notch)).abs() < 1e-2);
+        assert!((points[6].1 - (49.5 + notch)).abs() < 1e-2);
+        assert!(chart.plotting_area().draw(&boxplot).is_ok());
+    }
+
+    #[test]
+    fn test_width_relative() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let widths = Rc::new(RefCell::new(vec![]));
+        let collected = widths.clone();
+        let root = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_rect(move |_, _, _, ul, br| collected.borrow_mut().push(br.0 - ul.0));
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d((0..3).into_segmented(), 0f64..100f64)
+            .unwrap();
+        let segment = chart.backend_coord(&(SegmentValue::Exact(2), 0.0)).0
+            - chart.backend_coord(&(SegmentValue::Exact(1), 0.0)).0;
+
+        let values = Quartiles::new(&[10, 20, 30, 40, 50]);
+        let relative =
+            Boxplot::new_vertical(SegmentValue::CenterOf(1), &values).width_relative(0.6);
+        chart.plotting_area().draw(&relative).unwrap();
+        // The key that isn't the center of a segment keeps the fixed width
+        let fixed = Boxplot::new_vertical(SegmentValue::Exact(2), &values).width_relative(0.6);
+        chart.plotting_area().draw(&fixed).unwrap();
+
+        let widths = widths.borrow();
+        assert!((f64::from(widths[0]) - f64::from(segment) * 0.6).abs() <= 2.0);
+        assert_eq!(widths[1], DEFAULT_WIDTH as i32);
+    }
+
+    #[test]
+    fn test_draw_outliers() {
+        let root = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_line(|c, _, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+            });
+            m.check_draw_rect(|c, _, filled, (x0, y0), (x1, y1)| {
+                if filled {
+                    assert_eq!(c, RED.to_rgba());
+                    assert_eq!((x1 - x0, y1 - y0), (4, 4));
+                }
+            });
+            m.drop_check(|b| {
+                // The box and two outliers
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_circle_call, 0);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, 0f64..200f64)
+            .unwrap();
+
+        let values = Quartiles::new(&[1, 40, 41, 42, 43, 44, 150]);
+        assert_eq!(values.outliers(), &[1.0, 150.0]);
+        let boxplot = Boxplot::new_vertical(1, &values)
+            .outlier_style(RED.filled())
+            .outlier_marker(MarkerShape::Square)
+            .outlier_size(2);
+        assert!(chart.plotting_area().draw(&boxplot).is_ok());
+    }
+
+    #[test]
+    fn test_outlier_jitter() {
+        let draw_outliers = |seed| {
+            let centers = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+            let recorded = centers.clone();
+            let root = create_mocked_drawing_area(1024, 768, move |m| {
+                m.check_draw_circle(move |_, _, _, center, _| {
+                    recorded.borrow_mut().push(center);
+                });
+            });
+            let chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0..2, 0f64..200f64)
+                .unwrap();
+            let mut samples: Vec<_> = (40..50).collect();
+            samples.extend(vec![150; 3]);
+            let values = Quartiles::new(&samples);
+            let boxplot = Boxplot::new_vertical(1, &values).jitter(seed, 5.0);
+            chart.plotting_area().draw(&boxplot).unwrap();
+            drop(chart);
+            drop(root);
+            let centers = centers.borrow().clone();
+            centers
+        };
+
+        let centers = draw_outliers(1);
+        assert_eq!(centers.len(), 3);
+        assert!(centers.iter().all(|c| c.1 == centers[0].1));
+        assert!(centers.iter().any(|c| c.0 != centers[0].0));
+        assert_eq!(centers, draw_outliers(1));
+
+        let mut rng = JitterRng::new(0);
+        assert!((0..100).map(|_| rng.next()).all(|v| v >= 0.0 && v < 1.0));
+    }
 }
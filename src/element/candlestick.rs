@@ -1,18 +1,23 @@
 /*!
-  The candlestick element, which showing the high/low/open/close price
+  The candlestick and OHLC bar elements, which showing the high/low/open/close price
 */
 
 use std::cmp::Ordering;
+use std::ops::Add;
 
-use crate::element::{Drawable, PointCollection};
+use crate::element::{check_point_count, Drawable, PointCollection};
 use crate::style::ShapeStyle;
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// The candlestick data point element
 pub struct CandleStick<X, Y: PartialOrd> {
     style: ShapeStyle,
+    wick_style: Option<ShapeStyle>,
+    gain: bool,
+    fill: (bool, bool),
     width: u32,
-    points: [(X, Y); 4],
+    width_ratio: f64,
+    points: Vec<(X, Y)>,
 }
 
 impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
@@ -45,13 +50,19 @@ impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
         loss_style: LS,
         width: u32,
     ) -> Self {
+        let gain = open.partial_cmp(&close) == Some(Ordering::Less);
         Self {
-            style: match open.partial_cmp(&close) {
-                Some(Ordering::Less) => gain_style.into(),
-                _ => loss_style.into(),
+            style: if gain {
+                gain_style.into()
+            } else {
+                loss_style.into()
             },
+            wick_style: None,
+            gain,
+            fill: (false, false),
             width,
-            points: [
+            width_ratio: 0.0,
+            points: vec![
                 (x.clone(), open),
                 (x.clone(), high),
                 (x.clone(), low),
@@ -59,6 +70,71 @@ impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
             ],
         }
     }
+
+    /// Fill the bodies of the candlesticks, which are hollow by default.
+    ///
+    /// - `gain`: If the body of a gain is filled
+    /// - `loss`: If the body of a loss is filled
+    /// - **returns** The up-to-dated candlestick element
+    pub fn fill_body(mut self, gain: bool, loss: bool) -> Self {
+        self.fill = (gain, loss);
+        self
+    }
+
+    /// Draw the gains with the hollow bodies and the losses with the filled bodies, as the
+    /// traditional candlestick charts do.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let candlestick = CandleStick::new(1, 130.06, 131.37, 128.83, 129.15, &GREEN, &RED, 15)
+    ///     .hollow_gain()
+    ///     .wick_style(&BLACK);
+    /// ```
+    ///
+    /// - **returns** The up-to-dated candlestick element
+    pub fn hollow_gain(self) -> Self {
+        self.fill_body(false, true)
+    }
+
+    /// Set the style of the wicks, that is the high/low lines, which are drawn with the style of
+    /// the body by default.
+    ///
+    /// - `style`: The style of the wicks
+    /// - **returns** The up-to-dated candlestick element
+    pub fn wick_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.wick_style = Some(style.into());
+        self
+    }
+
+    /// Compute the width of the body from the distance between the X coordinate and the next
+    /// one, so that the candlesticks keep their proportion at any zoom level. The width given
+    /// when the element is created is used as the minimum width.
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    /// use plotters::prelude::*;
+    ///
+    /// let day = Utc.ymd(2019, 4, 25);
+    /// // The body takes 80 percent of a day
+    /// let candlestick = CandleStick::new(day, 130.06, 131.37, 128.83, 129.15, &GREEN, &RED, 1)
+    ///     .auto_width(chrono::Duration::days(1), 0.8);
+    /// ```
+    ///
+    /// - `step`: The distance to the next X coordinate, such as the length of the period
+    /// - `ratio`: The fraction of the distance that the body takes
+    /// - **returns** The up-to-dated candlestick element
+    pub fn auto_width<D>(mut self, step: D, ratio: f64) -> Self
+    where
+        X: Add<D, Output = X>,
+        Y: Clone,
+    {
+        let (x, open) = self.points[0].clone();
+        self.points.truncate(4);
+        self.points.push((x + step, open));
+        self.width_ratio = ratio;
+        self
+    }
 }
 
 impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a CandleStick<X, Y> {
@@ -76,19 +152,24 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        let mut points: Vec<_> = points.take(4).collect();
-        if points.len() == 4 {
-            let fill = false;
+        let mut points: Vec<_> = points.take(self.points.len()).collect();
+        if check_point_count("CandleStick", self.points.len(), points.len()) {
+            let fill = if self.gain { self.fill.0 } else { self.fill.1 };
+            let width = match points.get(4) {
+                Some(next) => {
+                    let auto = f64::from((next.0 - points[0].0).abs()) * self.width_ratio;
+                    self.width.max(auto.round() as u32)
+                }
+                None => self.width,
+            };
             if points[0].1 > points[3].1 {
                 points.swap(0, 3);
             }
-            let (l, r) = (
-                self.width as i32 / 2,
-                self.width as i32 - self.width as i32 / 2,
-            );
+            let (l, r) = (width as i32 / 2, width as i32 - width as i32 / 2);
 
-            backend.draw_line(points[0], points[1], &self.style)?;
-            backend.draw_line(points[2], points[3], &self.style)?;
+            let wick_style = self.wick_style.as_ref().unwrap_or(&self.style);
+            backend.draw_line(points[0], points[1], wick_style)?;
+            backend.draw_line(points[2], points[3], wick_style)?;
 
             points[0].0 -= l;
             points[3].0 += r;
@@ -98,3 +179,156 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
         Ok(())
     }
 }
+
+/// The OHLC bar data point element, which shows the open and close price as the ticks on the
+/// left and the right side of the high/low line, instead of a candlestick body
+pub struct OHLCBar<X, Y: PartialOrd> {
+    style: ShapeStyle,
+    width: u32,
+    points: [(X, Y); 4],
+}
+
+impl<X: Clone, Y: PartialOrd> OHLCBar<X, Y> {
+    /// Create a new OHLC bar element, which requires the Y coordinate can be compared
+    ///
+    /// - `x`: The x coordinate
+    /// - `open`: The open value
+    /// - `high`: The high value
+    /// - `low`: The low value
+    /// - `close`: The close value
+    /// - `gain_style`: The style for gain
+    /// - `loss_style`: The style for loss
+    /// - `width`: The total width of the open and close ticks
+    /// - **returns** The newly created OHLC bar element
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    /// use plotters::prelude::*;
+    ///
+    /// let bar = OHLCBar::new(Local::now(), 130.0600, 131.3700, 128.8300, 129.1500, &GREEN, &RED, 15);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+        x: X,
+        open: Y,
+        high: Y,
+        low: Y,
+        close: Y,
+        gain_style: GS,
+        loss_style: LS,
+        width: u32,
+    ) -> Self {
+        Self {
+            style: match open.partial_cmp(&close) {
+                Some(Ordering::Less) => gain_style.into(),
+                _ => loss_style.into(),
+            },
+            width,
+            points: [
+                (x.clone(), open),
+                (x.clone(), high),
+                (x.clone(), low),
+                (x, close),
+            ],
+        }
+    }
+}
+
+impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a OHLCBar<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for OHLCBar<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(4).collect();
+        if check_point_count("OHLCBar", 4, points.len()) {
+            let (open, high, low, close) = (points[0], points[1], points[2], points[3]);
+            let (l, r) = (
+                self.width as i32 / 2,
+                self.width as i32 - self.width as i32 / 2,
+            );
+
+            backend.draw_line(high, low, &self.style)?;
+            backend.draw_line((open.0 - l, open.1), open, &self.style)?;
+            backend.draw_line(close, (close.0 + r, close.1), &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_candlestick_style() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(from.0, to.0);
+            });
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                // The body of a loss is filled, and it takes 80 percent of the step
+                assert!(filled);
+                assert_eq!((ul.0, br.0), (46, 54));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 2);
+                assert_eq!(b.num_draw_rect_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(
+                CandleStick::new(50.0, 70.0, 90.0, 10.0, 30.0, &GREEN, &RED, 1)
+                    .hollow_gain()
+                    .wick_style(&BLACK)
+                    .auto_width(10.0, 0.8),
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_ohlc_bar() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, GREEN.to_rgba());
+                assert_eq!((from, to), ((50, 9), (50, 89)));
+            });
+            m.check_draw_line(|_, _, from, to| {
+                // The open tick is on the left
+                assert_eq!((from.0, to.0), (45, 50));
+                assert_eq!(from.1, to.1);
+            });
+            m.check_draw_line(|_, _, from, to| {
+                // The close tick is on the right and above the open tick
+                assert_eq!((from.0, to.0), (50, 55));
+                assert!(from.1 < 50);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(OHLCBar::new(
+                50.0, 30.0, 90.0, 10.0, 70.0, &GREEN, &RED, 10,
+            )))
+            .unwrap();
+    }
+}
@@ -0,0 +1,363 @@
+use std::ops::Range;
+
+use super::{Drawable, PointCollection};
+use crate::coord::ranged1d::ValueFormatter;
+use crate::coord::types::RangedCoordf64;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ColorMap, Normalizer, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The length of the tick marks of a colorbar
+const TICK_SIZE: i32 = 5;
+/// The gap between the tick marks and the labels
+const LABEL_GAP: i32 = 3;
+
+/// The formatter of the colorbar labels
+type ColorbarFormatter<'a> = Box<dyn Fn(f64) -> String + 'a>;
+
+/// The element that explains a [ColorMap](../style/trait.ColorMap.html) with a gradient strip and
+/// the ticks for the mapped value range.
+///
+/// The strip is drawn as thin rectangles of one pixel each, thus both the bitmap and SVG output
+/// get a crisp gradient. A quantized color map, which reports its
+/// [levels](../style/trait.ColorMap.html#method.levels), is drawn as discrete blocks, and so is
+/// a [Normalizer::Discrete](../style/enum.Normalizer.html#variant.Discrete).
+///
+/// The element is positioned by its upper left corner. To place it inside the plotting area, use
+/// [ChartContext::draw_colorbar_inside](../chart/struct.ChartContext.html#method.draw_colorbar_inside),
+/// or reserve an area next to the plotting area with
+/// [ChartBuilder::colorbar_area_size](../chart/struct.ChartBuilder.html#method.colorbar_area_size)
+/// and use [ChartContext::draw_colorbar](../chart/struct.ChartContext.html#method.draw_colorbar).
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .colorbar_area_size(60)
+///     .build_cartesian_2d(0..10, 0..10)
+///     .unwrap();
+/// chart
+///     .draw_colorbar(
+///         Colorbar::new(ViridisRGB, 0.0..100.0).label_formatter(|v| format!("{}°", v)),
+///     )
+///     .unwrap();
+/// ```
+pub struct Colorbar<'a, M: ColorMap> {
+    pos: BackendCoord,
+    map: M,
+    range: Range<f64>,
+    normalizer: Normalizer,
+    horizontal: bool,
+    size: (u32, u32),
+    num_ticks: usize,
+    label_style: TextStyle<'a>,
+    formatter: Option<ColorbarFormatter<'a>>,
+}
+
+impl<'a, M: ColorMap> Colorbar<'a, M> {
+    /// Create a new vertical colorbar
+    /// - `map`: The color map to explain
+    /// - `range`: The value range that is mapped onto the color map
+    /// - **returns** The newly created colorbar, which is 200 pixels long and 20 pixels thick
+    pub fn new(map: M, range: Range<f64>) -> Self {
+        Self {
+            pos: (0, 0),
+            map,
+            range,
+            normalizer: Normalizer::Linear,
+            horizontal: false,
+            size: (200, 20),
+            num_ticks: 5,
+            label_style: ("sans-serif", 12).into(),
+            formatter: None,
+        }
+    }
+
+    /// Set how the values are mapped onto the color map, which should be the same as the
+    /// chart that the colorbar explains. The strip follows the normalizer, thus the ticks of a
+    /// logarithmic colorbar are at the powers of ten, evenly spaced.
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Draw the colorbar horizontally, the smallest value is on the left side
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Set the size of the gradient strip
+    /// - `length`: The length of the strip in pixels
+    /// - `thickness`: The thickness of the strip in pixels
+    pub fn size(mut self, length: u32, thickness: u32) -> Self {
+        self.size = (length, thickness);
+        self
+    }
+
+    /// Set the maximum number of ticks
+    pub fn ticks(mut self, num_ticks: usize) -> Self {
+        self.num_ticks = num_ticks;
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the formatter of the labels
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, formatter: F) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Move the colorbar to the given upper left corner
+    pub fn move_to(&mut self, pos: BackendCoord) {
+        self.pos = pos;
+    }
+
+    /// Get the value range that is mapped onto the color map
+    pub fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+
+    /// Get the length of the gradient strip in pixels
+    pub fn length(&self) -> u32 {
+        self.size.0
+    }
+
+    /// Check if the colorbar is drawn horizontally
+    pub fn is_horizontal(&self) -> bool {
+        self.horizontal
+    }
+
+    /// Get the ticks with their labels
+    fn ticks_with_labels(&self) -> Vec<(f64, String)> {
+        self.normalizer
+            .ticks(self.range.start, self.range.end, self.num_ticks)
+            .into_iter()
+            .map(|value| {
+                let label = match self.formatter {
+                    Some(ref f) => f(value),
+                    None => RangedCoordf64::format(&value),
+                };
+                (value, label)
+            })
+            .collect()
+    }
+
+    /// Change the length of the strip, while the thickness is kept
+    pub(crate) fn with_length(mut self, length: u32) -> Self {
+        self.size.0 = length;
+        self
+    }
+
+    /// Get the size of the largest label
+    pub(crate) fn label_extent(&self) -> (u32, u32) {
+        let (mut label_w, mut label_h) = (0, 0);
+        for (_, label) in self.ticks_with_labels() {
+            let (w, h) = self.label_style.font.box_size(&label).unwrap_or((0, 0));
+            label_w = label_w.max(w);
+            label_h = label_h.max(h);
+        }
+        (label_w, label_h)
+    }
+
+    /// Estimate the size of the element including the labels
+    pub fn estimate_dimension(&self) -> (u32, u32) {
+        let (label_w, label_h) = self.label_extent();
+        let (length, thickness) = self.size;
+        let extra = (TICK_SIZE + LABEL_GAP) as u32;
+        if self.horizontal {
+            (length + label_w, thickness + extra + label_h)
+        } else {
+            (thickness + extra + label_w, length + label_h)
+        }
+    }
+
+    /// Map the value to the offset along the strip
+    fn offset_of(&self, value: f64) -> i32 {
+        let (min, max) = (self.range.start, self.range.end);
+        let length = self.size.0 as i32;
+        let t = self.normalizer.position(value, min, max);
+        if !t.is_finite() {
+            return 0;
+        }
+        let offset = (t * (length - 1) as f64).round() as i32;
+        if self.horizontal {
+            offset
+        } else {
+            length - 1 - offset
+        }
+    }
+
+    /// Get the color of the given offset along the strip
+    fn color_at(&self, offset: i32) -> ShapeStyle {
+        let length = self.size.0.max(2) as f64;
+        let t = f64::from(offset) / (length - 1.0);
+        let t = if self.horizontal { t } else { 1.0 - t };
+        let t = self.normalizer.quantize(t);
+        let h = match self.map.levels() {
+            // Use the center of the level, thus every block has a single color
+            Some(levels) => {
+                ((t * levels as f64).floor().min(levels as f64 - 1.0) + 0.5) / levels as f64
+            }
+            None => t,
+        };
+        self.map.get_color(h).filled()
+    }
+}
+
+impl<'b, 'a, M: ColorMap> PointCollection<'b, BackendCoord> for &'b Colorbar<'a, M> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, M: ColorMap, DB: DrawingBackend> Drawable<DB> for Colorbar<'a, M> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let (length, thickness) = (self.size.0 as i32, self.size.1 as i32);
+        let strip = |offset: i32, span: i32| {
+            if self.horizontal {
+                (
+                    (x0 + offset, y0),
+                    (x0 + offset + span - 1, y0 + thickness - 1),
+                )
+            } else {
+                (
+                    (x0, y0 + offset),
+                    (x0 + thickness - 1, y0 + offset + span - 1),
+                )
+            }
+        };
+
+        // Neighbouring pixels of the same color are merged, so a quantized map is drawn as blocks
+        let mut start = 0;
+        while start < length {
+            let style = self.color_at(start);
+            let mut end = start + 1;
+            while end < length && self.color_at(end).color == style.color {
+                end += 1;
+            }
+            let (a, b) = strip(start, end - start);
+            backend.draw_rect(a, b, &style, true)?;
+            start = end;
+        }
+
+        let (a, b) = strip(0, length);
+        backend.draw_rect(a, b, &BLACK.to_rgba(), false)?;
+
+        let label_style = if self.horizontal {
+            self.label_style.pos(Pos::new(HPos::Center, VPos::Top))
+        } else {
+            self.label_style.pos(Pos::new(HPos::Left, VPos::Center))
+        };
+
+        for (value, label) in self.ticks_with_labels() {
+            let offset = self.offset_of(value);
+            let (tick_from, tick_to, label_pos) = if self.horizontal {
+                let (x, y) = (x0 + offset, y0 + thickness);
+                ((x, y), (x, y + TICK_SIZE), (x, y + TICK_SIZE + LABEL_GAP))
+            } else {
+                let (x, y) = (x0 + thickness, y0 + offset);
+                ((x, y), (x + TICK_SIZE, y), (x + TICK_SIZE + LABEL_GAP, y))
+            };
+            backend.draw_line(tick_from, tick_to, &BLACK.to_rgba())?;
+            backend.draw_text(&label, &label_style, label_pos)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_colorbar_gradient() {
+        let drawing_area = create_mocked_drawing_area(200, 300, |m| {
+            m.check_draw_rect(|c, _, filled, (x0, y0), (x1, _)| {
+                // The largest value is at the top of the vertical colorbar
+                assert!(filled);
+                assert_eq!(y0, 10);
+                assert_eq!((x0, x1), (10, 29));
+                assert_eq!(c.rgb(), (255, 255, 255));
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 100 + 1);
+                assert!(b.num_draw_text_call >= 2);
+            });
+        });
+
+        let mut colorbar = Colorbar::new(BlackWhite, 0.0..1.0).size(100, 20);
+        colorbar.move_to((10, 10));
+        drawing_area.draw(&colorbar).unwrap();
+    }
+
+    #[test]
+    fn test_colorbar_quantized() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_rect(|c, _, _, (x0, _), _| {
+                assert_eq!(x0, 0);
+                assert_eq!(c.rgb(), (0, 0, 0));
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4 + 1);
+            });
+        });
+
+        let colorbar = Colorbar::new(QuantizedColorMap::new(BlackWhite, 4), 0.0..1.0)
+            .size(200, 10)
+            .horizontal()
+            .label_formatter(|v| format!("{:.1}", v));
+        drawing_area.draw(&colorbar).unwrap();
+    }
+
+    #[test]
+    fn test_colorbar_normalizer() {
+        // The discrete bins are drawn as blocks, with a tick at each boundary
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3 + 1);
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+        let colorbar = Colorbar::new(BlackWhite, 0.0..100.0)
+            .normalizer(Normalizer::Discrete {
+                boundaries: vec![0.0, 1.0, 10.0, 100.0],
+            })
+            .size(200, 10)
+            .horizontal();
+        drawing_area.draw(&colorbar).unwrap();
+
+        // The ticks of a logarithmic colorbar are evenly spaced
+        let colorbar = Colorbar::new(BlackWhite, 1.0..1000.0)
+            .normalizer(Normalizer::Log)
+            .size(301, 10)
+            .horizontal();
+        let offsets: Vec<_> = [1.0, 10.0, 100.0, 1000.0]
+            .iter()
+            .map(|v| colorbar.offset_of(*v))
+            .collect();
+        assert_eq!(offsets, vec![0, 100, 200, 300]);
+    }
+}
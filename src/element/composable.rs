@@ -1,4 +1,5 @@
 use super::*;
+use crate::drawing::{BackendCapabilities, BlendMode};
 use plotters_backend::DrawingBackend;
 use std::borrow::Borrow;
 use std::iter::{once, Once};
@@ -77,23 +78,38 @@ where
     A: Drawable<DB>,
 {
     fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw_with_capabilities(pos, backend, ps, &BackendCapabilities::all())
+    }
+
+    fn draw_with_capabilities<I: Iterator<Item = BackendCoord>>(
         &self,
         mut pos: I,
         backend: &mut DB,
         ps: (u32, u32),
+        capabilities: &BackendCapabilities,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x0, y0)) = pos.next() {
-            self.inner.draw(
+            self.inner.draw_with_capabilities(
                 self.inner.point_iter().into_iter().map(|p| {
                     let p = p.borrow();
                     (p.0 + x0, p.1 + y0)
                 }),
                 backend,
                 ps,
+                capabilities,
             )?;
         }
         Ok(())
     }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.inner.blend_mode()
+    }
 }
 
 impl<Coord, DB: DrawingBackend, My, Yours> Add<Yours> for BoxedElement<Coord, DB, My>
@@ -147,31 +163,51 @@ where
     B: Drawable<DB>,
 {
     fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw_with_capabilities(pos, backend, ps, &BackendCapabilities::all())
+    }
+
+    fn draw_with_capabilities<I: Iterator<Item = BackendCoord>>(
         &self,
         mut pos: I,
         backend: &mut DB,
         ps: (u32, u32),
+        capabilities: &BackendCapabilities,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x0, y0)) = pos.next() {
-            self.first.draw(
+            self.first.draw_with_capabilities(
                 self.first.point_iter().into_iter().map(|p| {
                     let p = p.borrow();
                     (p.0 + x0, p.1 + y0)
                 }),
                 backend,
                 ps,
+                capabilities,
             )?;
-            self.second.draw(
+            self.second.draw_with_capabilities(
                 self.second.point_iter().into_iter().map(|p| {
                     let p = p.borrow();
                     (p.0 + x0, p.1 + y0)
                 }),
                 backend,
                 ps,
+                capabilities,
             )?;
         }
         Ok(())
     }
+
+    // The anchor of a composed element is usually the empty element, which has no style
+    fn blend_mode(&self) -> BlendMode {
+        match self.first.blend_mode() {
+            BlendMode::Normal => self.second.blend_mode(),
+            mode => mode,
+        }
+    }
 }
 
 impl<Coord, DB: DrawingBackend, A, B, C> Add<C> for ComposedElement<Coord, DB, A, B>
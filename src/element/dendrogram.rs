@@ -0,0 +1,282 @@
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// Where the root of a [Dendrogram](struct.Dendrogram.html) is
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DendrogramOrientation {
+    /// The leaves are along the X axis at the height 0, with the root above them
+    Top,
+    /// The leaves are along the X axis at the largest height, with the root below them
+    Bottom,
+    /// The leaves are along the Y axis at the largest height, with the root to the left of them
+    Left,
+    /// The leaves are along the Y axis at the height 0, with the root to the right of them
+    Right,
+}
+
+/// The tree of a hierarchical clustering, which is drawn from a linkage matrix.
+///
+/// The linkage matrix is in the same format as SciPy: the `n - 1` merges of `n` leaves, where
+/// each merge joins two clusters at a height. The leaves are the clusters `0` to `n - 1`, and
+/// the `i`-th merge forms the cluster `n + i`. The merges that refer to a cluster that isn't
+/// formed yet or is merged already are skipped.
+///
+/// The element is in the chart coordinate, the leaf in the slot `k` of the
+/// [leaf order](#method.leaf_order) is at `k + 0.5` and the heights are along the other axis.
+/// Thus a dendrogram on a chart of the range `0.0..n` lines up with the cells of a
+/// [HeatmapSeries](../series/struct.HeatmapSeries.html) of `n` columns or rows in the same
+/// range, whose grid is reordered by the leaf order, for a clustered heatmap.
+///
+/// The leaf labels are outside of the plotting area, thus the dendrogram is drawn with the
+/// [clipping](../chart/struct.ChartContext.html#method.clip) off when it has labels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// // The leaves 0 and 1 merge at 1.0, then the leaves 2 and 3 at 1.5, then the two clusters
+/// let linkage = vec![(0, 1, 1.0), (2, 3, 1.5), (4, 5, 3.0)];
+/// let names = ["ant", "bee", "cat", "dog"];
+/// let dendrogram = Dendrogram::new(&linkage, DendrogramOrientation::Top)
+///     .leaf_labels(|leaf| names[leaf].to_string());
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .margin_bottom(20)
+///     .build_cartesian_2d(0.0..4.0, 0.0..dendrogram.max_height())
+///     .unwrap();
+/// chart
+///     .clip(false)
+///     .draw_series(std::iter::once(dendrogram))
+///     .unwrap();
+/// ```
+pub struct Dendrogram<'a> {
+    orientation: DendrogramOrientation,
+    /// The position along the leaves and the height of each cluster
+    nodes: Vec<(f64, f64)>,
+    /// The two children of each valid merge and its cluster
+    merges: Vec<(usize, usize, usize)>,
+    order: Vec<usize>,
+    max_height: f64,
+    points: Vec<(f64, f64)>,
+    style: ShapeStyle,
+    labels: Vec<String>,
+    label_style: TextStyle<'a>,
+}
+
+impl<'a> Dendrogram<'a> {
+    /// Create a new dendrogram
+    /// - `linkage`: The merges as `(cluster, cluster, height)`
+    /// - `orientation`: Where the root is
+    /// - **returns** The newly created element
+    pub fn new(linkage: &[(usize, usize, f64)], orientation: DendrogramOrientation) -> Self {
+        let n = linkage.len() + 1;
+        let mut children = vec![None; n + linkage.len()];
+        let mut merged = vec![false; n + linkage.len()];
+        for (idx, &(a, b, _)) in linkage.iter().enumerate() {
+            if a < n + idx && b < n + idx && a != b && !merged[a] && !merged[b] {
+                merged[a] = true;
+                merged[b] = true;
+                children[n + idx] = Some((a, b));
+            }
+        }
+
+        // The leaves are ordered by walking each tree from the left child to the right child
+        let mut order = vec![];
+        for root in (0..n + linkage.len()).rev() {
+            if merged[root] || (root >= n && children[root].is_none()) {
+                continue;
+            }
+            let mut stack = vec![root];
+            while let Some(node) = stack.pop() {
+                match children[node] {
+                    Some((a, b)) => {
+                        stack.push(b);
+                        stack.push(a);
+                    }
+                    None => order.push(node),
+                }
+            }
+        }
+
+        let mut nodes = vec![(0.0, 0.0); n + linkage.len()];
+        for (slot, &leaf) in order.iter().enumerate() {
+            nodes[leaf] = (slot as f64 + 0.5, 0.0);
+        }
+        let mut merges = vec![];
+        let mut max_height = 0.0f64;
+        for (idx, &(_, _, height)) in linkage.iter().enumerate() {
+            if let Some((a, b)) = children[n + idx] {
+                nodes[n + idx] = ((nodes[a].0 + nodes[b].0) / 2.0, height);
+                merges.push((a, b, n + idx));
+                max_height = max_height.max(height);
+            }
+        }
+
+        let mut dendrogram = Self {
+            orientation,
+            nodes,
+            merges,
+            order,
+            max_height,
+            points: vec![],
+            style: BLACK.into(),
+            labels: vec![],
+            label_style: ("sans-serif", 12).into(),
+        };
+        dendrogram.points = dendrogram.layout();
+        dendrogram
+    }
+
+    /// Map a position along the leaves and a height to the chart coordinate
+    fn coord(&self, (pos, height): (f64, f64)) -> (f64, f64) {
+        match self.orientation {
+            DendrogramOrientation::Top => (pos, height),
+            DendrogramOrientation::Bottom => (pos, self.max_height - height),
+            DendrogramOrientation::Left => (self.max_height - height, pos),
+            DendrogramOrientation::Right => (height, pos),
+        }
+    }
+
+    /// The 4 points of the link of each merge, then the point of each leaf
+    fn layout(&self) -> Vec<(f64, f64)> {
+        let mut points = vec![];
+        for &(a, b, cluster) in &self.merges {
+            let (a, b, height) = (self.nodes[a], self.nodes[b], self.nodes[cluster].1);
+            for &point in [a, (a.0, height), (b.0, height), b].iter() {
+                points.push(self.coord(point));
+            }
+        }
+        for &leaf in &self.order {
+            points.push(self.coord(self.nodes[leaf]));
+        }
+        points
+    }
+
+    /// Set the style of the links
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Label the leaves, which are drawn next to the leaves on the opposite side of the root
+    /// - `format`: The function that formats the label of a leaf from its index in the linkage
+    pub fn leaf_labels<F: Fn(usize) -> String>(mut self, format: F) -> Self {
+        self.labels = self.order.iter().map(|leaf| format(*leaf)).collect();
+        self
+    }
+
+    /// Set the style of the leaf labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Get the leaves in the order they're drawn, which is the order that the rows or the
+    /// columns of a heatmap next to the dendrogram are sorted by
+    pub fn leaf_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Get the height of the highest merge, which is the end of the height range of a chart
+    /// that fits the dendrogram
+    pub fn max_height(&self) -> f64 {
+        self.max_height
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, (f64, f64)> for &'b Dendrogram<'a> {
+    type Point = &'b (f64, f64);
+    type IntoIter = &'b [(f64, f64)];
+    fn point_iter(self) -> &'b [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Dendrogram<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let links = self.merges.len() * 4;
+        if points.len() < links {
+            return Ok(());
+        }
+        for link in points[..links].chunks(4) {
+            backend.draw_path(link.iter().cloned(), &self.style)?;
+        }
+
+        const GAP: i32 = 5;
+        let (pos, offset) = match self.orientation {
+            DendrogramOrientation::Top => (Pos::new(HPos::Center, VPos::Top), (0, GAP)),
+            DendrogramOrientation::Bottom => (Pos::new(HPos::Center, VPos::Bottom), (0, -GAP)),
+            DendrogramOrientation::Left => (Pos::new(HPos::Left, VPos::Center), (GAP, 0)),
+            DendrogramOrientation::Right => (Pos::new(HPos::Right, VPos::Center), (-GAP, 0)),
+        };
+        let style = self.label_style.pos(pos);
+        for (label, leaf) in self.labels.iter().zip(&points[links..]) {
+            backend.draw_text(label, &style, (leaf.0 + offset.0, leaf.1 + offset.1))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dendrogram_layout() {
+        // The leaf 2 joins the cluster of 0 and 1, and the leaf 3 is merged last
+        let linkage = vec![(0, 1, 1.0), (2, 4, 2.0), (5, 3, 4.0)];
+        let dendrogram = Dendrogram::new(&linkage, DendrogramOrientation::Top);
+        assert_eq!(dendrogram.leaf_order(), &[2, 0, 1, 3]);
+        assert_eq!(dendrogram.max_height(), 4.0);
+        // The first link joins the leaves 0 and 1 at the height 1
+        assert_eq!(
+            &dendrogram.points[..4],
+            &[(1.5, 0.0), (1.5, 1.0), (2.5, 1.0), (2.5, 0.0)]
+        );
+        // The root is above the middle of its children
+        assert_eq!(dendrogram.nodes[6], (2.375, 4.0));
+
+        let dendrogram = Dendrogram::new(&linkage, DendrogramOrientation::Left);
+        assert_eq!(dendrogram.points[0], (4.0, 1.5));
+
+        // The merge of a cluster that is merged already is skipped
+        let dendrogram = Dendrogram::new(&[(0, 1, 1.0), (0, 2, 2.0)], DendrogramOrientation::Top);
+        assert_eq!(dendrogram.merges.len(), 1);
+        assert_eq!(dendrogram.leaf_order(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn test_draw_dendrogram() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 4));
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "leaf 1");
+                assert!(pos.1 > 90);
+            });
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "leaf 0"));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let dendrogram = Dendrogram::new(&[(1, 0, 1.0)], DendrogramOrientation::Top)
+            .leaf_labels(|leaf| format!("leaf {}", leaf));
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .clip(false)
+            .draw_series(std::iter::once(dendrogram))
+            .unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 use super::{Drawable, PointCollection};
+use crate::drawing::{BackendCapabilities, BlendMode};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 use std::borrow::Borrow;
@@ -9,7 +10,10 @@ trait DynDrawable<DB: DrawingBackend> {
         points: &mut dyn Iterator<Item = BackendCoord>,
         backend: &mut DB,
         parent_dim: (u32, u32),
+        capabilities: &BackendCapabilities,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    fn blend_mode_dyn(&self) -> BlendMode;
 }
 
 impl<DB: DrawingBackend, T: Drawable<DB>> DynDrawable<DB> for T {
@@ -18,8 +22,13 @@ impl<DB: DrawingBackend, T: Drawable<DB>> DynDrawable<DB> for T {
         points: &mut dyn Iterator<Item = BackendCoord>,
         backend: &mut DB,
         parent_dim: (u32, u32),
+        capabilities: &BackendCapabilities,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        T::draw(self, points, backend, parent_dim)
+        T::draw_with_capabilities(self, points, backend, parent_dim, capabilities)
+    }
+
+    fn blend_mode_dyn(&self) -> BlendMode {
+        T::blend_mode(self)
     }
 }
 
@@ -45,12 +54,28 @@ impl<'a, 'b: 'a, DB: DrawingBackend, Coord: Clone> PointCollection<'a, Coord>
 
 impl<'a, DB: DrawingBackend, Coord: Clone> Drawable<DB> for DynElement<'a, DB, Coord> {
     fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let capabilities = BackendCapabilities::all();
+        self.draw_with_capabilities(pos, backend, parent_dim, &capabilities)
+    }
+
+    fn draw_with_capabilities<I: Iterator<Item = BackendCoord>>(
         &self,
         mut pos: I,
         backend: &mut DB,
         parent_dim: (u32, u32),
+        capabilities: &BackendCapabilities,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        self.drawable.draw_dyn(&mut pos, backend, parent_dim)
+        self.drawable
+            .draw_dyn(&mut pos, backend, parent_dim, capabilities)
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.drawable.blend_mode_dyn()
     }
 }
 
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::element::{Drawable, PointCollection};
+use crate::element::{check_point_count, Drawable, PointCollection};
 use crate::style::ShapeStyle;
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
@@ -48,15 +48,51 @@ impl<K, V> ErrorBarOrient<K, V> for ErrorBarOrientV<K, V> {
     }
 }
 
+/// The error bar element, which is a line from the low bound to the high bound of a value, with
+/// a cap at each end and a marker at the center value.
+///
+/// The marker can be disabled with [marker_size](#method.marker_size), so the error bars can be
+/// drawn under the markers of a point series. It also works as the element of a point series:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data = [(1, 2.0, 3.0, 3.5), (2, 4.0, 4.5, 6.0), (3, 3.0, 5.0, 5.5)];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..4, 0.0..7.0)
+///     .unwrap();
+/// chart
+///     .draw_series(PointSeries::of_element(
+///         data.iter().map(|&(x, low, y, high)| (x, (low, y, high))),
+///         6,
+///         &RED,
+///         &|(x, (low, y, high)), size, style| {
+///             ErrorBar::new_vertical(x, low, y, high, style, size).cap_width(10)
+///         },
+///     ))
+///     .unwrap();
+/// ```
 pub struct ErrorBar<K, V, O: ErrorBarOrient<K, V>> {
     style: ShapeStyle,
-    width: u32,
+    cap_width: u32,
+    marker_size: u32,
     key: K,
     values: [V; 3],
     _p: PhantomData<O>,
 }
 
 impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
+    /// Create a new vertical error bar
+    /// - `key`: The X value of the error bar
+    /// - `min`: The low bound
+    /// - `avg`: The center value
+    /// - `max`: The high bound
+    /// - `style`: The style of the error bar
+    /// - `width`: The width of the caps, and the diameter of the marker
+    /// - **returns** The newly created error bar
     pub fn new_vertical<S: Into<ShapeStyle>>(
         key: K,
         min: V,
@@ -67,7 +103,8 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
     ) -> Self {
         Self {
             style: style.into(),
-            width,
+            cap_width: width,
+            marker_size: width / 2,
             key,
             values: [min, avg, max],
             _p: PhantomData,
@@ -76,6 +113,14 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
 }
 
 impl<K, V> ErrorBar<K, V, ErrorBarOrientH<K, V>> {
+    /// Create a new horizontal error bar
+    /// - `key`: The Y value of the error bar
+    /// - `min`: The low bound
+    /// - `avg`: The center value
+    /// - `max`: The high bound
+    /// - `style`: The style of the error bar
+    /// - `width`: The height of the caps, and the diameter of the marker
+    /// - **returns** The newly created error bar
     pub fn new_horizontal<S: Into<ShapeStyle>>(
         key: K,
         min: V,
@@ -86,7 +131,8 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientH<K, V>> {
     ) -> Self {
         Self {
             style: style.into(),
-            width,
+            cap_width: width,
+            marker_size: width / 2,
             key,
             values: [min, avg, max],
             _p: PhantomData,
@@ -94,6 +140,20 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientH<K, V>> {
     }
 }
 
+impl<K, V, O: ErrorBarOrient<K, V>> ErrorBar<K, V, O> {
+    /// Set the width of the caps, 0 draws the error bar without caps
+    pub fn cap_width(mut self, width: u32) -> Self {
+        self.cap_width = width;
+        self
+    }
+
+    /// Set the radius of the marker at the center value, 0 draws the error bar without marker
+    pub fn marker_size(mut self, size: u32) -> Self {
+        self.marker_size = size;
+        self
+    }
+}
+
 impl<'a, K: Clone, V: Clone, O: ErrorBarOrient<K, V>> PointCollection<'a, (O::XType, O::YType)>
     for &'a ErrorBar<K, V, O>
 {
@@ -115,16 +175,23 @@ impl<K, V, O: ErrorBarOrient<K, V>, DB: DrawingBackend> Drawable<DB> for ErrorBa
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         let points: Vec<_> = points.take(3).collect();
+        if !check_point_count("ErrorBar", 3, points.len()) {
+            return Ok(());
+        }
 
-        let (from, to) = O::ending_coord(points[0], self.width);
-        backend.draw_line(from, to, &self.style)?;
+        if self.cap_width > 0 {
+            let (from, to) = O::ending_coord(points[0], self.cap_width);
+            backend.draw_line(from, to, &self.style)?;
 
-        let (from, to) = O::ending_coord(points[2], self.width);
-        backend.draw_line(from, to, &self.style)?;
+            let (from, to) = O::ending_coord(points[2], self.cap_width);
+            backend.draw_line(from, to, &self.style)?;
+        }
 
         backend.draw_line(points[0], points[2], &self.style)?;
 
-        backend.draw_circle(points[1], self.width / 2, &self.style, self.style.filled)?;
+        if self.marker_size > 0 {
+            backend.draw_circle(points[1], self.marker_size, &self.style, self.style.filled)?;
+        }
 
         Ok(())
     }
@@ -145,3 +212,29 @@ fn test_preserve_stroke_width() {
     da.draw(&h).expect("Drawing Failure");
     da.draw(&v).expect("Drawing Failure");
 }
+
+#[cfg(test)]
+#[test]
+fn test_caps_and_marker() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_line(|_, _, from, to| {
+            // The cap at the low bound
+            assert_eq!(from.1, to.1);
+            assert_eq!(to.0 - from.0, 8);
+        });
+        m.check_draw_line(|_, _, _, _| {});
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 4);
+            assert_eq!(b.num_draw_circle_call, 0);
+        });
+    });
+    let with_caps = ErrorBar::new_vertical(100, 20, 50, 70, &RED, 4)
+        .cap_width(8)
+        .marker_size(0);
+    let without_caps = ErrorBar::new_horizontal(100, 20, 50, 70, &RED, 4)
+        .cap_width(0)
+        .marker_size(0);
+    da.draw(&with_caps).expect("Drawing Failure");
+    da.draw(&without_caps).expect("Drawing Failure");
+}
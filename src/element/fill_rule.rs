@@ -0,0 +1,206 @@
+use plotters_backend::BackendCoord;
+
+/// How the rings of a [Polygon](struct.Polygon.html) decide which parts of the plane are
+/// inside, see [Polygon::with_rings](struct.Polygon.html#method.with_rings)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the rings an odd number of times, thus a ring
+    /// inside another one is a hole, whatever the direction of the rings is
+    EvenOdd,
+    /// A point is inside if the rings wind around it a nonzero number of times, thus a hole
+    /// needs to go the opposite direction of the ring around it
+    NonZero,
+}
+
+impl FillRule {
+    fn is_inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+/// An edge of a ring, from the top to the bottom, with the direction of the ring
+struct Edge {
+    top: (f64, f64),
+    bottom: (f64, f64),
+    dir: i32,
+}
+
+impl Edge {
+    fn x_at(&self, y: f64) -> f64 {
+        let t = (y - self.top.1) / (self.bottom.1 - self.top.1);
+        self.top.0 + (self.bottom.0 - self.top.0) * t
+    }
+
+    /// The Y coordinate that the two edges cross at, if they do
+    fn crossing(&self, other: &Edge) -> Option<f64> {
+        let (top, bottom) = (
+            self.top.1.max(other.top.1),
+            self.bottom.1.min(other.bottom.1),
+        );
+        if top >= bottom {
+            return None;
+        }
+        let (d_top, d_bottom) = (
+            self.x_at(top) - other.x_at(top),
+            self.x_at(bottom) - other.x_at(bottom),
+        );
+        if d_top * d_bottom >= 0.0 {
+            return None;
+        }
+        Some(top + (bottom - top) * d_top / (d_top - d_bottom))
+    }
+}
+
+/// Split the area inside the rings into the trapezoids that are filled with the backend, since
+/// the backends only fill the simple polygons. The trapezoids of the neighbouring bands between
+/// the same pair of edges are merged.
+pub(crate) fn trapezoids(rings: &[Vec<BackendCoord>], rule: FillRule) -> Vec<Vec<BackendCoord>> {
+    let mut edges = vec![];
+    for ring in rings.iter().filter(|ring| ring.len() >= 3) {
+        for (idx, a) in ring.iter().enumerate() {
+            let b = ring[(idx + 1) % ring.len()];
+            let (a, b) = (
+                (f64::from(a.0), f64::from(a.1)),
+                (f64::from(b.0), f64::from(b.1)),
+            );
+            if a.1 < b.1 {
+                edges.push(Edge {
+                    top: a,
+                    bottom: b,
+                    dir: 1,
+                });
+            } else if a.1 > b.1 {
+                edges.push(Edge {
+                    top: b,
+                    bottom: a,
+                    dir: -1,
+                });
+            }
+        }
+    }
+
+    // The bands are between the vertices and the crossings of the edges, which are snapped to
+    // the pixel rows
+    let mut ys: Vec<i64> = edges
+        .iter()
+        .flat_map(|e| vec![e.top.1 as i64, e.bottom.1 as i64])
+        .collect();
+    for (idx, a) in edges.iter().enumerate() {
+        for b in edges.iter().skip(idx + 1) {
+            if let Some(y) = a.crossing(b) {
+                ys.push(y.round() as i64);
+            }
+        }
+    }
+    ys.sort();
+    ys.dedup();
+
+    let mut result = vec![];
+    // The open trapezoids, as the indices of the left and the right edges and the top
+    let mut open: Vec<(usize, usize, f64)> = vec![];
+    let close = |(left, right, top): (usize, usize, f64), bottom: f64| -> Vec<BackendCoord> {
+        let (l, r): (&Edge, &Edge) = (&edges[left], &edges[right]);
+        vec![
+            (l.x_at(top), top),
+            (r.x_at(top), top),
+            (r.x_at(bottom), bottom),
+            (l.x_at(bottom), bottom),
+        ]
+        .into_iter()
+        .map(|(x, y)| (x.round() as i32, y.round() as i32))
+        .collect()
+    };
+    for band in ys.windows(2) {
+        let (top, bottom) = (band[0] as f64, band[1] as f64);
+        let middle = (top + bottom) / 2.0;
+        let mut active: Vec<(f64, usize)> = edges
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.top.1 <= top && e.bottom.1 >= bottom)
+            .map(|(idx, e)| (e.x_at(middle), idx))
+            .collect();
+        active.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut spans = vec![];
+        let (mut winding, mut left) = (0, None);
+        for &(_, idx) in &active {
+            let was_inside = rule.is_inside(winding);
+            winding += edges[idx].dir;
+            match (was_inside, rule.is_inside(winding)) {
+                (false, true) => left = Some(idx),
+                (true, false) => spans.extend(left.take().map(|l| (l, idx))),
+                _ => {}
+            }
+        }
+
+        let mut next_open = vec![];
+        for (l, r, t) in open.drain(..) {
+            if spans.contains(&(l, r)) {
+                next_open.push((l, r, t));
+            } else {
+                result.push(close((l, r, t), top));
+            }
+        }
+        for &(l, r) in &spans {
+            if !next_open.iter().any(|o| (o.0, o.1) == (l, r)) {
+                next_open.push((l, r, top));
+            }
+        }
+        open = next_open;
+    }
+    if let Some(&last) = ys.last() {
+        for trapezoid in open {
+            result.push(close(trapezoid, last as f64));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn area(polygons: &[Vec<BackendCoord>]) -> i64 {
+        polygons
+            .iter()
+            .map(|p| {
+                let mut twice = 0;
+                for (idx, a) in p.iter().enumerate() {
+                    let b = p[(idx + 1) % p.len()];
+                    twice += i64::from(a.0) * i64::from(b.1) - i64::from(b.0) * i64::from(a.1);
+                }
+                twice.abs() / 2
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_trapezoids() {
+        let outer = vec![(0, 0), (100, 0), (100, 100), (0, 100)];
+        let hole = vec![(25, 25), (75, 25), (75, 75), (25, 75)];
+        let reversed: Vec<_> = hole.iter().rev().cloned().collect();
+
+        let rings = vec![outer.clone(), hole.clone()];
+        let parts = trapezoids(&rings, FillRule::EvenOdd);
+        assert_eq!(area(&parts), 100 * 100 - 50 * 50);
+        // The band above and below the hole, and the two sides of it
+        assert_eq!(parts.len(), 4);
+
+        // The hole of the same direction is filled with the nonzero rule
+        assert_eq!(area(&trapezoids(&rings, FillRule::NonZero)), 100 * 100);
+        let rings = vec![outer.clone(), reversed];
+        assert_eq!(
+            area(&trapezoids(&rings, FillRule::NonZero)),
+            100 * 100 - 50 * 50
+        );
+
+        // The crossing edges of a bow tie
+        let bow_tie = vec![vec![(0, 0), (100, 100), (100, 0), (0, 100)]];
+        assert_eq!(area(&trapezoids(&bow_tie, FillRule::EvenOdd)), 5000);
+
+        assert!(trapezoids(&[vec![(0, 0), (10, 10)]], FillRule::EvenOdd).is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The funnel chart element, which is positioned by its upper left corner in pixels and draws
+/// the ordered stages from the top to the bottom as the centered trapezoids.
+///
+/// The top of each stage is as wide as its value relative to the largest value, and the bottom
+/// narrows to the width of the next stage, while the last stage is a rectangle. Each stage is
+/// labelled in its middle with its label and the conversion from the previous stage, which is
+/// the percentage of the value of the previous stage that reaches the stage.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (400, 300)).into_drawing_area();
+/// let funnel = Funnel::new((20, 20), (360, 260), &[1000.0, 600.0, 250.0, 90.0], &[BLUE, CYAN])
+///     .labels(&["Visit", "Sign up", "Trial", "Purchase"])
+///     .gap(4);
+/// root.draw(&funnel).unwrap();
+/// ```
+pub struct Funnel<'a> {
+    upper_left: BackendCoord,
+    size: (u32, u32),
+    values: Vec<f64>,
+    styles: Vec<ShapeStyle>,
+    labels: Vec<String>,
+    gap: u32,
+    conversion: bool,
+    label_style: TextStyle<'a>,
+}
+
+impl<'a> Funnel<'a> {
+    /// Create a new funnel chart
+    /// - `upper_left`: The upper left corner in pixels
+    /// - `size`: The size in pixels
+    /// - `values`: The values of the stages from the top, the negative and NaN values are
+    ///   treated as zero
+    /// - `colors`: The colors of the stages, which are repeated if there are more stages
+    /// - **returns** The newly created element
+    pub fn new<C: Color>(
+        upper_left: BackendCoord,
+        size: (u32, u32),
+        values: &[f64],
+        colors: &[C],
+    ) -> Self {
+        Self {
+            upper_left,
+            size,
+            values: values
+                .iter()
+                .map(|&v| if v > 0.0 { v } else { 0.0 })
+                .collect(),
+            styles: colors.iter().map(|c| c.filled()).collect(),
+            labels: vec![],
+            gap: 2,
+            conversion: true,
+            label_style: ("sans-serif", 15).into(),
+        }
+    }
+
+    /// Set the labels of the stages
+    pub fn labels<L: ToString>(mut self, labels: &[L]) -> Self {
+        self.labels = labels.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Set the vertical gap between the stages in pixels
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set if the conversion percentages are drawn after the labels, which they are by default
+    pub fn conversion(mut self, conversion: bool) -> Self {
+        self.conversion = conversion;
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Get the percentage of the value of the previous stage that reaches each stage, the first
+    /// stage is 100%
+    pub fn conversions(&self) -> Vec<f64> {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(idx, &value)| match idx {
+                0 => 100.0,
+                _ if self.values[idx - 1] > 0.0 => value / self.values[idx - 1] * 100.0,
+                _ => 0.0,
+            })
+            .collect()
+    }
+
+    /// The text of the label of a stage
+    fn label(&self, idx: usize, conversion: f64) -> String {
+        let label = self.labels.get(idx).map_or("", String::as_str);
+        match (self.conversion, label.is_empty()) {
+            (false, _) => label.to_string(),
+            (true, true) => format!("{:.1}%", conversion),
+            (true, false) => format!("{} ({:.1}%)", label, conversion),
+        }
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b Funnel<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.upper_left)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Funnel<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let n = self.values.len();
+        let max = self.values.iter().cloned().fold(0.0, f64::max);
+        if n == 0 || max <= 0.0 {
+            return Ok(());
+        }
+        let (width, height) = (f64::from(self.size.0), f64::from(self.size.1));
+        let gap = f64::from(self.gap);
+        let row = (height - gap * (n - 1) as f64) / n as f64;
+        let center = f64::from(x0) + width / 2.0;
+        let half = |value: f64| value / max * width / 2.0;
+
+        let style = self.label_style.pos(Pos::new(HPos::Center, VPos::Center));
+        for (idx, (&value, conversion)) in self.values.iter().zip(self.conversions()).enumerate() {
+            let top = f64::from(y0) + (row + gap) * idx as f64;
+            let bottom = top + row;
+            let (upper, lower) = (
+                half(value),
+                half(*self.values.get(idx + 1).unwrap_or(&value)),
+            );
+            let shape = vec![
+                (center - upper, top),
+                (center + upper, top),
+                (center + lower, bottom),
+                (center - lower, bottom),
+            ];
+            let shape: Vec<_> = shape
+                .into_iter()
+                .map(|(x, y)| (x.round() as i32, y.round() as i32))
+                .collect();
+            if !self.styles.is_empty() {
+                backend.fill_polygon(shape, &self.styles[idx % self.styles.len()])?;
+            }
+
+            let label = self.label(idx, conversion);
+            if !label.is_empty() {
+                let pos = (center.round() as i32, ((top + bottom) / 2.0).round() as i32);
+                backend.draw_text(&label, &style, pos)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_funnel_conversions() {
+        let funnel = Funnel::new((0, 0), (100, 100), &[200.0, 50.0, 0.0, 10.0], &[RED]);
+        assert_eq!(funnel.conversions(), vec![100.0, 25.0, 0.0, 0.0]);
+        assert_eq!(funnel.label(1, 25.0), "25.0%");
+        let funnel = funnel.labels(&["a", "b"]);
+        assert_eq!(funnel.label(1, 25.0), "b (25.0%)");
+        assert_eq!(funnel.conversion(false).label(1, 25.0), "b");
+    }
+
+    #[test]
+    fn test_draw_funnel() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(10, 10), (110, 10), (85, 55), (35, 55)]);
+            });
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path, vec![(35, 65), (85, 65), (85, 110), (35, 110)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "first (100.0%)");
+                assert_eq!(pos, (60, 33));
+            });
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "second (50.0%)"));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let funnel = Funnel::new((10, 10), (100, 100), &[4.0, 2.0], &[RED, BLUE])
+            .labels(&["first", "second"])
+            .gap(10);
+        drawing_area.draw(&funnel).unwrap();
+    }
+}
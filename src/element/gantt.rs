@@ -0,0 +1,222 @@
+/*!
+  The Gantt bar element, which shows the time span of a task on a timeline
+*/
+
+use crate::element::{check_point_count, Drawable, PointCollection};
+use crate::style::colors::{BLACK, BLUE};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+const DEFAULT_HEIGHT: u32 = 16;
+/// The space between the start of the bar and its label, in pixels
+const LABEL_PADDING: i32 = 4;
+
+/// The Gantt bar element, which shows a task as a bar from its start time to its end time on the
+/// row of its key.
+///
+/// The element is designed for a chart with a date or time X axis and a segmented key Y axis,
+/// where the key of a row is `SegmentValue::CenterOf(key)`. The bar has a fixed height in pixels
+/// and it is centered on the row. The finished part of the task is drawn on top of the bar with
+/// [GanttBar::progress](#method.progress).
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use plotters::prelude::*;
+///
+/// let tasks = [("Design", 1, 10, 1.0), ("Build", 8, 25, 0.4), ("Test", 20, 30, 0.0)];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(
+///         Utc.ymd(2020, 6, 1)..Utc.ymd(2020, 7, 1),
+///         (0..tasks.len() as i32 - 1).into_segmented(),
+///     )
+///     .unwrap();
+/// let mut colors = chart.color_cycle(PaletteCategory10);
+/// chart
+///     .draw_series(tasks.iter().enumerate().map(|(idx, &(name, start, end, done))| {
+///         GanttBar::new(
+///             SegmentValue::CenterOf(idx as i32),
+///             Utc.ymd(2020, 6, start),
+///             Utc.ymd(2020, 6, end),
+///         )
+///         .style(colors.next_filled_style())
+///         .progress(done)
+///         .label(name)
+///     }))
+///     .unwrap();
+/// ```
+pub struct GanttBar<'a, K, T> {
+    points: [(T, K); 2],
+    style: ShapeStyle,
+    height: u32,
+    progress: Option<(f64, ShapeStyle)>,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, K: Clone, T> GanttBar<'a, K, T> {
+    /// Create a new Gantt bar
+    /// - `key`: The key of the row of the task
+    /// - `start`: The start time of the task
+    /// - `end`: The end time of the task
+    /// - **returns** The newly created Gantt bar
+    pub fn new(key: K, start: T, end: T) -> Self {
+        Self {
+            points: [(start, key.clone()), (end, key)],
+            style: BLUE.filled(),
+            height: DEFAULT_HEIGHT,
+            progress: None,
+            label: None,
+        }
+    }
+
+    /// Set the style of the bar, a filled style fills the bar, otherwise only the outline is
+    /// drawn
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the bar from its key, which is useful to give each category its own color
+    /// - `style_fn`: The function that maps the key to the style
+    pub fn style_by_key<S: Into<ShapeStyle>, F: FnOnce(&K) -> S>(mut self, style_fn: F) -> Self {
+        self.style = style_fn(&self.points[0].1).into();
+        self
+    }
+
+    /// Set the height of the bar in pixels
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Mark the finished part of the task, which is drawn darker than the bar
+    /// - `fraction`: The finished fraction of the task from 0 to 1
+    pub fn progress(mut self, fraction: f64) -> Self {
+        let style = self
+            .progress
+            .take()
+            .map(|p| p.1)
+            .unwrap_or_else(|| BLACK.mix(0.3).filled());
+        self.progress = Some((fraction, style));
+        self
+    }
+
+    /// Set the style of the finished part of the task
+    pub fn progress_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        let fraction = self.progress.as_ref().map_or(0.0, |p| p.0);
+        self.progress = Some((fraction, style.into()));
+        self
+    }
+
+    /// Draw a label in the bar, next to its start
+    pub fn label<L: ToString>(mut self, label: L) -> Self {
+        let style = self
+            .label
+            .take()
+            .map(|l| l.1)
+            .unwrap_or_else(|| ("sans-serif", 12).into());
+        self.label = Some((label.to_string(), style));
+        self
+    }
+
+    /// Set the style of the label
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        let text = self.label.take().map(|l| l.0).unwrap_or_default();
+        self.label = Some((text, style.into()));
+        self
+    }
+}
+
+impl<'b, 'a, K: 'b, T: 'b> PointCollection<'b, (T, K)> for &'b GanttBar<'a, K, T> {
+    type Point = &'b (T, K);
+    type IntoIter = &'b [(T, K)];
+    fn point_iter(self) -> &'b [(T, K)] {
+        &self.points
+    }
+}
+
+impl<'a, K, T, DB: DrawingBackend> Drawable<DB> for GanttBar<'a, K, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(2).collect();
+        if !check_point_count("GanttBar", 2, points.len()) {
+            return Ok(());
+        }
+        let (start, end) = (points[0].0.min(points[1].0), points[0].0.max(points[1].0));
+        let top = points[0].1 - self.height as i32 / 2;
+        let bottom = top + self.height as i32;
+
+        backend.draw_rect((start, top), (end, bottom), &self.style, self.style.filled)?;
+
+        if let Some((fraction, ref style)) = self.progress {
+            let fraction = if fraction.is_nan() || fraction <= 0.0 {
+                0.0
+            } else if fraction >= 1.0 {
+                1.0
+            } else {
+                fraction
+            };
+            let done = start + ((end - start) as f64 * fraction).round() as i32;
+            if done > start {
+                backend.draw_rect((start, top), (done, bottom), style, style.filled)?;
+            }
+        }
+
+        if let Some((ref text, ref style)) = self.label {
+            let style = style.pos(Pos::new(HPos::Left, VPos::Center));
+            let pos = (start + LABEL_PADDING, (top + bottom) / 2);
+            backend.draw_text(text, &style, pos)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_draw_gantt_bar() {
+        let drawing_area = create_mocked_drawing_area(101, 100, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(filled);
+                assert_eq!((ul.0, br.0), (20, 60));
+                assert_eq!(br.1 - ul.1, 10);
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, BLACK.mix(0.3).to_rgba());
+                // The first quarter of the task is done
+                assert_eq!((ul.0, br.0), (20, 30));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "task");
+                assert_eq!(pos.0, 24);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, (0..1).into_segmented())
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(
+                GanttBar::new(SegmentValue::CenterOf(0), 20, 60)
+                    .style_by_key(|_| RED.filled())
+                    .height(10)
+                    .progress(0.25)
+                    .label("task"),
+            ))
+            .unwrap();
+    }
+}
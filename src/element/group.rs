@@ -0,0 +1,190 @@
+use super::{Drawable, DynElement, IntoDynElement, PointCollection};
+use crate::drawing::BackendCapabilities;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The affine transform `(a, b, c, d, e, f)`, which maps `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`
+type Transform = [f64; 6];
+
+const IDENTITY: Transform = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+/// Apply the transform `m` after the transform `n`
+fn then(n: Transform, m: Transform) -> Transform {
+    [
+        m[0] * n[0] + m[2] * n[1],
+        m[1] * n[0] + m[3] * n[1],
+        m[0] * n[2] + m[2] * n[3],
+        m[1] * n[2] + m[3] * n[3],
+        m[0] * n[4] + m[2] * n[5] + m[4],
+        m[1] * n[4] + m[3] * n[5] + m[5],
+    ]
+}
+
+fn apply(m: &Transform, (x, y): (f64, f64)) -> (f64, f64) {
+    (m[0] * x + m[2] * y + m[4], m[1] * x + m[3] * y + m[5])
+}
+
+/// The element that owns a group of child elements and draws them as a unit, such as a
+/// compass rose or a custom annotation that is reused at many places.
+///
+/// The children are in a local coordinate system in pixels, whose origin is put at the position
+/// of the group, and the group moves, scales and rotates them with an affine transform. Only
+/// the points of the children are transformed, such as the corners of the polygons and the
+/// centers of the circles, while the sizes in pixels, such as the radii, the line widths and the
+/// fonts, stay the same. Thus the shapes that are rotated should be polygons or paths, since a
+/// rectangle is always aligned with the axes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// let rose = |pos, size, angle| {
+///     Group::new(pos)
+///         .push(Polygon::new(vec![(0.0, -1.0), (0.2, 0.0), (0.0, 1.0), (-0.2, 0.0)], RED.filled()))
+///         .push(Text::new("N", (0.0, -1.3), ("sans-serif", 12)))
+///         .scale(size, size)
+///         .rotate(angle)
+/// };
+/// chart
+///     .draw_series(vec![rose((2.0, 2.0), 20.0, 0.0), rose((7.0, 6.0), 40.0, 30.0)])
+///     .unwrap();
+/// ```
+pub struct Group<'a, Coord, DB: DrawingBackend> {
+    pos: Coord,
+    children: Vec<DynElement<'a, DB, (f64, f64)>>,
+    transform: Transform,
+}
+
+impl<'a, Coord, DB: DrawingBackend> Group<'a, Coord, DB> {
+    /// Create a new empty group
+    /// - `pos`: The position that the origin of the children is put at
+    /// - **returns** The newly created group
+    pub fn new(pos: Coord) -> Self {
+        Self {
+            pos,
+            children: vec![],
+            transform: IDENTITY,
+        }
+    }
+
+    /// Add a child element, whose coordinates are in the local pixels of the group
+    pub fn push<E: IntoDynElement<'a, DB, (f64, f64)>>(mut self, element: E) -> Self {
+        self.children.push(element.into_dyn());
+        self
+    }
+
+    /// Move the children by the offset in pixels. The transforms are applied to the children in
+    /// the order they're set.
+    pub fn translate(mut self, dx: f64, dy: f64) -> Self {
+        self.transform = then(self.transform, [1.0, 0.0, 0.0, 1.0, dx, dy]);
+        self
+    }
+
+    /// Scale the children about the origin of the group
+    pub fn scale(mut self, sx: f64, sy: f64) -> Self {
+        self.transform = then(self.transform, [sx, 0.0, 0.0, sy, 0.0, 0.0]);
+        self
+    }
+
+    /// Rotate the children about the origin of the group by the clockwise angle in degrees
+    pub fn rotate(mut self, degrees: f64) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        self.transform = then(self.transform, [cos, sin, -sin, cos, 0.0, 0.0]);
+        self
+    }
+}
+
+impl<'a, 'b, Coord, DB: DrawingBackend> PointCollection<'a, Coord> for &'a Group<'b, Coord, DB> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Group<'a, Coord, DB> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let capabilities = BackendCapabilities::all();
+        self.draw_with_capabilities(pos, backend, parent_dim, &capabilities)
+    }
+
+    fn draw_with_capabilities<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+        capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match pos.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        for child in &self.children {
+            let points: Vec<BackendCoord> = child
+                .point_iter()
+                .iter()
+                .map(|p| {
+                    let (x, y) = apply(&self.transform, *p);
+                    (x0 + x.round() as i32, y0 + y.round() as i32)
+                })
+                .collect();
+            child.draw_with_capabilities(points.into_iter(), backend, parent_dim, capabilities)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_transform() {
+        let group = Group::<(), MockedBackend>::new(())
+            .scale(2.0, 2.0)
+            .rotate(90.0)
+            .translate(5.0, 0.0);
+        // Scaled, then rotated clockwise in the pixels whose Y axis points down, then moved
+        let (x, y) = apply(&group.transform, (1.0, 0.0));
+        assert!((x - 5.0).abs() < 1e-9 && (y - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_draw_group() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(50, 49), (50, 69), (40, 69)]);
+            });
+            m.check_draw_circle(|_, _, _, center, radius| {
+                assert_eq!(center, (60, 49));
+                assert_eq!(radius, 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        let group = Group::new((5.0, 5.0))
+            .push(Polygon::new(
+                vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0)],
+                RED.filled(),
+            ))
+            .push(Circle::new((0.0, -1.0), 3, &BLUE))
+            .scale(10.0, 10.0)
+            .rotate(90.0);
+        chart.draw_series(std::iter::once(group)).unwrap();
+    }
+}
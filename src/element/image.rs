@@ -1,5 +1,7 @@
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
-use image::{DynamicImage, GenericImageView};
+use image::imageops::{resize, FilterType};
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use image::{DynamicImage, GenericImageView, ImageBuffer, Rgb};
 
 use super::{Drawable, PointCollection};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
@@ -58,6 +60,7 @@ pub struct BitMapElement<'a, Coord, P: PixelFormat = RGBPixel> {
     image: Buffer<'a>,
     size: (u32, u32),
     pos: Coord,
+    extent: Option<Coord>,
     phantom: PhantomData<P>,
 }
 
@@ -72,6 +75,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(vec![0; (size.0 * size.1) as usize * P::PIXEL_SIZE]),
             size,
             pos,
+            extent: None,
             phantom: PhantomData,
         }
     }
@@ -93,6 +97,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(buf),
             size,
             pos,
+            extent: None,
             phantom: PhantomData,
         })
     }
@@ -113,6 +118,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::BorrowedMut(buf),
             size,
             pos,
+            extent: None,
             phantom: PhantomData,
         })
     }
@@ -134,6 +140,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(buf),
             size,
             pos,
+            extent: None,
             phantom: PhantomData,
         })
     }
@@ -146,6 +153,7 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(self.image.borrow()),
             size: self.size,
             pos,
+            extent: None,
             phantom: PhantomData,
         }
     }
@@ -165,28 +173,93 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
 }
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
-impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBPixel> {
-    fn from((pos, image): (Coord, DynamicImage)) -> Self {
+impl<'a, Coord> BitMapElement<'a, Coord, RGBPixel> {
+    /// Create a new bitmap element from a decoded image, such as a logo, an icon or a
+    /// background map
+    ///
+    /// - `pos`: The left upper coordinate of the element
+    /// - `image`: The image to draw
+    /// - **returns**: The newly created bitmap element, which is drawn with the size of the
+    ///   image in pixels, unless it's scaled with
+    ///   [BitMapElement::scale_to](#method.scale_to)
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    ///
+    /// let map = image::open("map.png").unwrap();
+    ///
+    /// let root = BitMapBackend::new("out.png", (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(-180.0..180.0, -90.0..90.0)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(std::iter::once(
+    ///         BitMapElement::from_image((-180.0, 90.0), &map).scale_to((180.0, -90.0)),
+    ///     ))
+    ///     .unwrap();
+    /// ```
+    pub fn from_image(pos: Coord, image: &DynamicImage) -> Self {
         let (w, h) = image.dimensions();
-        let rgb_image = image.to_rgb().into_raw();
         Self {
             pos,
-            image: Buffer::Owned(rgb_image),
+            image: Buffer::Owned(image.to_rgb8().into_raw()),
             size: (w, h),
+            extent: None,
             phantom: PhantomData,
         }
     }
+
+    /// Scale the bitmap to fill the rectangle between its position and the given corner. The
+    /// bitmap is resized with bilinear interpolation when it's drawn, so it follows the extent
+    /// in the guest coordinate. The corners can be in any order, the bitmap is never flipped.
+    ///
+    /// - `corner`: The opposite corner of the bitmap
+    pub fn scale_to(mut self, corner: Coord) -> Self {
+        self.extent = Some(corner);
+        self
+    }
+
+    fn draw_scaled<DB: DrawingBackend>(
+        &self,
+        (x0, y0): BackendCoord,
+        (x1, y1): BackendCoord,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let pos = (x0.min(x1), y0.min(y1));
+        let size = ((x0.max(x1) - pos.0) as u32, (y0.max(y1) - pos.1) as u32);
+        if size.0 == 0 || size.1 == 0 {
+            return Ok(());
+        }
+        if size == self.size {
+            return backend.blit_bitmap(pos, size, self.image.as_ref());
+        }
+        match ImageBuffer::<Rgb<u8>, _>::from_raw(self.size.0, self.size.1, self.image.as_ref()) {
+            Some(buffer) => {
+                let resized = resize(&buffer, size.0, size.1, FilterType::Triangle);
+                backend.blit_bitmap(pos, size, &resized.into_raw())
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBPixel> {
+    fn from((pos, image): (Coord, DynamicImage)) -> Self {
+        Self::from_image(pos, &image)
+    }
 }
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPixel> {
     fn from((pos, image): (Coord, DynamicImage)) -> Self {
         let (w, h) = image.dimensions();
-        let rgb_image = image.to_bgra().into_raw();
+        let rgb_image = image.to_bgra8().into_raw();
         Self {
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            extent: None,
             phantom: PhantomData,
         }
     }
@@ -194,9 +267,9 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPix
 
 impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a BitMapElement<'b, Coord> {
     type Point = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = std::iter::Chain<std::iter::Once<&'a Coord>, std::option::Iter<'a, Coord>>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.pos)
+        std::iter::once(&self.pos).chain(self.extent.iter())
     }
 }
 
@@ -208,9 +281,44 @@ impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
+            #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+            {
+                if let Some(corner) = points.next() {
+                    return self.draw_scaled((x, y), corner, backend);
+                }
+            }
             // TODO: convert the pixel format when needed
             return backend.blit_bitmap((x, y), self.size, self.image.as_ref());
         }
         Ok(())
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "image"))]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use image::RgbImage;
+
+    #[test]
+    fn test_bitmap_scaled_to_extent() {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 2, Rgb([255, 0, 0])));
+        let element = BitMapElement::from_image((10, 60), &image).scale_to((30, 80));
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_pixel(|c, pos| {
+                assert_eq!(c, RED.to_rgba());
+                // The corners are swapped, so the bitmap starts at the top of the extent
+                assert!(pos.0 >= 10 && pos.0 < 30);
+                assert!(pos.1 >= 19 && pos.1 < 39);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixel_call, 20 * 20);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0..100)
+            .unwrap();
+        chart.draw_series(std::iter::once(element)).unwrap();
+    }
+}
@@ -0,0 +1,246 @@
+use image::imageops::{resize, FilterType};
+use image::{DynamicImage, GenericImageView, ImageBuffer, Rgba};
+
+use super::{Drawable, PointCollection};
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+
+use std::borrow::Cow;
+
+/// The element that stamps a raster image at a data coordinate.
+///
+/// Unlike [BitMapElement](struct.BitMapElement.html), this element works with any backend, it
+/// respects the alpha channel of the image and it can be resized. The image is decoded only once,
+/// and [ImageElement::copy_to](#method.copy_to) and [ImageElement::stamp_at](#method.stamp_at)
+/// reuse the decoded pixels, which makes it cheap to draw the same icon at many coordinates.
+///
+/// A fully opaque image is blitted directly. The SVG backend embeds it as a base64 encoded
+/// `<image>` only when the opt-in `svg_image` feature is enabled, otherwise it draws each pixel
+/// as a rectangle. The pixels of a translucent image are blended onto the backend one by one.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let icon = image::open("icon.png").unwrap();
+/// let icon = ImageElement::new((0.0, 0.0), &icon).resize(16, 16);
+///
+/// let root = BitMapBackend::new("out.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(icon.stamp_at((0..10).map(|x| (x as f64, x as f64))))
+///     .unwrap();
+/// ```
+pub struct ImageElement<'a, Coord> {
+    pixels: Cow<'a, [u8]>,
+    size: (u32, u32),
+    opaque: bool,
+    pos: Coord,
+}
+
+impl<'a, Coord> ImageElement<'a, Coord> {
+    /// Create a new image element from a decoded image
+    ///
+    /// - `pos`: The left upper coordinate of the element
+    /// - `image`: The image to draw
+    pub fn new(pos: Coord, image: &DynamicImage) -> Self {
+        let size = image.dimensions();
+        let mut pixels = Vec::with_capacity((size.0 * size.1) as usize * 4);
+        for (_, _, pixel) in image.pixels() {
+            pixels.extend_from_slice(&pixel.0);
+        }
+        Self::from_rgba(pos, size, pixels)
+    }
+
+    fn from_rgba(pos: Coord, size: (u32, u32), rgba: Vec<u8>) -> Self {
+        let opaque = rgba.chunks(4).all(|p| p[3] == 255);
+        let pixels = if opaque {
+            rgba.chunks(4)
+                .flat_map(|p| p[..3].iter().cloned())
+                .collect()
+        } else {
+            rgba
+        };
+        Self {
+            pixels: Cow::Owned(pixels),
+            size,
+            opaque,
+            pos,
+        }
+    }
+
+    /// Get the size of the image in pixels
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Scale the image to the given size with bilinear interpolation
+    ///
+    /// - `w`: The new width in pixels
+    /// - `h`: The new height in pixels
+    /// - **returns**: The resized image element
+    pub fn resize(self, w: u32, h: u32) -> Self {
+        if (w, h) == self.size {
+            return self;
+        }
+        let rgba = if self.opaque {
+            self.pixels
+                .chunks(3)
+                .flat_map(|p| p.iter().cloned().chain(std::iter::once(255)))
+                .collect()
+        } else {
+            self.pixels.into_owned()
+        };
+        let resized = match ImageBuffer::<Rgba<u8>, _>::from_raw(self.size.0, self.size.1, rgba) {
+            Some(buffer) => resize(&buffer, w, h, FilterType::Triangle).into_raw(),
+            None => vec![0; (w * h) as usize * 4],
+        };
+        Self::from_rgba(self.pos, (w, h), resized)
+    }
+
+    /// Copy the existing image element to another location, the pixels are shared with the
+    /// original element
+    ///
+    /// - `pos`: The new location to copy
+    pub fn copy_to<Coord2>(&self, pos: Coord2) -> ImageElement<'_, Coord2> {
+        ImageElement {
+            pixels: Cow::Borrowed(&self.pixels),
+            size: self.size,
+            opaque: self.opaque,
+            pos,
+        }
+    }
+
+    /// Stamp the same image at each of the given coordinates, the pixels are decoded and scaled
+    /// only once and shared by all the stamps
+    ///
+    /// - `points`: The left upper coordinates of the stamps
+    /// - **returns**: The iterator of image elements, which can be used as a series
+    pub fn stamp_at<'b, Coord2, I: IntoIterator<Item = Coord2>>(
+        &'b self,
+        points: I,
+    ) -> impl Iterator<Item = ImageElement<'b, Coord2>> + 'b
+    where
+        I::IntoIter: 'b,
+    {
+        points.into_iter().map(move |pos| self.copy_to(pos))
+    }
+
+    /// Move the existing image element to a new position
+    ///
+    /// - `pos`: The new position
+    pub fn move_to(&mut self, pos: Coord) {
+        self.pos = pos;
+    }
+}
+
+impl<'a, Coord> From<(Coord, DynamicImage)> for ImageElement<'a, Coord> {
+    fn from((pos, image): (Coord, DynamicImage)) -> Self {
+        Self::new(pos, &image)
+    }
+}
+
+impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a ImageElement<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for ImageElement<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+
+        if self.opaque {
+            return backend.blit_bitmap((x0, y0), self.size, &self.pixels);
+        }
+
+        for (idx, pixel) in self.pixels.chunks(4).enumerate() {
+            if pixel[3] == 0 {
+                continue;
+            }
+            let x = x0 + (idx as u32 % self.size.0) as i32;
+            let y = y0 + (idx as u32 / self.size.0) as i32;
+            let color = BackendColor {
+                alpha: f64::from(pixel[3]) / 255.0,
+                rgb: (pixel[0], pixel[1], pixel[2]),
+            };
+            backend.draw_pixel((x, y), color)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use image::RgbaImage;
+
+    fn make_image(alpha: u8) -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 2, Rgba([255, 0, 0, alpha])))
+    }
+
+    #[test]
+    fn test_opaque_image_is_blitted() {
+        let element = ImageElement::new((0, 0), &make_image(255)).resize(8, 4);
+        assert_eq!(element.size(), (8, 4));
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_pixel(|c, _| {
+                assert_eq!(c, RED.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixel_call, 8 * 4);
+            });
+        });
+        drawing_area.draw(&element).unwrap();
+    }
+
+    #[cfg(feature = "svg_image")]
+    #[test]
+    fn test_opaque_image_is_embedded_in_svg() {
+        let element = ImageElement::new((0, 0), &make_image(255));
+        let mut content = String::new();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw(&element).unwrap();
+        }
+        assert!(content.contains("<image"));
+        assert!(!content.contains("<rect"));
+    }
+
+    #[test]
+    fn test_translucent_image_is_blended() {
+        let element = ImageElement::new((0, 0), &make_image(128));
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_pixel(|_, pos| {
+                assert_eq!(pos, (10, 19));
+            });
+            m.check_draw_pixel(|c, _| {
+                assert_eq!(c.rgb(), (255, 0, 0));
+                assert!((c.alpha() - 128.0 / 255.0).abs() < 1e-6);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixel_call, 8 * 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0..100)
+            .unwrap();
+        chart
+            .draw_series(element.stamp_at(vec![(10, 80), (20, 80), (30, 80)]))
+            .unwrap();
+    }
+}
@@ -0,0 +1,377 @@
+use super::*;
+use crate::drawing::{BackendCapabilities, BlendMode};
+use plotters_backend::{BackendColor, BackendTextStyle, FontFamily};
+
+/// The font family of the metadata markers
+const MARKER_FAMILY: &str = "plotters-element-metadata";
+/// The separator of the fields of a metadata marker
+const MARKER_SEPARATOR: char = '\u{1f}';
+/// The metadata marker that ends the element
+const END_MARKER: &str = "end";
+
+/// The interactive metadata of an element, which is the id, the class and the tooltip of the
+/// element in the output of the backends that can carry them, such as an SVG document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ElementMetadata {
+    /// The unique id of the element
+    pub id: Option<String>,
+    /// The class of the element, which is used to style the element with CSS
+    pub class: Option<String>,
+    /// The text that is shown when the element is hovered
+    pub tooltip: Option<String>,
+}
+
+impl ElementMetadata {
+    /// Parse the metadata from the text of a metadata marker
+    #[cfg_attr(not(feature = "svg_backend"), allow(dead_code))]
+    /// - **returns** The metadata of the element that starts, or `None` if the element ends
+    pub(crate) fn from_marker(text: &str) -> Option<Self> {
+        let mut fields = text.strip_prefix("begin")?.split(MARKER_SEPARATOR).skip(1);
+        let mut field = || fields.next().map(str::to_string).and_then(non_empty);
+        Some(Self {
+            id: field(),
+            class: field(),
+            tooltip: field(),
+        })
+    }
+
+    fn to_marker(&self) -> String {
+        let mut text = "begin".to_string();
+        for field in [&self.id, &self.class, &self.tooltip].iter() {
+            text.push(MARKER_SEPARATOR);
+            text.push_str(field.as_deref().unwrap_or(""));
+        }
+        text
+    }
+}
+
+/// The text style that passes the metadata to the backend through
+/// `DrawingBackend::estimate_text_size`, which is forwarded by all the wrapper backends and
+/// has no effect on the drawing. The backends that write the metadata, such as the
+/// `AccessibleSVGBackend`, recognize the style by its font family, and the other backends only
+/// measure the empty layout of the style.
+pub(crate) struct MetadataMarker;
+
+impl MetadataMarker {
+    /// Check if the text style is a metadata marker
+    #[cfg_attr(not(feature = "svg_backend"), allow(dead_code))]
+    pub(crate) fn is_marker<S: BackendTextStyle>(style: &S) -> bool {
+        match style.family() {
+            FontFamily::Name(name) => name == MARKER_FAMILY,
+            _ => false,
+        }
+    }
+
+    fn send<DB: DrawingBackend>(backend: &DB, text: &str) {
+        // The marker is only a hint, the backends that can't measure it draw without it
+        let _ = backend.estimate_text_size(text, &MetadataMarker);
+    }
+}
+
+impl BackendTextStyle for MetadataMarker {
+    type FontError = std::fmt::Error;
+
+    fn family(&self) -> FontFamily<'_> {
+        FontFamily::Name(MARKER_FAMILY)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box(&self, _text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        Ok(((0, 0), (0, 0)))
+    }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        _text: &str,
+        _pos: BackendCoord,
+        _draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        Ok(Ok(()))
+    }
+}
+
+/// An element with the interactive metadata attached, which is created by
+/// [ElementExt::with_metadata](trait.ElementExt.html#method.with_metadata).
+///
+/// The element is drawn as the wrapped one on any backend, either with `DrawingArea::draw` or
+/// with `ChartContext::draw_series`. The
+/// [AccessibleSVGBackend](../drawing/struct.AccessibleSVGBackend.html) groups the element in a
+/// `<g>` with the id and the class and the tooltip as its `<title>`, the other backends ignore
+/// the metadata.
+///
+/// **Note:** The plain `SVGBackend` comes from the `plotters-svg` crate, whose output has no
+/// place for the groups and the attributes of the elements, thus it drops the metadata. Use the
+/// `AccessibleSVGBackend`, which draws the shapes with the `SVGBackend`, to write it.
+pub struct WithMetadata<Coord, E> {
+    inner: E,
+    points: Vec<Coord>,
+    metadata: ElementMetadata,
+}
+
+impl<Coord, E> WithMetadata<Coord, E> {
+    /// Get the metadata of the element
+    pub fn metadata(&self) -> &ElementMetadata {
+        &self.metadata
+    }
+
+    /// Get the wrapped element
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// Take the wrapped element out
+    pub fn into_inner(self) -> E {
+        self.inner
+    }
+}
+
+// The points are kept in the wrapper, since delegating to the point collection of the wrapped
+// element makes the trait resolution recursive
+impl<'a, Coord: 'a, E> PointCollection<'a, Coord> for &'a WithMetadata<Coord, E> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend, E: Drawable<DB>> Drawable<DB> for WithMetadata<Coord, E> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        MetadataMarker::send(backend, &self.metadata.to_marker());
+        let ret = self.inner.draw(pos, backend, parent_dim);
+        MetadataMarker::send(backend, END_MARKER);
+        ret
+    }
+
+    fn draw_with_capabilities<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+        capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        MetadataMarker::send(backend, &self.metadata.to_marker());
+        let ret = self
+            .inner
+            .draw_with_capabilities(pos, backend, parent_dim, capabilities);
+        MetadataMarker::send(backend, END_MARKER);
+        ret
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.inner.blend_mode()
+    }
+}
+
+fn non_empty(text: String) -> Option<String> {
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// The extension of the elements, which attaches the interactive metadata to any element.
+///
+/// ```rust
+/// use plotters::drawing::AccessibleSVGBackend;
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// {
+///     let root = AccessibleSVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+///     for x in 0..10 {
+///         let point = Circle::new((x * 60, 240), 5, RED.filled()).with_metadata(
+///             format!("point-{}", x),
+///             "marker",
+///             format!("x = {}", x),
+///         );
+///         root.draw(&point).unwrap();
+///     }
+///     root.present().unwrap();
+/// }
+/// assert!(content.contains("<g id=\"point-3\" class=\"marker\">\n<title>x = 3</title>"));
+/// ```
+pub trait ElementExt: Sized {
+    /// Attach the metadata to the element, an empty string leaves the field unset
+    /// - `id`: The unique id of the element
+    /// - `class`: The class of the element
+    /// - `tooltip`: The text that is shown when the element is hovered
+    /// - **returns** The element with the metadata
+    fn with_metadata<Coord, I: Into<String>, C: Into<String>, T: Into<String>>(
+        self,
+        id: I,
+        class: C,
+        tooltip: T,
+    ) -> WithMetadata<Coord, Self>
+    where
+        Coord: Clone,
+        for<'a> &'a Self: PointCollection<'a, Coord>,
+    {
+        let points = self
+            .point_iter()
+            .into_iter()
+            .map(|p| p.borrow().clone())
+            .collect();
+        WithMetadata {
+            inner: self,
+            points,
+            metadata: ElementMetadata {
+                id: non_empty(id.into()),
+                class: non_empty(class.into()),
+                tooltip: non_empty(tooltip.into()),
+            },
+        }
+    }
+}
+
+impl<E> ElementExt for E {}
+
+#[cfg(test)]
+mod test {
+    use super::{ElementMetadata, MetadataMarker, END_MARKER};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_with_metadata() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_circle(|_, _, _, center, radius| {
+                assert_eq!((center, radius), ((50, 50), 5));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+
+        let element = Circle::new((50, 50), 5, &RED).with_metadata("dot", "", "Hello");
+        assert_eq!(element.metadata().id, Some("dot".to_string()));
+        assert_eq!(element.metadata().class, None);
+        assert_eq!(element.metadata().tooltip, Some("Hello".to_string()));
+        drawing_area.draw(&element).unwrap();
+    }
+
+    #[test]
+    fn test_metadata_marker() {
+        let metadata = Circle::new((0, 0), 1, &RED)
+            .with_metadata("dot", "", "Hello")
+            .metadata()
+            .clone();
+        let marker = metadata.to_marker();
+        assert_eq!(ElementMetadata::from_marker(&marker), Some(metadata));
+        assert_eq!(ElementMetadata::from_marker(END_MARKER), None);
+        assert!(MetadataMarker::is_marker(&MetadataMarker));
+        let font = TextStyle::from(("sans-serif", 10));
+        assert!(!MetadataMarker::is_marker(&font));
+    }
+
+    #[test]
+    fn test_metadata_on_other_backends() {
+        use crate::drawing::DummyBackendError;
+        use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        // A backend of another crate, which only implements DrawingBackend
+        struct PixelCounter(Rc<Cell<usize>>);
+
+        impl DrawingBackend for PixelCounter {
+            type ErrorType = DummyBackendError;
+            fn get_size(&self) -> (u32, u32) {
+                (100, 100)
+            }
+            fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+                Ok(())
+            }
+            fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+                Ok(())
+            }
+            fn draw_pixel(
+                &mut self,
+                _: BackendCoord,
+                _: BackendColor,
+            ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+                self.0.set(self.0.get() + 1);
+                Ok(())
+            }
+        }
+
+        let count = Rc::new(Cell::new(0));
+        let root = PixelCounter(count.clone()).into_drawing_area();
+        root.draw(&Pixel::new((10, 10), &RED).with_metadata("dot", "", ""))
+            .unwrap();
+        assert_eq!(count.get(), 1);
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        chart
+            .draw_series((1..4).map(|x| Pixel::new((x, x), &RED).with_metadata("", "dot", "")))
+            .unwrap();
+        assert_eq!(count.get(), 4);
+    }
+
+    #[cfg(feature = "svg_backend")]
+    #[test]
+    fn test_metadata_in_svg() {
+        use crate::drawing::AccessibleSVGBackend;
+
+        let mut content = String::new();
+        {
+            let root =
+                AccessibleSVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let dot = Circle::new((50, 50), 5, RED.filled()).with_metadata("dot", "", "<Hello>");
+            root.draw(&dot).unwrap();
+            let bar =
+                Rectangle::new([(10, 10), (20, 40)], BLUE.filled()).with_metadata("", "bar", "");
+            root.draw(&bar).unwrap();
+            root.present().unwrap();
+        }
+
+        assert!(content.contains("<g id=\"dot\">\n<title>&lt;Hello&gt;</title>\n<circle"));
+        assert!(content.contains("<g class=\"bar\">\n<rect"));
+        assert_eq!(
+            content.matches("<g").count(),
+            content.matches("</g>").count()
+        );
+    }
+
+    #[cfg(feature = "svg_backend")]
+    #[test]
+    fn test_draw_series_with_metadata() {
+        use crate::drawing::AccessibleSVGBackend;
+
+        let mut content = String::new();
+        {
+            let root =
+                AccessibleSVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            chart
+                .draw_series((1..4).map(|x| {
+                    Circle::new((x, x), 3, RED.filled()).with_metadata(
+                        format!("point-{}", x),
+                        "marker",
+                        "",
+                    )
+                }))
+                .unwrap();
+            root.present().unwrap();
+        }
+
+        assert!(content.contains("<g id=\"point-2\" class=\"marker\">\n<circle"));
+        assert_eq!(content.matches("class=\"marker\"").count(), 3);
+
+        // The plain SVG backend draws the elements without the metadata
+        let mut plain = String::new();
+        {
+            let root = SVGBackend::with_string(&mut plain, (100, 100)).into_drawing_area();
+            let dot = Circle::new((50, 50), 5, RED.filled()).with_metadata("dot", "", "Hello");
+            root.draw(&dot).unwrap();
+        }
+        assert!(plain.contains("<circle"));
+        assert!(!plain.contains("dot"));
+    }
+}
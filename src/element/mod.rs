@@ -160,6 +160,9 @@ use std::borrow::Borrow;
 mod basic_shapes;
 pub use basic_shapes::*;
 
+mod fill_rule;
+pub use fill_rule::FillRule;
+
 mod basic_shapes_3d;
 pub use basic_shapes_3d::*;
 
@@ -172,10 +175,42 @@ pub use points::*;
 mod composable;
 pub use composable::{ComposedElement, EmptyElement};
 
+mod group;
+pub use group::Group;
+
+mod arrow;
+pub use arrow::{Arrow, ArrowHead};
+
+mod colorbar;
+pub use colorbar::Colorbar;
+
+mod pie;
+pub use pie::Pie;
+
+mod funnel;
+pub use funnel::Funnel;
+
+mod reference_line;
+pub use reference_line::{Crosshair, HLine, HSpan, VLine, VSpan};
+
+mod sankey;
+pub use sankey::Sankey;
+
+mod dendrogram;
+pub use dendrogram::{Dendrogram, DendrogramOrientation};
+
+mod table;
+pub use table::Table;
+
 #[cfg(feature = "candlestick")]
 mod candlestick;
 #[cfg(feature = "candlestick")]
-pub use candlestick::CandleStick;
+pub use candlestick::{CandleStick, OHLCBar};
+
+#[cfg(feature = "gantt")]
+mod gantt;
+#[cfg(feature = "gantt")]
+pub use gantt::GanttBar;
 
 #[cfg(feature = "errorbar")]
 mod errorbar;
@@ -185,18 +220,33 @@ pub use errorbar::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV};
 #[cfg(feature = "boxplot")]
 mod boxplot;
 #[cfg(feature = "boxplot")]
-pub use boxplot::Boxplot;
+pub use boxplot::{Boxplot, BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+
+#[cfg(feature = "boxplot")]
+mod violin;
+#[cfg(feature = "boxplot")]
+pub use violin::Violin;
 
 #[cfg(feature = "bitmap_backend")]
 mod image;
 #[cfg(feature = "bitmap_backend")]
 pub use self::image::BitMapElement;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+mod image_element;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+pub use self::image_element::ImageElement;
+
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
 
+mod metadata;
+#[cfg(feature = "svg_backend")]
+pub(crate) use metadata::MetadataMarker;
+pub use metadata::{ElementExt, ElementMetadata, WithMetadata};
+
 use crate::coord::CoordTranslate;
-use crate::drawing::Rect;
+use crate::drawing::{BackendCapabilities, BlendMode, Rect};
 
 /// A type which is logically a collection of points, under any given coordinate system.
 /// Note: Ideally, a point collection trait should be any type of which coordinate elements can be
@@ -246,6 +296,53 @@ pub trait Drawable<DB: DrawingBackend, CM: CoordMapper = BackendCoordOnly> {
         backend: &mut DB,
         parent_dim: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    /// Draws the element on a backend that has the given drawing features, see
+    /// [BackendCapabilities](../drawing/struct.BackendCapabilities.html). The elements that
+    /// degrade a feature, such as the shapes with a gradient fill, override it, and the elements
+    /// that contain other elements pass the capabilities on. By default the element is drawn
+    /// with [draw](#tymethod.draw).
+    fn draw_with_capabilities<I: Iterator<Item = CM::Output>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+        _capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw(pos, backend, parent_dim)
+    }
+
+    /// The blend mode of the element's style, see
+    /// [ShapeStyle::blend_mode](../style/struct.ShapeStyle.html#method.blend_mode). The series
+    /// drawn with `ChartContext::draw_series` is blended as a group with the blend mode of its
+    /// first element. By default the element is drawn with the normal blend mode.
+    fn blend_mode(&self) -> BlendMode {
+        BlendMode::Normal
+    }
+}
+
+/// Check if the element's key points are translated into as many backend coordinates as the
+/// drawable expects. A mismatch usually means the element is used with an unexpected coordinate
+/// type, which would otherwise make the element silently disappear. Thus the mismatch triggers
+/// a warning on stderr when the `debug-draw` feature is enabled.
+///
+/// - `element`: The name of the element
+/// - `expected`: The number of points the element requires
+/// - `actual`: The number of points that are actually translated
+/// - **returns**: If there are enough points to draw the element
+#[cfg_attr(not(feature = "debug-draw"), allow(unused_variables))]
+pub(crate) fn check_point_count(element: &str, expected: usize, actual: usize) -> bool {
+    let matched = actual >= expected;
+    #[cfg(feature = "debug-draw")]
+    {
+        if !matched {
+            eprintln!(
+                "plotters: {} expects {} points, but only {} points are translated",
+                element, expected, actual
+            );
+        }
+    }
+    matched
 }
 
 pub trait CoordMapper {
@@ -255,10 +352,22 @@ pub trait CoordMapper {
 
 pub struct BackendCoordOnly;
 
+/// Move the point into the drawing area. When the backend clips the drawing to it, only the
+/// points that are one pixel off, such as the end of a flipped range, are moved, and the shapes
+/// that reach further are cut at the border rather than squashed onto it.
+fn truncate(rect: &Rect, point: BackendCoord) -> BackendCoord {
+    let truncated = rect.truncate(point);
+    if rect.is_clipped() && ((truncated.0 - point.0).abs() > 1 || (truncated.1 - point.1).abs() > 1)
+    {
+        return point;
+    }
+    truncated
+}
+
 impl CoordMapper for BackendCoordOnly {
     type Output = BackendCoord;
     fn map<CT: CoordTranslate>(coord_trans: &CT, from: &CT::From, rect: &Rect) -> BackendCoord {
-        rect.truncate(coord_trans.translate(from))
+        truncate(rect, coord_trans.translate(from))
     }
 }
 
@@ -271,7 +380,7 @@ impl CoordMapper for BackendCoordAndZ {
         from: &CT::From,
         rect: &Rect,
     ) -> (BackendCoord, i32) {
-        let coord = rect.truncate(coord_trans.translate(from));
+        let coord = truncate(rect, coord_trans.translate(from));
         let z = coord_trans.depth(from);
         (coord, z)
     }
@@ -0,0 +1,229 @@
+use std::f64::consts::PI;
+
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The pie chart element, which is positioned by its center in pixels and draws each value as a
+/// slice proportional to it. With an inner radius, the element is a donut chart.
+///
+/// The angles are in degrees, 0 is at 3 o'clock and the slices go clockwise. The first slice
+/// starts at 12 o'clock by default.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (400, 400)).into_drawing_area();
+/// let pie = Pie::new((200, 200), 120.0, &[3.0, 2.0, 1.0], &[RED, GREEN, BLUE])
+///     .labels(&["Red", "Green", "Blue"])
+///     .explode(&[10.0])
+///     .inner_radius(50.0);
+/// root.draw(&pie).unwrap();
+/// ```
+pub struct Pie<'a> {
+    center: BackendCoord,
+    radius: f64,
+    inner_radius: f64,
+    sizes: Vec<f64>,
+    styles: Vec<ShapeStyle>,
+    labels: Vec<String>,
+    explode: Vec<f64>,
+    start_angle: f64,
+    label_style: TextStyle<'a>,
+    label_offset: f64,
+}
+
+impl<'a> Pie<'a> {
+    /// Create a new pie chart
+    /// - `center`: The center of the pie in pixels
+    /// - `radius`: The radius of the pie in pixels
+    /// - `sizes`: The values of the slices, the negative and NaN values are treated as zero
+    /// - `colors`: The colors of the slices, which are repeated if there are more slices
+    /// - **returns** The newly created element
+    pub fn new<C: Color>(center: BackendCoord, radius: f64, sizes: &[f64], colors: &[C]) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0.0,
+            sizes: sizes
+                .iter()
+                .map(|&v| if v > 0.0 { v } else { 0.0 })
+                .collect(),
+            styles: colors.iter().map(|c| c.filled()).collect(),
+            labels: vec![],
+            explode: vec![],
+            start_angle: -90.0,
+            label_style: ("sans-serif", 15).into(),
+            label_offset: 1.1,
+        }
+    }
+
+    /// Set the labels of the slices, which are drawn outside of the pie
+    pub fn labels<L: ToString>(mut self, labels: &[L]) -> Self {
+        self.labels = labels.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the distance of the labels from the center, as the fraction of the radius
+    pub fn label_offset(mut self, offset: f64) -> Self {
+        self.label_offset = offset;
+        self
+    }
+
+    /// Move the slices away from the center, which highlights them
+    /// - `offsets`: The offsets of the slices in pixels, the slices without offset stay in place
+    pub fn explode(mut self, offsets: &[f64]) -> Self {
+        self.explode = offsets.to_vec();
+        self
+    }
+
+    /// Set the inner radius in pixels, which turns the pie into a donut
+    pub fn inner_radius(mut self, radius: f64) -> Self {
+        self.inner_radius = radius;
+        self
+    }
+
+    /// Set the angle where the first slice starts, in degrees
+    pub fn start_angle(mut self, angle: f64) -> Self {
+        self.start_angle = angle;
+        self
+    }
+
+    /// Get the start and end angles of the slices in radians
+    fn slice_angles(&self) -> Vec<(f64, f64)> {
+        let total: f64 = self.sizes.iter().sum();
+        let mut start = self.start_angle.to_radians();
+        self.sizes
+            .iter()
+            .map(|size| {
+                let span = if total > 0.0 {
+                    size / total * 2.0 * PI
+                } else {
+                    0.0
+                };
+                start += span;
+                (start - span, start)
+            })
+            .collect()
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b Pie<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Pie<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (cx, cy) = match points.next() {
+            Some(center) => (f64::from(center.0), f64::from(center.1)),
+            None => return Ok(()),
+        };
+        if self.styles.is_empty() {
+            return Ok(());
+        }
+
+        for (idx, (start, end)) in self.slice_angles().into_iter().enumerate() {
+            if end <= start {
+                continue;
+            }
+            let mid = (start + end) / 2.0;
+            let offset = self.explode.get(idx).cloned().unwrap_or(0.0);
+            let (ox, oy) = (cx + offset * mid.cos(), cy + offset * mid.sin());
+            let at = |angle: f64, r: f64| {
+                (
+                    (ox + r * angle.cos()).round() as i32,
+                    (oy + r * angle.sin()).round() as i32,
+                )
+            };
+
+            // Approximate the arcs with about one point per 2 pixels
+            let steps = ((end - start) * self.radius / 2.0).ceil().max(1.0) as usize;
+            let arc = |r: f64| {
+                (0..=steps)
+                    .map(move |s| start + (end - start) * s as f64 / steps as f64)
+                    .map(move |angle| at(angle, r))
+            };
+            let mut polygon: Vec<_> = arc(self.radius).collect();
+            if self.inner_radius > 0.0 {
+                let inner: Vec<_> = arc(self.inner_radius).collect();
+                polygon.extend(inner.into_iter().rev());
+            } else {
+                polygon.push(at(0.0, 0.0));
+            }
+            let style = &self.styles[idx % self.styles.len()];
+            backend.fill_polygon(polygon, style)?;
+
+            if let Some(label) = self.labels.get(idx) {
+                let hpos = if mid.cos() >= 0.0 {
+                    HPos::Left
+                } else {
+                    HPos::Right
+                };
+                let style = self.label_style.pos(Pos::new(hpos, VPos::Center));
+                backend.draw_text(label, &style, at(mid, self.radius * self.label_offset))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_slice_angles() {
+        let pie = Pie::new((0, 0), 10.0, &[1.0, std::f64::NAN, 3.0], &[RED]).start_angle(0.0);
+        let angles = pie.slice_angles();
+        assert_eq!(angles[0], (0.0, PI / 2.0));
+        assert_eq!(angles[1], (PI / 2.0, PI / 2.0));
+        assert_eq!(angles[2], (PI / 2.0, 2.0 * PI));
+    }
+
+    #[test]
+    fn test_draw_pie() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                // The slice from 12 o'clock to 3 o'clock, which is moved to the upper right
+                assert!(path.iter().all(|&(x, y)| x >= 105 && y <= 95));
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, BLUE.to_rgba());
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert!(pos.0 > 100 && pos.1 < 100);
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let pie = Pie::new((100, 100), 50.0, &[1.0, 3.0], &[RED, BLUE])
+            .labels(&["a", "b"])
+            .explode(&[8.0])
+            .inner_radius(10.0);
+        drawing_area.draw(&pie).unwrap();
+    }
+}
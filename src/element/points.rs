@@ -8,6 +8,234 @@ pub trait PointElement<Coord, Size: SizeDesc> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self;
 }
 
+/// The outline of a custom marker: it's given the size of the marker in pixels and returns the
+/// corners of the marker relative to its center
+pub type MarkerPath = fn(u32) -> Vec<BackendCoord>;
+
+/// The shape of the markers that an element draws for its individual data points, such as the
+/// outliers of a boxplot or the points of a [Marker](struct.Marker.html) series
+#[derive(Clone, Copy, Debug)]
+pub enum MarkerShape {
+    /// A circle
+    Circle,
+    /// A diagonal cross
+    Cross,
+    /// A triangle pointing upwards
+    Triangle,
+    /// An axis-aligned square
+    Square,
+    /// A square standing on its corner
+    Diamond,
+    /// A five-pointed star
+    Star,
+    /// An axis-aligned cross
+    Plus,
+    /// A user-defined polygon, which is filled or outlined like the built-in shapes
+    Custom(MarkerPath),
+}
+
+impl PartialEq for MarkerShape {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Two custom shapes are the same if they use the same function
+            (MarkerShape::Custom(a), MarkerShape::Custom(b)) => *a as usize == *b as usize,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+/// The built-in shapes in the order that [MarkerShape::pick](enum.MarkerShape.html#method.pick)
+/// cycles through
+const BUILTIN_SHAPES: [MarkerShape; 7] = [
+    MarkerShape::Circle,
+    MarkerShape::Square,
+    MarkerShape::Triangle,
+    MarkerShape::Diamond,
+    MarkerShape::Cross,
+    MarkerShape::Star,
+    MarkerShape::Plus,
+];
+
+/// The ratio between the inner and the outer radius of a star
+const STAR_INNER_RATIO: f64 = 0.4;
+
+impl MarkerShape {
+    /// Pick a built-in shape by index, which cycles through the shapes, so each category of a
+    /// scatter plot can get its own shape
+    pub fn pick(idx: usize) -> Self {
+        BUILTIN_SHAPES[idx % BUILTIN_SHAPES.len()]
+    }
+
+    /// The corners of a polygonal shape relative to its center, `None` for the other shapes
+    fn outline(self, size: u32) -> Option<Vec<BackendCoord>> {
+        let polar = |deg: f64, r: f64| {
+            let rad = deg * std::f64::consts::PI / 180.0;
+            (
+                (rad.cos() * r).round() as i32,
+                (rad.sin() * r).round() as i32,
+            )
+        };
+        let r = f64::from(size);
+        match self {
+            MarkerShape::Diamond => Some(vec![
+                (0, -(size as i32)),
+                (size as i32, 0),
+                (0, size as i32),
+                (-(size as i32), 0),
+            ]),
+            MarkerShape::Star => Some(
+                (0..10)
+                    .map(|i| {
+                        let radius = if i % 2 == 0 { r } else { r * STAR_INNER_RATIO };
+                        polar(-90.0 + 36.0 * f64::from(i), radius)
+                    })
+                    .collect(),
+            ),
+            MarkerShape::Custom(path) => Some(path(size)),
+            _ => None,
+        }
+    }
+
+    /// Draw the marker on the backend, the outline is drawn unless the style is filled
+    pub(crate) fn draw<DB: DrawingBackend>(
+        self,
+        backend: &mut DB,
+        (x, y): BackendCoord,
+        size: u32,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let size = size as i32;
+        match self {
+            MarkerShape::Circle => backend.draw_circle((x, y), size as u32, style, style.filled),
+            MarkerShape::Cross => {
+                backend.draw_line((x - size, y - size), (x + size, y + size), style)?;
+                backend.draw_line((x - size, y + size), (x + size, y - size), style)
+            }
+            MarkerShape::Triangle => {
+                let mut points: Vec<_> = [-90, -210, -330]
+                    .iter()
+                    .map(|deg| f64::from(*deg) * std::f64::consts::PI / 180.0)
+                    .map(|rad| {
+                        (
+                            (rad.cos() * f64::from(size) + f64::from(x)).ceil() as i32,
+                            (rad.sin() * f64::from(size) + f64::from(y)).ceil() as i32,
+                        )
+                    })
+                    .collect();
+                if style.filled {
+                    backend.fill_polygon(points, style)
+                } else {
+                    points.push(points[0]);
+                    backend.draw_path(points, style)
+                }
+            }
+            MarkerShape::Square => backend.draw_rect(
+                (x - size, y - size),
+                (x + size, y + size),
+                style,
+                style.filled,
+            ),
+            MarkerShape::Plus => {
+                backend.draw_line((x - size, y), (x + size, y), style)?;
+                backend.draw_line((x, y - size), (x, y + size), style)
+            }
+            MarkerShape::Diamond | MarkerShape::Star | MarkerShape::Custom(_) => {
+                let mut points: Vec<_> = self
+                    .outline(size as u32)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(dx, dy)| (x + dx, y + dy))
+                    .collect();
+                if points.is_empty() {
+                    return Ok(());
+                }
+                if style.filled {
+                    backend.fill_polygon(points, style)
+                } else {
+                    points.push(points[0]);
+                    backend.draw_path(points, style)
+                }
+            }
+        }
+    }
+}
+
+/// The element that draws a marker of any [MarkerShape](enum.MarkerShape.html) at a point.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let categories = vec![vec![(1.0, 2.0), (2.0, 3.0)], vec![(3.0, 1.0)], vec![(4.0, 4.0)]];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..5.0, 0.0..5.0)
+///     .unwrap();
+/// for (idx, points) in categories.into_iter().enumerate() {
+///     let shape = MarkerShape::pick(idx);
+///     chart
+///         .draw_series(PointSeries::of_element(points, 5, &RED, &|c, s, st| {
+///             Marker::new(c, s, st, shape)
+///         }))
+///         .unwrap();
+/// }
+/// ```
+pub struct Marker<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    style: ShapeStyle,
+    shape: MarkerShape,
+}
+
+impl<Coord, Size: SizeDesc> Marker<Coord, Size> {
+    /// Create a new marker
+    /// - `coord`: The center of the marker
+    /// - `size`: The radius of the marker
+    /// - `style`: The style of the marker, a filled style fills the closed shapes
+    /// - `shape`: The shape of the marker
+    pub fn new<T: Into<ShapeStyle>>(
+        coord: Coord,
+        size: Size,
+        style: T,
+        shape: MarkerShape,
+    ) -> Self {
+        Self {
+            center: coord,
+            size,
+            style: style.into(),
+            shape,
+        }
+    }
+}
+
+impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a Marker<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Marker<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(pos) = points.next() {
+            let size = self.size.in_pixels(&ps).max(0) as u32;
+            self.shape.draw(backend, pos, size, &self.style)?;
+        }
+        Ok(())
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
+}
+
 /// Describe a cross
 pub struct Cross<Coord, Size: SizeDesc> {
     center: Coord,
@@ -49,6 +277,10 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Cross<Coord, Si
         }
         Ok(())
     }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
 }
 
 /// Describe a triangle marker
@@ -98,6 +330,10 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for TriangleMarker<
         }
         Ok(())
     }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.style.blend_mode
+    }
 }
 
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Cross<Coord, Size> {
@@ -123,3 +359,66 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Pixel<Coord> {
         Self::new(pos, style)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn arrow_up(size: u32) -> Vec<BackendCoord> {
+        let size = size as i32;
+        vec![(0, -size), (size, size), (0, 0), (-size, size)]
+    }
+
+    #[test]
+    fn test_marker_shapes() {
+        assert_eq!(MarkerShape::pick(1), MarkerShape::Square);
+        assert_eq!(MarkerShape::pick(BUILTIN_SHAPES.len()), MarkerShape::Circle);
+
+        let star = MarkerShape::Star.outline(10).unwrap();
+        assert_eq!(star.len(), 10);
+        assert_eq!(star[0], (0, -10));
+        assert_eq!(star[5], (0, 4));
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(50, 45), (55, 50), (50, 55), (45, 50)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                // The custom outline is closed
+                assert_eq!(path, vec![(20, 15), (25, 25), (20, 20), (15, 25), (20, 15)]);
+            });
+            m.check_draw_line(|_, _, from, to| {
+                assert_eq!((from, to), ((5, 10), (15, 10)));
+            });
+            m.check_draw_line(|_, _, from, to| {
+                assert_eq!((from, to), ((10, 5), (10, 15)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_line_call, 2);
+            });
+        });
+        drawing_area
+            .draw(&Marker::new(
+                (50, 50),
+                5,
+                RED.filled(),
+                MarkerShape::Diamond,
+            ))
+            .unwrap();
+        drawing_area
+            .draw(&Marker::new(
+                (20, 20),
+                5,
+                &RED,
+                MarkerShape::Custom(arrow_up),
+            ))
+            .unwrap();
+        drawing_area
+            .draw(&Marker::new((10, 10), 5, &RED, MarkerShape::Plus))
+            .unwrap();
+    }
+}
@@ -0,0 +1,514 @@
+use std::ops::Range;
+
+use super::{Drawable, PointCollection, Rectangle};
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The gap between a value label and its line in pixels
+const LABEL_GAP: i32 = 3;
+
+/// Draw a reference line, and its label above the start of the line
+fn draw_reference_line<DB: DrawingBackend>(
+    backend: &mut DB,
+    from: BackendCoord,
+    to: BackendCoord,
+    style: &ShapeStyle,
+    label: Option<(&str, &TextStyle)>,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    style.stroke_path(backend, vec![from, to])?;
+    if let Some((text, label_style)) = label {
+        let label_style = label_style.pos(Pos::new(HPos::Left, VPos::Bottom));
+        backend.draw_text(text, &label_style, (from.0 + LABEL_GAP, from.1 - LABEL_GAP))?;
+    }
+    Ok(())
+}
+
+/// The default style of the value labels
+fn default_label_style<'a>() -> TextStyle<'a> {
+    ("sans-serif", 12).into()
+}
+
+/// The horizontal reference line at a Y value, which spans the whole X range of the chart it's
+/// made for, so the line reaches both sides of the plotting area whatever the X range is.
+///
+/// The value label is formatted by the Y coordinate in the same way as the axis labels, and it's
+/// pinned to the Y axis, above the left end of the line.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+///     .unwrap();
+/// let threshold = HLine::new(&chart, 80.0, RED.stroke_width(2)).value_label(true);
+/// let mean = VLine::new(&chart, 4.5, BLUE);
+/// chart.draw_series(vec![threshold]).unwrap();
+/// chart.draw_series(vec![mean]).unwrap();
+/// ```
+pub struct HLine<'a, X, Y> {
+    points: [(X, Y); 2],
+    style: ShapeStyle,
+    label: String,
+    show_label: bool,
+    label_style: TextStyle<'a>,
+}
+
+impl<'a, X, Y> HLine<'a, X, Y> {
+    /// Create a new horizontal reference line
+    /// - `chart`: The chart that the line spans
+    /// - `y`: The Y value of the line
+    /// - `style`: The style of the line
+    /// - **returns** The newly created element
+    pub fn new<DB, XR, YR, S>(chart: &ChartContext<DB, Cartesian2d<XR, YR>>, y: Y, style: S) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y> + ValueFormatter<Y>,
+        Y: Clone,
+        S: Into<ShapeStyle>,
+    {
+        let spec = chart.as_coord_spec();
+        let x_range = spec.x_spec().range();
+        Self {
+            label: spec.y_spec().format_ext(&y),
+            points: [(x_range.start, y.clone()), (x_range.end, y)],
+            style: style.into(),
+            show_label: false,
+            label_style: default_label_style(),
+        }
+    }
+
+    /// Set if the value label is drawn, which it isn't by default
+    pub fn value_label(mut self, show: bool) -> Self {
+        self.show_label = show;
+        self
+    }
+
+    /// Set the style of the value label
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+}
+
+impl<'b, 'a, X, Y> PointCollection<'b, (X, Y)> for &'b HLine<'a, X, Y> {
+    type Point = &'b (X, Y);
+    type IntoIter = &'b [(X, Y)];
+    fn point_iter(self) -> &'b [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<'a, X, Y, DB: DrawingBackend> Drawable<DB> for HLine<'a, X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(from), Some(to)) = (points.next(), points.next()) {
+            let label = if self.show_label {
+                Some((self.label.as_str(), &self.label_style))
+            } else {
+                None
+            };
+            draw_reference_line(backend, from, to, &self.style, label)?;
+        }
+        Ok(())
+    }
+}
+
+/// The vertical reference line at a X value, which spans the whole Y range of the chart it's
+/// made for. See [HLine](struct.HLine.html) for the example.
+///
+/// The value label is formatted by the X coordinate in the same way as the axis labels, and it's
+/// pinned to the X axis, right next to the bottom end of the line.
+pub struct VLine<'a, X, Y> {
+    points: [(X, Y); 2],
+    style: ShapeStyle,
+    label: String,
+    show_label: bool,
+    label_style: TextStyle<'a>,
+}
+
+impl<'a, X, Y> VLine<'a, X, Y> {
+    /// Create a new vertical reference line
+    /// - `chart`: The chart that the line spans
+    /// - `x`: The X value of the line
+    /// - `style`: The style of the line
+    /// - **returns** The newly created element
+    pub fn new<DB, XR, YR, S>(chart: &ChartContext<DB, Cartesian2d<XR, YR>>, x: X, style: S) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X> + ValueFormatter<X>,
+        YR: Ranged<ValueType = Y>,
+        X: Clone,
+        S: Into<ShapeStyle>,
+    {
+        let spec = chart.as_coord_spec();
+        let y_range = spec.y_spec().range();
+        Self {
+            label: spec.x_spec().format_ext(&x),
+            points: [(x.clone(), y_range.start), (x, y_range.end)],
+            style: style.into(),
+            show_label: false,
+            label_style: default_label_style(),
+        }
+    }
+
+    /// Set if the value label is drawn, which it isn't by default
+    pub fn value_label(mut self, show: bool) -> Self {
+        self.show_label = show;
+        self
+    }
+
+    /// Set the style of the value label
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+}
+
+impl<'b, 'a, X, Y> PointCollection<'b, (X, Y)> for &'b VLine<'a, X, Y> {
+    type Point = &'b (X, Y);
+    type IntoIter = &'b [(X, Y)];
+    fn point_iter(self) -> &'b [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<'a, X, Y, DB: DrawingBackend> Drawable<DB> for VLine<'a, X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(from), Some(to)) = (points.next(), points.next()) {
+            let label = if self.show_label {
+                Some((self.label.as_str(), &self.label_style))
+            } else {
+                None
+            };
+            draw_reference_line(backend, from, to, &self.style, label)?;
+        }
+        Ok(())
+    }
+}
+
+/// The crosshair at a point, which is the horizontal and the vertical reference lines that
+/// cross at the point and span the whole chart it's made for, such as the cursor position of an
+/// interactive chart. The value labels are pinned to the axes like the labels of
+/// [HLine](struct.HLine.html) and [VLine](struct.VLine.html).
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..100, 0.0..1.0)
+///     .unwrap();
+/// let cursor = Crosshair::new(&chart, (42, 0.3), BLACK.mix(0.5)).value_label(true);
+/// chart.draw_series(std::iter::once(cursor)).unwrap();
+/// ```
+pub struct Crosshair<'a, X, Y> {
+    points: [(X, Y); 4],
+    style: ShapeStyle,
+    labels: (String, String),
+    show_label: bool,
+    label_style: TextStyle<'a>,
+}
+
+impl<'a, X, Y> Crosshair<'a, X, Y> {
+    /// Create a new crosshair
+    /// - `chart`: The chart that the lines span
+    /// - `point`: The point where the lines cross
+    /// - `style`: The style of the lines
+    /// - **returns** The newly created element
+    pub fn new<DB, XR, YR, S>(
+        chart: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        point: (X, Y),
+        style: S,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X> + ValueFormatter<X>,
+        YR: Ranged<ValueType = Y> + ValueFormatter<Y>,
+        X: Clone,
+        Y: Clone,
+        S: Into<ShapeStyle>,
+    {
+        let spec = chart.as_coord_spec();
+        let (x_range, y_range) = (spec.x_spec().range(), spec.y_spec().range());
+        let (x, y) = point;
+        Self {
+            labels: (spec.x_spec().format_ext(&x), spec.y_spec().format_ext(&y)),
+            points: [
+                (x_range.start, y.clone()),
+                (x_range.end, y.clone()),
+                (x.clone(), y_range.start),
+                (x, y_range.end),
+            ],
+            style: style.into(),
+            show_label: false,
+            label_style: default_label_style(),
+        }
+    }
+
+    /// Set if the value labels are drawn, which they aren't by default
+    pub fn value_label(mut self, show: bool) -> Self {
+        self.show_label = show;
+        self
+    }
+
+    /// Set the style of the value labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+}
+
+impl<'b, 'a, X, Y> PointCollection<'b, (X, Y)> for &'b Crosshair<'a, X, Y> {
+    type Point = &'b (X, Y);
+    type IntoIter = &'b [(X, Y)];
+    fn point_iter(self) -> &'b [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<'a, X, Y, DB: DrawingBackend> Drawable<DB> for Crosshair<'a, X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < 4 {
+            return Ok(());
+        }
+        for (line, label) in points.chunks(2).zip(&[&self.labels.1, &self.labels.0]) {
+            let label = if self.show_label {
+                Some((label.as_str(), &self.label_style))
+            } else {
+                None
+            };
+            draw_reference_line(backend, line[0], line[1], &self.style, label)?;
+        }
+        Ok(())
+    }
+}
+
+/// The horizontal band between two Y values, which is filled across the whole X range of the
+/// chart it's made for, such as the normal range of a measurement.
+///
+/// The band is shrunk to the plotting area when the series is clipped. Draw it before the
+/// series to shade the area beneath them.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..24, -5.0..5.0)
+///     .unwrap();
+/// let nights = vec![VSpan::new(&chart, 0..6, BLACK.mix(0.1)), VSpan::new(&chart, 20..24, BLACK.mix(0.1))];
+/// chart.draw_series(nights).unwrap();
+/// chart.draw_series(std::iter::once(HSpan::new(&chart, -1.0..1.0, GREEN.mix(0.2)))).unwrap();
+/// chart
+///     .draw_series(LineSeries::new((0..24).map(|h| (h, (h as f64 / 4.0).sin() * 4.0)), &RED))
+///     .unwrap();
+/// ```
+pub struct HSpan<X, Y> {
+    rect: Rectangle<(X, Y)>,
+}
+
+impl<X, Y> HSpan<X, Y> {
+    /// Create a new horizontal band
+    /// - `chart`: The chart that the band spans
+    /// - `y`: The Y values between which the band is filled
+    /// - `style`: The style of the band, which is always filled
+    /// - **returns** The newly created element
+    pub fn new<DB, XR, YR, S>(
+        chart: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        y: Range<Y>,
+        style: S,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        S: Into<ShapeStyle>,
+    {
+        let x = chart.as_coord_spec().x_spec().range();
+        let style = ShapeStyle {
+            filled: true,
+            ..style.into()
+        };
+        Self {
+            rect: Rectangle::new([(x.start, y.start), (x.end, y.end)], style),
+        }
+    }
+}
+
+impl<'b, X, Y> PointCollection<'b, (X, Y)> for &'b HSpan<X, Y> {
+    type Point = &'b (X, Y);
+    type IntoIter = &'b [(X, Y)];
+    fn point_iter(self) -> &'b [(X, Y)] {
+        self.rect.point_iter()
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for HSpan<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.rect.draw(points, backend, parent_dim)
+    }
+}
+
+/// The vertical band between two X values, which is filled across the whole Y range of the
+/// chart it's made for, such as the nights or the recession periods of a time series. See
+/// [HSpan](struct.HSpan.html) for the example.
+///
+/// The band is shrunk to the plotting area when the series is clipped. Draw it before the
+/// series to shade the area beneath them.
+pub struct VSpan<X, Y> {
+    rect: Rectangle<(X, Y)>,
+}
+
+impl<X, Y> VSpan<X, Y> {
+    /// Create a new vertical band
+    /// - `chart`: The chart that the band spans
+    /// - `x`: The X values between which the band is filled
+    /// - `style`: The style of the band, which is always filled
+    /// - **returns** The newly created element
+    pub fn new<DB, XR, YR, S>(
+        chart: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        x: Range<X>,
+        style: S,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        S: Into<ShapeStyle>,
+    {
+        let y = chart.as_coord_spec().y_spec().range();
+        let style = ShapeStyle {
+            filled: true,
+            ..style.into()
+        };
+        Self {
+            rect: Rectangle::new([(x.start, y.start), (x.end, y.end)], style),
+        }
+    }
+}
+
+impl<'b, X, Y> PointCollection<'b, (X, Y)> for &'b VSpan<X, Y> {
+    type Point = &'b (X, Y);
+    type IntoIter = &'b [(X, Y)];
+    fn point_iter(self) -> &'b [(X, Y)] {
+        self.rect.point_iter()
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for VSpan<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.rect.draw(points, backend, parent_dim)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_reference_lines() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(0, 49), (100, 49)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(25, 99), (25, 0)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "25");
+                assert_eq!(pos, (28, 96));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0.0..1.0)
+            .unwrap();
+        let hline = HLine::new(&chart, 0.5, RED);
+        let vline = VLine::new(&chart, 25, BLUE).value_label(true);
+        chart.draw_series(vec![hline]).unwrap();
+        chart.draw_series(vec![vline]).unwrap();
+    }
+
+    #[test]
+    fn test_crosshair() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path, vec![(0, 74), (100, 74)]));
+            m.check_draw_path(|_, _, path| assert_eq!(path, vec![(40, 99), (40, 0)]));
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "0.25");
+                assert_eq!(pos, (3, 71));
+            });
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "40"));
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0.0..1.0)
+            .unwrap();
+        let cursor = Crosshair::new(&chart, (40, 0.25), BLACK).value_label(true);
+        chart.draw_series(std::iter::once(cursor)).unwrap();
+    }
+
+    #[test]
+    fn test_spans() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(filled);
+                assert_eq!((u.0, d.0), (0, 100));
+            });
+            m.check_draw_rect(|_, _, filled, u, d| {
+                assert!(filled);
+                // The band out of the range is shrunk to the plotting area
+                assert_eq!((u.0, d.0), (90, 100));
+                assert_eq!((u.1, d.1), (0, 99));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0.0..1.0)
+            .unwrap();
+        let hspan = HSpan::new(&chart, 0.25..0.5, BLUE);
+        let vspan = VSpan::new(&chart, 90..120, RED.mix(0.2));
+        chart.draw_series(vec![hspan]).unwrap();
+        chart.draw_series(vec![vspan]).unwrap();
+    }
+}
@@ -0,0 +1,473 @@
+use std::collections::HashMap;
+
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, Palette, Palette99, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The number of the sweeps that reorder the nodes of each layer by their neighbours
+const ORDER_SWEEPS: usize = 6;
+/// The number of the segments of each curved edge of a link
+const LINK_STEPS: usize = 16;
+
+/// The positions of the nodes and the links in the pixels relative to the upper left corner
+struct Layout {
+    /// The upper left and the bottom right corners of each node
+    nodes: Vec<[(f64, f64); 2]>,
+    /// The source, the start Y on the source, the end Y on the target, and the width of each link
+    links: Vec<(usize, f64, f64, f64)>,
+    /// The X of the right side of the source and the left side of the target of each link
+    link_x: Vec<(f64, f64)>,
+    last_layer: Vec<bool>,
+}
+
+/// The Sankey diagram, which draws the weighted flows between the nodes as the curved ribbons of
+/// the widths proportional to the values.
+///
+/// The nodes are put in the layers from the left to the right: the sources are on the first
+/// layer, each node is on the layer after the furthest node that flows into it, and the nodes
+/// without outgoing links are on the last layer. The nodes of each layer are ordered by their
+/// neighbours to reduce the crossings, and the height of each node is proportional to the larger
+/// of its inflow and its outflow. The links that close a cycle are left out.
+///
+/// The element is positioned by its upper left corner in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let sankey = Sankey::new(
+///     (20, 20),
+///     (560, 440),
+///     vec![
+///         ("coal", "electricity", 25.0),
+///         ("gas", "electricity", 20.0),
+///         ("gas", "heating", 15.0),
+///         ("electricity", "homes", 20.0),
+///         ("electricity", "industry", 25.0),
+///         ("heating", "homes", 15.0),
+///     ],
+/// )
+/// .label_style(("sans-serif", 14));
+/// root.draw(&sankey).unwrap();
+/// ```
+pub struct Sankey<'a> {
+    upper_left: BackendCoord,
+    size: (u32, u32),
+    names: Vec<String>,
+    links: Vec<(usize, usize, f64)>,
+    node_width: u32,
+    node_padding: u32,
+    styles: Vec<ShapeStyle>,
+    link_opacity: f64,
+    label_style: TextStyle<'a>,
+}
+
+impl<'a> Sankey<'a> {
+    /// Create a new Sankey diagram
+    /// - `upper_left`: The upper left corner in pixels
+    /// - `size`: The size in pixels
+    /// - `links`: The links as `(source, target, value)`, the nodes are identified by their names
+    ///   and the links of the values that aren't positive are ignored
+    /// - **returns** The newly created element
+    pub fn new<I: IntoIterator<Item = (S, S, f64)>, S: Into<String>>(
+        upper_left: BackendCoord,
+        size: (u32, u32),
+        links: I,
+    ) -> Self {
+        let mut names = vec![];
+        let mut ids = HashMap::new();
+        let mut id = |name: S| {
+            let name = name.into();
+            *ids.entry(name.clone()).or_insert_with(|| {
+                names.push(name);
+                names.len() - 1
+            })
+        };
+        let links = links
+            .into_iter()
+            .map(|(source, target, value)| (id(source), id(target), value))
+            .filter(|&(source, target, value)| source != target && value > 0.0)
+            .collect();
+        Self {
+            upper_left,
+            size,
+            names,
+            links,
+            node_width: 15,
+            node_padding: 10,
+            styles: vec![],
+            link_opacity: 0.4,
+            label_style: ("sans-serif", 12).into(),
+        }
+    }
+
+    /// Set the width of the nodes in pixels
+    pub fn node_width(mut self, width: u32) -> Self {
+        self.node_width = width;
+        self
+    }
+
+    /// Set the vertical gap between the nodes of a layer in pixels
+    pub fn node_padding(mut self, padding: u32) -> Self {
+        self.node_padding = padding;
+        self
+    }
+
+    /// Set the colors of the nodes in the order they first appear in the links, which are
+    /// repeated if there are more nodes. The nodes are colored by `Palette99` by default.
+    pub fn colors<C: Color>(mut self, colors: &[C]) -> Self {
+        self.styles = colors.iter().map(|c| c.filled()).collect();
+        self
+    }
+
+    /// Set the opacity of the links, which have the color of their sources
+    pub fn link_opacity(mut self, opacity: f64) -> Self {
+        self.link_opacity = opacity;
+        self
+    }
+
+    /// Set the style of the node labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    fn node_style(&self, node: usize) -> ShapeStyle {
+        if self.styles.is_empty() {
+            Palette99::pick(node).filled()
+        } else {
+            self.styles[node % self.styles.len()].clone()
+        }
+    }
+
+    /// Put each node on the layer after the furthest node that flows into it, and the sinks on
+    /// the last layer. When the remaining nodes are all in the cycles, the one with the fewest
+    /// links from them is taken next, and the links that don't go to a later layer close a cycle.
+    fn layers(&self) -> Vec<usize> {
+        let n = self.names.len();
+        let mut in_degree = vec![0; n];
+        for &(_, target, _) in &self.links {
+            in_degree[target] += 1;
+        }
+        let mut layers = vec![0; n];
+        let mut visited = vec![false; n];
+        let mut queue: Vec<usize> = (0..n).filter(|&i| in_degree[i] == 0).collect();
+        loop {
+            let node = match queue.pop() {
+                Some(node) => node,
+                None => match (0..n)
+                    .filter(|&i| !visited[i])
+                    .min_by_key(|&i| in_degree[i])
+                {
+                    Some(node) => node,
+                    None => break,
+                },
+            };
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            for &(source, target, _) in &self.links {
+                if source == node && !visited[target] {
+                    layers[target] = layers[target].max(layers[node] + 1);
+                    in_degree[target] -= 1;
+                    if in_degree[target] == 0 {
+                        queue.push(target);
+                    }
+                }
+            }
+        }
+        let last = layers.iter().cloned().max().unwrap_or(0);
+        for (node, layer) in layers.iter_mut().enumerate() {
+            if !self.links.iter().any(|l| l.0 == node) {
+                *layer = last;
+            }
+        }
+        layers
+    }
+
+    fn layout(&self) -> Layout {
+        let n = self.names.len();
+        let layers = self.layers();
+        let links: Vec<(usize, usize, f64)> = self
+            .links
+            .iter()
+            .cloned()
+            .filter(|&(source, target, _)| layers[source] < layers[target])
+            .collect();
+        let layer_count = layers.iter().cloned().max().map_or(0, |l| l + 1);
+
+        let mut values = vec![(0.0, 0.0); n];
+        for &(source, target, value) in &links {
+            values[source].1 += value;
+            values[target].0 += value;
+        }
+        let values: Vec<f64> = values.iter().map(|(i, o): &(f64, f64)| i.max(*o)).collect();
+
+        let mut columns: Vec<Vec<usize>> = vec![vec![]; layer_count];
+        for (node, &layer) in layers.iter().enumerate() {
+            columns[layer].push(node);
+        }
+        let (width, height) = (f64::from(self.size.0), f64::from(self.size.1));
+        let padding = f64::from(self.node_padding);
+        let mut scales = columns.iter().filter(|c| !c.is_empty()).map(|c| {
+            let total: f64 = c.iter().map(|&i| values[i]).sum();
+            (height - padding * (c.len() - 1) as f64) / total
+        });
+        let scale = match scales.next() {
+            Some(first) => scales.fold(first, f64::min),
+            None => 0.0,
+        };
+        let scale = if scale.is_finite() && scale > 0.0 {
+            scale
+        } else {
+            0.0
+        };
+
+        // Stack the nodes of each layer from the top, and center the layer vertically
+        let mut tops = vec![0.0; n];
+        let place = |columns: &[Vec<usize>], tops: &mut Vec<f64>| {
+            for column in columns {
+                let total: f64 = column.iter().map(|&i| values[i] * scale).sum::<f64>()
+                    + padding * column.len().saturating_sub(1) as f64;
+                let mut y = (height - total) / 2.0;
+                for &node in column {
+                    tops[node] = y;
+                    y += values[node] * scale + padding;
+                }
+            }
+        };
+        place(&columns, &mut tops);
+
+        // Reorder each layer by the weighted mean of the centers of the neighbours, sweeping
+        // forward by the sources and backward by the targets
+        for sweep in 0..ORDER_SWEEPS {
+            let forward = sweep % 2 == 0;
+            let center = |node: usize, tops: &Vec<f64>| tops[node] + values[node] * scale / 2.0;
+            let mut order: Vec<usize> = (0..layer_count).collect();
+            if !forward {
+                order.reverse();
+            }
+            for layer in order {
+                let mut keyed: Vec<(f64, usize)> = columns[layer]
+                    .iter()
+                    .map(|&node| {
+                        let (mut sum, mut weight) = (0.0, 0.0);
+                        for &(source, target, value) in &links {
+                            let other = if forward && target == node {
+                                source
+                            } else if !forward && source == node {
+                                target
+                            } else {
+                                continue;
+                            };
+                            sum += center(other, &tops) * value;
+                            weight += value;
+                        }
+                        let key = if weight > 0.0 {
+                            sum / weight
+                        } else {
+                            center(node, &tops)
+                        };
+                        (key, node)
+                    })
+                    .collect();
+                keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+                columns[layer] = keyed.into_iter().map(|(_, node)| node).collect();
+                place(&columns, &mut tops);
+            }
+        }
+
+        let node_width = f64::from(self.node_width);
+        let x = |layer: usize| {
+            if layer_count > 1 {
+                (width - node_width) * layer as f64 / (layer_count - 1) as f64
+            } else {
+                0.0
+            }
+        };
+        let nodes: Vec<[(f64, f64); 2]> = (0..n)
+            .map(|i| {
+                let x0 = x(layers[i]);
+                [
+                    (x0, tops[i]),
+                    (x0 + node_width, tops[i] + values[i] * scale),
+                ]
+            })
+            .collect();
+
+        // The links leave a source in the order of their targets, and enter a target in the
+        // order of their sources, which keeps them from crossing at the nodes
+        let center = |node: usize| (nodes[node][0].1 + nodes[node][1].1) / 2.0;
+        let mut out_order: Vec<usize> = (0..links.len()).collect();
+        out_order.sort_by(|&a, &b| {
+            center(links[a].1)
+                .partial_cmp(&center(links[b].1))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let mut in_order: Vec<usize> = (0..links.len()).collect();
+        in_order.sort_by(|&a, &b| {
+            center(links[a].0)
+                .partial_cmp(&center(links[b].0))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let mut out_offset: Vec<f64> = nodes.iter().map(|r| r[0].1).collect();
+        let mut in_offset = out_offset.clone();
+        let mut starts = vec![0.0; links.len()];
+        let mut ends = vec![0.0; links.len()];
+        for idx in out_order {
+            starts[idx] = out_offset[links[idx].0];
+            out_offset[links[idx].0] += links[idx].2 * scale;
+        }
+        for idx in in_order {
+            ends[idx] = in_offset[links[idx].1];
+            in_offset[links[idx].1] += links[idx].2 * scale;
+        }
+
+        Layout {
+            links: links
+                .iter()
+                .enumerate()
+                .map(|(idx, l)| (l.0, starts[idx], ends[idx], l.2 * scale))
+                .collect(),
+            link_x: links
+                .iter()
+                .map(|l| (nodes[l.0][1].0, nodes[l.1][0].0))
+                .collect(),
+            last_layer: layers
+                .iter()
+                .map(|&l| layer_count > 1 && l == layer_count - 1)
+                .collect(),
+            nodes,
+        }
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b Sankey<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.upper_left)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Sankey<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => (f64::from(pos.0), f64::from(pos.1)),
+            None => return Ok(()),
+        };
+        let at = |(x, y): (f64, f64)| ((x0 + x).round() as i32, (y0 + y).round() as i32);
+        let layout = self.layout();
+
+        for (&(source, start, end, width), &(from, to)) in layout.links.iter().zip(&layout.link_x) {
+            // The edges are the cubic curves whose control points are half way between the nodes
+            let edge = |y_from: f64, y_to: f64| {
+                (0..=LINK_STEPS).map(move |step| {
+                    let t = step as f64 / LINK_STEPS as f64;
+                    let s = 1.0 - t;
+                    let x =
+                        from * (s * s * s + 3.0 * s * s * t) + to * (3.0 * s * t * t + t * t * t);
+                    let y = y_from * (s * s * s + 3.0 * s * s * t)
+                        + y_to * (3.0 * s * t * t + t * t * t);
+                    (x, y)
+                })
+            };
+            let mut ribbon: Vec<_> = edge(start, end).map(at).collect();
+            let bottom: Vec<_> = edge(start + width, end + width).map(at).collect();
+            ribbon.extend(bottom.into_iter().rev());
+            let color = self.node_style(source).color;
+            let alpha = color.3 * self.link_opacity;
+            backend.fill_polygon(ribbon, &color.mix(alpha).filled())?;
+        }
+
+        for (node, rect) in layout.nodes.iter().enumerate() {
+            backend.draw_rect(at(rect[0]), at(rect[1]), &self.node_style(node), true)?;
+        }
+
+        for (node, rect) in layout.nodes.iter().enumerate() {
+            let y = (rect[0].1 + rect[1].1) / 2.0;
+            let (hpos, x) = if layout.last_layer[node] {
+                (HPos::Right, rect[0].0 - 6.0)
+            } else {
+                (HPos::Left, rect[1].0 + 6.0)
+            };
+            let style = self.label_style.pos(Pos::new(hpos, VPos::Center));
+            backend.draw_text(&self.names[node], &style, at((x, y)))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sankey_layout() {
+        let sankey = Sankey::new(
+            (0, 0),
+            (215, 110),
+            vec![
+                ("a", "c", 3.0),
+                ("b", "c", 1.0),
+                ("c", "d", 4.0),
+                ("b", "e", 1.0),
+                ("d", "a", 1.0),
+                ("a", "a", 1.0),
+            ],
+        );
+        // The nodes are a, c, b, d and e. The sink e is moved to the last layer, and the link
+        // from d to a closes a cycle
+        assert_eq!(sankey.layers(), vec![0, 1, 0, 2, 2]);
+        let layout = sankey.layout();
+        assert_eq!(layout.links.len(), 4);
+
+        // The first and the last layers have 5 units and a padding, which set the scale
+        let height = |node: usize| layout.nodes[node][1].1 - layout.nodes[node][0].1;
+        assert!((height(0) - 60.0).abs() < 1e-9);
+        assert!((height(1) - 80.0).abs() < 1e-9);
+        assert!((height(2) - 40.0).abs() < 1e-9);
+        assert_eq!(layout.nodes[3][0].0, 200.0);
+
+        // The link from b to e leaves under the link from b to c, since e is below c
+        let (from_b_to_c, from_b_to_e) = (layout.links[1], layout.links[3]);
+        assert!(from_b_to_c.1 < from_b_to_e.1);
+    }
+
+    #[test]
+    fn test_draw_sankey() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(path.len(), (LINK_STEPS + 1) * 2);
+                assert_eq!(c, RED.mix(0.5).to_rgba());
+                assert_eq!(path[0], (30, 10));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "x");
+                assert_eq!(pos, (36, 100));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "y");
+                assert_eq!(pos, (264, 100));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let sankey = Sankey::new((10, 10), (280, 180), vec![("x", "y", 1.0)])
+            .node_width(20)
+            .colors(&[RED, BLUE])
+            .link_opacity(0.5);
+        drawing_area.draw(&sankey).unwrap();
+    }
+}
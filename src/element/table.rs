@@ -0,0 +1,330 @@
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontStyle, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The height of a row relative to the font size, without the padding
+const LINE_HEIGHT: f64 = 1.25;
+
+/// The element that draws a table of text cells, such as a small summary of statistics next to
+/// a chart.
+///
+/// The table is positioned by its upper left corner, which is a data coordinate when it's drawn
+/// on a chart and a pixel coordinate when it's drawn on a drawing area. To put the table into a
+/// margin of a chart, split the drawing area and draw the table on the sub-area. The width of
+/// a column fits its widest cell, unless it's set with
+/// [Table::column_widths](#method.column_widths).
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::style::text_anchor::HPos;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let (plot, side) = root.split_horizontally(480);
+/// let mut chart = ChartBuilder::on(&plot)
+///     .build_cartesian_2d(0..10, 0.0..10.0)
+///     .unwrap();
+/// let quartiles = Quartiles::new(&[1, 4, 5, 7, 9]);
+/// chart
+///     .draw_series(std::iter::once(Boxplot::new_vertical(5, &quartiles)))
+///     .unwrap();
+/// side.draw(
+///     &Table::new((10, 10), ("sans-serif", 14))
+///         .header(vec!["quartile", "value"])
+///         .row(vec!["Q1", "4"])
+///         .row(vec!["median", "5"])
+///         .row(vec!["Q3", "7"])
+///         .column_align(1, HPos::Right)
+///         .header_background(RGBColor(220, 220, 220).filled()),
+/// )
+/// .unwrap();
+/// ```
+pub struct Table<'a, Coord> {
+    pos: Coord,
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    column_widths: Vec<u32>,
+    column_align: Vec<(usize, HPos)>,
+    padding: u32,
+    style: TextStyle<'a>,
+    header_style: TextStyle<'a>,
+    cell_styles: Vec<((usize, usize), TextStyle<'a>)>,
+    header_background: Option<ShapeStyle>,
+    grid_style: Option<ShapeStyle>,
+}
+
+impl<'a, Coord> Table<'a, Coord> {
+    /// Create a new table without rows
+    /// - `pos`: The upper left corner of the table
+    /// - `style`: The style of the cells, the header is drawn with its bold variant
+    /// - **returns** The newly created table
+    pub fn new<S: Into<TextStyle<'a>>>(pos: Coord, style: S) -> Self {
+        let style = style.into();
+        let header_style = TextStyle {
+            font: style.font.style(FontStyle::Bold),
+            ..style.clone()
+        };
+        Self {
+            pos,
+            header: None,
+            rows: vec![],
+            column_widths: vec![],
+            column_align: vec![],
+            padding: 4,
+            style,
+            header_style,
+            cell_styles: vec![],
+            header_background: None,
+            grid_style: Some(BLACK.into()),
+        }
+    }
+
+    /// Set the header row, which is drawn above the other rows
+    pub fn header<I: IntoIterator>(mut self, cells: I) -> Self
+    where
+        I::Item: ToString,
+    {
+        self.header = Some(cells.into_iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// Append a row to the table
+    pub fn row<I: IntoIterator>(mut self, cells: I) -> Self
+    where
+        I::Item: ToString,
+    {
+        self.rows
+            .push(cells.into_iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// Set the widths of the columns in pixels, the columns without a width fit their widest
+    /// cell
+    pub fn column_widths(mut self, widths: &[u32]) -> Self {
+        self.column_widths = widths.to_vec();
+        self
+    }
+
+    /// Set the horizontal alignment of the cells of a column, which are left aligned by default
+    /// - `column`: The index of the column
+    /// - `align`: The alignment of the cells
+    pub fn column_align(mut self, column: usize, align: HPos) -> Self {
+        self.column_align.push((column, align));
+        self
+    }
+
+    /// Set the space between the text and the grid lines in pixels
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the style of the header
+    pub fn header_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.header_style = style.into();
+        self
+    }
+
+    /// Fill the header row with the given style
+    pub fn header_background<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.header_background = Some(style.into());
+        self
+    }
+
+    /// Set the style of a single cell, which is useful to highlight a value
+    /// - `row`: The index of the row, not counting the header
+    /// - `column`: The index of the column
+    /// - `style`: The style of the text in the cell
+    pub fn cell_style<S: Into<TextStyle<'a>>>(
+        mut self,
+        row: usize,
+        column: usize,
+        style: S,
+    ) -> Self {
+        self.cell_styles.push(((row, column), style.into()));
+        self
+    }
+
+    /// Set the style of the grid lines
+    pub fn grid_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.grid_style = Some(style.into());
+        self
+    }
+
+    /// Do not draw the grid lines
+    pub fn hide_grid(mut self) -> Self {
+        self.grid_style = None;
+        self
+    }
+
+    fn align_of(&self, column: usize) -> HPos {
+        self.column_align
+            .iter()
+            .rev()
+            .find(|c| c.0 == column)
+            .map_or(HPos::Left, |c| c.1)
+    }
+
+    fn style_of(&self, row: Option<usize>, column: usize) -> &TextStyle<'a> {
+        match row {
+            None => &self.header_style,
+            Some(row) => self
+                .cell_styles
+                .iter()
+                .rev()
+                .find(|c| c.0 == (row, column))
+                .map_or(&self.style, |c| &c.1),
+        }
+    }
+
+    /// All the rows with the header first, the index of a row is `None` for the header
+    fn all_rows(&self) -> impl Iterator<Item = (Option<usize>, &Vec<String>)> {
+        self.header
+            .iter()
+            .map(|h| (None, h))
+            .chain(self.rows.iter().enumerate().map(|(i, r)| (Some(i), r)))
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a Table<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for Table<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let padding = self.padding as i32;
+        let num_columns = self.all_rows().map(|r| r.1.len()).max().unwrap_or(0);
+        if num_columns == 0 {
+            return Ok(());
+        }
+
+        let mut widths = vec![0; num_columns];
+        for (idx, width) in widths.iter_mut().enumerate() {
+            if let Some(&w) = self.column_widths.get(idx) {
+                *width = w as i32;
+                continue;
+            }
+            for (row, cells) in self.all_rows() {
+                if let Some(text) = cells.get(idx) {
+                    let size = backend.estimate_text_size(text, self.style_of(row, idx))?;
+                    *width = (*width).max(size.0 as i32 + 2 * padding);
+                }
+            }
+        }
+        let font_size = self
+            .style
+            .font
+            .get_size()
+            .max(self.header_style.font.get_size());
+        let row_height = (font_size * LINE_HEIGHT).round() as i32 + 2 * padding;
+        let num_rows = self.all_rows().count() as i32;
+        let (x1, y1) = (x0 + widths.iter().sum::<i32>(), y0 + row_height * num_rows);
+
+        if let (Some(style), true) = (&self.header_background, self.header.is_some()) {
+            backend.draw_rect((x0, y0), (x1, y0 + row_height), style, true)?;
+        }
+
+        for (row_idx, (row, cells)) in self.all_rows().enumerate() {
+            let top = y0 + row_height * row_idx as i32;
+            let mut left = x0;
+            for (idx, text) in cells.iter().enumerate() {
+                let align = self.align_of(idx);
+                let x = match align {
+                    HPos::Left => left + padding,
+                    HPos::Center => left + widths[idx] / 2,
+                    HPos::Right => left + widths[idx] - padding,
+                };
+                let style = self.style_of(row, idx).pos(Pos::new(align, VPos::Center));
+                backend.draw_text(text, &style, (x, top + row_height / 2))?;
+                left += widths[idx];
+            }
+        }
+
+        if let Some(ref style) = self.grid_style {
+            backend.draw_rect((x0, y0), (x1, y1), style, false)?;
+            for row in 1..num_rows {
+                let y = y0 + row_height * row;
+                backend.draw_line((x0, y), (x1, y), style)?;
+            }
+            let mut x = x0;
+            for width in widths.iter().take(num_columns - 1) {
+                x += width;
+                backend.draw_line((x, y0), (x, y1), style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_draw_table() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(filled);
+                // The header row is 12 * 1.25 = 15 pixels high with the padding of 4
+                assert_eq!((ul, br), ((10, 20), (80, 43)));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "name");
+                assert_eq!(pos, (14, 31));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "value");
+                assert_eq!(pos, (76, 31));
+            });
+            m.check_draw_text(|c, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(pos, (14, 54));
+            });
+            m.check_draw_text(|c, _, _, _, text| {
+                assert_eq!(text, "1");
+                assert_eq!(c, RED.to_rgba());
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert!(!filled);
+                assert_eq!((ul, br), ((10, 20), (80, 89)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_text_call, 5);
+                // Two lines between the rows and one between the columns
+                assert_eq!(b.num_draw_line_call, 3);
+            });
+        });
+        drawing_area
+            .draw(
+                &Table::new((10, 20), ("sans-serif", 12))
+                    .header(vec!["name", "value"])
+                    .row(vec!["a", "1"])
+                    .row(vec!["b"])
+                    .column_widths(&[40, 30])
+                    .column_align(1, HPos::Right)
+                    .cell_style(0, 1, ("sans-serif", 12).into_font().color(&RED))
+                    .header_background(WHITE.filled()),
+            )
+            .unwrap();
+    }
+}
@@ -2,12 +2,14 @@ use std::borrow::Borrow;
 use std::i32;
 
 use super::{Drawable, PointCollection};
-use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontDesc, FontResult, LayoutBox, RichText, ShapeStyle, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// A single line text element. This can be owned or borrowed string, dependents on
-/// `String` or `str` moved into.
-pub struct Text<'a, Coord, T: Borrow<str>> {
+/// `String` or `str` moved into, or a [RichText](../style/struct.RichText.html) with
+/// superscripts, subscripts and styled spans created by [Text::rich](#method.rich).
+pub struct Text<'a, Coord, T> {
     text: T,
     coord: Coord,
     style: TextStyle<'a>,
@@ -28,7 +30,26 @@ impl<'a, Coord, T: Borrow<str>> Text<'a, Coord, T> {
     }
 }
 
-impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord> for &'a Text<'b, Coord, T> {
+impl<'a, Coord> Text<'a, Coord, RichText> {
+    /// Create a new rich text element
+    /// - `text`: The rich text, which is anchored as a whole by the position of the style
+    /// - `points`: The anchor point of the text
+    /// - `style`: The text style that the spans are relative to
+    /// - Return the newly created text element
+    pub fn rich<R: Into<RichText>, S: Into<TextStyle<'a>>>(
+        text: R,
+        points: Coord,
+        style: S,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            coord: points,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'b, 'a, Coord: 'a, T: 'a> PointCollection<'a, Coord> for &'a Text<'b, Coord, T> {
     type Point = &'a Coord;
     type IntoIter = std::iter::Once<&'a Coord>;
     fn point_iter(self) -> Self::IntoIter {
@@ -44,14 +65,29 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            return backend.draw_text(self.text.borrow(), &self.style, a);
+            return self.style.draw_text(backend, self.text.borrow(), a);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for Text<'a, Coord, RichText> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(a) = points.next() {
+            return self.text.draw(backend, &self.style, a);
         }
         Ok(())
     }
 }
 
 /// An multi-line text element. The `Text` element allows only single line text
-/// and the `MultiLineText` supports drawing multiple lines
+/// and the `MultiLineText` supports drawing multiple lines. See [TextBox](struct.TextBox.html)
+/// for the text that is wrapped between words and drawn in a box.
 pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
     lines: Vec<T>,
     coord: Coord,
@@ -240,3 +276,289 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         Ok(())
     }
 }
+
+/// Break the text into lines no wider than `max_width` pixels, at the whitespace between the
+/// words. The explicit line breaks are kept, and a word that is wider than `max_width` is put on
+/// a line of its own.
+fn wrap_words<E, F: FnMut(&str) -> Result<u32, E>>(
+    text: &str,
+    max_width: u32,
+    mut measure: F,
+) -> Result<Vec<String>, E> {
+    let mut lines = vec![];
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if line.is_empty() {
+                line.push_str(word);
+                continue;
+            }
+            let candidate = format!("{} {}", line, word);
+            if max_width > 0 && measure(&candidate)? > max_width {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    Ok(lines)
+}
+
+/// A text box element, which wraps the text to a maximum width and draws it in a box with
+/// padding, an optional border and an optional background.
+///
+/// The box is anchored at a single point, which is a data coordinate when it's drawn on a chart
+/// and a pixel coordinate when it's drawn on a drawing area. The anchor sets which point of the
+/// box is placed there, and the box can be moved from it by a pixel offset.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::style::text_anchor::{HPos, Pos, VPos};
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(std::iter::once(
+///         TextBox::new(
+///             "The sensor was recalibrated here, so the readings after this point are lower",
+///             (5.0, 8.0),
+///             ("sans-serif", 15),
+///         )
+///         .max_width(200)
+///         .align(HPos::Center)
+///         .anchor(Pos::new(HPos::Center, VPos::Bottom))
+///         .background_style(WHITE.filled())
+///         .border_style(&BLACK),
+///     ))
+///     .unwrap();
+/// ```
+pub struct TextBox<'a, Coord> {
+    text: String,
+    coord: Coord,
+    style: TextStyle<'a>,
+    max_width: u32,
+    line_height: f64,
+    align: HPos,
+    anchor: Pos,
+    offset: (i32, i32),
+    padding: u32,
+    border_style: Option<ShapeStyle>,
+    background_style: Option<ShapeStyle>,
+}
+
+impl<'a, Coord> TextBox<'a, Coord> {
+    /// Create a new text box
+    /// - `text`: The text in the box, which may contain line breaks
+    /// - `coord`: The anchor point of the box
+    /// - `style`: The style of the text, its position is ignored
+    /// - **returns** The newly created text box, which is left aligned, anchored at its upper
+    ///   left corner and has no border or background
+    pub fn new<T: ToString, S: Into<TextStyle<'a>>>(text: T, coord: Coord, style: S) -> Self {
+        Self {
+            text: text.to_string(),
+            coord,
+            style: style.into(),
+            max_width: 0,
+            line_height: 1.25,
+            align: HPos::Left,
+            anchor: Pos::new(HPos::Left, VPos::Top),
+            offset: (0, 0),
+            padding: 4,
+            border_style: None,
+            background_style: None,
+        }
+    }
+
+    /// Set the maximum width of the text in pixels, the longer lines are wrapped between the
+    /// words. If 0 is given, which is the default, only the explicit line breaks are kept.
+    pub fn max_width(mut self, width: u32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Set the height of a line relative to the font size
+    pub fn line_height(mut self, value: f64) -> Self {
+        self.line_height = value;
+        self
+    }
+
+    /// Set the horizontal alignment of the lines in the box
+    pub fn align(mut self, align: HPos) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set which point of the box is placed at the anchor point
+    pub fn anchor(mut self, anchor: Pos) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Move the box from the anchor point by the given number of pixels
+    pub fn offset(mut self, dx: i32, dy: i32) -> Self {
+        self.offset = (dx, dy);
+        self
+    }
+
+    /// Set the space between the text and the edges of the box in pixels
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Draw the outline of the box with the given style
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = Some(style.into());
+        self
+    }
+
+    /// Fill the box with the given style before the text is drawn
+    pub fn background_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.background_style = Some(style.into());
+        self
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a TextBox<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for TextBox<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(point) => point,
+            None => return Ok(()),
+        };
+        let lines = wrap_words(&self.text, self.max_width, |text| {
+            backend
+                .estimate_text_size(text, &self.style)
+                .map(|size| size.0)
+        })?;
+        let mut text_width = 0;
+        for line in lines.iter() {
+            text_width = text_width.max(backend.estimate_text_size(line, &self.style)?.0);
+        }
+
+        let font_size = self.style.font.get_size();
+        let line_step = font_size * self.line_height;
+        let text_height = (lines.len() as f64 - 1.0) * line_step + font_size;
+        let padding = self.padding as i32;
+        let width = text_width as i32 + 2 * padding;
+        let height = text_height.round() as i32 + 2 * padding;
+
+        let left = x + self.offset.0
+            - match self.anchor.h_pos {
+                HPos::Left => 0,
+                HPos::Center => width / 2,
+                HPos::Right => width,
+            };
+        let top = y + self.offset.1
+            - match self.anchor.v_pos {
+                VPos::Top => 0,
+                VPos::Center => height / 2,
+                VPos::Bottom => height,
+            };
+        let (ul, br) = ((left, top), (left + width, top + height));
+
+        if let Some(ref style) = self.background_style {
+            backend.draw_rect(ul, br, style, true)?;
+        }
+        if let Some(ref style) = self.border_style {
+            backend.draw_rect(ul, br, style, false)?;
+        }
+
+        let line_x = match self.align {
+            HPos::Left => left + padding,
+            HPos::Center => left + width / 2,
+            HPos::Right => left + width - padding,
+        };
+        let style = self.style.pos(Pos::new(self.align, VPos::Top));
+        for (idx, line) in lines.iter().enumerate() {
+            let line_y = top + padding + (idx as f64 * line_step).round() as i32;
+            backend.draw_text(line, &style, (line_x, line_y))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_wrap_words() {
+        // Every character is 10 pixels wide
+        let measure = |text: &str| Ok::<_, ()>(text.len() as u32 * 10);
+        assert_eq!(
+            wrap_words("the quick brown fox", 100, measure),
+            Ok(vec!["the quick".to_string(), "brown fox".to_string()])
+        );
+        assert_eq!(
+            wrap_words("a verylongword b\nc", 50, measure),
+            Ok(vec![
+                "a".to_string(),
+                "verylongword".to_string(),
+                "b".to_string(),
+                "c".to_string()
+            ])
+        );
+        assert_eq!(
+            wrap_words("no   wrapping", 0, measure),
+            Ok(vec!["no wrapping".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_draw_text_box() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(filled);
+                // The box is centered on the anchor point, two lines of 10 pixels high
+                assert_eq!(br.1 - ul.1, 10 + 12 + 2 * 5);
+                assert!(ul.0 + br.0 - 2 * 100 <= 1);
+            });
+            m.check_draw_rect(|c, _, filled, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert!(!filled);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "first");
+                assert_eq!(pos, (100, 100 - 16 + 5));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "second");
+                assert_eq!(pos, (100, 100 - 16 + 5 + 12));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        drawing_area
+            .draw(
+                &TextBox::new("first\nsecond", (100, 100), ("sans-serif", 10))
+                    .line_height(1.2)
+                    .padding(5)
+                    .align(HPos::Center)
+                    .anchor(Pos::new(HPos::Center, VPos::Center))
+                    .background_style(WHITE.filled())
+                    .border_style(&BLACK),
+            )
+            .unwrap();
+    }
+}
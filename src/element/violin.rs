@@ -0,0 +1,337 @@
+use std::marker::PhantomData;
+
+use crate::data::kde::{weighted_gaussian_density, weighted_silverman_bandwidth};
+use crate::element::boxplot::{BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ShapeStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+const DEFAULT_WIDTH: u32 = 20;
+/// The number of points the density is evaluated at
+const PROFILE_SIZE: usize = 50;
+
+/// Evaluate the kernel density estimation of the weighted samples over the range of the samples.
+/// The densities are scaled, so that the largest one is 1.
+fn density_profile(samples: &[(f64, f64)], bandwidth: Option<f64>) -> Vec<(f64, f64)> {
+    if samples.is_empty() {
+        return vec![];
+    }
+    let min = samples.iter().map(|s| s.0).fold(samples[0].0, f64::min);
+    let max = samples.iter().map(|s| s.0).fold(samples[0].0, f64::max);
+    let bandwidth = bandwidth.unwrap_or_else(|| weighted_silverman_bandwidth(samples));
+    if max == min || bandwidth.is_nan() || bandwidth <= 0.0 {
+        return vec![(min, 1.0)];
+    }
+
+    let mut profile: Vec<_> = (0..PROFILE_SIZE)
+        .map(|idx| {
+            let v = min + (max - min) * idx as f64 / (PROFILE_SIZE - 1) as f64;
+            (v, weighted_gaussian_density(samples, bandwidth, v))
+        })
+        .collect();
+
+    let peak = profile.iter().map(|p| p.1).fold(0.0, f64::max);
+    if peak > 0.0 {
+        for p in profile.iter_mut() {
+            p.1 /= peak;
+        }
+    }
+    profile
+}
+
+/// The violin plot element, which draws the distribution of the samples as a shape that is
+/// mirrored around the key. The shape follows the kernel density estimation of the samples, with
+/// a gaussian kernel, and covers the range from the smallest to the largest sample.
+///
+/// The violin plot shares the orientation with the [Boxplot](struct.Boxplot.html), thus a
+/// narrow boxplot can be drawn on top of a violin with the same key.
+pub struct Violin<K, O: BoxplotOrient<K, f64>> {
+    style: ShapeStyle,
+    width: u32,
+    offset: f64,
+    key: K,
+    samples: Vec<(f64, f64)>,
+    profile: Vec<(f64, f64)>,
+    _p: PhantomData<O>,
+}
+
+impl<K: Clone> Violin<K, BoxplotOrientV<K, f64>> {
+    /// Create a new vertical violin element.
+    ///
+    /// - `key`: The key (the X axis value)
+    /// - `samples`: The samples of the distribution on the Y axis
+    /// - **returns** The newly created violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_vertical("group", &[7, 15, 36, 39, 40, 41]);
+    /// ```
+    pub fn new_vertical<T: Into<f64> + Copy>(key: K, samples: &[T]) -> Self {
+        Self::from_samples(key, samples)
+    }
+
+    /// Create a new vertical violin element from the weighted samples, such as the survey
+    /// weights or the counts of the pre-aggregated values.
+    ///
+    /// - `key`: The key (the X axis value)
+    /// - `samples`: The samples of the distribution on the Y axis and their weights
+    /// - **returns** The newly created violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_vertical_weighted("group", &[(7, 2.0), (15, 10.0), (36, 4.0)]);
+    /// ```
+    pub fn new_vertical_weighted<T: Into<f64> + Copy>(key: K, samples: &[(T, f64)]) -> Self {
+        Self::from_weighted(key, samples.iter().map(|&(v, w)| (v.into(), w)))
+    }
+}
+
+impl<K: Clone> Violin<K, BoxplotOrientH<K, f64>> {
+    /// Create a new horizontal violin element.
+    ///
+    /// - `key`: The key (the Y axis value)
+    /// - `samples`: The samples of the distribution on the X axis
+    /// - **returns** The newly created violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", &[7, 15, 36, 39, 40, 41]);
+    /// ```
+    pub fn new_horizontal<T: Into<f64> + Copy>(key: K, samples: &[T]) -> Self {
+        Self::from_samples(key, samples)
+    }
+
+    /// Create a new horizontal violin element from the weighted samples, such as the survey
+    /// weights or the counts of the pre-aggregated values.
+    ///
+    /// - `key`: The key (the Y axis value)
+    /// - `samples`: The samples of the distribution on the X axis and their weights
+    /// - **returns** The newly created violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal_weighted("group", &[(7, 2.0), (15, 10.0), (36, 4.0)]);
+    /// ```
+    pub fn new_horizontal_weighted<T: Into<f64> + Copy>(key: K, samples: &[(T, f64)]) -> Self {
+        Self::from_weighted(key, samples.iter().map(|&(v, w)| (v.into(), w)))
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f64>> Violin<K, O> {
+    fn from_samples<T: Into<f64> + Copy>(key: K, samples: &[T]) -> Self {
+        Self::from_weighted(key, samples.iter().map(|&v| (v.into(), 1.0)))
+    }
+
+    /// The samples that are not finite or have no positive weight are ignored
+    fn from_weighted<I: Iterator<Item = (f64, f64)>>(key: K, samples: I) -> Self {
+        let samples: Vec<(f64, f64)> = samples
+            .filter(|(v, w)| v.is_finite() && *w > 0.0 && w.is_finite())
+            .collect();
+        Self {
+            style: Into::<ShapeStyle>::into(&BLACK),
+            width: DEFAULT_WIDTH,
+            offset: 0.0,
+            key,
+            profile: density_profile(&samples, None),
+            samples,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the style of the violin, a filled style fills the shape, otherwise only the outline
+    /// is drawn.
+    ///
+    /// - `S`: The required style
+    /// - **returns** The up-to-dated violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", &[7, 15, 36, 39, 40, 41]).style(BLUE.filled());
+    /// ```
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of the violin at the densest point.
+    ///
+    /// - `width`: The required width
+    /// - **returns** The up-to-dated violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", &[7, 15, 36, 39, 40, 41]).width(30);
+    /// ```
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the element offset on the key axis.
+    ///
+    /// - `offset`: The required offset (on the X axis for vertical, on the Y axis for horizontal)
+    /// - **returns** The up-to-dated violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", &[7, 15, 36, 39, 40, 41]).offset(-5);
+    /// ```
+    pub fn offset<T: Into<f64> + Copy>(mut self, offset: T) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Set the bandwidth of the gaussian kernel, by default it's estimated with the Silverman's
+    /// rule of thumb.
+    ///
+    /// - `bandwidth`: The required bandwidth, in the unit of the samples
+    /// - **returns** The up-to-dated violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", &[7, 15, 36, 39, 40, 41]).bandwidth(2.0);
+    /// ```
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.profile = density_profile(&self.samples, Some(bandwidth));
+        self
+    }
+}
+
+impl<'a, K: Clone, O: BoxplotOrient<K, f64>> PointCollection<'a, (O::XType, O::YType)>
+    for &'a Violin<K, O>
+{
+    type Point = (O::XType, O::YType);
+    type IntoIter = Vec<Self::Point>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.profile
+            .iter()
+            .map(|(v, _)| O::make_coord(self.key.clone(), *v))
+            .collect()
+    }
+}
+
+impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f64>> Drawable<DB> for Violin<K, O> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let half_width = f64::from(self.width) / 2.0;
+        let moved = |coord| O::with_offset(coord, self.offset);
+
+        let mut outline = vec![];
+        let mut mirrored = vec![];
+        for (coord, (_, density)) in points.zip(self.profile.iter()) {
+            outline.push(O::with_offset(moved(coord), -half_width * density));
+            mirrored.push(O::with_offset(moved(coord), half_width * density));
+        }
+        if outline.is_empty() {
+            return Ok(());
+        }
+        outline.extend(mirrored.into_iter().rev());
+
+        if self.style.filled {
+            backend.fill_polygon(outline, &self.style)
+        } else {
+            outline.push(outline[0]);
+            backend.draw_path(outline, &self.style)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn unweighted(samples: &[f64]) -> Vec<(f64, f64)> {
+        samples.iter().map(|v| (*v, 1.0)).collect()
+    }
+
+    #[test]
+    fn test_density_profile() {
+        let profile = density_profile(&unweighted(&[1.0, 2.0, 2.0, 2.0, 3.0]), None);
+        assert_eq!(profile.len(), PROFILE_SIZE);
+        assert_eq!(profile[0].0, 1.0);
+        assert_eq!(profile[PROFILE_SIZE - 1].0, 3.0);
+        let peak = profile.iter().map(|p| p.1).fold(0.0, f64::max);
+        assert_eq!(peak, 1.0);
+        // The distribution is symmetric and densest at the center
+        assert!((profile[0].1 - profile[PROFILE_SIZE - 1].1).abs() < 1e-9);
+        assert!(profile[0].1 < profile[PROFILE_SIZE / 2].1);
+
+        assert_eq!(
+            density_profile(&unweighted(&[4.0, 4.0]), None),
+            vec![(4.0, 1.0)]
+        );
+        assert!(density_profile(&[], None).is_empty());
+
+        // The weights are the counts of the repeated samples
+        let weighted = density_profile(&[(1.0, 1.0), (2.0, 3.0), (3.0, 1.0)], Some(0.5));
+        let repeated = density_profile(&unweighted(&[1.0, 2.0, 2.0, 2.0, 3.0]), Some(0.5));
+        for (a, b) in weighted.iter().zip(repeated.iter()) {
+            assert!((a.1 - b.1).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_draw_v() {
+        let root = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path.len(), PROFILE_SIZE * 2);
+                // The shape is mirrored around the key
+                let n = path.len();
+                let double_key = path[0].0 + path[n - 1].0;
+                for i in 0..n / 2 {
+                    assert_eq!(path[i].1, path[n - 1 - i].1);
+                    assert!((path[i].0 + path[n - 1 - i].0 - double_key).abs() <= 1);
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, 0f64..100f64)
+            .unwrap();
+
+        assert!(chart
+            .plotting_area()
+            .draw(&Violin::new_vertical(1, &[10, 20, 20, 30, 60]).style(RED.filled()))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_draw_h() {
+        let root = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), PROFILE_SIZE * 2 + 1);
+                assert_eq!(path[0], path[path.len() - 1]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0f64..100f64, 0..2)
+            .unwrap();
+
+        assert!(chart
+            .plotting_area()
+            .draw(
+                &Violin::new_horizontal(1, &[10.0, 20.0, 25.0, 50.0])
+                    .width(40)
+                    .bandwidth(5.0)
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,330 @@
+/*!
+  The adapters that read the columns of an Arrow [RecordBatch](https://docs.rs/arrow-array),
+  by the column name, as the data of the series.
+
+  Only the Arrow record batches are supported, there's no Polars adapter. A Polars `DataFrame`
+  can be converted to the Arrow record batches first.
+
+  The numeric columns of any integer or floating point type are read as `f64`, and the null
+  values are skipped, or split a line into segments with
+  [segments](struct.ArrowFrame.html#method.segments).
+
+  ```rust
+  use std::sync::Arc;
+
+  use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+  use plotters::interop::dataframe::ArrowFrame;
+  use plotters::prelude::*;
+
+  let batch = RecordBatch::try_from_iter(vec![
+      ("t", Arc::new(Float64Array::from(vec![0.0, 1.0, 2.0, 3.0])) as ArrayRef),
+      (
+          "value",
+          Arc::new(Float64Array::from(vec![Some(1.0), None, Some(3.0), Some(2.0)])) as ArrayRef,
+      ),
+      ("host", Arc::new(StringArray::from(vec!["a", "b", "a", "b"])) as ArrayRef),
+  ])
+  .unwrap();
+  let frame = ArrowFrame::new(&batch);
+
+  let mut content = String::new();
+  let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+  let mut chart = ChartBuilder::on(&root)
+      .build_cartesian_2d(0.0..3.0, 0.0..4.0)
+      .unwrap();
+  chart
+      .draw_series(frame.line_series("t", "value", &RED).unwrap())
+      .unwrap();
+
+  let groups = frame.groups("host", "value").unwrap();
+  assert_eq!(groups[0], ("a".to_string(), vec![1.0, 3.0]));
+  assert_eq!(groups[1], ("b".to_string(), vec![2.0]));
+  ```
+*/
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type, UInt32Type,
+    UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, RecordBatch};
+
+#[cfg(feature = "point_series")]
+use crate::element::PointElement;
+#[cfg(feature = "line_series")]
+use crate::series::LineSeries;
+#[cfg(feature = "point_series")]
+use crate::series::PointSeries;
+#[cfg(any(feature = "line_series", feature = "point_series"))]
+use crate::style::ShapeStyle;
+#[cfg(feature = "line_series")]
+use plotters_backend::DrawingBackend;
+
+/// The scatter series of the points that are read from a record batch
+#[cfg(feature = "point_series")]
+pub type ScatterSeries<E> = PointSeries<'static, (f64, f64), Vec<(f64, f64)>, E, u32>;
+
+/// The error of reading the columns of a record batch
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataFrameError {
+    /// There's no column of the name
+    MissingColumn(String),
+    /// The column of the name can't be read as the values that are required
+    UnsupportedType(String),
+}
+
+impl Display for DataFrameError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            DataFrameError::MissingColumn(name) => write!(fmt, "There's no column {}", name),
+            DataFrameError::UnsupportedType(name) => {
+                write!(fmt, "The type of the column {} isn't supported", name)
+            }
+        }
+    }
+}
+
+impl Error for DataFrameError {}
+
+/// Read the numeric array as `f64`, or `None` if it's not numeric
+fn numeric_values(array: &dyn Array) -> Option<Vec<Option<f64>>> {
+    macro_rules! read_as {
+        ($($ty: ty),*) => {
+            $(
+                if let Some(array) = array.as_primitive_opt::<$ty>() {
+                    return Some(array.iter().map(|v| v.map(|v| v as f64)).collect());
+                }
+            )*
+        };
+    }
+    read_as!(
+        Float64Type,
+        Float32Type,
+        Int8Type,
+        Int16Type,
+        Int32Type,
+        Int64Type,
+        UInt8Type,
+        UInt16Type,
+        UInt32Type,
+        UInt64Type
+    );
+    None
+}
+
+/// Read the array as the keys of the groups, the numeric values are formatted as strings
+fn key_values(array: &dyn Array) -> Option<Vec<Option<String>>> {
+    if let Some(array) = array.as_string_opt::<i32>() {
+        return Some(array.iter().map(|v| v.map(str::to_string)).collect());
+    }
+    if let Some(array) = array.as_string_opt::<i64>() {
+        return Some(array.iter().map(|v| v.map(str::to_string)).collect());
+    }
+    numeric_values(array).map(|values| {
+        values
+            .into_iter()
+            .map(|v| v.map(|v| v.to_string()))
+            .collect()
+    })
+}
+
+/// The view of a record batch that reads its columns by name as the data of the series
+pub struct ArrowFrame<'a> {
+    batch: &'a RecordBatch,
+}
+
+impl<'a> ArrowFrame<'a> {
+    /// Create a view of the record batch
+    pub fn new(batch: &'a RecordBatch) -> Self {
+        Self { batch }
+    }
+
+    fn array(&self, name: &str) -> Result<&'a dyn Array, DataFrameError> {
+        self.batch
+            .column_by_name(name)
+            .map(|column| column.as_ref())
+            .ok_or_else(|| DataFrameError::MissingColumn(name.to_string()))
+    }
+
+    /// Read a numeric column as `f64`, the null values are `None`
+    pub fn column(&self, name: &str) -> Result<Vec<Option<f64>>, DataFrameError> {
+        numeric_values(self.array(name)?)
+            .ok_or_else(|| DataFrameError::UnsupportedType(name.to_string()))
+    }
+
+    /// Read the points of two numeric columns, the rows with a null value are skipped
+    pub fn points(&self, x: &str, y: &str) -> Result<Vec<(f64, f64)>, DataFrameError> {
+        Ok(self.segments(x, y)?.into_iter().flatten().collect())
+    }
+
+    /// Read the points of two numeric columns, which are split into the segments at the rows
+    /// with a null value, thus a line drawn for each segment shows the gaps of the data
+    pub fn segments(&self, x: &str, y: &str) -> Result<Vec<Vec<(f64, f64)>>, DataFrameError> {
+        let mut segments = vec![vec![]];
+        for (x, y) in self.column(x)?.into_iter().zip(self.column(y)?) {
+            match (x, y) {
+                (Some(x), Some(y)) => segments.last_mut().unwrap().push((x, y)),
+                _ => {
+                    if !segments[segments.len() - 1].is_empty() {
+                        segments.push(vec![]);
+                    }
+                }
+            }
+        }
+        segments.retain(|segment| !segment.is_empty());
+        Ok(segments)
+    }
+
+    /// Read the values of a numeric column grouped by the keys of another column, in the order
+    /// that the keys first appear, for example, as the samples of a
+    /// [BoxplotSeries](../../series/struct.BoxplotSeries.html). The rows with a null key or
+    /// value are skipped.
+    pub fn groups(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<Vec<(String, Vec<f64>)>, DataFrameError> {
+        let keys = key_values(self.array(key)?)
+            .ok_or_else(|| DataFrameError::UnsupportedType(key.to_string()))?;
+        // The keys in the order that they first appear, and the values of each key
+        let mut order: Vec<String> = vec![];
+        let mut groups: HashMap<String, Vec<f64>> = HashMap::new();
+        for (key, value) in keys.into_iter().zip(self.column(value)?) {
+            if let (Some(key), Some(value)) = (key, value) {
+                groups
+                    .entry(key)
+                    .or_insert_with_key(|key| {
+                        order.push(key.clone());
+                        vec![]
+                    })
+                    .push(value);
+            }
+        }
+        Ok(order
+            .into_iter()
+            .map(|key| {
+                let values = groups.remove(&key).unwrap_or_default();
+                (key, values)
+            })
+            .collect())
+    }
+
+    /// Make a line series of two numeric columns, the rows with a null value are skipped
+    #[cfg(feature = "line_series")]
+    pub fn line_series<DB: DrawingBackend, S: Into<ShapeStyle>>(
+        &self,
+        x: &str,
+        y: &str,
+        style: S,
+    ) -> Result<LineSeries<DB, (f64, f64)>, DataFrameError> {
+        Ok(LineSeries::new(self.points(x, y)?, style))
+    }
+
+    /// Make a scatter series of two numeric columns, the rows with a null value are skipped
+    #[cfg(feature = "point_series")]
+    pub fn scatter<E: PointElement<(f64, f64), u32>, S: Into<ShapeStyle>>(
+        &self,
+        x: &str,
+        y: &str,
+        size: u32,
+        style: S,
+    ) -> Result<ScatterSeries<E>, DataFrameError> {
+        Ok(PointSeries::new(self.points(x, y)?, size, style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use arrow_array::{ArrayRef, Float32Array, Int64Array, StringArray};
+    use std::sync::Arc;
+
+    fn batch() -> RecordBatch {
+        RecordBatch::try_from_iter(vec![
+            (
+                "x",
+                Arc::new(Int64Array::from(vec![0, 1, 2, 3, 4])) as ArrayRef,
+            ),
+            (
+                "y",
+                Arc::new(Float32Array::from(vec![
+                    Some(1.0),
+                    Some(2.0),
+                    None,
+                    Some(4.0),
+                    Some(5.0),
+                ])) as ArrayRef,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    None,
+                    Some("b"),
+                    Some("a"),
+                    Some("b"),
+                ])) as ArrayRef,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_arrow_frame() {
+        let batch = batch();
+        let frame = ArrowFrame::new(&batch);
+        assert_eq!(
+            frame.points("x", "y").unwrap(),
+            vec![(0.0, 1.0), (1.0, 2.0), (3.0, 4.0), (4.0, 5.0)]
+        );
+        assert_eq!(
+            frame.segments("x", "y").unwrap(),
+            vec![vec![(0.0, 1.0), (1.0, 2.0)], vec![(3.0, 4.0), (4.0, 5.0)]]
+        );
+        assert_eq!(
+            frame.groups("name", "y").unwrap(),
+            vec![
+                ("a".to_string(), vec![1.0, 4.0]),
+                ("b".to_string(), vec![5.0])
+            ]
+        );
+        assert_eq!(
+            frame.groups("x", "y").unwrap()[0],
+            ("0".to_string(), vec![1.0])
+        );
+        assert_eq!(
+            frame.column("missing"),
+            Err(DataFrameError::MissingColumn("missing".to_string()))
+        );
+        assert_eq!(
+            frame.column("name"),
+            Err(DataFrameError::UnsupportedType("name".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_arrow_series() {
+        let batch = batch();
+        let frame = ArrowFrame::new(&batch);
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 4));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 4);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, 0.0..5.0)
+            .unwrap();
+        chart
+            .draw_series(frame.line_series("x", "y", RED).unwrap())
+            .unwrap();
+        chart
+            .draw_series(frame.scatter::<Circle<_, _>, _>("x", "y", 3, RED).unwrap())
+            .unwrap();
+    }
+}
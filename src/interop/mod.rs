@@ -0,0 +1,7 @@
+/*!
+  The adapters that turn the data structures of other crates into the data of the series, so
+  that the data doesn't need to be converted by hand for each chart.
+*/
+
+#[cfg(feature = "arrow")]
+pub mod dataframe;
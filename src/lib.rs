@@ -645,7 +645,11 @@ The following list is a complete list of features that can be opt in and out.
 |---------|--------------|--------|------------|
 | bitmap\_encoder  | Allow `BitMapBackend` save the result to bitmap files | image, rusttype, font-kit | Yes |
 | svg\_backend     | Enable `SVGBackend` Support | None | Yes |
+| svg\_image       | Allow `SVGBackend` to embed raster images, e.g. `ImageElement` and `BitMapElement` | image | No |
 | bitmap\_gif| Opt-in GIF animation Rendering support for `BitMapBackend`, implies `bitmap` enabled | gif | Yes |
+| animated\_backend | The `AnimatedBackend` that appends a frame to an animated GIF or PNG file on each present, implies `bitmap_backend` enabled | gif, png | No |
+| tiled\_bitmap | The `TiledBitMap` that renders a huge bitmap band by band with bounded memory, implies `bitmap_backend` enabled | png | No |
+| vertex\_batch\_backend | The `VertexBatchBackend` that tessellates the drawing into a batch of triangles for a GPU render pass | None | No |
 
 - Font manipulation features
 
@@ -658,6 +662,7 @@ The following list is a complete list of features that can be opt in and out.
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
 | datetime | Eanble the date and time coordinate support | chrono | Yes |
+| time | Enable the date and time coordinates of the `time` crate | time | No |
 
 - Element, series and util functions
 
@@ -665,11 +670,22 @@ The following list is a complete list of features that can be opt in and out.
 |---------|--------------|--------|------------|
 | errorbar | The errorbar element support | None | Yes |
 | candlestick | The candlestick element support | None | Yes |
-| boxplot | The boxplot element support | None | Yes |
+| boxplot | The boxplot and violin element support | None | Yes |
+| gantt | The Gantt bar element support | None | Yes |
 | area\_series | The area series support | None | Yes |
 | line\_series | The line series support | None | Yes |
 | histogram | The histogram series support | None | Yes |
+| heatmap\_series | The heatmap series support | None | Yes |
+| contour\_series | The contour series support | None | Yes |
 | point\_series| The point series support | None | Yes |
+| event\_series | The event (raster) plot series support | None | Yes |
+| radar\_series | The radar chart series support | None | Yes |
+| quiver\_series | The quiver (vector field) series support | None | Yes |
+| ridgeline\_series | The ridgeline (joyplot) series support | None | Yes |
+| rose\_series | The rose (wind rose) chart series support | None | Yes |
+| treemap\_series | The treemap series support | None | Yes |
+| waterfall\_series | The waterfall chart series support | None | Yes |
+| geo\_series | The map feature series support | None | Yes |
 
 - Misc
 
@@ -677,6 +693,14 @@ The following list is a complete list of features that can be opt in and out.
 |---------|--------------|--------|------------|
 | deprecated\_items | This feature allows use of deprecated items which is going to be removed in the future | None | Yes |
 | debug | Enable the code used for debugging | None | No |
+| debug-draw | Print a warning when an element gets fewer key points than it requires | None | No |
+| spec | The serde-serializable chart specification in `plotters::spec`, implies `serialize` | serde | No |
+| serialize | Serialize and deserialize the colors, the shape and text styles, and the palettes with serde | serde | No |
+| parallel | Draw the panels of a bitmap on multiple threads with `draw_panels_parallel` | rayon | No |
+| ndarray | Accept the `ndarray` arrays in `HeatmapSeries`, `SurfaceSeries` and `data::ArrayPoints` | ndarray | No |
+| arrow | Build the series from the columns of the Arrow record batches in `plotters::interop::dataframe` | arrow-array | No |
+| quick | Plot the CSV files in one call with `plotters::quick` | None | No |
+| testing | Compare the rendered charts with the reference images in `plotters::testing` | image | No |
 
 
 ## FAQ List
@@ -735,6 +759,18 @@ pub mod style;
 #[cfg(feature = "evcxr")]
 pub mod evcxr;
 
+#[cfg(feature = "arrow")]
+pub mod interop;
+
+#[cfg(feature = "quick")]
+pub mod quick;
+
+#[cfg(feature = "spec")]
+pub mod spec;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
+pub mod testing;
+
 #[cfg(test)]
 pub use crate::drawing::{check_color, create_mocked_drawing_area};
 
@@ -744,17 +780,27 @@ pub use palette;
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
     // Chart related types
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        animate, render_frames, Aspect, AspectFit, AutoRangeChart, ChartBuilder, ChartContext,
+        Easing, FacetCell, FacetGrid, HitShape, Interpolate, LabelAreaPosition, Overflow,
+        PairDiagonal, PairPlot, ParallelCoordinates, SeriesLabelPosition,
+    };
 
     // Coordinates
     pub use crate::coord::{
         cartesian::Cartesian2d,
         combinators::{
-            make_partial_axis, BindKeyPointMethod, BindKeyPoints, BuildNestedCoord, GroupBy,
-            IntoLinspace, IntoLogRange, IntoPartialAxis, Linspace, LogCoord, LogScalable,
-            NestedRange, NestedValue, ToGroupByRange,
+            make_partial_axis, BindKeyPointMethod, BindKeyPoints, BrokenAxis, BuildNestedCoord,
+            GroupBy, IntoBrokenAxis, IntoLinspace, IntoLogRange, IntoNestedSegmented,
+            IntoPartialAxis, IntoSymLogRange, Linspace, LogCoord, LogScalable, NestedRange,
+            NestedSegmentValue, NestedSegmentedCoord, NestedValue, RangeExt, SymLogCoord,
+            ToGroupByRange,
         },
-        ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue},
+        geo::{GeoCoord, Projection},
+        polar::Polar,
+        ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue, TickLocator},
+        ternary::TernaryCoord,
+        types::Categorical,
         CoordTranslate,
     };
 
@@ -763,52 +809,104 @@ pub mod prelude {
 
     #[cfg(feature = "chrono")]
     pub use crate::coord::types::{
-        IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration,
+        IntoMonthly, IntoYearly, IntoZonedDateTime, RangedDate, RangedDateTime, RangedDuration,
+        TimeBucket, TimeLevel,
     };
 
+    #[cfg(feature = "time")]
+    pub use crate::coord::types::{RangedOffsetDateTime, RangedTimeDate, RangedTimeDuration};
+
     // Re-export the backend for backward compatibility
     pub use plotters_backend::DrawingBackend;
 
     pub use crate::drawing::*;
 
     // Series helpers
+    #[cfg(feature = "boxplot")]
+    pub use crate::series::BoxplotSeries;
+    #[cfg(feature = "contour_series")]
+    pub use crate::series::ContourSeries;
+    #[cfg(feature = "event_series")]
+    pub use crate::series::EventSeries;
     #[cfg(feature = "area_series")]
-    pub use crate::series::AreaSeries;
-    #[cfg(feature = "histogram")]
-    pub use crate::series::Histogram;
-    #[cfg(feature = "line_series")]
-    pub use crate::series::LineSeries;
-    #[cfg(feature = "point_series")]
-    pub use crate::series::PointSeries;
+    pub use crate::series::FillBetween;
+    #[cfg(feature = "heatmap_series")]
+    pub use crate::series::HeatmapSeries;
+    #[cfg(feature = "radar_series")]
+    pub use crate::series::RadarSeries;
+    #[cfg(feature = "ridgeline_series")]
+    pub use crate::series::RidgelineSeries;
+    #[cfg(feature = "rose_series")]
+    pub use crate::series::RoseSeries;
     #[cfg(feature = "surface_series")]
     pub use crate::series::SurfaceSeries;
+    #[cfg(feature = "waterfall_series")]
+    pub use crate::series::WaterfallSeries;
+    #[cfg(feature = "area_series")]
+    pub use crate::series::{
+        AreaSeries, BandSeries, StackedAreaSeries, StreamBaseline, StreamGraphSeries,
+    };
+    #[cfg(feature = "histogram")]
+    pub use crate::series::{
+        BarSeries, BinnedHistogram, Binning, CountScale, Hexbin, Histogram, Histogram2D,
+    };
+    #[cfg(feature = "point_series")]
+    pub use crate::series::{
+        BubbleScale, BubbleSeries, ColorScatterSeries, PointLabelSeries, PointSeries, PointSeries3d,
+    };
+    #[cfg(feature = "line_series")]
+    pub use crate::series::{
+        EcdfSeries, LineSeries, RollingSeries, SplineKind, SplineSeries, StepLineSeries,
+        TrendlineSeries,
+    };
+    #[cfg(any(feature = "line_series", feature = "area_series"))]
+    pub use crate::series::{GapPolicy, StepKind};
+    #[cfg(feature = "geo_series")]
+    pub use crate::series::{GeoSeries, Geometry};
+    #[cfg(feature = "quiver_series")]
+    pub use crate::series::{QuiverScale, QuiverSeries};
+    #[cfg(feature = "treemap_series")]
+    pub use crate::series::{TreemapNode, TreemapSeries};
 
     // Styles
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        IntoTextStyle, Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBColor,
-        ShapeStyle, TextStyle,
+        AsRelative, BlackWhite, Color, ColorCycle, ColorMap, DerivedColorMap, FontDesc, FontFamily,
+        FontStyle, FontTransform, Gradient, HSLColor, IntoFont, IntoTextStyle, LineCap, LineJoin,
+        LinearColorMap, MagmaRGB, Normalizer, Palette, Palette100, Palette99, Palette9999,
+        PaletteCategory10, PaletteCategory20, PaletteColor, PaletteColorblind, Pattern,
+        PatternKind, PlasmaRGB, QuantizedColorMap, RGBColor, RichText, ShapeStyle, TextStyle,
+        Theme, TurboRGB, ViridisRGB,
     };
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
 
     // Elements
     pub use crate::element::{
-        Circle, Cross, Cubiod, DynElement, EmptyElement, IntoDynElement, MultiLineText,
-        PathElement, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        Arrow, ArrowHead, Circle, Colorbar, Cross, Crosshair, Cubiod, Dendrogram,
+        DendrogramOrientation, DynElement, ElementExt, EmptyElement, FillRule, Funnel, Group,
+        HLine, HSpan, IntoDynElement, Marker, MarkerShape, MultiLineText, PathElement, Pie, Pixel,
+        PolyPath, Polygon, Rectangle, Sankey, Table, Text, TextBox, TriangleMarker, VLine, VSpan,
     };
 
-    #[cfg(feature = "boxplot")]
-    pub use crate::element::Boxplot;
     #[cfg(feature = "candlestick")]
-    pub use crate::element::CandleStick;
+    pub use crate::chart::PriceVolumePanels;
     #[cfg(feature = "errorbar")]
     pub use crate::element::ErrorBar;
+    #[cfg(feature = "gantt")]
+    pub use crate::element::GanttBar;
+    #[cfg(feature = "boxplot")]
+    pub use crate::element::{Boxplot, Violin};
+    #[cfg(feature = "candlestick")]
+    pub use crate::element::{CandleStick, OHLCBar};
 
     #[cfg(feature = "bitmap_backend")]
     pub use crate::element::BitMapElement;
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub use crate::element::ImageElement;
 
     // Data
-    pub use crate::data::Quartiles;
+    pub use crate::data::{
+        Downsample, MovingAverage, Quartiles, SavitzkyGolay, TrendKind, TrendLine,
+    };
 
     // TODO: This should be deprecated and completely removed
     #[cfg(feature = "deprecated_items")]
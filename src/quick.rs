@@ -0,0 +1,436 @@
+/*!
+  The helpers that plot the data in one call, with the ranges, the axes and the legend set up
+  from the data, for a quick look at the data before a chart is crafted.
+
+  ```rust,no_run
+  use plotters::quick;
+
+  // The first column of the CSV file is the header, the "time" column can be either numeric or
+  // the dates and times, such as `2020-01-01` or `2020-01-01 12:00:00`
+  quick::line_chart_from_csv("data.csv", "time", &["cpu", "memory"], "usage.svg").unwrap();
+  ```
+*/
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::chart::ChartBuilder;
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+#[cfg(feature = "chrono")]
+use crate::coord::types::RangedDateTime;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, IntoDrawingArea};
+use crate::element::PathElement;
+use crate::series::LineSeries;
+use crate::style::{Color, Palette, Palette99, BLACK, WHITE};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime};
+use plotters_backend::DrawingBackend;
+#[cfg(all(feature = "bitmap_backend", feature = "bitmap_encoder"))]
+use plotters_bitmap::BitMapBackend;
+use plotters_svg::SVGBackend;
+
+/// The size of the image that the quick charts are rendered to
+pub const QUICK_CHART_SIZE: (u32, u32) = (1024, 768);
+
+/// The error of making a quick chart
+#[derive(Debug)]
+pub enum QuickPlotError {
+    /// The input can't be read, or the output can't be written
+    Io(io::Error),
+    /// There's no column of the name in the header
+    MissingColumn(String),
+    /// The values of the column can't be read as the numbers, or the dates and times for the X
+    /// column
+    UnsupportedColumn(String),
+    /// There's no row that has the values of the X column and a Y column
+    NoData,
+    /// The extension of the output file isn't supported
+    UnsupportedOutput(String),
+    /// The chart can't be drawn
+    Drawing(String),
+}
+
+impl Display for QuickPlotError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            QuickPlotError::Io(e) => write!(fmt, "I/O error: {}", e),
+            QuickPlotError::MissingColumn(name) => write!(fmt, "There's no column {}", name),
+            QuickPlotError::UnsupportedColumn(name) => {
+                write!(fmt, "The values of the column {} aren't supported", name)
+            }
+            QuickPlotError::NoData => write!(fmt, "There's no data to plot"),
+            QuickPlotError::UnsupportedOutput(path) => {
+                write!(fmt, "The output format of {} isn't supported", path)
+            }
+            QuickPlotError::Drawing(e) => write!(fmt, "Drawing error: {}", e),
+        }
+    }
+}
+
+impl Error for QuickPlotError {}
+
+impl From<io::Error> for QuickPlotError {
+    fn from(e: io::Error) -> Self {
+        QuickPlotError::Io(e)
+    }
+}
+
+/// Split a CSV record into the fields, the quoted fields may have the commas and the escaped
+/// quotes (`""`) in them
+fn split_record(line: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                fields.push(field.clone());
+                field.clear();
+            }
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields.into_iter().map(|f| f.trim().to_string()).collect()
+}
+
+/// The CSV table, which is the header and the records
+struct Table {
+    header: Vec<String>,
+    records: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn parse(text: &str) -> Self {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+        let header = lines.next().map(split_record).unwrap_or_default();
+        let records = lines.map(split_record).collect();
+        Self { header, records }
+    }
+
+    /// Get the fields of the column, the empty fields are `None`
+    fn column(&self, name: &str) -> Result<Vec<Option<&str>>, QuickPlotError> {
+        let idx = self
+            .header
+            .iter()
+            .position(|h| h == name)
+            .ok_or_else(|| QuickPlotError::MissingColumn(name.to_string()))?;
+        Ok(self
+            .records
+            .iter()
+            .map(|r| r.get(idx).map(String::as_str).filter(|v| !v.is_empty()))
+            .collect())
+    }
+}
+
+/// Read all the fields of a column by the parser, or `None` if any of the fields can't be read
+fn parse_column<T, F: Fn(&str) -> Option<T>>(
+    fields: &[Option<&str>],
+    parse: F,
+) -> Option<Vec<Option<T>>> {
+    fields
+        .iter()
+        .map(|v| match v {
+            Some(v) => parse(v).map(Some),
+            None => Some(None),
+        })
+        .collect()
+}
+
+#[cfg(feature = "chrono")]
+fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.naive_utc());
+    }
+    for format in &["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(dt);
+        }
+    }
+    for format in &["%Y-%m-%d", "%Y/%m/%d"] {
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            return date.and_hms_opt(0, 0, 0);
+        }
+    }
+    None
+}
+
+/// The range that covers all the values, with the padding of 5% on both sides
+fn padded_range<I: IntoIterator<Item = f64>>(values: I) -> Option<(f64, f64)> {
+    let (min, max) = values
+        .into_iter()
+        .fold(None, |range: Option<(f64, f64)>, v| match range {
+            Some((min, max)) => Some((min.min(v), max.max(v))),
+            None => Some((v, v)),
+        })?;
+    let padding = if max > min { (max - min) * 0.05 } else { 1.0 };
+    Some((min - padding, max + padding))
+}
+
+/// The lines of the Y columns over the X values, the rows without the values are skipped
+type Lines<X> = Vec<(String, Vec<(X, f64)>)>;
+
+fn collect_lines<X: Clone>(
+    table: &Table,
+    xs: &[Option<X>],
+    y_cols: &[&str],
+) -> Result<Lines<X>, QuickPlotError> {
+    let mut lines = vec![];
+    for name in y_cols {
+        let ys = parse_column(&table.column(name)?, |v| v.parse::<f64>().ok())
+            .ok_or_else(|| QuickPlotError::UnsupportedColumn(name.to_string()))?;
+        let points: Vec<_> = xs
+            .iter()
+            .zip(ys)
+            .filter_map(|(x, y)| Some((x.clone()?, y.filter(|y| y.is_finite())?)))
+            .collect();
+        lines.push((name.to_string(), points));
+    }
+    if lines.iter().all(|(_, points)| points.is_empty()) {
+        return Err(QuickPlotError::NoData);
+    }
+    Ok(lines)
+}
+
+fn y_range<X>(lines: &[(String, Vec<(X, f64)>)]) -> (f64, f64) {
+    padded_range(lines.iter().flat_map(|(_, p)| p.iter().map(|p| p.1))).unwrap_or((0.0, 1.0))
+}
+
+fn draw_lines<DB: DrawingBackend, XR>(
+    area: &DrawingArea<DB, Shift>,
+    x_range: XR,
+    y_range: (f64, f64),
+    x_desc: &str,
+    lines: Lines<XR::ValueType>,
+) -> Result<(), QuickPlotError>
+where
+    XR: Ranged + ValueFormatter<<XR as Ranged>::ValueType>,
+    XR::ValueType: Clone + 'static,
+{
+    let to_error = |e: crate::drawing::DrawingAreaErrorKind<DB::ErrorType>| {
+        QuickPlotError::Drawing(e.to_string())
+    };
+    area.fill(&WHITE).map_err(to_error)?;
+
+    let mut chart = ChartBuilder::on(area)
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(60)
+        .build_cartesian_2d(x_range, y_range.0..y_range.1)
+        .map_err(to_error)?;
+
+    let mut mesh = chart.configure_mesh();
+    mesh.x_desc(x_desc).light_line_style(BLACK.mix(0.05));
+    if lines.len() == 1 {
+        mesh.y_desc(lines[0].0.as_str());
+    }
+    mesh.draw().map_err(to_error)?;
+
+    let legend = lines.len() > 1;
+    for (idx, (name, points)) in lines.into_iter().enumerate() {
+        let style = Palette99::pick(idx).stroke_width(2);
+        let anno = chart
+            .draw_series(LineSeries::new(points, style.clone()))
+            .map_err(to_error)?;
+        if legend {
+            anno.label(name)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style.clone()));
+        }
+    }
+
+    if legend {
+        chart
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()
+            .map_err(to_error)?;
+    }
+
+    area.present().map_err(to_error)
+}
+
+/// Draw the line chart of the CSV text on the drawing area, which is what
+/// [line_chart_from_csv](fn.line_chart_from_csv.html) draws on the output file.
+/// - `csv`: The CSV text, the first line is the header
+/// - `x_col`: The name of the X column, which is either numeric or the dates and times
+/// - `y_cols`: The names of the numeric Y columns, a line is drawn for each
+/// - `area`: The drawing area to draw on
+pub fn draw_csv_line_chart<DB: DrawingBackend>(
+    csv: &str,
+    x_col: &str,
+    y_cols: &[&str],
+    area: &DrawingArea<DB, Shift>,
+) -> Result<(), QuickPlotError> {
+    let table = Table::parse(csv);
+    let x_fields = table.column(x_col)?;
+
+    if let Some(xs) = parse_column(&x_fields, |v| {
+        v.parse::<f64>().ok().filter(|v| v.is_finite())
+    }) {
+        let lines = collect_lines(&table, &xs, y_cols)?;
+        let x_range = padded_range(lines.iter().flat_map(|(_, p)| p.iter().map(|p| p.0)))
+            .ok_or(QuickPlotError::NoData)?;
+        return draw_lines(
+            area,
+            RangedCoordf64::from(x_range.0..x_range.1),
+            y_range(&lines),
+            x_col,
+            lines,
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    {
+        if let Some(xs) = parse_column(&x_fields, parse_datetime) {
+            let lines = collect_lines(&table, &xs, y_cols)?;
+            let mut times = lines.iter().flat_map(|(_, p)| p.iter().map(|p| p.0));
+            let first = times.next().ok_or(QuickPlotError::NoData)?;
+            let (mut start, mut end) =
+                times.fold((first, first), |(min, max), t| (min.min(t), max.max(t)));
+            if start == end {
+                start -= Duration::days(1);
+                end += Duration::days(1);
+            }
+            return draw_lines(
+                area,
+                RangedDateTime::from(start..end),
+                y_range(&lines),
+                x_col,
+                lines,
+            );
+        }
+    }
+
+    Err(QuickPlotError::UnsupportedColumn(x_col.to_string()))
+}
+
+/// Read a CSV file and render the line chart of the columns in one call. The X column can be
+/// numeric, or the dates and times (`2020-01-01`, `2020-01-01 12:00:00` or RFC 3339) when the
+/// `chrono` feature is enabled. The ranges cover all the data, and a legend is drawn when there
+/// are multiple Y columns.
+/// - `path`: The path to the CSV file, the first line is the header
+/// - `x_col`: The name of the X column
+/// - `y_cols`: The names of the numeric Y columns, a line is drawn for each
+/// - `output`: The path to the output image, which is an SVG file, or a PNG file when the
+///   `bitmap_encoder` feature is enabled
+pub fn line_chart_from_csv<P: AsRef<Path>, Q: AsRef<Path>>(
+    path: P,
+    x_col: &str,
+    y_cols: &[&str],
+    output: Q,
+) -> Result<(), QuickPlotError> {
+    let csv = fs::read_to_string(path)?;
+    let output = output.as_ref();
+    let extension = output
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "svg" => {
+            let root = SVGBackend::new(output, QUICK_CHART_SIZE).into_drawing_area();
+            draw_csv_line_chart(&csv, x_col, y_cols, &root)
+        }
+        #[cfg(all(feature = "bitmap_backend", feature = "bitmap_encoder"))]
+        "png" | "jpg" | "jpeg" | "bmp" => {
+            let root = BitMapBackend::new(output, QUICK_CHART_SIZE).into_drawing_area();
+            draw_csv_line_chart(&csv, x_col, y_cols, &root)
+        }
+        _ => Err(QuickPlotError::UnsupportedOutput(
+            output.display().to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_split_record() {
+        assert_eq!(split_record("a, b,c"), vec!["a", "b", "c"]);
+        assert_eq!(
+            split_record(r#""x, y",1,"say ""hi""""#),
+            vec!["x, y", "1", r#"say "hi""#]
+        );
+        assert_eq!(split_record("1,,3"), vec!["1", "", "3"]);
+    }
+
+    #[test]
+    fn test_csv_line_chart() {
+        let csv = "x,a,b,name\n0,1,2,p\n1,,3,q\n2,5,4,r\n";
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_path(|c, _, path| {
+                if c == Palette99::pick(0).to_rgba() {
+                    assert_eq!(path.len(), 2);
+                }
+            });
+            m.drop_check(|b| assert!(b.num_draw_path_call >= 2));
+        });
+        draw_csv_line_chart(csv, "x", &["a", "b"], &drawing_area).unwrap();
+
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        let error = |csv, y| {
+            draw_csv_line_chart(csv, "x", &[y], &drawing_area)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(error(csv, "c"), "There's no column c");
+        assert_eq!(
+            error(csv, "name"),
+            "The values of the column name aren't supported"
+        );
+        assert_eq!(error("x,a\n", "a"), "There's no data to plot");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_csv_datetime_column() {
+        assert_eq!(
+            parse_datetime("2020-01-02"),
+            NaiveDate::from_ymd_opt(2020, 1, 2).and_then(|d| d.and_hms_opt(0, 0, 0))
+        );
+        assert_eq!(
+            parse_datetime("2020-01-02T03:04:05Z"),
+            NaiveDate::from_ymd_opt(2020, 1, 2).and_then(|d| d.and_hms_opt(3, 4, 5))
+        );
+        assert_eq!(parse_datetime("yesterday"), None);
+
+        let csv = "day,value\n2020-01-01,1\n2020-01-02 12:00:00,3\n2020-01-04,2\n";
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.drop_check(|b| assert!(b.num_draw_path_call >= 1));
+        });
+        draw_csv_line_chart(csv, "day", &["value"], &drawing_area).unwrap();
+    }
+
+    #[test]
+    fn test_line_chart_from_csv() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("plotters_quick_test.csv");
+        let output = dir.join("plotters_quick_test.svg");
+        fs::write(&input, "x,y\n0,0\n1,1\n2,4\n").unwrap();
+        line_chart_from_csv(&input, "x", &["y"], &output).unwrap();
+        assert!(fs::read_to_string(&output).unwrap().contains("<svg"));
+        let result = line_chart_from_csv(&input, "x", &["y"], dir.join("a.txt"));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("The output format"));
+        fs::remove_file(input).ok();
+        fs::remove_file(output).ok();
+    }
+}
@@ -1,15 +1,19 @@
+use super::gap::{mark_missing, split_segments, GapPolicy, MissingValue};
+use super::step_line::{step_points, StepKind};
 use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
 use crate::style::colors::TRANSPARENT;
-use crate::style::ShapeStyle;
+use crate::style::{Fill, Gradient, Pattern, ShapeStyle};
+use num_traits::{NumCast, ToPrimitive};
 use plotters_backend::DrawingBackend;
 
 /// An area series is similar to a line series but use a filled polygon
 pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
     area_style: ShapeStyle,
+    fill: Option<Fill>,
     border_style: ShapeStyle,
     baseline: Y,
-    data: Vec<(X, Y)>,
-    state: u32,
+    segments: Vec<Vec<(X, Y)>>,
+    state: usize,
     _p: std::marker::PhantomData<DB>,
 }
 
@@ -21,8 +25,9 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
     ) -> Self {
         Self {
             area_style: area_style.into(),
+            fill: None,
             baseline,
-            data: iter.into_iter().collect(),
+            segments: vec![iter.into_iter().collect()],
             state: 0,
             border_style: (&TRANSPARENT).into(),
             _p: std::marker::PhantomData,
@@ -33,26 +38,73 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
         self.border_style = style.into();
         self
     }
+
+    /// Fill the area with a gradient instead of the color of the area style, the gradient is
+    /// relative to the bounding box of the area
+    pub fn fill_gradient(mut self, gradient: Gradient) -> Self {
+        self.fill = Some(Fill::Gradient(gradient));
+        self
+    }
+
+    /// Fill the area with a pattern instead of the color of the area style
+    pub fn fill_pattern(mut self, pattern: Pattern) -> Self {
+        self.fill = Some(Fill::Pattern(pattern));
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + ToPrimitive + NumCast, Y: Clone> AreaSeries<DB, X, Y> {
+    /// Fill the area under a staircase instead of the straight lines between the data points,
+    /// the same way as [StepLineSeries](struct.StepLineSeries.html) draws the line
+    /// - `kind`: Where the value changes between two points
+    pub fn step(mut self, kind: StepKind) -> Self {
+        for segment in self.segments.iter_mut() {
+            *segment = step_points(segment, kind);
+        }
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y>
+where
+    (X, Y): MissingValue,
+{
+    /// Set what the area does at the points with a NaN coordinate, the area is split into the
+    /// separate areas at the gaps, see [GapPolicy](enum.GapPolicy.html)
+    pub fn gap_policy(mut self, policy: GapPolicy) -> Self {
+        let mut segments = vec![];
+        std::mem::swap(&mut self.segments, &mut segments);
+        let data = segments.into_iter().flatten().map(Some).collect();
+        self.segments = split_segments(mark_missing(data, policy), policy);
+        self
+    }
 }
 
 impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for AreaSeries<DB, X, Y> {
     type Item = DynElement<'static, DB, (X, Y)>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.state == 0 {
-            let mut data: Vec<_> = self.data.clone();
+        // All the areas are filled first, then their borders are drawn
+        let count = self.segments.len();
+        if self.state < count {
+            let mut data: Vec<_> = self.segments[self.state].clone();
 
             if !data.is_empty() {
                 data.push((data[data.len() - 1].0.clone(), self.baseline.clone()));
                 data.push((data[0].0.clone(), self.baseline.clone()));
             }
 
-            self.state = 1;
+            self.state += 1;
 
-            Some(Polygon::new(data, self.area_style.clone()).into_dyn())
-        } else if self.state == 1 {
-            let data: Vec<_> = self.data.clone();
+            let polygon = Polygon::new(data, self.area_style.clone());
+            Some(match self.fill.clone() {
+                Some(Fill::Gradient(gradient)) => polygon.fill_gradient(gradient).into_dyn(),
+                Some(Fill::Pattern(pattern)) => polygon.fill_pattern(pattern).into_dyn(),
+                None => polygon.into_dyn(),
+            })
+        } else if self.state < count * 2 {
+            let data: Vec<_> = self.segments[self.state - count].clone();
 
-            self.state = 2;
+            self.state += 1;
 
             Some(PathElement::new(data, self.border_style.clone()).into_dyn())
         } else {
@@ -0,0 +1,329 @@
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use super::FillBetween;
+use crate::element::{
+    DynElement, IntoDynElement, PathElement, PointCollection, Polygon, Rectangle,
+};
+use crate::style::ShapeStyle;
+use num_traits::{NumCast, ToPrimitive};
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The rectangle that the band is clipped to, as (x0, y0, x1, y1)
+type ClipRect = (f64, f64, f64, f64);
+
+/// Clip a polygon to the rectangle with the Sutherland-Hodgman algorithm
+fn clip_polygon(points: Vec<(f64, f64)>, (x0, y0, x1, y1): ClipRect) -> Vec<(f64, f64)> {
+    // Each edge of the rectangle is the axis (0 = X, 1 = Y), the bound, and if the inside is
+    // below the bound
+    let edges = [(0, x0, false), (0, x1, true), (1, y0, false), (1, y1, true)];
+    let coord = |p: &(f64, f64), axis: usize| if axis == 0 { p.0 } else { p.1 };
+
+    edges.iter().fold(points, |points, &(axis, bound, below)| {
+        let inside = |p: &(f64, f64)| (coord(p, axis) <= bound) == below;
+        let intersect = |a: &(f64, f64), b: &(f64, f64)| {
+            let t = (bound - coord(a, axis)) / (coord(b, axis) - coord(a, axis));
+            (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+        };
+        let mut result = vec![];
+        for (idx, b) in points.iter().enumerate() {
+            let a = &points[(idx + points.len() - 1) % points.len()];
+            match (inside(a), inside(b)) {
+                (true, true) => result.push(*b),
+                (true, false) => result.push(intersect(a, b)),
+                (false, true) => {
+                    result.push(intersect(a, b));
+                    result.push(*b);
+                }
+                (false, false) => {}
+            }
+        }
+        result
+    })
+}
+
+/// Clip a line segment to the rectangle with the Liang-Barsky algorithm
+fn clip_segment(
+    a: (f64, f64),
+    b: (f64, f64),
+    (x0, y0, x1, y1): ClipRect,
+) -> Option<((f64, f64), (f64, f64))> {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let (mut t0, mut t1) = (0.0, 1.0);
+    for &(p, q) in [
+        (-dx, a.0 - x0),
+        (dx, x1 - a.0),
+        (-dy, a.1 - y0),
+        (dy, y1 - a.1),
+    ]
+    .iter()
+    {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = (q / p).max(t0);
+        } else {
+            t1 = (q / p).min(t1);
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    let at = |t: f64| (a.0 + dx * t, a.1 + dy * t);
+    Some((at(t0), at(t1)))
+}
+
+/// Clip a polyline to the rectangle, which may split it into several polylines
+fn clip_polyline(points: &[(f64, f64)], rect: ClipRect) -> Vec<Vec<(f64, f64)>> {
+    let mut lines: Vec<Vec<(f64, f64)>> = vec![];
+    let mut connected = false;
+    for pair in points.windows(2) {
+        match clip_segment(pair[0], pair[1], rect) {
+            Some((from, to)) => {
+                if !connected || from != pair[0] {
+                    lines.push(vec![from]);
+                }
+                lines.last_mut().unwrap().push(to);
+                connected = to == pair[1];
+            }
+            None => connected = false,
+        }
+    }
+    lines
+}
+
+/// The series that draws a confidence band: a filled area between a lower and an upper curve,
+/// with optional lines along both edges.
+///
+/// Since the drawing area squeezes the points out of the plotting area onto its border, a band
+/// that leaves the axis ranges is distorted. Use [BandSeries::clip](#method.clip) with the axis
+/// ranges to cut the band at the border of the plotting area instead.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data: Vec<_> = (0..=100)
+///     .map(|x| {
+///         let x = x as f64 / 10.0;
+///         (x, x.sin() - 0.3, x.sin() + 0.3)
+///     })
+///     .collect();
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, -1.0..1.0)
+///     .unwrap();
+/// let band = BandSeries::new(data.iter().cloned(), &BLUE.mix(0.2))
+///     .edge_style(&BLUE)
+///     .clip(chart.x_range(), chart.y_range());
+/// let legend = band.legend_glyph();
+/// chart.draw_series(band).unwrap().label("sin(x) ± 0.3").legend(legend);
+/// chart
+///     .draw_series(LineSeries::new(data.iter().map(|&(x, lo, hi)| (x, (lo + hi) / 2.0)), &BLUE))
+///     .unwrap();
+/// ```
+pub struct BandSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
+    data: Vec<(X, Y, Y)>,
+    style: ShapeStyle,
+    edge_style: Option<ShapeStyle>,
+    clip: Option<ClipRect>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (X, Y)>>>,
+    _p: PhantomData<DB>,
+}
+
+impl<DB, X, Y> BandSeries<DB, X, Y>
+where
+    DB: DrawingBackend,
+    X: Clone + ToPrimitive + NumCast + 'static,
+    Y: Clone + ToPrimitive + NumCast + 'static,
+{
+    /// Create a new band series
+    /// - `iter`: The iterator over the X values with the lower and upper values of the band,
+    ///   the band is split at NaN values
+    /// - `style`: The style of the filled area
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y, Y)>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+            style: style.into().filled(),
+            edge_style: None,
+            clip: None,
+            elements: None,
+            _p: PhantomData,
+        }
+    }
+
+    /// Draw lines along the lower and the upper edges of the band
+    pub fn edge_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.edge_style = Some(style.into());
+        self
+    }
+
+    /// Clip the band to the given ranges, which are usually the axis ranges of the chart
+    pub fn clip(mut self, x_range: Range<X>, y_range: Range<Y>) -> Self {
+        let values = (
+            x_range.start.to_f64(),
+            y_range.start.to_f64(),
+            x_range.end.to_f64(),
+            y_range.end.to_f64(),
+        );
+        if let (Some(x0), Some(y0), Some(x1), Some(y1)) = values {
+            self.clip = Some((x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)));
+        }
+        self
+    }
+
+    /// Make the legend glyph of this series, which is a filled rectangle in the series style.
+    /// The result can be passed to [SeriesAnno::legend](../chart/struct.SeriesAnno.html#method.legend)
+    pub fn legend_glyph(&self) -> impl Fn(BackendCoord) -> Rectangle<BackendCoord> {
+        let style = self.style.clone();
+        move |(x, y)| Rectangle::new([(x - 10, y - 5), (x, y + 5)], style.clone())
+    }
+
+    /// Clip the points and convert them back to the data types
+    fn clipped<F: Fn(Vec<(f64, f64)>, ClipRect) -> Vec<Vec<(f64, f64)>>>(
+        &self,
+        points: &[(X, Y)],
+        clip_fn: F,
+    ) -> Vec<Vec<(X, Y)>> {
+        let rect = match self.clip {
+            Some(rect) => rect,
+            None => return vec![points.to_vec()],
+        };
+        let points: Option<Vec<_>> = points
+            .iter()
+            .map(|(x, y)| Some((x.to_f64()?, y.to_f64()?)))
+            .collect();
+        clip_fn(points.unwrap_or_default(), rect)
+            .into_iter()
+            .map(|part| {
+                part.into_iter()
+                    .filter_map(|(x, y)| Some((NumCast::from(x)?, NumCast::from(y)?)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn build_elements(&self) -> Vec<DynElement<'static, DB, (X, Y)>> {
+        let mut elements = vec![];
+
+        let fill = FillBetween::new(
+            self.data.iter().map(|p| p.0.clone()),
+            self.data.iter().map(|p| p.1.clone()),
+            self.data.iter().map(|p| p.2.clone()),
+            self.style.clone(),
+        );
+        for polygon in fill {
+            let points: Vec<_> = (&polygon).point_iter().to_vec();
+            for part in self.clipped(&points, |p, rect| vec![clip_polygon(p, rect)]) {
+                if part.len() >= 3 {
+                    elements.push(Polygon::new(part, self.style.clone()).into_dyn());
+                }
+            }
+        }
+
+        if let Some(ref edge_style) = self.edge_style {
+            // The edges are split where the band is, so they end with the filled area
+            let is_nan = |v: Option<f64>| v.filter(|v| !v.is_nan()).is_none();
+            let sections = self.data.split(|(x, lo, hi)| {
+                is_nan(x.to_f64()) || is_nan(lo.to_f64()) || is_nan(hi.to_f64())
+            });
+            for section in sections {
+                let lower: Vec<_> = section.iter().map(|p| (p.0.clone(), p.1.clone())).collect();
+                let upper: Vec<_> = section.iter().map(|p| (p.0.clone(), p.2.clone())).collect();
+                for edge in [lower, upper].iter() {
+                    for part in self.clipped(edge, |p, rect| clip_polyline(&p, rect)) {
+                        if part.len() >= 2 {
+                            elements.push(PathElement::new(part, edge_style.clone()).into_dyn());
+                        }
+                    }
+                }
+            }
+        }
+
+        elements
+    }
+}
+
+impl<DB, X, Y> Iterator for BandSeries<DB, X, Y>
+where
+    DB: DrawingBackend,
+    X: Clone + ToPrimitive + NumCast + 'static,
+    Y: Clone + ToPrimitive + NumCast + 'static,
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(Iterator::next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_clip() {
+        let rect = (0.0, 0.0, 2.0, 2.0);
+        let square = vec![(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+        let clipped = clip_polygon(square, rect);
+        assert_eq!(clipped.len(), 4);
+        assert!(clipped.contains(&(0.0, 0.0)) && clipped.contains(&(1.0, 1.0)));
+
+        assert_eq!(
+            clip_segment((-1.0, 1.0), (3.0, 1.0), rect),
+            Some(((0.0, 1.0), (2.0, 1.0)))
+        );
+        assert_eq!(clip_segment((-1.0, 3.0), (3.0, 3.0), rect), None);
+
+        // The line leaves the rectangle and comes back
+        let lines = clip_polyline(&[(0.0, 1.0), (1.0, 3.0), (2.0, 1.0)], rect);
+        assert_eq!(
+            lines,
+            vec![vec![(0.0, 1.0), (0.5, 2.0)], vec![(1.5, 2.0), (2.0, 1.0)]]
+        );
+    }
+
+    #[test]
+    fn test_band_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.mix(0.5).to_rgba());
+                assert!(path.iter().all(|&(_, y)| y >= 0));
+            });
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, BLUE.to_rgba());
+            });
+            m.drop_check(|b| {
+                // The band is split by the NaN value
+                assert_eq!(b.num_fill_polygon_call, 2);
+                // The upper edge leaves the plotting area in the first part
+                assert_eq!(b.num_draw_path_call, 5);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..2.0)
+            .unwrap();
+        let data = vec![
+            (0.0, 0.5, 1.5),
+            (1.0, 0.5, 3.0),
+            (2.0, 0.5, 1.5),
+            (3.0, std::f64::NAN, 1.5),
+            (4.0, 0.5, 1.5),
+            (5.0, 0.5, 1.5),
+        ];
+        chart
+            .draw_series(
+                BandSeries::new(data, &BLUE.mix(0.5))
+                    .edge_style(&BLUE)
+                    .clip(chart.x_range(), chart.y_range()),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,460 @@
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::ops::AddAssign;
+
+use super::histogram::{HistogramType, Horizontal, Vertical};
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::element::{Drawable, PointCollection};
+use crate::style::formatters::format_percent;
+use crate::style::ShapeStyle;
+use num_traits::Float;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// How the bars of the data sets are placed on the same category
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BarMode {
+    /// The bars are placed side by side, with the given gap in pixels between them
+    Grouped(u32),
+    /// The bars are stacked on top of each other
+    Stacked,
+}
+
+/// The category, the start value, the end value, the style and the slot of a bar
+type BarSpan<K, A> = (K, A, A, ShapeStyle, (usize, usize));
+/// The bounds of the segment of a category
+type Segment<K> = (K, K);
+/// Scale a value to the percentage of the total magnitude of its stack, from the value and the
+/// sums of the positive and the negative values of the stack
+type Normalize<A> = fn(A, A, A) -> A;
+
+fn percent_of<A: Float>(value: A, positive: A, negative: A) -> A {
+    let total = positive - negative;
+    if total == A::zero() {
+        return A::zero();
+    }
+    value / total * A::from(100.0).unwrap()
+}
+
+/// A single bar of a bar series, which takes a slot of the category it belongs to
+pub struct Bar<Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    vertical: bool,
+    margin: u32,
+    gap: u32,
+    slot: (usize, usize),
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Bar<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Bar<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        let (mut ul, mut br) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+
+        // Split the category into the slots along the category axis
+        let (start, end) = if self.vertical {
+            (ul.0, br.0)
+        } else {
+            (ul.1, br.1)
+        };
+        let (index, count) = self.slot;
+        let total = (end - start - 2 * self.margin as i32) as f64;
+        let gaps = (self.gap as usize * (count - 1)) as f64;
+        let width = ((total - gaps) / count as f64).max(1.0);
+        let from = start as f64 + self.margin as f64 + (width + self.gap as f64) * index as f64;
+        let (from, to) = (from.round() as i32, (from + width).round() as i32);
+        if self.vertical {
+            ul.0 = from;
+            br.0 = to;
+        } else {
+            ul.1 = from;
+            br.1 = to;
+        }
+
+        backend.draw_rect(ul, br, &self.style, self.style.filled)
+    }
+}
+
+/// The series that draws the bars of several data sets over a discrete category axis, either
+/// grouped side by side or stacked on top of each other.
+///
+/// The values of the same category within a data set are summed up. In the stacked mode, the
+/// positive values are stacked upwards from the baseline, and the negative ones downwards.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d((0..3).into_segmented(), 0..20)
+///     .unwrap();
+/// let mut colors = chart.color_cycle(PaletteCategory10);
+///
+/// let grouped = BarSeries::vertical(&chart)
+///     .data(vec![(0, 3), (1, 5), (2, 7)], colors.next_filled_style())
+///     .data(vec![(0, 6), (1, 2), (2, 4)], colors.next_filled_style())
+///     .grouped(2);
+/// chart.draw_series(grouped).unwrap();
+/// ```
+pub struct BarSeries<BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default + Clone + PartialOrd,
+    Tag: HistogramType,
+{
+    br: BR,
+    data: Vec<(BTreeMap<usize, A>, ShapeStyle)>,
+    mode: BarMode,
+    margin: u32,
+    baseline: A,
+    normalize: Option<Normalize<A>>,
+    bars: Option<std::vec::IntoIter<BarSpan<usize, A>>>,
+    _p: PhantomData<Tag>,
+}
+
+impl<BR, A, Tag> BarSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + Clone + PartialOrd,
+    Tag: HistogramType,
+{
+    fn empty(br: &BR) -> Self {
+        Self {
+            br: br.clone(),
+            data: vec![],
+            mode: BarMode::Grouped(0),
+            margin: 5,
+            baseline: A::default(),
+            normalize: None,
+            bars: None,
+            _p: PhantomData,
+        }
+    }
+
+    /// Add a data set
+    /// - `iter`: The iterator over the categories and the values
+    /// - `style`: The style of the bars of this data set
+    pub fn data<TB: Into<BR::ValueType>, I: IntoIterator<Item = (TB, A)>, S: Into<ShapeStyle>>(
+        mut self,
+        iter: I,
+        style: S,
+    ) -> Self {
+        let mut buffer = BTreeMap::<usize, A>::new();
+        for (x, y) in iter {
+            if let Some(x) = self.br.index_of(&x.into()) {
+                *buffer.entry(x).or_default() += y;
+            }
+        }
+        self.data.push((buffer, style.into()));
+        self
+    }
+
+    /// Place the bars of the data sets side by side in each category
+    /// - `gap`: The gap between the bars of the same category in pixels
+    pub fn grouped(mut self, gap: u32) -> Self {
+        self.mode = BarMode::Grouped(gap);
+        self
+    }
+
+    /// Stack the bars of the data sets on top of each other in each category
+    pub fn stacked(mut self) -> Self {
+        self.mode = BarMode::Stacked;
+        self
+    }
+
+    /// Set the margin between the bars and the borders of the category
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /// Set the baseline that the bars start from
+    pub fn baseline(mut self, baseline: A) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Compute the category, the start value, the end value, the style and the slot of each bar
+    fn build_bars(&self) -> Vec<BarSpan<usize, A>> {
+        let mut categories: Vec<usize> =
+            self.data.iter().flat_map(|d| d.0.keys()).cloned().collect();
+        categories.sort_unstable();
+        categories.dedup();
+
+        let mut bars = vec![];
+        for category in categories {
+            let (mut positive_total, mut negative_total) = (A::default(), A::default());
+            if self.normalize.is_some() {
+                for value in self.data.iter().filter_map(|d| d.0.get(&category)) {
+                    if *value >= A::default() {
+                        positive_total += value.clone();
+                    } else {
+                        negative_total += value.clone();
+                    }
+                }
+            }
+            let (mut positive, mut negative) = (self.baseline.clone(), self.baseline.clone());
+            for (idx, (values, style)) in self.data.iter().enumerate() {
+                let value = match (values.get(&category), self.normalize) {
+                    (Some(value), Some(normalize)) => normalize(
+                        value.clone(),
+                        positive_total.clone(),
+                        negative_total.clone(),
+                    ),
+                    (Some(value), None) => value.clone(),
+                    (None, _) => continue,
+                };
+                let (from, slot) = match self.mode {
+                    BarMode::Grouped(_) => (self.baseline.clone(), (idx, self.data.len())),
+                    BarMode::Stacked if value >= A::default() => (positive.clone(), (0, 1)),
+                    BarMode::Stacked => (negative.clone(), (0, 1)),
+                };
+                let mut to = from.clone();
+                to += value.clone();
+                if self.mode == BarMode::Stacked {
+                    if value >= A::default() {
+                        positive = to.clone();
+                    } else {
+                        negative = to.clone();
+                    }
+                }
+                bars.push((category, from, to, style.clone(), slot));
+            }
+        }
+        bars
+    }
+
+    /// Get the next bar, whose category is given by the bounds of its segment
+    fn next_bar(&mut self) -> Option<BarSpan<Segment<BR::ValueType>, A>> {
+        if self.bars.is_none() {
+            self.bars = Some(self.build_bars().into_iter());
+        }
+        loop {
+            let (category, from, to, style, slot) = self.bars.as_mut()?.next()?;
+            if let (Some(key), Some(next)) = (
+                self.br.from_index(category),
+                self.br.from_index(category + 1),
+            ) {
+                return Some(((key, next), from, to, style, slot));
+            }
+        }
+    }
+
+    fn make_bar<Coord>(
+        &self,
+        points: [Coord; 2],
+        style: ShapeStyle,
+        slot: (usize, usize),
+        vertical: bool,
+    ) -> Bar<Coord> {
+        let gap = match self.mode {
+            BarMode::Grouped(gap) => gap,
+            BarMode::Stacked => 0,
+        };
+        Bar {
+            points,
+            style,
+            vertical,
+            margin: self.margin,
+            gap,
+            slot,
+        }
+    }
+}
+
+impl<BR, A, Tag> BarSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged + Clone,
+    A: Float + AddAssign<A> + Default,
+    Tag: HistogramType,
+{
+    /// Stack the bars of the data sets, and scale each stack to the percentages of its total,
+    /// so that every category reaches 100 from the baseline. The negative values take their
+    /// share of the total magnitude, and they are stacked downwards. The value axis is
+    /// typically `0.0..100.0`, labelled with the [percent_formatter](#method.percent_formatter).
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .set_all_label_area_size(40)
+    ///     .build_cartesian_2d((0..3).into_segmented(), 0.0..100.0)
+    ///     .unwrap();
+    /// let series = BarSeries::vertical(&chart)
+    ///     .data(vec![(0, 3.0), (1, 5.0), (2, 7.0)], RED.filled())
+    ///     .data(vec![(0, 6.0), (1, 2.0), (2, 4.0)], BLUE.filled())
+    ///     .normalized();
+    /// chart
+    ///     .configure_mesh()
+    ///     .y_label_formatter(&series.percent_formatter())
+    ///     .draw()
+    ///     .unwrap();
+    /// chart.draw_series(series).unwrap();
+    /// ```
+    pub fn normalized(mut self) -> Self {
+        self.mode = BarMode::Stacked;
+        self.normalize = Some(percent_of::<A>);
+        self
+    }
+
+    /// Get the formatter of the value axis, which labels the percentages with the `%` sign
+    pub fn percent_formatter(&self) -> impl Fn(&A) -> String {
+        |value| value.to_f64().map_or_else(String::new, format_percent)
+    }
+}
+
+impl<BR, A> BarSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + Clone + PartialOrd,
+{
+    /// Create a new vertical bar series, whose categories are the X axis of the chart
+    pub fn vertical<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty(parent.as_coord_spec().x_spec())
+    }
+}
+
+impl<BR, A> BarSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + Clone + PartialOrd,
+{
+    /// Create a new horizontal bar series, whose categories are the Y axis of the chart
+    pub fn horizontal<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty(parent.as_coord_spec().y_spec())
+    }
+}
+
+impl<BR, A> Iterator for BarSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + Clone + PartialOrd,
+{
+    type Item = Bar<(BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((key, next), from, to, style, slot) = self.next_bar()?;
+        Some(self.make_bar([(key, to), (next, from)], style, slot, true))
+    }
+}
+
+impl<BR, A> Iterator for BarSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: AddAssign<A> + Default + Clone + PartialOrd,
+{
+    type Item = Bar<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((key, next), from, to, style, slot) = self.next_bar()?;
+        Some(self.make_bar([(from, key), (to, next)], style, slot, false))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_grouped_bars() {
+        let drawing_area = create_mocked_drawing_area(220, 100, |m| {
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                // The first of the two slots of the first category, which is 110 pixels wide
+                assert_eq!((ul.0, br.0), (5, 53));
+                assert_eq!(br.1, 99);
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!((ul.0, br.0), (57, 105));
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), 0..10)
+            .unwrap();
+        let series = BarSeries::vertical(&chart)
+            .data(vec![(0, 3), (1, 5)], RED.filled())
+            .data(vec![(0, 2)], BLUE.filled())
+            .grouped(4);
+        chart.draw_series(series).unwrap();
+    }
+
+    #[test]
+    fn test_stacked_bars() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-10..10, (0..1).into_segmented())
+            .unwrap();
+        let series = BarSeries::horizontal(&chart)
+            .data(vec![(0, 3), (0, 1), (1, 2)], RED.filled())
+            .data(vec![(0, 2), (1, -5)], BLUE.filled())
+            .data(vec![(1, -1)], GREEN.filled())
+            .stacked();
+        let bars: Vec<_> = series
+            .map(|bar| (bar.points[0].0, bar.points[1].0))
+            .collect();
+        assert_eq!(bars, vec![(0, 4), (4, 6), (0, 2), (0, -5), (-5, -6)]);
+    }
+
+    #[test]
+    fn test_normalized_bars() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).into_segmented(), -100.0..100.0)
+            .unwrap();
+        let series = BarSeries::vertical(&chart)
+            .data(vec![(0, 1.0), (1, 3.0), (2, 0.0)], RED.filled())
+            .data(vec![(0, 3.0), (1, -1.0)], BLUE.filled())
+            .normalized();
+        let formatter = series.percent_formatter();
+        assert_eq!(formatter(&100.0), "100%");
+        assert_eq!(formatter(&(0.1 + 0.2)), "0.3%");
+        let bars: Vec<_> = series
+            .map(|bar| (bar.points[1].1, bar.points[0].1))
+            .collect();
+        // The stack without a total is empty rather than NaN
+        assert_eq!(
+            bars,
+            vec![
+                (0.0, 25.0),
+                (25.0, 100.0),
+                (0.0, 75.0),
+                (0.0, -25.0),
+                (0.0, 0.0)
+            ]
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::marker::PhantomData;
+
+use crate::data::Quartiles;
+use crate::element::{Boxplot, BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+use crate::style::{ShapeStyle, BLACK};
+
+const DEFAULT_WIDTH: u32 = 10;
+const DEFAULT_GAP: u32 = 2;
+
+/// The series that computes the quartiles of each group of samples and creates a boxplot for
+/// each of them.
+///
+/// For the grouped boxplots, draw one series per group and use
+/// [group](struct.BoxplotSeries.html#method.group) to place the boxes of the groups next to each
+/// other on the same key.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let treatment_a = vec![(0, vec![3, 4, 5, 7]), (1, vec![5, 6, 8, 9])];
+/// let treatment_b = vec![(0, vec![2, 4, 4, 6]), (1, vec![6, 7, 7, 10])];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d((0..1).into_segmented(), 0f64..12f64)
+///     .unwrap();
+///
+/// for (idx, (data, color)) in [(&treatment_a, &RED), (&treatment_b, &BLUE)].iter().enumerate() {
+///     let groups = data.iter().map(|(key, v)| (SegmentValue::CenterOf(*key), &v[..]));
+///     chart
+///         .draw_series(BoxplotSeries::new_vertical(groups).style(*color).group(idx, 2))
+///         .unwrap();
+/// }
+/// ```
+pub struct BoxplotSeries<'a, K, T, I, O>
+where
+    I: Iterator<Item = (K, &'a [T])>,
+    T: 'a,
+{
+    iter: I,
+    style: ShapeStyle,
+    width: u32,
+    gap: u32,
+    group: (usize, usize),
+    _p: PhantomData<O>,
+}
+
+impl<'a, K, T, I> BoxplotSeries<'a, K, T, I, BoxplotOrientV<K, f64>>
+where
+    I: Iterator<Item = (K, &'a [T])>,
+{
+    /// Create a new vertical boxplot series
+    /// - `iter`: The iterator over the keys (the X axis values) and their samples
+    /// - **returns** The newly created series
+    pub fn new_vertical<S: IntoIterator<Item = (K, &'a [T]), IntoIter = I>>(iter: S) -> Self {
+        Self::from_iter(iter)
+    }
+}
+
+impl<'a, K, T, I> BoxplotSeries<'a, K, T, I, BoxplotOrientH<K, f64>>
+where
+    I: Iterator<Item = (K, &'a [T])>,
+{
+    /// Create a new horizontal boxplot series
+    /// - `iter`: The iterator over the keys (the Y axis values) and their samples
+    /// - **returns** The newly created series
+    pub fn new_horizontal<S: IntoIterator<Item = (K, &'a [T]), IntoIter = I>>(iter: S) -> Self {
+        Self::from_iter(iter)
+    }
+}
+
+impl<'a, K, T, I, O> BoxplotSeries<'a, K, T, I, O>
+where
+    I: Iterator<Item = (K, &'a [T])>,
+{
+    fn from_iter<S: IntoIterator<Item = (K, &'a [T]), IntoIter = I>>(iter: S) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            style: Into::<ShapeStyle>::into(&BLACK),
+            width: DEFAULT_WIDTH,
+            gap: DEFAULT_GAP,
+            group: (0, 1),
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the style of the boxplots
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of each boxplot
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the gap between the boxplots of the neighbouring groups
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Place the boxplots as one of the groups that share the same keys, the groups are centered
+    /// around the key
+    /// - `index`: The index of the group of this series
+    /// - `count`: The number of groups
+    pub fn group(mut self, index: usize, count: usize) -> Self {
+        self.group = (index, count.max(1));
+        self
+    }
+
+    fn offset(&self) -> f64 {
+        let (index, count) = self.group;
+        let step = f64::from(self.width + self.gap);
+        (index as f64 - (count - 1) as f64 / 2.0) * step
+    }
+}
+
+impl<'a, K: Clone, T, I, O> Iterator for BoxplotSeries<'a, K, T, I, O>
+where
+    I: Iterator<Item = (K, &'a [T])>,
+    T: Into<f64> + Copy + PartialOrd,
+    O: BoxplotOrient<K, f64>,
+{
+    type Item = Boxplot<K, O>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (key, samples) = self.iter.next()?;
+            // The groups without samples don't have quartiles
+            if samples.is_empty() {
+                continue;
+            }
+            return Some(
+                Boxplot::from_quartiles(key, &Quartiles::new(samples))
+                    .style(self.style.clone())
+                    .width(self.width)
+                    .offset(self.offset()),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_grouped_boxplots() {
+        let data = vec![(0, vec![1.0, 2.0, 3.0]), (1, vec![]), (2, vec![4.0, 5.0])];
+        let series =
+            || BoxplotSeries::new_vertical(data.iter().map(|(k, v)| (*k, &v[..]))).width(20);
+        assert_eq!(series().count(), 2);
+        assert_eq!(series().offset(), 0.0);
+        assert_eq!(series().group(0, 2).offset(), -11.0);
+        assert_eq!(series().group(1, 2).offset(), 11.0);
+        assert_eq!(series().gap(0).group(2, 3).offset(), 20.0);
+
+        let root = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_rect(|c, _, _, (x0, _), (x1, _)| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(x1 - x0, 20);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(-1..3, 0f64..10f64)
+            .unwrap();
+        chart.draw_series(series().style(&RED).group(1, 2)).unwrap();
+
+        let horizontal: Vec<_> =
+            BoxplotSeries::new_horizontal(data.iter().map(|(k, v)| (*k, &v[..]))).collect();
+        assert_eq!(horizontal.len(), 2);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+use crate::element::{Circle, DynElement, IntoDynElement, Text};
+use crate::style::colors::BLUE;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// How the size values of a [BubbleSeries](struct.BubbleSeries.html) are turned into the radii
+/// of the bubbles
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BubbleScale {
+    /// The area of a bubble is proportional to the value, which is how the sizes are perceived,
+    /// the bubble of the largest value gets the largest radius
+    Area,
+    /// The radius grows linearly from the smallest to the largest value
+    Radius,
+}
+
+/// The scatter series that maps a third variable to the sizes of the markers, which are
+/// circles with the radii between the smallest and the largest radius.
+///
+/// The values that aren't finite are skipped, and in the area scale the values that aren't
+/// positive get the smallest radius.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let cities = vec![(1.0, 2.0, 8.4), (3.0, 5.0, 3.9), (6.0, 4.0, 2.7), (8.0, 7.0, 0.9)];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// let bubbles = BubbleSeries::new(cities).radius_range(3, 30);
+/// let legend = bubbles.size_legend((520, 20), 3, ("sans-serif", 12));
+/// chart.draw_series(bubbles).unwrap();
+/// for element in legend {
+///     root.draw(&element).unwrap();
+/// }
+/// ```
+pub struct BubbleSeries<X, Y> {
+    data: Vec<(X, Y, f64)>,
+    style: ShapeStyle,
+    radius: (u32, u32),
+    value_range: (f64, f64),
+    scale: BubbleScale,
+    idx: usize,
+}
+
+impl<X, Y> BubbleSeries<X, Y> {
+    /// Create a new bubble series
+    /// - `iter`: The bubbles as `(x, y, size value)`
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y, f64)>>(iter: I) -> Self {
+        let data: Vec<_> = iter.into_iter().filter(|p| p.2.is_finite()).collect();
+        let mut values = data.iter().map(|p| p.2);
+        let value_range = match values.next() {
+            Some(first) => values.fold((first, first), |r, v| (r.0.min(v), r.1.max(v))),
+            None => (0.0, 0.0),
+        };
+        Self {
+            data,
+            style: BLUE.mix(0.5).filled(),
+            radius: (2, 20),
+            value_range,
+            scale: BubbleScale::Area,
+            idx: 0,
+        }
+    }
+
+    /// Set the style of the bubbles, which are translucent blue circles by default
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the range of the radii of the bubbles in pixels, which is 2 to 20 by default
+    pub fn radius_range(mut self, min: u32, max: u32) -> Self {
+        self.radius = (min.min(max), min.max(max));
+        self
+    }
+
+    /// Set how the values are turned into the radii, the areas are proportional to the values
+    /// by default
+    pub fn scale(mut self, scale: BubbleScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the range of the values that is mapped onto the radii, which is the range of the
+    /// values in the series by default. Setting the same range on several series makes their
+    /// bubbles comparable.
+    pub fn value_range(mut self, range: std::ops::Range<f64>) -> Self {
+        self.value_range = (range.start, range.end);
+        self
+    }
+
+    /// Get the radius of the bubble of a value in pixels
+    pub fn radius_of(&self, value: f64) -> u32 {
+        let (r0, r1) = (f64::from(self.radius.0), f64::from(self.radius.1));
+        let (min, max) = self.value_range;
+        let radius = match self.scale {
+            BubbleScale::Area if max > 0.0 && value > 0.0 => r1 * (value / max).sqrt(),
+            BubbleScale::Area => r0,
+            BubbleScale::Radius => {
+                let t = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    1.0
+                };
+                r0 + (r1 - r0) * t
+            }
+        };
+        let radius = if radius.is_nan() || radius <= r0 {
+            r0
+        } else {
+            radius.min(r1)
+        };
+        radius.round() as u32
+    }
+
+    /// Create the legend of the bubble sizes, which is a column of the bubbles of some round
+    /// values with their labels. The legend is in pixels, thus it's drawn onto a drawing area
+    /// without the coordinate, such as the root area.
+    ///
+    /// - `pos`: The upper left corner of the legend in pixels
+    /// - `count`: The maximum number of the reference bubbles
+    /// - `font`: The style of the labels
+    /// - **returns** The elements of the legend
+    pub fn size_legend<'b, DB: DrawingBackend, S: Into<TextStyle<'b>>>(
+        &self,
+        pos: BackendCoord,
+        count: usize,
+        font: S,
+    ) -> Vec<DynElement<'b, DB, BackendCoord>> {
+        let (min, max) = self.value_range;
+        let coord: RangedCoordf64 = (min..max).into();
+        let mut values: Vec<f64> = coord
+            .key_points(count)
+            .into_iter()
+            .filter(|v| *v > 0.0 || self.scale == BubbleScale::Radius)
+            .collect();
+        if values.is_empty() {
+            values.push(max);
+        }
+
+        let font = font.into().pos(Pos::new(HPos::Left, VPos::Center));
+        let max_radius = values.iter().map(|v| self.radius_of(*v)).max().unwrap_or(0) as i32;
+        let mut elements = vec![];
+        let mut y = pos.1;
+        for value in values {
+            let radius = self.radius_of(value) as i32;
+            let center = (pos.0 + max_radius, y + radius);
+            elements.push(Circle::new(center, radius, self.style.clone()).into_dyn());
+            elements.push(
+                Text::new(
+                    RangedCoordf64::format(&value),
+                    (pos.0 + max_radius * 2 + 5, center.1),
+                    font.clone(),
+                )
+                .into_dyn(),
+            );
+            y += radius * 2 + 5;
+        }
+        elements
+    }
+}
+
+impl<X: Clone, Y: Clone> Iterator for BubbleSeries<X, Y> {
+    type Item = Circle<(X, Y), u32>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, value) = self.data.get(self.idx)?.clone();
+        self.idx += 1;
+        Some(Circle::new(
+            (x, y),
+            self.radius_of(value),
+            self.style.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bubble_radius() {
+        let bubbles = BubbleSeries::new(vec![(0, 0, 1.0), (1, 1, 4.0), (2, 2, std::f64::NAN)])
+            .radius_range(2, 20);
+        assert_eq!(bubbles.value_range, (1.0, 4.0));
+        // A quarter of the largest value gets a quarter of the area
+        assert_eq!(bubbles.radius_of(4.0), 20);
+        assert_eq!(bubbles.radius_of(1.0), 10);
+        assert_eq!(bubbles.radius_of(-1.0), 2);
+        assert_eq!(bubbles.radius_of(100.0), 20);
+
+        let bubbles = bubbles.scale(BubbleScale::Radius);
+        assert_eq!(bubbles.radius_of(1.0), 2);
+        assert_eq!(bubbles.radius_of(2.5), 11);
+
+        let circles: Vec<_> = bubbles.collect();
+        assert_eq!(circles.len(), 2);
+    }
+
+    #[test]
+    fn test_size_legend() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|_, _, _, pos, radius| {
+                assert_eq!(pos.0, 10 + 20);
+                assert!(radius <= 20);
+            });
+            m.drop_check(|b| {
+                assert!(b.num_draw_circle_call >= 2);
+                assert_eq!(b.num_draw_circle_call, b.num_draw_text_call);
+            });
+        });
+        let bubbles = BubbleSeries::new(vec![(0, 0, 10.0), (1, 1, 100.0)]);
+        for element in bubbles.size_legend((10, 10), 3, ("sans-serif", 12)) {
+            drawing_area.draw(&element).unwrap();
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::Range;
+
+use crate::element::{Circle, Colorbar};
+use crate::style::{Color, ColorMap, Normalizer};
+
+/// The scatter series whose markers are colored by a third variable through a color map, with
+/// a matching [Colorbar](../element/struct.Colorbar.html) that explains the colors.
+///
+/// The markers are filled circles, and the points whose value is NaN are skipped.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let samples = (0..50).map(|i| {
+///     let x = i as f64 / 5.0;
+///     (x, x.sin() * 5.0 + 5.0, x * x + 1.0)
+/// });
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .colorbar_area_size(60)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// let scatter = ColorScatterSeries::new(samples, 4, ViridisRGB).normalizer(Normalizer::Log);
+/// chart.draw_colorbar(scatter.colorbar()).unwrap();
+/// chart.draw_series(scatter).unwrap();
+/// ```
+pub struct ColorScatterSeries<M: ColorMap, X, Y> {
+    data: Vec<(X, Y, f64)>,
+    size: u32,
+    map: M,
+    value_range: Range<f64>,
+    normalizer: Normalizer,
+    idx: usize,
+}
+
+impl<M: ColorMap, X, Y> ColorScatterSeries<M, X, Y> {
+    /// Create a new color scatter series
+    /// - `iter`: The points as `(x, y, value)`
+    /// - `size`: The radius of the markers in pixels
+    /// - `map`: The color map, the values are mapped from the smallest to the largest finite
+    ///   value
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y, f64)>>(iter: I, size: u32, map: M) -> Self {
+        let data: Vec<_> = iter.into_iter().filter(|p| !p.2.is_nan()).collect();
+        let mut finite = data.iter().map(|p| p.2).filter(|v| v.is_finite());
+        let value_range = match finite.next() {
+            Some(first) => finite.fold(first..first, |r, v| r.start.min(v)..r.end.max(v)),
+            None => 0.0..0.0,
+        };
+        Self {
+            data,
+            size,
+            map,
+            value_range,
+            normalizer: Normalizer::Linear,
+            idx: 0,
+        }
+    }
+
+    /// Set the value range that is mapped onto the color map, the values out of the range get
+    /// the colors at the ends of the map
+    pub fn value_range(mut self, range: Range<f64>) -> Self {
+        self.value_range = range;
+        self
+    }
+
+    /// Set how the values are mapped onto the color map, they're mapped linearly by default
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Create a colorbar that explains the colors of the markers, with the same color map, the
+    /// same value range and the same normalizer as the series. It's drawn into the area reserved
+    /// by [ChartBuilder::colorbar_area_size](../chart/struct.ChartBuilder.html#method.colorbar_area_size)
+    /// with [ChartContext::draw_colorbar](../chart/struct.ChartContext.html#method.draw_colorbar).
+    pub fn colorbar(&self) -> Colorbar<'static, M>
+    where
+        M: Clone,
+    {
+        Colorbar::new(self.map.clone(), self.value_range.clone())
+            .normalizer(self.normalizer.clone())
+    }
+}
+
+impl<M: ColorMap, X: Clone, Y: Clone> Iterator for ColorScatterSeries<M, X, Y> {
+    type Item = Circle<(X, Y), u32>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, value) = self.data.get(self.idx)?.clone();
+        self.idx += 1;
+        let (min, max) = (self.value_range.start, self.value_range.end);
+        let color = self
+            .map
+            .get_color(self.normalizer.normalize(value, min, max));
+        Some(Circle::new((x, y), self.size, color.filled()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_color_scatter_series() {
+        let data = vec![(0, 0, 1.0), (1, 1, std::f64::NAN), (2, 2, 3.0), (3, 3, 2.0)];
+        // The colorbar shares the value range, which is computed from the finite values
+        let scatter = ColorScatterSeries::new(data.clone(), 3, BlackWhite);
+        assert_eq!(scatter.colorbar().range(), 1.0..3.0);
+        assert_eq!(scatter.count(), 3);
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            for value in [0.25, 0.75, 0.5].iter() {
+                let expected = BlackWhite.get_color(*value).to_rgba();
+                m.check_draw_circle(move |c, _, filled, _, radius| {
+                    assert!(filled);
+                    assert_eq!(radius, 3);
+                    assert_eq!(c, expected);
+                });
+            }
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 3));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..4, 0..4)
+            .unwrap();
+        chart
+            .draw_series(ColorScatterSeries::new(data, 3, BlackWhite).value_range(0.0..4.0))
+            .unwrap();
+    }
+}
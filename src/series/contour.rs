@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::element::PathElement;
+use crate::style::{ShapeStyle, BLACK};
+
+/// The grid edge that a contour line crosses, which is either the horizontal edge to the right
+/// of the grid point, or the vertical edge above it
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Edge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+/// Trace the iso-lines of a level over the sampled grid with the marching squares algorithm
+fn trace_level(
+    values: &[Vec<f64>],
+    point_of: &dyn Fn(usize, usize) -> (f64, f64),
+    level: f64,
+) -> Vec<Vec<(f64, f64)>> {
+    let above = |i: usize, j: usize| values[i][j] >= level;
+    let crossing = |edge: Edge| {
+        let ((i0, j0), (i1, j1)) = match edge {
+            Edge::Horizontal(i, j) => ((i, j), (i + 1, j)),
+            Edge::Vertical(i, j) => ((i, j), (i, j + 1)),
+        };
+        let (v0, v1) = (values[i0][j0], values[i1][j1]);
+        let t = if v1 == v0 {
+            0.5
+        } else {
+            (level - v0) / (v1 - v0)
+        };
+        let (p0, p1) = (point_of(i0, j0), point_of(i1, j1));
+        (p0.0 + (p1.0 - p0.0) * t, p0.1 + (p1.1 - p0.1) * t)
+    };
+
+    let mut segments = vec![];
+    for i in 0..values.len().saturating_sub(1) {
+        for j in 0..values[i].len().saturating_sub(1) {
+            let corners = [
+                values[i][j],
+                values[i + 1][j],
+                values[i + 1][j + 1],
+                values[i][j + 1],
+            ];
+            if corners.iter().any(|v| v.is_nan()) {
+                continue;
+            }
+            // The corners are counter-clockwise from the lower left one, and edge k is between
+            // corner k and the next corner
+            let state = [
+                above(i, j),
+                above(i + 1, j),
+                above(i + 1, j + 1),
+                above(i, j + 1),
+            ];
+            let edges = [
+                Edge::Horizontal(i, j),
+                Edge::Vertical(i + 1, j),
+                Edge::Horizontal(i, j + 1),
+                Edge::Vertical(i, j),
+            ];
+            let crossed: Vec<_> = (0..4).filter(|&k| state[k] != state[(k + 1) % 4]).collect();
+            match crossed.len() {
+                2 => segments.push((edges[crossed[0]], edges[crossed[1]])),
+                4 => {
+                    // The saddle is resolved with the average of the corners
+                    let center = corners.iter().sum::<f64>() / 4.0 >= level;
+                    if center == state[0] {
+                        segments.push((edges[0], edges[1]));
+                        segments.push((edges[2], edges[3]));
+                    } else {
+                        segments.push((edges[3], edges[0]));
+                        segments.push((edges[1], edges[2]));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Join the segments that share an edge into polylines
+    let mut adjacent: HashMap<Edge, Vec<usize>> = HashMap::new();
+    for (idx, (a, b)) in segments.iter().enumerate() {
+        adjacent.entry(*a).or_default().push(idx);
+        adjacent.entry(*b).or_default().push(idx);
+    }
+    let mut used = vec![false; segments.len()];
+    let mut lines = vec![];
+    // The open lines start at an edge with a single segment, the rest are closed loops
+    let open_ends: Vec<_> = segments
+        .iter()
+        .flat_map(|(a, b)| vec![*a, *b])
+        .filter(|e| adjacent[e].len() == 1)
+        .collect();
+    let starts = open_ends
+        .into_iter()
+        .chain(segments.iter().map(|(a, _)| *a))
+        .collect::<Vec<_>>();
+    for start in starts {
+        let mut edge = start;
+        let mut line = vec![crossing(edge)];
+        while let Some(&idx) = adjacent[&edge].iter().find(|&&idx| !used[idx]) {
+            used[idx] = true;
+            let (a, b) = segments[idx];
+            edge = if a == edge { b } else { a };
+            line.push(crossing(edge));
+        }
+        if line.len() > 1 {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// The series that draws the contour lines of a scalar field, which are traced with the
+/// marching squares algorithm. Each contour line of each level is a path element.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         ContourSeries::new(
+///             -2.0..2.0,
+///             -2.0..2.0,
+///             |x, y| x * x + y * y,
+///             vec![0.5, 1.0, 2.0],
+///             50,
+///         )
+///         .style_func(|level| HSLColor(level / 3.0, 0.8, 0.5).stroke_width(2)),
+///     )
+///     .unwrap();
+/// ```
+pub struct ContourSeries<'a> {
+    lines: std::vec::IntoIter<(f64, Vec<(f64, f64)>)>,
+    style: Box<dyn Fn(f64) -> ShapeStyle + 'a>,
+}
+
+impl<'a> ContourSeries<'a> {
+    /// Create a new contour series
+    /// - `x_range`: The X range that the field is sampled in
+    /// - `y_range`: The Y range that the field is sampled in
+    /// - `f`: The scalar field, the NaN values leave holes in the contours
+    /// - `levels`: The values that the contour lines are drawn for
+    /// - `resolution`: The number of the grid cells along each axis
+    /// - **returns** The newly created series
+    pub fn new<F: Fn(f64, f64) -> f64, L: IntoIterator<Item = f64>>(
+        x_range: Range<f64>,
+        y_range: Range<f64>,
+        f: F,
+        levels: L,
+        resolution: usize,
+    ) -> Self {
+        let resolution = resolution.max(1);
+        let point_of = |i: usize, j: usize| {
+            let tx = i as f64 / resolution as f64;
+            let ty = j as f64 / resolution as f64;
+            (
+                x_range.start + (x_range.end - x_range.start) * tx,
+                y_range.start + (y_range.end - y_range.start) * ty,
+            )
+        };
+        let values: Vec<Vec<f64>> = (0..=resolution)
+            .map(|i| {
+                (0..=resolution)
+                    .map(|j| {
+                        let (x, y) = point_of(i, j);
+                        f(x, y)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let lines: Vec<_> = levels
+            .into_iter()
+            .flat_map(|level| {
+                trace_level(&values, &point_of, level)
+                    .into_iter()
+                    .map(move |line| (level, line))
+            })
+            .collect();
+
+        Self {
+            lines: lines.into_iter(),
+            style: Box::new(|_| BLACK.into()),
+        }
+    }
+
+    /// Set the style of all the contour lines
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        let style = style.into();
+        self.style = Box::new(move |_| style.clone());
+        self
+    }
+
+    /// Set the style of the contour lines with a function of the level
+    pub fn style_func(mut self, style_func: impl Fn(f64) -> ShapeStyle + 'a) -> Self {
+        self.style = Box::new(style_func);
+        self
+    }
+}
+
+impl<'a> Iterator for ContourSeries<'a> {
+    type Item = PathElement<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (level, line) = self.lines.next()?;
+        Some(PathElement::new(line, (self.style)(level)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_circle_contour() {
+        let lines: Vec<_> = ContourSeries::new(
+            -2.0..2.0,
+            -2.0..2.0,
+            |x, y| x * x + y * y,
+            vec![1.0, 100.0],
+            40,
+        )
+        .collect();
+        // The level 100 is out of the field
+        assert_eq!(lines.len(), 1);
+        let points: Vec<_> = (&lines[0]).point_iter().to_vec();
+        // The circle is closed
+        assert_eq!(points[0], points[points.len() - 1]);
+        assert!(points
+            .iter()
+            .all(|(x, y)| ((x * x + y * y).sqrt() - 1.0).abs() < 0.02));
+    }
+
+    #[test]
+    fn test_open_contours() {
+        // Two separated lines, which are open at the border of the grid
+        let series = ContourSeries::new(0.0..4.0, 0.0..1.0, |x, _| (x - 2.0).abs(), vec![1.0], 8);
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 9);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, 0.0..1.0)
+            .unwrap();
+        chart.draw_series(series.style(&RED)).unwrap();
+    }
+}
@@ -0,0 +1,235 @@
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+use num_traits::ToPrimitive;
+
+/// The series that draws the empirical cumulative distribution function (ECDF) of the samples,
+/// which is the fraction of the samples that are less than or equal to each value, as a step
+/// line. The percentiles, such as the p50, p95 and p99 of the latencies, can be read directly
+/// from the chart, and they're also available with
+/// [quantile](struct.EcdfSeries.html#method.quantile).
+///
+/// The line starts at the smallest sample and ends at the largest sample, thus it can be drawn
+/// on a logarithmic X axis as well. The samples can be weighted with
+/// [weighted](#method.weighted), then the fractions are of the total weight.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let latencies: Vec<f64> = (1..=1000).map(|i| (i as f64 / 100.0).exp()).collect();
+/// let ecdf = EcdfSeries::new(latencies, &RED);
+/// // The p99 is the 990th smallest of the 1000 latencies
+/// assert_eq!(ecdf.quantile(0.99), Some((990.0f64 / 100.0).exp()));
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d((1.0..30000.0).log_scale(), 0.0..1.0)
+///     .unwrap();
+/// chart.draw_series(ecdf).unwrap();
+/// ```
+pub struct EcdfSeries {
+    sorted: Vec<(f64, f64)>,
+    total: f64,
+    style: ShapeStyle,
+    survival: bool,
+    positive: bool,
+    done: bool,
+}
+
+impl EcdfSeries {
+    /// Create a new ECDF series, the samples that are NaN or infinite are ignored
+    /// - `samples`: The samples
+    /// - `style`: The style of the line
+    /// - **returns** The newly created series
+    pub fn new<T: ToPrimitive, I: IntoIterator<Item = T>, S: Into<ShapeStyle>>(
+        samples: I,
+        style: S,
+    ) -> Self {
+        Self::weighted(samples.into_iter().map(|v| (v, 1.0)), style)
+    }
+
+    /// Create a new ECDF series of the weighted samples, such as the survey weights or the
+    /// counts of the pre-aggregated values. The samples that are NaN or infinite and the
+    /// samples that have no positive weight are ignored.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let ecdf = EcdfSeries::weighted(vec![(10, 1.0), (20, 2.0), (50, 1.0)], &RED);
+    /// assert_eq!(ecdf.fraction_at(20.0), 0.75);
+    /// assert_eq!(ecdf.quantile(0.5), Some(20.0));
+    /// ```
+    ///
+    /// - `samples`: The samples and their weights
+    /// - `style`: The style of the line
+    /// - **returns** The newly created series
+    pub fn weighted<T: ToPrimitive, I: IntoIterator<Item = (T, f64)>, S: Into<ShapeStyle>>(
+        samples: I,
+        style: S,
+    ) -> Self {
+        let mut sorted: Vec<(f64, f64)> = samples
+            .into_iter()
+            .filter_map(|(v, w)| v.to_f64().map(|v| (v, w)))
+            .filter(|(v, w)| v.is_finite() && *w > 0.0 && w.is_finite())
+            .collect();
+        sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        Self {
+            total: sorted.iter().map(|s| s.1).sum(),
+            sorted,
+            style: style.into(),
+            survival: false,
+            positive: false,
+            done: false,
+        }
+    }
+
+    /// Set if the survival function, which is the fraction of the samples that are greater than
+    /// each value, is drawn instead, thus the line goes down from 1 to 0
+    pub fn survival(mut self, survival: bool) -> Self {
+        self.survival = survival;
+        self
+    }
+
+    /// Only draw the line over the positive values, for a logarithmic X axis. The fractions are
+    /// still of all the samples, thus the line starts at the fraction of the non-positive samples.
+    pub fn log_x(mut self, log_x: bool) -> Self {
+        self.positive = log_x;
+        self
+    }
+
+    /// Get the number of the samples
+    pub fn len(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// Check if there's no sample
+    pub fn is_empty(&self) -> bool {
+        self.sorted.is_empty()
+    }
+
+    /// Get the fraction of the samples that are less than or equal to the value
+    pub fn fraction_at(&self, value: f64) -> f64 {
+        if self.sorted.is_empty() {
+            return 0.0;
+        }
+        let weight = self
+            .sorted
+            .iter()
+            .take_while(|s| s.0 <= value)
+            .map(|s| s.1)
+            .sum::<f64>();
+        weight / self.total
+    }
+
+    /// Get the smallest sample that is greater than or equal to the fraction of the samples,
+    /// for example, `quantile(0.95)` is the p95
+    /// - `fraction`: The fraction in `[0, 1]`
+    /// - **returns** The sample, or `None` if there's no sample or the fraction is out of range
+    pub fn quantile(&self, fraction: f64) -> Option<f64> {
+        if self.sorted.is_empty() || !(0.0..=1.0).contains(&fraction) {
+            return None;
+        }
+        let target = fraction * self.total;
+        let mut weight = 0.0;
+        for (value, w) in &self.sorted {
+            weight += w;
+            if weight >= target {
+                return Some(*value);
+            }
+        }
+        self.sorted.last().map(|s| s.0)
+    }
+
+    fn value(&self, fraction: f64) -> f64 {
+        if self.survival {
+            1.0 - fraction
+        } else {
+            fraction
+        }
+    }
+}
+
+impl Iterator for EcdfSeries {
+    type Item = PathElement<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.sorted.is_empty() {
+            return None;
+        }
+        self.done = true;
+
+        let mut points = vec![];
+        let mut below = 0.0;
+        let mut weight = 0.0;
+        for (idx, (x, w)) in self.sorted.iter().enumerate() {
+            weight += w;
+            // Only the last of the equal samples makes a step
+            if self.sorted.get(idx + 1).map(|s| s.0) == Some(*x) {
+                continue;
+            }
+            let fraction = weight / self.total;
+            if !self.positive || *x > 0.0 {
+                points.push((*x, self.value(below)));
+                points.push((*x, self.value(fraction)));
+            }
+            below = fraction;
+        }
+        Some(PathElement::new(points, self.style.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ecdf() {
+        let ecdf = EcdfSeries::new(vec![3, 1, 2, 2, 4], &RED);
+        assert_eq!(ecdf.len(), 5);
+        assert_eq!(ecdf.fraction_at(2.0), 0.6);
+        assert_eq!(ecdf.fraction_at(0.0), 0.0);
+        assert_eq!(ecdf.quantile(0.5), Some(2.0));
+        assert_eq!(ecdf.quantile(1.0), Some(4.0));
+        assert_eq!(ecdf.quantile(0.0), Some(1.0));
+        assert_eq!(ecdf.quantile(1.5), None);
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 8);
+                assert_eq!(path[0].1, 99);
+                assert_eq!(path[7].1, 0);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 4);
+                assert!(path[0].1 < path[1].1);
+                assert_eq!(path[3], (80, 99));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..1.0)
+            .unwrap();
+        chart.draw_series(ecdf).unwrap();
+        // The survival function over the positive samples, the first step is at 2
+        let survival = EcdfSeries::new(vec![-3, 0, 2, 2, 4], &RED)
+            .survival(true)
+            .log_x(true);
+        chart.draw_series(survival).unwrap();
+    }
+
+    #[test]
+    fn test_weighted_ecdf() {
+        let ecdf = EcdfSeries::weighted(vec![(1, 3.0), (2, 1.0), (3, 0.0), (4, 4.0)], &RED);
+        assert_eq!(ecdf.len(), 3);
+        assert_eq!(ecdf.fraction_at(1.0), 0.375);
+        assert_eq!(ecdf.fraction_at(3.0), 0.5);
+        assert_eq!(ecdf.quantile(0.3), Some(1.0));
+        assert_eq!(ecdf.quantile(0.5), Some(2.0));
+        assert_eq!(ecdf.quantile(0.51), Some(4.0));
+
+        let path = ecdf.into_iter().next().unwrap();
+        let points: Vec<_> = (&path).point_iter().iter().map(|p| p.1).collect();
+        assert_eq!(points, vec![0.0, 0.375, 0.375, 0.5, 0.5, 1.0]);
+    }
+}
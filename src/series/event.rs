@@ -0,0 +1,187 @@
+use crate::coord::ranged1d::SegmentValue;
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+const DEFAULT_TICK_RATIO: f64 = 0.8;
+
+/// How long the ticks of an event series are
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TickLength {
+    /// The fraction of the height of the lane
+    Relative(f64),
+    /// The length in pixels
+    Fixed(u32),
+}
+
+/// A single tick of an event series, which is a vertical line centered on the lane of the event
+pub struct EventTick<X, K> {
+    points: [(X, SegmentValue<K>); 2],
+    style: ShapeStyle,
+    length: TickLength,
+}
+
+impl<'a, X, K> PointCollection<'a, (X, SegmentValue<K>)> for &'a EventTick<X, K> {
+    type Point = &'a (X, SegmentValue<K>);
+    type IntoIter = &'a [(X, SegmentValue<K>)];
+    fn point_iter(self) -> &'a [(X, SegmentValue<K>)] {
+        &self.points
+    }
+}
+
+impl<X, K, DB: DrawingBackend> Drawable<DB> for EventTick<X, K> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (center, start) = match (points.next(), points.next()) {
+            (Some(center), Some(start)) => (center, start),
+            _ => return Ok(()),
+        };
+        // The start of the segment is apart from the center by the half of the lane
+        let half = match self.length {
+            TickLength::Relative(ratio) => f64::from((center.1 - start.1).abs()) * ratio,
+            TickLength::Fixed(length) => f64::from(length) / 2.0,
+        };
+        let half = half.round() as i32;
+        backend.draw_line(
+            (center.0, center.1 - half),
+            (center.0, center.1 + half),
+            &self.style,
+        )
+    }
+}
+
+/// The series that draws an event plot, also known as a raster plot: each event is a short
+/// vertical tick at its X position, on the lane of its row. The lanes are the segments of a
+/// segmented Y axis, such as the neurons of a spike raster or the sources of a trace timeline.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, (0..2).into_segmented())
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         EventSeries::new()
+///             .lane(0, vec![0.5, 1.2, 4.8, 7.7], &RED)
+///             .lane(1, vec![2.1, 2.3, 6.0], &BLUE)
+///             .tick_ratio(0.6),
+///     )
+///     .unwrap();
+/// ```
+pub struct EventSeries<X, K> {
+    lanes: Vec<(K, Vec<X>, ShapeStyle)>,
+    length: TickLength,
+    ticks: Option<std::vec::IntoIter<EventTick<X, K>>>,
+}
+
+impl<X: Clone, K: Clone> EventSeries<X, K> {
+    /// Create a new empty event series
+    pub fn new() -> Self {
+        Self {
+            lanes: vec![],
+            length: TickLength::Relative(DEFAULT_TICK_RATIO),
+            ticks: None,
+        }
+    }
+
+    /// Add the events of a lane
+    /// - `key`: The key of the lane on the Y axis
+    /// - `events`: The X positions of the events
+    /// - `style`: The style of the ticks of this lane
+    pub fn lane<I: IntoIterator<Item = X>, S: Into<ShapeStyle>>(
+        mut self,
+        key: K,
+        events: I,
+        style: S,
+    ) -> Self {
+        self.lanes
+            .push((key, events.into_iter().collect(), style.into()));
+        self
+    }
+
+    /// Set the length of the ticks as a fraction of the height of the lane, which is 0.8 by
+    /// default
+    pub fn tick_ratio(mut self, ratio: f64) -> Self {
+        self.length = TickLength::Relative(ratio);
+        self
+    }
+
+    /// Set the length of the ticks in pixels
+    pub fn tick_length(mut self, length: u32) -> Self {
+        self.length = TickLength::Fixed(length);
+        self
+    }
+
+    fn build_ticks(&self) -> Vec<EventTick<X, K>> {
+        let mut ticks = vec![];
+        for (key, events, style) in &self.lanes {
+            for x in events {
+                ticks.push(EventTick {
+                    points: [
+                        (x.clone(), SegmentValue::CenterOf(key.clone())),
+                        (x.clone(), SegmentValue::Exact(key.clone())),
+                    ],
+                    style: style.clone(),
+                    length: self.length,
+                });
+            }
+        }
+        ticks
+    }
+}
+
+impl<X: Clone, K: Clone> Default for EventSeries<X, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<X: Clone, K: Clone> Iterator for EventSeries<X, K> {
+    type Item = EventTick<X, K>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ticks.is_none() {
+            self.ticks = Some(self.build_ticks().into_iter());
+        }
+        self.ticks.as_mut().and_then(Iterator::next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_event_series() {
+        let drawing_area = create_mocked_drawing_area(100, 200, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(from.0, to.0);
+                // The lane is 100 pixels high
+                assert_eq!(to.1 - from.1, 50);
+            });
+            m.check_draw_line(|_, _, _, _| {});
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(to.1 - from.1, 10);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_line_call, 3));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, (0..1).into_segmented())
+            .unwrap();
+        chart
+            .draw_series(EventSeries::new().lane(0, vec![1, 5], &RED).tick_ratio(0.5))
+            .unwrap();
+        chart
+            .draw_series(EventSeries::new().lane(1, vec![3], &BLUE).tick_length(10))
+            .unwrap();
+    }
+}
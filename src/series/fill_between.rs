@@ -0,0 +1,258 @@
+use crate::element::{Polygon, Rectangle};
+use crate::style::ShapeStyle;
+use num_traits::{NumCast, ToPrimitive};
+use plotters_backend::BackendCoord;
+
+/// The predicate which decides if the area at a data point should be shaded
+type WhereFn<X, Y> = Box<dyn Fn(&X, &Y, &Y) -> bool>;
+
+/// The series that shades the area between a lower and an upper curve, for example the confidence
+/// band around a line.
+///
+/// The shaded area is split into multiple polygons wherever the two curves cross each other, or
+/// where either value is NaN. With [FillBetween::where_fn](#method.where_fn), only the intervals
+/// that satisfy the predicate are shaded.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = SVGBackend::new("fill-between.svg", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, -2.0..2.0)
+///     .unwrap();
+///
+/// let xs: Vec<f64> = (0..=100).map(|x| x as f64 / 10.0).collect();
+/// let band = FillBetween::new(
+///     xs.clone(),
+///     xs.iter().map(|x| x.sin() - 0.5),
+///     xs.iter().map(|x| x.sin() + 0.5),
+///     &BLUE.mix(0.2),
+/// );
+/// let legend = band.legend_glyph();
+///
+/// chart.draw_series(band).unwrap().label("sin(x) ± 0.5").legend(legend);
+/// ```
+pub struct FillBetween<X, Y> {
+    style: ShapeStyle,
+    data: Vec<(X, Y, Y)>,
+    where_fn: Option<WhereFn<X, Y>>,
+    polygons: Option<std::vec::IntoIter<Vec<(X, Y)>>>,
+}
+
+impl<X, Y> FillBetween<X, Y>
+where
+    X: Clone + ToPrimitive + NumCast,
+    Y: Clone + ToPrimitive + NumCast,
+{
+    /// Create a new series that shades the area between two curves
+    /// - `x`: The X values of the data points
+    /// - `lower`: The values of the lower curve
+    /// - `upper`: The values of the upper curve
+    /// - `style`: The style of the shaded area
+    pub fn new<XI, LI, UI, S>(x: XI, lower: LI, upper: UI, style: S) -> Self
+    where
+        XI: IntoIterator<Item = X>,
+        LI: IntoIterator<Item = Y>,
+        UI: IntoIterator<Item = Y>,
+        S: Into<ShapeStyle>,
+    {
+        Self {
+            style: style.into().filled(),
+            data: x
+                .into_iter()
+                .zip(lower.into_iter().zip(upper))
+                .map(|(x, (lo, hi))| (x, lo, hi))
+                .collect(),
+            where_fn: None,
+            polygons: None,
+        }
+    }
+
+    /// Only shade the area where the predicate holds. If the predicate switches between two data
+    /// points because the curves cross, the shaded area ends exactly at the crossing point.
+    /// - `func`: The predicate, which takes the X value, the lower value and the upper value
+    pub fn where_fn<F: Fn(&X, &Y, &Y) -> bool + 'static>(mut self, func: F) -> Self {
+        self.where_fn = Some(Box::new(func));
+        self
+    }
+
+    /// Make the legend glyph of this series, which is a filled rectangle in the series style.
+    /// The result can be passed to [SeriesAnno::legend](../chart/struct.SeriesAnno.html#method.legend)
+    pub fn legend_glyph(&self) -> impl Fn(BackendCoord) -> Rectangle<BackendCoord> {
+        let style = self.style.clone();
+        move |(x, y)| Rectangle::new([(x - 10, y - 5), (x, y + 5)], style.clone())
+    }
+
+    fn is_valid(&self, (x, lo, hi): &(X, Y, Y)) -> bool {
+        [x.to_f64(), lo.to_f64(), hi.to_f64()]
+            .iter()
+            .all(|v| v.filter(|v| !v.is_nan()).is_some())
+    }
+
+    fn is_included(&self, (x, lo, hi): &(X, Y, Y)) -> bool {
+        match self.where_fn {
+            Some(ref f) => f(x, lo, hi),
+            None => true,
+        }
+    }
+
+    /// Find the point where the two curves cross between two adjacent data points
+    fn crossing_point(&self, a: &(X, Y, Y), b: &(X, Y, Y)) -> Option<(X, Y, Y)> {
+        let to_f64 = |(x, lo, hi): &(X, Y, Y)| -> Option<(f64, f64, f64)> {
+            Some((x.to_f64()?, lo.to_f64()?, hi.to_f64()?))
+        };
+        let (ax, alo, ahi) = to_f64(a)?;
+        let (bx, blo, bhi) = to_f64(b)?;
+        let (da, db) = (ahi - alo, bhi - blo);
+        if da * db >= 0.0 {
+            return None;
+        }
+        let t = da / (da - db);
+        let y: Y = NumCast::from(alo + (blo - alo) * t)?;
+        Some((NumCast::from(ax + (bx - ax) * t)?, y.clone(), y))
+    }
+
+    fn build_polygons(&self) -> Vec<Vec<(X, Y)>> {
+        let mut polygons = vec![];
+        let mut current: Vec<(X, Y, Y)> = vec![];
+
+        let flush = |current: &mut Vec<(X, Y, Y)>, polygons: &mut Vec<Vec<(X, Y)>>| {
+            if current.len() >= 2 {
+                let mut polygon: Vec<_> = current
+                    .iter()
+                    .map(|(x, _, hi)| (x.clone(), hi.clone()))
+                    .collect();
+                polygon.extend(
+                    current
+                        .iter()
+                        .rev()
+                        .map(|(x, lo, _)| (x.clone(), lo.clone())),
+                );
+                polygons.push(polygon);
+            }
+            current.clear();
+        };
+
+        for (idx, point) in self.data.iter().enumerate() {
+            if !self.is_valid(point) {
+                flush(&mut current, &mut polygons);
+                continue;
+            }
+
+            let prev = if idx > 0 {
+                Some(&self.data[idx - 1])
+            } else {
+                None
+            };
+            let crossing = prev
+                .filter(|prev| self.is_valid(prev))
+                .and_then(|prev| self.crossing_point(prev, point));
+
+            if let Some(crossing) = crossing {
+                if !current.is_empty() {
+                    current.push(crossing.clone());
+                    flush(&mut current, &mut polygons);
+                }
+                if self.is_included(point) {
+                    current.push(crossing);
+                }
+            }
+
+            if self.is_included(point) {
+                current.push(point.clone());
+            } else {
+                flush(&mut current, &mut polygons);
+            }
+        }
+
+        flush(&mut current, &mut polygons);
+        polygons
+    }
+}
+
+impl<X, Y> Iterator for FillBetween<X, Y>
+where
+    X: Clone + ToPrimitive + NumCast,
+    Y: Clone + ToPrimitive + NumCast,
+{
+    type Item = Polygon<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.polygons.is_none() {
+            self.polygons = Some(self.build_polygons().into_iter());
+        }
+        let style = self.style.clone();
+        self.polygons
+            .as_mut()
+            .and_then(|polygons| polygons.next())
+            .map(|points| Polygon::new(points, style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_fill_between_split() {
+        let xs = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+        let band = FillBetween::new(
+            xs.clone(),
+            vec![0.0, 0.0, std::f64::NAN, 0.0, 0.0],
+            vec![1.0, 1.0, 1.0, 1.0, 1.0],
+            &RED,
+        );
+        assert_eq!(band.count(), 2);
+
+        // The curves cross at x = 1.5 and x = 3.5
+        let crossing = || {
+            FillBetween::new(
+                xs.clone(),
+                vec![0.0, 0.0, 1.0, 1.0, 0.0],
+                vec![1.0, 1.0, 0.0, 0.0, 1.0],
+                &RED,
+            )
+        };
+        assert_eq!(crossing().count(), 3);
+
+        let mut above = crossing().where_fn(|_, lo, hi| hi > lo);
+        let first = above.next().unwrap();
+        assert_eq!(above.count(), 1);
+        let points: Vec<_> = (&first).point_iter().into_iter().cloned().collect();
+        assert_eq!(
+            points,
+            vec![
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (1.5, 0.5),
+                (1.5, 0.5),
+                (1.0, 0.0),
+                (0.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fill_between_draw() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, BLUE.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..4, 0..4)
+            .unwrap();
+
+        let band = FillBetween::new(0..5, vec![0, 1, 3, 1, 0], vec![2, 2, 2, 2, 2], &BLUE);
+        let legend = band.legend_glyph();
+        chart
+            .draw_series(band)
+            .unwrap()
+            .label("band")
+            .legend(legend);
+    }
+}
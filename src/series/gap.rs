@@ -0,0 +1,250 @@
+use num_traits::NumCast;
+
+/// What a line or an area does at the missing points, which are the points with a NaN
+/// coordinate and the `None` points, see
+/// [LineSeries::gap_policy](struct.LineSeries.html#method.gap_policy)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GapPolicy {
+    /// Break the line into segments at the missing points, thus they're drawn as gaps
+    Gap,
+    /// Drop the missing points, thus the line connects the points next to them
+    Skip,
+    /// Replace a point whose Y value is missing with the linear interpolation between the points
+    /// next to it, the points that can't be interpolated are dropped
+    Interpolate,
+}
+
+/// The coordinates that can be missing, such as a NaN value, which is used by the
+/// [GapPolicy](enum.GapPolicy.html) to find the gaps of a series
+pub trait MissingValue: Sized {
+    /// Check if the value is missing
+    fn is_missing(&self) -> bool;
+
+    /// Convert the value to a number for the interpolation, or `None` if the value isn't numeric
+    fn to_number(&self) -> Option<f64> {
+        None
+    }
+
+    /// Create a value from an interpolated number, or `None` if the value isn't numeric
+    fn from_number(_value: f64) -> Option<Self> {
+        None
+    }
+
+    /// Fill in the missing parts of the point from the points before and after it, or `None`
+    /// if it can't be interpolated
+    fn interpolate(&self, _prev: &Self, _next: &Self) -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! impl_missing_float {
+    ($($t:ty),*) => {$(
+        impl MissingValue for $t {
+            fn is_missing(&self) -> bool {
+                self.is_nan()
+            }
+            fn to_number(&self) -> Option<f64> {
+                Some(<f64 as From<$t>>::from(*self))
+            }
+            fn from_number(value: f64) -> Option<Self> {
+                <$t as NumCast>::from(value)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_missing_int {
+    ($($t:ty),*) => {$(
+        impl MissingValue for $t {
+            fn is_missing(&self) -> bool {
+                false
+            }
+            fn to_number(&self) -> Option<f64> {
+                <f64 as NumCast>::from(*self)
+            }
+            fn from_number(value: f64) -> Option<Self> {
+                <$t as NumCast>::from(value.round())
+            }
+        }
+    )*};
+}
+
+impl_missing_float!(f32, f64);
+impl_missing_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "chrono")]
+mod datetime {
+    use super::MissingValue;
+    #[allow(deprecated)]
+    use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone};
+
+    impl MissingValue for NaiveDate {
+        fn is_missing(&self) -> bool {
+            false
+        }
+    }
+
+    impl MissingValue for NaiveDateTime {
+        fn is_missing(&self) -> bool {
+            false
+        }
+    }
+
+    #[allow(deprecated)]
+    impl<Z: TimeZone> MissingValue for Date<Z> {
+        fn is_missing(&self) -> bool {
+            false
+        }
+    }
+
+    impl<Z: TimeZone> MissingValue for DateTime<Z> {
+        fn is_missing(&self) -> bool {
+            false
+        }
+    }
+
+    impl MissingValue for Duration {
+        fn is_missing(&self) -> bool {
+            false
+        }
+    }
+}
+
+impl<X: MissingValue + Clone, Y: MissingValue> MissingValue for (X, Y) {
+    fn is_missing(&self) -> bool {
+        self.0.is_missing() || self.1.is_missing()
+    }
+
+    fn interpolate(&self, prev: &Self, next: &Self) -> Option<Self> {
+        if self.0.is_missing() {
+            return None;
+        }
+        let (x, x0, x1) = (
+            self.0.to_number()?,
+            prev.0.to_number()?,
+            next.0.to_number()?,
+        );
+        let (y0, y1) = (prev.1.to_number()?, next.1.to_number()?);
+        let t = if x1 == x0 { 0.5 } else { (x - x0) / (x1 - x0) };
+        Some((self.0.clone(), Y::from_number(y0 + (y1 - y0) * t)?))
+    }
+}
+
+impl<X: MissingValue, Y: MissingValue, Z: MissingValue> MissingValue for (X, Y, Z) {
+    fn is_missing(&self) -> bool {
+        self.0.is_missing() || self.1.is_missing() || self.2.is_missing()
+    }
+}
+
+/// Turn the missing points into `None`, or into the interpolated points if the policy is to
+/// interpolate
+pub(crate) fn mark_missing<C: MissingValue>(
+    data: Vec<Option<C>>,
+    policy: GapPolicy,
+) -> Vec<Option<C>> {
+    let present: Vec<bool> = data
+        .iter()
+        .map(|point| match point {
+            Some(point) => !point.is_missing(),
+            None => false,
+        })
+        .collect();
+    let mut filled: Vec<Option<C>> = data.iter().map(|_| None).collect();
+    if policy == GapPolicy::Interpolate {
+        let mut prev = vec![None; data.len()];
+        for idx in 1..data.len() {
+            prev[idx] = if present[idx - 1] {
+                Some(idx - 1)
+            } else {
+                prev[idx - 1]
+            };
+        }
+        let mut next = None;
+        for idx in (0..data.len()).rev() {
+            if !present[idx] {
+                if let (Some(point), Some(p), Some(n)) = (&data[idx], prev[idx], next) {
+                    if let (Some(a), Some(b)) = (&data[p], &data[n]) {
+                        filled[idx] = point.interpolate(a, b);
+                    }
+                }
+            } else {
+                next = Some(idx);
+            }
+        }
+    }
+    data.into_iter()
+        .zip(present)
+        .zip(filled)
+        .map(|((point, present), filled)| if present { point } else { filled })
+        .collect()
+}
+
+/// Split the points into the segments that are drawn, the `None` points are either gaps or
+/// dropped
+pub(crate) fn split_segments<C>(data: Vec<Option<C>>, policy: GapPolicy) -> Vec<Vec<C>> {
+    let mut segments = vec![];
+    let mut current = vec![];
+    for point in data {
+        match point {
+            Some(point) => current.push(point),
+            None if policy == GapPolicy::Gap && !current.is_empty() => {
+                let mut segment = vec![];
+                std::mem::swap(&mut current, &mut segment);
+                segments.push(segment);
+            }
+            None => {}
+        }
+    }
+    if !current.is_empty() || segments.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gap_policy() {
+        let nan = std::f64::NAN;
+        let data = vec![
+            Some((0.0, 0.0)),
+            Some((1.0, nan)),
+            Some((2.0, 2.0)),
+            None,
+            Some((4.0, 4.0)),
+            Some((5.0, nan)),
+        ];
+
+        let marked = mark_missing(data.clone(), GapPolicy::Gap);
+        assert_eq!(
+            split_segments(marked, GapPolicy::Gap),
+            vec![vec![(0.0, 0.0)], vec![(2.0, 2.0)], vec![(4.0, 4.0)]]
+        );
+        let marked = mark_missing(data.clone(), GapPolicy::Skip);
+        assert_eq!(
+            split_segments(marked, GapPolicy::Skip),
+            vec![vec![(0.0, 0.0), (2.0, 2.0), (4.0, 4.0)]]
+        );
+        // The last point has no point after it, thus it's dropped
+        let marked = mark_missing(data, GapPolicy::Interpolate);
+        assert_eq!(
+            split_segments(marked, GapPolicy::Interpolate),
+            vec![vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (4.0, 4.0)]]
+        );
+
+        // The numeric X coordinates of any type are interpolated, the integers are rounded
+        let marked = mark_missing(
+            vec![Some((0, 0.0)), Some((1, nan)), Some((4, 4.0))],
+            GapPolicy::Interpolate,
+        );
+        assert_eq!(marked[1], Some((1, 1.0)));
+        assert_eq!((1, 0).interpolate(&(0, 0), &(3, 2)), Some((1, 1)));
+
+        assert_eq!(
+            split_segments::<i32>(vec![], GapPolicy::Gap),
+            vec![Vec::<i32>::new()]
+        );
+    }
+}
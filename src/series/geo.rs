@@ -0,0 +1,146 @@
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::{Color, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/// The geometry of a map feature in `(longitude, latitude)` degrees, which follows the geometry
+/// objects of GeoJSON
+#[derive(Clone, Debug, PartialEq)]
+pub enum Geometry {
+    /// A polyline
+    LineString(Vec<(f64, f64)>),
+    /// A set of polylines
+    MultiLineString(Vec<Vec<(f64, f64)>>),
+    /// A polygon made of the outer ring and the rings of the holes
+    Polygon(Vec<Vec<(f64, f64)>>),
+    /// A set of polygons
+    MultiPolygon(Vec<Vec<Vec<(f64, f64)>>>),
+}
+
+/// The series that draws the map features, such as the coastlines and the borders, on a
+/// geographic chart built with
+/// [ChartBuilder::build_geo](../chart/struct.ChartBuilder.html#method.build_geo).
+///
+/// The lines and the rings of the polygons are stroked with the line style, and the polygons are
+/// filled when the fill style is set. The holes of the polygons, such as the lakes, are left
+/// unfilled.
+pub struct GeoSeries<DB: DrawingBackend> {
+    geometries: Vec<Geometry>,
+    style: ShapeStyle,
+    fill: Option<ShapeStyle>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> GeoSeries<DB> {
+    /// Create a new geographic series
+    /// - `geometries`: The geometries of the features
+    /// - `style`: The style of the lines and the outlines of the polygons
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = Geometry>, S: Into<ShapeStyle>>(
+        geometries: I,
+        style: S,
+    ) -> Self {
+        Self {
+            geometries: geometries.into_iter().collect(),
+            style: style.into(),
+            fill: None,
+            elements: None,
+        }
+    }
+
+    /// Fill the polygons with the color
+    pub fn fill<C: Color>(mut self, color: &C) -> Self {
+        self.fill = Some(color.filled());
+        self
+    }
+
+    fn add_polygon(
+        &self,
+        rings: &[Vec<(f64, f64)>],
+        elements: &mut Vec<DynElement<'static, DB, (f64, f64)>>,
+    ) {
+        if let Some(ref fill) = self.fill {
+            elements.push(Polygon::with_rings(rings.to_vec(), fill.clone()).into_dyn());
+        }
+        for ring in rings {
+            let mut border = ring.clone();
+            if border.first() != border.last() {
+                border.extend(ring.first().cloned());
+            }
+            elements.push(PathElement::new(border, self.style.clone()).into_dyn());
+        }
+    }
+
+    fn build_elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut elements = vec![];
+        for geometry in &self.geometries {
+            match geometry {
+                Geometry::LineString(line) => {
+                    elements.push(PathElement::new(line.clone(), self.style.clone()).into_dyn());
+                }
+                Geometry::MultiLineString(lines) => {
+                    for line in lines {
+                        elements
+                            .push(PathElement::new(line.clone(), self.style.clone()).into_dyn());
+                    }
+                }
+                Geometry::Polygon(rings) => self.add_polygon(rings, &mut elements),
+                Geometry::MultiPolygon(polygons) => {
+                    for rings in polygons {
+                        self.add_polygon(rings, &mut elements);
+                    }
+                }
+            }
+        }
+        elements
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for GeoSeries<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(Iterator::next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_geo_series() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, GREEN.to_rgba());
+                // The parts of the fill are around the hole
+                assert!(path.iter().all(|p| p.0 >= 100 && p.0 <= 150 && p.1 >= 25));
+            });
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+            });
+            m.drop_check(|b| {
+                // The line, and the outer ring and the hole of the polygon
+                assert_eq!(b.num_draw_path_call, 3);
+                assert!(b.num_fill_polygon_call > 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo(Projection::Equirectangular, -180.0..180.0, -90.0..90.0)
+            .unwrap();
+        assert_eq!(chart.as_coord_spec().pixel_range(), ((0, 0), (200, 100)));
+        let features = vec![
+            Geometry::LineString(vec![(-180.0, 0.0), (0.0, 0.0)]),
+            Geometry::Polygon(vec![
+                vec![(0.0, 0.0), (90.0, 0.0), (90.0, 45.0)],
+                vec![(60.0, 10.0), (80.0, 10.0), (80.0, 30.0), (60.0, 10.0)],
+            ]),
+        ];
+        chart
+            .draw_series(GeoSeries::new(features, &BLACK).fill(&GREEN))
+            .unwrap();
+    }
+}
@@ -0,0 +1,425 @@
+use std::ops::Range;
+
+use crate::element::{Colorbar, Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ColorMap, Normalizer, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The formatter of the cell labels
+type CellFormatter<'a> = Box<dyn Fn(f64) -> String + 'a>;
+
+/// A single cell of a heatmap, which is a filled rectangle with an optional label in the center
+pub struct HeatmapCell<'a, X = f64, Y = f64> {
+    rect: [(X, Y); 2],
+    style: ShapeStyle,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'b, 'a, X, Y> PointCollection<'b, (X, Y)> for &'b HeatmapCell<'a, X, Y> {
+    type Point = &'b (X, Y);
+    type IntoIter = &'b [(X, Y)];
+    fn point_iter(self) -> &'b [(X, Y)] {
+        &self.rect
+    }
+}
+
+impl<'a, X, Y, DB: DrawingBackend> Drawable<DB> for HeatmapCell<'a, X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        let (ul, br) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        backend.draw_rect(ul, br, &self.style, true)?;
+        if let Some((ref text, ref style)) = self.label {
+            let center = ((ul.0 + br.0) / 2, (ul.1 + br.1) / 2);
+            backend.draw_text(
+                text,
+                &style.pos(Pos::new(HPos::Center, VPos::Center)),
+                center,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The series that draws a grid of values as colored cells, which fill the given area of the
+/// chart evenly. The first row of the grid is at the start of the Y range and the first column
+/// is at the start of the X range. The NaN values are left blank.
+///
+/// The cells can also be placed between the given edges with
+/// [HeatmapSeries::with_edges](#method.with_edges), which works with any coordinate type, such
+/// as the segmented and the categorical axes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let grid: Vec<Vec<f64>> = (0..10)
+///     .map(|y| (0..10).map(|x| ((x * y) as f64).sqrt()).collect())
+///     .collect();
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         HeatmapSeries::new(grid, 0.0..10.0, 0.0..10.0, ViridisRGB)
+///             .labels(("sans-serif", 10).into_font().color(&WHITE)),
+///     )
+///     .unwrap();
+/// ```
+pub struct HeatmapSeries<'a, M: ColorMap, X = f64, Y = f64> {
+    grid: Vec<Vec<f64>>,
+    x_edges: Vec<X>,
+    y_edges: Vec<Y>,
+    value_range: Range<f64>,
+    normalizer: Normalizer,
+    map: M,
+    label_style: Option<TextStyle<'a>>,
+    formatter: CellFormatter<'a>,
+    row: usize,
+    col: usize,
+}
+
+impl<'a, M: ColorMap> HeatmapSeries<'a, M> {
+    /// Create a new heatmap series
+    /// - `grid`: The rows of the values
+    /// - `x_range`: The X range that the columns fill
+    /// - `y_range`: The Y range that the rows fill
+    /// - `map`: The color map, the values are mapped from the smallest to the largest finite
+    ///   value in the grid
+    /// - **returns** The newly created series
+    pub fn new<G, R, T>(grid: G, x_range: Range<f64>, y_range: Range<f64>, map: M) -> Self
+    where
+        G: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<f64>,
+    {
+        let grid = collect_grid(grid);
+        let columns = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let rows = grid.len();
+        let split = |range: Range<f64>, count: usize| -> Vec<f64> {
+            let step = (range.end - range.start) / count as f64;
+            (0..=count).map(|i| range.start + step * i as f64).collect()
+        };
+        Self::from_grid(grid, split(x_range, columns), split(y_range, rows), map)
+    }
+
+    /// Create a new heatmap series from a 2D array, whose rows are the rows of the heatmap
+    /// - `grid`: The view of the values
+    /// - `x_range`: The X range that the columns fill
+    /// - `y_range`: The Y range that the rows fill
+    /// - `map`: The color map
+    /// - **returns** The newly created series
+    #[cfg(feature = "ndarray")]
+    pub fn from_array(
+        grid: ndarray::ArrayView2<f64>,
+        x_range: Range<f64>,
+        y_range: Range<f64>,
+        map: M,
+    ) -> Self {
+        Self::new(
+            grid.outer_iter()
+                .map(|row| row.iter().cloned().collect::<Vec<_>>()),
+            x_range,
+            y_range,
+            map,
+        )
+    }
+}
+
+/// Collect the rows of the values
+fn collect_grid<G, R, T>(grid: G) -> Vec<Vec<f64>>
+where
+    G: IntoIterator<Item = R>,
+    R: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    grid.into_iter()
+        .map(|row| row.into_iter().map(Into::into).collect())
+        .collect()
+}
+
+impl<'a, M: ColorMap, X, Y> HeatmapSeries<'a, M, X, Y> {
+    /// Create a new heatmap series whose cells are between the given edges, the cell in the
+    /// row `r` and the column `c` spans from `x_edges[c]` to `x_edges[c + 1]` and from
+    /// `y_edges[r]` to `y_edges[r + 1]`. The values without the edges are skipped.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let grid = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d((0..3).into_segmented(), (0..2).into_segmented())
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(HeatmapSeries::with_edges(
+    ///         grid,
+    ///         (0..=3).map(SegmentValue::Exact),
+    ///         (0..=2).map(SegmentValue::Exact),
+    ///         ViridisRGB,
+    ///     ))
+    ///     .unwrap();
+    /// ```
+    /// - `grid`: The rows of the values
+    /// - `x_edges`: The edges of the columns in the X coordinate
+    /// - `y_edges`: The edges of the rows in the Y coordinate
+    /// - `map`: The color map, the values are mapped from the smallest to the largest finite
+    ///   value in the grid
+    /// - **returns** The newly created series
+    pub fn with_edges<G, R, T, XE, YE>(grid: G, x_edges: XE, y_edges: YE, map: M) -> Self
+    where
+        G: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<f64>,
+        XE: IntoIterator<Item = X>,
+        YE: IntoIterator<Item = Y>,
+    {
+        Self::from_grid(
+            collect_grid(grid),
+            x_edges.into_iter().collect(),
+            y_edges.into_iter().collect(),
+            map,
+        )
+    }
+
+    fn from_grid(grid: Vec<Vec<f64>>, x_edges: Vec<X>, y_edges: Vec<Y>, map: M) -> Self {
+        let mut finite = grid.iter().flatten().cloned().filter(|v| v.is_finite());
+        let value_range = match finite.next() {
+            Some(first) => finite.fold(first..first, |r, v| r.start.min(v)..r.end.max(v)),
+            None => 0.0..0.0,
+        };
+        Self {
+            grid,
+            x_edges,
+            y_edges,
+            value_range,
+            normalizer: Normalizer::Linear,
+            map,
+            label_style: None,
+            formatter: Box::new(|v| format!("{:.2}", v)),
+            row: 0,
+            col: 0,
+        }
+    }
+
+    /// Set the value range that is mapped onto the color map, the values out of the range get
+    /// the colors at the ends of the map
+    pub fn value_range(mut self, range: Range<f64>) -> Self {
+        self.value_range = range;
+        self
+    }
+
+    /// Set how the values are mapped onto the color map, they're mapped linearly by default
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// // The values span several orders of magnitude
+    /// let grid: Vec<Vec<f64>> = (0..10)
+    ///     .map(|y| (0..10).map(|x| 10f64.powf((x + y) as f64 / 3.0)).collect())
+    ///     .collect();
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .colorbar_area_size(60)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    ///     .unwrap();
+    /// let heatmap =
+    ///     HeatmapSeries::new(grid, 0.0..10.0, 0.0..10.0, ViridisRGB).normalizer(Normalizer::Log);
+    /// chart.draw_colorbar(heatmap.colorbar()).unwrap();
+    /// chart.draw_series(heatmap).unwrap();
+    /// ```
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Create a colorbar that explains the colors of the cells, with the same color map, the
+    /// same value range and the same normalizer as the heatmap
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let grid = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .colorbar_area_size(60)
+    ///     .build_cartesian_2d(0.0..2.0, 0.0..2.0)
+    ///     .unwrap();
+    /// let heatmap = HeatmapSeries::new(grid, 0.0..2.0, 0.0..2.0, ViridisRGB);
+    /// chart.draw_colorbar(heatmap.colorbar()).unwrap();
+    /// chart.draw_series(heatmap).unwrap();
+    /// ```
+    pub fn colorbar(&self) -> Colorbar<'static, M>
+    where
+        M: Clone,
+    {
+        Colorbar::new(self.map.clone(), self.value_range.clone())
+            .normalizer(self.normalizer.clone())
+    }
+
+    /// Draw the value of each cell in the center of the cell
+    /// - `style`: The style of the labels
+    pub fn labels<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Set the formatter of the cell labels, the values are formatted with two decimal places by
+    /// default
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, formatter: F) -> Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+}
+
+impl<'a, M: ColorMap, X: Clone, Y: Clone> Iterator for HeatmapSeries<'a, M, X, Y> {
+    type Item = HeatmapCell<'a, X, Y>;
+    fn next(&mut self) -> Option<HeatmapCell<'a, X, Y>> {
+        loop {
+            let row = self.grid.get(self.row)?;
+            if self.col >= row.len() {
+                self.row += 1;
+                self.col = 0;
+                continue;
+            }
+            let (r, c) = (self.row, self.col);
+            let value = row[c];
+            self.col += 1;
+            if value.is_nan() || c + 1 >= self.x_edges.len() || r + 1 >= self.y_edges.len() {
+                continue;
+            }
+
+            let (x0, x1) = (self.x_edges[c].clone(), self.x_edges[c + 1].clone());
+            let (y0, y1) = (self.y_edges[r].clone(), self.y_edges[r + 1].clone());
+            let (min, max) = (self.value_range.start, self.value_range.end);
+            let color = self
+                .map
+                .get_color(self.normalizer.normalize(value, min, max));
+            let label = self
+                .label_style
+                .as_ref()
+                .map(|style| ((self.formatter)(value), style.clone()));
+
+            return Some(HeatmapCell {
+                rect: [(x0, y0), (x1, y1)],
+                style: color.filled(),
+                label,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_heatmap_series() {
+        let grid = vec![vec![0.0, 1.0], vec![std::f64::NAN, 2.0]];
+        let cells: Vec<_> =
+            HeatmapSeries::new(grid.clone(), 0.0..4.0, 0.0..2.0, BlackWhite).collect();
+        assert_eq!(cells.len(), 3);
+        assert_eq!(cells[0].rect, [(0.0, 0.0), (2.0, 1.0)]);
+        assert_eq!(cells[2].rect, [(2.0, 1.0), (4.0, 2.0)]);
+        assert_eq!(cells[0].style.color, BLACK.to_rgba());
+        assert_eq!(cells[2].style.color, WHITE.to_rgba());
+
+        // The colorbar shares the value range, which is computed from the finite values
+        let heatmap = HeatmapSeries::new(grid.clone(), 0.0..4.0, 0.0..2.0, BlackWhite);
+        assert_eq!(heatmap.colorbar().range(), 0.0..2.0);
+        let heatmap = heatmap.value_range(-1.0..1.0);
+        assert_eq!(heatmap.colorbar().range(), -1.0..1.0);
+
+        // The values are normalized before they are mapped onto the color map
+        let cells: Vec<_> =
+            HeatmapSeries::new(vec![vec![1.0, 10.0, 100.0]], 0.0..3.0, 0.0..1.0, BlackWhite)
+                .normalizer(Normalizer::Log)
+                .collect();
+        assert_eq!(cells[1].style.color, BlackWhite.get_color(0.5).to_rgba());
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, _, _| {
+                assert!(filled);
+                assert_eq!(c, BLACK.to_rgba());
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "0");
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, 0.0..2.0)
+            .unwrap();
+        chart
+            .draw_series(
+                HeatmapSeries::new(grid, 0.0..4.0, 0.0..2.0, BlackWhite)
+                    .value_range(0.0..2.0)
+                    .labels(("sans-serif", 10))
+                    .label_formatter(|v| format!("{}", v)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_heatmap_with_edges() {
+        let grid = vec![vec![0.0, 1.0, 2.0], vec![3.0, 4.0]];
+        let cells: Vec<_> =
+            HeatmapSeries::with_edges(grid.clone(), vec![0, 1, 2], vec!["a", "b", "c"], BlackWhite)
+                .collect();
+        // The third column has no right edge
+        assert_eq!(cells.len(), 4);
+        assert_eq!(cells[3].rect, [(1, "b"), (2, "c")]);
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The first cell covers the first of the three segments on both axes
+            m.check_draw_rect(|_, _, _, ul, br| {
+                assert_eq!((ul, br), ((0, 132), (66, 199)));
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 4));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).into_segmented(), (0..2).into_segmented())
+            .unwrap();
+        chart
+            .draw_series(HeatmapSeries::with_edges(
+                grid,
+                (0..=2).map(SegmentValue::Exact),
+                (0..=2).map(SegmentValue::Exact),
+                BlackWhite,
+            ))
+            .unwrap();
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_heatmap_from_array() {
+        let grid = ndarray::arr2(&[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let cells: Vec<_> =
+            HeatmapSeries::from_array(grid.view(), 0.0..3.0, 0.0..2.0, BlackWhite).collect();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[2].rect, [(2.0, 0.0), (3.0, 1.0)]);
+        assert_eq!(cells[5].style.color, WHITE.to_rgba());
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use crate::element::{Polygon, Rectangle};
+use crate::style::{Color, ColorMap, ShapeStyle};
+use num_traits::ToPrimitive;
+
+/// How the bin counts are scaled before they are mapped onto the color map
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CountScale {
+    /// The color is proportional to the count
+    Linear,
+    /// The color is proportional to the logarithm of the count, which keeps the sparse bins
+    /// visible next to very dense ones
+    Log,
+}
+
+impl CountScale {
+    fn normalize(self, count: usize, max: usize) -> f64 {
+        if max <= 1 {
+            return 1.0;
+        }
+        match self {
+            CountScale::Linear => count as f64 / max as f64,
+            CountScale::Log => (count as f64).ln() / (max as f64).ln(),
+        }
+    }
+}
+
+/// The bins with their counts
+type BinCounts = Vec<((i64, i64), usize)>;
+
+/// Count the points in each bin, the bins are sorted so the drawing order is stable
+fn count_bins<X, Y, I, F>(points: I, bin_of: F) -> (BinCounts, usize)
+where
+    X: ToPrimitive,
+    Y: ToPrimitive,
+    I: IntoIterator<Item = (X, Y)>,
+    F: Fn(f64, f64) -> (i64, i64),
+{
+    let mut bins: HashMap<(i64, i64), usize> = HashMap::new();
+    for (x, y) in points {
+        if let (Some(x), Some(y)) = (x.to_f64(), y.to_f64()) {
+            if x.is_finite() && y.is_finite() {
+                *bins.entry(bin_of(x, y)).or_default() += 1;
+            }
+        }
+    }
+    let max = bins.values().cloned().max().unwrap_or(0);
+    let mut bins: Vec<_> = bins.into_iter().collect();
+    bins.sort();
+    (bins, max)
+}
+
+/// The series that aggregates a point cloud into a grid of rectangular bins, and fills each
+/// non-empty bin with the color of its count. The bins are aligned to the multiples of the bin
+/// size.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let points: Vec<_> = (0..1000).map(|i| ((i % 37) as f64, (i % 23) as f64)).collect();
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..40.0, 0.0..25.0)
+///     .unwrap();
+/// chart
+///     .draw_series(Histogram2D::new(points, (5.0, 5.0), ViridisRGB))
+///     .unwrap();
+/// ```
+pub struct Histogram2D<M: ColorMap> {
+    bins: std::vec::IntoIter<((i64, i64), usize)>,
+    max: usize,
+    bin_size: (f64, f64),
+    map: M,
+    scale: CountScale,
+}
+
+impl<M: ColorMap> Histogram2D<M> {
+    /// Create a new 2D histogram series
+    /// - `points`: The points to aggregate, the points with a NaN or infinite coordinate are
+    ///   ignored
+    /// - `bin_size`: The width and height of the bins
+    /// - `map`: The color map for the counts
+    /// - **returns** The newly created series
+    pub fn new<X: ToPrimitive, Y: ToPrimitive, I: IntoIterator<Item = (X, Y)>>(
+        points: I,
+        bin_size: (f64, f64),
+        map: M,
+    ) -> Self {
+        let (w, h) = bin_size;
+        let (bins, max) = count_bins(points, |x, y| {
+            ((x / w).floor() as i64, (y / h).floor() as i64)
+        });
+        Self {
+            bins: bins.into_iter(),
+            max,
+            bin_size,
+            map,
+            scale: CountScale::Linear,
+        }
+    }
+
+    /// Set how the counts are scaled before the color mapping
+    pub fn scale(mut self, scale: CountScale) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+impl<M: ColorMap> Iterator for Histogram2D<M> {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((i, j), count) = self.bins.next()?;
+        let (w, h) = self.bin_size;
+        let (x0, y0) = (i as f64 * w, j as f64 * h);
+        let style: ShapeStyle = self
+            .map
+            .get_color(self.scale.normalize(count, self.max))
+            .filled();
+        Some(Rectangle::new([(x0, y0), (x0 + w, y0 + h)], style))
+    }
+}
+
+/// The vertical distance between the center and the top vertex of a hexagon, in the unit of the
+/// row spacing
+const HEX_VERTEX: f64 = 2.0 / 3.0;
+
+/// The series that aggregates a point cloud into hexagonal bins, and fills each non-empty bin
+/// with the color of its count.
+///
+/// The hexagons have a vertex on the top. The bin size is the horizontal distance between the
+/// neighbouring hexagons of a row and the vertical distance between the rows, the rows are
+/// shifted by the half of the width alternately. A hexagon is regular on the screen if the
+/// height in pixels is √3/2 of the width in pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let points: Vec<_> = (0..1000).map(|i| ((i % 37) as f64, (i % 23) as f64)).collect();
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..40.0, 0.0..25.0)
+///     .unwrap();
+/// chart
+///     .draw_series(Hexbin::new(points, (4.0, 3.0), ViridisRGB).scale(CountScale::Log))
+///     .unwrap();
+/// ```
+pub struct Hexbin<M: ColorMap> {
+    bins: std::vec::IntoIter<((i64, i64), usize)>,
+    max: usize,
+    bin_size: (f64, f64),
+    map: M,
+    scale: CountScale,
+}
+
+/// Get the horizontal shift of a row of hexagons, in the unit of the hexagon width
+fn row_shift(row: i64) -> f64 {
+    if row % 2 == 0 {
+        0.0
+    } else {
+        0.5
+    }
+}
+
+/// Find the hexagon that contains the point, which is given in the unit of the bin size
+fn hexagon_of(u: f64, v: f64) -> (i64, i64) {
+    let row = v.round() as i64;
+    let candidates = (row - 1..=row + 1).map(|r| {
+        let shift = row_shift(r);
+        let col = (u - shift).round() as i64;
+        let (du, dv) = (u - col as f64 - shift, v - r as f64);
+        // The distance with the aspect ratio of a regular hexagon
+        ((col, r), du * du + dv * dv * 0.75)
+    });
+    candidates
+        .fold(None, |best: Option<((i64, i64), f64)>, c| match best {
+            Some(b) if b.1 <= c.1 => Some(b),
+            _ => Some(c),
+        })
+        .map_or((0, row), |b| b.0)
+}
+
+impl<M: ColorMap> Hexbin<M> {
+    /// Create a new hexbin series
+    /// - `points`: The points to aggregate, the points with a NaN or infinite coordinate are
+    ///   ignored
+    /// - `bin_size`: The width of the hexagons and the distance of the rows
+    /// - `map`: The color map for the counts
+    /// - **returns** The newly created series
+    pub fn new<X: ToPrimitive, Y: ToPrimitive, I: IntoIterator<Item = (X, Y)>>(
+        points: I,
+        bin_size: (f64, f64),
+        map: M,
+    ) -> Self {
+        let (w, h) = bin_size;
+        let (bins, max) = count_bins(points, |x, y| hexagon_of(x / w, y / h));
+        Self {
+            bins: bins.into_iter(),
+            max,
+            bin_size,
+            map,
+            scale: CountScale::Linear,
+        }
+    }
+
+    /// Set how the counts are scaled before the color mapping
+    pub fn scale(mut self, scale: CountScale) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+impl<M: ColorMap> Iterator for Hexbin<M> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((col, row), count) = self.bins.next()?;
+        let (w, h) = self.bin_size;
+        let (cu, cv) = (col as f64 + row_shift(row), row as f64);
+        let vertices: Vec<_> = [
+            (0.0, HEX_VERTEX),
+            (0.5, HEX_VERTEX / 2.0),
+            (0.5, -HEX_VERTEX / 2.0),
+            (0.0, -HEX_VERTEX),
+            (-0.5, -HEX_VERTEX / 2.0),
+            (-0.5, HEX_VERTEX / 2.0),
+        ]
+        .iter()
+        .map(|(du, dv)| ((cu + du) * w, (cv + dv) * h))
+        .collect();
+        let style: ShapeStyle = self
+            .map
+            .get_color(self.scale.normalize(count, self.max))
+            .filled();
+        Some(Polygon::new(vertices, style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_histogram_2d() {
+        let points = vec![
+            (0.5, 0.5),
+            (0.7, 0.2),
+            (1.5, 0.5),
+            (-0.5, 0.5),
+            (std::f64::NAN, 0.0),
+        ];
+        let bins: Vec<_> = Histogram2D::new(points, (1.0, 1.0), BlackWhite).collect();
+        assert_eq!(bins.len(), 3);
+        let corners: Vec<_> = bins.iter().map(|r| r.point_iter().to_vec()).collect();
+        assert_eq!(corners[0], vec![(-1.0, 0.0), (0.0, 1.0)]);
+        assert_eq!(corners[1], vec![(0.0, 0.0), (1.0, 1.0)]);
+        assert_eq!(corners[2], vec![(1.0, 0.0), (2.0, 1.0)]);
+
+        assert_eq!(CountScale::Linear.normalize(5, 10), 0.5);
+        assert_eq!(CountScale::Log.normalize(1, 10), 0.0);
+        assert_eq!(CountScale::Log.normalize(10, 10), 1.0);
+    }
+
+    #[test]
+    fn test_hexbin() {
+        assert_eq!(hexagon_of(0.1, 0.1), (0, 0));
+        assert_eq!(hexagon_of(0.6, 1.1), (0, 1));
+        assert_eq!(hexagon_of(0.4, -0.9), (0, -1));
+        // Close to the top vertex of the hexagon (0, 0), but in the hexagon above it
+        assert_eq!(hexagon_of(0.45, 0.6), (0, 1));
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(path.len(), 6);
+                assert_eq!(c, WHITE.to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, BLACK.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+            .unwrap();
+        let points = vec![(0.1, 0.1), (-0.1, 0.0), (1.0, 0.1)];
+        chart
+            .draw_series(Hexbin::new(points, (1.0, 1.0), BlackWhite).scale(CountScale::Log))
+            .unwrap();
+    }
+}
@@ -1,12 +1,14 @@
 use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
 use std::marker::PhantomData;
-use std::ops::AddAssign;
+use std::ops::{AddAssign, Range};
 
 use crate::chart::ChartContext;
 use crate::coord::cartesian::Cartesian2d;
 use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::data::weighted_percentile;
 use crate::element::Rectangle;
 use crate::style::{Color, ShapeStyle, GREEN};
+use num_traits::ToPrimitive;
 use plotters_backend::DrawingBackend;
 
 pub trait HistogramType {}
@@ -17,6 +19,10 @@ impl HistogramType for Vertical {}
 impl HistogramType for Horizontal {}
 
 /// The series that aggregate data into a histogram
+///
+/// The values are counted in the segments of a discrete coordinate. For the events over time,
+/// [ZonedDateTime::buckets](../coord/types/struct.ZonedDateTime.html#method.buckets) makes the
+/// discrete coordinate of the minutes, hours, days, weeks or months in a time zone.
 pub struct Histogram<'a, BR, A, Tag = Vertical>
 where
     BR: DiscreteRanged,
@@ -180,3 +186,388 @@ where
         None
     }
 }
+
+/// The strategy that splits the range of the samples into the bins of a
+/// [BinnedHistogram](struct.BinnedHistogram.html)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binning {
+    /// `log2(n) + 1` bins of the same width, which suits the data close to a normal distribution
+    Sturges,
+    /// The bins of the width `2 * IQR / n^(1/3)`, which is robust to the outliers
+    FreedmanDiaconis,
+    /// The bins of the width `3.49 * σ / n^(1/3)`
+    Scott,
+    /// The bins of the given width, aligned to the multiples of the width
+    Width(f64),
+    /// The bins between the given edges, the samples out of the edges are ignored. The edges
+    /// are sorted in ascending order and the NaN edges are dropped.
+    Edges(Vec<f64>),
+}
+
+/// The maximum number of the bins computed from a width, a width that gives more bins than this
+/// falls back to the Sturges' rule
+const MAX_BIN_COUNT: usize = 10_000;
+
+/// The edges of the bins of the same width, which covers the range of the samples
+fn uniform_edges(min: f64, max: f64, count: usize) -> Vec<f64> {
+    let count = count.max(1);
+    let width = (max - min) / count as f64;
+    (0..=count).map(|i| min + width * i as f64).collect()
+}
+
+/// The edges of the bins for the weighted samples, which are sorted, finite and have positive
+/// weights. The number of the samples is the effective sample size of the weights, which is the
+/// actual number if all the weights are the same.
+fn compute_edges(sorted: &[(f64, f64)], binning: &Binning) -> Vec<f64> {
+    if let Binning::Edges(edges) = binning {
+        let mut edges: Vec<_> = edges.iter().cloned().filter(|e| !e.is_nan()).collect();
+        edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        return edges;
+    }
+    let (min, max) = match (sorted.first(), sorted.last()) {
+        (Some(min), Some(max)) => (min.0, max.0),
+        _ => return vec![],
+    };
+    // All the samples are in a single bin centered at the value
+    if min == max {
+        return vec![min - 0.5, max + 0.5];
+    }
+    let total = sorted.iter().map(|s| s.1).sum::<f64>();
+    let n = total * total / sorted.iter().map(|s| s.1 * s.1).sum::<f64>();
+    let sturges = (n.log2().ceil() as usize) + 1;
+    let width = match binning {
+        Binning::Sturges => return uniform_edges(min, max, sturges),
+        Binning::FreedmanDiaconis => {
+            2.0 * (weighted_percentile(sorted, 0.75) - weighted_percentile(sorted, 0.25)) / n.cbrt()
+        }
+        Binning::Scott => {
+            let mean = sorted.iter().map(|(v, w)| v * w).sum::<f64>() / total;
+            let variance = sorted
+                .iter()
+                .map(|(v, w)| (v - mean) * (v - mean) * w)
+                .sum::<f64>()
+                / total;
+            3.49 * variance.sqrt() / n.cbrt()
+        }
+        Binning::Width(width) => {
+            if *width > 0.0 {
+                let start = (min / width).floor() * width;
+                let count = ((max - start) / width).floor() + 1.0;
+                if count <= MAX_BIN_COUNT as f64 {
+                    return (0..=count as usize)
+                        .map(|i| start + width * i as f64)
+                        .collect();
+                }
+            }
+            0.0
+        }
+        Binning::Edges(_) => unreachable!(),
+    };
+    // The width is zero when most of the samples are the same value
+    let count = ((max - min) / width).ceil();
+    if width > 0.0 && count <= MAX_BIN_COUNT as f64 {
+        uniform_edges(min, max, count as usize)
+    } else {
+        uniform_edges(min, max, sturges)
+    }
+}
+
+/// Get the index of the bin that the value falls in
+fn bin_of(edges: &[f64], value: f64) -> Option<usize> {
+    match edges.binary_search_by(|e| e.partial_cmp(&value).unwrap()) {
+        Ok(idx) if idx + 1 < edges.len() => Some(idx),
+        // The right edge of the last bin is included
+        Ok(idx) if idx > 0 => Some(idx - 1),
+        Err(idx) if idx > 0 && idx < edges.len() => Some(idx - 1),
+        _ => None,
+    }
+}
+
+/// The histogram of the raw samples, which computes the bins with a
+/// [Binning](enum.Binning.html) strategy and counts the samples of each bin. The edges and the
+/// counts are available before the series is drawn, thus they can be used to set up the ranges of
+/// the chart. Each bin includes its left edge, and the last bin also includes its right edge.
+///
+/// The histogram of the weighted samples, which is created with
+/// [weighted](#method.weighted), sums the weights of each bin instead, thus the counts are
+/// `f64`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let samples: Vec<f64> = (0..1000).map(|i| ((i * 37 % 101) as f64).sqrt()).collect();
+/// let histogram = BinnedHistogram::new(samples, Binning::FreedmanDiaconis).style(BLUE.filled());
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(histogram.x_range(), 0..histogram.max_count() + 10)
+///     .unwrap();
+/// chart.draw_series(histogram).unwrap();
+/// ```
+pub struct BinnedHistogram<C = u32> {
+    edges: Vec<f64>,
+    counts: Vec<C>,
+    style: ShapeStyle,
+    margin: u32,
+    idx: usize,
+}
+
+/// Sort the weighted samples, the samples that are not finite or have no positive weight are
+/// ignored
+fn sorted_weighted<T: ToPrimitive, I: IntoIterator<Item = (T, f64)>>(
+    samples: I,
+) -> Vec<(f64, f64)> {
+    let mut sorted: Vec<(f64, f64)> = samples
+        .into_iter()
+        .filter_map(|(v, w)| v.to_f64().map(|v| (v, w)))
+        .filter(|(v, w)| v.is_finite() && *w > 0.0 && w.is_finite())
+        .collect();
+    sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    sorted
+}
+
+impl BinnedHistogram<u32> {
+    /// Compute the bins of the samples, the samples that are NaN or infinite are ignored
+    ///
+    /// - `samples`: The raw samples
+    /// - `binning`: The strategy that computes the edges of the bins
+    /// - **returns** The histogram series
+    pub fn new<T: ToPrimitive, I: IntoIterator<Item = T>>(samples: I, binning: Binning) -> Self {
+        let sorted = sorted_weighted(samples.into_iter().map(|v| (v, 1.0)));
+        let edges = compute_edges(&sorted, &binning);
+        let mut counts = vec![0; edges.len().saturating_sub(1)];
+        for (value, _) in sorted {
+            if let Some(idx) = bin_of(&edges, value) {
+                counts[idx] += 1;
+            }
+        }
+        Self::with_counts(edges, counts)
+    }
+
+    /// Get the largest count of the bins
+    pub fn max_count(&self) -> u32 {
+        self.counts.iter().cloned().max().unwrap_or(0)
+    }
+}
+
+impl BinnedHistogram<f64> {
+    /// Compute the bins of the weighted samples, such as the survey weights or the counts of the
+    /// pre-aggregated values, and sum the weights of each bin. The samples that are not finite
+    /// or have no positive weight are ignored.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let counts = vec![(1.0, 120.0), (2.0, 340.0), (3.0, 90.0), (4.0, 15.0)];
+    /// let histogram = BinnedHistogram::weighted(counts, Binning::Width(2.0));
+    /// assert_eq!(histogram.counts(), &[120.0, 430.0, 15.0][..]);
+    /// ```
+    ///
+    /// - `samples`: The samples and their weights
+    /// - `binning`: The strategy that computes the edges of the bins
+    /// - **returns** The histogram series
+    pub fn weighted<T: ToPrimitive, I: IntoIterator<Item = (T, f64)>>(
+        samples: I,
+        binning: Binning,
+    ) -> Self {
+        let sorted = sorted_weighted(samples);
+        let edges = compute_edges(&sorted, &binning);
+        let mut counts = vec![0.0; edges.len().saturating_sub(1)];
+        for (value, weight) in sorted {
+            if let Some(idx) = bin_of(&edges, value) {
+                counts[idx] += weight;
+            }
+        }
+        Self::with_counts(edges, counts)
+    }
+
+    /// Get the largest sum of the weights of the bins
+    pub fn max_count(&self) -> f64 {
+        self.counts.iter().cloned().fold(0.0, f64::max)
+    }
+}
+
+impl<C: Copy> BinnedHistogram<C> {
+    fn with_counts(edges: Vec<f64>, counts: Vec<C>) -> Self {
+        Self {
+            edges,
+            counts,
+            style: GREEN.filled(),
+            margin: 0,
+            idx: 0,
+        }
+    }
+
+    /// Set the style of the bars
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the margin of each bar in pixels
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /// Get the edges of the bins, which has one more element than the bins
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// Get the number of the samples in each bin, or the sum of the weights for the weighted
+    /// samples
+    pub fn counts(&self) -> &[C] {
+        &self.counts
+    }
+
+    /// Get the range from the first edge to the last edge
+    pub fn x_range(&self) -> Range<f64> {
+        match (self.edges.first(), self.edges.last()) {
+            (Some(first), Some(last)) => *first..*last,
+            _ => 0.0..1.0,
+        }
+    }
+}
+
+impl<C: Copy + Default> Iterator for BinnedHistogram<C> {
+    type Item = Rectangle<(f64, C)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.idx;
+        let count = *self.counts.get(idx)?;
+        self.idx += 1;
+        let mut rect = Rectangle::new(
+            [
+                (self.edges[idx], count),
+                (self.edges[idx + 1], C::default()),
+            ],
+            self.style.clone(),
+        );
+        rect.set_margin(0, 0, self.margin, self.margin);
+        Some(rect)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_binning() {
+        let samples: Vec<_> = (0..16).map(f64::from).collect();
+        let histogram = BinnedHistogram::new(samples.clone(), Binning::Sturges);
+        assert_eq!(histogram.edges().len(), 6);
+        assert_eq!(histogram.counts(), &[3, 3, 3, 3, 4][..]);
+        assert_eq!(histogram.x_range(), 0.0..15.0);
+
+        let histogram = BinnedHistogram::new(samples.clone(), Binning::Width(4.0));
+        assert_eq!(histogram.edges(), &[0.0, 4.0, 8.0, 12.0, 16.0][..]);
+        assert_eq!(histogram.counts(), &[4, 4, 4, 4][..]);
+        assert_eq!(histogram.max_count(), 4);
+
+        let histogram = BinnedHistogram::new(samples.clone(), Binning::Edges(vec![2.0, 5.0, 6.0]));
+        assert_eq!(histogram.counts(), &[3, 2][..]);
+        let histogram = BinnedHistogram::new(
+            samples.clone(),
+            Binning::Edges(vec![6.0, std::f64::NAN, 2.0, 5.0]),
+        );
+        assert_eq!(histogram.edges(), &[2.0, 5.0, 6.0][..]);
+        assert_eq!(histogram.counts(), &[3, 2][..]);
+
+        // The tiny widths fall back to the Sturges' rule
+        let histogram = BinnedHistogram::new(samples.clone(), Binning::Width(1e-9));
+        assert_eq!(histogram.edges().len(), 6);
+        let mut outlier = vec![0.0; 8];
+        outlier.extend(vec![1.0; 8]);
+        outlier.push(1e12);
+        let histogram = BinnedHistogram::new(outlier, Binning::FreedmanDiaconis);
+        assert_eq!(histogram.edges().len(), 7);
+
+        // IQR = 7.5, thus the width is 15 / 16^(1/3), about 5.95
+        let histogram = BinnedHistogram::new(samples.clone(), Binning::FreedmanDiaconis);
+        assert_eq!(histogram.counts().len(), 3);
+        let histogram = BinnedHistogram::new(samples, Binning::Scott);
+        assert_eq!(histogram.counts().iter().sum::<u32>(), 16);
+
+        let histogram = BinnedHistogram::new(vec![1, 1, 1], Binning::FreedmanDiaconis);
+        assert_eq!(histogram.edges(), &[0.5, 1.5][..]);
+        assert_eq!(histogram.counts(), &[3][..]);
+        assert_eq!(
+            BinnedHistogram::new(Vec::<f64>::new(), Binning::Scott).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_weighted_binning() {
+        // The same weights give the same edges as the raw samples
+        let samples: Vec<_> = (0..16).map(f64::from).collect();
+        for binning in vec![Binning::Sturges, Binning::FreedmanDiaconis, Binning::Scott] {
+            let raw = BinnedHistogram::new(samples.clone(), binning.clone());
+            let weighted =
+                BinnedHistogram::weighted(samples.iter().map(|v| (*v, 2.0)), binning.clone());
+            assert_eq!(raw.edges(), weighted.edges());
+            let doubled: Vec<_> = raw.counts().iter().map(|c| f64::from(*c) * 2.0).collect();
+            assert_eq!(weighted.counts(), &doubled[..]);
+        }
+
+        let histogram = BinnedHistogram::weighted(
+            vec![(0.5, 3.0), (1.5, 0.5), (1.0, -1.0), (std::f64::NAN, 1.0)],
+            Binning::Edges(vec![0.0, 1.0, 2.0]),
+        );
+        assert_eq!(histogram.counts(), &[3.0, 0.5][..]);
+        assert_eq!(histogram.max_count(), 3.0);
+        let bars: Vec<_> = histogram.collect();
+        assert_eq!(bars.len(), 2);
+    }
+
+    #[test]
+    fn test_binned_histogram() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, fill, u, d| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(fill);
+                assert_eq!(d.1, 99);
+                assert!(u.0 < d.0);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 3));
+        });
+        let histogram = BinnedHistogram::new(vec![0.0, 0.5, 1.5, 2.0], Binning::Width(1.0))
+            .style(BLUE.filled());
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(histogram.x_range(), 0..histogram.max_count())
+            .unwrap();
+        assert_eq!(histogram.counts(), &[2, 1, 1][..]);
+        chart.draw_series(histogram).unwrap();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetime_histogram() {
+        use chrono::{TimeZone, Utc};
+
+        let at = |d, h| Utc.with_ymd_and_hms(2020, 1, d, h, 0, 0).unwrap();
+        let drawing_area = create_mocked_drawing_area(300, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(
+                (at(1, 12)..at(3, 12))
+                    .with_timezone(Utc)
+                    .buckets(TimeBucket::Day),
+                0..5,
+            )
+            .unwrap();
+        let histogram = Histogram::vertical(&chart).data(
+            vec![at(1, 1), at(1, 23), at(3, 0), at(3, 20)]
+                .into_iter()
+                .map(|t| (t, 1)),
+        );
+        let bars: Vec<_> = histogram.collect();
+        // The buckets are the whole days, and the day without events has no bar
+        assert_eq!(bars.len(), 2);
+        chart.draw_series(bars).unwrap();
+    }
+}
@@ -1,15 +1,55 @@
-use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
+use super::gap::{mark_missing, split_segments, GapPolicy, MissingValue};
+use super::spline::{spline_points, SplineKind, DEFAULT_RESOLUTION};
+use crate::data::decimate_columns;
+use crate::element::{Circle, Drawable, DynElement, IntoDynElement, PathElement, PointCollection};
 use crate::style::ShapeStyle;
-use plotters_backend::DrawingBackend;
+use num_traits::{NumCast, ToPrimitive};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use std::marker::PhantomData;
 
+/// The function that turns a segment of the line into a smooth curve
+type Smoothing<Coord> = Box<dyn Fn(&[Coord]) -> Vec<Coord>>;
+
+/// The line of a line series, which draws only the first, the lowest, the highest and the last
+/// point of each pixel column when the line has far more points than the pixel columns
+struct DecimatedPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a DecimatedPath<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for DecimatedPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        (width, _): (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.points.len() > width as usize * 4 {
+            return self.style.stroke_path(backend, decimate_columns(points));
+        }
+        self.style.stroke_path(backend, points)
+    }
+}
+
 /// The line series object, which takes an iterator of points in guest coordinate system
 /// and creates the element rendering the line plot
 pub struct LineSeries<DB: DrawingBackend, Coord> {
     style: ShapeStyle,
-    data: Vec<Coord>,
+    data: Vec<Option<Coord>>,
+    segments: Vec<Vec<Coord>>,
+    gap_policy: GapPolicy,
+    smoothing: Option<Smoothing<Coord>>,
     point_idx: usize,
     point_size: u32,
+    downsample: bool,
     phantom: PhantomData<DB>,
 }
 
@@ -17,30 +57,57 @@ impl<DB: DrawingBackend, Coord: Clone + 'static> Iterator for LineSeries<DB, Coo
     type Item = DynElement<'static, DB, Coord>;
     fn next(&mut self) -> Option<Self::Item> {
         if !self.data.is_empty() {
-            if self.point_size > 0 && self.point_idx < self.data.len() {
+            while self.point_size > 0 && self.point_idx < self.data.len() {
                 let idx = self.point_idx;
                 self.point_idx += 1;
-                return Some(
-                    Circle::new(self.data[idx].clone(), self.point_size, self.style.clone())
-                        .into_dyn(),
-                );
+                if let Some(ref point) = self.data[idx] {
+                    return Some(
+                        Circle::new(point.clone(), self.point_size, self.style.clone()).into_dyn(),
+                    );
+                }
             }
             let mut data = vec![];
             std::mem::swap(&mut self.data, &mut data);
-            Some(PathElement::new(data, self.style.clone()).into_dyn())
-        } else {
-            None
+            self.segments = split_segments(data, self.gap_policy);
+            self.segments.reverse();
+        }
+        let mut points = self.segments.pop()?;
+        if let Some(ref smoothing) = self.smoothing {
+            points = smoothing(&points);
         }
+        if self.downsample {
+            return Some(
+                DecimatedPath {
+                    points,
+                    style: self.style.clone(),
+                }
+                .into_dyn(),
+            );
+        }
+        Some(PathElement::new(points, self.style.clone()).into_dyn())
     }
 }
 
 impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
     pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self::with_missing(iter.into_iter().map(Some), style)
+    }
+
+    /// Create a new line series, whose `None` points are missing, which are drawn as the gaps
+    /// of the line by default, see [gap_policy](#method.gap_policy)
+    pub fn with_missing<I: IntoIterator<Item = Option<Coord>>, S: Into<ShapeStyle>>(
+        iter: I,
+        style: S,
+    ) -> Self {
         Self {
             style: style.into(),
             data: iter.into_iter().collect(),
+            segments: vec![],
+            gap_policy: GapPolicy::Gap,
+            smoothing: None,
             point_size: 0,
             point_idx: 0,
+            downsample: true,
             phantom: PhantomData,
         }
     }
@@ -49,6 +116,69 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
         self.point_size = size;
         self
     }
+
+    /// Set if the line is downsampled when it has far more points than the pixel columns of the
+    /// plotting area, which is on by default. The points of each pixel column are reduced to the
+    /// first, the lowest, the highest and the last of them, which draws the same pixels. See
+    /// [Downsample](../data/struct.Downsample.html) for downsampling the data itself.
+    pub fn downsample(mut self, enabled: bool) -> Self {
+        self.downsample = enabled;
+        self
+    }
+}
+
+impl<DB, X, Y> LineSeries<DB, (X, Y)>
+where
+    DB: DrawingBackend,
+    X: Clone + ToPrimitive + NumCast + 'static,
+    Y: Clone + ToPrimitive + NumCast + 'static,
+{
+    /// Set if the line is drawn as a smooth curve through the points, which is the monotone
+    /// cubic interpolation that doesn't overshoot the data. See
+    /// [SplineSeries](struct.SplineSeries.html) for the other kinds of the curve and the
+    /// resolution.
+    pub fn smooth(mut self, enabled: bool) -> Self {
+        self.smoothing = if enabled {
+            Some(Box::new(|points: &[(X, Y)]| {
+                spline_points(points, SplineKind::Monotone, DEFAULT_RESOLUTION)
+            }))
+        } else {
+            None
+        };
+        self
+    }
+}
+
+impl<DB: DrawingBackend, Coord: MissingValue> LineSeries<DB, Coord> {
+    /// Set what the line does at the missing points, which are the points with a NaN coordinate
+    /// and the `None` points of [with_missing](#method.with_missing). The NaN points are only
+    /// detected when the policy is set, thus a sensor dropout is drawn as a gap with
+    /// `GapPolicy::Gap`, without splitting the series.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let data = (0..100).map(|x| {
+    ///     let y = if x % 30 < 5 { std::f64::NAN } else { (x as f64 / 10.0).sin() };
+    ///     (x as f64, y)
+    /// });
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..100.0, -1.0..1.0)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(LineSeries::new(data, &RED).gap_policy(GapPolicy::Gap))
+    ///     .unwrap();
+    /// ```
+    pub fn gap_policy(mut self, policy: GapPolicy) -> Self {
+        let mut data = vec![];
+        std::mem::swap(&mut self.data, &mut data);
+        self.data = mark_missing(data, policy);
+        self.gap_policy = policy;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -83,4 +213,81 @@ mod test {
             ))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_line_series_downsample() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert!(path.len() <= 400);
+                assert!(path.contains(&(0, 99)) && path.contains(&(0, 0)));
+            });
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 10_000));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10_000, 0..2)
+            .unwrap();
+        let data: Vec<_> = (0..10_000).map(|x| (x, x % 2 * 2)).collect();
+        chart
+            .draw_series(LineSeries::new(data.clone(), &RED))
+            .unwrap();
+        chart
+            .draw_series(LineSeries::new(data, &RED).downsample(false))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_line_series_smooth() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 2 * 16 + 1));
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 3));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..2.0)
+            .unwrap();
+        let data = vec![(0.0, 0.0), (1.0, 2.0), (2.0, 1.0)];
+        chart
+            .draw_series(LineSeries::new(data.clone(), &RED).smooth(true))
+            .unwrap();
+        chart
+            .draw_series(LineSeries::new(data, &RED).smooth(true).smooth(false))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_line_series_gaps() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 2));
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 3));
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 6));
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 2));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 4);
+                assert_eq!(b.num_draw_circle_call, 5);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..6.0, 0.0..6.0)
+            .unwrap();
+        let data: Vec<_> = (0..6)
+            .map(|x| (x as f64, if x == 2 { std::f64::NAN } else { x as f64 }))
+            .collect();
+        chart
+            .draw_series(
+                LineSeries::new(data.clone(), &RED)
+                    .point_size(2)
+                    .gap_policy(GapPolicy::Gap),
+            )
+            .unwrap();
+        chart
+            .draw_series(LineSeries::new(data, &RED).gap_policy(GapPolicy::Interpolate))
+            .unwrap();
+        chart
+            .draw_series(
+                LineSeries::with_missing(vec![Some((0.0, 0.0)), None, Some((2.0, 2.0))], &RED)
+                    .gap_policy(GapPolicy::Skip),
+            )
+            .unwrap();
+    }
 }
@@ -12,22 +12,136 @@
 
 #[cfg(feature = "area_series")]
 mod area_series;
+#[cfg(feature = "area_series")]
+mod band;
+#[cfg(feature = "histogram")]
+mod bar;
+#[cfg(feature = "boxplot")]
+mod boxplot_series;
+#[cfg(feature = "point_series")]
+mod bubble;
+#[cfg(feature = "point_series")]
+mod color_scatter;
+#[cfg(feature = "contour_series")]
+mod contour;
+#[cfg(feature = "line_series")]
+mod ecdf;
+#[cfg(feature = "event_series")]
+mod event;
+#[cfg(feature = "area_series")]
+mod fill_between;
+#[cfg(any(feature = "line_series", feature = "area_series"))]
+mod gap;
+#[cfg(feature = "geo_series")]
+mod geo;
+#[cfg(feature = "heatmap_series")]
+mod heatmap;
+#[cfg(feature = "histogram")]
+mod hexbin;
 #[cfg(feature = "histogram")]
 mod histogram;
 #[cfg(feature = "line_series")]
 mod line_series;
 #[cfg(feature = "point_series")]
+mod point_label;
+#[cfg(feature = "point_series")]
 mod point_series;
+#[cfg(feature = "point_series")]
+mod point_series_3d;
+#[cfg(feature = "quiver_series")]
+mod quiver;
+#[cfg(feature = "radar_series")]
+mod radar;
+#[cfg(feature = "ridgeline_series")]
+mod ridgeline;
+#[cfg(feature = "line_series")]
+mod rolling;
+#[cfg(feature = "rose_series")]
+mod rose;
+#[cfg(any(feature = "line_series", feature = "area_series"))]
+mod spline;
+#[cfg(feature = "area_series")]
+mod stacked_area;
+#[cfg(any(feature = "line_series", feature = "area_series"))]
+mod step_line;
+#[cfg(feature = "area_series")]
+mod stream_graph;
 #[cfg(feature = "surface_series")]
 mod surface;
+#[cfg(feature = "treemap_series")]
+mod treemap;
+#[cfg(feature = "line_series")]
+mod trendline;
+#[cfg(feature = "waterfall_series")]
+mod waterfall;
 
 #[cfg(feature = "area_series")]
 pub use area_series::AreaSeries;
+#[cfg(feature = "area_series")]
+pub use band::BandSeries;
 #[cfg(feature = "histogram")]
-pub use histogram::Histogram;
+pub use bar::{Bar, BarSeries};
+#[cfg(feature = "boxplot")]
+pub use boxplot_series::BoxplotSeries;
+#[cfg(feature = "point_series")]
+pub use bubble::{BubbleScale, BubbleSeries};
+#[cfg(feature = "point_series")]
+pub use color_scatter::ColorScatterSeries;
+#[cfg(feature = "contour_series")]
+pub use contour::ContourSeries;
+#[cfg(feature = "line_series")]
+pub use ecdf::EcdfSeries;
+#[cfg(feature = "event_series")]
+pub use event::{EventSeries, EventTick};
+#[cfg(feature = "area_series")]
+pub use fill_between::FillBetween;
+#[cfg(any(feature = "line_series", feature = "area_series"))]
+pub use gap::{GapPolicy, MissingValue};
+#[cfg(feature = "geo_series")]
+pub use geo::{GeoSeries, Geometry};
+#[cfg(feature = "heatmap_series")]
+pub use heatmap::{HeatmapCell, HeatmapSeries};
+#[cfg(feature = "histogram")]
+pub use hexbin::{CountScale, Hexbin, Histogram2D};
+#[cfg(feature = "histogram")]
+pub use histogram::{BinnedHistogram, Binning, Histogram};
 #[cfg(feature = "line_series")]
 pub use line_series::LineSeries;
 #[cfg(feature = "point_series")]
+pub use point_label::{PointLabelSeries, PointLabels};
+#[cfg(feature = "point_series")]
 pub use point_series::PointSeries;
+#[cfg(feature = "point_series")]
+pub use point_series_3d::PointSeries3d;
+#[cfg(feature = "quiver_series")]
+pub use quiver::{QuiverScale, QuiverSeries};
+#[cfg(feature = "radar_series")]
+pub use radar::RadarSeries;
+#[cfg(feature = "ridgeline_series")]
+pub use ridgeline::RidgelineSeries;
+#[cfg(feature = "line_series")]
+pub use rolling::RollingSeries;
+#[cfg(feature = "rose_series")]
+pub use rose::RoseSeries;
+#[cfg(feature = "line_series")]
+pub use spline::{SplineKind, SplineSeries};
+#[cfg(feature = "area_series")]
+pub use stacked_area::StackedAreaSeries;
+#[cfg(feature = "area_series")]
+pub(crate) use stacked_area::StackedLayer;
+#[cfg(any(feature = "line_series", feature = "area_series"))]
+pub use step_line::StepKind;
+#[cfg(feature = "line_series")]
+pub use step_line::StepLineSeries;
+#[cfg(feature = "area_series")]
+pub use stream_graph::{StreamBaseline, StreamGraphSeries};
+#[cfg(all(feature = "surface_series", feature = "ndarray"))]
+pub use surface::ArraySurfaceFunc;
 #[cfg(feature = "surface_series")]
 pub use surface::SurfaceSeries;
+#[cfg(feature = "treemap_series")]
+pub use treemap::{TreemapCell, TreemapNode, TreemapSeries};
+#[cfg(feature = "line_series")]
+pub use trendline::TrendlineSeries;
+#[cfg(feature = "waterfall_series")]
+pub use waterfall::WaterfallSeries;
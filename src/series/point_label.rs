@@ -0,0 +1,346 @@
+use crate::drawing::BackendFeatures;
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::TextStyle;
+use plotters_backend::{BackendCoord, DrawingErrorKind};
+
+/// A box in the backend coordinate, as `(left, top, right, bottom)`
+type LabelBox = (i32, i32, i32, i32);
+
+fn overlap(a: &LabelBox, b: &LabelBox) -> (i32, i32) {
+    (
+        (a.2.min(b.2) - a.0.max(b.0)).max(0),
+        (a.3.min(b.3) - a.1.max(b.1)).max(0),
+    )
+}
+
+fn overlap_area(a: &LabelBox, b: &LabelBox) -> i64 {
+    let (w, h) = overlap(a, b);
+    i64::from(w) * i64::from(h)
+}
+
+/// The area of the box that is out of the bounds
+fn outside_area(a: &LabelBox, bounds: &Option<LabelBox>) -> i64 {
+    match bounds {
+        Some(bounds) => {
+            let (w, h) = (i64::from(a.2 - a.0), i64::from(a.3 - a.1));
+            w * h - overlap_area(a, bounds)
+        }
+        None => 0,
+    }
+}
+
+fn translate(a: &LabelBox, dx: i32, dy: i32) -> LabelBox {
+    (a.0 + dx, a.1 + dy, a.2 + dx, a.3 + dy)
+}
+
+/// Move the box into the bounds, if it fits
+fn keep_inside(a: &LabelBox, bounds: &Option<LabelBox>) -> LabelBox {
+    match bounds {
+        Some(b) => {
+            let dx = (b.0 - a.0).max(0) + (b.2 - a.2).min(0);
+            let dy = (b.1 - a.1).max(0) + (b.3 - a.3).min(0);
+            translate(a, dx, dy)
+        }
+        None => *a,
+    }
+}
+
+/// Move the box away from the other one along the axis that they overlap the least, or `None`
+/// if they don't overlap
+fn push_apart(a: &LabelBox, b: &LabelBox, half: bool) -> Option<LabelBox> {
+    let (ow, oh) = overlap(a, b);
+    if ow == 0 || oh == 0 {
+        return None;
+    }
+    let share = |d: i32| if half { (d + 1) / 2 } else { d };
+    let (dx, dy) = if ow <= oh {
+        let d = share(ow);
+        (if a.0 + a.2 < b.0 + b.2 { -d } else { d }, 0)
+    } else {
+        let d = share(oh);
+        (0, if a.1 + a.3 < b.1 + b.3 { -d } else { d })
+    };
+    Some(translate(a, dx, dy))
+}
+
+/// Place the labels of the sizes near the points, and return the upper left corners of them.
+///
+/// Each label is first put at the candidate position around its point that overlaps the least
+/// with the labels placed before it, all the markers and the out of the bounds area. Then the
+/// labels that still overlap are pushed apart, along the axis that they overlap the least, for
+/// the given number of iterations, and the labels that cover a marker are pushed off it.
+fn place_labels(
+    points: &[BackendCoord],
+    sizes: &[(u32, u32)],
+    marker_size: i32,
+    offset: i32,
+    bounds: Option<LabelBox>,
+    iterations: usize,
+) -> Vec<BackendCoord> {
+    let markers: Vec<LabelBox> = points
+        .iter()
+        .map(|&(x, y)| {
+            (
+                x - marker_size,
+                y - marker_size,
+                x + marker_size,
+                y + marker_size,
+            )
+        })
+        .collect();
+    let gap = marker_size + offset;
+
+    let mut placed: Vec<LabelBox> = vec![];
+    for (&(x, y), &(w, h)) in points.iter().zip(sizes) {
+        let (w, h) = (w as i32, h as i32);
+        // Above right first, then the other corners, then the sides
+        let candidates = [
+            (x + gap, y - gap - h),
+            (x - gap - w, y - gap - h),
+            (x + gap, y + gap),
+            (x - gap - w, y + gap),
+            (x + gap, y - h / 2),
+            (x - gap - w, y - h / 2),
+            (x - w / 2, y - gap - h),
+            (x - w / 2, y + gap),
+        ];
+        let cost = |&(cx, cy): &BackendCoord| {
+            let label = (cx, cy, cx + w, cy + h);
+            let labels: i64 = placed.iter().map(|b| overlap_area(&label, b)).sum();
+            let markers: i64 = markers.iter().map(|b| overlap_area(&label, b)).sum();
+            labels + markers + outside_area(&label, &bounds)
+        };
+        let mut best = candidates[0];
+        let mut best_cost = cost(&best);
+        for candidate in candidates.iter().skip(1) {
+            let candidate_cost = cost(candidate);
+            if candidate_cost < best_cost {
+                best = *candidate;
+                best_cost = candidate_cost;
+            }
+        }
+        placed.push((best.0, best.1, best.0 + w, best.1 + h));
+    }
+
+    for _ in 0..iterations {
+        let mut moved = false;
+        for i in 0..placed.len() {
+            // The labels move by half of the overlap, since the other label moves as well,
+            // while the markers are fixed
+            let others = placed
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, b)| (*b, true))
+                .chain(markers.iter().map(|b| (*b, false)))
+                .collect::<Vec<_>>();
+            for (other, half) in others {
+                if let Some(label) = push_apart(&placed[i], &other, half) {
+                    placed[i] = keep_inside(&label, &bounds);
+                    moved = true;
+                }
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+
+    placed.into_iter().map(|b| (b.0, b.1)).collect()
+}
+
+/// The labels of the points, which are laid out altogether when they are drawn, see
+/// [PointLabelSeries](struct.PointLabelSeries.html)
+pub struct PointLabels<'a, Coord> {
+    points: Vec<Coord>,
+    labels: Vec<String>,
+    style: TextStyle<'a>,
+    marker_size: u32,
+    offset: u32,
+    iterations: usize,
+}
+
+impl<'b, 'a, Coord> PointCollection<'b, Coord> for &'b PointLabels<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: BackendFeatures> Drawable<DB> for PointLabels<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let mut sizes = vec![];
+        for label in &self.labels {
+            sizes.push(backend.estimate_text_size(label, &self.style)?);
+        }
+        // The labels are kept in the plotting area when the series is clipped to it
+        let bounds = backend
+            .clip_bounds()
+            .map(|((x0, y0), (x1, y1))| (x0, y0, x1, y1));
+        let positions = place_labels(
+            &points,
+            &sizes,
+            self.marker_size as i32,
+            self.offset as i32,
+            bounds,
+            self.iterations,
+        );
+        let style = self.style.pos(Pos::new(HPos::Left, VPos::Top));
+        for (label, pos) in self.labels.iter().zip(positions) {
+            style.draw_text(backend, label, pos)?;
+        }
+        Ok(())
+    }
+}
+
+/// The series of the value labels of the points, which are placed next to the points so that
+/// they overlap neither each other nor the markers, as far as possible. The labels are laid out
+/// together, thus the whole series is a single element.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data: Vec<_> = (0..50).map(|x| (x as f64, (x as f64 / 5.0).sin())).collect();
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..50.0, -1.2..1.2)
+///     .unwrap();
+/// chart
+///     .draw_series(data.iter().map(|p| Circle::new(*p, 3, RED.filled())))
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         PointLabelSeries::new(data, |&(_, y)| format!("{:.2}", y), ("sans-serif", 12))
+///             .marker_size(3),
+///     )
+///     .unwrap();
+/// ```
+pub struct PointLabelSeries<'a, Coord> {
+    labels: Option<PointLabels<'a, Coord>>,
+}
+
+impl<'a, Coord> PointLabelSeries<'a, Coord> {
+    /// Create a new series of the labels of the points
+    /// - `data`: The points to label
+    /// - `label`: The function that formats the label of a point
+    /// - `style`: The style of the labels
+    pub fn new<I, F, S>(data: I, label: F, style: S) -> Self
+    where
+        I: IntoIterator<Item = Coord>,
+        F: Fn(&Coord) -> String,
+        S: Into<TextStyle<'a>>,
+    {
+        let points: Vec<_> = data.into_iter().collect();
+        let labels = points.iter().map(label).collect();
+        Self {
+            labels: Some(PointLabels {
+                points,
+                labels,
+                style: style.into(),
+                marker_size: 3,
+                offset: 2,
+                iterations: 50,
+            }),
+        }
+    }
+
+    fn update<F: FnOnce(&mut PointLabels<'a, Coord>)>(mut self, f: F) -> Self {
+        if let Some(ref mut labels) = self.labels {
+            f(labels);
+        }
+        self
+    }
+
+    /// Set the radius of the markers in pixels, which the labels avoid
+    pub fn marker_size(self, size: u32) -> Self {
+        self.update(|labels| labels.marker_size = size)
+    }
+
+    /// Set the gap between the markers and their labels in pixels
+    pub fn offset(self, offset: u32) -> Self {
+        self.update(|labels| labels.offset = offset)
+    }
+
+    /// Set the maximum number of the passes that push the overlapping labels apart, the labels
+    /// only take the positions around the points when it's zero
+    pub fn iterations(self, iterations: usize) -> Self {
+        self.update(|labels| labels.iterations = iterations)
+    }
+}
+
+impl<'a, Coord> Iterator for PointLabelSeries<'a, Coord> {
+    type Item = PointLabels<'a, Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.labels.take()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_place_labels() {
+        // Two labels of the same point take different corners
+        let points = [(50, 50), (50, 50)];
+        let positions = place_labels(&points, &[(20, 10), (20, 10)], 3, 2, None, 0);
+        assert_eq!(positions, vec![(55, 35), (25, 35)]);
+
+        // The label of the point at the corner of the bounds is moved to the other side
+        let positions = place_labels(&[(95, 5)], &[(20, 10)], 3, 2, Some((0, 0, 100, 100)), 0);
+        assert_eq!(positions, vec![(70, 10)]);
+
+        // The nine labels of the same point don't fit the candidates, thus they are pushed apart
+        let points = [(50, 50); 9];
+        let sizes = [(20, 10); 9];
+        let boxes = |positions: Vec<BackendCoord>| -> Vec<LabelBox> {
+            positions
+                .into_iter()
+                .map(|(x, y)| (x, y, x + 20, y + 10))
+                .collect()
+        };
+        let overlaps = |boxes: &[LabelBox]| {
+            let mut total = 0;
+            for (i, a) in boxes.iter().enumerate() {
+                for b in boxes.iter().skip(i + 1) {
+                    total += overlap_area(a, b);
+                }
+            }
+            total
+        };
+        let before = overlaps(&boxes(place_labels(&points, &sizes, 3, 2, None, 0)));
+        let after = overlaps(&boxes(place_labels(&points, &sizes, 3, 2, None, 50)));
+        assert!(before > 0);
+        assert!(after < before);
+    }
+
+    #[test]
+    fn test_point_label_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "1.0"));
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "2.0"));
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        chart
+            .draw_series(PointLabelSeries::new(
+                vec![(1.0, 1.0), (2.0, 2.0)],
+                |&(_, y)| format!("{:.1}", y),
+                ("sans-serif", 10),
+            ))
+            .unwrap();
+    }
+}
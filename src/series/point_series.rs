@@ -1,6 +1,9 @@
 use crate::element::PointElement;
 use crate::style::{ShapeStyle, SizeDesc};
 
+type PointStyleFn<'a, Coord, Size> =
+    dyn Fn(usize, &Coord, Size, ShapeStyle) -> (Size, ShapeStyle) + 'a;
+
 /// The point plot object, which takes an iterator of points in guest coordinate system
 /// and create an element for each point
 pub struct PointSeries<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> {
@@ -8,6 +11,8 @@ pub struct PointSeries<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDe
     size: Size,
     data_iter: I::IntoIter,
     make_point: &'a dyn Fn(Coord, Size, ShapeStyle) -> E,
+    point_style: Option<&'a PointStyleFn<'a, Coord, Size>>,
+    idx: usize,
 }
 
 impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterator
@@ -15,9 +20,13 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterat
 {
     type Item = E;
     fn next(&mut self) -> Option<Self::Item> {
-        self.data_iter
-            .next()
-            .map(|x| (self.make_point)(x, self.size.clone(), self.style.clone()))
+        let point = self.data_iter.next()?;
+        let (size, style) = match self.point_style {
+            Some(func) => func(self.idx, &point, self.size.clone(), self.style.clone()),
+            None => (self.size.clone(), self.style.clone()),
+        };
+        self.idx += 1;
+        Some((self.make_point)(point, size, style))
     }
 }
 
@@ -35,6 +44,8 @@ where
             size,
             style: style.into(),
             make_point: &|a, b, c| E::make_point(a, b, c),
+            point_style: None,
+            idx: 0,
         }
     }
 }
@@ -56,6 +67,82 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
             size,
             style: style.into(),
             make_point: cons,
+            point_style: None,
+            idx: 0,
         }
     }
+
+    /// Style each point by the data, such as coloring the points above a threshold or
+    /// highlighting some of them. The function gets the index and the coordinate of the point
+    /// with the size and the style of the series, and returns the size and the style of the point.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+    ///     .unwrap();
+    /// let highlight = |_: usize, &(_, y): &(f64, f64), size: u32, style: ShapeStyle| {
+    ///     if y > 50.0 {
+    ///         (size * 2, RED.filled())
+    ///     } else {
+    ///         (size, style)
+    ///     }
+    /// };
+    /// chart
+    ///     .draw_series(
+    ///         PointSeries::<_, _, Circle<_, _>, _>::new(
+    ///             (0..10).map(|x| (x as f64, (x * x) as f64)),
+    ///             3,
+    ///             &BLUE,
+    ///         )
+    ///         .point_style(&highlight),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn point_style<F: Fn(usize, &Coord, Size, ShapeStyle) -> (Size, ShapeStyle)>(
+        mut self,
+        func: &'a F,
+    ) -> Self {
+        self.point_style = Some(func);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_point_style() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_circle(|c, _, _, pos, size| {
+                if pos.0 < 50 {
+                    assert_eq!((c, size), (BLUE.to_rgba(), 2));
+                } else {
+                    assert_eq!((c, size), (RED.to_rgba(), 4));
+                }
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 10));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        let threshold = |idx: usize, &(x, _): &(i32, i32), size: i32, style: ShapeStyle| {
+            assert_eq!(idx as i32, x);
+            if x >= 5 {
+                (size * 2, RED.filled())
+            } else {
+                (size, style)
+            }
+        };
+        chart
+            .draw_series(
+                PointSeries::<_, _, Circle<_, _>, _>::new((0..10).map(|x| (x, x)), 2, &BLUE)
+                    .point_style(&threshold),
+            )
+            .unwrap();
+    }
 }
@@ -0,0 +1,175 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian3d;
+use crate::coord::ranged1d::Ranged;
+use crate::element::Circle;
+use crate::style::ShapeStyle;
+use plotters_backend::DrawingBackend;
+
+/// The point series on a 3D chart, which draws the markers from the farthest to the nearest
+/// against the projection of the chart, thus the near points are never covered by the far
+/// ones. The markers can also shrink with the depth to give a sense of perspective.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+///     .unwrap();
+/// let points = (0..100).map(|i| {
+///     let t = i as f64 / 100.0;
+///     (t, (t * 12.0).sin() / 2.0 + 0.5, (t * 12.0).cos() / 2.0 + 0.5)
+/// });
+/// chart
+///     .draw_series(
+///         PointSeries3d::new(points, 5, BLUE.filled())
+///             .size_attenuation(1.0)
+///             .sorted(&chart),
+///     )
+///     .unwrap();
+/// ```
+pub struct PointSeries3d<'a, X, Y, Z, E> {
+    data: Vec<(X, Y, Z)>,
+    size: u32,
+    style: ShapeStyle,
+    attenuation: f64,
+    make_point: &'a dyn Fn((X, Y, Z), u32, ShapeStyle) -> E,
+}
+
+impl<'a, X, Y, Z> PointSeries3d<'a, X, Y, Z, Circle<(X, Y, Z), u32>> {
+    /// Create a new 3D point series with the circle markers
+    /// - `iter`: The points
+    /// - `size`: The radius of the nearest markers
+    /// - `style`: The style of the markers
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y, Z)>, S: Into<ShapeStyle>>(
+        iter: I,
+        size: u32,
+        style: S,
+    ) -> Self {
+        Self::of_element(iter, size, style, &|c, s, st| Circle::new(c, s, st))
+    }
+}
+
+impl<'a, X, Y, Z, E> PointSeries3d<'a, X, Y, Z, E> {
+    /// Create a new 3D point series with the customized function for the element creation,
+    /// see [PointSeries::of_element](struct.PointSeries.html#method.of_element)
+    pub fn of_element<I, S, F>(iter: I, size: u32, style: S, cons: &'a F) -> Self
+    where
+        I: IntoIterator<Item = (X, Y, Z)>,
+        S: Into<ShapeStyle>,
+        F: Fn((X, Y, Z), u32, ShapeStyle) -> E,
+    {
+        Self {
+            data: iter.into_iter().collect(),
+            size,
+            style: style.into(),
+            attenuation: 0.0,
+            make_point: cons,
+        }
+    }
+
+    /// Shrink the markers with the depth. The markers at the front of the chart have the full
+    /// size and the ones at the back are `1 / (1 + strength)` of it, thus 0 turns the
+    /// attenuation off, which is the default.
+    pub fn size_attenuation(mut self, strength: f64) -> Self {
+        self.attenuation = if strength > 0.0 { strength } else { 0.0 };
+        self
+    }
+
+    /// Create the markers in the drawing order of the painter's algorithm, from the farthest to
+    /// the nearest
+    /// - `chart`: The chart that the series is drawn on
+    /// - **returns** The markers that are drawn with `ChartContext::draw_series`
+    pub fn sorted<DB, XR, YR, ZR>(self, chart: &ChartContext<DB, Cartesian3d<XR, YR, ZR>>) -> Vec<E>
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        ZR: Ranged<ValueType = Z>,
+    {
+        let coord = chart.as_coord_spec();
+
+        // The depth range of the chart is the one of the corners of the axis box
+        let (x, y, z) = (
+            coord.logic_x.range(),
+            coord.logic_y.range(),
+            coord.logic_z.range(),
+        );
+        let mut corners = vec![];
+        for x in [&x.start, &x.end].iter() {
+            for y in [&y.start, &y.end].iter() {
+                for z in [&z.start, &z.end].iter() {
+                    corners.push(coord.projected_depth(x, y, z));
+                }
+            }
+        }
+        let near = corners.iter().cloned().min().unwrap_or(0);
+        let far = corners.iter().cloned().max().unwrap_or(0);
+
+        let mut points: Vec<_> = self
+            .data
+            .into_iter()
+            .map(|p| (coord.projected_depth(&p.0, &p.1, &p.2), p))
+            .collect();
+        points.sort_by_key(|p| std::cmp::Reverse(p.0));
+
+        let (size, attenuation, style, make_point) =
+            (self.size, self.attenuation, self.style, self.make_point);
+        let scale = |depth: i32| {
+            if attenuation == 0.0 || far == near {
+                return size;
+            }
+            let t = f64::from(depth - near) / f64::from(far - near);
+            (f64::from(size) / (1.0 + attenuation * t)).round() as u32
+        };
+        points
+            .into_iter()
+            .map(|(depth, p)| make_point(p, scale(depth), style.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_point_series_3d() {
+        let radii = Rc::new(RefCell::new(vec![]));
+        let collected = radii.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(move |_, _, _, _, r| {
+                collected.borrow_mut().push(r);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .unwrap();
+
+        let points = vec![(0.5, 0.5, 0.0), (0.5, 0.5, 1.0), (0.5, 0.5, 0.5)];
+        let series = PointSeries3d::new(points, 8, &RED)
+            .size_attenuation(1.0)
+            .sorted(&chart);
+        let coord = chart.as_coord_spec();
+        let depths: Vec<_> = series
+            .iter()
+            .map(|c| {
+                let (x, y, z) = c.point_iter().next().unwrap();
+                coord.projected_depth(x, y, z)
+            })
+            .collect();
+        assert!(depths.windows(2).all(|w| w[0] >= w[1]));
+        chart.draw_series(series).unwrap();
+
+        // The markers grow from the back to the front
+        let radii = radii.borrow();
+        assert_eq!(radii.len(), 3);
+        assert!(radii.windows(2).all(|w| w[0] <= w[1]));
+        assert!(radii[0] < radii[2] && radii[2] <= 8);
+    }
+}
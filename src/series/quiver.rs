@@ -0,0 +1,204 @@
+use crate::element::{Arrow, ArrowHead};
+use crate::style::colors::BLACK;
+use crate::style::{Color, ColorMap, Normalizer, ShapeStyle};
+
+/// How the vectors of a quiver series are turned into the arrows
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuiverScale {
+    /// Each vector is multiplied by the factor, so the arrow lengths are proportional to the
+    /// magnitudes
+    Absolute(f64),
+    /// Each vector is scaled to the given length in the data coordinate, so only the directions
+    /// are shown
+    Normalized(f64),
+}
+
+/// The series that draws a vector field as arrows, which start at the grid points.
+///
+/// The arrows can be colored by the magnitude of the vectors with a color map, otherwise they
+/// are drawn with the same style. The vectors with a NaN component are skipped.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let field = (0..10).flat_map(|y| (0..10).map(move |x| (x as f64, y as f64)))
+///     .map(|(x, y)| (x, y, -(y - 4.5), x - 4.5));
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(-1.0..10.0, -1.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         QuiverSeries::new(field)
+///             .scale(QuiverScale::Normalized(0.8))
+///             .color_map(ViridisRGB),
+///     )
+///     .unwrap();
+/// ```
+pub struct QuiverSeries<'a> {
+    data: Vec<(f64, f64, f64, f64)>,
+    style: ShapeStyle,
+    scale: QuiverScale,
+    head: ArrowHead,
+    head_size: (u32, u32),
+    color_map: Option<Box<dyn ColorMap + 'a>>,
+    magnitude_range: (f64, f64),
+    normalizer: Normalizer,
+    idx: usize,
+}
+
+impl<'a> QuiverSeries<'a> {
+    /// Create a new quiver series
+    /// - `iter`: The points of the field as `(x, y, dx, dy)`
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (f64, f64, f64, f64)>>(iter: I) -> Self {
+        let data: Vec<_> = iter
+            .into_iter()
+            .filter(|&(x, y, dx, dy)| !(x.is_nan() || y.is_nan() || dx.is_nan() || dy.is_nan()))
+            .collect();
+        let mut magnitudes = data.iter().map(|p| p.2.hypot(p.3));
+        let magnitude_range = match magnitudes.next() {
+            Some(first) => magnitudes.fold((first, first), |r, v| (r.0.min(v), r.1.max(v))),
+            None => (0.0, 0.0),
+        };
+        Self {
+            data,
+            style: BLACK.into(),
+            scale: QuiverScale::Absolute(1.0),
+            head: ArrowHead::Filled,
+            head_size: (8, 6),
+            color_map: None,
+            magnitude_range,
+            normalizer: Normalizer::Linear,
+            idx: 0,
+        }
+    }
+
+    /// Set the style of the arrows, the color is ignored if a color map is set
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set how the vectors are turned into the arrows
+    pub fn scale(mut self, scale: QuiverScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the shape of the arrow heads
+    pub fn head(mut self, head: ArrowHead) -> Self {
+        self.head = head;
+        self
+    }
+
+    /// Set the size of the arrow heads
+    /// - `length`: The length of the head along the arrow in pixels
+    /// - `width`: The width of the head across the arrow in pixels
+    pub fn head_size(mut self, length: u32, width: u32) -> Self {
+        self.head_size = (length, width);
+        self
+    }
+
+    /// Get the range of the magnitudes in the field, which is mapped onto the color map. A
+    /// [Colorbar](../element/struct.Colorbar.html) with this range explains the colors.
+    pub fn magnitude_range(&self) -> std::ops::Range<f64> {
+        self.magnitude_range.0..self.magnitude_range.1
+    }
+
+    /// Color the arrows by the magnitude of the vectors, which are mapped from the smallest to
+    /// the largest magnitude in the field
+    pub fn color_map<M: ColorMap + 'a>(mut self, map: M) -> Self {
+        self.color_map = Some(Box::new(map));
+        self
+    }
+
+    /// Set how the magnitudes are mapped onto the color map, they're mapped linearly by default
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+}
+
+impl<'a> Iterator for QuiverSeries<'a> {
+    type Item = Arrow<'static, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, dx, dy) = *self.data.get(self.idx)?;
+        self.idx += 1;
+
+        let magnitude = dx.hypot(dy);
+        let factor = match self.scale {
+            QuiverScale::Absolute(factor) => factor,
+            QuiverScale::Normalized(_) if magnitude == 0.0 => 0.0,
+            QuiverScale::Normalized(length) => length / magnitude,
+        };
+        let mut style = self.style.clone();
+        if let Some(ref map) = self.color_map {
+            let (min, max) = self.magnitude_range;
+            style.color = map
+                .get_color(self.normalizer.normalize(magnitude, min, max))
+                .to_rgba();
+        }
+
+        let arrow = Arrow::new((x, y), (x + dx * factor, y + dy * factor), style);
+        Some(
+            arrow
+                .head(self.head)
+                .head_size(self.head_size.0, self.head_size.1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_quiver_scale() {
+        let data = vec![(0.0, 0.0, 3.0, 4.0), (1.0, 1.0, 0.0, std::f64::NAN)];
+        let arrows: Vec<_> = QuiverSeries::new(data.clone()).collect();
+        assert_eq!(arrows.len(), 1);
+        assert_eq!((&arrows[0]).point_iter(), &[(0.0, 0.0), (3.0, 4.0)]);
+
+        let arrows: Vec<_> = QuiverSeries::new(data)
+            .scale(QuiverScale::Normalized(10.0))
+            .collect();
+        assert_eq!((&arrows[0]).point_iter(), &[(0.0, 0.0), (6.0, 8.0)]);
+    }
+
+    #[test]
+    fn test_draw_arrows() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert_eq!(path[0].1, path[1].1);
+            });
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, WHITE.to_rgba());
+                // The tip and the two corners of the head behind it
+                assert_eq!(path.len(), 3);
+                assert_eq!(path[1].0, path[0].0 - 8);
+                assert_eq!(path[1].1 - path[2].1, 6);
+            });
+            // The zero vector has the smallest magnitude, and no head
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        let data = vec![(1.0, 5.0, 6.0, 0.0), (5.0, 5.0, 0.0, 0.0)];
+        chart
+            .draw_series(QuiverSeries::new(data).color_map(BlackWhite))
+            .unwrap();
+    }
+}
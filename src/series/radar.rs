@@ -0,0 +1,204 @@
+use std::f64::consts::PI;
+
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon, Text};
+use crate::style::colors::BLACK;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, RGBAColor, ShapeStyle, TextStyle};
+use plotters_backend::DrawingBackend;
+
+const DEFAULT_OPACITY: f64 = 0.3;
+const DEFAULT_LEVELS: usize = 4;
+/// The distance of the axis names from the center, relative to the outer ring
+const LABEL_DISTANCE: f64 = 1.05;
+
+/// The series that draws a radar chart: the spokes and the rings of the named axes, and a
+/// filled polygon for each data set.
+///
+/// The series is drawn on a polar chart with the angle range `0.0..N`, where `N` is the number
+/// of the axes, so each axis gets an equal segment of the circle. The radius range should start
+/// at 0 and end at the maximum value of the radar chart.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let axes = ["Speed", "Power", "Range", "Comfort", "Price"];
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (480, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(40)
+///     .build_polar(0.0..axes.len() as f64, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         RadarSeries::new(&axes, 10.0)
+///             .add(&[7.0, 5.0, 8.0, 4.0, 6.0], &RED)
+///             .add(&[4.0, 9.0, 5.0, 7.0, 3.0], &BLUE)
+///             .opacity(0.2),
+///     )
+///     .unwrap();
+/// ```
+pub struct RadarSeries<'a, DB: DrawingBackend> {
+    axes: Vec<String>,
+    max: f64,
+    data: Vec<(Vec<f64>, RGBAColor)>,
+    opacity: f64,
+    levels: usize,
+    grid_style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    elements: Option<std::vec::IntoIter<DynElement<'a, DB, (f64, f64)>>>,
+}
+
+impl<'a, DB: DrawingBackend> RadarSeries<'a, DB> {
+    /// Create a new radar series
+    /// - `axes`: The names of the axes, which are placed clockwise from 12 o'clock
+    /// - `max`: The value at the outer ring
+    /// - **returns** The newly created series
+    pub fn new<S: ToString>(axes: &[S], max: f64) -> Self {
+        Self {
+            axes: axes.iter().map(ToString::to_string).collect(),
+            max,
+            data: vec![],
+            opacity: DEFAULT_OPACITY,
+            levels: DEFAULT_LEVELS,
+            grid_style: Into::<ShapeStyle>::into(&BLACK.mix(0.3)),
+            label_style: ("sans-serif", 15).into(),
+            elements: None,
+        }
+    }
+
+    /// Add a data set to the radar chart
+    /// - `values`: The values on each axis, the missing values are treated as zero
+    /// - `color`: The color of the polygon
+    pub fn add<C: Color>(mut self, values: &[f64], color: &C) -> Self {
+        self.data.push((values.to_vec(), color.to_rgba()));
+        self
+    }
+
+    /// Set the opacity of the filled polygons, the borders are always opaque
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Set the number of the rings of the grid
+    pub fn levels(mut self, levels: usize) -> Self {
+        self.levels = levels;
+        self
+    }
+
+    /// Set the style of the spokes and the rings
+    pub fn grid_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.grid_style = style.into();
+        self
+    }
+
+    /// Set the style of the axis names
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    fn build_elements(&self) -> Vec<DynElement<'a, DB, (f64, f64)>> {
+        let n = self.axes.len();
+        let mut elements = vec![];
+
+        for level in 1..=self.levels {
+            let r = self.max * level as f64 / self.levels as f64;
+            let ring: Vec<_> = (0..=n).map(|i| ((i % n) as f64, r)).collect();
+            elements.push(PathElement::new(ring, self.grid_style.clone()).into_dyn());
+        }
+
+        for (i, name) in self.axes.iter().enumerate() {
+            let theta = i as f64;
+            let spoke = vec![(theta, 0.0), (theta, self.max)];
+            elements.push(PathElement::new(spoke, self.grid_style.clone()).into_dyn());
+
+            // Place the name just outside of the outer ring, anchored on the side facing the center
+            let angle = theta / n as f64 * 2.0 * PI;
+            let (sin, cos) = (angle.sin(), angle.cos());
+            let hpos = if sin > 0.3 {
+                HPos::Left
+            } else if sin < -0.3 {
+                HPos::Right
+            } else {
+                HPos::Center
+            };
+            let vpos = if cos > 0.3 {
+                VPos::Bottom
+            } else if cos < -0.3 {
+                VPos::Top
+            } else {
+                VPos::Center
+            };
+            let style = self.label_style.pos(Pos::new(hpos, vpos));
+            let label = Text::new(name.clone(), (theta, self.max * LABEL_DISTANCE), style);
+            elements.push(label.into_dyn());
+        }
+
+        for (values, color) in &self.data {
+            let polygon: Vec<_> = (0..n)
+                .map(|i| (i as f64, values.get(i).cloned().unwrap_or(0.0)))
+                .collect();
+            let mut border = polygon.clone();
+            border.extend(polygon.first().cloned());
+            elements.push(Polygon::new(polygon, color.mix(self.opacity).filled()).into_dyn());
+            elements.push(PathElement::new(border, color).into_dyn());
+        }
+
+        elements
+    }
+}
+
+impl<'a, DB: DrawingBackend> Iterator for RadarSeries<'a, DB> {
+    type Item = DynElement<'a, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            if self.axes.is_empty() {
+                return None;
+            }
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(Iterator::next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_radar_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.mix(0.5).to_rgba());
+                assert_eq!(path, vec![(100, 20), (140, 100), (100, 140), (100, 100)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert_eq!(pos, (100, 16));
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                // 2 rings, 4 spokes and the border of the polygon
+                assert_eq!(b.num_draw_path_call, 7);
+                assert_eq!(b.num_draw_text_call, 4);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_polar(0.0..4.0, 0.0..10.0)
+            .unwrap();
+        chart
+            .draw_series(
+                RadarSeries::new(&["a", "b", "c", "d"], 10.0)
+                    .add(&[10.0, 5.0, 5.0], &RED)
+                    .opacity(0.5)
+                    .levels(2),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,272 @@
+use crate::data::kde::{weighted_gaussian_density, weighted_silverman_bandwidth};
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::colors::BLACK;
+use crate::style::{ColorCycle, Palette, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/// The number of points the density of a group is evaluated at
+const PROFILE_SIZE: usize = 100;
+/// How far the curves extend beyond the samples, in the unit of the bandwidth
+const TAIL_BANDWIDTHS: f64 = 3.0;
+const DEFAULT_OVERLAP: f64 = 1.5;
+
+/// The series that draws a ridgeline plot (joyplot): the kernel density estimation of each
+/// group is a filled curve on its own row, and the rows are close enough for the curves to
+/// overlap.
+///
+/// The Y axis is categorical: the first group is on the top row, which has the baseline at
+/// `N - 1`, where `N` is the number of groups, and the last group has the baseline at 0. The
+/// peak of each curve is at `overlap` rows above its baseline, so the Y range should be
+/// `0.0..(N - 1) + overlap`. Use [RidgelineSeries::label_formatter](#method.label_formatter)
+/// to label the rows with the names of the groups.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let series = RidgelineSeries::new(PaletteCategory10)
+///     .group("a", &[1.0, 2.0, 2.5, 3.0, 5.0])
+///     .group("b", &[2.0, 3.0, 3.5, 4.0, 4.5])
+///     .group("c", &[4.0, 5.0, 5.5, 6.0, 8.0]);
+/// let mut chart = ChartBuilder::on(&root)
+///     .y_label_area_size(30)
+///     .build_cartesian_2d(0.0..10.0, series.y_range())
+///     .unwrap();
+/// let formatter = series.label_formatter();
+/// chart
+///     .configure_mesh()
+///     .y_labels(3)
+///     .y_label_formatter(&formatter)
+///     .draw()
+///     .unwrap();
+/// chart.draw_series(series).unwrap();
+/// ```
+pub struct RidgelineSeries<DB: DrawingBackend, P: Palette> {
+    groups: Vec<(String, Vec<(f64, f64)>)>,
+    colors: ColorCycle<P>,
+    overlap: f64,
+    bandwidth: Option<f64>,
+    border_style: ShapeStyle,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend, P: Palette> RidgelineSeries<DB, P> {
+    /// Create a new ridgeline series without groups
+    /// - `palette`: The palette that the curves are filled with
+    /// - **returns** The newly created series
+    pub fn new(palette: P) -> Self {
+        Self {
+            groups: vec![],
+            colors: ColorCycle::new(palette),
+            overlap: DEFAULT_OVERLAP,
+            bandwidth: None,
+            border_style: BLACK.into(),
+            elements: None,
+        }
+    }
+
+    /// Add a group on the row below the previous groups
+    /// - `label`: The name of the group
+    /// - `samples`: The samples of the distribution, the values that are not finite are ignored
+    pub fn group<L: Into<String>, T: Into<f64> + Copy>(self, label: L, samples: &[T]) -> Self {
+        let samples: Vec<_> = samples.iter().map(|&v| (v, 1.0)).collect();
+        self.weighted_group(label, &samples)
+    }
+
+    /// Add a group of weighted samples on the row below the previous groups, such as the survey
+    /// weights or the counts of the pre-aggregated values
+    /// - `label`: The name of the group
+    /// - `samples`: The samples of the distribution and their weights, the values that are not
+    ///   finite and the weights that are not positive are ignored
+    pub fn weighted_group<L: Into<String>, T: Into<f64> + Copy>(
+        mut self,
+        label: L,
+        samples: &[(T, f64)],
+    ) -> Self {
+        let samples = samples
+            .iter()
+            .map(|&(v, w)| (v.into(), w))
+            .filter(|(v, w): &(f64, f64)| v.is_finite() && *w > 0.0 && w.is_finite())
+            .collect();
+        self.groups.push((label.into(), samples));
+        self
+    }
+
+    /// Set the height of the peak of each curve, in rows. With the values above 1, the curves
+    /// overlap the rows above them.
+    pub fn overlap(mut self, overlap: f64) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Set the bandwidth of the gaussian kernel, by default it's estimated for each group with
+    /// the Silverman's rule of thumb
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    /// Set the style of the outlines of the curves
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Get the Y range that fits all the rows and the peaks of the curves
+    pub fn y_range(&self) -> std::ops::Range<f64> {
+        0.0..(self.groups.len() as f64 - 1.0).max(0.0) + self.overlap
+    }
+
+    /// Get the formatter of the Y labels, which maps the baseline of each row to the name of its
+    /// group
+    pub fn label_formatter(&self) -> impl Fn(&f64) -> String {
+        let labels: Vec<_> = self.groups.iter().map(|g| g.0.clone()).collect();
+        move |y| {
+            let row = y.round();
+            if (y - row).abs() > 1e-6 || row < 0.0 || row as usize >= labels.len() {
+                return String::new();
+            }
+            labels[labels.len() - 1 - row as usize].clone()
+        }
+    }
+
+    /// Compute the outline of the density curve of each group, from the top row to the bottom
+    fn build_curves(&self) -> Vec<Vec<(f64, f64)>> {
+        let n = self.groups.len();
+        let mut curves = vec![];
+        for (idx, (_, samples)) in self.groups.iter().enumerate() {
+            let baseline = (n - 1 - idx) as f64;
+            if samples.is_empty() {
+                curves.push(vec![]);
+                continue;
+            }
+            let min = samples.iter().map(|s| s.0).fold(samples[0].0, f64::min);
+            let max = samples.iter().map(|s| s.0).fold(samples[0].0, f64::max);
+            let bandwidth = self
+                .bandwidth
+                .unwrap_or_else(|| weighted_silverman_bandwidth(samples));
+            if bandwidth.is_nan() || bandwidth <= 0.0 {
+                // All the density is at a single value
+                curves.push(vec![(min, baseline), (min, baseline + self.overlap)]);
+                continue;
+            }
+
+            let (from, to) = (
+                min - bandwidth * TAIL_BANDWIDTHS,
+                max + bandwidth * TAIL_BANDWIDTHS,
+            );
+            let profile: Vec<_> = (0..PROFILE_SIZE)
+                .map(|i| {
+                    let x = from + (to - from) * i as f64 / (PROFILE_SIZE - 1) as f64;
+                    (x, weighted_gaussian_density(samples, bandwidth, x))
+                })
+                .collect();
+            let peak = profile.iter().map(|p| p.1).fold(0.0, f64::max);
+            let scale = if peak > 0.0 { self.overlap / peak } else { 0.0 };
+            curves.push(
+                profile
+                    .into_iter()
+                    .map(|(x, d)| (x, baseline + d * scale))
+                    .collect(),
+            );
+        }
+        curves
+    }
+
+    fn build_elements(&mut self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut elements = vec![];
+        for (idx, curve) in self.build_curves().into_iter().enumerate() {
+            let style = self.colors.next_filled_style();
+            let (first, last) = match (curve.first(), curve.last()) {
+                (Some(first), Some(last)) => (first.0, last.0),
+                _ => continue,
+            };
+            let baseline = (self.groups.len() - 1 - idx) as f64;
+            let mut outline = curve.clone();
+            outline.push((last, baseline));
+            outline.push((first, baseline));
+            // The rows below are drawn later, so they cover the curves that overlap them
+            elements.push(Polygon::new(outline, style).into_dyn());
+            elements.push(PathElement::new(curve, self.border_style.clone()).into_dyn());
+        }
+        elements
+    }
+}
+
+impl<DB: DrawingBackend, P: Palette> Iterator for RidgelineSeries<DB, P> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(Iterator::next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ridgeline_curves() {
+        let series = RidgelineSeries::<MockedBackend, _>::new(PaletteCategory10)
+            .group("a", &[1.0, 2.0, 3.0])
+            .group("b", &[5.0, 5.0])
+            .overlap(2.0)
+            .bandwidth(0.5);
+        assert_eq!(series.y_range(), 0.0..3.0);
+
+        let curves = series.build_curves();
+        assert_eq!(curves.len(), 2);
+        // The first group is on the top row, and its peak is two rows above the baseline
+        let peak = curves[0].iter().map(|p| p.1).fold(0.0, f64::max);
+        assert!((peak - 3.0).abs() < 1e-9);
+        assert!(curves[0].iter().all(|p| p.1 >= 1.0));
+        assert!((curves[0][0].0 - -0.5).abs() < 1e-9);
+        assert!((curves[1][PROFILE_SIZE - 1].0 - 6.5).abs() < 1e-9);
+
+        let formatter = series.label_formatter();
+        assert_eq!(formatter(&1.0), "a");
+        assert_eq!(formatter(&0.0), "b");
+        assert_eq!(formatter(&0.5), "");
+        assert_eq!(formatter(&2.0), "");
+
+        // The sample without weight doesn't extend the curve
+        let series = RidgelineSeries::<MockedBackend, _>::new(PaletteCategory10)
+            .weighted_group("c", &[(1.0, 2.0), (9.0, 0.0)])
+            .bandwidth(0.5);
+        assert!((series.build_curves()[0][PROFILE_SIZE - 1].0 - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_draw_ridgeline() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, PaletteCategory10::pick(0).to_rgba());
+            });
+            m.check_draw_path(|c, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, PaletteCategory10::pick(1).to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..2.5)
+            .unwrap();
+        chart
+            .draw_series(
+                RidgelineSeries::new(PaletteCategory10)
+                    .group("a", &[1, 2, 3])
+                    .group("b", &[4, 6, 7]),
+            )
+            .unwrap();
+    }
+}
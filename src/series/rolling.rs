@@ -0,0 +1,151 @@
+use std::collections::vec_deque::{Iter, VecDeque};
+use std::ops::{Range, Sub};
+
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+/// The series of the latest data points of a live signal, which keeps the points in a ring buffer
+/// of a fixed capacity, so that an oscilloscope-style plot keeps a rolling window of the data
+/// without rebuilding a vector on each tick.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0u8; 640 * 480 * 3];
+/// let mut signal = RollingSeries::new(200);
+/// let state = {
+///     let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+///     ChartBuilder::on(&root)
+///         .build_cartesian_2d(0.0..10.0, -1.0..1.0)
+///         .unwrap()
+///         .into_chart_state()
+/// };
+/// for tick in 0..300 {
+///     let t = tick as f64 / 50.0;
+///     signal.push((t, t.sin()));
+///     let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+///     // The X axis slides with the signal, showing the last 4 seconds
+///     let mut chart = state.clone().restore(&root).set_x_range(signal.x_window(4.0).unwrap());
+///     chart.plotting_area().fill(&WHITE).unwrap();
+///     chart.draw_series(signal.line(&RED)).unwrap();
+/// }
+/// ```
+pub struct RollingSeries<X, Y> {
+    capacity: usize,
+    data: VecDeque<(X, Y)>,
+}
+
+impl<X, Y> RollingSeries<X, Y> {
+    /// Create an empty series
+    /// - `capacity`: The number of the latest points that are kept, which is at least 1
+    /// - **returns** The newly created series
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            data: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Append a point, the oldest point is dropped if the series is full
+    pub fn push(&mut self, point: (X, Y)) {
+        if self.data.len() == self.capacity {
+            self.data.pop_front();
+        }
+        self.data.push_back(point);
+    }
+
+    /// Remove all the points
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Get the number of the points
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if there's no point
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get the number of the points that are kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Iterate over the points, from the oldest to the latest
+    pub fn iter(&self) -> Iter<'_, (X, Y)> {
+        self.data.iter()
+    }
+}
+
+impl<X: Clone, Y: Clone> RollingSeries<X, Y> {
+    /// Get the range of X from the oldest point to the latest point, or `None` if empty
+    pub fn x_range(&self) -> Option<Range<X>> {
+        match (self.data.front(), self.data.back()) {
+            (Some(first), Some(last)) => Some(first.0.clone()..last.0.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the range of X of the given width that ends at the latest point, which slides with
+    /// the data, or `None` if empty
+    pub fn x_window<W>(&self, width: W) -> Option<Range<X>>
+    where
+        X: Sub<W, Output = X>,
+    {
+        self.data
+            .back()
+            .map(|last| last.0.clone() - width..last.0.clone())
+    }
+
+    /// Make the series that draws the points as a single line
+    /// - `style`: The style of the line
+    /// - **returns** The series, which is taken by
+    ///   [ChartContext::draw_series](../chart/struct.ChartContext.html#method.draw_series)
+    pub fn line<S: Into<ShapeStyle>>(&self, style: S) -> std::iter::Once<PathElement<(X, Y)>> {
+        let points: Vec<_> = self.data.iter().cloned().collect();
+        std::iter::once(PathElement::new(points, style))
+    }
+}
+
+impl<'a, X, Y> IntoIterator for &'a RollingSeries<X, Y> {
+    type Item = &'a (X, Y);
+    type IntoIter = Iter<'a, (X, Y)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rolling_series() {
+        let mut series = RollingSeries::new(3);
+        assert_eq!(series.x_range(), None);
+        for x in 0..5 {
+            series.push((x, x * 10));
+        }
+        assert_eq!(series.len(), 3);
+        assert_eq!(
+            series.iter().cloned().collect::<Vec<_>>(),
+            vec![(2, 20), (3, 30), (4, 40)]
+        );
+        assert_eq!(series.x_range(), Some(2..4));
+        assert_eq!(series.x_window(10), Some(-6..4));
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 3));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(series.x_range().unwrap(), 0..50)
+            .unwrap();
+        chart.draw_series(series.line(&RED)).unwrap();
+    }
+}
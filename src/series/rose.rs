@@ -0,0 +1,198 @@
+use crate::element::Polygon;
+use crate::style::ShapeStyle;
+
+/// The largest angle in degrees that an arc of a sector is drawn straight
+const ARC_STEP: f64 = 5.0;
+
+/// The series that draws a rose chart, such as a wind rose: the directions are counted in the
+/// sectors of the circle, and each sector is drawn as a wedge whose length is the count. Each
+/// data set is a band, and the bands are stacked outwards in the order they are added, for
+/// example the wind speed classes.
+///
+/// The series is drawn on a polar chart with the angle range `0.0..360.0`, thus the directions
+/// are the compass degrees, clockwise from the north at 12 o'clock. The first sector is centered
+/// at the north. The radius range should start at 0 and end at the largest total count, see
+/// [max_count](#method.max_count).
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let calm = vec![10.0, 20.0, 350.0, 90.0, 95.0, 180.0];
+/// let strong = vec![5.0, 15.0, 100.0, 270.0];
+/// let rose = RoseSeries::new(8)
+///     .data(calm, BLUE.mix(0.6))
+///     .data(strong, RED.mix(0.6));
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (480, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_polar((0.0..360.0).step(45.0), 0.0..rose.max_count())
+///     .unwrap();
+/// chart.configure_polar_mesh().draw().unwrap();
+/// chart.draw_series(rose).unwrap();
+/// ```
+pub struct RoseSeries {
+    sectors: usize,
+    bands: Vec<(Vec<f64>, ShapeStyle)>,
+    width: f64,
+    elements: Option<std::vec::IntoIter<Polygon<(f64, f64)>>>,
+}
+
+impl RoseSeries {
+    /// Create a new rose series
+    /// - `sectors`: The number of the sectors that the circle is divided into
+    /// - **returns** The newly created series
+    pub fn new(sectors: usize) -> Self {
+        Self {
+            sectors: sectors.max(1),
+            bands: vec![],
+            width: 1.0,
+            elements: None,
+        }
+    }
+
+    /// The width of a sector in degrees
+    fn sector_size(&self) -> f64 {
+        360.0 / self.sectors as f64
+    }
+
+    /// Get the index of the sector that the direction falls in
+    fn sector_of(&self, direction: f64) -> usize {
+        let size = self.sector_size();
+        let angle = ((direction + size / 2.0) % 360.0 + 360.0) % 360.0;
+        (angle / size).floor() as usize % self.sectors
+    }
+
+    /// Add a band of weighted directions, for example the durations of the wind
+    /// - `iter`: The iterator over the directions in degrees and the weights
+    /// - `style`: The style of the band
+    pub fn weighted_data<I: IntoIterator<Item = (f64, f64)>, S: Into<ShapeStyle>>(
+        mut self,
+        iter: I,
+        style: S,
+    ) -> Self {
+        let mut counts = vec![0.0; self.sectors];
+        for (direction, weight) in iter {
+            if direction.is_finite() {
+                counts[self.sector_of(direction)] += weight;
+            }
+        }
+        self.bands.push((counts, style.into()));
+        self
+    }
+
+    /// Add a band of directions, each of them counted once
+    /// - `iter`: The iterator over the directions in degrees
+    /// - `style`: The style of the band
+    pub fn data<I: IntoIterator<Item = f64>, S: Into<ShapeStyle>>(self, iter: I, style: S) -> Self {
+        self.weighted_data(iter.into_iter().map(|d| (d, 1.0)), style)
+    }
+
+    /// Set the fraction of the sector that a wedge takes, the rest is the gap between the wedges
+    pub fn sector_width(mut self, ratio: f64) -> Self {
+        self.width = ratio;
+        self
+    }
+
+    /// Get the counts of the sectors of each band, the first sector is centered at the north
+    pub fn counts(&self) -> Vec<Vec<f64>> {
+        self.bands
+            .iter()
+            .map(|(counts, _)| counts.clone())
+            .collect()
+    }
+
+    /// Get the largest count of a sector with all the bands stacked, which is the end of the
+    /// radius range that fits the chart
+    pub fn max_count(&self) -> f64 {
+        (0..self.sectors)
+            .map(|i| self.bands.iter().map(|(counts, _)| counts[i]).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    fn build_elements(&self) -> Vec<Polygon<(f64, f64)>> {
+        let size = self.sector_size();
+        let half = size * self.width / 2.0;
+        let steps = ((2.0 * half / ARC_STEP).ceil() as usize).max(1);
+        let arc = |center: f64, r: f64| {
+            (0..=steps).map(move |i| (center - half + 2.0 * half * i as f64 / steps as f64, r))
+        };
+
+        let mut elements = vec![];
+        let mut inner = vec![0.0; self.sectors];
+        for (counts, style) in &self.bands {
+            for (i, count) in counts.iter().enumerate() {
+                if *count <= 0.0 {
+                    continue;
+                }
+                let (center, from, to) = (i as f64 * size, inner[i], inner[i] + count);
+                let mut outline: Vec<_> = arc(center, to).collect();
+                if from > 0.0 {
+                    outline.extend(arc(center, from).collect::<Vec<_>>().into_iter().rev());
+                } else {
+                    outline.push((center, 0.0));
+                }
+                elements.push(Polygon::new(outline, style.clone()));
+                inner[i] = to;
+            }
+        }
+        elements
+    }
+}
+
+impl Iterator for RoseSeries {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(Iterator::next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rose_counts() {
+        let rose = RoseSeries::new(4)
+            .data(vec![0.0, 44.0, 316.0, 46.0, 180.0, -90.0], &RED)
+            .weighted_data(vec![(90.0, 2.0), (f64::NAN, 1.0)], &BLUE);
+        assert_eq!(
+            rose.counts(),
+            vec![vec![3.0, 1.0, 1.0, 1.0], vec![0.0, 2.0, 0.0, 0.0]]
+        );
+        assert_eq!(rose.max_count(), 3.0);
+    }
+
+    #[test]
+    fn test_rose_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                // The wedge of the north sector ends at the center
+                assert_eq!(path.last(), Some(&(100, 100)));
+                assert!(path.iter().all(|p| p.1 <= 100));
+            });
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                // The stacked band doesn't reach the center
+                assert!(path.iter().all(|p| p.1 < 100));
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_polar(0.0..360.0, 0.0..2.0)
+            .unwrap();
+        chart
+            .draw_series(
+                RoseSeries::new(4)
+                    .data(vec![10.0], &RED)
+                    .data(vec![350.0], &BLUE)
+                    .sector_width(0.8),
+            )
+            .unwrap();
+    }
+}
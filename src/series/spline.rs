@@ -0,0 +1,277 @@
+use num_traits::{NumCast, ToPrimitive};
+
+#[cfg(feature = "line_series")]
+use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
+#[cfg(feature = "line_series")]
+use crate::style::ShapeStyle;
+#[cfg(feature = "line_series")]
+use plotters_backend::DrawingBackend;
+#[cfg(feature = "line_series")]
+use std::marker::PhantomData;
+
+/// The number of the segments between two data points of a spline by default
+pub(crate) const DEFAULT_RESOLUTION: usize = 16;
+
+/// How a spline interpolates between the data points
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(not(feature = "line_series"), allow(dead_code))]
+pub enum SplineKind {
+    /// The Catmull-Rom spline, whose tangent at each point is parallel to the line between the
+    /// points next to it. It can overshoot the data between the points.
+    CatmullRom,
+    /// The monotone cubic interpolation, which never overshoots the data, thus a monotone part
+    /// of the data stays monotone. It needs ascending X values, otherwise the Catmull-Rom spline
+    /// is used.
+    Monotone,
+}
+
+fn catmull_rom(points: &[(f64, f64)], resolution: usize) -> Vec<(f64, f64)> {
+    let mut result = Vec::with_capacity((points.len() - 1) * resolution + 1);
+    for i in 0..points.len() - 1 {
+        let p0 = points[i.saturating_sub(1)];
+        let (p1, p2) = (points[i], points[i + 1]);
+        let p3 = points[(i + 2).min(points.len() - 1)];
+        let at = |a: f64, b: f64, c: f64, d: f64, t: f64| {
+            0.5 * (2.0 * b
+                + (c - a) * t
+                + (2.0 * a - 5.0 * b + 4.0 * c - d) * t * t
+                + (3.0 * b - a - 3.0 * c + d) * t * t * t)
+        };
+        for step in 0..resolution {
+            let t = step as f64 / resolution as f64;
+            result.push((at(p0.0, p1.0, p2.0, p3.0, t), at(p0.1, p1.1, p2.1, p3.1, t)));
+        }
+    }
+    result.push(points[points.len() - 1]);
+    result
+}
+
+/// The Fritsch-Carlson monotone cubic interpolation, or `None` if the X values aren't ascending
+fn monotone(points: &[(f64, f64)], resolution: usize) -> Option<Vec<(f64, f64)>> {
+    let n = points.len();
+    let widths: Vec<f64> = points.windows(2).map(|p| p[1].0 - p[0].0).collect();
+    if widths.iter().any(|w| *w <= 0.0) {
+        return None;
+    }
+    let slopes: Vec<f64> = points
+        .windows(2)
+        .zip(&widths)
+        .map(|(p, w)| (p[1].1 - p[0].1) / w)
+        .collect();
+
+    let mut tangents = vec![0.0; n];
+    tangents[0] = slopes[0];
+    tangents[n - 1] = slopes[n - 2];
+    for i in 1..n - 1 {
+        if slopes[i - 1] * slopes[i] > 0.0 {
+            tangents[i] = (slopes[i - 1] + slopes[i]) / 2.0;
+        }
+    }
+    for i in 0..n - 1 {
+        if slopes[i] == 0.0 {
+            tangents[i] = 0.0;
+            tangents[i + 1] = 0.0;
+            continue;
+        }
+        let (a, b) = (tangents[i] / slopes[i], tangents[i + 1] / slopes[i]);
+        let norm = a.hypot(b);
+        if norm > 3.0 {
+            tangents[i] = 3.0 / norm * a * slopes[i];
+            tangents[i + 1] = 3.0 / norm * b * slopes[i];
+        }
+    }
+
+    let mut result = Vec::with_capacity((n - 1) * resolution + 1);
+    for i in 0..n - 1 {
+        let ((x0, y0), (_, y1), w) = (points[i], points[i + 1], widths[i]);
+        for step in 0..resolution {
+            let t = step as f64 / resolution as f64;
+            let (t2, t3) = (t * t, t * t * t);
+            let y = (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+                + (t3 - 2.0 * t2 + t) * w * tangents[i]
+                + (3.0 * t2 - 2.0 * t3) * y1
+                + (t3 - t2) * w * tangents[i + 1];
+            result.push((x0 + w * t, y));
+        }
+    }
+    result.push(points[n - 1]);
+    Some(result)
+}
+
+/// Interpolate a smooth curve through the data points, with the given number of segments
+/// between two points. The points are kept as they are if they can't be converted to numbers.
+pub(crate) fn spline_points<X, Y>(
+    points: &[(X, Y)],
+    kind: SplineKind,
+    resolution: usize,
+) -> Vec<(X, Y)>
+where
+    X: Clone + ToPrimitive + NumCast,
+    Y: Clone + ToPrimitive + NumCast,
+{
+    let numbers: Option<Vec<(f64, f64)>> = points
+        .iter()
+        .map(|(x, y)| Some((x.to_f64()?, y.to_f64()?)))
+        .collect();
+    let numbers = match numbers {
+        Some(numbers) if numbers.len() >= 3 && resolution > 1 => numbers,
+        _ => return points.to_vec(),
+    };
+    let curve = match kind {
+        SplineKind::Monotone => monotone(&numbers, resolution),
+        SplineKind::CatmullRom => None,
+    };
+    let curve = curve.unwrap_or_else(|| catmull_rom(&numbers, resolution));
+    curve
+        .into_iter()
+        .filter_map(|(x, y)| Some((NumCast::from(x)?, NumCast::from(y)?)))
+        .collect()
+}
+
+/// The series that draws a smooth curve through the data points, which is a spline with the
+/// given number of segments between two points
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..6.0, 0.0..100.0)
+///     .unwrap();
+/// let visits = vec![(0.0, 20.0), (1.0, 45.0), (2.0, 40.0), (3.0, 80.0), (4.0, 75.0), (5.0, 90.0)];
+/// chart
+///     .draw_series(SplineSeries::new(visits, SplineKind::Monotone, &BLUE).point_size(3))
+///     .unwrap();
+/// ```
+#[cfg(feature = "line_series")]
+pub struct SplineSeries<DB: DrawingBackend, X, Y> {
+    style: ShapeStyle,
+    data: Vec<(X, Y)>,
+    kind: SplineKind,
+    resolution: usize,
+    point_idx: usize,
+    point_size: u32,
+    phantom: PhantomData<DB>,
+}
+
+#[cfg(feature = "line_series")]
+impl<DB, X, Y> Iterator for SplineSeries<DB, X, Y>
+where
+    DB: DrawingBackend,
+    X: Clone + ToPrimitive + NumCast + 'static,
+    Y: Clone + ToPrimitive + NumCast + 'static,
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.data.is_empty() {
+            if self.point_size > 0 && self.point_idx < self.data.len() {
+                let idx = self.point_idx;
+                self.point_idx += 1;
+                return Some(
+                    Circle::new(self.data[idx].clone(), self.point_size, self.style.clone())
+                        .into_dyn(),
+                );
+            }
+            let data = spline_points(&self.data, self.kind, self.resolution);
+            self.data.clear();
+            Some(PathElement::new(data, self.style.clone()).into_dyn())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "line_series")]
+impl<DB: DrawingBackend, X, Y> SplineSeries<DB, X, Y> {
+    /// Create a new spline series
+    /// - `iter`: The data points, in the order of the X values
+    /// - `kind`: How the curve interpolates between the points
+    /// - `style`: The style of the curve
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(
+        iter: I,
+        kind: SplineKind,
+        style: S,
+    ) -> Self {
+        Self {
+            style: style.into(),
+            data: iter.into_iter().collect(),
+            kind,
+            resolution: DEFAULT_RESOLUTION,
+            point_size: 0,
+            point_idx: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Set the number of the segments between two data points, which is 16 by default
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Draw the data points as circles of the given size
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = size;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_spline_points() {
+        let points = [(0.0f64, 0.0f64), (1.0, 1.0), (2.0, 1.0), (3.0, 0.0)];
+        for kind in [SplineKind::CatmullRom, SplineKind::Monotone].iter() {
+            let curve = spline_points(&points, *kind, 4);
+            assert_eq!(curve.len(), 3 * 4 + 1);
+            // The curve passes through the data points
+            for (idx, point) in points.iter().enumerate() {
+                let (x, y) = curve[idx * 4];
+                assert!((x - point.0).abs() < 1e-9 && (y - point.1).abs() < 1e-9);
+            }
+        }
+
+        // The monotone spline doesn't overshoot the flat part, while the Catmull-Rom does
+        let monotone = spline_points(&points, SplineKind::Monotone, 4);
+        assert!(monotone.iter().all(|p| p.1 <= 1.0 + 1e-9));
+        let catmull_rom = spline_points(&points, SplineKind::CatmullRom, 4);
+        assert!(catmull_rom.iter().any(|p| p.1 > 1.0));
+
+        // Too few points to interpolate
+        assert_eq!(
+            spline_points(&[(0, 0), (1, 1)], SplineKind::Monotone, 4),
+            vec![(0, 0), (1, 1)]
+        );
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_spline_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 2 * 8 + 1));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..3.0)
+            .unwrap();
+        chart
+            .draw_series(
+                SplineSeries::new(
+                    vec![(0.0, 0.0), (1.0, 2.0), (2.0, 1.0)],
+                    SplineKind::CatmullRom,
+                    &RED,
+                )
+                .resolution(8)
+                .point_size(2),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,284 @@
+use crate::element::Polygon;
+use crate::style::formatters::format_percent;
+use crate::style::{ColorCycle, Palette, ShapeStyle};
+
+/// The label, the style and the outline of a layer
+pub(crate) type StackedLayer = (String, ShapeStyle, Vec<(f64, f64)>);
+
+/// The series that stacks several areas on top of each other, so the upper curve of each layer
+/// is the cumulative sum of the values of the layers below.
+///
+/// The layers don't need to share the same X values. The layers are stacked over the union of
+/// all the X values, and the value of a layer between its data points is linearly interpolated.
+/// Out of the X range of a layer, and at the NaN values, the layer contributes zero. The layers
+/// are filled with the colors of the palette in order.
+///
+/// Drawing the series with [ChartContext::draw_stacked_areas](../chart/struct.ChartContext.html#method.draw_stacked_areas)
+/// also adds a legend entry for each layer.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..30.0)
+///     .unwrap();
+/// let stack = StackedAreaSeries::new(PaletteCategory10)
+///     .layer("a", (0..=10).map(|x| (x as f64, 5.0)))
+///     .layer("b", vec![(0.0, 2.0), (5.0, 8.0), (10.0, 2.0)])
+///     .layer("c", (0..=20).map(|x| (x as f64 / 2.0, (x as f64 / 2.0).sin() + 1.0)));
+/// chart.draw_stacked_areas(stack).unwrap();
+/// chart.configure_series_labels().draw().unwrap();
+/// ```
+pub struct StackedAreaSeries<P: Palette> {
+    layers: Vec<(String, Vec<(f64, f64)>)>,
+    colors: ColorCycle<P>,
+    baseline: f64,
+    normalized: bool,
+    polygons: Option<std::vec::IntoIter<StackedLayer>>,
+}
+
+/// Get the value of a layer at the given X value by linear interpolation, the points are sorted
+/// by the X value
+pub(super) fn interpolate(points: &[(f64, f64)], x: f64) -> f64 {
+    let idx = points.iter().position(|p| p.0 >= x);
+    let value = match idx {
+        Some(idx) if points[idx].0 == x => points[idx].1,
+        Some(idx) if idx > 0 => {
+            let ((x0, y0), (x1, y1)) = (points[idx - 1], points[idx]);
+            y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+        }
+        _ => 0.0,
+    };
+    if value.is_nan() {
+        0.0
+    } else {
+        value
+    }
+}
+
+impl<P: Palette> StackedAreaSeries<P> {
+    /// Create a new stacked area series without layers
+    /// - `palette`: The palette that the layers are filled with
+    /// - **returns** The newly created series
+    pub fn new(palette: P) -> Self {
+        Self {
+            layers: vec![],
+            colors: ColorCycle::new(palette),
+            baseline: 0.0,
+            normalized: false,
+            polygons: None,
+        }
+    }
+
+    /// Add a layer on the top of the stack
+    /// - `label`: The label of the layer in the legend
+    /// - `iter`: The data points of the layer, the points with a NaN X value are ignored
+    pub fn layer<L: Into<String>, I: IntoIterator<Item = (f64, f64)>>(
+        mut self,
+        label: L,
+        iter: I,
+    ) -> Self {
+        let mut points: Vec<_> = iter.into_iter().filter(|p| !p.0.is_nan()).collect();
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        self.layers.push((label.into(), points));
+        self
+    }
+
+    /// Set the value that the stack starts from, which is 0 by default
+    pub fn baseline(mut self, baseline: f64) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Scale the stack to the percentages of its total at each X value, so that the top layer
+    /// reaches 100 above the baseline and each layer shows its share of the composition. The
+    /// negative values take their share of the total magnitude, like
+    /// [BarSeries::normalized](struct.BarSeries.html#method.normalized). The X values where the
+    /// total is 0 are left empty. The Y axis is typically `0.0..100.0`,
+    /// labelled with the [percent_formatter](#method.percent_formatter).
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut content = String::new();
+    /// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .set_all_label_area_size(40)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+    ///     .unwrap();
+    /// let stack = StackedAreaSeries::new(PaletteCategory10)
+    ///     .layer("a", (0..=10).map(|x| (x as f64, 5.0)))
+    ///     .layer("b", (0..=10).map(|x| (x as f64, x as f64)))
+    ///     .normalized();
+    /// chart
+    ///     .configure_mesh()
+    ///     .y_label_formatter(&stack.percent_formatter())
+    ///     .draw()
+    ///     .unwrap();
+    /// chart.draw_stacked_areas(stack).unwrap();
+    /// ```
+    pub fn normalized(mut self) -> Self {
+        self.normalized = true;
+        self
+    }
+
+    /// Get the formatter of the Y axis, which labels the percentages with the `%` sign
+    pub fn percent_formatter(&self) -> impl Fn(&f64) -> String {
+        |value| format_percent(*value)
+    }
+
+    /// Compute the label, the style and the outline of each layer, from the bottom to the top
+    pub(crate) fn build_layers(&mut self) -> Vec<StackedLayer> {
+        let mut xs: Vec<f64> = self
+            .layers
+            .iter()
+            .flat_map(|(_, points)| points.iter().map(|p| p.0))
+            .collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        xs.dedup();
+
+        let values: Vec<Vec<f64>> = self
+            .layers
+            .iter()
+            .map(|(_, points)| xs.iter().map(|&x| interpolate(points, x)).collect())
+            .collect();
+        let scales: Vec<f64> = (0..xs.len())
+            .map(|i| {
+                let total: f64 = values.iter().map(|v| v[i].abs()).sum();
+                if !self.normalized {
+                    1.0
+                } else if total == 0.0 {
+                    0.0
+                } else {
+                    100.0 / total
+                }
+            })
+            .collect();
+
+        let mut lower = vec![self.baseline; xs.len()];
+        let mut result = vec![];
+        for ((label, _), values) in self.layers.iter().zip(values.iter()) {
+            let upper: Vec<_> = (0..xs.len())
+                .map(|i| lower[i] + values[i] * scales[i])
+                .collect();
+            let mut outline: Vec<_> = xs.iter().cloned().zip(upper.iter().cloned()).collect();
+            outline.extend(xs.iter().cloned().zip(lower.iter().cloned()).rev());
+            result.push((label.clone(), self.colors.next_filled_style(), outline));
+            lower = upper;
+        }
+        result
+    }
+}
+
+impl<P: Palette> Iterator for StackedAreaSeries<P> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.polygons.is_none() {
+            self.polygons = Some(self.build_layers().into_iter());
+        }
+        self.polygons
+            .as_mut()
+            .and_then(Iterator::next)
+            .map(|(_, style, outline)| Polygon::new(outline, style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interpolate() {
+        let points = [(0.0, 1.0), (2.0, 3.0), (3.0, std::f64::NAN)];
+        assert_eq!(interpolate(&points, -1.0), 0.0);
+        assert_eq!(interpolate(&points, 0.0), 1.0);
+        assert_eq!(interpolate(&points, 1.0), 2.0);
+        assert_eq!(interpolate(&points, 3.0), 0.0);
+        assert_eq!(interpolate(&points, 4.0), 0.0);
+    }
+
+    #[test]
+    fn test_stacking() {
+        let polygons: Vec<_> = StackedAreaSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 1.0), (2.0, 1.0)])
+            .layer("b", vec![(1.0, 2.0), (2.0, 4.0)])
+            .collect();
+        assert_eq!(polygons.len(), 2);
+        let points: Vec<_> = (&polygons[1]).point_iter().to_vec();
+        assert_eq!(
+            points,
+            vec![
+                (0.0, 1.0),
+                (1.0, 3.0),
+                (2.0, 5.0),
+                (2.0, 1.0),
+                (1.0, 1.0),
+                (0.0, 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalized_stacking() {
+        let stack = StackedAreaSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 1.0), (2.0, 1.0)])
+            .layer("b", vec![(0.0, 3.0), (1.0, 0.0), (2.0, 0.0)])
+            .layer("c", vec![(0.0, 0.0), (1.0, 0.0)])
+            .normalized();
+        assert_eq!(stack.percent_formatter()(&25.0), "25%");
+        let polygons: Vec<_> = stack.collect();
+        let upper = |idx: usize| -> Vec<_> { (&polygons[idx]).point_iter()[..3].to_vec() };
+        assert_eq!(upper(0), vec![(0.0, 25.0), (1.0, 100.0), (2.0, 100.0)]);
+        assert_eq!(upper(1), vec![(0.0, 100.0), (1.0, 100.0), (2.0, 100.0)]);
+        assert_eq!(upper(2), upper(1));
+
+        // The negative values take their share of the magnitude, as in the normalized bars
+        let polygons: Vec<_> = StackedAreaSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 3.0)])
+            .layer("b", vec![(0.0, -1.0)])
+            .normalized()
+            .collect();
+        assert_eq!((&polygons[0]).point_iter()[0], (0.0, 75.0));
+        assert_eq!((&polygons[1]).point_iter()[0], (0.0, 50.0));
+    }
+
+    #[test]
+    fn test_draw_stacked_areas() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, PaletteCategory10::pick(0).to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, PaletteCategory10::pick(1).to_rgba());
+            });
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "a");
+            });
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "b");
+            });
+            m.drop_check(|b| {
+                // The legend glyphs are rectangles, so only the layers are polygons
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..5.0)
+            .unwrap();
+        let stack = StackedAreaSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 1.0), (2.0, 1.0)])
+            .layer("b", vec![(1.0, 2.0), (2.0, 4.0)]);
+        chart.draw_stacked_areas(stack).unwrap();
+        chart
+            .configure_series_labels()
+            .border_style(&TRANSPARENT)
+            .background_style(&TRANSPARENT)
+            .draw()
+            .unwrap();
+    }
+}
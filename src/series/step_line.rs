@@ -0,0 +1,184 @@
+use num_traits::{NumCast, ToPrimitive};
+
+#[cfg(feature = "line_series")]
+use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
+#[cfg(feature = "line_series")]
+use crate::style::ShapeStyle;
+#[cfg(feature = "line_series")]
+use plotters_backend::DrawingBackend;
+#[cfg(feature = "line_series")]
+use std::marker::PhantomData;
+
+/// Where the value changes between two data points of a step line
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepKind {
+    /// The value changes at the previous point, so each point's value is held since the point
+    /// before it
+    Pre,
+    /// The value changes at the next point, so each point's value is held until the point after it
+    Post,
+    /// The value changes halfway between the points
+    Mid,
+}
+
+/// Turn the data points into the corners of the staircase with the given step position
+pub(crate) fn step_points<X, Y>(points: &[(X, Y)], kind: StepKind) -> Vec<(X, Y)>
+where
+    X: Clone + ToPrimitive + NumCast,
+    Y: Clone,
+{
+    let mut result = Vec::with_capacity(points.len() * 2);
+    for (i, (x, y)) in points.iter().enumerate() {
+        if i > 0 {
+            let (px, py) = &points[i - 1];
+            match kind {
+                StepKind::Pre => result.push((px.clone(), y.clone())),
+                StepKind::Post => result.push((x.clone(), py.clone())),
+                StepKind::Mid => {
+                    let mid = match (px.to_f64(), x.to_f64()) {
+                        (Some(a), Some(b)) => NumCast::from(a + (b - a) / 2.0),
+                        _ => None,
+                    };
+                    let mid = mid.unwrap_or_else(|| x.clone());
+                    result.push((mid.clone(), py.clone()));
+                    result.push((mid, y.clone()));
+                }
+            }
+        }
+        result.push((x.clone(), y.clone()));
+    }
+    result
+}
+
+/// The step line series, which connects the data points with horizontal and vertical segments,
+/// so that discrete signals are drawn as staircases
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..10, 0..2)
+///     .unwrap();
+/// let signal = vec![(0, 0), (2, 1), (5, 0), (6, 1), (9, 1)];
+/// chart
+///     .draw_series(StepLineSeries::new(signal, StepKind::Post, &RED))
+///     .unwrap();
+/// ```
+#[cfg(feature = "line_series")]
+pub struct StepLineSeries<DB: DrawingBackend, X, Y> {
+    style: ShapeStyle,
+    data: Vec<(X, Y)>,
+    kind: StepKind,
+    point_idx: usize,
+    point_size: u32,
+    phantom: PhantomData<DB>,
+}
+
+#[cfg(feature = "line_series")]
+impl<DB, X, Y> Iterator for StepLineSeries<DB, X, Y>
+where
+    DB: DrawingBackend,
+    X: Clone + ToPrimitive + NumCast + 'static,
+    Y: Clone + 'static,
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.data.is_empty() {
+            if self.point_size > 0 && self.point_idx < self.data.len() {
+                let idx = self.point_idx;
+                self.point_idx += 1;
+                return Some(
+                    Circle::new(self.data[idx].clone(), self.point_size, self.style.clone())
+                        .into_dyn(),
+                );
+            }
+            let data = step_points(&self.data, self.kind);
+            self.data.clear();
+            Some(PathElement::new(data, self.style.clone()).into_dyn())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "line_series")]
+impl<DB: DrawingBackend, X, Y> StepLineSeries<DB, X, Y> {
+    /// Create a new step line series
+    /// - `iter`: The data points, in the order of the X values
+    /// - `kind`: Where the value changes between two points
+    /// - `style`: The style of the line
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(
+        iter: I,
+        kind: StepKind,
+        style: S,
+    ) -> Self {
+        Self {
+            style: style.into(),
+            data: iter.into_iter().collect(),
+            kind,
+            point_size: 0,
+            point_idx: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Draw the data points as circles of the given size
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = size;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_step_points() {
+        let points = [(0, 1), (2, 3), (4, 2)];
+        assert_eq!(
+            step_points(&points, StepKind::Pre),
+            vec![(0, 1), (0, 3), (2, 3), (2, 2), (4, 2)]
+        );
+        assert_eq!(
+            step_points(&points, StepKind::Post),
+            vec![(0, 1), (2, 1), (2, 3), (4, 3), (4, 2)]
+        );
+        assert_eq!(
+            step_points(&points, StepKind::Mid),
+            vec![(0, 1), (1, 1), (1, 3), (2, 3), (3, 3), (3, 2), (4, 2)]
+        );
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_step_line_series() {
+        use crate::prelude::*;
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 3);
+                // The horizontal segment comes first with the post step
+                assert_eq!(path[0].1, path[1].1);
+                assert_eq!(path[1].0, path[2].0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        chart
+            .draw_series(StepLineSeries::new(
+                vec![(0, 0), (10, 10)],
+                StepKind::Post,
+                &RED,
+            ))
+            .unwrap();
+    }
+}
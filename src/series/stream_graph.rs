@@ -0,0 +1,282 @@
+use super::spline::{spline_points, SplineKind, DEFAULT_RESOLUTION};
+use super::stacked_area::{interpolate, StackedLayer};
+use crate::element::Polygon;
+use crate::style::{ColorCycle, Palette};
+
+/// Where the bottom of a [StreamGraphSeries](struct.StreamGraphSeries.html) is
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StreamBaseline {
+    /// The stack is centered around zero, so the outline is symmetric
+    Symmetric,
+    /// The baseline that minimizes the weighted changes of the slopes of the layers, which keeps
+    /// the layers as flat as it can. The stack is shifted to be centered around zero on average.
+    Wiggle,
+}
+
+/// The stream graph, or the theme river, which stacks several layers around a baseline that
+/// moves with the data, so the layers flow like a river instead of being piled up on the X axis.
+///
+/// The layers are stacked over the union of all the X values in the same way as
+/// [StackedAreaSeries](struct.StackedAreaSeries.html), and the boundaries between the layers are
+/// smoothed by the monotone cubic interpolation, which never makes a layer thinner than zero.
+/// The layers are filled with the colors of the palette in order.
+///
+/// Drawing the series with [ChartContext::draw_stream_graph](../chart/struct.ChartContext.html#method.draw_stream_graph)
+/// also adds a legend entry for each layer.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..20.0, -15.0..15.0)
+///     .unwrap();
+/// let stream = StreamGraphSeries::new(PaletteCategory10)
+///     .layer("a", (0..=20).map(|x| (x as f64, 5.0 + (x as f64 / 3.0).sin() * 4.0)))
+///     .layer("b", (0..=20).map(|x| (x as f64, x as f64 / 4.0)))
+///     .layer("c", (0..=20).map(|x| (x as f64, (10.0 - x as f64).abs() / 2.0)));
+/// chart.draw_stream_graph(stream).unwrap();
+/// chart.configure_series_labels().draw().unwrap();
+/// ```
+pub struct StreamGraphSeries<P: Palette> {
+    layers: Vec<(String, Vec<(f64, f64)>)>,
+    colors: ColorCycle<P>,
+    baseline: StreamBaseline,
+    resolution: usize,
+    polygons: Option<std::vec::IntoIter<StackedLayer>>,
+}
+
+impl<P: Palette> StreamGraphSeries<P> {
+    /// Create a new stream graph without layers, whose baseline is
+    /// [Wiggle](enum.StreamBaseline.html#variant.Wiggle)
+    /// - `palette`: The palette that the layers are filled with
+    /// - **returns** The newly created series
+    pub fn new(palette: P) -> Self {
+        Self {
+            layers: vec![],
+            colors: ColorCycle::new(palette),
+            baseline: StreamBaseline::Wiggle,
+            resolution: DEFAULT_RESOLUTION,
+            polygons: None,
+        }
+    }
+
+    /// Add a layer on the top of the stack
+    /// - `label`: The label of the layer in the legend
+    /// - `iter`: The data points of the layer, the points with a NaN X value are ignored and the
+    ///   negative values are treated as zero
+    pub fn layer<L: Into<String>, I: IntoIterator<Item = (f64, f64)>>(
+        mut self,
+        label: L,
+        iter: I,
+    ) -> Self {
+        let mut points: Vec<_> = iter
+            .into_iter()
+            .filter(|p| !p.0.is_nan())
+            .map(|(x, y)| (x, if y > 0.0 { y } else { 0.0 }))
+            .collect();
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        self.layers.push((label.into(), points));
+        self
+    }
+
+    /// Set where the bottom of the stack is
+    pub fn baseline(mut self, baseline: StreamBaseline) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Set the number of the segments between two X values, which is 16 by default, and 1 draws
+    /// the straight lines between the data points
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// The bottom of the stack at each X value
+    fn baseline_values(&self, xs: &[f64], thickness: &[Vec<f64>]) -> Vec<f64> {
+        let total: Vec<f64> = (0..xs.len())
+            .map(|j| thickness.iter().map(|t| t[j]).sum())
+            .collect();
+        match self.baseline {
+            StreamBaseline::Symmetric => total.iter().map(|s| -s / 2.0).collect(),
+            StreamBaseline::Wiggle => {
+                // Each layer moves the baseline against the change of the middle of the layer,
+                // weighted by its thickness (Byron and Wattenberg)
+                let mut baseline = vec![0.0; xs.len()];
+                for j in 1..xs.len() {
+                    let mut below = 0.0;
+                    let mut moved = 0.0;
+                    for t in thickness {
+                        let change = t[j] - t[j - 1];
+                        moved += (below + change / 2.0) * t[j];
+                        below += change;
+                    }
+                    baseline[j] = baseline[j - 1];
+                    if total[j] > 0.0 {
+                        baseline[j] -= moved / total[j];
+                    }
+                }
+                let center = baseline
+                    .iter()
+                    .zip(&total)
+                    .map(|(b, s)| b + s / 2.0)
+                    .sum::<f64>()
+                    / xs.len() as f64;
+                baseline.iter().map(|b| b - center).collect()
+            }
+        }
+    }
+
+    /// Compute the label, the style and the outline of each layer, from the bottom to the top
+    pub(crate) fn build_layers(&mut self) -> Vec<StackedLayer> {
+        let mut xs: Vec<f64> = self
+            .layers
+            .iter()
+            .flat_map(|(_, points)| points.iter().map(|p| p.0))
+            .collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        xs.dedup();
+        if xs.is_empty() {
+            return vec![];
+        }
+
+        let thickness: Vec<Vec<f64>> = self
+            .layers
+            .iter()
+            .map(|(_, points)| xs.iter().map(|&x| interpolate(points, x)).collect())
+            .collect();
+        // The baseline and the thickness of each layer are smoothed separately, so the layers
+        // are never thinner than zero and all the curves share the same X values
+        let resolution = self.resolution;
+        let smooth = |values: &[f64]| -> Vec<(f64, f64)> {
+            let points: Vec<_> = xs.iter().cloned().zip(values.iter().cloned()).collect();
+            spline_points(&points, SplineKind::Monotone, resolution)
+        };
+        let mut lower = smooth(&self.baseline_values(&xs, &thickness));
+
+        let mut result = vec![];
+        for ((label, _), values) in self.layers.iter().zip(&thickness) {
+            let upper: Vec<_> = lower
+                .iter()
+                .zip(smooth(values))
+                .map(|(&(x, y), (_, t))| (x, y + t))
+                .collect();
+            let mut outline = upper.clone();
+            outline.extend(lower.iter().rev());
+            result.push((label.clone(), self.colors.next_filled_style(), outline));
+            lower = upper;
+        }
+        result
+    }
+}
+
+impl<P: Palette> Iterator for StreamGraphSeries<P> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.polygons.is_none() {
+            self.polygons = Some(self.build_layers().into_iter());
+        }
+        self.polygons
+            .as_mut()
+            .and_then(Iterator::next)
+            .map(|(_, style, outline)| Polygon::new(outline, style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    fn bottoms(series: StreamGraphSeries<PaletteCategory10>) -> Vec<Vec<f64>> {
+        series
+            .resolution(1)
+            .map(|p| {
+                let points: Vec<_> = (&p).point_iter().to_vec();
+                points[points.len() / 2..]
+                    .iter()
+                    .rev()
+                    .map(|p| p.1)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stream_baseline() {
+        let stream = StreamGraphSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 1.0), (1.0, 3.0)])
+            .layer("b", vec![(0.0, 1.0), (1.0, 1.0)])
+            .baseline(StreamBaseline::Symmetric);
+        assert_eq!(bottoms(stream), vec![vec![-1.0, -2.0], vec![0.0, 1.0]]);
+
+        // A single layer isn't bent at all, and the stack is centered on average
+        let stream =
+            StreamGraphSeries::new(PaletteCategory10).layer("a", vec![(0.0, 1.0), (1.0, 3.0)]);
+        assert_eq!(bottoms(stream), vec![vec![-0.5, -1.5]]);
+
+        // The thin layer on the top stays flat when the bottom layer grows
+        let stream = StreamGraphSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 1.0), (1.0, 3.0)])
+            .layer("b", vec![(0.0, 1.0), (1.0, 1.0)]);
+        let layers = bottoms(stream);
+        assert!(layers[1][1] - layers[1][0] < 2.0);
+        assert!(layers[0][1] < layers[0][0]);
+    }
+
+    #[test]
+    fn test_smooth_layers() {
+        let layers: Vec<_> = StreamGraphSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 1.0), (1.0, 0.0), (2.0, 1.0)])
+            .layer("b", vec![(0.0, 2.0), (1.0, 2.0), (2.0, 2.0)])
+            .resolution(4)
+            .collect();
+        let points: Vec<_> = (&layers[0]).point_iter().to_vec();
+        assert_eq!(points.len(), 2 * (2 * 4 + 1));
+        // The layer that reaches zero doesn't become negative between the data points
+        let n = points.len() / 2;
+        for i in 0..n {
+            let (top, bottom) = (points[i], points[points.len() - 1 - i]);
+            assert_eq!(top.0, bottom.0);
+            assert!(top.1 - bottom.1 >= -1e-9);
+        }
+    }
+
+    #[test]
+    fn test_draw_stream_graph() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, PaletteCategory10::pick(0).to_rgba());
+            });
+            m.check_fill_polygon(|c, _| {
+                assert_eq!(c, PaletteCategory10::pick(1).to_rgba());
+            });
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "a");
+            });
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "b");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, -5.0..5.0)
+            .unwrap();
+        let stream = StreamGraphSeries::new(PaletteCategory10)
+            .layer("a", vec![(0.0, 1.0), (1.0, 2.0), (2.0, 1.0)])
+            .layer("b", vec![(0.0, 2.0), (2.0, 4.0)]);
+        chart.draw_stream_graph(stream).unwrap();
+        chart
+            .configure_series_labels()
+            .border_style(&TRANSPARENT)
+            .background_style(&TRANSPARENT)
+            .draw()
+            .unwrap();
+    }
+}
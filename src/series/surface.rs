@@ -1,6 +1,18 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian3d;
+use crate::coord::ranged1d::Ranged;
 use crate::element::Polygon;
-use crate::style::{colors::BLUE, Color, ShapeStyle};
+use crate::style::{colors::BLUE, Color, ColorMap, RGBAColor, ShapeStyle};
+use num_traits::ToPrimitive;
+use plotters_backend::DrawingBackend;
 use std::marker::PhantomData;
+
+/// The share of the light that reaches the faces that are turned away from the light source
+const DEFAULT_AMBIENT: f64 = 0.3;
+
+/// A color map along with the conversion from the surface value to a number
+type ValueColorMap<'a, T> = (&'a dyn ColorMap, fn(&T) -> Option<f64>);
+
 pub trait Direction<X, Y, Z> {
     type Input1Type;
     type Input2Type;
@@ -48,10 +60,28 @@ impl<T> StyleConfig<'_, T> {
 
 /// The surface series.
 ///
-/// Currently the surface is representing any surface in form
-/// y = f(x,z)
+/// The surface is a function of two of the axes, such as `y = f(x, z)` for
+/// [SurfaceSeries::xoz](#method.xoz), and it's drawn as a grid of quadrilateral faces. The faces
+/// are emitted in the order of the grid, thus a surface that folds over itself may be drawn with
+/// the far faces on top of the near ones. [SurfaceSeries::shaded](#method.shaded) sorts the faces
+/// from the farthest to the nearest and shades them by the light source instead.
+///
+/// ```rust
+/// use plotters::prelude::*;
 ///
-/// TODO: make this more general
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_3d(-3.0..3.0, -1.0..1.0, -3.0..3.0)
+///     .unwrap();
+/// let axis = || (-30..=30).map(|v| v as f64 / 10.0);
+/// let surface = SurfaceSeries::xoz(axis(), axis(), |x: f64, z: f64| (x * x + z * z).cos())
+///     .light_source(1.0, 2.0, -1.0)
+///     .ambient(0.2)
+///     .color_map(&ViridisRGB)
+///     .shaded(&chart);
+/// chart.draw_series(surface).unwrap();
+/// ```
 pub struct SurfaceSeries<'a, X, Y, Z, D, SurfaceFunc>
 where
     D: Direction<X, Y, Z>,
@@ -61,6 +91,9 @@ where
     free_var_2: Vec<D::Input2Type>,
     surface_f: SurfaceFunc,
     style: StyleConfig<'a, D::OutputType>,
+    light: (f64, f64, f64),
+    ambient: f64,
+    color_map: Option<ValueColorMap<'a, D::OutputType>>,
     vidx_1: usize,
     vidx_2: usize,
     _phantom: PhantomData<(X, Y, Z, D)>,
@@ -81,6 +114,9 @@ where
             free_var_2: second_iter.collect(),
             surface_f: func,
             style: StyleConfig::Fixed(BLUE.mix(0.4).filled()),
+            light: (1.0, 1.0, 1.0),
+            ambient: DEFAULT_AMBIENT,
+            color_map: None,
             vidx_1: 0,
             vidx_2: 0,
             _phantom: PhantomData,
@@ -96,6 +132,161 @@ where
         self.style = StyleConfig::Fixed(s.into());
         self
     }
+
+    /// Set the direction towards the light source of the shaded surface, which is in the unit
+    /// of the axes of the chart, i.e. `(0.0, 1.0, 0.0)` is the light from the top of the Y axis.
+    /// The light comes from `(1.0, 1.0, 1.0)` by default.
+    pub fn light_source(mut self, x: f64, y: f64, z: f64) -> Self {
+        self.light = (x, y, z);
+        self
+    }
+
+    /// Set the share of the light that reaches the faces turned away from the light source,
+    /// from 0 to 1, which is 0.3 by default
+    pub fn ambient(mut self, ambient: f64) -> Self {
+        self.ambient = if ambient.is_nan() || ambient <= 0.0 {
+            0.0
+        } else if ambient >= 1.0 {
+            1.0
+        } else {
+            ambient
+        };
+        self
+    }
+
+    /// Color the faces of the shaded surface by their values with the color map, from the
+    /// lowest to the highest value of the surface. This overrides the style.
+    pub fn color_map<M: ColorMap>(mut self, map: &'a M) -> Self
+    where
+        D::OutputType: ToPrimitive,
+    {
+        self.color_map = Some((map, ToPrimitive::to_f64));
+        self
+    }
+}
+
+impl<'a, X, Y, Z, D, SurfaceFunc> SurfaceSeries<'a, X, Y, Z, D, SurfaceFunc>
+where
+    D: Direction<X, Y, Z>,
+    D::Input1Type: Clone,
+    D::Input2Type: Clone,
+    D::OutputType: Clone,
+    SurfaceFunc: Fn(D::Input1Type, D::Input2Type) -> D::OutputType,
+{
+    /// Compute the faces of the surface in the drawing order of the painter's algorithm, from
+    /// the farthest to the nearest, and shade each face by the angle between its normal and the
+    /// light source.
+    /// - `chart`: The chart that the surface is drawn on
+    /// - **returns** The faces that are drawn with `ChartContext::draw_series`
+    pub fn shaded<DB, XR, YR, ZR>(
+        self,
+        chart: &ChartContext<DB, Cartesian3d<XR, YR, ZR>>,
+    ) -> Vec<Polygon<(X, Y, Z)>>
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        ZR: Ranged<ValueType = Z>,
+    {
+        let coord = chart.as_coord_spec();
+        let values: Vec<Vec<_>> = self
+            .free_var_1
+            .iter()
+            .map(|a| {
+                self.free_var_2
+                    .iter()
+                    .map(|b| (self.surface_f)(a.clone(), b.clone()))
+                    .collect()
+            })
+            .collect();
+
+        let light = normalize(self.light);
+        let mut faces = vec![];
+        for i in 1..self.free_var_1.len() {
+            for j in 1..self.free_var_2.len() {
+                let corners = [(i - 1, j - 1), (i - 1, j), (i, j), (i, j - 1)];
+                let vert: Vec<_> = corners
+                    .iter()
+                    .map(|&(i, j)| {
+                        let free = (self.free_var_1[i].clone(), self.free_var_2[j].clone());
+                        D::make_coord(free, values[i][j].clone())
+                    })
+                    .collect();
+                let cube: Vec<_> = vert
+                    .iter()
+                    .map(|(x, y, z)| {
+                        let (x, y, z) = coord.map_3d(x, y, z);
+                        (f64::from(x), f64::from(y), f64::from(z))
+                    })
+                    .collect();
+                let depth: f64 = cube
+                    .iter()
+                    .map(|&(x, y, z)| {
+                        let p = (x.round() as i32, y.round() as i32, z.round() as i32);
+                        f64::from(coord.projection().projected_depth(p))
+                    })
+                    .sum();
+
+                // The normal is the cross product of the diagonals, which is the orientation of
+                // the face even if the 4 corners aren't on the same plane
+                let u = sub(cube[2], cube[0]);
+                let v = sub(cube[3], cube[1]);
+                let normal = normalize((
+                    u.1 * v.2 - u.2 * v.1,
+                    u.2 * v.0 - u.0 * v.2,
+                    u.0 * v.1 - u.1 * v.0,
+                ));
+                let cos = normal.0 * light.0 + normal.1 * light.1 + normal.2 * light.2;
+                let intensity = self.ambient + (1.0 - self.ambient) * cos.abs();
+
+                let value = self.color_map.and_then(|(_, to_f64)| {
+                    let sum = corners
+                        .iter()
+                        .map(|&(i, j)| to_f64(&values[i][j]))
+                        .sum::<Option<f64>>()?;
+                    Some(sum / 4.0)
+                });
+                let style = self.style.get_style(&values[i - 1][j - 1]);
+                faces.push((vert, depth, intensity, value, style));
+            }
+        }
+
+        let range = faces
+            .iter()
+            .filter_map(|face| face.3)
+            .fold(None, |range, v| {
+                Some(range.map_or((v, v), |(min, max): (f64, f64)| (min.min(v), max.max(v))))
+            });
+        faces.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        faces
+            .into_iter()
+            .map(|(vert, _, intensity, value, mut style)| {
+                let base = match (self.color_map, value, range) {
+                    (Some((map, _)), Some(value), Some((min, max))) => map
+                        .get_color_normalized(value, min, max)
+                        .mix(style.color.alpha()),
+                    _ => style.color,
+                };
+                let shade = |c: u8| (f64::from(c) * intensity).round() as u8;
+                let rgb = base.rgb();
+                style.color = RGBAColor(shade(rgb.0), shade(rgb.1), shade(rgb.2), base.alpha());
+                Polygon::new(vert, style)
+            })
+            .collect()
+    }
+}
+
+fn sub(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
+    (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+}
+
+fn normalize((x, y, z): (f64, f64, f64)) -> (f64, f64, f64) {
+    let length = (x * x + y * y + z * z).sqrt();
+    if length == 0.0 {
+        (0.0, 0.0, 0.0)
+    } else {
+        (x / length, y / length, z / length)
+    }
 }
 
 macro_rules! impl_constructor {
@@ -121,6 +312,56 @@ macro_rules! impl_constructor {
 impl_constructor!(XOY, xoy);
 impl_constructor!(XOZ, xoz);
 impl_constructor!(YOZ, yoz);
+
+/// The surface function that looks up the values of a 2D array
+#[cfg(feature = "ndarray")]
+pub type ArraySurfaceFunc<'a> = Box<dyn Fn(f64, f64) -> f64 + 'a>;
+
+#[cfg(feature = "ndarray")]
+macro_rules! impl_array_constructor {
+    ($dir: ty, $name: ident, $doc: literal) => {
+        impl<'a> SurfaceSeries<'a, f64, f64, f64, $dir, ArraySurfaceFunc<'a>> {
+            #[doc = $doc]
+            pub fn $name(
+                first: ndarray::ArrayView1<'a, f64>,
+                second: ndarray::ArrayView1<'a, f64>,
+                values: ndarray::ArrayView2<'a, f64>,
+            ) -> Self {
+                let (free_1, free_2) = (first.to_vec(), second.to_vec());
+                // The free variables are the values of the arrays, thus they're found exactly
+                let lookup: ArraySurfaceFunc<'a> = Box::new(move |a, b| {
+                    match (
+                        first.iter().position(|v| *v == a),
+                        second.iter().position(|v| *v == b),
+                    ) {
+                        (Some(i), Some(j)) => values.get((i, j)).cloned().unwrap_or(f64::NAN),
+                        _ => f64::NAN,
+                    }
+                });
+                Self::new(free_1.into_iter(), free_2.into_iter(), lookup)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ndarray")]
+impl_array_constructor!(
+    XOY,
+    xoy_array,
+    "Create a surface from a 2D array, the value at `[i, j]` is Z at X `first[i]` and Y `second[j]`"
+);
+#[cfg(feature = "ndarray")]
+impl_array_constructor!(
+    XOZ,
+    xoz_array,
+    "Create a surface from a 2D array, the value at `[i, j]` is Y at X `first[i]` and Z `second[j]`"
+);
+#[cfg(feature = "ndarray")]
+impl_array_constructor!(
+    YOZ,
+    yoz_array,
+    "Create a surface from a 2D array, the value at `[i, j]` is X at Y `first[i]` and Z `second[j]`"
+);
 impl<'a, X, Y, Z, D, SurfaceFunc> Iterator for SurfaceSeries<'a, X, Y, Z, D, SurfaceFunc>
 where
     D: Direction<X, Y, Z>,
@@ -176,3 +417,59 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_shaded_surface() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, _| {
+                // The flat surface is turned away from the light from the side
+                assert_eq!(c, RGBAColor(77, 0, 0, 1.0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 4);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..2.0, 0.0..2.0, 0.0..2.0)
+            .unwrap();
+
+        let axis = || vec![0.0, 1.0, 2.0].into_iter();
+        let faces = SurfaceSeries::xoz(axis(), axis(), |x: f64, z: f64| x * z / 2.0).shaded(&chart);
+        let coord = chart.as_coord_spec();
+        let depths: Vec<i32> = faces
+            .iter()
+            .map(|face| {
+                face.point_iter()
+                    .iter()
+                    .map(|(x, y, z)| coord.projected_depth(x, y, z))
+                    .sum()
+            })
+            .collect();
+        assert_eq!(depths.len(), 4);
+        assert!(depths.windows(2).all(|w| w[0] >= w[1]));
+
+        let faces = SurfaceSeries::xoz(axis(), axis(), |_: f64, _: f64| 1.0)
+            .style(RED.filled())
+            .light_source(1.0, 0.0, 0.0)
+            .shaded(&chart);
+        chart.draw_series(faces).unwrap();
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_surface_from_array() {
+        let xs = ndarray::arr1(&[0.0, 1.0, 2.0]);
+        let zs = ndarray::arr1(&[0.0, 1.0]);
+        let ys = ndarray::arr2(&[[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+        let faces: Vec<_> = SurfaceSeries::xoz_array(xs.view(), zs.view(), ys.view()).collect();
+        assert_eq!(faces.len(), 2);
+        let points: Vec<_> = (&faces[1]).point_iter().to_vec();
+        assert!(points.contains(&(2.0, 5.0, 1.0)));
+    }
+}
@@ -0,0 +1,433 @@
+use std::ops::Range;
+
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ColorMap, Palette, ShapeStyle, TextStyle, WHITE};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// A node of the hierarchical data of a [TreemapSeries](struct.TreemapSeries.html), which is
+/// either a leaf with a value or a branch whose value is the sum of its children
+#[derive(Clone, Debug)]
+pub struct TreemapNode {
+    label: String,
+    value: f64,
+    children: Vec<TreemapNode>,
+}
+
+impl TreemapNode {
+    /// Create a leaf
+    /// - `label`: The label of the leaf
+    /// - `value`: The weight of the leaf, the negative and NaN values are treated as zero
+    /// - **returns** The newly created node
+    pub fn leaf<L: Into<String>>(label: L, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value: if value > 0.0 { value } else { 0.0 },
+            children: vec![],
+        }
+    }
+
+    /// Create a branch
+    /// - `label`: The label of the branch
+    /// - `children`: The children of the branch
+    /// - **returns** The newly created node
+    pub fn branch<L: Into<String>>(label: L, children: Vec<TreemapNode>) -> Self {
+        Self {
+            label: label.into(),
+            value: children.iter().map(|c| c.value).sum(),
+            children,
+        }
+    }
+
+    /// Get the weight of the node
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// The worst aspect ratio of the rectangles of a non-empty row of the areas along a side
+fn worst(row: &[f64], side: f64) -> f64 {
+    let sum: f64 = row.iter().sum();
+    let max = row.iter().cloned().fold(row[0], f64::max);
+    let min = row.iter().cloned().fold(row[0], f64::min);
+    let (sum2, side2) = (sum * sum, side * side);
+    (side2 * max / sum2).max(sum2 / (side2 * min))
+}
+
+/// Split the rectangle into the rectangles whose areas are proportional to the values, with the
+/// squarified treemap layout of Bruls, Huizing and van Wijk, which keeps the aspect ratios close
+/// to 1. The rectangles are in the order of the values, and the values that aren't positive get
+/// empty rectangles.
+pub(crate) fn squarify(values: &[f64], rect: [(f64, f64); 2]) -> Vec<[(f64, f64); 2]> {
+    let ((x0, y0), (x1, y1)) = (rect[0], rect[1]);
+    let (width, height) = ((x1 - x0).abs(), (y1 - y0).abs());
+    let mut result = vec![[(x0, y0), (x0, y0)]; values.len()];
+    let total: f64 = values.iter().filter(|v| **v > 0.0).sum();
+    if total <= 0.0 || width * height <= 0.0 {
+        return result;
+    }
+
+    // The layout is in the rectangle of the positive size, which is mapped back at the end
+    let map = |(x, y): (f64, f64)| (x0 + x * (x1 - x0) / width, y0 + y * (y1 - y0) / height);
+    let mut order: Vec<usize> = (0..values.len()).filter(|&i| values[i] > 0.0).collect();
+    order.sort_by(|&a, &b| {
+        values[b]
+            .partial_cmp(&values[a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let areas: Vec<f64> = values.iter().map(|v| v / total * width * height).collect();
+
+    let mut free = (0.0, 0.0, width, height);
+    let mut row: Vec<usize> = vec![];
+    let mut next = 0;
+    while next <= order.len() {
+        let side = free.2.min(free.3);
+        if next < order.len() {
+            let current: Vec<f64> = row.iter().map(|&i| areas[i]).collect();
+            let mut extended = current.clone();
+            extended.push(areas[order[next]]);
+            if row.is_empty() || worst(&extended, side) <= worst(&current, side) {
+                row.push(order[next]);
+                next += 1;
+                continue;
+            }
+        } else if row.is_empty() {
+            break;
+        }
+
+        // Put the row along the shorter side of the free space
+        let sum: f64 = row.iter().map(|&i| areas[i]).sum();
+        let (x, y, w, h) = free;
+        let mut offset = 0.0;
+        if w >= h {
+            let thickness = sum / h;
+            for &i in &row {
+                let length = areas[i] / thickness;
+                result[i] = [
+                    map((x, y + offset)),
+                    map((x + thickness, y + offset + length)),
+                ];
+                offset += length;
+            }
+            free = (x + thickness, y, w - thickness, h);
+        } else {
+            let thickness = sum / w;
+            for &i in &row {
+                let length = areas[i] / thickness;
+                result[i] = [
+                    map((x + offset, y)),
+                    map((x + offset + length, y + thickness)),
+                ];
+                offset += length;
+            }
+            free = (x, y + thickness, w, h - thickness);
+        }
+        row.clear();
+        if next == order.len() {
+            break;
+        }
+    }
+    result
+}
+
+/// A rectangle of a treemap, which is the filled rectangle of a leaf, or the outline of a branch
+pub struct TreemapCell<'a> {
+    rect: [(f64, f64); 2],
+    fill: Option<ShapeStyle>,
+    border: ShapeStyle,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'b, 'a> PointCollection<'b, (f64, f64)> for &'b TreemapCell<'a> {
+    type Point = &'b (f64, f64);
+    type IntoIter = &'b [(f64, f64)];
+    fn point_iter(self) -> &'b [(f64, f64)] {
+        &self.rect
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for TreemapCell<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        let (ul, br) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        if let Some(ref fill) = self.fill {
+            backend.draw_rect(ul, br, fill, true)?;
+        }
+        backend.draw_rect(ul, br, &self.border, false)?;
+        if let Some((ref text, ref style)) = self.label {
+            // The labels that don't fit the cells are left out
+            let style = style.pos(Pos::new(HPos::Center, VPos::Center));
+            let (w, h) = backend.estimate_text_size(text, &style)?;
+            if w as i32 <= br.0 - ul.0 && h as i32 <= br.1 - ul.1 {
+                backend.draw_text(text, &style, ((ul.0 + br.0) / 2, (ul.1 + br.1) / 2))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The series that draws the hierarchical weighted data as a treemap, which splits the given
+/// area of the chart into the nested rectangles whose areas are proportional to the values, with
+/// the squarified layout.
+///
+/// The branches are drawn as the outlines around their children, and the leaves are filled with
+/// the color of their top level branch from the palette, or with the color of their value from a
+/// color map. The leaves are labeled if the labels fit them.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let usage = TreemapNode::branch(
+///     "/",
+///     vec![
+///         TreemapNode::branch(
+///             "home",
+///             vec![TreemapNode::leaf("photos", 40.0), TreemapNode::leaf("music", 15.0)],
+///         ),
+///         TreemapNode::branch(
+///             "usr",
+///             vec![TreemapNode::leaf("lib", 20.0), TreemapNode::leaf("bin", 5.0)],
+///         ),
+///         TreemapNode::leaf("tmp", 3.0),
+///     ],
+/// );
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         TreemapSeries::new(usage, 0.0..1.0, 0.0..1.0, PaletteCategory10)
+///             .labels(("sans-serif", 12)),
+///     )
+///     .unwrap();
+/// ```
+pub struct TreemapSeries<'a, P: Palette> {
+    root: TreemapNode,
+    x_range: Range<f64>,
+    y_range: Range<f64>,
+    palette: std::marker::PhantomData<P>,
+    color_map: Option<Box<dyn ColorMap + 'a>>,
+    border_style: ShapeStyle,
+    label_style: Option<TextStyle<'a>>,
+    cells: Option<std::vec::IntoIter<TreemapCell<'a>>>,
+}
+
+impl<'a, P: Palette> TreemapSeries<'a, P> {
+    /// Create a new treemap series
+    /// - `root`: The root of the data, whose children fill the area
+    /// - `x_range`: The X range that the treemap fills
+    /// - `y_range`: The Y range that the treemap fills
+    /// - `palette`: The palette that the top level branches are colored with
+    /// - **returns** The newly created series
+    pub fn new(root: TreemapNode, x_range: Range<f64>, y_range: Range<f64>, _palette: P) -> Self {
+        Self {
+            root,
+            x_range,
+            y_range,
+            palette: std::marker::PhantomData,
+            color_map: None,
+            border_style: WHITE.into(),
+            label_style: None,
+            cells: None,
+        }
+    }
+
+    /// Color the leaves by their values with the color map, from the smallest to the largest
+    /// value of the leaves, rather than by their top level branches
+    pub fn color_map<M: ColorMap + 'a>(mut self, map: M) -> Self {
+        self.color_map = Some(Box::new(map));
+        self
+    }
+
+    /// Set the style of the borders of the rectangles, which are white by default
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Draw the labels of the leaves in the center of their rectangles
+    /// - `style`: The style of the labels
+    pub fn labels<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    fn leaf_range(node: &TreemapNode, range: &mut Option<(f64, f64)>) {
+        if node.children.is_empty() {
+            let (min, max) = range.unwrap_or((node.value, node.value));
+            *range = Some((min.min(node.value), max.max(node.value)));
+        }
+        for child in &node.children {
+            Self::leaf_range(child, range);
+        }
+    }
+
+    fn layout(
+        &self,
+        node: &TreemapNode,
+        rect: [(f64, f64); 2],
+        color_idx: usize,
+        value_range: (f64, f64),
+        cells: &mut Vec<TreemapCell<'a>>,
+    ) {
+        let values: Vec<f64> = node.children.iter().map(|c| c.value).collect();
+        for (idx, (child, rect)) in node
+            .children
+            .iter()
+            .zip(squarify(&values, rect))
+            .enumerate()
+        {
+            if child.value <= 0.0 {
+                continue;
+            }
+            // The children of the root pick their own colors, the deeper ones inherit them
+            let color_idx = if std::ptr::eq(node, &self.root) {
+                idx
+            } else {
+                color_idx
+            };
+            if !child.children.is_empty() {
+                cells.push(TreemapCell {
+                    rect,
+                    fill: None,
+                    border: self.border_style.clone(),
+                    label: None,
+                });
+                self.layout(child, rect, color_idx, value_range, cells);
+                continue;
+            }
+            let fill = match self.color_map {
+                Some(ref map) => {
+                    let (min, max) = value_range;
+                    let t = if max > min {
+                        (child.value - min) / (max - min)
+                    } else {
+                        1.0
+                    };
+                    map.get_color(t).filled()
+                }
+                None => P::pick(color_idx).filled(),
+            };
+            cells.push(TreemapCell {
+                rect,
+                fill: Some(fill),
+                border: self.border_style.clone(),
+                label: self
+                    .label_style
+                    .as_ref()
+                    .map(|style| (child.label.clone(), style.clone())),
+            });
+        }
+    }
+}
+
+impl<'a, P: Palette> Iterator for TreemapSeries<'a, P> {
+    type Item = TreemapCell<'a>;
+    fn next(&mut self) -> Option<TreemapCell<'a>> {
+        if self.cells.is_none() {
+            let mut value_range = None;
+            Self::leaf_range(&self.root, &mut value_range);
+            let rect = [
+                (self.x_range.start, self.y_range.start),
+                (self.x_range.end, self.y_range.end),
+            ];
+            let mut cells = vec![];
+            self.layout(
+                &self.root,
+                rect,
+                0,
+                value_range.unwrap_or((0.0, 0.0)),
+                &mut cells,
+            );
+            self.cells = Some(cells.into_iter());
+        }
+        self.cells.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_squarify() {
+        // The example of the paper, which fills a 6 by 4 rectangle
+        let values = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0];
+        let rects = squarify(&values, [(0.0, 0.0), (6.0, 4.0)]);
+        for (value, rect) in values.iter().zip(&rects) {
+            let area = (rect[1].0 - rect[0].0) * (rect[1].1 - rect[0].1);
+            assert!((area - value).abs() < 1e-9);
+        }
+        // The first two are stacked on the left, in a column of the width 3
+        assert_eq!(rects[0], [(0.0, 0.0), (3.0, 2.0)]);
+        assert_eq!(rects[1], [(0.0, 2.0), (3.0, 4.0)]);
+        let worst_ratio = rects
+            .iter()
+            .map(|r| {
+                let (w, h) = (r[1].0 - r[0].0, r[1].1 - r[0].1);
+                w.max(h) / w.min(h)
+            })
+            .fold(0.0, f64::max);
+        assert!(worst_ratio < 3.0);
+
+        // The reversed ranges and the empty values
+        let rects = squarify(&[1.0, 0.0, 1.0], [(0.0, 2.0), (2.0, 0.0)]);
+        assert_eq!(rects[0], [(0.0, 2.0), (2.0, 1.0)]);
+        assert_eq!(rects[1][0], rects[1][1]);
+    }
+
+    #[test]
+    fn test_treemap_series() {
+        let data = TreemapNode::branch(
+            "root",
+            vec![
+                TreemapNode::branch(
+                    "a",
+                    vec![TreemapNode::leaf("a1", 2.0), TreemapNode::leaf("a2", 1.0)],
+                ),
+                TreemapNode::leaf("b", 1.0),
+                TreemapNode::leaf("c", -1.0),
+            ],
+        );
+        assert_eq!(data.value(), 4.0);
+
+        let cells: Vec<_> =
+            TreemapSeries::new(data.clone(), 0.0..1.0, 0.0..1.0, Palette99).collect();
+        // The branch outline and the two leaves of it, then the leaf at the top level
+        assert_eq!(cells.len(), 4);
+        assert!(cells[0].fill.is_none());
+        let colors: Vec<_> = cells[1..]
+            .iter()
+            .map(|c| c.fill.as_ref().unwrap().color)
+            .collect();
+        assert_eq!(colors[0], colors[1]);
+        assert_ne!(colors[0], colors[2]);
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, _, text| assert!(["a1", "a2", "b"].contains(&text)));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 4 + 3));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(
+                TreemapSeries::new(data, 0.0..1.0, 0.0..1.0, Palette99)
+                    .color_map(ViridisRGB)
+                    .labels(("sans-serif", 20)),
+            )
+            .unwrap();
+    }
+}
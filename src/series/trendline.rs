@@ -0,0 +1,132 @@
+use crate::data::{RegressionError, TrendFit, TrendKind};
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+use num_traits::{NumCast, ToPrimitive};
+use std::marker::PhantomData;
+
+/// The series that draws the curve fitted to the data points with the least squares method, as
+/// a line over the X range of the data. The fit is available before the series is drawn, thus
+/// the equation and the R² can be used as the label of the legend.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data = vec![(1.0, 1.2), (2.0, 3.8), (3.0, 9.1), (4.0, 16.3)];
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..5.0, 0.0..20.0)
+///     .unwrap();
+/// let trend = TrendlineSeries::new(data.iter().cloned(), TrendKind::Polynomial(2), &RED).unwrap();
+/// assert!(trend.fit().r2() > 0.99);
+/// let label = trend.label();
+/// chart
+///     .draw_series(trend)
+///     .unwrap()
+///     .label(label)
+///     .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+/// ```
+pub struct TrendlineSeries<X, Y> {
+    fit: TrendFit,
+    style: ShapeStyle,
+    samples: usize,
+    done: bool,
+    phantom: PhantomData<(X, Y)>,
+}
+
+impl<X, Y> TrendlineSeries<X, Y> {
+    /// Fit the curve to the data points, the points with a NaN or infinite coordinate, and the
+    /// points out of the domain of an exponential or logarithmic curve are ignored.
+    ///
+    /// - `iter`: The iterator over the data points
+    /// - `kind`: The kind of the curve
+    /// - `style`: The style of the line
+    /// - **returns** The series, or an error if the curve can't be fitted
+    pub fn new<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(
+        iter: I,
+        kind: TrendKind,
+        style: S,
+    ) -> Result<Self, RegressionError>
+    where
+        X: ToPrimitive,
+        Y: ToPrimitive,
+    {
+        Ok(Self {
+            fit: TrendFit::new(iter, kind)?,
+            style: style.into(),
+            samples: 100,
+            done: false,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Set the number of the points that the line is sampled at, the linear fit is always drawn
+    /// with the two end points. By default, the curve is sampled at 100 points.
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(2);
+        self
+    }
+
+    /// Get the fitted curve, which has the coefficients and the R²
+    pub fn fit(&self) -> &TrendFit {
+        &self.fit
+    }
+
+    /// Get the label of the series for the legend, the equation and the R² of the fit
+    pub fn label(&self) -> String {
+        format!("{} (R² = {:.3})", self.fit.equation(), self.fit.r2())
+    }
+}
+
+impl<X: NumCast, Y: NumCast> Iterator for TrendlineSeries<X, Y> {
+    type Item = PathElement<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.done = true;
+
+        let (x0, x1) = self.fit.x_range();
+        let samples = if let TrendKind::Linear = self.fit.kind() {
+            2
+        } else {
+            self.samples
+        };
+        let points: Vec<_> = (0..samples)
+            .map(|i| x0 + (x1 - x0) * i as f64 / (samples - 1) as f64)
+            .filter_map(|x| Some((NumCast::from(x)?, NumCast::from(self.fit.eval(x))?)))
+            .collect();
+        Some(PathElement::new(points, self.style.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_trendline_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 2);
+            });
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 50));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+            .unwrap();
+
+        let data: Vec<_> = (0..10).map(|x| (x as f64, x as f64 * 2.0 + 1.0)).collect();
+        let trend = TrendlineSeries::new(data.clone(), TrendKind::Linear, &RED).unwrap();
+        assert_eq!(trend.label(), "y = 2.00x + 1.00 (R² = 1.000)");
+        chart.draw_series(trend).unwrap();
+
+        let curve = data.iter().map(|(x, _)| (*x, x * x));
+        let trend = TrendlineSeries::new(curve, TrendKind::Polynomial(2), &BLUE).unwrap();
+        chart.draw_series(trend.samples(50)).unwrap();
+
+        assert!(TrendlineSeries::<f64, f64>::new(vec![], TrendKind::Linear, &RED).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::element::{DynElement, IntoDynElement, PathElement, Rectangle};
+use crate::style::colors::{BLACK, BLUE, GREEN, RED};
+use crate::style::{Color, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/// The elements of a waterfall chart on the category axis `BR`
+type WaterfallElement<DB, BR> = DynElement<'static, DB, (<BR as Ranged>::ValueType, f64)>;
+
+/// A step of a waterfall chart
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Step {
+    /// The running total changes by the value
+    Delta(f64),
+    /// The bar of the running total so far
+    Subtotal,
+}
+
+/// The series that draws a waterfall chart: each signed delta is a floating bar from the previous
+/// running total to the new one, and the subtotal bars go from the baseline to the running total.
+///
+/// The bars are colored by the sign of the delta, and each bar is connected to the next one with
+/// a line at the running total between them. The categories are the X axis of the chart, which
+/// has to be discrete, so a segmented range is recommended.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d((0..6).into_segmented(), 0.0..100.0)
+///     .unwrap();
+/// let series = WaterfallSeries::new(&chart)
+///     .deltas(vec![(0, 50.0), (1, 20.0), (2, -15.0)])
+///     .subtotal(3)
+///     .delta(4, 30.0)
+///     .subtotal(5);
+/// chart.draw_series(series).unwrap();
+/// ```
+pub struct WaterfallSeries<DB: DrawingBackend, BR: DiscreteRanged>
+where
+    BR::ValueType: Clone,
+{
+    br: BR,
+    steps: Vec<(usize, Step)>,
+    baseline: f64,
+    margin: u32,
+    positive_style: ShapeStyle,
+    negative_style: ShapeStyle,
+    subtotal_style: ShapeStyle,
+    connector_style: Option<ShapeStyle>,
+    elements: Option<std::vec::IntoIter<WaterfallElement<DB, BR>>>,
+}
+
+impl<DB, BR> WaterfallSeries<DB, BR>
+where
+    DB: DrawingBackend,
+    BR: DiscreteRanged + Clone,
+    BR::ValueType: Clone + 'static,
+{
+    /// Create a new waterfall series without steps
+    /// - `parent`: The chart that the series is drawn on
+    /// - **returns** The newly created series
+    pub fn new<PDB: DrawingBackend, ACoord: Ranged<ValueType = f64>>(
+        parent: &ChartContext<PDB, Cartesian2d<BR, ACoord>>,
+    ) -> Self {
+        Self {
+            br: parent.as_coord_spec().x_spec().clone(),
+            steps: vec![],
+            baseline: 0.0,
+            margin: 5,
+            positive_style: GREEN.filled(),
+            negative_style: RED.filled(),
+            subtotal_style: BLUE.filled(),
+            connector_style: Some(BLACK.into()),
+            elements: None,
+        }
+    }
+
+    /// Add a step that changes the running total, the steps out of the X range are ignored
+    /// - `x`: The category of the bar
+    /// - `delta`: The signed change of the running total
+    pub fn delta<X: Into<BR::ValueType>>(mut self, x: X, delta: f64) -> Self {
+        if let Some(idx) = self.br.index_of(&x.into()) {
+            self.steps.push((idx, Step::Delta(delta)));
+        }
+        self
+    }
+
+    /// Add the steps that change the running total, in order
+    pub fn deltas<X: Into<BR::ValueType>, I: IntoIterator<Item = (X, f64)>>(self, iter: I) -> Self {
+        iter.into_iter()
+            .fold(self, |series, (x, delta)| series.delta(x, delta))
+    }
+
+    /// Add a subtotal bar, which shows the running total of the steps before it
+    /// - `x`: The category of the bar
+    pub fn subtotal<X: Into<BR::ValueType>>(mut self, x: X) -> Self {
+        if let Some(idx) = self.br.index_of(&x.into()) {
+            self.steps.push((idx, Step::Subtotal));
+        }
+        self
+    }
+
+    /// Set the value that the running total and the subtotal bars start from
+    pub fn baseline(mut self, baseline: f64) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Set the margin between the bars and the borders of the category
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /// Set the style of the bars of the positive deltas
+    pub fn positive_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.positive_style = style.into();
+        self
+    }
+
+    /// Set the style of the bars of the negative deltas
+    pub fn negative_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.negative_style = style.into();
+        self
+    }
+
+    /// Set the style of the subtotal bars
+    pub fn subtotal_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.subtotal_style = style.into();
+        self
+    }
+
+    /// Set the style of the lines that connect the bars
+    pub fn connector_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.connector_style = Some(style.into());
+        self
+    }
+
+    /// Do not draw the lines that connect the bars
+    pub fn hide_connectors(mut self) -> Self {
+        self.connector_style = None;
+        self
+    }
+
+    fn build_elements(&self) -> Vec<WaterfallElement<DB, BR>> {
+        let mut connectors = vec![];
+        let mut bars = vec![];
+        let mut total = self.baseline;
+
+        for (i, &(idx, step)) in self.steps.iter().enumerate() {
+            let (from, to, style) = match step {
+                Step::Delta(delta) if delta.is_nan() => continue,
+                Step::Delta(delta) if delta >= 0.0 => (total, total + delta, &self.positive_style),
+                Step::Delta(delta) => (total, total + delta, &self.negative_style),
+                Step::Subtotal => (self.baseline, total, &self.subtotal_style),
+            };
+            total = to;
+
+            let (key, next) = match (self.br.from_index(idx), self.br.from_index(idx + 1)) {
+                (Some(key), Some(next)) => (key, next),
+                _ => continue,
+            };
+            let mut bar = Rectangle::new([(key.clone(), to), (next, from)], style.clone());
+            bar.set_margin(0, 0, self.margin, self.margin);
+            bars.push(bar.into_dyn());
+
+            // The connector runs along the running total, from this bar to the next one
+            if let (Some(style), Some(&(next_idx, _))) =
+                (&self.connector_style, self.steps.get(i + 1))
+            {
+                let (lo, hi) = (idx.min(next_idx), idx.max(next_idx) + 1);
+                if let (Some(start), Some(end)) = (self.br.from_index(lo), self.br.from_index(hi)) {
+                    let line = vec![(start, total), (end, total)];
+                    connectors.push(PathElement::new(line, style.clone()).into_dyn());
+                }
+            }
+        }
+
+        // Draw the connectors first, so the bars are on top of them
+        connectors.extend(bars);
+        connectors
+    }
+}
+
+impl<DB, BR> Iterator for WaterfallSeries<DB, BR>
+where
+    DB: DrawingBackend,
+    BR: DiscreteRanged + Clone,
+    BR::ValueType: Clone + 'static,
+{
+    type Item = WaterfallElement<DB, BR>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(Iterator::next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_waterfall_series() {
+        let drawing_area = create_mocked_drawing_area(400, 100, |m| {
+            // The connectors between the three bars
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(0, 49), (200, 49)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(100, 74), (300, 74)]);
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, GREEN.to_rgba());
+                assert_eq!((ul, br), ((5, 49), (95, 99)));
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((ul, br), ((105, 49), (195, 74)));
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!((ul, br), ((205, 74), (295, 99)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..3).into_segmented(), 0.0..100.0)
+            .unwrap();
+        let series = WaterfallSeries::new(&chart)
+            .deltas(vec![(0, 50.0), (1, -25.0)])
+            .subtotal(2);
+        chart.draw_series(series).unwrap();
+    }
+}
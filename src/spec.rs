@@ -0,0 +1,446 @@
+/*!
+  The declarative chart specification, which describes a chart as the plain data that can be
+  serialized with serde, for example, to keep the charts of a dashboard in the config files.
+
+  A [ChartSpec](struct.ChartSpec.html) is rendered on any drawing area with
+  [ChartSpec::render](struct.ChartSpec.html#method.render). The colors are written like the
+  other styles serialized with the `serialize` feature, as the hex strings, the names of the
+  basic colors or the arrays of the components.
+
+  ```rust
+  use plotters::prelude::*;
+  use plotters::spec::{AxisSpec, ChartSpec, SeriesKind, SeriesSpec};
+
+  let spec = ChartSpec {
+      caption: Some("Temperature".to_string()),
+      x_axis: AxisSpec::new(0.0, 24.0).label("Hour"),
+      y_axis: AxisSpec::new(-5.0, 25.0).label("°C"),
+      series: vec![SeriesSpec::new(
+          SeriesKind::Line,
+          (0..24).map(|h| (h as f64, (h as f64 / 4.0).sin() * 10.0 + 10.0)),
+      )
+      .label("Today")],
+      ..ChartSpec::default()
+  };
+
+  let mut content = String::new();
+  let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+  spec.render(&root).unwrap();
+  ```
+*/
+use std::iter::once;
+
+use crate::chart::{ChartBuilder, Interpolate};
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Circle, IntoDynElement, PathElement, Polygon};
+use crate::style::{Color, Palette, Palette99, RGBColor, ShapeStyle, BLACK, WHITE};
+
+use plotters_backend::DrawingBackend;
+use serde_derive::{Deserialize, Serialize};
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_caption_size() -> f64 {
+    30.0
+}
+
+fn default_label_area_size() -> u32 {
+    40
+}
+
+fn default_stroke_width() -> u32 {
+    1
+}
+
+fn default_point_size() -> u32 {
+    3
+}
+
+fn default_opacity() -> f64 {
+    1.0
+}
+
+/// The specification of a 2D cartesian chart
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChartSpec {
+    /// The caption of the chart
+    #[serde(default)]
+    pub caption: Option<String>,
+    /// The font size of the caption in pixels
+    #[serde(default = "default_caption_size")]
+    pub caption_size: f64,
+    /// The margin around the chart in pixels
+    #[serde(default)]
+    pub margin: u32,
+    /// The background color, the background isn't filled if it's not set
+    #[serde(default)]
+    pub background: Option<RGBColor>,
+    /// The X axis
+    pub x_axis: AxisSpec,
+    /// The Y axis
+    pub y_axis: AxisSpec,
+    /// If the mesh and the axes are drawn
+    #[serde(default = "default_true")]
+    pub mesh: bool,
+    /// If the labeled series are listed in a legend
+    #[serde(default = "default_true")]
+    pub legend: bool,
+    /// The series, which are drawn in order
+    #[serde(default)]
+    pub series: Vec<SeriesSpec>,
+}
+
+impl Default for ChartSpec {
+    fn default() -> Self {
+        Self {
+            caption: None,
+            caption_size: default_caption_size(),
+            margin: 0,
+            background: None,
+            x_axis: AxisSpec::new(0.0, 1.0),
+            y_axis: AxisSpec::new(0.0, 1.0),
+            mesh: true,
+            legend: true,
+            series: vec![],
+        }
+    }
+}
+
+/// The specification of an axis
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AxisSpec {
+    /// The start and the end of the axis range
+    pub range: (f64, f64),
+    /// The description of the axis
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The size of the label area in pixels, 0 hides the axis labels
+    #[serde(default = "default_label_area_size")]
+    pub label_area_size: u32,
+    /// The maximum number of the tick labels, the default of the mesh is used if it's not set
+    #[serde(default)]
+    pub labels: Option<usize>,
+}
+
+impl AxisSpec {
+    /// Create an axis spec of the range with the default label area
+    pub fn new(start: f64, end: f64) -> Self {
+        Self {
+            range: (start, end),
+            label: None,
+            label_area_size: default_label_area_size(),
+            labels: None,
+        }
+    }
+
+    /// Set the description of the axis
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// The kind of a series
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeriesKind {
+    /// The points are connected with a line
+    Line,
+    /// Each point is drawn as a circle
+    Point,
+    /// The area between the line and the baseline is filled
+    Area,
+}
+
+/// The specification of a data series
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SeriesSpec {
+    /// The kind of the series
+    pub kind: SeriesKind,
+    /// The label of the series in the legend
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The data points
+    pub data: Vec<(f64, f64)>,
+    /// The style of the series
+    #[serde(default)]
+    pub style: StyleSpec,
+    /// The Y value that an area series is filled down to
+    #[serde(default)]
+    pub baseline: f64,
+}
+
+impl SeriesSpec {
+    /// Create a series spec with the default style
+    pub fn new<I: IntoIterator<Item = (f64, f64)>>(kind: SeriesKind, data: I) -> Self {
+        Self {
+            kind,
+            label: None,
+            data: data.into_iter().collect(),
+            style: StyleSpec::default(),
+            baseline: 0.0,
+        }
+    }
+
+    /// Set the label of the series
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the style of the series
+    pub fn style(mut self, style: StyleSpec) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The specification of the style of a series
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StyleSpec {
+    /// The color of the series, the series is colored from the palette by its index if it's
+    /// not set
+    #[serde(default)]
+    pub color: Option<RGBColor>,
+    /// The opacity of the color
+    #[serde(default = "default_opacity")]
+    pub opacity: f64,
+    /// The width of the lines
+    #[serde(default = "default_stroke_width")]
+    pub stroke_width: u32,
+    /// The radius of the points
+    #[serde(default = "default_point_size")]
+    pub point_size: u32,
+    /// If the points are filled
+    #[serde(default = "default_true")]
+    pub filled: bool,
+}
+
+impl Default for StyleSpec {
+    fn default() -> Self {
+        Self {
+            color: None,
+            opacity: default_opacity(),
+            stroke_width: default_stroke_width(),
+            point_size: default_point_size(),
+            filled: true,
+        }
+    }
+}
+
+impl StyleSpec {
+    fn shape_style(&self, idx: usize) -> ShapeStyle {
+        let color = match self.color {
+            Some(color) => color.mix(self.opacity),
+            None => Palette99::pick(idx).mix(self.opacity),
+        };
+        color.stroke_width(self.stroke_width)
+    }
+}
+
+impl ChartSpec {
+    /// Render the chart on the drawing area
+    /// - `area`: The drawing area to render on
+    pub fn render<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if let Some(ref background) = self.background {
+            area.fill(background)?;
+        }
+
+        let mut builder = ChartBuilder::on(area);
+        builder
+            .margin(self.margin)
+            .x_label_area_size(self.x_axis.label_area_size)
+            .y_label_area_size(self.y_axis.label_area_size);
+        if let Some(ref caption) = self.caption {
+            builder.caption(caption, ("sans-serif", self.caption_size));
+        }
+        let (x, y) = (self.x_axis.range, self.y_axis.range);
+        let mut chart = builder.build_cartesian_2d(x.0..x.1, y.0..y.1)?;
+
+        if self.mesh {
+            let mut mesh = chart.configure_mesh();
+            if let Some(n) = self.x_axis.labels {
+                mesh.x_labels(n);
+            }
+            if let Some(n) = self.y_axis.labels {
+                mesh.y_labels(n);
+            }
+            if let Some(ref desc) = self.x_axis.label {
+                mesh.x_desc(desc);
+            }
+            if let Some(ref desc) = self.y_axis.label {
+                mesh.y_desc(desc);
+            }
+            mesh.draw()?;
+        }
+
+        for (idx, series) in self.series.iter().enumerate() {
+            let style = series.style.shape_style(idx);
+            let point_size = series.style.point_size;
+            let anno = match series.kind {
+                SeriesKind::Line => {
+                    chart.draw_series(once(PathElement::new(series.data.clone(), style.clone())))?
+                }
+                SeriesKind::Point => {
+                    let point_style = if series.style.filled {
+                        style.filled()
+                    } else {
+                        style.clone()
+                    };
+                    chart.draw_series(
+                        series
+                            .data
+                            .iter()
+                            .map(|p| Circle::new(*p, point_size, point_style.clone())),
+                    )?
+                }
+                SeriesKind::Area => {
+                    let first = series.data.first().map(|p| p.0);
+                    let last = series.data.last().map(|p| p.0);
+                    if let (Some(first), Some(last)) = (first, last) {
+                        let mut outline = series.data.clone();
+                        outline.push((last, series.baseline));
+                        outline.push((first, series.baseline));
+                        chart.draw_series(once(Polygon::new(
+                            outline,
+                            style.color.mix(0.2).filled(),
+                        )))?;
+                    }
+                    chart.draw_series(once(PathElement::new(series.data.clone(), style.clone())))?
+                }
+            };
+
+            if let Some(ref label) = series.label {
+                let kind = series.kind;
+                anno.label(label.as_str()).legend(move |(x, y)| match kind {
+                    SeriesKind::Point => {
+                        Circle::new((x + 10, y), point_size, style.filled()).into_dyn()
+                    }
+                    _ => PathElement::new(vec![(x, y), (x + 20, y)], style.clone()).into_dyn(),
+                });
+            }
+        }
+
+        if self.legend && self.series.iter().any(|s| s.label.is_some()) {
+            chart
+                .configure_series_labels()
+                .background_style(WHITE.mix(0.8))
+                .border_style(BLACK)
+                .draw()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The axis ranges are interpolated, the labels are taken from the end state
+impl Interpolate for AxisSpec {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            range: self.range.interpolate(&to.range, t),
+            ..to.clone()
+        }
+    }
+}
+
+/// The data, the baseline and the opacity are interpolated, the rest is taken from the end
+/// state
+impl Interpolate for SeriesSpec {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            data: self.data.interpolate(&to.data, t),
+            baseline: self.baseline.interpolate(&to.baseline, t),
+            style: StyleSpec {
+                opacity: self.style.opacity.interpolate(&to.style.opacity, t),
+                ..to.style.clone()
+            },
+            ..to.clone()
+        }
+    }
+}
+
+/// The ranges of the axes and the series are interpolated, thus two specs of a chart before and
+/// after an update can be animated with [animate](../chart/fn.animate.html). The caption,
+/// the layout and the kinds of the series are taken from the end state.
+impl Interpolate for ChartSpec {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            x_axis: self.x_axis.interpolate(&to.x_axis, t),
+            y_axis: self.y_axis.interpolate(&to.y_axis, t),
+            series: self.series.interpolate(&to.series, t),
+            ..to.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_spec_render() {
+        let spec = ChartSpec {
+            x_axis: AxisSpec::new(0.0, 10.0),
+            y_axis: AxisSpec::new(0.0, 10.0),
+            mesh: false,
+            series: vec![
+                SeriesSpec::new(SeriesKind::Line, vec![(0.0, 0.0), (10.0, 10.0)]).label("line"),
+                SeriesSpec::new(SeriesKind::Point, vec![(1.0, 1.0), (2.0, 2.0)]).style(StyleSpec {
+                    color: Some(RED),
+                    ..StyleSpec::default()
+                }),
+                SeriesSpec::new(SeriesKind::Area, vec![(0.0, 5.0), (10.0, 5.0)]),
+            ],
+            ..ChartSpec::default()
+        };
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, filled, _, r| {
+                assert_eq!(c.to_rgba(), RED.to_rgba());
+                assert!(filled);
+                assert_eq!(r, 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        spec.render(&drawing_area).unwrap();
+    }
+
+    #[test]
+    fn test_spec_interpolate() {
+        let from = ChartSpec {
+            x_axis: AxisSpec::new(0.0, 10.0),
+            series: vec![SeriesSpec::new(
+                SeriesKind::Line,
+                vec![(0.0, 0.0), (10.0, 2.0)],
+            )],
+            ..ChartSpec::default()
+        };
+        let to = ChartSpec {
+            caption: Some("Updated".to_string()),
+            x_axis: AxisSpec::new(0.0, 20.0).label("X"),
+            series: vec![
+                SeriesSpec::new(SeriesKind::Line, vec![(0.0, 4.0), (20.0, 6.0)]),
+                SeriesSpec::new(SeriesKind::Point, vec![(5.0, 5.0)]),
+            ],
+            ..ChartSpec::default()
+        };
+        let frames: Vec<_> = animate(&from, &to, 5, Easing::Linear).collect();
+        assert_eq!(frames[0].x_axis.range, (0.0, 10.0));
+        assert_eq!(frames[2].x_axis.range, (0.0, 15.0));
+        assert_eq!(frames[2].x_axis.label, Some("X".to_string()));
+        assert_eq!(frames[2].series[0].data, vec![(0.0, 2.0), (15.0, 4.0)]);
+        assert_eq!(frames[2].series.len(), 2);
+        assert_eq!(frames[4], to);
+    }
+}
@@ -0,0 +1,290 @@
+use super::{Color, RGBAColor};
+
+mod tables;
+use tables::{MAGMA, PLASMA, TURBO, VIRIDIS};
+
+/// The mapping from a scalar value to a color, which is used by the value-colored charts, such
+/// as heatmaps, and by the [Colorbar](../element/struct.Colorbar.html) that explains them.
+///
+/// The colors are RGBA colors, thus a color map can fade the values out, for example with the
+/// transparent stops of a [LinearColorMap](struct.LinearColorMap.html).
+pub trait ColorMap {
+    /// Get the color of a normalized value
+    /// - `h`: The value in range `0.0..=1.0`, the values out of the range are clamped
+    /// - **returns** The mapped color
+    fn get_color(&self, h: f64) -> RGBAColor;
+
+    /// Get the color of a value in the given range
+    /// - `v`: The value
+    /// - `min`: The value that maps to the first color
+    /// - `max`: The value that maps to the last color
+    /// - **returns** The mapped color
+    fn get_color_normalized(&self, v: f64, min: f64, max: f64) -> RGBAColor {
+        if max == min {
+            return self.get_color(0.0);
+        }
+        self.get_color((v - min) / (max - min))
+    }
+
+    /// The number of discrete colors of a quantized map, or `None` if the map is continuous
+    fn levels(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<M: ColorMap + ?Sized> ColorMap for &M {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        (*self).get_color(h)
+    }
+    fn levels(&self) -> Option<usize> {
+        (*self).levels()
+    }
+}
+
+fn clamp(h: f64) -> f64 {
+    if h.is_nan() || h <= 0.0 {
+        0.0
+    } else if h >= 1.0 {
+        1.0
+    } else {
+        h
+    }
+}
+
+/// The color map that linearly interpolates between the given stops, which are evenly spaced
+#[derive(Clone)]
+pub struct LinearColorMap {
+    stops: Vec<RGBAColor>,
+}
+
+impl LinearColorMap {
+    /// Create a new linear color map
+    /// - `stops`: The colors, at least one color is required. The alpha channels of the colors
+    ///   are interpolated as well.
+    pub fn new<C: Color, I: IntoIterator<Item = C>>(stops: I) -> Self {
+        let stops: Vec<_> = stops.into_iter().map(|c| c.to_rgba()).collect();
+        assert!(!stops.is_empty());
+        Self { stops }
+    }
+}
+
+fn interpolate<C: Color>(stops: &[C], h: f64) -> RGBAColor {
+    let pos = clamp(h) * (stops.len() - 1) as f64;
+    let idx = (pos.floor() as usize).min(stops.len() - 1);
+    let next = (idx + 1).min(stops.len() - 1);
+    let t = pos - idx as f64;
+    let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+    let (a, b) = (stops[idx].to_rgba(), stops[next].to_rgba());
+    RGBAColor(
+        mix(a.0, b.0),
+        mix(a.1, b.1),
+        mix(a.2, b.2),
+        a.3 + (b.3 - a.3) * t,
+    )
+}
+
+impl ColorMap for LinearColorMap {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        interpolate(&self.stops, h)
+    }
+}
+
+/// The perceptually uniform viridis color map, from dark purple to yellow
+///
+/// The map linearly interpolates between the 256 colors of the viridis map of matplotlib.
+#[derive(Clone, Copy)]
+pub struct ViridisRGB;
+
+impl ColorMap for ViridisRGB {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        interpolate(&VIRIDIS, h)
+    }
+}
+
+/// The perceptually uniform magma color map, from black through purple and orange to pale
+/// yellow
+///
+/// The map linearly interpolates between the 256 colors of the magma map of matplotlib.
+#[derive(Clone, Copy)]
+pub struct MagmaRGB;
+
+impl ColorMap for MagmaRGB {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        interpolate(&MAGMA, h)
+    }
+}
+
+/// The perceptually uniform plasma color map, from dark blue through magenta to yellow
+///
+/// The map linearly interpolates between the 256 colors of the plasma map of matplotlib.
+#[derive(Clone, Copy)]
+pub struct PlasmaRGB;
+
+impl ColorMap for PlasmaRGB {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        interpolate(&PLASMA, h)
+    }
+}
+
+/// The turbo color map, a rainbow from dark blue through green to dark red that is smoother
+/// than the jet color map. It isn't perceptually uniform, but it keeps the details visible.
+///
+/// The map linearly interpolates between the 256 colors of the turbo map of Google.
+#[derive(Clone, Copy)]
+pub struct TurboRGB;
+
+impl ColorMap for TurboRGB {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        interpolate(&TURBO, h)
+    }
+}
+
+/// The color map that linearly interpolates between the stops at the given positions, unlike
+/// [LinearColorMap](struct.LinearColorMap.html), whose stops are evenly spaced
+#[derive(Clone)]
+pub struct DerivedColorMap {
+    stops: Vec<(f64, RGBAColor)>,
+}
+
+impl DerivedColorMap {
+    /// Create a new color map from the stops
+    /// - `stops`: The positions in range `0.0..=1.0` and their colors, at least one stop is
+    ///   required. The values before the first stop and after the last stop get the color of
+    ///   that stop.
+    pub fn from_stops<C: Color, I: IntoIterator<Item = (f64, C)>>(stops: I) -> Self {
+        let mut stops: Vec<_> = stops
+            .into_iter()
+            .filter(|s| !s.0.is_nan())
+            .map(|(pos, c)| (pos, c.to_rgba()))
+            .collect();
+        assert!(!stops.is_empty());
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        Self { stops }
+    }
+}
+
+impl ColorMap for DerivedColorMap {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        let h = clamp(h);
+        let next = match self.stops.iter().position(|s| s.0 > h) {
+            Some(0) => return self.stops[0].1,
+            Some(next) => next,
+            None => return self.stops[self.stops.len() - 1].1,
+        };
+        let ((x0, a), (x1, b)) = (self.stops[next - 1], self.stops[next]);
+        interpolate(&[a, b], (h - x0) / (x1 - x0))
+    }
+}
+
+/// The grayscale color map, from black to white
+#[derive(Clone, Copy)]
+pub struct BlackWhite;
+
+impl ColorMap for BlackWhite {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        let v = (clamp(h) * 255.0).round() as u8;
+        RGBAColor(v, v, v, 1.0)
+    }
+}
+
+/// The color map that quantizes another color map into the given number of discrete levels
+#[derive(Clone)]
+pub struct QuantizedColorMap<M: ColorMap> {
+    map: M,
+    levels: usize,
+}
+
+impl<M: ColorMap> QuantizedColorMap<M> {
+    /// Create a new quantized color map
+    /// - `map`: The underlying color map
+    /// - `levels`: The number of discrete colors, at least one
+    pub fn new(map: M, levels: usize) -> Self {
+        Self {
+            map,
+            levels: levels.max(1),
+        }
+    }
+}
+
+impl<M: ColorMap> ColorMap for QuantizedColorMap<M> {
+    fn get_color(&self, h: f64) -> RGBAColor {
+        let idx = ((clamp(h) * self.levels as f64) as usize).min(self.levels - 1);
+        let h = if self.levels == 1 {
+            0.5
+        } else {
+            idx as f64 / (self.levels - 1) as f64
+        };
+        self.map.get_color(h)
+    }
+
+    fn levels(&self) -> Option<usize> {
+        Some(self.levels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::RGBColor;
+
+    #[test]
+    fn test_color_maps() {
+        let map = LinearColorMap::new(vec![RGBColor(0, 0, 0), RGBColor(200, 100, 0)]);
+        assert_eq!(map.get_color(0.5), RGBAColor(100, 50, 0, 1.0));
+        assert_eq!(map.get_color(2.0), RGBAColor(200, 100, 0, 1.0));
+        assert_eq!(
+            map.get_color_normalized(5.0, 0.0, 10.0),
+            RGBAColor(100, 50, 0, 1.0)
+        );
+        let fading = LinearColorMap::new(vec![
+            RGBColor(255, 0, 0).mix(0.0),
+            RGBColor(255, 0, 0).mix(0.5),
+        ]);
+        assert_eq!(fading.get_color(0.5), RGBAColor(255, 0, 0, 0.25));
+
+        assert_eq!(ViridisRGB.get_color(-1.0), ViridisRGB.get_color(0.0));
+        assert_eq!(TurboRGB.get_color(2.0), TurboRGB.get_color(1.0));
+
+        let derived = DerivedColorMap::from_stops(vec![
+            (0.75, RGBColor(0, 200, 0)),
+            (0.25, RGBColor(0, 0, 0)),
+        ]);
+        assert_eq!(derived.get_color(0.0), RGBAColor(0, 0, 0, 1.0));
+        assert_eq!(derived.get_color(0.5), RGBAColor(0, 100, 0, 1.0));
+        assert_eq!(derived.get_color(0.75), RGBAColor(0, 200, 0, 1.0));
+        assert_eq!(derived.get_color(1.0), RGBAColor(0, 200, 0, 1.0));
+
+        let quantized = QuantizedColorMap::new(BlackWhite, 3);
+        assert_eq!(quantized.levels(), Some(3));
+        assert_eq!(quantized.get_color(0.2), RGBAColor(0, 0, 0, 1.0));
+        assert_eq!(quantized.get_color(0.5), RGBAColor(128, 128, 128, 1.0));
+        assert_eq!(quantized.get_color(1.0), RGBAColor(255, 255, 255, 1.0));
+    }
+    #[test]
+    fn test_reference_colors() {
+        // The colors of the reference tables at 0, 1/4, 1/2, 3/4 and 1, which are the entries
+        // 0, 64, 128, 192 and 255 of the tables
+        let check = |map: &dyn ColorMap, colors: [u32; 5]| {
+            for (idx, color) in [0, 64, 128, 192, 255].iter().zip(colors.iter()) {
+                let expected = RGBColor((color >> 16) as u8, (color >> 8) as u8, *color as u8);
+                assert_eq!(map.get_color(f64::from(*idx) / 255.0), expected.to_rgba());
+            }
+        };
+        check(
+            &ViridisRGB,
+            [0x440154, 0x3b528b, 0x21918c, 0x5ec962, 0xfde725],
+        );
+        check(
+            &MagmaRGB,
+            [0x000004, 0x51127c, 0xb73779, 0xfc8961, 0xfcfdbf],
+        );
+        check(
+            &PlasmaRGB,
+            [0x0d0887, 0x7e03a8, 0xcc4778, 0xf89540, 0xf0f921],
+        );
+        check(
+            &TurboRGB,
+            [0x30123b, 0x28bceb, 0xa4fc3c, 0xfb7e21, 0x7a0403],
+        );
+    }
+}
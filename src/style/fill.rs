@@ -0,0 +1,41 @@
+use super::{Gradient, Pattern};
+use crate::drawing::BackendCapabilities;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The fill of a shape that replaces the color of its style
+#[derive(Clone)]
+pub(crate) enum Fill {
+    Gradient(Gradient),
+    Pattern(Pattern),
+}
+
+impl Fill {
+    /// Fill the polygon on the backend that has the capabilities
+    pub(crate) fn fill_polygon<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        points: &[BackendCoord],
+        capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self {
+            Fill::Gradient(gradient) => gradient.fill_polygon(backend, points, capabilities),
+            Fill::Pattern(pattern) => pattern.fill_polygon(backend, points),
+        }
+    }
+
+    /// Fill a part of a shape, whose bounding box is given, thus a gradient spans the whole shape
+    pub(crate) fn fill_polygon_in<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        points: &[BackendCoord],
+        bounds: (BackendCoord, BackendCoord),
+        capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self {
+            Fill::Gradient(gradient) => {
+                gradient.fill_polygon_in(backend, points, bounds, capabilities)
+            }
+            Fill::Pattern(pattern) => pattern.fill_polygon(backend, points),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use super::{FontFamily, FontStyle, LayoutBox};
+
+/// The number of the layout boxes that are cached by default
+const DEFAULT_CAPACITY: usize = 4096;
+
+/// The font that the layout boxes in a bucket are measured with
+struct FontKey {
+    family: String,
+    style: FontStyle,
+    size: u64,
+}
+
+impl FontKey {
+    fn matches(&self, family: &FontFamily, style: FontStyle, size: f64) -> bool {
+        self.size == size.to_bits()
+            && self.family == family.as_str()
+            && self.style.as_str() == style.as_str()
+    }
+}
+
+struct LayoutCache {
+    fonts: Vec<(FontKey, HashMap<String, LayoutBox>)>,
+    entries: usize,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl LayoutCache {
+    fn new() -> Self {
+        Self {
+            fonts: vec![],
+            entries: 0,
+            capacity: DEFAULT_CAPACITY,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn bucket(
+        &mut self,
+        family: &FontFamily,
+        style: FontStyle,
+        size: f64,
+    ) -> &mut HashMap<String, LayoutBox> {
+        let idx = match self
+            .fonts
+            .iter()
+            .position(|(key, _)| key.matches(family, style, size))
+        {
+            Some(idx) => idx,
+            None => {
+                let key = FontKey {
+                    family: family.as_str().to_string(),
+                    style,
+                    size: size.to_bits(),
+                };
+                self.fonts.push((key, HashMap::new()));
+                self.fonts.len() - 1
+            }
+        };
+        &mut self.fonts[idx].1
+    }
+
+    fn clear(&mut self) {
+        self.fonts.clear();
+        self.entries = 0;
+    }
+}
+
+thread_local! {
+    static LAYOUT_CACHE: RefCell<LayoutCache> = RefCell::new(LayoutCache::new());
+}
+
+/// The statistics of the text layout cache of the current thread, see
+/// [text_cache_stats](fn.text_cache_stats.html).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextCacheStats {
+    /// The number of the layouts that are found in the cache
+    pub hits: u64,
+    /// The number of the layouts that are measured with the font
+    pub misses: u64,
+    /// The number of the layouts in the cache
+    pub entries: usize,
+    /// The number of the layouts that the cache holds before it's cleared
+    pub capacity: usize,
+}
+
+/// Get the statistics of the text layout cache.
+///
+/// The layout boxes of the text are cached by the font family, the style, the size and the
+/// text, thus the tick labels and the data labels that are drawn in every frame are measured
+/// with the font only once. The cache belongs to the current thread, like the loaded fonts, and
+/// it's cleared as a whole when it's full.
+///
+/// ```rust
+/// use plotters::style::{text_cache_stats, IntoFont};
+///
+/// let font = ("sans-serif", 20).into_font();
+/// let before = text_cache_stats();
+/// for _ in 0..10 {
+///     font.box_size("label").ok();
+/// }
+/// let after = text_cache_stats();
+/// // The first measurement may miss, the other nine are served from the cache
+/// assert!(after.hits - before.hits >= 9);
+/// ```
+pub fn text_cache_stats() -> TextCacheStats {
+    LAYOUT_CACHE.with(|cache| {
+        let cache = cache.borrow();
+        TextCacheStats {
+            hits: cache.hits,
+            misses: cache.misses,
+            entries: cache.entries,
+            capacity: cache.capacity,
+        }
+    })
+}
+
+/// Set the number of the layouts that the text layout cache of the current thread holds, the
+/// cache is cleared when it's full. The capacity of 0 disables the cache.
+///
+/// - `capacity`: The new capacity of the cache
+pub fn set_text_cache_capacity(capacity: usize) {
+    LAYOUT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.capacity = capacity;
+        if cache.entries > capacity {
+            cache.clear();
+        }
+    })
+}
+
+/// Clear the text layout cache of the current thread and reset its statistics
+pub fn clear_text_cache() {
+    LAYOUT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.clear();
+        cache.hits = 0;
+        cache.misses = 0;
+    })
+}
+
+/// Drop the cached layouts of the current thread, since the fonts have been changed
+#[cfg_attr(any(target_arch = "wasm32", not(feature = "ttf")), allow(dead_code))]
+pub(super) fn invalidate_text_cache() {
+    LAYOUT_CACHE.with(|cache| cache.borrow_mut().clear())
+}
+
+/// Get the layout box of the text from the cache, or measure it with the function and cache
+/// it. The errors are not cached.
+pub(super) fn cached_layout<E, F: FnOnce() -> Result<LayoutBox, E>>(
+    family: &FontFamily,
+    style: FontStyle,
+    size: f64,
+    text: &str,
+    measure: F,
+) -> Result<LayoutBox, E> {
+    let cached = LAYOUT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.capacity == 0 {
+            return None;
+        }
+        let layout = cache.bucket(family, style, size).get(text).cloned();
+        if layout.is_some() {
+            cache.hits += 1;
+        } else {
+            cache.misses += 1;
+        }
+        layout
+    });
+    if let Some(layout) = cached {
+        return Ok(layout);
+    }
+
+    // The cache isn't borrowed while the text is measured
+    let layout = measure()?;
+    LAYOUT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.capacity == 0 {
+            return;
+        }
+        if cache.entries >= cache.capacity {
+            cache.clear();
+        }
+        if cache
+            .bucket(family, style, size)
+            .insert(text.to_string(), layout)
+            .is_none()
+        {
+            cache.entries += 1;
+        }
+    });
+    Ok(layout)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cached_layout() {
+        clear_text_cache();
+        let family = FontFamily::SansSerif;
+        let measure = |text: &str| ((0, 0), (text.len() as i32, 10));
+        let layout = |text: &str, size: f64| {
+            cached_layout::<(), _>(&family, FontStyle::Normal, size, text, || Ok(measure(text)))
+        };
+
+        assert_eq!(layout("abc", 10.0), Ok(((0, 0), (3, 10))));
+        assert_eq!(layout("abc", 10.0), Ok(((0, 0), (3, 10))));
+        // A different size is another font
+        layout("abc", 12.0).unwrap();
+        let stats = text_cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 2));
+
+        // The errors are measured again
+        let failed = || cached_layout(&family, FontStyle::Bold, 10.0, "abc", || Err(()));
+        assert!(failed().is_err() && failed().is_err());
+        assert_eq!(text_cache_stats().entries, 2);
+
+        // The full cache is cleared
+        set_text_cache_capacity(3);
+        for text in ["a", "b"].iter() {
+            layout(text, 10.0).unwrap();
+        }
+        assert_eq!(text_cache_stats().entries, 1);
+
+        set_text_cache_capacity(0);
+        layout("abc", 10.0).unwrap();
+        assert_eq!(text_cache_stats().entries, 0);
+        set_text_cache_capacity(DEFAULT_CAPACITY);
+    }
+}
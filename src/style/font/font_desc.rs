@@ -1,3 +1,4 @@
+use super::cache::cached_layout;
 use super::{FontData, FontDataInternal};
 use crate::style::text_anchor::Pos;
 use crate::style::{Color, TextStyle};
@@ -19,6 +20,7 @@ pub struct FontDesc<'a> {
     family: FontFamily<'a>,
     data: FontResult<FontDataInternal>,
     transform: FontTransform,
+    rotation: f64,
     style: FontStyle,
 }
 
@@ -35,6 +37,7 @@ impl<'a> FontDesc<'a> {
             family,
             data: FontDataInternal::new(family, style),
             transform: FontTransform::None,
+            rotation: 0.0,
             style,
         }
     }
@@ -49,6 +52,7 @@ impl<'a> FontDesc<'a> {
             family: self.family,
             data: self.data.clone(),
             transform: self.transform.clone(),
+            rotation: self.rotation,
             style: self.style,
         }
     }
@@ -63,6 +67,7 @@ impl<'a> FontDesc<'a> {
             family: self.family,
             data: self.data.clone(),
             transform: self.transform.clone(),
+            rotation: self.rotation,
             style,
         }
     }
@@ -77,15 +82,76 @@ impl<'a> FontDesc<'a> {
             family: self.family,
             data: self.data.clone(),
             transform: trans,
+            rotation: 0.0,
             style: self.style,
         }
     }
 
+    /// Rotate the font by an arbitrary angle, which replaces the font transformation. The
+    /// multiples of 90 degrees are drawn natively by the backends, and the other angles are
+    /// rasterized by Plotters.
+    ///
+    /// - `degrees`: The clockwise angle, thus 90 is the same as `FontTransform::Rotate90` and
+    ///   -45 draws the text rising to the right
+    /// - **returns** The new font description with the rotation applied
+    pub fn rotate(&self, degrees: f64) -> Self {
+        let degrees = degrees % 360.0;
+        let degrees = if degrees < 0.0 {
+            degrees + 360.0
+        } else {
+            degrees
+        };
+        let transform = if degrees == 0.0 {
+            FontTransform::None
+        } else if degrees == 90.0 {
+            FontTransform::Rotate90
+        } else if degrees == 180.0 {
+            FontTransform::Rotate180
+        } else if degrees == 270.0 {
+            FontTransform::Rotate270
+        } else {
+            return Self {
+                transform: FontTransform::None,
+                rotation: degrees,
+                ..self.clone()
+            };
+        };
+        self.transform(transform)
+    }
+
     /// Get the font transformation description
     pub fn get_transform(&self) -> FontTransform {
         self.transform.clone()
     }
 
+    /// Get the clockwise rotation of the font in degrees, including the font transformation
+    pub fn get_rotation(&self) -> f64 {
+        match self.transform {
+            FontTransform::None => self.rotation,
+            FontTransform::Rotate90 => 90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 270.0,
+        }
+    }
+
+    /// Check if the font is drawn by the backends, because the rotation is a font transformation
+    pub(crate) fn is_native_rotation(&self) -> bool {
+        self.rotation == 0.0
+    }
+
+    /// Rotate an offset in the frame of the text into the offset in pixels
+    pub(crate) fn rotate_offset(&self, (x, y): (i32, i32)) -> (i32, i32) {
+        if self.is_native_rotation() {
+            return self.transform.transform(x, y);
+        }
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let (x, y) = (f64::from(x), f64::from(y));
+        (
+            (x * cos - y * sin).round() as i32,
+            (x * sin + y * cos).round() as i32,
+        )
+    }
+
     /// Set the color of the font and return the result text style object
     pub fn color<C: Color>(&self, color: &C) -> TextStyle<'a> {
         TextStyle {
@@ -117,10 +183,13 @@ impl<'a> FontDesc<'a> {
     /// Get the size of the text if rendered in this font
     ///
     /// For a TTF type, zero point of the layout box is the left most baseline char of the string
-    /// Thus the upper bound of the box is most likely be negative
+    /// Thus the upper bound of the box is most likely be negative.
+    /// The layout boxes are cached, see [text_cache_stats](fn.text_cache_stats.html)
     pub fn layout_box(&self, text: &str) -> FontResult<((i32, i32), (i32, i32))> {
         match &self.data {
-            Ok(ref font) => font.estimate_layout(self.size, text),
+            Ok(ref font) => cached_layout(&self.family, self.style, self.size, text, || {
+                font.estimate_layout(self.size, text)
+            }),
             Err(e) => Err(e.clone()),
         }
     }
@@ -130,7 +199,15 @@ impl<'a> FontDesc<'a> {
     /// and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
-        let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
+        let (w, h) = (max_x - min_x, max_y - min_y);
+        if !self.is_native_rotation() {
+            let (sin, cos) = self.rotation.to_radians().sin_cos();
+            let (w, h) = (f64::from(w), f64::from(h));
+            let rotated_w = (w * cos).abs() + (h * sin).abs();
+            let rotated_h = (w * sin).abs() + (h * cos).abs();
+            return Ok((rotated_w.round() as u32, rotated_h.round() as u32));
+        }
+        let (w, h) = self.get_transform().transform(w, h);
         Ok((w.abs() as u32, h.abs() as u32))
     }
 
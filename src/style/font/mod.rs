@@ -10,6 +10,8 @@
 mod ttf;
 #[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
 use ttf::FontDataInternal;
+#[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
+pub use ttf::{register_font, set_font_fallback};
 
 #[cfg(all(not(target_arch = "wasm32"), not(feature = "ttf")))]
 mod naive;
@@ -21,6 +23,9 @@ mod web;
 #[cfg(target_arch = "wasm32")]
 use web::FontDataInternal;
 
+mod cache;
+pub use cache::{clear_text_cache, set_text_cache_capacity, text_cache_stats, TextCacheStats};
+
 mod font_desc;
 pub use font_desc::*;
 
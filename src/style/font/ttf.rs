@@ -22,6 +22,7 @@ use ttf_parser::{Face, GlyphId};
 use pathfinder_geometry::transform2d::Transform2F;
 use pathfinder_geometry::vector::{Vector2F, Vector2I};
 
+use super::cache::invalidate_text_cache;
 use super::{FontData, FontFamily, FontStyle, LayoutBox};
 
 type FontResult<T> = Result<T, FontError>;
@@ -52,6 +53,7 @@ impl std::error::Error for FontError {}
 lazy_static! {
     static ref DATA_CACHE: RwLock<HashMap<String, FontResult<Handle>>> =
         RwLock::new(HashMap::new());
+    static ref FALLBACKS: RwLock<HashMap<String, Vec<String>>> = RwLock::new(HashMap::new());
 }
 
 thread_local! {
@@ -110,13 +112,69 @@ impl std::ops::Deref for FontExt {
     }
 }
 
+/// The key of a font in the caches
+fn cache_key<'a>(face: &'a FontFamily<'a>, style: FontStyle) -> Cow<'a, str> {
+    match style {
+        FontStyle::Normal => Cow::Borrowed(face.as_str()),
+        _ => Cow::Owned(format!("{}, {}", face.as_str(), style.as_str())),
+    }
+}
+
+/// Register the TTF or OTF data of a font, which is used instead of the system fonts whenever
+/// the family is requested with the style. This makes the text rendering independent from the
+/// fonts that are installed, for example in a container.
+///
+/// The font should be registered before it's used for the first time, since the threads that
+/// have already loaded the family keep using the loaded font.
+///
+/// - `name`: The name of the font family, such as `"sans-serif"` to replace the generic family
+/// - `style`: The style that the data is registered for
+/// - `bytes`: The content of the font file
+/// - **returns** The error if the data can't be loaded as a font
+///
+/// ```rust,no_run
+/// use plotters::style::{register_font, FontStyle};
+///
+/// let bytes = std::fs::read("fonts/NotoSans-Regular.ttf").expect("Missing font");
+/// register_font("sans-serif", FontStyle::Normal, bytes).expect("Invalid font");
+/// ```
+pub fn register_font<B: Into<Vec<u8>>>(
+    name: &str,
+    style: FontStyle,
+    bytes: B,
+) -> Result<(), FontError> {
+    let bytes = Arc::new(bytes.into());
+    Font::from_bytes(bytes.clone(), 0).map_err(|e| FontError::FontLoadError(Arc::new(e)))?;
+
+    let key = cache_key(&FontFamily::Name(name), style).into_owned();
+    FONT_OBJECT_CACHE.with(|font_object_cache| font_object_cache.borrow_mut().remove(&key));
+    DATA_CACHE
+        .write()
+        .map_err(|_| FontError::LockError)?
+        .insert(key, Ok(Handle::from_memory(bytes, 0)));
+    invalidate_text_cache();
+    Ok(())
+}
+
+/// Set the font families that are searched for the characters that aren't covered by the
+/// family, in the given order, for example to draw CJK text or symbols with a Latin font.
+///
+/// - `name`: The name of the font family, such as `"sans-serif"`
+/// - `fallbacks`: The names of the fallback families, which replace the previous fallbacks
+pub fn set_font_fallback(name: &str, fallbacks: &[&str]) -> Result<(), FontError> {
+    let fallbacks = fallbacks.iter().map(|f| f.to_string()).collect();
+    FALLBACKS
+        .write()
+        .map_err(|_| FontError::LockError)?
+        .insert(name.to_owned(), fallbacks);
+    invalidate_text_cache();
+    Ok(())
+}
+
 /// Lazily load font data. Font type doesn't own actual data, which
 /// lives in the cache.
 fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
-    let key = match style {
-        FontStyle::Normal => Cow::Borrowed(face.as_str()),
-        _ => Cow::Owned(format!("{}, {}", face.as_str(), style.as_str())),
-    };
+    let key = cache_key(&face, style);
 
     // First, we try to find the font object for current thread
     if let Some(font_object) = FONT_OBJECT_CACHE.with(|font_object_cache| {
@@ -193,41 +251,71 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
 }
 
 #[derive(Clone)]
-pub struct FontDataInternal(FontExt);
+pub struct FontDataInternal {
+    font: FontExt,
+    fallbacks: Vec<FontExt>,
+}
+
+impl FontDataInternal {
+    /// Find the font that covers the character, and the glyph of the character in the font. The
+    /// index of the font is 0 for the font itself, and the characters that aren't covered by any
+    /// font are drawn with the placeholder of the font.
+    fn glyph_for_char(&self, c: char) -> Option<(usize, &FontExt, u32)> {
+        if let Some(glyph_id) = self.font.glyph_for_char(c) {
+            return Some((0, &self.font, glyph_id));
+        }
+        for (idx, font) in self.fallbacks.iter().enumerate() {
+            if let Some(glyph_id) = font.glyph_for_char(c) {
+                return Some((idx + 1, font, glyph_id));
+            }
+        }
+        self.font
+            .glyph_for_char(PLACEHOLDER_CHAR)
+            .map(|glyph_id| (0, &self.font, glyph_id))
+    }
+}
 
 impl FontData for FontDataInternal {
     type ErrorType = FontError;
 
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, FontError> {
-        Ok(FontDataInternal(load_font_data(family, style)?))
+        let font = load_font_data(family, style)?;
+        let names = FALLBACKS
+            .read()
+            .map_err(|_| FontError::LockError)?
+            .get(family.as_str())
+            .cloned()
+            .unwrap_or_default();
+        let fallbacks = names
+            .iter()
+            .filter_map(|name| load_font_data(FontFamily::Name(name), style).ok())
+            .collect();
+        Ok(FontDataInternal { font, fallbacks })
     }
 
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
-        let font = &self.0;
         let pixel_per_em = size / 1.24;
-        let metrics = font.metrics();
-
-        let font = &self.0;
 
-        let mut x_in_unit = 0f32;
+        let mut x_pixels = 0f32;
 
         let mut prev = None;
-        let place_holder = font.glyph_for_char(PLACEHOLDER_CHAR);
 
         for c in text.chars() {
-            if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
+            if let Some((idx, font, glyph_id)) = self.glyph_for_char(c) {
+                let mut x_in_unit = 0f32;
                 if let Ok(size) = font.advance(glyph_id) {
                     x_in_unit += size.x();
                 }
-                if let Some(pc) = prev {
-                    x_in_unit += font.query_kerning_table(pc, glyph_id);
+                if let Some((prev_idx, pc)) = prev {
+                    if prev_idx == idx {
+                        x_in_unit += font.query_kerning_table(pc, glyph_id);
+                    }
                 }
-                prev = Some(glyph_id);
+                x_pixels += x_in_unit * pixel_per_em as f32 / font.metrics().units_per_em as f32;
+                prev = Some((idx, glyph_id));
             }
         }
 
-        let x_pixels = x_in_unit * pixel_per_em as f32 / metrics.units_per_em as f32;
-
         Ok(((0, 0), (x_pixels as i32, pixel_per_em as i32)))
     }
 
@@ -241,22 +329,23 @@ impl FontData for FontDataInternal {
         let em = (size / 1.24) as f32;
 
         let mut x = base_x as f32;
-        let font = &self.0;
-        let metrics = font.metrics();
 
         let canvas_size = size as usize;
 
         base_y -= (0.24 * em) as i32;
 
         let mut prev = None;
-        let place_holder = font.glyph_for_char(PLACEHOLDER_CHAR);
 
         let mut result = Ok(());
 
         for c in text.chars() {
-            if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
-                if let Some(pc) = prev {
-                    x += font.query_kerning_table(pc, glyph_id) * em / metrics.units_per_em as f32;
+            if let Some((idx, font, glyph_id)) = self.glyph_for_char(c) {
+                let metrics = font.metrics();
+                if let Some((prev_idx, pc)) = prev {
+                    if prev_idx == idx {
+                        x += font.query_kerning_table(pc, glyph_id) * em
+                            / metrics.units_per_em as f32;
+                    }
                 }
 
                 let mut canvas = Canvas::new(Vector2I::splat(canvas_size as i32), Format::A8);
@@ -287,7 +376,7 @@ impl FontData for FontDataInternal {
                 x += font.advance(glyph_id).map(|size| size.x()).unwrap_or(0.0) * em
                     / metrics.units_per_em as f32;
 
-                prev = Some(glyph_id);
+                prev = Some((idx, glyph_id));
             }
         }
         result?;
@@ -315,4 +404,27 @@ mod test {
 
         return Ok(());
     }
+
+    #[test]
+    fn test_register_font() -> FontResult<()> {
+        assert!(register_font("invalid-font", FontStyle::Normal, vec![0u8; 16]).is_err());
+
+        let system_font = load_font_data(FontFamily::SansSerif, FontStyle::Normal)?;
+        let bytes = match system_font.handle() {
+            Some(Handle::Memory { bytes, .. }) => bytes.to_vec(),
+            _ => unreachable!(),
+        };
+        register_font("registered-font", FontStyle::Bold, bytes)?;
+        assert!(DATA_CACHE
+            .read()
+            .unwrap()
+            .contains_key("registered-font, bold"));
+        let font = FontDataInternal::new(FontFamily::Name("registered-font"), FontStyle::Bold)?;
+        assert!(font.estimate_layout(20.0, "abc")?.1 .0 > 0);
+
+        set_font_fallback("registered-font", &["serif", "monospace"])?;
+        let font = FontDataInternal::new(FontFamily::Name("registered-font"), FontStyle::Bold)?;
+        assert_eq!(font.fallbacks.len(), 2);
+        Ok(())
+    }
 }
@@ -0,0 +1,552 @@
+/*!
+  Ready-made formatters for the numeric tick labels.
+
+  Each formatter can be used as a plain label formatter closure, for example
+  `.y_label_formatter(&formatters::si())`, in which case a reasonable default precision is
+  used. When the formatter is attached with `MeshStyle::y_tick_formatter`, the distance between
+  two adjacent ticks is known and the precision is picked so that adjacent ticks never render to
+  the same string.
+
+  ```rust
+  use plotters::prelude::*;
+  use plotters::style::formatters;
+
+  let si = formatters::si();
+  assert_eq!(si(&1_200_000.0), "1.2M");
+  assert_eq!(si(&350_000.0), "350k");
+
+  let percent = formatters::percent(0);
+  assert_eq!(percent(&0.12), "12%");
+
+  let thousands = formatters::thousands(',');
+  assert_eq!(thousands(&1_234_567), "1,234,567");
+
+  let engineering = formatters::engineering(1);
+  assert_eq!(engineering(&0.000_42), "420.0e-6");
+  ```
+
+  For the axes with large or tiny values, `MeshStyle::y_offset_notation` divides the labels by a
+  common power of 10 and shows it once in the axis description instead. Similarly,
+  `MeshStyle::y_unit` rescales the labels with the prefix of a unit, such as `MiB` for the bytes,
+  which is shown once in the axis description.
+*/
+use num_traits::ToPrimitive;
+
+/// The formatter for a numeric tick label
+pub trait TickFormatter {
+    /// Format a tick value
+    /// - `value`: The value to format
+    /// - `step`: The distance between two adjacent ticks, if known
+    /// - **returns**: The label text
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String;
+}
+
+/// The SI-prefix formatter, which formats `1200000` as `1.2M`
+pub struct SIFormatter;
+
+/// The percentage formatter, which formats `0.12` as `12%` with at least the given number of
+/// decimals
+pub struct PercentFormatter(pub usize);
+
+/// The formatter that groups the digits of the integer part with the given separator
+pub struct ThousandsFormatter(pub char);
+
+/// The scientific notation formatter with at least the given number of decimals in mantissa
+pub struct ScientificFormatter(pub usize);
+
+/// The binary prefix formatter for byte sizes, which formats `1536` as `1.5KiB`
+pub struct BytesFormatter;
+
+/// The engineering notation formatter, the exponent is a multiple of 3 and the mantissa has at
+/// least the given number of decimals, e.g. `12.3e3`
+pub struct EngineeringFormatter(pub usize);
+
+/// The prefixes of the SI units from the smallest, and the index of the unprefixed unit
+const SI_PREFIXES: ([&str; 8], usize) = (["n", "µ", "m", "", "k", "M", "G", "T"], 3);
+
+/// The binary prefixes from the smallest, which is the unprefixed unit
+const BINARY_PREFIXES: [&str; 6] = ["", "Ki", "Mi", "Gi", "Ti", "Pi"];
+
+/// The kind of the prefixes of an [AxisUnit](struct.AxisUnit.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnitPrefixes {
+    /// The SI prefixes from `n` to `T`, each one is 1000 times the previous one
+    Si,
+    /// The binary prefixes from `Ki` to `Pi`, each one is 1024 times the previous one
+    Binary,
+}
+
+/// The unit of an axis, whose labels are rescaled with the prefix that suits the largest tick,
+/// and the prefixed unit is shown once in the axis description, e.g. the labels `0`, `0.5` and
+/// `1` with `(MiB)`. See
+/// [MeshStyle::y_unit](../../chart/struct.MeshStyle.html#method.y_unit).
+///
+/// A unit converted from a string uses the SI prefixes, except `"bytes"`, `"byte"` and `"B"`,
+/// which are the bytes with the binary prefixes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisUnit {
+    symbol: String,
+    prefixes: UnitPrefixes,
+}
+
+impl AxisUnit {
+    /// Create a unit with the SI prefixes
+    /// - `symbol`: The symbol of the unit, e.g. `"m"` or `"Hz"`
+    pub fn si<S: Into<String>>(symbol: S) -> Self {
+        Self {
+            symbol: symbol.into(),
+            prefixes: UnitPrefixes::Si,
+        }
+    }
+
+    /// Create a unit with the binary prefixes
+    /// - `symbol`: The symbol of the unit, e.g. `"B"` or `"bit"`
+    pub fn binary<S: Into<String>>(symbol: S) -> Self {
+        Self {
+            symbol: symbol.into(),
+            prefixes: UnitPrefixes::Binary,
+        }
+    }
+
+    /// Choose the prefix for the tick values, which is the largest one that keeps the largest
+    /// magnitude at least 1
+    /// - **returns**: The scale of the prefix and the prefixed unit
+    pub fn prefix_for<I: IntoIterator<Item = f64>>(&self, ticks: I) -> (f64, String) {
+        let max = ticks
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .fold(0.0f64, |max, v| max.max(v.abs()));
+        let (base, prefixes, zero): (f64, &[&str], usize) = match self.prefixes {
+            UnitPrefixes::Si => (1000.0, &SI_PREFIXES.0, SI_PREFIXES.1),
+            UnitPrefixes::Binary => (1024.0, &BINARY_PREFIXES, 0),
+        };
+        let (mut idx, mut scale) = (zero, 1.0);
+        if max > 0.0 {
+            while idx + 1 < prefixes.len() && max >= scale * base {
+                idx += 1;
+                scale *= base;
+            }
+            while idx > 0 && max < scale {
+                idx -= 1;
+                scale /= base;
+            }
+        }
+        (scale, format!("{}{}", prefixes[idx], self.symbol))
+    }
+}
+
+impl<'a> From<&'a str> for AxisUnit {
+    fn from(symbol: &'a str) -> Self {
+        match symbol {
+            "bytes" | "byte" | "B" => AxisUnit::binary("B"),
+            _ => AxisUnit::si(symbol),
+        }
+    }
+}
+
+impl From<String> for AxisUnit {
+    fn from(symbol: String) -> Self {
+        AxisUnit::from(symbol.as_str())
+    }
+}
+
+/// The formatter for the offset notation, which formats the values in the unit of
+/// `10^exponent`, thus `2500000` is formatted as `2.5` with the exponent `6`. The exponent is
+/// shown once on the axis, see
+/// [MeshStyle::y_offset_notation](../../chart/struct.MeshStyle.html#method.y_offset_notation).
+pub struct OffsetFormatter(pub i32);
+
+/// Compute the number of decimals that is required to represent every multiple of the step
+fn decimals_for_step(step: f64) -> usize {
+    let step = step.abs();
+    if !step.is_finite() || step == 0.0 {
+        return 0;
+    }
+    for decimals in 0..10 {
+        let scaled = step * (10f64).powi(decimals as i32);
+        if (scaled - scaled.round()).abs() < 1e-6 * scaled.max(1.0) {
+            return decimals;
+        }
+    }
+    10
+}
+
+/// Format the value with the decimals determined by the step, or with three significant digits
+/// if the step is unknown. Trailing zeros are trimmed in the latter case.
+fn format_decimal(value: f64, step: Option<f64>) -> String {
+    if let Some(step) = step {
+        return format!("{:.*}", decimals_for_step(step), value);
+    }
+
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    let magnitude = value.abs().log10().floor() as i32;
+    let decimals = (2 - magnitude).max(0) as usize;
+    let mut result = format!("{:.*}", decimals, value);
+    if result.contains('.') {
+        while result.ends_with('0') {
+            result.pop();
+        }
+        if result.ends_with('.') {
+            result.pop();
+        }
+    }
+    result
+}
+
+/// Format the value with a prefixed unit, `base` is the ratio between two adjacent prefixes and
+/// `prefixes` lists the prefixes starting from the smallest one, `zero` is the index of the
+/// unprefixed unit
+fn format_prefixed(
+    value: f64,
+    step: Option<f64>,
+    base: f64,
+    prefixes: &[&str],
+    zero: usize,
+) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}{}", format_decimal(value, None), prefixes[zero]);
+    }
+
+    let mut idx = zero;
+    let mut scale = 1.0;
+
+    while idx + 1 < prefixes.len() && value.abs() >= scale * base {
+        idx += 1;
+        scale *= base;
+    }
+
+    while idx > 0 && value.abs() < scale {
+        idx -= 1;
+        scale /= base;
+    }
+
+    let mut mantissa = format_decimal(value / scale, step.map(|s| s / scale));
+    // The value right below the next prefix can round up to the base, such as 999999 to 1000k,
+    // thus the next prefix is used instead
+    let rounded = mantissa.parse::<f64>().map_or(0.0, f64::abs);
+    if idx + 1 < prefixes.len() && rounded >= base {
+        idx += 1;
+        scale *= base;
+        mantissa = format_decimal(value / scale, step.map(|s| s / scale));
+    }
+
+    format!("{}{}", mantissa, prefixes[idx])
+}
+
+impl TickFormatter for SIFormatter {
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String {
+        format_prefixed(value, step, 1000.0, &SI_PREFIXES.0, SI_PREFIXES.1)
+    }
+}
+
+impl TickFormatter for PercentFormatter {
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String {
+        let decimals = step
+            .map(|s| decimals_for_step(s * 100.0))
+            .unwrap_or(0)
+            .max(self.0);
+        format!("{:.*}%", decimals, value * 100.0)
+    }
+}
+
+impl TickFormatter for ThousandsFormatter {
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String {
+        let decimals = match step {
+            Some(step) => decimals_for_step(step),
+            None if value.fract() == 0.0 => 0,
+            None => 2,
+        };
+        let text = format!("{:.*}", decimals, value.abs());
+        let (int_part, dec_part) = match text.find('.') {
+            Some(idx) => text.split_at(idx),
+            None => (&text[..], ""),
+        };
+
+        let mut result = String::new();
+        if value < 0.0 && text.chars().any(|c| c != '0' && c != '.') {
+            result.push('-');
+        }
+        for (idx, digit) in int_part.chars().enumerate() {
+            if idx > 0 && (int_part.len() - idx) % 3 == 0 {
+                result.push(self.0);
+            }
+            result.push(digit);
+        }
+        result.push_str(dec_part);
+        result
+    }
+}
+
+impl TickFormatter for ScientificFormatter {
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String {
+        if value == 0.0 {
+            return "0".to_string();
+        }
+        let precision = match step {
+            Some(step) if step > 0.0 => {
+                let value_exp = value.abs().log10().floor() as i32;
+                let step_exp = step.log10().floor() as i32;
+                let extra = decimals_for_step(step / (10f64).powi(step_exp)) as i32;
+                (value_exp - step_exp + extra).max(0) as usize
+            }
+            _ => 0,
+        }
+        .max(self.0);
+        format!("{:.*e}", precision, value)
+    }
+}
+
+impl TickFormatter for BytesFormatter {
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String {
+        format_prefixed(
+            value,
+            step,
+            1024.0,
+            &["B", "KiB", "MiB", "GiB", "TiB", "PiB"],
+            0,
+        )
+    }
+}
+
+impl TickFormatter for EngineeringFormatter {
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String {
+        if value == 0.0 || !value.is_finite() {
+            return format_decimal(value, None);
+        }
+        let exponent = (value.abs().log10().floor() as i32).div_euclid(3) * 3;
+        let scale = (10f64).powi(exponent);
+        let mantissa = value / scale;
+        let text = match step {
+            Some(step) => format!(
+                "{:.*}",
+                decimals_for_step(step / scale).max(self.0),
+                mantissa
+            ),
+            None if self.0 > 0 => format!("{:.*}", self.0, mantissa),
+            None => format_decimal(mantissa, None),
+        };
+        format!("{}e{}", text, exponent)
+    }
+}
+
+impl TickFormatter for OffsetFormatter {
+    fn format_tick(&self, value: f64, step: Option<f64>) -> String {
+        format_scaled(value, step, (10f64).powi(self.0))
+    }
+}
+
+/// Format a tick value in the unit of the scale, with the decimals determined by the step
+pub(crate) fn format_scaled(value: f64, step: Option<f64>, scale: f64) -> String {
+    format_decimal(value / scale, step.map(|s| s / scale))
+}
+
+/// Format a percentage that is already scaled to `0..=100`, such as the value axis of a
+/// normalized stack, without the rounding errors of the tick positions
+#[cfg_attr(
+    not(any(feature = "histogram", feature = "area_series")),
+    allow(dead_code)
+)]
+pub(crate) fn format_percent(value: f64) -> String {
+    format!("{}%", (value * 1e6).round() / 1e6)
+}
+
+fn into_label_formatter<T: ToPrimitive, F: TickFormatter>(fmt: F) -> impl Fn(&T) -> String {
+    move |value: &T| match value.to_f64() {
+        Some(value) => fmt.format_tick(value, None),
+        None => String::new(),
+    }
+}
+
+/// The SI-prefix label formatter (n, µ, m, k, M, G, T), e.g. `1.2M` and `350k`
+pub fn si<T: ToPrimitive>() -> impl Fn(&T) -> String {
+    into_label_formatter(SIFormatter)
+}
+
+/// The percentage label formatter, e.g. `12%` for `0.12`
+/// - `decimals`: The number of decimals
+pub fn percent<T: ToPrimitive>(decimals: usize) -> impl Fn(&T) -> String {
+    into_label_formatter(PercentFormatter(decimals))
+}
+
+/// The label formatter with thousands separators, e.g. `1,234,567`
+/// - `separator`: The separator between the digit groups
+pub fn thousands<T: ToPrimitive>(separator: char) -> impl Fn(&T) -> String {
+    into_label_formatter(ThousandsFormatter(separator))
+}
+
+/// The scientific notation label formatter, e.g. `1.23e4`
+/// - `precision`: The number of decimals of the mantissa
+pub fn scientific<T: ToPrimitive>(precision: usize) -> impl Fn(&T) -> String {
+    into_label_formatter(ScientificFormatter(precision))
+}
+
+/// The engineering notation label formatter, e.g. `12.3e3`
+/// - `precision`: The number of decimals of the mantissa
+pub fn engineering<T: ToPrimitive>(precision: usize) -> impl Fn(&T) -> String {
+    into_label_formatter(EngineeringFormatter(precision))
+}
+
+/// The byte size label formatter with binary prefixes, e.g. `1.5KiB` and `2MiB`
+pub fn bytes<T: ToPrimitive>() -> impl Fn(&T) -> String {
+    into_label_formatter(BytesFormatter)
+}
+
+/// Compute the exponent of the offset notation for the tick values, which is the exponent of the
+/// largest magnitude. There's no offset if the labels are short without it.
+pub(crate) fn offset_exponent<I: IntoIterator<Item = f64>>(ticks: I) -> Option<i32> {
+    let max = ticks
+        .into_iter()
+        .filter(|v| v.is_finite())
+        .fold(0.0f64, |max, v| max.max(v.abs()));
+    if max == 0.0 {
+        return None;
+    }
+    let exponent = max.log10().floor() as i32;
+    if exponent.abs() < 4 {
+        None
+    } else {
+        Some(exponent)
+    }
+}
+
+/// Compute the distance between adjacent ticks from the tick values
+pub(crate) fn tick_step<I: IntoIterator<Item = f64>>(ticks: I) -> Option<f64> {
+    let mut ticks: Vec<_> = ticks.into_iter().filter(|v| v.is_finite()).collect();
+    ticks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ticks
+        .iter()
+        .zip(ticks.iter().skip(1))
+        .map(|(a, b)| b - a)
+        .filter(|d| *d > 0.0)
+        .fold(None, |min: Option<f64>, d| {
+            Some(min.map_or(d, |m| m.min(d)))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_axis_unit() {
+        let bytes = AxisUnit::from("bytes");
+        assert_eq!(bytes, AxisUnit::binary("B"));
+        assert_eq!(
+            bytes.prefix_for(vec![0.0, 1_572_864.0, 3_145_728.0]),
+            (1_048_576.0, "MiB".to_string())
+        );
+        assert_eq!(bytes.prefix_for(vec![0.0, 512.0]), (1.0, "B".to_string()));
+
+        let meters = AxisUnit::from("m");
+        assert_eq!(
+            meters.prefix_for(vec![0.0, 2500.0]),
+            (1000.0, "km".to_string())
+        );
+        assert_eq!(meters.prefix_for(vec![-0.004]), (0.001, "mm".to_string()));
+        assert_eq!(meters.prefix_for(vec![0.0]), (1.0, "m".to_string()));
+        assert_eq!(
+            format_scaled(1_572_864.0, Some(524_288.0), 1_048_576.0),
+            "1.5"
+        );
+    }
+
+    #[test]
+    fn test_si_formatter() {
+        assert_eq!(SIFormatter.format_tick(1_200_000.0, None), "1.2M");
+        assert_eq!(SIFormatter.format_tick(350_000.0, None), "350k");
+        assert_eq!(SIFormatter.format_tick(0.000_002_5, None), "2.5µ");
+        assert_eq!(SIFormatter.format_tick(0.0, None), "0");
+        assert_eq!(SIFormatter.format_tick(1_001_000.0, Some(1000.0)), "1.001M");
+        assert_eq!(SIFormatter.format_tick(1_002_000.0, Some(1000.0)), "1.002M");
+        // The mantissa that rounds up to 1000 moves to the next prefix
+        assert_eq!(SIFormatter.format_tick(999_999.0, None), "1M");
+        assert_eq!(SIFormatter.format_tick(-999_999.0, None), "-1M");
+        assert_eq!(SIFormatter.format_tick(0.000_999_9, None), "1m");
+        assert_eq!(SIFormatter.format_tick(999_400.0, None), "999k");
+    }
+
+    #[test]
+    fn test_adjacent_ticks_are_distinct() {
+        let ticks: Vec<f64> = (0..10).map(|i| 1.0 + i as f64 * 0.25).collect();
+        let step = tick_step(ticks.iter().cloned());
+        assert_eq!(step, Some(0.25));
+        let formatters: Vec<Box<dyn TickFormatter>> = vec![
+            Box::new(SIFormatter),
+            Box::new(PercentFormatter(0)),
+            Box::new(ThousandsFormatter(',')),
+            Box::new(ScientificFormatter(0)),
+            Box::new(BytesFormatter),
+            Box::new(EngineeringFormatter(0)),
+            Box::new(OffsetFormatter(-1)),
+        ];
+        for fmt in formatters {
+            let labels: Vec<_> = ticks.iter().map(|v| fmt.format_tick(*v, step)).collect();
+            for (a, b) in labels.iter().zip(labels.iter().skip(1)) {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_other_formatters() {
+        assert_eq!(percent(1)(&0.125), "12.5%");
+        assert_eq!(thousands(',')(&1_234_567), "1,234,567");
+        assert_eq!(thousands('.')(&-1234.5), "-1.234.50");
+        assert_eq!(scientific(2)(&12345.0), "1.23e4");
+        assert_eq!(bytes()(&1536), "1.5KiB");
+        assert_eq!(bytes()(&(2 * 1024 * 1024)), "2MiB");
+        assert_eq!(engineering(0)(&12345.0), "12.3e3");
+        assert_eq!(engineering(1)(&0.000_42), "420.0e-6");
+        assert_eq!(
+            OffsetFormatter(6).format_tick(2_500_000.0, Some(500_000.0)),
+            "2.5"
+        );
+        assert_eq!(offset_exponent(vec![0.0, 1e6, 2e6]), Some(6));
+        assert_eq!(offset_exponent(vec![0.0, 500.0, 1000.0]), None);
+    }
+
+    #[test]
+    fn test_mesh_tick_formatter() {
+        use crate::prelude::*;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            for _ in 0..100 {
+                let labels = labels.clone();
+                m.check_draw_text(move |_, _, _, _, text| {
+                    labels.borrow_mut().push(text.to_string())
+                });
+            }
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0.0..2_000_000.0, 0.0..0.1)
+            .unwrap();
+
+        chart
+            .configure_mesh()
+            .x_label_formatter(&si())
+            .y_tick_formatter(&PercentFormatter(0))
+            .draw()
+            .unwrap();
+
+        let labels = labels.borrow();
+        let y_labels: Vec<_> = labels.iter().filter(|l| l.ends_with('%')).collect();
+        assert_eq!(
+            y_labels,
+            vec!["1%", "2%", "3%", "4%", "5%", "6%", "7%", "8%", "9%"]
+        );
+        let x_labels: Vec<_> = labels.iter().filter(|l| !l.ends_with('%')).collect();
+        assert_eq!(
+            x_labels,
+            vec!["200k", "400k", "600k", "800k", "1M", "1.2M", "1.4M", "1.6M", "1.8M", "2M"]
+        );
+    }
+}
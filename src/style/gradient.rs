@@ -0,0 +1,334 @@
+use super::color::{Color, RGBAColor};
+use crate::drawing::BackendCapabilities;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The largest number of colors that a gradient is drawn with
+const LEVELS: usize = 256;
+
+/// The geometry of a gradient
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GradientKind {
+    Linear { from: (f64, f64), to: (f64, f64) },
+    Radial { center: (f64, f64), radius: f64 },
+}
+
+/// The gradient that fills a shape instead of a single color.
+///
+/// The geometry of a gradient is relative to the bounding box of the filled shape: `(0.0, 0.0)`
+/// is the upper left corner and `(1.0, 1.0)` is the lower right corner, thus the same gradient
+/// follows the size of each bar or area that it fills. The colors between the stops are
+/// interpolated, including the alpha channel, and the area beyond the first and the last stop
+/// has the color of that stop.
+///
+/// The backends have no native gradients, so a gradient is drawn as bands of solid colors: a
+/// linear gradient is drawn as polygons and a radial gradient as horizontal runs of pixels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..10, 0..10)
+///     .unwrap();
+/// let fade = Gradient::vertical().stop(0.0, &BLUE).stop(1.0, &BLUE.mix(0.0));
+/// chart
+///     .draw_series(
+///         AreaSeries::new((0..10).map(|x| (x, x)), 0, &BLUE).fill_gradient(fade.clone()),
+///     )
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .data(vec![(2, 5), (7, 3)])
+///             .map(|bar| bar.fill_gradient(fade.clone())),
+///     )
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    kind: GradientKind,
+    stops: Vec<(f64, RGBAColor)>,
+}
+
+impl Gradient {
+    fn with_kind(kind: GradientKind) -> Self {
+        Self {
+            kind,
+            stops: vec![],
+        }
+    }
+
+    /// Create a linear gradient without color stops
+    /// - `from`: The point where the gradient starts, which has the color at 0
+    /// - `to`: The point where the gradient ends, which has the color at 1
+    pub fn linear(from: (f64, f64), to: (f64, f64)) -> Self {
+        Self::with_kind(GradientKind::Linear { from, to })
+    }
+
+    /// Create a linear gradient from the top to the bottom of the shape
+    pub fn vertical() -> Self {
+        Self::linear((0.0, 0.0), (0.0, 1.0))
+    }
+
+    /// Create a linear gradient from the left to the right of the shape
+    pub fn horizontal() -> Self {
+        Self::linear((0.0, 0.0), (1.0, 0.0))
+    }
+
+    /// Create a radial gradient without color stops
+    /// - `center`: The center of the gradient, which has the color at 0
+    /// - `radius`: The distance from the center that has the color at 1, relative to the larger
+    ///   side of the bounding box
+    pub fn radial(center: (f64, f64), radius: f64) -> Self {
+        Self::with_kind(GradientKind::Radial { center, radius })
+    }
+
+    /// Add a color stop to the gradient
+    /// - `pos`: The position of the stop from 0 to 1
+    /// - `color`: The color at the stop
+    pub fn stop<C: Color>(mut self, pos: f64, color: &C) -> Self {
+        if !pos.is_nan() {
+            let idx = self.stops.iter().take_while(|s| s.0 <= pos).count();
+            self.stops.insert(idx, (pos, color.to_rgba()));
+        }
+        self
+    }
+
+    /// Get the color of the gradient at the given position
+    /// - `t`: The position from 0 to 1
+    /// - **returns** The interpolated color, which is transparent if the gradient has no stops
+    pub fn color_at(&self, t: f64) -> RGBAColor {
+        let next = match self.stops.iter().position(|s| s.0 > t) {
+            _ if self.stops.is_empty() => return RGBAColor(0, 0, 0, 0.0),
+            Some(0) => return self.stops[0].1,
+            Some(next) => next,
+            None => return self.stops[self.stops.len() - 1].1,
+        };
+        let ((x0, a), (x1, b)) = (self.stops[next - 1], self.stops[next]);
+        let r = (t - x0) / (x1 - x0);
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * r).round() as u8;
+        RGBAColor(
+            mix(a.0, b.0),
+            mix(a.1, b.1),
+            mix(a.2, b.2),
+            a.3 + (b.3 - a.3) * r,
+        )
+    }
+
+    /// The color of the band that the position falls in
+    fn band_color(&self, t: f64) -> RGBAColor {
+        let t = if t.is_nan() || t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            t
+        };
+        let level = (t * (LEVELS - 1) as f64).round();
+        self.color_at(level / (LEVELS - 1) as f64)
+    }
+
+    /// Fill the polygon with the gradient, or with its middle color if the backend has no
+    /// gradients
+    pub(crate) fn fill_polygon<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        points: &[BackendCoord],
+        capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if points.len() < 3 {
+            return Ok(());
+        }
+        self.fill_polygon_in(backend, points, bounding_box(points), capabilities)
+    }
+
+    /// Fill the polygon with the gradient that is relative to the given box, thus the parts of
+    /// a shape share the same gradient
+    pub(crate) fn fill_polygon_in<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        points: &[BackendCoord],
+        ((x0, y0), (x1, y1)): (BackendCoord, BackendCoord),
+        capabilities: &BackendCapabilities,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if points.len() < 3 {
+            return Ok(());
+        }
+        if !capabilities.gradients {
+            return backend.fill_polygon(points.iter().cloned(), &self.color_at(0.5));
+        }
+        let (w, h) = (f64::from(x1 - x0), f64::from(y1 - y0));
+        let to_pixel = |p: (f64, f64)| (f64::from(x0) + p.0 * w, f64::from(y0) + p.1 * h);
+
+        match self.kind {
+            GradientKind::Linear { from, to } => {
+                let (from, to) = (to_pixel(from), to_pixel(to));
+                self.fill_linear(backend, points, from, (to.0 - from.0, to.1 - from.1))
+            }
+            GradientKind::Radial { center, radius } => {
+                let radius = radius * w.max(h);
+                let rows = bounding_box(points);
+                self.fill_radial(
+                    backend,
+                    points,
+                    to_pixel(center),
+                    radius,
+                    ((rows.0).1, (rows.1).1),
+                )
+            }
+        }
+    }
+
+    /// Fill the polygon with the bands of a linear gradient, which are the slices of the polygon
+    /// between the lines at the band boundaries
+    fn fill_linear<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        points: &[BackendCoord],
+        from: (f64, f64),
+        dir: (f64, f64),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let polygon: Vec<_> = points
+            .iter()
+            .map(|p| (f64::from(p.0), f64::from(p.1)))
+            .collect();
+        let len2 = dir.0 * dir.0 + dir.1 * dir.1;
+        if len2 == 0.0 {
+            let color = self.band_color(0.0).to_backend_color();
+            return backend.fill_polygon(points.iter().cloned(), &color);
+        }
+        let project = |p: &(f64, f64)| ((p.0 - from.0) * dir.0 + (p.1 - from.1) * dir.1) / len2;
+
+        let ts: Vec<_> = polygon.iter().map(project).collect();
+        let (t_min, t_max) = ts
+            .iter()
+            .fold((ts[0], ts[0]), |r, &t| (r.0.min(t), r.1.max(t)));
+        let pixels = ((t_max - t_min) * len2.sqrt()).ceil() as usize;
+        let num_bands = if pixels == 0 { 1 } else { pixels.min(LEVELS) };
+        let step = (t_max - t_min) / num_bands as f64;
+
+        let mut start = 0;
+        for idx in 0..num_bands {
+            let color = self.band_color(t_min + (idx as f64 + 0.5) * step);
+            // Merge the neighboring bands with the same color
+            if idx + 1 < num_bands && self.band_color(t_min + (idx as f64 + 1.5) * step) == color {
+                continue;
+            }
+            let (low, high) = (t_min + start as f64 * step, t_min + (idx + 1) as f64 * step);
+            start = idx + 1;
+
+            let band = clip(&polygon, |p| project(p) - low);
+            let band = clip(&band, |p| high - project(p));
+            if band.len() >= 3 {
+                let band = band
+                    .iter()
+                    .map(|p| (p.0.round() as i32, p.1.round() as i32));
+                backend.fill_polygon(band, &color.to_backend_color())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill the polygon with a radial gradient, row by row, as the runs of the pixels that have
+    /// the same color
+    fn fill_radial<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        points: &[BackendCoord],
+        center: (f64, f64),
+        radius: f64,
+        (y0, y1): (i32, i32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let position = |x: i32, y: i32| {
+            if radius <= 0.0 {
+                return 0.0;
+            }
+            (f64::from(x) - center.0).hypot(f64::from(y) - center.1) / radius
+        };
+        for y in y0..=y1 {
+            let yc = f64::from(y) + 0.5;
+            let mut crossings = vec![];
+            for (idx, a) in points.iter().enumerate() {
+                let b = points[(idx + 1) % points.len()];
+                let (ay, by) = (f64::from(a.1), f64::from(b.1));
+                if (ay <= yc) != (by <= yc) {
+                    let r = (yc - ay) / (by - ay);
+                    crossings.push(f64::from(a.0) + r * f64::from(b.0 - a.0));
+                }
+            }
+            crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            for span in crossings.chunks(2).filter(|s| s.len() == 2) {
+                let (from, to) = (
+                    (span[0] - 0.5).ceil() as i32,
+                    (span[1] - 0.5).floor() as i32,
+                );
+                let mut run_start = from;
+                for x in from..=to {
+                    let color = self.band_color(position(x, y));
+                    if x < to && self.band_color(position(x + 1, y)) == color {
+                        continue;
+                    }
+                    backend.draw_line((run_start, y), (x, y), &color.to_backend_color())?;
+                    run_start = x + 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The upper left and the bottom right corners of the points
+fn bounding_box(points: &[BackendCoord]) -> (BackendCoord, BackendCoord) {
+    let (mut x0, mut y0, mut x1, mut y1) = (points[0].0, points[0].1, points[0].0, points[0].1);
+    for &(x, y) in points {
+        x0 = x0.min(x);
+        y0 = y0.min(y);
+        x1 = x1.max(x);
+        y1 = y1.max(y);
+    }
+    ((x0, y0), (x1, y1))
+}
+
+/// Clip the polygon to the half plane where the function is not negative, with the
+/// Sutherland-Hodgman algorithm
+fn clip<F: Fn(&(f64, f64)) -> f64>(polygon: &[(f64, f64)], side: F) -> Vec<(f64, f64)> {
+    let mut result = vec![];
+    for (idx, a) in polygon.iter().enumerate() {
+        let b = &polygon[(idx + 1) % polygon.len()];
+        let (sa, sb) = (side(a), side(b));
+        if sa >= 0.0 {
+            result.push(*a);
+        }
+        if (sa >= 0.0) != (sb >= 0.0) {
+            let r = sa / (sa - sb);
+            result.push((a.0 + (b.0 - a.0) * r, a.1 + (b.1 - a.1) * r));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gradient_colors() {
+        let gradient = Gradient::horizontal()
+            .stop(1.0, &RGBColor(200, 0, 0))
+            .stop(0.0, &RGBColor(0, 0, 100).mix(0.0));
+        assert_eq!(gradient.color_at(-1.0), RGBAColor(0, 0, 100, 0.0));
+        assert_eq!(gradient.color_at(0.5), RGBAColor(100, 0, 50, 0.5));
+        assert_eq!(gradient.color_at(2.0), RGBAColor(200, 0, 0, 1.0));
+        assert_eq!(Gradient::vertical().color_at(0.5).alpha(), 0.0);
+    }
+
+    #[test]
+    fn test_clip_polygon() {
+        let square = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        let left = clip(&square, |p| 4.0 - p.0);
+        assert_eq!(left, vec![(0.0, 0.0), (4.0, 0.0), (4.0, 10.0), (0.0, 10.0)]);
+    }
+}
@@ -2,21 +2,47 @@
   The style for shapes and text, font, color, etc.
 */
 mod color;
+mod colormap;
 pub mod colors;
+mod fill;
 mod font;
+pub mod formatters;
+mod gradient;
+mod normalizer;
 mod palette;
+mod pattern;
+mod rich_text;
+#[cfg(feature = "serialize")]
+mod serialize;
 mod shape;
 mod size;
+mod stroke;
 mod text;
+mod theme;
 
 /// Definitions of palettes of accessibility
 pub use self::palette::*;
 pub use color::{Color, HSLColor, PaletteColor, RGBAColor, RGBColor};
+pub use colormap::{
+    BlackWhite, ColorMap, DerivedColorMap, LinearColorMap, MagmaRGB, PlasmaRGB, QuantizedColorMap,
+    TurboRGB, ViridisRGB,
+};
 pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
+pub(crate) use fill::Fill;
 pub use font::{
-    FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
+    clear_text_cache, set_text_cache_capacity, text_cache_stats, FontDesc, FontError, FontFamily,
+    FontResult, FontStyle, FontTransform, IntoFont, LayoutBox, TextCacheStats,
 };
-pub use shape::ShapeStyle;
+#[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
+pub use font::{register_font, set_font_fallback};
+pub use gradient::Gradient;
+pub use normalizer::Normalizer;
+pub use pattern::{Pattern, PatternKind};
+pub use rich_text::{Baseline, RichText, SpanStyle};
+#[cfg(feature = "serialize")]
+pub use serialize::{PaletteSpec, TextStyleSpec};
+pub use shape::{LineCap, LineJoin, ShapeStyle};
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
 pub use text::{IntoTextStyle, TextStyle};
+pub use theme::Theme;
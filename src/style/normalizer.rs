@@ -0,0 +1,209 @@
+use crate::coord::ranged1d::Ranged;
+use crate::coord::types::RangedCoordf64;
+
+/// How the data values are turned into the normalized values of a
+/// [ColorMap](trait.ColorMap.html), which sits between the data and the color map. It's shared by
+/// the [HeatmapSeries](../series/struct.HeatmapSeries.html), the
+/// [QuiverSeries](../series/struct.QuiverSeries.html) and the
+/// [Colorbar](../element/struct.Colorbar.html), thus a skewed data set can be colored
+/// meaningfully and explained with the matching colorbar.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// assert_eq!(Normalizer::Linear.normalize(5.0, 0.0, 10.0), 0.5);
+/// assert!((Normalizer::Log.normalize(10.0, 1.0, 100.0) - 0.5).abs() < 1e-9);
+/// assert_eq!(Normalizer::Diverging { center: 0.0 }.normalize(0.0, -1.0, 10.0), 0.5);
+/// let bins = Normalizer::Discrete { boundaries: vec![0.0, 1.0, 10.0, 100.0] };
+/// assert_eq!(bins.normalize(5.0, 0.0, 100.0), 0.5);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Normalizer {
+    /// The values are mapped linearly from the start to the end of the range
+    Linear,
+    /// The logarithms of the values are mapped linearly, the range needs to be positive, and the
+    /// values that aren't positive get the first color. A range that isn't positive is mapped
+    /// linearly.
+    Log,
+    /// The symmetric logarithm, which is linear near zero and logarithmic far from it, thus the
+    /// values of both signs that span many orders of magnitude can be shown
+    SymLog {
+        /// The magnitude below which the mapping is roughly linear
+        threshold: f64,
+    },
+    /// The values below the center are mapped to the first half of the color map and the values
+    /// above it to the second half, thus the center gets the middle color even if the range isn't
+    /// symmetric. This fits the diverging color maps.
+    Diverging {
+        /// The value that gets the middle color
+        center: f64,
+    },
+    /// The values are sorted into the bins between the consecutive boundaries, and each bin gets
+    /// a single color, evenly spaced on the color map. The values out of the boundaries get the
+    /// colors of the first and the last bins, and the range is ignored.
+    Discrete {
+        /// The ascending boundaries of the bins
+        boundaries: Vec<f64>,
+    },
+}
+
+/// The position of the value between `a` and `b`, or the end that the value is at if they're the
+/// same
+fn ratio(v: f64, a: f64, b: f64) -> f64 {
+    if a == b {
+        return if v > a { 1.0 } else { 0.0 };
+    }
+    (v - a) / (b - a)
+}
+
+fn symlog(v: f64, threshold: f64) -> f64 {
+    v.signum() * (v.abs() / threshold).ln_1p()
+}
+
+impl Normalizer {
+    /// Normalize the value
+    /// - `v`: The value
+    /// - `min`: The start of the value range
+    /// - `max`: The end of the value range
+    /// - **returns** The normalized value, which is out of `0.0..=1.0` if the value is out of the
+    ///   range
+    pub fn normalize(&self, v: f64, min: f64, max: f64) -> f64 {
+        self.quantize(self.position(v, min, max))
+    }
+
+    /// The continuous position of the value on the color map, which only differs from the
+    /// normalized value for the discrete bins, whose values within a bin are spread over the bin
+    pub(crate) fn position(&self, v: f64, min: f64, max: f64) -> f64 {
+        match self {
+            Normalizer::Linear => ratio(v, min, max),
+            Normalizer::Log => {
+                if min <= 0.0 || max <= 0.0 {
+                    return ratio(v, min, max);
+                }
+                if v <= 0.0 {
+                    return 0.0;
+                }
+                ratio(v.ln(), min.ln(), max.ln())
+            }
+            Normalizer::SymLog { threshold } => {
+                let threshold = if *threshold > 0.0 { *threshold } else { 1.0 };
+                ratio(
+                    symlog(v, threshold),
+                    symlog(min, threshold),
+                    symlog(max, threshold),
+                )
+            }
+            Normalizer::Diverging { center } => {
+                if v < *center {
+                    ratio(v, min, *center) * 0.5
+                } else {
+                    0.5 + ratio(v, *center, max) * 0.5
+                }
+            }
+            Normalizer::Discrete { boundaries } => {
+                let bins = boundaries.len().saturating_sub(1);
+                if bins == 0 || v <= boundaries[0] {
+                    return 0.0;
+                }
+                match boundaries.windows(2).position(|pair| v < pair[1]) {
+                    Some(bin) => {
+                        let (a, b) = (boundaries[bin], boundaries[bin + 1]);
+                        (bin as f64 + ratio(v, a, b)) / bins as f64
+                    }
+                    None => 1.0,
+                }
+            }
+        }
+    }
+
+    /// Turn a continuous position into the normalized value, which is the center of the bin for
+    /// the discrete bins
+    pub(crate) fn quantize(&self, t: f64) -> f64 {
+        match self {
+            Normalizer::Discrete { boundaries } if boundaries.len() >= 2 => {
+                let bins = (boundaries.len() - 1) as f64;
+                let t = if t.is_nan() || t <= 0.0 {
+                    0.0
+                } else {
+                    t.min(1.0)
+                };
+                ((t * bins).floor().min(bins - 1.0) + 0.5) / bins
+            }
+            _ => t,
+        }
+    }
+
+    /// The values to put the ticks of a colorbar at, which are the powers of ten for the
+    /// logarithmic mapping and the boundaries for the discrete bins
+    pub(crate) fn ticks(&self, min: f64, max: f64, max_ticks: usize) -> Vec<f64> {
+        let linear = || {
+            let coord: RangedCoordf64 = (min..max).into();
+            coord.key_points(max_ticks)
+        };
+        match self {
+            Normalizer::Log if min > 0.0 && max > 0.0 => {
+                let (low, high) = (min.min(max).log10(), min.max(max).log10());
+                let (first, last) = (low.ceil() as i32, high.floor() as i32);
+                if last <= first {
+                    return linear();
+                }
+                let count = (last - first + 1) as usize;
+                let step = 1 + (count - 1) / max_ticks.max(1);
+                (first..=last)
+                    .step_by(step)
+                    .map(|exp| 10f64.powi(exp))
+                    .collect()
+            }
+            Normalizer::Discrete { boundaries } if !boundaries.is_empty() => boundaries.clone(),
+            _ => linear(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalizers() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        assert_eq!(Normalizer::Linear.normalize(2.5, 0.0, 10.0), 0.25);
+        assert_eq!(Normalizer::Linear.normalize(1.0, 1.0, 1.0), 0.0);
+
+        assert!(close(Normalizer::Log.normalize(100.0, 1.0, 10000.0), 0.5));
+        assert_eq!(Normalizer::Log.normalize(-1.0, 1.0, 100.0), 0.0);
+        assert_eq!(Normalizer::Log.normalize(5.0, 0.0, 10.0), 0.5);
+
+        let symlog = Normalizer::SymLog { threshold: 1.0 };
+        assert!(close(symlog.normalize(0.0, -100.0, 100.0), 0.5));
+        assert!(close(
+            symlog.normalize(10.0, -100.0, 100.0) - 0.5,
+            0.5 - symlog.normalize(-10.0, -100.0, 100.0)
+        ));
+        // The small values get a much larger share of the color map than linearly
+        assert!(symlog.normalize(10.0, -100.0, 100.0) > 0.7);
+
+        let diverging = Normalizer::Diverging { center: 0.0 };
+        assert_eq!(diverging.normalize(-1.0, -1.0, 9.0), 0.0);
+        assert_eq!(diverging.normalize(-0.5, -1.0, 9.0), 0.25);
+        assert_eq!(diverging.normalize(0.0, -1.0, 9.0), 0.5);
+        assert_eq!(diverging.normalize(9.0, -1.0, 9.0), 1.0);
+
+        let discrete = Normalizer::Discrete {
+            boundaries: vec![0.0, 1.0, 10.0, 100.0, 1000.0],
+        };
+        assert_eq!(discrete.normalize(0.5, 0.0, 0.0), 0.125);
+        assert_eq!(discrete.normalize(50.0, 0.0, 0.0), 0.625);
+        assert_eq!(discrete.normalize(-5.0, 0.0, 0.0), 0.125);
+        assert_eq!(discrete.normalize(5000.0, 0.0, 0.0), 0.875);
+        assert_eq!(discrete.position(5.5, 0.0, 0.0), 0.375);
+
+        assert_eq!(
+            Normalizer::Log.ticks(1.0, 1000.0, 5),
+            vec![1.0, 10.0, 100.0, 1000.0]
+        );
+        assert_eq!(Normalizer::Log.ticks(1.0, 1e8, 3), vec![1.0, 1000.0, 1e6]);
+        assert_eq!(discrete.ticks(0.0, 0.0, 2).len(), 5);
+    }
+}
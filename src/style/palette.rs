@@ -1,4 +1,7 @@
 use super::color::PaletteColor;
+use super::ShapeStyle;
+
+use std::marker::PhantomData;
 
 pub trait Palette {
     const COLORS: &'static [(u8, u8, u8)];
@@ -16,6 +19,12 @@ pub struct Palette99;
 pub struct Palette9999;
 /// The palette of 100% accessibility
 pub struct Palette100;
+/// The qualitative palette with 10 colors, which is also known as Tableau 10
+pub struct PaletteCategory10;
+/// The qualitative palette with 20 colors in 10 pairs of a saturated and a light shade
+pub struct PaletteCategory20;
+/// The colorblind safe palette by Okabe and Ito
+pub struct PaletteColorblind;
 
 impl Palette for Palette99 {
     const COLORS: &'static [(u8, u8, u8)] = &[
@@ -61,3 +70,113 @@ impl Palette for Palette100 {
     const COLORS: &'static [(u8, u8, u8)] =
         &[(255, 225, 25), (0, 130, 200), (128, 128, 128), (0, 0, 0)];
 }
+
+impl Palette for PaletteCategory10 {
+    const COLORS: &'static [(u8, u8, u8)] = &[
+        (31, 119, 180),
+        (255, 127, 14),
+        (44, 160, 44),
+        (214, 39, 40),
+        (148, 103, 189),
+        (140, 86, 75),
+        (227, 119, 194),
+        (127, 127, 127),
+        (188, 189, 34),
+        (23, 190, 207),
+    ];
+}
+
+impl Palette for PaletteCategory20 {
+    const COLORS: &'static [(u8, u8, u8)] = &[
+        (31, 119, 180),
+        (174, 199, 232),
+        (255, 127, 14),
+        (255, 187, 120),
+        (44, 160, 44),
+        (152, 223, 138),
+        (214, 39, 40),
+        (255, 152, 150),
+        (148, 103, 189),
+        (197, 176, 213),
+        (140, 86, 75),
+        (196, 156, 148),
+        (227, 119, 194),
+        (247, 182, 210),
+        (127, 127, 127),
+        (199, 199, 199),
+        (188, 189, 34),
+        (219, 219, 141),
+        (23, 190, 207),
+        (158, 218, 229),
+    ];
+}
+
+impl Palette for PaletteColorblind {
+    const COLORS: &'static [(u8, u8, u8)] = &[
+        (0, 114, 178),
+        (230, 159, 0),
+        (0, 158, 115),
+        (213, 94, 0),
+        (86, 180, 233),
+        (204, 121, 167),
+        (240, 228, 66),
+        (0, 0, 0),
+    ];
+}
+
+/// The helper that hands out the colors of a palette in order, so that each series drawn in a
+/// loop gets a distinct color. Once all the colors are used, the cycle starts over.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut colors = ColorCycle::new(PaletteColorblind);
+/// let first = colors.next_style();
+/// let second = colors.next_filled_style();
+/// assert_ne!(first.color, second.color);
+/// assert!(second.filled);
+/// ```
+pub struct ColorCycle<P: Palette> {
+    idx: usize,
+    _p: PhantomData<P>,
+}
+
+impl<P: Palette> ColorCycle<P> {
+    /// Create a new color cycle that starts from the first color of the palette
+    /// - `palette`: The palette to pick colors from
+    pub fn new(_palette: P) -> Self {
+        Self {
+            idx: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Get the next color of the palette
+    pub fn next_color(&mut self) -> PaletteColor<P> {
+        let color = PaletteColor::pick(self.idx);
+        self.idx = (self.idx + 1) % P::COLORS.len();
+        color
+    }
+
+    /// Get a stroked shape style with the next color of the palette
+    pub fn next_style(&mut self) -> ShapeStyle {
+        self.next_color().into()
+    }
+
+    /// Get a filled shape style with the next color of the palette
+    pub fn next_filled_style(&mut self) -> ShapeStyle {
+        self.next_style().filled()
+    }
+
+    /// Restart the cycle from the first color of the palette
+    pub fn reset(&mut self) {
+        self.idx = 0;
+    }
+}
+
+impl<P: Palette> Iterator for ColorCycle<P> {
+    type Item = PaletteColor<P>;
+    fn next(&mut self) -> Option<PaletteColor<P>> {
+        Some(self.next_color())
+    }
+}
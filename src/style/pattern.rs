@@ -0,0 +1,198 @@
+use super::color::{Color, RGBAColor};
+use super::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The shape that a pattern repeats
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PatternKind {
+    /// Horizontal lines
+    Horizontal,
+    /// Vertical lines
+    Vertical,
+    /// Lines that rise from the lower left to the upper right
+    Diagonal,
+    /// Lines that fall from the upper left to the lower right
+    BackDiagonal,
+    /// Horizontal and vertical lines
+    Grid,
+    /// Lines in both diagonal directions
+    CrossHatch,
+    /// Dots on a square grid
+    Dots,
+}
+
+/// The hatch pattern that fills a shape instead of a single color, which tells the series
+/// apart in print and in grayscale.
+///
+/// The pattern is aligned to the pixel grid of the drawing area rather than to each shape, so
+/// the neighboring bars and areas with the same pattern line up. The backends have no native
+/// patterns, so the pattern is drawn as lines and dots clipped to the shape.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0..10, 0..10)
+///     .unwrap();
+/// let stripes = Pattern::new(PatternKind::Diagonal, &BLACK).spacing(6).background(&WHITE);
+/// chart
+///     .draw_series(
+///         AreaSeries::new((0..10).map(|x| (x, x)), 0, &BLACK).fill_pattern(stripes.clone()),
+///     )
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .data(vec![(2, 5), (7, 3)])
+///             .map(|bar| bar.fill_pattern(Pattern::new(PatternKind::Dots, &BLACK))),
+///     )
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Pattern {
+    kind: PatternKind,
+    style: ShapeStyle,
+    spacing: u32,
+    background: Option<RGBAColor>,
+}
+
+impl Pattern {
+    /// Create a new pattern on a transparent background
+    /// - `kind`: The shape that the pattern repeats
+    /// - `style`: The style of the lines, the radius of the dots is the stroke width
+    pub fn new<S: Into<ShapeStyle>>(kind: PatternKind, style: S) -> Self {
+        Self {
+            kind,
+            style: style.into(),
+            spacing: 8,
+            background: None,
+        }
+    }
+
+    /// Set the distance between the lines or the dots in pixels
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing.max(1);
+        self
+    }
+
+    /// Fill the shape with the color before the pattern is drawn
+    pub fn background<C: Color>(mut self, color: &C) -> Self {
+        self.background = Some(color.to_rgba());
+        self
+    }
+
+    /// Fill the polygon with the pattern
+    pub(crate) fn fill_polygon<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        points: &[BackendCoord],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if points.len() < 3 {
+            return Ok(());
+        }
+        if let Some(color) = self.background {
+            backend.fill_polygon(points.iter().cloned(), &color.to_backend_color())?;
+        }
+        let polygon: Vec<_> = points
+            .iter()
+            .map(|p| (f64::from(p.0), f64::from(p.1)))
+            .collect();
+        let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+        let normals: &[(f64, f64)] = match self.kind {
+            PatternKind::Horizontal => &[(0.0, 1.0)],
+            PatternKind::Vertical => &[(1.0, 0.0)],
+            PatternKind::Diagonal => &[(diagonal, diagonal)],
+            PatternKind::BackDiagonal => &[(diagonal, -diagonal)],
+            PatternKind::Grid => &[(0.0, 1.0), (1.0, 0.0)],
+            PatternKind::CrossHatch => &[(diagonal, diagonal), (diagonal, -diagonal)],
+            PatternKind::Dots => return self.fill_dots(backend, &polygon),
+        };
+        for &normal in normals {
+            for (from, to) in hatch_segments(&polygon, normal, f64::from(self.spacing)) {
+                let from = (from.0.round() as i32, from.1.round() as i32);
+                let to = (to.0.round() as i32, to.1.round() as i32);
+                backend.draw_line(from, to, &self.style)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_dots<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        polygon: &[(f64, f64)],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let spacing = f64::from(self.spacing);
+        let radius = self.style.stroke_width.max(1);
+        // The dots are the midpoints of the horizontal hatch lines at the grid columns
+        for (from, to) in hatch_segments(polygon, (0.0, 1.0), spacing) {
+            let first = (from.0 / spacing).ceil() as i32;
+            let last = (to.0 / spacing).floor() as i32;
+            for col in first..=last {
+                let x = (f64::from(col) * spacing).round() as i32;
+                backend.draw_circle((x, from.1.round() as i32), radius, &self.style, true)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compute the segments of the hatch lines inside the polygon. The lines are the points `p` with
+/// `normal · p = k * spacing` for each integer `k`, and each line is cut at the edges of the
+/// polygon with the even-odd rule.
+fn hatch_segments(
+    polygon: &[(f64, f64)],
+    normal: (f64, f64),
+    spacing: f64,
+) -> Vec<((f64, f64), (f64, f64))> {
+    let project = |p: &(f64, f64)| normal.0 * p.0 + normal.1 * p.1;
+    let along = |p: &(f64, f64)| normal.0 * p.1 - normal.1 * p.0;
+    let start = project(&polygon[0]);
+    let (low, high) = polygon
+        .iter()
+        .map(project)
+        .fold((start, start), |r, v| (r.0.min(v), r.1.max(v)));
+
+    let mut segments = vec![];
+    let first = (low / spacing).ceil() as i64;
+    let last = (high / spacing).floor() as i64;
+    for k in first..=last {
+        let c = k as f64 * spacing;
+        let mut crossings = vec![];
+        for (idx, a) in polygon.iter().enumerate() {
+            let b = &polygon[(idx + 1) % polygon.len()];
+            let (sa, sb) = (project(a) - c, project(b) - c);
+            if (sa < 0.0) != (sb < 0.0) {
+                let r = sa / (sa - sb);
+                crossings.push((a.0 + (b.0 - a.0) * r, a.1 + (b.1 - a.1) * r));
+            }
+        }
+        crossings.sort_by(|a, b| along(a).partial_cmp(&along(b)).unwrap());
+        for pair in crossings.chunks(2).filter(|p| p.len() == 2) {
+            segments.push((pair[0], pair[1]));
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hatch_segments() {
+        let square = [(0.0, 0.0), (20.0, 0.0), (20.0, 20.0), (0.0, 20.0)];
+        let lines = hatch_segments(&square, (0.0, 1.0), 8.0);
+        // The line on the top edge is left out, because it isn't inside the square
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], ((20.0, 8.0), (0.0, 8.0)));
+
+        let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+        let lines = hatch_segments(&square, (diagonal, diagonal), 8.0);
+        assert!(lines
+            .iter()
+            .all(|(a, b)| (a.0 + a.1 - b.0 - b.1).abs() < 1e-9));
+    }
+}
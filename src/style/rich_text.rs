@@ -0,0 +1,398 @@
+use super::color::{Color, RGBAColor};
+use super::font::FontStyle;
+use super::text_anchor::{HPos, Pos, VPos};
+use super::TextStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The size of a superscript or a subscript relative to the surrounding text
+const SCRIPT_SCALE: f64 = 0.7;
+/// How far the bottom of a superscript is raised, relative to the size of the surrounding text
+const SUPERSCRIPT_RISE: f64 = 0.4;
+/// How far the bottom of a subscript is lowered, relative to the size of the surrounding text
+const SUBSCRIPT_DROP: f64 = 0.2;
+
+/// The vertical position of a span of rich text
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Baseline {
+    /// The span sits on the same line as the surrounding text
+    Normal,
+    /// The span is raised and drawn smaller, such as an exponent
+    Superscript,
+    /// The span is lowered and drawn smaller, such as the index of a chemical formula
+    Subscript,
+}
+
+/// The style of a span of rich text, which is relative to the text style that the rich text is
+/// drawn with. The fields that are `None` keep the value of the text style.
+#[derive(Clone)]
+pub struct SpanStyle {
+    /// The font style of the span, such as bold
+    pub font_style: Option<FontStyle>,
+    /// The color of the span
+    pub color: Option<RGBAColor>,
+    /// The font size relative to the text style, which is multiplied with the size of the
+    /// superscripts and the subscripts
+    pub scale: f64,
+    /// The vertical position of the span
+    pub baseline: Baseline,
+}
+
+impl Default for SpanStyle {
+    fn default() -> Self {
+        Self {
+            font_style: None,
+            color: None,
+            scale: 1.0,
+            baseline: Baseline::Normal,
+        }
+    }
+}
+
+impl SpanStyle {
+    fn is_plain(&self) -> bool {
+        self.font_style.is_none()
+            && self.color.is_none()
+            && self.scale == 1.0
+            && self.baseline == Baseline::Normal
+    }
+
+    fn resolve<'a>(&self, base: &TextStyle<'a>) -> TextStyle<'a> {
+        let mut font = base.font.clone();
+        if let Some(style) = self.font_style {
+            font = font.style(style);
+        }
+        let scale = match self.baseline {
+            Baseline::Normal => self.scale,
+            _ => self.scale * SCRIPT_SCALE,
+        };
+        if scale != 1.0 {
+            font = font.resize(font.get_size() * scale);
+        }
+        TextStyle {
+            font,
+            color: self
+                .color
+                .map_or(base.color, |color| color.to_backend_color()),
+            pos: Pos::new(HPos::Left, VPos::Top),
+        }
+    }
+}
+
+/// The text that is made of the spans with different font styles, sizes, colors and baselines,
+/// such as the scientific labels "m/s²" or "CO₂".
+///
+/// The rich text is drawn with a [Text](../element/struct.Text.html) element created by
+/// [Text::rich](../element/struct.Text.html#method.rich), as the caption of a chart with
+/// [ChartBuilder::caption_rich](../chart/struct.ChartBuilder.html#method.caption_rich), or as
+/// an axis description. The spans are relative to the text style it's drawn with, and the text
+/// is anchored as a whole by the position of the style.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .caption_rich(RichText::new().text("Emissions of ").bold("CO").subscript("2"), ("sans-serif", 30))
+///     .x_label_area_size(40)
+///     .y_label_area_size(40)
+///     .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+///     .unwrap();
+/// chart
+///     .configure_mesh()
+///     .x_desc("time (s)")
+///     .y_desc(RichText::parse("acceleration (m/s^{2})"))
+///     .draw()
+///     .unwrap();
+/// ```
+#[derive(Clone, Default)]
+pub struct RichText {
+    spans: Vec<(String, SpanStyle)>,
+}
+
+impl RichText {
+    /// Create an empty rich text
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the rich text from the plain text with `^` for the superscripts and `_` for the
+    /// subscripts, which apply to the next character or to a group in braces, such as
+    /// `"m/s^2"` or `"CO_{2}"`. A backslash escapes the next character.
+    pub fn parse(markup: &str) -> Self {
+        let mut text = Self::new();
+        let mut plain = String::new();
+        let mut chars = markup.chars();
+        while let Some(c) = chars.next() {
+            let baseline = match c {
+                '^' => Baseline::Superscript,
+                '_' => Baseline::Subscript,
+                '\\' => {
+                    plain.extend(chars.next());
+                    continue;
+                }
+                c => {
+                    plain.push(c);
+                    continue;
+                }
+            };
+            let script: String = match chars.next() {
+                Some('{') => chars.by_ref().take_while(|c| *c != '}').collect(),
+                Some(c) => c.to_string(),
+                None => String::new(),
+            };
+            text = text.text(plain);
+            plain = String::new();
+            let style = SpanStyle {
+                baseline,
+                ..SpanStyle::default()
+            };
+            text = text.span(script, style);
+        }
+        text.text(plain)
+    }
+
+    /// Append a span with the given style
+    pub fn span<S: Into<String>>(mut self, text: S, style: SpanStyle) -> Self {
+        let text = text.into();
+        if !text.is_empty() {
+            self.spans.push((text, style));
+        }
+        self
+    }
+
+    /// Append a span in the text style
+    pub fn text<S: Into<String>>(self, text: S) -> Self {
+        self.span(text, SpanStyle::default())
+    }
+
+    /// Append a superscript
+    pub fn superscript<S: Into<String>>(self, text: S) -> Self {
+        let style = SpanStyle {
+            baseline: Baseline::Superscript,
+            ..SpanStyle::default()
+        };
+        self.span(text, style)
+    }
+
+    /// Append a subscript
+    pub fn subscript<S: Into<String>>(self, text: S) -> Self {
+        let style = SpanStyle {
+            baseline: Baseline::Subscript,
+            ..SpanStyle::default()
+        };
+        self.span(text, style)
+    }
+
+    /// Append a bold span
+    pub fn bold<S: Into<String>>(self, text: S) -> Self {
+        let style = SpanStyle {
+            font_style: Some(FontStyle::Bold),
+            ..SpanStyle::default()
+        };
+        self.span(text, style)
+    }
+
+    /// Append an italic span
+    pub fn italic<S: Into<String>>(self, text: S) -> Self {
+        let style = SpanStyle {
+            font_style: Some(FontStyle::Italic),
+            ..SpanStyle::default()
+        };
+        self.span(text, style)
+    }
+
+    /// Append a span in the given color
+    pub fn colored<S: Into<String>, C: Color>(self, text: S, color: &C) -> Self {
+        let style = SpanStyle {
+            color: Some(color.to_rgba()),
+            ..SpanStyle::default()
+        };
+        self.span(text, style)
+    }
+
+    /// Get the text without the styles
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|s| s.0.as_str()).collect()
+    }
+
+    /// The text of the rich text that is a single span in the text style, which is drawn as the
+    /// plain text
+    pub(crate) fn as_plain(&self) -> Option<&str> {
+        match self.spans.as_slice() {
+            [] => Some(""),
+            [(text, style)] if style.is_plain() => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Draw the rich text, which is anchored as a whole by the position of the style
+    pub(crate) fn draw<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(text) = self.as_plain() {
+            return style.draw_text(backend, text, pos);
+        }
+        let ((w, h), spans) =
+            self.layout(style, |text, style| backend.estimate_text_size(text, style))?;
+        let (w, h) = (w as i32, h as i32);
+        let dx = match style.pos.h_pos {
+            HPos::Left => 0,
+            HPos::Center => -w / 2,
+            HPos::Right => -w,
+        };
+        let dy = match style.pos.v_pos {
+            VPos::Top => 0,
+            VPos::Center => -h / 2,
+            VPos::Bottom => -h,
+        };
+        // The spans are laid out in the frame of the text, which is rotated with the font
+        for ((x, y), text, span_style) in spans {
+            let (x, y) = style.font.rotate_offset((x + dx, y + dy));
+            span_style.draw_text(backend, text, (pos.0 + x, pos.1 + y))?;
+        }
+        Ok(())
+    }
+
+    /// Lay out the spans on a line
+    /// - `base`: The text style that the spans are relative to
+    /// - `measure`: The function that measures the size of a text in pixels
+    /// - **returns** The size of the line, and the upper left corner and the style of each span
+    pub(crate) fn layout<'a, 'b, E, F>(
+        &'b self,
+        base: &TextStyle<'a>,
+        mut measure: F,
+    ) -> Result<RichTextLayout<'a, 'b>, E>
+    where
+        F: FnMut(&str, &TextStyle<'a>) -> Result<(u32, u32), E>,
+    {
+        let size = base.font.get_size();
+        let mut spans = vec![];
+        let (mut x, mut top, mut bottom) = (0, i32::MAX, i32::MIN);
+        for (text, span_style) in self.spans.iter() {
+            let style = span_style.resolve(base);
+            let (w, h) = measure(text, &style)?;
+            let shift = match span_style.baseline {
+                Baseline::Normal => 0.0,
+                Baseline::Superscript => -SUPERSCRIPT_RISE * size,
+                Baseline::Subscript => SUBSCRIPT_DROP * size,
+            };
+            let span_bottom = shift.round() as i32;
+            let span_top = span_bottom - h as i32;
+            top = top.min(span_top);
+            bottom = bottom.max(span_bottom);
+            spans.push(((x, span_top), text.as_str(), style));
+            x += w as i32;
+        }
+        if spans.is_empty() {
+            return Ok(((0, 0), spans));
+        }
+        for span in spans.iter_mut() {
+            (span.0).1 -= top;
+        }
+        Ok(((x as u32, (bottom - top) as u32), spans))
+    }
+}
+
+/// The size of a line of rich text, and the upper left corner, the text and the style of each
+/// span of the line
+pub(crate) type RichTextLayout<'a, 'b> = ((u32, u32), Vec<((i32, i32), &'b str, TextStyle<'a>)>);
+
+impl<'a> From<&'a str> for RichText {
+    fn from(text: &'a str) -> Self {
+        RichText::new().text(text)
+    }
+}
+
+impl<'a> From<&'a String> for RichText {
+    fn from(text: &'a String) -> Self {
+        RichText::new().text(text.as_str())
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        RichText::new().text(text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_parse_rich_text() {
+        let text = RichText::parse("m/s^2 and CO_{22}\\^");
+        assert_eq!(text.plain_text(), "m/s2 and CO22^");
+        let baselines: Vec<_> = text.spans.iter().map(|s| s.1.baseline).collect();
+        assert_eq!(
+            baselines,
+            vec![
+                Baseline::Normal,
+                Baseline::Superscript,
+                Baseline::Normal,
+                Baseline::Subscript,
+                Baseline::Normal
+            ]
+        );
+        assert_eq!(RichText::parse("plain").as_plain(), Some("plain"));
+        assert_eq!(RichText::parse("x^2").as_plain(), None);
+    }
+
+    #[test]
+    fn test_rich_text_layout() {
+        let text = RichText::new().text("ab").superscript("2").subscript("i");
+        let base = TextStyle::from(("sans-serif", 20));
+        // Each character is as wide and as high as the font size
+        let measure = |text: &str, style: &TextStyle| -> Result<(u32, u32), ()> {
+            let size = style.font.get_size() as u32;
+            Ok((text.len() as u32 * size, size))
+        };
+        let ((w, h), spans) = text.layout(&base, measure).unwrap();
+        // The superscript is 14 pixels high and raised by 8 pixels, the subscript is lowered by 4
+        assert_eq!((w, h), (68, 26));
+        assert_eq!(spans[0].0, (0, 2));
+        assert_eq!(spans[1].0, (40, 0));
+        assert_eq!(spans[2].0, (54, 12));
+        assert_eq!(spans[1].2.font.get_size(), 14.0);
+    }
+
+    #[test]
+    fn test_draw_rich_text() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The superscript is drawn at the top, which moves the base text down
+            m.check_draw_text(|c, _, size, pos, text| {
+                assert_eq!(text, "m/s");
+                assert_eq!(size, 20.0);
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(pos.0, 10);
+                assert!(pos.1 > 10);
+            });
+            m.check_draw_text(|c, _, size, pos, text| {
+                assert_eq!(text, "2");
+                assert_eq!(size, 14.0);
+                assert_eq!(c, RED.to_rgba());
+                assert!(pos.0 > 10);
+                assert_eq!(pos.1, 10);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let text = RichText::new().text("m/s").span(
+            "2",
+            SpanStyle {
+                color: Some(RED.to_rgba()),
+                baseline: Baseline::Superscript,
+                ..SpanStyle::default()
+            },
+        );
+        drawing_area
+            .draw(&Text::rich(text, (10, 10), ("sans-serif", 20)))
+            .unwrap();
+    }
+}
@@ -0,0 +1,240 @@
+/*!
+  The serde support of the styles, thus the colors, the shape styles, the text styles and the
+  palettes of a chart can be loaded from the config files.
+
+  The colors are written as the hex strings, such as `"#ff8000"`, `"#f80"` or `"#ff800080"`,
+  the names of the basic colors, such as `"red"`, or the arrays of the components, such as
+  `[255, 128, 0]` for an `RGBColor` and `[255, 128, 0, 0.5]` for an `RGBAColor`.
+
+  ```rust
+  use plotters::prelude::*;
+  use plotters::style::{PaletteSpec, TextStyleSpec};
+
+  let style: ShapeStyle =
+      serde_json::from_str(r##"{ "color": "#1f77b4", "stroke_width": 2 }"##).unwrap();
+  assert_eq!(style.color, RGBColor(0x1f, 0x77, 0xb4).to_rgba());
+
+  let palette: PaletteSpec = serde_json::from_str(r##"["red", [0, 128, 0], "#00f"]"##).unwrap();
+  assert_eq!(palette.pick(4), RGBColor(0, 128, 0));
+  let palette: PaletteSpec = serde_json::from_str(r#""colorblind""#).unwrap();
+  assert_eq!(palette.colors.len(), PaletteColorblind::COLORS.len());
+
+  let caption: TextStyleSpec =
+      serde_json::from_str(r#"{ "family": "serif", "size": 24, "style": "bold" }"#).unwrap();
+  let _text_style = caption.to_text_style();
+  ```
+*/
+use super::color::{Color, RGBAColor, RGBColor};
+use super::colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
+use super::font::{FontDesc, FontFamily, FontStyle};
+use super::palette::{
+    Palette, Palette100, Palette99, Palette9999, PaletteCategory10, PaletteCategory20,
+    PaletteColorblind,
+};
+use super::TextStyle;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The representations of a color in the config files
+#[derive(serde_derive::Deserialize)]
+#[serde(untagged)]
+enum ColorRepr {
+    Text(String),
+    Rgb(u8, u8, u8),
+    Rgba(u8, u8, u8, f64),
+}
+
+fn parse_hex(hex: &str) -> Option<RGBAColor> {
+    let digits: Vec<u8> = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect::<Option<_>>()?;
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|d| d * 17).collect(),
+        6 | 8 => digits.chunks(2).map(|d| d[0] * 16 + d[1]).collect(),
+        _ => return None,
+    };
+    let alpha = channels
+        .get(3)
+        .map(|a| f64::from(*a) / 255.0)
+        .unwrap_or(1.0);
+    Some(RGBAColor(channels[0], channels[1], channels[2], alpha))
+}
+
+fn parse_color(text: &str) -> Option<RGBAColor> {
+    let mut chars = text.chars();
+    if chars.next() == Some('#') {
+        return parse_hex(chars.as_str());
+    }
+    let color = match text.to_lowercase().as_str() {
+        "black" => BLACK.to_rgba(),
+        "white" => WHITE.to_rgba(),
+        "red" => RED.to_rgba(),
+        "green" => GREEN.to_rgba(),
+        "blue" => BLUE.to_rgba(),
+        "yellow" => YELLOW.to_rgba(),
+        "cyan" => CYAN.to_rgba(),
+        "magenta" => MAGENTA.to_rgba(),
+        "transparent" => TRANSPARENT.to_rgba(),
+        _ => return None,
+    };
+    Some(color)
+}
+
+impl ColorRepr {
+    fn into_rgba<E: serde::de::Error>(self) -> Result<RGBAColor, E> {
+        match self {
+            ColorRepr::Text(text) => {
+                parse_color(&text).ok_or_else(|| E::custom(format!("invalid color {:?}", text)))
+            }
+            ColorRepr::Rgb(r, g, b) => Ok(RGBAColor(r, g, b, 1.0)),
+            ColorRepr::Rgba(r, g, b, a) => Ok(RGBAColor(r, g, b, a)),
+        }
+    }
+}
+
+impl Serialize for RGBColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2))
+    }
+}
+
+impl<'de> Deserialize<'de> for RGBColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let color = ColorRepr::deserialize(deserializer)?.into_rgba::<D::Error>()?;
+        if color.3 < 1.0 {
+            return Err(D::Error::custom("the RGB color can't be transparent"));
+        }
+        Ok(RGBColor(color.0, color.1, color.2))
+    }
+}
+
+/// The opaque colors are written as the hex strings, and the others as the arrays of the
+/// components, thus the alpha isn't rounded
+impl Serialize for RGBAColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.3 >= 1.0 {
+            RGBColor(self.0, self.1, self.2).serialize(serializer)
+        } else {
+            (self.0, self.1, self.2, self.3).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RGBAColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ColorRepr::deserialize(deserializer)?.into_rgba()
+    }
+}
+
+fn default_font_style() -> String {
+    "normal".to_string()
+}
+
+fn default_text_color() -> RGBAColor {
+    BLACK.to_rgba()
+}
+
+/// The description of a text style that can be serialized, since a
+/// [TextStyle](struct.TextStyle.html) borrows the font family name and holds the loaded font.
+#[derive(Clone, Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct TextStyleSpec {
+    /// The font family, such as `"sans-serif"` or the name of a font
+    pub family: String,
+    /// The font size in pixels
+    pub size: f64,
+    /// The font style, which is `"normal"`, `"bold"`, `"italic"` or `"oblique"`
+    #[serde(default = "default_font_style")]
+    pub style: String,
+    /// The color of the text, which is black by default
+    #[serde(default = "default_text_color")]
+    pub color: RGBAColor,
+}
+
+impl TextStyleSpec {
+    /// Create the text style that is described
+    pub fn to_text_style(&self) -> TextStyle<'_> {
+        FontDesc::new(
+            FontFamily::from(self.family.as_str()),
+            self.size,
+            FontStyle::from(self.style.as_str()),
+        )
+        .color(&self.color)
+    }
+}
+
+impl<'a> From<&TextStyle<'a>> for TextStyleSpec {
+    fn from(style: &TextStyle<'a>) -> Self {
+        Self {
+            family: style.font.get_family().as_str().to_string(),
+            size: style.font.get_size(),
+            style: style.font.get_style().as_str().to_string(),
+            color: RGBAColor(
+                style.color.rgb.0,
+                style.color.rgb.1,
+                style.color.rgb.2,
+                style.color.alpha,
+            ),
+        }
+    }
+}
+
+/// A palette that is defined at run time, such as a palette loaded from a config file. It's
+/// written as either the list of the colors, or the name of a built-in palette: `"palette99"`,
+/// `"palette9999"`, `"palette100"`, `"category10"`, `"category20"` or `"colorblind"`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteSpec {
+    /// The colors of the palette
+    pub colors: Vec<RGBColor>,
+}
+
+impl PaletteSpec {
+    /// Get the colors of a built-in palette by its name
+    pub fn named(name: &str) -> Option<Self> {
+        let colors = match name.to_lowercase().as_str() {
+            "palette99" => Palette99::COLORS,
+            "palette9999" => Palette9999::COLORS,
+            "palette100" => Palette100::COLORS,
+            "category10" => PaletteCategory10::COLORS,
+            "category20" => PaletteCategory20::COLORS,
+            "colorblind" => PaletteColorblind::COLORS,
+            _ => return None,
+        };
+        Some(Self {
+            colors: colors.iter().map(|&(r, g, b)| RGBColor(r, g, b)).collect(),
+        })
+    }
+
+    /// Pick a color from the palette, the colors are reused when the index is out of the
+    /// palette, and an empty palette is black
+    pub fn pick(&self, idx: usize) -> RGBColor {
+        if self.colors.is_empty() {
+            return BLACK;
+        }
+        self.colors[idx % self.colors.len()]
+    }
+}
+
+impl Serialize for PaletteSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.colors.serialize(serializer)
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+#[serde(untagged)]
+enum PaletteRepr {
+    Named(String),
+    Colors(Vec<RGBColor>),
+}
+
+impl<'de> Deserialize<'de> for PaletteSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match PaletteRepr::deserialize(deserializer)? {
+            PaletteRepr::Named(name) => PaletteSpec::named(&name)
+                .ok_or_else(|| D::Error::custom(format!("unknown palette {:?}", name))),
+            PaletteRepr::Colors(colors) => Ok(PaletteSpec { colors }),
+        }
+    }
+}
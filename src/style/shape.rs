@@ -1,12 +1,87 @@
 use super::color::{Color, RGBAColor};
-use plotters_backend::{BackendColor, BackendStyle};
+use super::stroke::stroke_outline;
+use crate::drawing::BlendMode;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The shape of the corners of a thick line
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet, the very sharp corners are beveled
+    Miter,
+    /// The corners are rounded with a circle
+    Round,
+    /// The corners are cut off between the outer edges
+    Bevel,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for LineJoin {
+    fn default() -> Self {
+        LineJoin::Miter
+    }
+}
+
+/// The shape of the ends of a thick line
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
+pub enum LineCap {
+    /// The line ends at its end points
+    Butt,
+    /// The ends are rounded with a circle
+    Round,
+    /// The line is extended by the half of its width beyond its end points
+    Square,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for LineCap {
+    fn default() -> Self {
+        LineCap::Butt
+    }
+}
+
+#[cfg(feature = "serialize")]
+fn default_stroke_width() -> u32 {
+    1
+}
 
 /// Style for any of shape
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct ShapeStyle {
     pub color: RGBAColor,
+    #[cfg_attr(feature = "serialize", serde(default))]
     pub filled: bool,
+    #[cfg_attr(feature = "serialize", serde(default = "default_stroke_width"))]
     pub stroke_width: u32,
+    /// The lengths of the dashes and the gaps between them in pixels, the lines are solid if it's
+    /// empty. See [ShapeStyle::stroke_dash](#method.stroke_dash).
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub stroke_dash: Vec<f64>,
+    /// The shape of the corners of the lines, see [ShapeStyle::line_join](#method.line_join)
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub line_join: LineJoin,
+    /// The shape of the ends of the lines, see [ShapeStyle::line_join](#method.line_join)
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub line_cap: LineCap,
+    /// The way the elements of the series are composited with each other, see
+    /// [ShapeStyle::blend_mode](#method.blend_mode)
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub blend_mode: BlendMode,
 }
 
 impl ShapeStyle {
@@ -16,6 +91,10 @@ impl ShapeStyle {
             color: self.color.to_rgba(),
             filled: true,
             stroke_width: self.stroke_width,
+            stroke_dash: self.stroke_dash.clone(),
+            line_join: self.line_join,
+            line_cap: self.line_cap,
+            blend_mode: self.blend_mode,
         }
     }
 
@@ -24,8 +103,178 @@ impl ShapeStyle {
             color: self.color.to_rgba(),
             filled: self.filled,
             stroke_width: width,
+            stroke_dash: self.stroke_dash.clone(),
+            line_join: self.line_join,
+            line_cap: self.line_cap,
+            blend_mode: self.blend_mode,
+        }
+    }
+
+    /// Set the dash pattern of the lines, such as `&[6.0, 3.0]` for a dashed line, or
+    /// `&[1.0, 2.0]` for a dotted line
+    /// - `pattern`: The lengths of the dashes and the gaps between them in pixels, starting with
+    ///   a dash. A pattern with an odd number of lengths is repeated, and an empty pattern makes
+    ///   the lines solid.
+    ///
+    /// The backends have no native dashes, so a dashed path is drawn as one path per dash. The
+    /// paths of [PathElement](../element/struct.PathElement.html), which draws the line series
+    /// and the mesh lines, and the outlines of rectangles honor the pattern.
+    pub fn stroke_dash(&self, pattern: &[f64]) -> Self {
+        Self {
+            color: self.color.to_rgba(),
+            filled: self.filled,
+            stroke_width: self.stroke_width,
+            stroke_dash: pattern.to_vec(),
+            line_join: self.line_join,
+            line_cap: self.line_cap,
+            blend_mode: self.blend_mode,
+        }
+    }
+
+    /// Set the shape of the corners of the lines
+    ///
+    /// The miter joins and the butt caps, which are the default, are left to the backend. The
+    /// other joins and caps of a line that is wider than one pixel are drawn as filled polygons
+    /// and circles, thus the joins of a semi-transparent line are darker where they overlap.
+    pub fn line_join(&self, join: LineJoin) -> Self {
+        Self {
+            line_join: join,
+            ..self.clone()
         }
     }
+
+    /// Set the shape of the ends of the lines, see [ShapeStyle::line_join](#method.line_join)
+    pub fn line_cap(&self, cap: LineCap) -> Self {
+        Self {
+            line_cap: cap,
+            ..self.clone()
+        }
+    }
+
+    /// Set the way the elements of the series are composited with each other. The series drawn
+    /// with [ChartContext::draw_series](../chart/struct.ChartContext.html#method.draw_series)
+    /// is blended as a group with the blend mode of its first element, thus the faint points of
+    /// a dense scatter plot drawn with `BlendMode::Additive` add up to the density, and the
+    /// series is composited over the drawing below it as a whole.
+    ///
+    /// On the drawing areas that have the `raster` capability, such as the ones of the
+    /// `BitMapBackend`, the series is rasterized into the pixels of a group, like the ones of
+    /// [DrawingArea::draw_composited](../drawing/struct.DrawingArea.html#method.draw_composited). The
+    /// [AccessibleSVGBackend](../drawing/struct.AccessibleSVGBackend.html) groups the series in
+    /// an isolated `<g>`, whose elements have the `mix-blend-mode` of the blend mode. The plain
+    /// `SVGBackend` has no place for the group, thus its drawing area should be made with
+    /// [with_fallbacks](../drawing/struct.DrawingArea.html#method.with_fallbacks), which knows
+    /// that the backend isn't a raster one and ignores the blend mode, rather than rasterizing
+    /// the series into the pixels of the document.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 320 * 240 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (320, 240)).into_drawing_area();
+    /// root.fill(&BLACK).unwrap();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+    ///     .unwrap();
+    /// let points = (0..1000).map(|i| ((i as f64 * 0.618).fract(), (i as f64 * 0.382).fract()));
+    /// let style = YELLOW.mix(0.2).filled().blend_mode(BlendMode::Additive);
+    /// chart
+    ///     .draw_series(points.map(|p| Circle::new(p, 3, style.clone())))
+    ///     .unwrap();
+    /// ```
+    pub fn blend_mode(&self, blend_mode: BlendMode) -> Self {
+        Self {
+            blend_mode,
+            ..self.clone()
+        }
+    }
+
+    /// Whether the backend can draw the joins and the caps of the style on its own
+    fn has_native_joins(&self) -> bool {
+        self.stroke_width <= 1
+            || (self.line_join == LineJoin::Miter && self.line_cap == LineCap::Butt)
+    }
+
+    /// Whether the backend can draw the lines of the style on its own
+    pub(crate) fn strokes_natively(&self) -> bool {
+        self.stroke_dash.is_empty() && self.has_native_joins()
+    }
+
+    /// Draw the path with the dash pattern, the joins and the caps of the style
+    pub(crate) fn stroke_path<DB: DrawingBackend, I: IntoIterator<Item = BackendCoord>>(
+        &self,
+        backend: &mut DB,
+        path: I,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.strokes_natively() {
+            return backend.draw_path(path, self);
+        }
+        let path: Vec<_> = path.into_iter().collect();
+        let dashes = if self.stroke_dash.is_empty() {
+            vec![path]
+        } else {
+            split_dashes(&path, &self.stroke_dash)
+        };
+        for dash in dashes {
+            if self.has_native_joins() {
+                backend.draw_path(dash, self)?;
+            } else {
+                stroke_outline(backend, &dash, self)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Split the path into the dashes of the pattern, which alternates between the lengths of the
+/// dashes and the gaps. The pattern continues around the corners of the path.
+fn split_dashes(path: &[BackendCoord], pattern: &[f64]) -> Vec<Vec<BackendCoord>> {
+    let mut pattern = pattern.to_vec();
+    if pattern.len() % 2 == 1 {
+        pattern.extend_from_slice(&pattern.clone());
+    }
+    let total: f64 = pattern.iter().sum();
+    if pattern.iter().any(|l| l.is_nan() || *l < 0.0) || total.is_nan() || total <= 0.0 {
+        return vec![path.to_vec()];
+    }
+
+    let round = |p: (f64, f64)| (p.0.round() as i32, p.1.round() as i32);
+    let mut dashes = vec![];
+    let mut current = vec![];
+    let (mut idx, mut left) = (0, pattern[0]);
+    for segment in path.windows(2) {
+        let (a, b) = (segment[0], segment[1]);
+        let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let len = (dx * dx + dy * dy).sqrt();
+        if idx % 2 == 0 && current.is_empty() {
+            current.push(a);
+        }
+        let mut pos = 0.0;
+        while len - pos > left {
+            pos += left;
+            let point = round((
+                f64::from(a.0) + dx * pos / len,
+                f64::from(a.1) + dy * pos / len,
+            ));
+            if idx % 2 == 0 {
+                current.push(point);
+                dashes.push(current);
+                current = vec![];
+            } else {
+                current = vec![point];
+            }
+            idx = (idx + 1) % pattern.len();
+            left = pattern[idx];
+        }
+        left -= len - pos;
+        if idx % 2 == 0 {
+            current.push(b);
+        }
+    }
+    if current.len() >= 2 {
+        dashes.push(current);
+    }
+    dashes
 }
 
 impl<T: Color> From<T> for ShapeStyle {
@@ -34,6 +283,10 @@ impl<T: Color> From<T> for ShapeStyle {
             color: f.to_rgba(),
             filled: false,
             stroke_width: 1,
+            stroke_dash: vec![],
+            line_join: LineJoin::Miter,
+            line_cap: LineCap::Butt,
+            blend_mode: BlendMode::Normal,
         }
     }
 }
@@ -46,3 +299,50 @@ impl BackendStyle for ShapeStyle {
         self.stroke_width
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::split_dashes;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_split_dashes() {
+        assert_eq!(
+            split_dashes(&[(0, 0), (20, 0)], &[5.0, 3.0]),
+            vec![
+                vec![(0, 0), (5, 0)],
+                vec![(8, 0), (13, 0)],
+                vec![(16, 0), (20, 0)]
+            ]
+        );
+        // The dash continues around the corner
+        assert_eq!(
+            split_dashes(&[(0, 0), (4, 0), (4, 10)], &[6.0, 2.0]),
+            vec![vec![(0, 0), (4, 0), (4, 2)], vec![(4, 4), (4, 10)]]
+        );
+        // An odd pattern is repeated, so the second dash is a gap
+        assert_eq!(
+            split_dashes(&[(0, 0), (0, 9)], &[3.0]),
+            vec![vec![(0, 0), (0, 3)], vec![(0, 6), (0, 9)]]
+        );
+        assert_eq!(split_dashes(&[(0, 0), (0, 9)], &[0.0]).len(), 1);
+    }
+
+    #[test]
+    fn test_dashed_path_element() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(10, 10), (16, 10)]);
+            });
+            m.check_draw_path(|_, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 4);
+            });
+        });
+        let style = ShapeStyle::from(&RED).stroke_dash(&[6.0, 4.0]);
+        drawing_area
+            .draw(&PathElement::new(vec![(10, 10), (50, 10)], style))
+            .unwrap();
+    }
+}
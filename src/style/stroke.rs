@@ -0,0 +1,190 @@
+use super::shape::{LineCap, LineJoin, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The longest miter relative to the stroke width, the longer miters are beveled
+const MITER_LIMIT: f64 = 4.0;
+
+/// Draw the thick polyline as filled polygons: a quad for each segment, with the joins between
+/// the segments and the caps at the ends. A path that ends at its first point is closed, which
+/// is joined at that point instead of capped.
+pub(crate) fn stroke_outline<DB: DrawingBackend>(
+    backend: &mut DB,
+    path: &[BackendCoord],
+    style: &ShapeStyle,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let mut points: Vec<(f64, f64)> = vec![];
+    for p in path {
+        let p = (f64::from(p.0), f64::from(p.1));
+        if points.last() != Some(&p) {
+            points.push(p);
+        }
+    }
+    let half = f64::from(style.stroke_width) / 2.0;
+    let fill_style = style.filled();
+    let round = |p: (f64, f64)| (p.0.round() as i32, p.1.round() as i32);
+    let radius = half.round() as u32;
+
+    if points.len() == 1 {
+        let p = points[0];
+        return match style.line_cap {
+            LineCap::Butt => Ok(()),
+            LineCap::Round => backend.draw_circle(round(p), radius, &fill_style, true),
+            LineCap::Square => backend.draw_rect(
+                round((p.0 - half, p.1 - half)),
+                round((p.0 + half, p.1 + half)),
+                &fill_style,
+                true,
+            ),
+        };
+    }
+
+    let closed = points.len() > 2 && points[0] == points[points.len() - 1];
+    let unit = |a: (f64, f64), b: (f64, f64)| {
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let len = dx.hypot(dy);
+        (dx / len, dy / len)
+    };
+    let last = points.len() - 1;
+    for idx in 0..last {
+        let (mut a, mut b) = (points[idx], points[idx + 1]);
+        let u = unit(a, b);
+        if style.line_cap == LineCap::Square && !closed {
+            if idx == 0 {
+                a = (a.0 - u.0 * half, a.1 - u.1 * half);
+            }
+            if idx + 1 == last {
+                b = (b.0 + u.0 * half, b.1 + u.1 * half);
+            }
+        }
+        let n = (-u.1 * half, u.0 * half);
+        let quad = vec![
+            round((a.0 + n.0, a.1 + n.1)),
+            round((b.0 + n.0, b.1 + n.1)),
+            round((b.0 - n.0, b.1 - n.1)),
+            round((a.0 - n.0, a.1 - n.1)),
+        ];
+        backend.fill_polygon(quad, &fill_style)?;
+    }
+
+    let joints = if closed { 1..=last } else { 1..=last - 1 };
+    for idx in joints {
+        let p = points[idx];
+        let prev = points[idx - 1];
+        let next = if idx == last {
+            points[1]
+        } else {
+            points[idx + 1]
+        };
+        draw_join(backend, style, prev, p, next)?;
+    }
+
+    if style.line_cap == LineCap::Round && !closed {
+        backend.draw_circle(round(points[0]), radius, &fill_style, true)?;
+        backend.draw_circle(round(points[last]), radius, &fill_style, true)?;
+    }
+    Ok(())
+}
+
+/// Fill the gap on the outer side of the corner at `p`
+fn draw_join<DB: DrawingBackend>(
+    backend: &mut DB,
+    style: &ShapeStyle,
+    prev: (f64, f64),
+    p: (f64, f64),
+    next: (f64, f64),
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let half = f64::from(style.stroke_width) / 2.0;
+    let fill_style = style.filled();
+    let round = |p: (f64, f64)| (p.0.round() as i32, p.1.round() as i32);
+    let (d1, d2) = ((p.0 - prev.0, p.1 - prev.1), (next.0 - p.0, next.1 - p.1));
+    let (l1, l2) = (d1.0.hypot(d1.1), d2.0.hypot(d2.1));
+    let (u1, u2) = ((d1.0 / l1, d1.1 / l1), (d2.0 / l2, d2.1 / l2));
+    let cross = u1.0 * u2.1 - u1.1 * u2.0;
+    if cross.abs() < 1e-9 && u1.0 * u2.0 + u1.1 * u2.1 > 0.0 {
+        return Ok(());
+    }
+    // The normals that point to the outer side of the corner
+    let side = if cross > 0.0 { -1.0 } else { 1.0 };
+    let n1 = (-u1.1 * side, u1.0 * side);
+    let n2 = (-u2.1 * side, u2.0 * side);
+    let o1 = round((p.0 + n1.0 * half, p.1 + n1.1 * half));
+    let o2 = round((p.0 + n2.0 * half, p.1 + n2.1 * half));
+
+    match style.line_join {
+        LineJoin::Round => backend.draw_circle(round(p), half.round() as u32, &fill_style, true),
+        LineJoin::Miter => {
+            let cos = n1.0 * n2.0 + n1.1 * n2.1;
+            // The distance from the corner to the tip of the miter is half / cos(θ / 2)
+            if 1.0 + cos > 2.0 / (MITER_LIMIT * MITER_LIMIT) {
+                let scale = half / (1.0 + cos);
+                let tip = round((p.0 + (n1.0 + n2.0) * scale, p.1 + (n1.1 + n2.1) * scale));
+                backend.fill_polygon(vec![round(p), o1, tip, o2], &fill_style)
+            } else {
+                backend.fill_polygon(vec![round(p), o1, o2], &fill_style)
+            }
+        }
+        LineJoin::Bevel => backend.fill_polygon(vec![round(p), o1, o2], &fill_style),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_round_joins_and_caps() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(10, 14), (50, 14), (50, 6), (10, 6)]);
+            });
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(46, 10), (46, 50), (54, 50), (54, 10)]);
+            });
+            m.check_draw_circle(|c, _, filled, center, radius| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(filled);
+                assert_eq!(radius, 4);
+                assert_eq!(center, (50, 10));
+            });
+            m.check_draw_circle(|_, _, _, _, _| {});
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 0);
+                assert_eq!(b.num_fill_polygon_call, 2);
+                // The join and the two caps
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+        let style = RED
+            .stroke_width(8)
+            .line_join(LineJoin::Round)
+            .line_cap(LineCap::Round);
+        drawing_area
+            .draw(&PathElement::new(vec![(10, 10), (50, 10), (50, 50)], style))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_miter_and_square_caps() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            // The square cap extends the first segment by the half width
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, vec![(6, 14), (50, 14), (50, 6), (6, 6)]);
+            });
+            m.check_fill_polygon(|_, _| {});
+            m.check_fill_polygon(|_, path| {
+                // The corner, the two outer corners of the segments and the tip of the miter
+                assert_eq!(path, vec![(50, 10), (50, 6), (54, 6), (54, 10)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 3);
+            });
+        });
+        let style = RED
+            .stroke_width(8)
+            .line_join(LineJoin::Miter)
+            .line_cap(LineCap::Square);
+        drawing_area
+            .draw(&PathElement::new(vec![(10, 10), (50, 10), (50, 50)], style))
+            .unwrap();
+    }
+}
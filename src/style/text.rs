@@ -3,7 +3,9 @@ use super::font::{FontDesc, FontError, FontFamily, FontStyle, FontTransform};
 use super::size::{HasDimension, SizeDesc};
 use super::BLACK;
 pub use plotters_backend::text_anchor;
-use plotters_backend::{BackendColor, BackendCoord, BackendStyle, BackendTextStyle};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
 
 /// Style of a text
 #[derive(Clone)]
@@ -100,6 +102,112 @@ impl<'a> TextStyle<'a> {
         }
     }
 
+    /// Rotates the text by an arbitrary angle, see [FontDesc::rotate](struct.FontDesc.html#method.rotate).
+    ///
+    /// - `degrees`: The clockwise angle
+    /// - **returns** The up-to-dated text style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let style = TextStyle::from(("sans-serif", 20).into_font()).rotate(-45.0);
+    /// ```
+    pub fn rotate(&self, degrees: f64) -> Self {
+        Self {
+            font: self.font.rotate(degrees),
+            color: self.color,
+            pos: self.pos,
+        }
+    }
+
+    /// Draw the text with the backend. The text that is rotated by an angle that the backend
+    /// can't draw is rasterized and drawn pixel by pixel.
+    pub(crate) fn draw_text<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        text: &str,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.font.is_native_rotation() {
+            return backend.draw_text(text, self, pos);
+        }
+        if self.color.alpha == 0.0 {
+            return Ok(());
+        }
+        let font_error = |e| DrawingErrorKind::FontError(Box::new(e));
+        let ((min_x, min_y), (max_x, max_y)) = self.font.layout_box(text).map_err(font_error)?;
+        let (w, h) = (max_x - min_x, max_y - min_y);
+        if w <= 0 || h <= 0 {
+            return Ok(());
+        }
+
+        // Render the coverage of the unrotated text, then sample it at each rotated pixel
+        let mut coverage = vec![0.0; (w * h) as usize];
+        self.font
+            .draw(text, (0, 0), |x, y, a| {
+                let (x, y) = (x - min_x, y - min_y);
+                if x >= 0 && x < w && y >= 0 && y < h {
+                    let cell = &mut coverage[(y * w + x) as usize];
+                    *cell = f64::from(a).max(*cell);
+                }
+                Ok::<(), ()>(())
+            })
+            .map_err(font_error)?
+            .ok();
+        let sample = |x: i32, y: i32| {
+            if x >= 0 && x < w && y >= 0 && y < h {
+                coverage[(y * w + x) as usize]
+            } else {
+                0.0
+            }
+        };
+
+        let dx = match self.pos.h_pos {
+            text_anchor::HPos::Left => 0,
+            text_anchor::HPos::Center => -w / 2,
+            text_anchor::HPos::Right => -w,
+        };
+        let dy = match self.pos.v_pos {
+            text_anchor::VPos::Top => 0,
+            text_anchor::VPos::Center => -h / 2,
+            text_anchor::VPos::Bottom => -h,
+        };
+        let corners = [(dx, dy), (dx + w, dy), (dx, dy + h), (dx + w, dy + h)];
+        let corners: Vec<_> = corners
+            .iter()
+            .map(|&c| self.font.rotate_offset(c))
+            .collect();
+        let (size_w, size_h) = backend.get_size();
+        let x0 = corners.iter().map(|c| c.0).min().unwrap() + pos.0;
+        let x1 = corners.iter().map(|c| c.0).max().unwrap() + pos.0;
+        let y0 = corners.iter().map(|c| c.1).min().unwrap() + pos.1;
+        let y1 = corners.iter().map(|c| c.1).max().unwrap() + pos.1;
+
+        let (sin, cos) = self.font.get_rotation().to_radians().sin_cos();
+        for y in y0.max(0)..=y1.min(size_h as i32 - 1) {
+            for x in x0.max(0)..=x1.min(size_w as i32 - 1) {
+                // Rotate the pixel center back into the frame of the text
+                let (px, py) = (f64::from(x - pos.0) + 0.5, f64::from(y - pos.1) + 0.5);
+                let u = px * cos + py * sin - f64::from(dx) - 0.5;
+                let v = -px * sin + py * cos - f64::from(dy) - 0.5;
+                let (iu, iv) = (u.floor() as i32, v.floor() as i32);
+                let (fu, fv) = (u - u.floor(), v - v.floor());
+                let alpha = sample(iu, iv) * (1.0 - fu) * (1.0 - fv)
+                    + sample(iu + 1, iv) * fu * (1.0 - fv)
+                    + sample(iu, iv + 1) * (1.0 - fu) * fv
+                    + sample(iu + 1, iv + 1) * fu * fv;
+                if alpha > 0.0 {
+                    let color = BackendColor {
+                        alpha: self.color.alpha * alpha,
+                        rgb: self.color.rgb,
+                    };
+                    backend.draw_pixel((x, y), color)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Sets the anchor position.
     ///
     /// - `pos`: The required anchor position
@@ -250,3 +358,60 @@ impl<'a> BackendTextStyle for TextStyle<'a> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rotated_font() {
+        let font = ("sans-serif", 20).into_font();
+        assert!(font.rotate(450.0).is_native_rotation());
+        assert_eq!(font.rotate(-90.0).get_rotation(), 270.0);
+        assert_eq!(font.rotate(-45.0).get_rotation(), 315.0);
+        assert!(!font.rotate(-45.0).is_native_rotation());
+        assert_eq!(font.rotate(30.0).rotate_offset((10, 0)), (9, 5));
+
+        let (w, h) = font.box_size("rotated label").unwrap();
+        let (rw, rh) = font.rotate(45.0).box_size("rotated label").unwrap();
+        let diagonal = (f64::from(w + h) * std::f64::consts::FRAC_1_SQRT_2).round() as i32;
+        assert!((rw as i32 - diagonal).abs() <= 1);
+        assert!((rh as i32 - diagonal).abs() <= 1);
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
+    #[test]
+    fn test_draw_rotated_text() {
+        use crate::style::text_anchor::{HPos, Pos, VPos};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let pixels = Rc::new(RefCell::new(vec![]));
+        let collected = pixels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_pixel(move |c, pos| {
+                assert_eq!((c.0, c.1, c.2), (255, 0, 0));
+                collected.borrow_mut().push(pos);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 0);
+            });
+        });
+        let style = TextStyle::from(("sans-serif", 20))
+            .rotate(-45.0)
+            .color(&RED)
+            .pos(Pos::new(HPos::Left, VPos::Bottom));
+        drawing_area.draw_text("abc", &style, (50, 150)).unwrap();
+
+        // The text rises to the upper right of the anchor point
+        let pixels = pixels.borrow();
+        assert!(!pixels.is_empty());
+        assert!(pixels.iter().all(|p| p.0 >= 35 && p.1 <= 152));
+        let n = pixels.len() as i32;
+        let center = (
+            pixels.iter().map(|p| p.0).sum::<i32>() / n,
+            pixels.iter().map(|p| p.1).sum::<i32>() / n,
+        );
+        assert!(center.0 > 50 && center.1 < 140);
+    }
+}
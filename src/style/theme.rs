@@ -0,0 +1,146 @@
+use super::color::{Color, RGBAColor, RGBColor};
+use super::font::{FontDesc, FontFamily, FontStyle};
+use super::palette::{Palette, PaletteCategory10};
+use super::TextStyle;
+
+/// The look of a chart: the colors of the background, the axes, the grid and the labels, the
+/// font family, and the palette of the series.
+///
+/// A theme is applied to a chart with [ChartBuilder::theme](../chart/struct.ChartBuilder.html#method.theme),
+/// which changes the defaults of the mesh, the axes and the series labels that aren't set
+/// explicitly, and the background is filled with
+/// [DrawingArea::fill_theme](../drawing/struct.DrawingArea.html#method.fill_theme). A custom
+/// theme is derived from one of the built-in themes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut content = String::new();
+/// let root = SVGBackend::with_string(&mut content, (640, 480)).into_drawing_area();
+/// let theme = Theme::DARK.foreground(&RGBColor(200, 200, 160));
+/// root.fill_theme(&theme).unwrap();
+/// let mut chart = ChartBuilder::on(&root)
+///     .theme(&theme)
+///     .caption("y = x^2", theme.text_style(24.0))
+///     .x_label_area_size(30)
+///     .y_label_area_size(30)
+///     .build_cartesian_2d(0..10, 0..100)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(LineSeries::new((0..10).map(|x| (x, x * x)), &theme.palette_color(0)))
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy)]
+pub struct Theme {
+    pub(crate) background: RGBAColor,
+    pub(crate) foreground: RGBAColor,
+    pub(crate) bold_grid: RGBAColor,
+    pub(crate) light_grid: RGBAColor,
+    pub(crate) font: FontFamily<'static>,
+    pub(crate) palette: &'static [(u8, u8, u8)],
+}
+
+impl Theme {
+    /// The black on white theme, which is the default of the charts
+    pub const LIGHT: Theme = Theme {
+        background: RGBAColor(255, 255, 255, 1.0),
+        foreground: RGBAColor(0, 0, 0, 1.0),
+        bold_grid: RGBAColor(0, 0, 0, 0.2),
+        light_grid: RGBAColor(0, 0, 0, 0.1),
+        font: FontFamily::SansSerif,
+        palette: PaletteCategory10::COLORS,
+    };
+
+    /// The light gray on dark gray theme
+    pub const DARK: Theme = Theme {
+        background: RGBAColor(30, 30, 30, 1.0),
+        foreground: RGBAColor(220, 220, 220, 1.0),
+        bold_grid: RGBAColor(255, 255, 255, 0.2),
+        light_grid: RGBAColor(255, 255, 255, 0.08),
+        font: FontFamily::SansSerif,
+        palette: PaletteCategory10::COLORS,
+    };
+
+    /// Set the color that the drawing area is filled with
+    pub fn background<C: Color>(mut self, color: &C) -> Self {
+        self.background = color.to_rgba();
+        self
+    }
+
+    /// Set the color of the axes and the labels
+    pub fn foreground<C: Color>(mut self, color: &C) -> Self {
+        self.foreground = color.to_rgba();
+        self
+    }
+
+    /// Set the colors of the grid lines
+    /// - `bold`: The color of the lines at the labels
+    /// - `light`: The color of the lines between the labels
+    pub fn grid<B: Color, L: Color>(mut self, bold: &B, light: &L) -> Self {
+        self.bold_grid = bold.to_rgba();
+        self.light_grid = light.to_rgba();
+        self
+    }
+
+    /// Set the font family of the labels
+    pub fn font<F: Into<FontFamily<'static>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Set the palette that the series colors are picked from
+    pub fn palette<P: Palette>(mut self, _palette: P) -> Self {
+        self.palette = P::COLORS;
+        self
+    }
+
+    /// Get the color of the background
+    pub fn background_color(&self) -> RGBAColor {
+        self.background
+    }
+
+    /// Get the color of the axes and the labels
+    pub fn foreground_color(&self) -> RGBAColor {
+        self.foreground
+    }
+
+    /// Get a color of the palette, which wraps around after the last color
+    pub fn palette_color(&self, idx: usize) -> RGBColor {
+        let (r, g, b) = self.palette[idx % self.palette.len()];
+        RGBColor(r, g, b)
+    }
+
+    /// Get the text style with the font and the foreground color of the theme, which is useful
+    /// for the captions and the annotations
+    /// - `size`: The font size in pixels
+    pub fn text_style(&self, size: f64) -> TextStyle<'static> {
+        FontDesc::new(self.font, size, FontStyle::Normal).color(&self.foreground)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::LIGHT
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_custom_theme() {
+        let theme = Theme::DARK
+            .foreground(&RED)
+            .palette(Palette100)
+            .font("monospace");
+        assert_eq!(theme.foreground_color(), RED.to_rgba());
+        assert_eq!(theme.background_color(), Theme::DARK.background_color());
+        assert_eq!(theme.palette_color(5), RGBColor(0, 130, 200));
+        let style = theme.text_style(14.0);
+        assert_eq!(style.color.rgb, (255, 0, 0));
+        assert_eq!(style.font.get_name(), "monospace");
+    }
+}
@@ -0,0 +1,347 @@
+/*!
+  The golden image regression testing, which renders a chart to an in-memory bitmap and compares
+  it with a stored reference image, with a threshold of the perceptual difference of the pixels.
+
+  When the rendering doesn't match, the rendered image and an image that marks the different
+  pixels in red are saved next to the reference, as `<name>.actual.png` and `<name>.diff.png`.
+  The missing reference images are created from the rendering, and all the references are
+  updated when the environment variable `PLOTTERS_UPDATE_GOLDEN` is set.
+
+  ```rust,no_run
+  use plotters::prelude::*;
+  use plotters::testing::GoldenTest;
+
+  GoldenTest::new("tests/golden/sine.png")
+      .tolerance(0.001)
+      .check_chart((320, 240), |root| -> Result<(), Box<dyn std::error::Error>> {
+          root.fill(&WHITE)?;
+          let mut chart = ChartBuilder::on(root).build_cartesian_2d(0.0..6.3, -1.0..1.0)?;
+          chart.draw_series(LineSeries::new(
+              (0..63).map(|x| (x as f64 / 10.0, (x as f64 / 10.0).sin())),
+              &RED,
+          ))?;
+          Ok(())
+      })
+      .unwrap();
+  ```
+*/
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, IntoDrawingArea};
+
+use image::{ImageError, Rgb, RgbImage};
+use plotters_bitmap::BitMapBackend;
+
+/// The environment variable that makes [GoldenTest](struct.GoldenTest.html) overwrite the
+/// reference images with the renderings, rather than comparing them
+pub const UPDATE_GOLDEN_ENV: &str = "PLOTTERS_UPDATE_GOLDEN";
+
+/// The largest squared YIQ distance between two colors
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+/// The error of a golden image test
+#[derive(Debug)]
+pub enum GoldenError {
+    /// The images can't be read or written
+    Io(io::Error),
+    /// The reference image can't be decoded, or the images can't be encoded
+    Image(String),
+    /// The chart can't be drawn
+    Drawing(String),
+    /// The rendering isn't of the size of the reference image
+    SizeMismatch {
+        /// The size of the reference image
+        expected: (u32, u32),
+        /// The size of the rendering
+        actual: (u32, u32),
+    },
+    /// There're more different pixels than the tolerance
+    Mismatch {
+        /// The number of the different pixels
+        diff_pixels: usize,
+        /// The number of all the pixels
+        total_pixels: usize,
+        /// The path of the saved image that marks the different pixels
+        diff_path: PathBuf,
+    },
+}
+
+impl Display for GoldenError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            GoldenError::Io(e) => write!(fmt, "I/O error: {}", e),
+            GoldenError::Image(e) => write!(fmt, "Image error: {}", e),
+            GoldenError::Drawing(e) => write!(fmt, "Drawing error: {}", e),
+            GoldenError::SizeMismatch { expected, actual } => write!(
+                fmt,
+                "The rendering is {}x{}, but the reference image is {}x{}",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+            GoldenError::Mismatch {
+                diff_pixels,
+                total_pixels,
+                diff_path,
+            } => write!(
+                fmt,
+                "{} of {} pixels are different, see {}",
+                diff_pixels,
+                total_pixels,
+                diff_path.display()
+            ),
+        }
+    }
+}
+
+impl Error for GoldenError {}
+
+impl From<io::Error> for GoldenError {
+    fn from(e: io::Error) -> Self {
+        GoldenError::Io(e)
+    }
+}
+
+impl From<ImageError> for GoldenError {
+    fn from(e: ImageError) -> Self {
+        GoldenError::Image(e.to_string())
+    }
+}
+
+/// Render a chart to an in-memory bitmap
+/// - `size`: The size of the bitmap in pixels
+/// - `draw`: The function that draws the chart onto the root drawing area
+/// - **returns** The rendered image
+pub fn render<F, E>(size: (u32, u32), draw: F) -> Result<RgbImage, GoldenError>
+where
+    F: FnOnce(&DrawingArea<BitMapBackend, Shift>) -> Result<(), E>,
+    E: Display,
+{
+    let mut buffer = vec![0; size.0 as usize * size.1 as usize * 3];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area();
+        draw(&root).map_err(|e| GoldenError::Drawing(e.to_string()))?;
+        root.present()
+            .map_err(|e| GoldenError::Drawing(e.to_string()))?;
+    }
+    Ok(RgbImage::from_raw(size.0, size.1, buffer).expect("The buffer fits the image"))
+}
+
+fn yiq(p: &Rgb<u8>) -> (f64, f64, f64) {
+    let (r, g, b) = (f64::from(p[0]), f64::from(p[1]), f64::from(p[2]));
+    (
+        0.298_895_31 * r + 0.586_622_47 * g + 0.114_482_23 * b,
+        0.595_977_99 * r - 0.274_176_10 * g - 0.321_801_89 * b,
+        0.211_470_17 * r - 0.522_617_11 * g + 0.311_146_94 * b,
+    )
+}
+
+/// The perceptual difference between two colors from 0 to 1, which is the distance in the YIQ
+/// color space weighted by how sensitive the eyes are to each channel. For example, the
+/// difference between black and white is almost 1, while a slight change of the anti-aliased edge is
+/// below 0.1.
+pub fn pixel_difference(a: &Rgb<u8>, b: &Rgb<u8>) -> f64 {
+    let (a, b) = (yiq(a), yiq(b));
+    let (dy, di, dq) = (a.0 - b.0, a.1 - b.1, a.2 - b.2);
+    let delta = 0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq;
+    (delta / MAX_YIQ_DELTA).sqrt().min(1.0)
+}
+
+/// The result of comparing two images
+pub struct ImageDiff {
+    /// The number of the pixels whose difference is above the threshold
+    pub diff_pixels: usize,
+    /// The number of all the pixels
+    pub total_pixels: usize,
+    /// The largest difference of the pixels
+    pub max_difference: f64,
+    /// The faded reference image, with the different pixels in red
+    pub diff_image: RgbImage,
+}
+
+/// Compare the rendering with the reference image
+/// - `actual`: The rendered image
+/// - `reference`: The reference image
+/// - `threshold`: The largest [pixel_difference](fn.pixel_difference.html) of the pixels that
+///   are considered the same
+/// - **returns** The comparison
+pub fn compare(
+    actual: &RgbImage,
+    reference: &RgbImage,
+    threshold: f64,
+) -> Result<ImageDiff, GoldenError> {
+    if actual.dimensions() != reference.dimensions() {
+        return Err(GoldenError::SizeMismatch {
+            expected: reference.dimensions(),
+            actual: actual.dimensions(),
+        });
+    }
+    let (width, height) = reference.dimensions();
+    let mut diff_image = RgbImage::new(width, height);
+    let (mut diff_pixels, mut max_difference) = (0, 0.0f64);
+    for (x, y, expected) in reference.enumerate_pixels() {
+        let difference = pixel_difference(actual.get_pixel(x, y), expected);
+        max_difference = max_difference.max(difference);
+        let marked = if difference > threshold {
+            diff_pixels += 1;
+            Rgb([255, 0, 0])
+        } else {
+            // The reference is faded towards white to make the marks stand out
+            let luma = (yiq(expected).0 * 0.1 + 255.0 * 0.9) as u8;
+            Rgb([luma, luma, luma])
+        };
+        diff_image.put_pixel(x, y, marked);
+    }
+    Ok(ImageDiff {
+        diff_pixels,
+        total_pixels: width as usize * height as usize,
+        max_difference,
+        diff_image,
+    })
+}
+
+/// A golden image test that compares the renderings with a reference image
+pub struct GoldenTest {
+    path: PathBuf,
+    threshold: f64,
+    tolerance: f64,
+}
+
+impl GoldenTest {
+    /// Create a new golden image test
+    /// - `path`: The path of the reference image
+    /// - **returns** The newly created test
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            threshold: 0.1,
+            tolerance: 0.0,
+        }
+    }
+
+    /// Set the largest perceptual difference of the pixels that are considered the same, which
+    /// is 0.1 by default, see [pixel_difference](fn.pixel_difference.html)
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the fraction of the pixels that are allowed to be different, which is 0 by default.
+    /// A small tolerance absorbs the differences of the font rendering across the platforms.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn sibling(&self, suffix: &str) -> PathBuf {
+        let stem = self
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.path.with_file_name(format!("{}.{}.png", stem, suffix))
+    }
+
+    /// Compare the rendered image with the reference image, or save it as the reference if
+    /// there's no reference yet or `PLOTTERS_UPDATE_GOLDEN` is set
+    pub fn check(&self, actual: &RgbImage) -> Result<ImageDiff, GoldenError> {
+        if !self.path.exists() || std::env::var_os(UPDATE_GOLDEN_ENV).is_some() {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            actual.save(&self.path)?;
+            return compare(actual, actual, self.threshold);
+        }
+        let reference = image::open(&self.path)?.to_rgb8();
+        let diff = compare(actual, &reference, self.threshold)?;
+        if diff.diff_pixels as f64 > self.tolerance * diff.total_pixels as f64 {
+            let diff_path = self.sibling("diff");
+            diff.diff_image.save(&diff_path)?;
+            actual.save(self.sibling("actual"))?;
+            return Err(GoldenError::Mismatch {
+                diff_pixels: diff.diff_pixels,
+                total_pixels: diff.total_pixels,
+                diff_path,
+            });
+        }
+        Ok(diff)
+    }
+
+    /// Render a chart to an in-memory bitmap and check it, see [render](fn.render.html)
+    pub fn check_chart<F, E>(&self, size: (u32, u32), draw: F) -> Result<ImageDiff, GoldenError>
+    where
+        F: FnOnce(&DrawingArea<BitMapBackend, Shift>) -> Result<(), E>,
+        E: Display,
+    {
+        self.check(&render(size, draw)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn square(
+        root: &DrawingArea<BitMapBackend, Shift>,
+        color: &RGBColor,
+    ) -> Result<(), Box<dyn Error>> {
+        root.fill(&WHITE)?;
+        root.draw(&Rectangle::new([(10, 10), (30, 30)], color.filled()))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_pixel_difference() {
+        assert!(pixel_difference(&Rgb([0, 0, 0]), &Rgb([255, 255, 255])) > 0.95);
+        assert_eq!(
+            pixel_difference(&Rgb([10, 20, 30]), &Rgb([10, 20, 30])),
+            0.0
+        );
+        assert!(pixel_difference(&Rgb([200, 200, 200]), &Rgb([205, 205, 205])) < 0.1);
+    }
+
+    #[test]
+    fn test_golden_test() {
+        let dir = std::env::temp_dir().join(format!("plotters-golden-{}", std::process::id()));
+        let test = GoldenTest::new(dir.join("square.png"));
+
+        // The reference is created by the first run, and the same rendering matches it
+        test.check_chart((40, 40), |root| square(root, &RED))
+            .unwrap();
+        let diff = test
+            .check_chart((40, 40), |root| square(root, &RED))
+            .unwrap();
+        assert_eq!(diff.diff_pixels, 0);
+
+        // The square of another color is a mismatch, which saves the diff image
+        match test.check_chart((40, 40), |root| square(root, &BLUE)) {
+            Err(GoldenError::Mismatch {
+                diff_pixels,
+                diff_path,
+                ..
+            }) => {
+                assert_eq!(diff_pixels, 20 * 20);
+                let diff = image::open(&diff_path).unwrap().to_rgb8();
+                assert_eq!(diff.get_pixel(20, 20), &Rgb([255, 0, 0]));
+                assert_ne!(diff.get_pixel(0, 0), &Rgb([255, 0, 0]));
+            }
+            _ => panic!("The different square should be a mismatch"),
+        }
+        assert!(GoldenTest::new(dir.join("square.png"))
+            .tolerance(0.5)
+            .check_chart((40, 40), |root| square(root, &BLUE))
+            .is_ok());
+
+        match test.check_chart((20, 40), |root| square(root, &RED)) {
+            Err(GoldenError::SizeMismatch { expected, actual }) => {
+                assert_eq!((expected, actual), ((40, 40), (20, 40)));
+            }
+            _ => panic!("The rendering of another size should be a size mismatch"),
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
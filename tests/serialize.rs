@@ -0,0 +1,104 @@
+#![cfg(feature = "serialize")]
+
+use plotters::prelude::*;
+use plotters::style::{LineCap, PaletteSpec, RGBAColor, TextStyleSpec};
+
+#[test]
+fn test_color_serde() {
+    let color: RGBColor = serde_json::from_str(r##""#FF8000""##).unwrap();
+    assert_eq!(color, RGBColor(255, 128, 0));
+    let color: RGBColor = serde_json::from_str(r##""#f80""##).unwrap();
+    assert_eq!(color, RGBColor(255, 136, 0));
+    let color: RGBColor = serde_json::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(color, RGBColor(1, 2, 3));
+    assert_eq!(serde_json::to_string(&color).unwrap(), r##""#010203""##);
+    assert!(serde_json::from_str::<RGBColor>(r#""purple-ish""#).is_err());
+    assert!(serde_json::from_str::<RGBColor>(r##""#ff000080""##).is_err());
+
+    let color: RGBAColor = serde_json::from_str("[1, 2, 3, 0.25]").unwrap();
+    assert_eq!(color, RGBColor(1, 2, 3).mix(0.25));
+    assert_eq!(serde_json::to_string(&color).unwrap(), "[1,2,3,0.25]");
+    let color: RGBAColor = serde_json::from_str(r#""Red""#).unwrap();
+    assert_eq!(serde_json::to_string(&color).unwrap(), r##""#ff0000""##);
+    let color: RGBAColor = serde_json::from_str(r##""#00000080""##).unwrap();
+    assert!((color.alpha() - 128.0 / 255.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_style_serde() {
+    let style: ShapeStyle = serde_json::from_str(
+        r#"{ "color": "blue", "filled": true, "stroke_dash": [4, 2], "line_cap": "round" }"#,
+    )
+    .unwrap();
+    assert_eq!(style.color, BLUE.to_rgba());
+    assert!(style.filled);
+    assert_eq!(style.stroke_width, 1);
+    assert_eq!(style.stroke_dash, vec![4.0, 2.0]);
+    assert_eq!(style.line_cap, LineCap::Round);
+    assert_eq!(style.blend_mode, BlendMode::Normal);
+    let style: ShapeStyle =
+        serde_json::from_str(r#"{ "color": "red", "blend_mode": "additive" }"#).unwrap();
+    assert_eq!(style.blend_mode, BlendMode::Additive);
+    let text = serde_json::to_string(&style).unwrap();
+    let back: ShapeStyle = serde_json::from_str(&text).unwrap();
+    assert_eq!(back.stroke_dash, style.stroke_dash);
+
+    let spec: TextStyleSpec = serde_json::from_str(r#"{ "family": "serif", "size": 20 }"#).unwrap();
+    assert_eq!(spec.style, "normal");
+    assert_eq!(spec.color, BLACK.to_rgba());
+    let text_style = spec.to_text_style();
+    assert_eq!(text_style.font.get_size(), 20.0);
+    assert_eq!(TextStyleSpec::from(&text_style), spec);
+
+    let palette: PaletteSpec = serde_json::from_str(r#""Category10""#).unwrap();
+    assert_eq!(palette.pick(10), palette.pick(0));
+    assert!(serde_json::from_str::<PaletteSpec>(r#""rainbow""#).is_err());
+    let palette = PaletteSpec { colors: vec![] };
+    assert_eq!(palette.pick(3), BLACK);
+}
+
+#[test]
+fn test_recorded_drawing_serde() {
+    let drawing = RecordedDrawing::record((100, 50), |root| {
+        root.draw(&Rectangle::new([(10, 10), (49, 29)], RED.filled()))?;
+        root.draw(&PathElement::new(
+            vec![(0, 0), (100, 50)],
+            ShapeStyle::from(&BLUE).stroke_width(2),
+        ))?;
+        root.draw(&Circle::new((50, 25), 5, &GREEN))?;
+        root.draw(&Text::new("label", (20, 40), ("sans-serif", 10)))?;
+        Ok(())
+    })
+    .unwrap();
+    let json = serde_json::to_string(&drawing).unwrap();
+    let back: RecordedDrawing = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, drawing);
+}
+
+#[cfg(feature = "spec")]
+#[test]
+fn test_spec_round_trip() {
+    use plotters::spec::{ChartSpec, SeriesKind};
+
+    let json = r#"{
+        "caption": "Sales",
+        "x_axis": { "range": [0, 10], "label": "Month" },
+        "y_axis": { "range": [0, 100], "label_area_size": 60 },
+        "series": [
+            { "kind": "line", "label": "2020", "data": [[0, 10], [5, 50], [10, 30]] },
+            { "kind": "point", "data": [[1, 20], [2, 40]], "style": { "color": [255, 0, 0] } },
+            { "kind": "area", "data": [[1, 20], [2, 40]], "style": { "color": "blue" } }
+        ]
+    }"#;
+    let spec: ChartSpec = serde_json::from_str(json).unwrap();
+    assert_eq!(spec.caption_size, 30.0);
+    assert_eq!(spec.x_axis.label_area_size, 40);
+    assert_eq!(spec.y_axis.label_area_size, 60);
+    assert_eq!(spec.series[1].kind, SeriesKind::Point);
+    assert_eq!(spec.series[1].style.color, Some(RED));
+    assert_eq!(spec.series[2].style.color, Some(BLUE));
+    assert_eq!(spec.series[1].style.stroke_width, 1);
+
+    let text = serde_json::to_string(&spec).unwrap();
+    assert_eq!(serde_json::from_str::<ChartSpec>(&text).unwrap(), spec);
+}